@@ -0,0 +1,84 @@
+use iced::widget::{column, row, text};
+use iced::{Element, Theme};
+use widgets::avatar::{avatar, avatar_group, Presence, Shape};
+
+const TEAM: [&str; 9] = [
+    "Ada Lovelace",
+    "Grace Hopper",
+    "Alan Turing",
+    "Edsger Dijkstra",
+    "Barbara Liskov",
+    "Donald Knuth",
+    "Margaret Hamilton",
+    "Ken Thompson",
+    "Frances Allen",
+];
+
+#[derive(Debug, Clone)]
+enum Message {
+    Selected(usize),
+}
+
+struct AvatarExample {
+    selected: Option<usize>,
+}
+
+impl AvatarExample {
+    fn new() -> (Self, iced::Task<Message>) {
+        (Self { selected: None }, iced::Task::none())
+    }
+
+    fn title(&self) -> String {
+        String::from("Avatar Example")
+    }
+
+    fn theme(&self) -> Theme {
+        Theme::Dark
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Selected(index) => self.selected = Some(index),
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let singles = row![
+            avatar("Ada Lovelace").presence(Presence::Online),
+            avatar("Grace Hopper").size(56).presence(Presence::Away),
+            avatar("Alan Turing").shape(Shape::Rounded(8.0)).presence(Presence::Busy),
+            avatar("Edsger Dijkstra").shape(Shape::Square).presence(Presence::Offline),
+            avatar("Prince").size(32),
+            avatar("Barbara Liskov").initials("BL").style(widgets::avatar::primary),
+        ]
+        .spacing(20)
+        .align_y(iced::Alignment::Center);
+
+        let group = avatar_group(TEAM.iter().map(|name| avatar(*name)))
+            .max(4)
+            .on_select(Message::Selected);
+
+        let selected = self
+            .selected
+            .map_or_else(|| String::from("nobody yet"), |index| TEAM[index].to_string());
+
+        column![
+            text("Avatar Example").size(25),
+            text("Initials fall back on a color picked from the name"),
+            singles,
+            text("Click the +N chip to list everyone else"),
+            group,
+            text(format!("Selected: {selected}")),
+        ]
+        .spacing(20)
+        .padding(20)
+        .into()
+    }
+}
+
+fn main() -> iced::Result {
+    iced::application(AvatarExample::new, AvatarExample::update, AvatarExample::view)
+        .theme(AvatarExample::theme)
+        .title(AvatarExample::title)
+        .run()
+}
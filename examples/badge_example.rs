@@ -0,0 +1,85 @@
+use iced::widget::{button, column, container, row, text};
+use iced::{Element, Length, Theme};
+use widgets::badge::{badge, Corner};
+
+#[derive(Debug, Clone)]
+enum Message {
+    Add,
+    AddMany,
+    Clear,
+    ToggleOnline,
+}
+
+struct BadgeExample {
+    unread: u32,
+    online: bool,
+}
+
+impl BadgeExample {
+    fn new() -> (Self, iced::Task<Message>) {
+        (
+            Self {
+                unread: 3,
+                online: true,
+            },
+            iced::Task::none(),
+        )
+    }
+
+    fn title(&self) -> String {
+        String::from("Badge Example")
+    }
+
+    fn theme(&self) -> Theme {
+        Theme::Dark
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Add => self.unread += 1,
+            Message::AddMany => self.unread += 50,
+            Message::Clear => self.unread = 0,
+            Message::ToggleOnline => self.online = !self.online,
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let inbox = badge(button("Inbox").on_press(Message::Add), self.unread);
+
+        let dot = badge(button("Updates").on_press(Message::Add), self.unread).dot();
+
+        let status = badge(
+            container(text("AB").size(18)).center(40).style(container::rounded_box),
+            if self.online { "on" } else { "off" },
+        )
+        .corner(Corner::BottomRight)
+        .style(widgets::badge::primary);
+
+        let beta = badge(button("Labs"), "beta").corner(Corner::TopLeft).max(9);
+
+        column![
+            text("Badge Example").size(25),
+            text("Badges sit over a corner of any element without changing its layout"),
+            row![inbox, dot, status, beta].spacing(40).padding(20),
+            row![
+                button("+1").on_press(Message::Add),
+                button("+50").on_press(Message::AddMany),
+                button("Clear").on_press(Message::Clear),
+                button("Toggle status").on_press(Message::ToggleOnline),
+            ]
+            .spacing(10),
+            text(format!("{} unread, counts above 99 read 99+, zero hides the badge", self.unread)),
+        ]
+        .spacing(20)
+        .padding(20)
+        .width(Length::Fill)
+        .into()
+    }
+}
+
+fn main() -> iced::Result {
+    iced::application(BadgeExample::new, BadgeExample::update, BadgeExample::view)
+        .theme(BadgeExample::theme)
+        .title(BadgeExample::title)
+        .run()
+}
@@ -0,0 +1,97 @@
+use std::collections::HashSet;
+
+use iced::widget::{column, text};
+use iced::{Element, Task, Theme};
+use widgets::chip_bar::{chip, chip_bar, outline};
+
+const LANGUAGES: [(&str, usize); 10] = [
+    ("Rust", 42),
+    ("Go", 17),
+    ("Python", 63),
+    ("TypeScript", 55),
+    ("C++", 21),
+    ("Zig", 4),
+    ("Haskell", 6),
+    ("Kotlin", 12),
+    ("Swift", 9),
+    ("Elixir", 3),
+];
+
+const SORTS: [&str; 3] = ["Newest", "Most stars", "Recently updated"];
+
+#[derive(Debug, Clone)]
+enum Message {
+    LanguagesChanged(HashSet<usize>),
+    SortChanged(HashSet<usize>),
+}
+
+struct ChipBarExample {
+    languages: HashSet<usize>,
+    sort: HashSet<usize>,
+}
+
+impl ChipBarExample {
+    fn new() -> (Self, Task<Message>) {
+        (
+            Self {
+                languages: HashSet::from([0]),
+                sort: HashSet::from([0]),
+            },
+            Task::none(),
+        )
+    }
+
+    fn title(&self) -> String {
+        String::from("Chip Bar Example")
+    }
+
+    fn theme(&self) -> Theme {
+        Theme::Dark
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::LanguagesChanged(languages) => self.languages = languages,
+            Message::SortChanged(sort) => self.sort = sort,
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let languages = LANGUAGES
+            .iter()
+            .enumerate()
+            .map(|(id, (label, count))| chip(id, *label).count(*count));
+        let sorts = SORTS.iter().enumerate().map(|(id, label)| chip(id, *label));
+
+        let mut picked: Vec<&str> = self.languages.iter().map(|id| LANGUAGES[*id].0).collect();
+        picked.sort_unstable();
+        let matching: usize = self.languages.iter().map(|id| LANGUAGES[*id].1).sum();
+
+        column![
+            text("Chip Bar Example").size(25),
+            text("Languages, any number at once; scroll sideways when they do not fit"),
+            chip_bar(languages, &self.languages, Message::LanguagesChanged),
+            text("Sort, one at a time"),
+            chip_bar(sorts, &self.sort, Message::SortChanged)
+                .single()
+                .clear_label(None::<String>)
+                .style(outline),
+            text(if picked.is_empty() {
+                String::from("Showing every repository")
+            } else {
+                format!("{matching} repositories in {}", picked.join(", "))
+            }),
+        ]
+        .spacing(15)
+        .padding(20)
+        .max_width(560)
+        .into()
+    }
+}
+
+fn main() -> iced::Result {
+    iced::application(ChipBarExample::new, ChipBarExample::update, ChipBarExample::view)
+        .theme(ChipBarExample::theme)
+        .title(ChipBarExample::title)
+        .run()
+}
@@ -0,0 +1,77 @@
+use iced::widget::{checkbox, column, text};
+use iced::{Element, Task, Theme};
+use widgets::code_view::code_view;
+
+const SNIPPET: &str = r#"use iced::Color;
+
+/// Generated from the color picker palette
+pub const PRIMARY: Color = Color::from_rgb(0.310, 0.275, 0.898);
+pub const SECONDARY: Color = Color::from_rgb(0.859, 0.153, 0.467);
+pub const ACCENT: Color = Color::from_rgb(0.961, 0.620, 0.043);
+
+pub fn palette() -> [Color; 3] {
+	[PRIMARY, SECONDARY, ACCENT] // this comment is long enough to need scrolling sideways in a narrow window
+}
+"#;
+
+#[derive(Debug, Clone)]
+enum Message {
+    LineNumbersToggled(bool),
+    Copied,
+}
+
+struct CodeViewExample {
+    line_numbers: bool,
+    copies: usize,
+}
+
+impl CodeViewExample {
+    fn new() -> (Self, Task<Message>) {
+        (
+            Self {
+                line_numbers: true,
+                copies: 0,
+            },
+            Task::none(),
+        )
+    }
+
+    fn title(&self) -> String {
+        String::from("Code View Example")
+    }
+
+    fn theme(&self) -> Theme {
+        Theme::Dark
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::LineNumbersToggled(show) => self.line_numbers = show,
+            Message::Copied => self.copies += 1,
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        column![
+            text("Code View Example").size(25),
+            checkbox(self.line_numbers)
+                .label("Line numbers")
+                .on_toggle(Message::LineNumbersToggled),
+            code_view(SNIPPET)
+                .line_numbers(self.line_numbers)
+                .on_copy(Message::Copied),
+            text(format!("Copied {} times", self.copies)),
+        ]
+        .spacing(20)
+        .padding(20)
+        .max_width(600)
+        .into()
+    }
+}
+
+fn main() -> iced::Result {
+    iced::application(CodeViewExample::new, CodeViewExample::update, CodeViewExample::view)
+        .theme(CodeViewExample::theme)
+        .title(CodeViewExample::title)
+        .run()
+}
@@ -0,0 +1,101 @@
+use iced::widget::{column, text};
+use iced::{Element, Length, Theme};
+use widgets::combobox::combobox;
+
+const COUNTRIES: [&str; 12] = [
+    "Argentina",
+    "Australia",
+    "Austria",
+    "Belgium",
+    "Brazil",
+    "Canada",
+    "Denmark",
+    "Finland",
+    "Germany",
+    "Netherlands",
+    "New Zealand",
+    "Switzerland",
+];
+
+#[derive(Debug, Clone)]
+enum Message {
+    CountryPicked(String),
+    CityPicked(String),
+    CityTyped(String),
+}
+
+struct ComboboxExample {
+    countries: Vec<String>,
+    country: Option<String>,
+    cities: Vec<String>,
+    city: Option<String>,
+}
+
+impl ComboboxExample {
+    fn new() -> (Self, iced::Task<Message>) {
+        (
+            Self {
+                countries: COUNTRIES.iter().map(ToString::to_string).collect(),
+                country: None,
+                cities: ["Amsterdam", "Berlin", "Copenhagen", "Helsinki", "Vienna"]
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect(),
+                city: None,
+            },
+            iced::Task::none(),
+        )
+    }
+
+    fn title(&self) -> String {
+        String::from("Combobox Example")
+    }
+
+    fn theme(&self) -> Theme {
+        Theme::Dark
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::CountryPicked(country) => self.country = Some(country),
+            Message::CityPicked(city) => self.city = Some(city),
+            Message::CityTyped(city) => {
+                // Typed cities are remembered as options
+                if !self.cities.contains(&city) {
+                    self.cities.push(city.clone());
+                }
+                self.city = Some(city);
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        column![
+            text("Combobox Example").size(25),
+            text("Country (must match an option)"),
+            combobox(&self.countries, self.country.as_ref(), Message::CountryPicked)
+                .placeholder("Pick a country")
+                .width(Length::Fixed(280.0)),
+            text("City (any text)"),
+            combobox(&self.cities, self.city.as_ref(), Message::CityPicked)
+                .free_text(Message::CityTyped)
+                .placeholder("Type a city")
+                .width(Length::Fixed(280.0)),
+            text(format!(
+                "Country: {}, city: {}",
+                self.country.as_deref().unwrap_or("-"),
+                self.city.as_deref().unwrap_or("-")
+            )),
+        ]
+        .spacing(12)
+        .padding(20)
+        .into()
+    }
+}
+
+fn main() -> iced::Result {
+    iced::application(ComboboxExample::new, ComboboxExample::update, ComboboxExample::view)
+        .theme(ComboboxExample::theme)
+        .title(ComboboxExample::title)
+        .run()
+}
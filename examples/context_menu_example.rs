@@ -0,0 +1,95 @@
+use iced::widget::{column, container, text};
+use iced::{Element, Length, Theme};
+use widgets::context_menu::{context_menu, Item};
+
+#[derive(Debug, Clone)]
+enum Message {
+    Cut,
+    Copy,
+    Paste,
+    Sort(&'static str),
+    Zoom(i32),
+}
+
+struct ContextMenuExample {
+    log: Vec<String>,
+    zoom: i32,
+}
+
+impl ContextMenuExample {
+    fn new() -> (Self, iced::Task<Message>) {
+        (Self { log: Vec::new(), zoom: 100 }, iced::Task::none())
+    }
+
+    fn title(&self) -> String {
+        String::from("Context Menu Example")
+    }
+
+    fn theme(&self) -> Theme {
+        Theme::Dark
+    }
+
+    fn update(&mut self, message: Message) {
+        let entry = match message {
+            Message::Cut => String::from("Cut"),
+            Message::Copy => String::from("Copy"),
+            Message::Paste => String::from("Paste"),
+            Message::Sort(by) => format!("Sort by {by}"),
+            Message::Zoom(step) => {
+                self.zoom = (self.zoom + step).clamp(25, 400);
+                format!("Zoom {}%", self.zoom)
+            }
+        };
+
+        self.log.push(entry);
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let items = [
+            Item::new("Cut", Message::Cut),
+            Item::new("Copy", Message::Copy),
+            Item::new("Paste", Message::Paste).disabled(self.log.is_empty()),
+            Item::separator(),
+            Item::submenu(
+                "Sort by",
+                [
+                    Item::new("Name", Message::Sort("name")),
+                    Item::new("Date", Message::Sort("date")),
+                    Item::submenu(
+                        "Size",
+                        [
+                            Item::new("Largest first", Message::Sort("largest")),
+                            Item::new("Smallest first", Message::Sort("smallest")),
+                        ],
+                    ),
+                ],
+            ),
+            Item::submenu(
+                "Zoom",
+                [Item::new("Zoom in", Message::Zoom(25)), Item::new("Zoom out", Message::Zoom(-25))],
+            ),
+            Item::separator(),
+            Item::submenu("Share", Vec::new()).disabled(true),
+        ];
+
+        let area = container(text("Right-click anywhere in here"))
+            .center(Length::Fill)
+            .style(container::bordered_box);
+
+        column![
+            text("Context Menu Example").size(25),
+            context_menu(area, items),
+            text(format!("Last actions: {}", self.log.iter().rev().take(5).cloned().collect::<Vec<_>>().join(", "))),
+        ]
+        .spacing(10)
+        .padding(20)
+        .into()
+    }
+}
+
+fn main() -> iced::Result {
+    iced::application(ContextMenuExample::new, ContextMenuExample::update, ContextMenuExample::view)
+        .theme(ContextMenuExample::theme)
+        .title(ContextMenuExample::title)
+        .run()
+}
@@ -0,0 +1,83 @@
+use iced::widget::{column, text};
+use iced::{Element, Theme};
+use widgets::date_range_picker::{date_range_picker, Date, DateRange, Preset};
+
+#[derive(Debug, Clone)]
+enum Message {
+    Period(DateRange),
+    Trip(DateRange),
+}
+
+struct DateRangePickerExample {
+    period: Option<DateRange>,
+    trip: Option<DateRange>,
+}
+
+impl DateRangePickerExample {
+    fn new() -> (Self, iced::Task<Message>) {
+        let today = Date::today();
+
+        (
+            Self {
+                period: Some(DateRange::new(today.add_days(-6), today)),
+                trip: None,
+            },
+            iced::Task::none(),
+        )
+    }
+
+    fn title(&self) -> String {
+        String::from("Date Range Picker Example")
+    }
+
+    fn theme(&self) -> Theme {
+        Theme::Dark
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Period(range) => self.period = Some(range),
+            Message::Trip(range) => self.trip = Some(range),
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let today = Date::today();
+        let summary = match self.period {
+            Some(range) => format!("Reporting on {} days, {range}", range.days()),
+            None => String::from("No period selected"),
+        };
+
+        let weekend = today.add_days(i64::from((6 + 7 - today.weekday()) % 7));
+        let trips = [
+            Preset::new("This weekend", DateRange::new(weekend, weekend.add_days(1))),
+            Preset::new("Next week", DateRange::new(today.add_days(7), today.add_days(13))),
+            Preset::new("Next month", {
+                let next = today.first_of_month().add_months(1);
+                DateRange::new(next, next.last_of_month())
+            }),
+        ];
+
+        column![
+            text("Date Range Picker Example").size(25),
+            text("Reporting period"),
+            date_range_picker(self.period, Message::Period),
+            text(summary),
+            text("Trip dates, weeks starting on Sunday"),
+            date_range_picker(self.trip, Message::Trip)
+                .presets(trips)
+                .sunday_first()
+                .placeholder("When are you travelling?"),
+        ]
+        .spacing(10)
+        .padding(20)
+        .into()
+    }
+}
+
+fn main() -> iced::Result {
+    iced::application(DateRangePickerExample::new, DateRangePickerExample::update, DateRangePickerExample::view)
+        .theme(DateRangePickerExample::theme)
+        .title(DateRangePickerExample::title)
+        .run()
+}
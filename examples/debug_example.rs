@@ -0,0 +1,86 @@
+use iced::widget::{button, column, row, scrollable, text};
+use iced::{Element, Length, Task, Theme};
+use widgets::debug::{self, inspector};
+use widgets::generic_overlay::overlay_button;
+use widgets::tree::{branch, tree_handle, DropInfo};
+
+#[derive(Debug, Clone)]
+enum Message {
+    Toggle,
+    Dropped(DropInfo),
+}
+
+struct DebugExample {
+    drops: usize,
+}
+
+impl DebugExample {
+    fn new() -> (Self, Task<Message>) {
+        (Self { drops: 0 }, Task::none())
+    }
+
+    fn title(&self) -> String {
+        String::from("Debug Example")
+    }
+
+    fn theme(&self) -> Theme {
+        Theme::Dark
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Toggle => debug::toggle(),
+            Message::Dropped(info) => self.drops += info.dragged_ids.len(),
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let tree = tree_handle(vec![
+            branch(text("Documents")).with_id(1).with_children(vec![
+                branch(text("Letters")).with_id(2),
+                branch(text("Invoices")).with_id(3).with_children(vec![
+                    branch(text("2025")).with_id(4),
+                    branch(text("2026")).with_id(5),
+                ]),
+            ]),
+            branch(text("Pictures")).with_id(6).accepts_drops().with_children(vec![
+                branch(text("Holidays")).with_id(7),
+            ]),
+            branch(text("Music")).with_id(8).accepts_drops(),
+        ])
+        .id("files")
+        .on_drop(Message::Dropped)
+        .width(Length::Fill);
+
+        let details = overlay_button(
+            "Details",
+            "Details",
+            text("Open overlays are outlined and numbered from the bottom"),
+        )
+        .overlay_width(320.0);
+
+        inspector(
+            column![
+                text("Debug Example").size(25),
+                text("Press F12 or the button to show the inspector, then drag branches around"),
+                row![
+                    button("Toggle inspector").on_press(Message::Toggle),
+                    details,
+                ]
+                .spacing(10),
+                text(format!("Branches moved: {}", self.drops)),
+                scrollable(tree).height(Length::Fill),
+            ]
+            .spacing(15)
+            .padding(20),
+        )
+        .into()
+    }
+}
+
+fn main() -> iced::Result {
+    iced::application(DebugExample::new, DebugExample::update, DebugExample::view)
+        .theme(DebugExample::theme)
+        .title(DebugExample::title)
+        .run()
+}
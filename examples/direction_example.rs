@@ -0,0 +1,104 @@
+use iced::widget::{button, column, row, text};
+use iced::{Element, Length, Task, Theme};
+use widgets::direction::{self, Direction};
+use widgets::generic_overlay::overlay_button;
+use widgets::tree::{branch, tree_handle};
+
+#[derive(Debug, Clone)]
+enum Message {
+    Toggle,
+}
+
+struct DirectionExample {
+    direction: Direction,
+}
+
+impl DirectionExample {
+    fn new() -> (Self, Task<Message>) {
+        (
+            Self {
+                direction: Direction::LeftToRight,
+            },
+            Task::none(),
+        )
+    }
+
+    fn title(&self) -> String {
+        String::from("Direction Example")
+    }
+
+    fn theme(&self) -> Theme {
+        Theme::Dark
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Toggle => {
+                self.direction = if self.direction.is_rtl() {
+                    Direction::LeftToRight
+                } else {
+                    Direction::RightToLeft
+                };
+                direction::set(self.direction);
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let tree = tree_handle(vec![
+            branch(text("Documents")).with_id(1).with_children(vec![
+                branch(text("Letters")).with_id(2),
+                branch(text("Invoices")).with_id(3).with_children(vec![
+                    branch(text("2025")).with_id(4),
+                    branch(text("2026")).with_id(5),
+                ]),
+            ]),
+            branch(text("Pictures")).with_id(6).with_children(vec![
+                branch(text("Holidays")).with_id(7),
+            ]),
+        ])
+        .width(Length::Fill);
+
+        // Always left to right, whatever the global direction
+        let fixed = tree_handle(vec![
+            branch(text("Always left to right")).with_id(1).with_children(vec![
+                branch(text("Child")).with_id(2),
+            ]),
+        ])
+        .direction(Direction::LeftToRight)
+        .width(Length::Fill);
+
+        let details = overlay_button(
+            "Details",
+            "Details",
+            text("The close button sits at the start of the reading order"),
+        )
+        .overlay_width(320.0);
+
+        column![
+            text("Direction Example").size(25),
+            row![
+                button(if self.direction.is_rtl() {
+                    "Switch to left to right"
+                } else {
+                    "Switch to right to left"
+                })
+                .on_press(Message::Toggle),
+                details,
+            ]
+            .spacing(10),
+            tree,
+            fixed,
+        ]
+        .spacing(15)
+        .padding(20)
+        .into()
+    }
+}
+
+fn main() -> iced::Result {
+    iced::application(DirectionExample::new, DirectionExample::update, DirectionExample::view)
+        .theme(DirectionExample::theme)
+        .title(DirectionExample::title)
+        .run()
+}
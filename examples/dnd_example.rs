@@ -0,0 +1,137 @@
+use iced::widget::{column, container, row, text};
+use iced::{Element, Length, Task, Theme};
+use widgets::dnd::{self, drag_source, drop_target, Payload};
+use widgets::tabs::{tab_bar, Tab};
+
+const CARD: &str = "card";
+const COLUMNS: [&str; 3] = ["To do", "Doing", "Done"];
+
+#[derive(Debug, Clone)]
+enum Message {
+    CardDropped(usize, Payload),
+    TabClosed(Payload),
+    SelectTab(usize),
+    ReorderTabs(Vec<usize>),
+}
+
+struct Card {
+    id: usize,
+    title: String,
+    column: usize,
+}
+
+struct DndExample {
+    cards: Vec<Card>,
+    tabs: Vec<String>,
+    active: usize,
+}
+
+impl DndExample {
+    fn new() -> (Self, Task<Message>) {
+        let cards = ["Write the parser", "Fix the tooltip", "Release notes", "Update icons"]
+            .into_iter()
+            .enumerate()
+            .map(|(id, title)| Card {
+                id,
+                title: title.to_string(),
+                column: id % 2,
+            })
+            .collect();
+
+        (
+            Self {
+                cards,
+                tabs: ["main.rs", "lib.rs", "dnd.rs", "README.md"].map(String::from).to_vec(),
+                active: 0,
+            },
+            Task::none(),
+        )
+    }
+
+    fn title(&self) -> String {
+        String::from("Drag and Drop Example")
+    }
+
+    fn theme(&self) -> Theme {
+        Theme::Dark
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::CardDropped(column, payload) => {
+                for card in self.cards.iter_mut().filter(|card| payload.items.contains(&card.id)) {
+                    card.column = column;
+                }
+            }
+            Message::TabClosed(payload) => {
+                if let Some(&index) = payload.items.first()
+                    && index < self.tabs.len()
+                {
+                    self.tabs.remove(index);
+                    self.active = self.active.min(self.tabs.len().saturating_sub(1));
+                }
+            }
+            Message::SelectTab(index) => self.active = index,
+            Message::ReorderTabs(order) => {
+                let active = order.iter().position(|&index| index == self.active);
+                self.tabs = order.iter().map(|&index| self.tabs[index].clone()).collect();
+                self.active = active.unwrap_or(0);
+            }
+        }
+    }
+
+    fn column(&self, index: usize) -> Element<'_, Message> {
+        let cards = self.cards.iter().filter(|card| card.column == index).map(|card| {
+            drag_source(
+                container(text(&card.title)).padding(10).width(Length::Fill).style(container::rounded_box),
+                Payload::new(CARD, [card.id]),
+            )
+            .into()
+        });
+
+        drop_target(
+            container(column![text(COLUMNS[index]).size(18), column(cards).spacing(8)].spacing(10))
+                .padding(10)
+                .width(Length::Fill)
+                .height(260),
+            move |payload| Message::CardDropped(index, payload),
+        )
+        .kinds([CARD])
+        .into()
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let bar = tab_bar(self.tabs.iter().map(|name| Tab::new(name).closable(false)))
+            .active(self.active)
+            .on_select(Message::SelectTab)
+            .on_reorder(Message::ReorderTabs);
+
+        let trash = drop_target(
+            container(text("Drop a tab here to close it"))
+                .padding(15)
+                .width(Length::Fill)
+                .center_x(Length::Fill),
+            Message::TabClosed,
+        )
+        .kinds([dnd::TAB]);
+
+        column![
+            text("Drag and Drop Example").size(25),
+            text("Drag cards between the columns"),
+            row((0..COLUMNS.len()).map(|index| self.column(index))).spacing(10),
+            text("Tabs still reorder within the bar, and can be dragged out onto the target below"),
+            bar,
+            trash,
+        ]
+        .spacing(15)
+        .padding(20)
+        .into()
+    }
+}
+
+fn main() -> iced::Result {
+    iced::application(DndExample::new, DndExample::update, DndExample::view)
+        .theme(DndExample::theme)
+        .title(DndExample::title)
+        .run()
+}
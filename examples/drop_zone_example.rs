@@ -0,0 +1,101 @@
+use std::path::PathBuf;
+
+use iced::widget::{column, row, text};
+use iced::{Element, Length, Task, Theme};
+use widgets::drop_zone::drop_zone;
+
+#[derive(Debug, Clone)]
+enum Message {
+    ImagesDropped(Vec<PathBuf>),
+    ImagesRejected(Vec<PathBuf>),
+    AnythingDropped(Vec<PathBuf>),
+}
+
+struct DropZoneExample {
+    images: Vec<PathBuf>,
+    rejected: Vec<PathBuf>,
+    files: Vec<PathBuf>,
+}
+
+impl DropZoneExample {
+    fn new() -> (Self, Task<Message>) {
+        (
+            Self {
+                images: Vec::new(),
+                rejected: Vec::new(),
+                files: Vec::new(),
+            },
+            Task::none(),
+        )
+    }
+
+    fn title(&self) -> String {
+        String::from("Drop Zone Example")
+    }
+
+    fn theme(&self) -> Theme {
+        Theme::Dark
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::ImagesDropped(paths) => {
+                self.images.extend(paths);
+                self.rejected.clear();
+            }
+            Message::ImagesRejected(paths) => self.rejected = paths,
+            Message::AnythingDropped(paths) => self.files.extend(paths),
+        }
+    }
+
+    fn list<'a>(paths: &'a [PathBuf], empty: &'a str) -> Element<'a, Message> {
+        if paths.is_empty() {
+            return text(empty).size(14).into();
+        }
+
+        column(paths.iter().map(|path| text(path.display().to_string()).size(14).into()))
+            .spacing(4)
+            .into()
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let images = drop_zone(text("Drop PNG or JPEG images here"), Message::ImagesDropped)
+            .extensions(["png", "jpg", "jpeg"])
+            .on_reject(Message::ImagesRejected)
+            .height(160);
+
+        let anything = drop_zone(text("Drop any files here"), Message::AnythingDropped).height(160);
+
+        let rejected: Element<'_, Message> = if self.rejected.is_empty() {
+            column![].into()
+        } else {
+            text(format!("Refused {} file(s) that are not images", self.rejected.len()))
+                .size(14)
+                .into()
+        };
+
+        column![
+            text("Drop Zone Example").size(25),
+            text("Drag files from your file manager onto either zone"),
+            row![
+                column![images, rejected, Self::list(&self.images, "No images yet")]
+                    .spacing(10)
+                    .width(Length::Fill),
+                column![anything, Self::list(&self.files, "No files yet")]
+                    .spacing(10)
+                    .width(Length::Fill),
+            ]
+            .spacing(20),
+        ]
+        .spacing(20)
+        .padding(20)
+        .into()
+    }
+}
+
+fn main() -> iced::Result {
+    iced::application(DropZoneExample::new, DropZoneExample::update, DropZoneExample::view)
+        .theme(DropZoneExample::theme)
+        .title(DropZoneExample::title)
+        .run()
+}
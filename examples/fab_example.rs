@@ -0,0 +1,114 @@
+use iced::widget::{column, container, pick_list, scrollable, text};
+use iced::{Element, Length, Theme};
+use widgets::fab::{fab, Action, Corner};
+
+#[derive(Debug, Clone)]
+enum Message {
+    NewNote,
+    NewList,
+    NewReminder,
+    CornerChanged(CornerChoice),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CornerChoice {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+impl std::fmt::Display for CornerChoice {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            CornerChoice::TopLeft => "Top left",
+            CornerChoice::TopRight => "Top right",
+            CornerChoice::BottomLeft => "Bottom left",
+            CornerChoice::BottomRight => "Bottom right",
+        })
+    }
+}
+
+impl From<CornerChoice> for Corner {
+    fn from(choice: CornerChoice) -> Self {
+        match choice {
+            CornerChoice::TopLeft => Corner::TopLeft,
+            CornerChoice::TopRight => Corner::TopRight,
+            CornerChoice::BottomLeft => Corner::BottomLeft,
+            CornerChoice::BottomRight => Corner::BottomRight,
+        }
+    }
+}
+
+struct FabExample {
+    items: Vec<String>,
+    corner: CornerChoice,
+}
+
+impl FabExample {
+    fn new() -> (Self, iced::Task<Message>) {
+        (
+            Self {
+                items: Vec::new(),
+                corner: CornerChoice::BottomRight,
+            },
+            iced::Task::none(),
+        )
+    }
+
+    fn title(&self) -> String {
+        String::from("Floating Action Button Example")
+    }
+
+    fn theme(&self) -> Theme {
+        Theme::Dark
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::NewNote => self.items.push(format!("Note {}", self.items.len() + 1)),
+            Message::NewList => self.items.push(format!("List {}", self.items.len() + 1)),
+            Message::NewReminder => self.items.push(format!("Reminder {}", self.items.len() + 1)),
+            Message::CornerChanged(corner) => self.corner = corner,
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let items = self
+            .items
+            .iter()
+            .fold(column![].spacing(6), |items, item| items.push(text(item)));
+
+        let content = column![
+            text("Floating Action Button Example").size(25),
+            text("Click the round button to open the speed dial"),
+            pick_list(
+                [
+                    CornerChoice::TopLeft,
+                    CornerChoice::TopRight,
+                    CornerChoice::BottomLeft,
+                    CornerChoice::BottomRight,
+                ],
+                Some(self.corner),
+                Message::CornerChanged,
+            ),
+            scrollable(items).height(Length::Fill),
+        ]
+        .spacing(20)
+        .padding(20);
+
+        fab(container(content).width(Length::Fill).height(Length::Fill))
+            .corner(self.corner.into())
+            .action(Action::new("N", "New note", Message::NewNote))
+            .action(Action::new("L", "New list", Message::NewList))
+            .action(Action::new("R", "New reminder", Message::NewReminder))
+            .into()
+    }
+}
+
+fn main() -> iced::Result {
+    iced::application(FabExample::new, FabExample::update, FabExample::view)
+        .theme(FabExample::theme)
+        .title(FabExample::title)
+        .run()
+}
@@ -0,0 +1,100 @@
+use iced::widget::{button, column, row, text, text_input};
+use iced::{Element, Task, Theme};
+use widgets::focus;
+use widgets::generic_overlay::overlay_button;
+use widgets::rating::rating;
+
+#[derive(Debug, Clone)]
+enum Message {
+    Name(String),
+    Email(String),
+    Rated(f32),
+    Street(String),
+    City(String),
+    Next,
+    Previous,
+}
+
+struct FocusExample {
+    name: String,
+    email: String,
+    stars: f32,
+    street: String,
+    city: String,
+}
+
+impl FocusExample {
+    fn new() -> (Self, Task<Message>) {
+        (
+            Self {
+                name: String::new(),
+                email: String::new(),
+                stars: 3.0,
+                street: String::new(),
+                city: String::new(),
+            },
+            Task::none(),
+        )
+    }
+
+    fn title(&self) -> String {
+        String::from("Focus Example")
+    }
+
+    fn theme(&self) -> Theme {
+        Theme::Dark
+    }
+
+    fn update(&mut self, message: Message) -> Task<Message> {
+        match message {
+            Message::Name(name) => self.name = name,
+            Message::Email(email) => self.email = email,
+            Message::Rated(stars) => self.stars = stars,
+            Message::Street(street) => self.street = street,
+            Message::City(city) => self.city = city,
+            Message::Next => return focus::next(),
+            Message::Previous => return focus::previous(),
+        }
+
+        Task::none()
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let address = overlay_button(
+            "Edit address",
+            "Address",
+            column![
+                text("Tab cycles between these two fields until the overlay is closed"),
+                text_input("Street", &self.street).on_input(Message::Street),
+                text_input("City", &self.city).on_input(Message::City),
+            ]
+            .spacing(10),
+        )
+        .overlay_width(320.0);
+
+        let form = column![
+            text("Focus Example").size(25),
+            text("Press Tab and Shift+Tab to move between the fields"),
+            text_input("Name", &self.name).on_input(Message::Name),
+            text_input("Email", &self.email).on_input(Message::Email),
+            row![text("Rating"), rating(self.stars).on_change(Message::Rated)].spacing(10),
+            address,
+            row![
+                button("Focus previous").on_press(Message::Previous),
+                button("Focus next").on_press(Message::Next),
+            ]
+            .spacing(10),
+        ]
+        .spacing(15)
+        .padding(20);
+
+        focus::scope(form).trap(true).into()
+    }
+}
+
+fn main() -> iced::Result {
+    iced::application(FocusExample::new, FocusExample::update, FocusExample::view)
+        .theme(FocusExample::theme)
+        .title(FocusExample::title)
+        .run()
+}
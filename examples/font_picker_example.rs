@@ -0,0 +1,65 @@
+use iced::widget::{column, text};
+use iced::{Element, Font, Theme};
+use widgets::font_picker::{self, font_picker};
+
+#[derive(Debug, Clone)]
+enum Message {
+    Heading(Font),
+    Body(Font),
+}
+
+struct FontPickerExample {
+    heading: Option<Font>,
+    body: Option<Font>,
+}
+
+impl FontPickerExample {
+    fn new() -> (Self, iced::Task<Message>) {
+        (Self { heading: None, body: Some(Font::MONOSPACE) }, iced::Task::none())
+    }
+
+    fn title(&self) -> String {
+        String::from("Font Picker Example")
+    }
+
+    fn theme(&self) -> Theme {
+        Theme::Dark
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Heading(font) => self.heading = Some(font),
+            Message::Body(font) => self.body = Some(font),
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let fonts = font_picker::installed_fonts();
+
+        column![
+            text("Font Picker Example").size(25),
+            text(format!("{} fonts found", fonts.len())),
+            text("Heading font"),
+            font_picker(self.heading, Message::Heading).preview("Chapter One"),
+            text("Body font, limited to a few families"),
+            font_picker(self.body, Message::Body)
+                .fonts(fonts.iter().take(12))
+                .max_recent(3),
+            text("The heading, in the picked font")
+                .size(28)
+                .font(self.heading.unwrap_or_default()),
+            text("Body text, in the picked font. Open a picker and type to search.")
+                .font(self.body.unwrap_or_default()),
+        ]
+        .spacing(12)
+        .padding(20)
+        .into()
+    }
+}
+
+fn main() -> iced::Result {
+    iced::application(FontPickerExample::new, FontPickerExample::update, FontPickerExample::view)
+        .theme(FontPickerExample::theme)
+        .title(FontPickerExample::title)
+        .run()
+}
@@ -0,0 +1,113 @@
+use iced::widget::{checkbox, column, scrollable, text};
+use iced::{Element, Length, Subscription, Theme};
+use std::collections::HashSet;
+use std::time::Duration;
+use widgets::fs_tree::FsTree;
+
+#[derive(Debug, Clone)]
+enum Message {
+    Toggled(usize, bool),
+    Selected(HashSet<usize>),
+    ShowHidden(bool),
+    Refresh,
+}
+
+struct FsTreeExample {
+    files: Option<FsTree>,
+    show_hidden: bool,
+    selected: Vec<String>,
+    error: Option<String>,
+}
+
+impl FsTreeExample {
+    fn new() -> (Self, iced::Task<Message>) {
+        let root = std::env::args().nth(1).unwrap_or_else(|| String::from("."));
+        let (files, error) = match FsTree::new(root) {
+            Ok(files) => (Some(files), None),
+            Err(error) => (None, Some(error.to_string())),
+        };
+
+        (
+            Self {
+                files,
+                show_hidden: false,
+                selected: Vec::new(),
+                error,
+            },
+            iced::Task::none(),
+        )
+    }
+
+    fn title(&self) -> String {
+        String::from("Filesystem Tree Example")
+    }
+
+    fn theme(&self) -> Theme {
+        Theme::Dark
+    }
+
+    fn update(&mut self, message: Message) {
+        let Some(files) = &mut self.files else {
+            return;
+        };
+
+        let result = match message {
+            Message::Toggled(id, expanded) => files.toggle(id, expanded),
+            Message::Selected(ids) => {
+                self.selected = ids
+                    .into_iter()
+                    .filter_map(|id| files.path(id))
+                    .map(|path| path.display().to_string())
+                    .collect();
+                Ok(())
+            }
+            Message::ShowHidden(show) => {
+                self.show_hidden = show;
+                files.set_show_hidden(show);
+                Ok(())
+            }
+            Message::Refresh => files.refresh().map(|_| ()),
+        };
+
+        self.error = result.err().map(|error| error.to_string());
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let tree: Element<'_, Message> = match &self.files {
+            Some(files) => files
+                .view()
+                .on_toggle(Message::Toggled)
+                .on_select(Message::Selected)
+                .width(Length::Fill)
+                .into(),
+            None => text("Could not read the directory").into(),
+        };
+
+        column![
+            text("Filesystem Tree Example").size(25),
+            text(format!(
+                "Root: {}",
+                self.files.as_ref().map_or_else(String::new, |files| files.root().display().to_string())
+            )),
+            checkbox(self.show_hidden).label("Show hidden files").on_toggle(Message::ShowHidden),
+            scrollable(tree).height(Length::Fill),
+            text(format!("Selected: {}", self.selected.join(", "))),
+            text(self.error.clone().unwrap_or_default()),
+        ]
+        .spacing(12)
+        .padding(20)
+        .into()
+    }
+
+    fn subscription(&self) -> Subscription<Message> {
+        FsTree::watch(Duration::from_secs(1)).map(|_| Message::Refresh)
+    }
+}
+
+fn main() -> iced::Result {
+    iced::application(FsTreeExample::new, FsTreeExample::update, FsTreeExample::view)
+        .theme(FsTreeExample::theme)
+        .title(FsTreeExample::title)
+        .subscription(FsTreeExample::subscription)
+        .run()
+}
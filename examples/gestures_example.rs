@@ -0,0 +1,86 @@
+use iced::widget::{column, container, row, text};
+use iced::{Element, Length, Task, Theme};
+use widgets::gestures::{gesture_area, Direction, Gesture};
+
+const PAGES: [&str; 4] = ["Welcome", "Swipe left or right to page", "Pinch to zoom the text", "Double tap to reset"];
+
+#[derive(Debug, Clone)]
+enum Message {
+    Gesture(Gesture),
+}
+
+struct GesturesExample {
+    page: usize,
+    zoom: f32,
+    last: Option<Gesture>,
+}
+
+impl GesturesExample {
+    fn new() -> (Self, Task<Message>) {
+        (
+            Self {
+                page: 0,
+                zoom: 1.0,
+                last: None,
+            },
+            Task::none(),
+        )
+    }
+
+    fn title(&self) -> String {
+        String::from("Gestures Example")
+    }
+
+    fn theme(&self) -> Theme {
+        Theme::Dark
+    }
+
+    fn update(&mut self, message: Message) {
+        let Message::Gesture(gesture) = message;
+
+        match gesture {
+            Gesture::Swipe {
+                direction: Direction::Left,
+                ..
+            } => self.page = (self.page + 1).min(PAGES.len() - 1),
+            Gesture::Swipe {
+                direction: Direction::Right,
+                ..
+            } => self.page = self.page.saturating_sub(1),
+            Gesture::Pinch { scale, .. } => self.zoom = scale.clamp(0.5, 3.0),
+            Gesture::DoubleTap(_) => self.zoom = 1.0,
+            _ => {}
+        }
+
+        self.last = Some(gesture);
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let page = container(text(PAGES[self.page]).size(24.0 * self.zoom))
+            .center(Length::Fill)
+            .style(container::rounded_box);
+
+        let dots = row((0..PAGES.len()).map(|index| text(if index == self.page { "●" } else { "○" }).into())).spacing(6);
+
+        column![
+            text("Gestures Example").size(25),
+            text("Use a touch screen: swipe, pinch, tap, double tap or hold"),
+            gesture_area(page, Message::Gesture),
+            container(dots).center_x(Length::Fill),
+            text(match self.last {
+                Some(gesture) => format!("Last gesture: {gesture:?}"),
+                None => String::from("No gesture yet"),
+            }),
+        ]
+        .spacing(15)
+        .padding(20)
+        .into()
+    }
+}
+
+fn main() -> iced::Result {
+    iced::application(GesturesExample::new, GesturesExample::update, GesturesExample::view)
+        .theme(GesturesExample::theme)
+        .title(GesturesExample::title)
+        .run()
+}
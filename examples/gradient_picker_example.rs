@@ -0,0 +1,64 @@
+use iced::widget::{column, container, text};
+use iced::{Color, Element, Length, Task, Theme};
+use widgets::gradient_picker::{gradient_picker, Gradient};
+
+#[derive(Debug, Clone)]
+enum Message {
+    GradientChanged(Gradient),
+}
+
+struct GradientPickerExample {
+    gradient: Gradient,
+}
+
+impl GradientPickerExample {
+    fn new() -> (Self, Task<Message>) {
+        (
+            Self {
+                gradient: Gradient::new(iced::Degrees(135.0))
+                    .add_stop(0.0, Color::from_rgb8(0x4F, 0x46, 0xE5))
+                    .add_stop(0.5, Color::from_rgb8(0xDB, 0x27, 0x77))
+                    .add_stop(1.0, Color::from_rgb8(0xF5, 0x9E, 0x0B)),
+            },
+            Task::none(),
+        )
+    }
+
+    fn title(&self) -> String {
+        String::from("Gradient Picker Example")
+    }
+
+    fn theme(&self) -> Theme {
+        Theme::Dark
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::GradientChanged(gradient) => self.gradient = gradient,
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let background = iced::Background::from(&self.gradient);
+        let preview = container(text("Preview").size(30))
+            .center(Length::Fill)
+            .style(move |_| container::Style::default().background(background));
+
+        column![
+            text("Gradient Picker Example").size(25),
+            text("Drag the handles, click the bar to add a stop, drag a handle away to remove it"),
+            gradient_picker(&self.gradient, Message::GradientChanged).width(480),
+            preview,
+        ]
+        .spacing(20)
+        .padding(20)
+        .into()
+    }
+}
+
+fn main() -> iced::Result {
+    iced::application(GradientPickerExample::new, GradientPickerExample::update, GradientPickerExample::view)
+        .theme(GradientPickerExample::theme)
+        .title(GradientPickerExample::title)
+        .run()
+}
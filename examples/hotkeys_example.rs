@@ -0,0 +1,120 @@
+use iced::widget::{column, text, text_input};
+use iced::{Element, Task, Theme};
+use widgets::hotkeys::{hotkeys, Keymap};
+use widgets::menu::{menu_bar, Item, Menu};
+
+#[derive(Debug, Clone, PartialEq)]
+enum Message {
+    New,
+    Open,
+    Save,
+    Undo,
+    Redo,
+    Find,
+    Note(String),
+}
+
+struct HotkeysExample {
+    keymap: Keymap<Message>,
+    note: String,
+    log: Vec<String>,
+}
+
+impl HotkeysExample {
+    fn new() -> (Self, Task<Message>) {
+        let keymap = Keymap::new()
+            .bind("Mod+N", Message::New)
+            .bind("Mod+O", Message::Open)
+            .bind("Mod+S", Message::Save)
+            .bind("Mod+Z", Message::Undo)
+            .bind("Mod+Shift+Z", Message::Redo)
+            .bind("Mod+Y", Message::Redo)
+            .bind("F3", Message::Find)
+            // Taken already, reported below
+            .bind("Mod+F", Message::Find)
+            .bind("Mod+F", Message::Open);
+
+        (
+            Self {
+                keymap,
+                note: String::new(),
+                log: Vec::new(),
+            },
+            Task::none(),
+        )
+    }
+
+    fn title(&self) -> String {
+        String::from("Hotkeys Example")
+    }
+
+    fn theme(&self) -> Theme {
+        Theme::Dark
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Note(note) => self.note = note,
+            message => self.log.push(format!("{message:?}")),
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let menus = menu_bar(
+            [
+                Menu::new(
+                    "File",
+                    [
+                        Item::new("New", Message::New),
+                        Item::new("Open…", Message::Open),
+                        Item::separator(),
+                        Item::new("Save", Message::Save),
+                    ],
+                ),
+                Menu::new(
+                    "Edit",
+                    [
+                        Item::new("Undo", Message::Undo),
+                        Item::new("Redo", Message::Redo),
+                        Item::separator(),
+                        Item::new("Find", Message::Find),
+                    ],
+                ),
+            ],
+            |message| message,
+        )
+        .hotkeys(&self.keymap);
+
+        let conflicts = self.keymap.conflicts().into_iter().map(|conflict| {
+            let ids: Vec<String> = conflict.ids.iter().map(|id| format!("{id:?}")).collect();
+            text(format!("{} is bound to {}", conflict.shortcut, ids.join(" and "))).into()
+        });
+
+        let content = column![
+            menus,
+            column![
+                text("Hotkeys Example").size(25),
+                text("Use the shortcuts shown in the menus; letters typed into the field stay there"),
+                text_input("A note", &self.note).on_input(Message::Note),
+                text(if self.log.is_empty() {
+                    String::from("Nothing triggered yet")
+                } else {
+                    self.log.join(", ")
+                }),
+                text("Conflicts:"),
+                column(conflicts).spacing(5),
+            ]
+            .spacing(15)
+            .padding(20),
+        ];
+
+        hotkeys(content, &self.keymap).into()
+    }
+}
+
+fn main() -> iced::Result {
+    iced::application(HotkeysExample::new, HotkeysExample::update, HotkeysExample::view)
+        .theme(HotkeysExample::theme)
+        .title(HotkeysExample::title)
+        .run()
+}
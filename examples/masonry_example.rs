@@ -0,0 +1,81 @@
+use iced::widget::{column, container, row, scrollable, slider, text};
+use iced::{Element, Length, Task, Theme};
+use widgets::masonry::masonry;
+
+#[derive(Debug, Clone)]
+enum Message {
+    MinWidthChanged(f32),
+}
+
+struct MasonryExample {
+    cards: Vec<(String, String)>,
+    min_width: f32,
+}
+
+impl MasonryExample {
+    fn new() -> (Self, Task<Message>) {
+        let sentence = "Masonry packs each card under the shortest column so far. ";
+        let cards = (1..=24)
+            .map(|index| {
+                (
+                    format!("Card {index}"),
+                    sentence.repeat(1 + (index * 7) % 5),
+                )
+            })
+            .collect();
+
+        (
+            Self {
+                cards,
+                min_width: 220.0,
+            },
+            Task::none(),
+        )
+    }
+
+    fn title(&self) -> String {
+        String::from("Masonry Example")
+    }
+
+    fn theme(&self) -> Theme {
+        Theme::Dark
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::MinWidthChanged(width) => self.min_width = width,
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let cards = masonry(self.cards.iter().map(|(title, body)| {
+            container(column![text(title).size(18), text(body)].spacing(8))
+                .padding(12)
+                .width(Length::Fill)
+                .style(container::rounded_box)
+                .into()
+        }))
+        .min_item_width(self.min_width)
+        .spacing(12);
+
+        column![
+            text("Masonry Example").size(25),
+            row![
+                text(format!("Minimum column width: {:.0}", self.min_width)),
+                slider(120.0..=480.0, self.min_width, Message::MinWidthChanged).width(300),
+            ]
+            .spacing(20),
+            scrollable(cards).height(Length::Fill),
+        ]
+        .spacing(20)
+        .padding(20)
+        .into()
+    }
+}
+
+fn main() -> iced::Result {
+    iced::application(MasonryExample::new, MasonryExample::update, MasonryExample::view)
+        .theme(MasonryExample::theme)
+        .title(MasonryExample::title)
+        .run()
+}
@@ -0,0 +1,145 @@
+use iced::widget::{column, container, text};
+use iced::{Element, Length, Theme};
+use widgets::menu::{menu_bar, Item, Menu};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Command {
+    New,
+    Open,
+    Save,
+    Quit,
+    Undo,
+    Redo,
+    ToggleWrap,
+    ToggleMinimap,
+    Layout(Layout),
+    Zoom(i32),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Layout {
+    Single,
+    Split,
+    Grid,
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    Command(Command),
+}
+
+struct MenuExample {
+    wrap: bool,
+    minimap: bool,
+    layout: Layout,
+    zoom: i32,
+    last: Option<Command>,
+}
+
+impl MenuExample {
+    fn new() -> (Self, iced::Task<Message>) {
+        (
+            Self {
+                wrap: true,
+                minimap: false,
+                layout: Layout::Single,
+                zoom: 100,
+                last: None,
+            },
+            iced::Task::none(),
+        )
+    }
+
+    fn title(&self) -> String {
+        String::from("Menu Example")
+    }
+
+    fn theme(&self) -> Theme {
+        Theme::Dark
+    }
+
+    fn update(&mut self, message: Message) {
+        let Message::Command(command) = message;
+
+        match command {
+            Command::ToggleWrap => self.wrap = !self.wrap,
+            Command::ToggleMinimap => self.minimap = !self.minimap,
+            Command::Layout(layout) => self.layout = layout,
+            Command::Zoom(step) => self.zoom = (self.zoom + step).clamp(25, 400),
+            _ => {}
+        }
+
+        self.last = Some(command);
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let bar = menu_bar(
+            [
+                Menu::new(
+                    "File",
+                    [
+                        Item::new("New", Command::New).icon("📄").shortcut("Ctrl+N"),
+                        Item::new("Open…", Command::Open).icon("📂").shortcut("Ctrl+O"),
+                        Item::new("Save", Command::Save).icon("💾").shortcut("Ctrl+S"),
+                        Item::separator(),
+                        Item::new("Quit", Command::Quit).shortcut("Ctrl+Q"),
+                    ],
+                ),
+                Menu::new(
+                    "Edit",
+                    [
+                        Item::new("Undo", Command::Undo).shortcut("Ctrl+Z"),
+                        Item::new("Redo", Command::Redo).shortcut("Ctrl+Shift+Z").disabled(true),
+                    ],
+                ),
+                Menu::new(
+                    "View",
+                    [
+                        Item::new("Word wrap", Command::ToggleWrap).checked(self.wrap).shortcut("Alt+Z"),
+                        Item::new("Minimap", Command::ToggleMinimap).checked(self.minimap),
+                        Item::separator(),
+                        Item::submenu(
+                            "Layout",
+                            Item::radio_group(
+                                [
+                                    ("Single", Command::Layout(Layout::Single)),
+                                    ("Split", Command::Layout(Layout::Split)),
+                                    ("Grid", Command::Layout(Layout::Grid)),
+                                ],
+                                &Command::Layout(self.layout),
+                            ),
+                        ),
+                        Item::submenu(
+                            "Zoom",
+                            [
+                                Item::new("Zoom in", Command::Zoom(10)).shortcut("Ctrl++"),
+                                Item::new("Zoom out", Command::Zoom(-10)).shortcut("Ctrl+-"),
+                            ],
+                        ),
+                    ],
+                ),
+            ],
+            Message::Command,
+        );
+
+        let status = column![
+            text(format!("Last command: {:?}", self.last)),
+            text(format!(
+                "Word wrap: {}, minimap: {}, layout: {:?}, zoom: {}%",
+                self.wrap, self.minimap, self.layout, self.zoom
+            )),
+            text("Open a menu, then hover across the titles or use the arrow keys."),
+        ]
+        .spacing(8)
+        .padding(20);
+
+        column![bar, container(status).width(Length::Fill).height(Length::Fill)].into()
+    }
+}
+
+fn main() -> iced::Result {
+    iced::application(MenuExample::new, MenuExample::update, MenuExample::view)
+        .theme(MenuExample::theme)
+        .title(MenuExample::title)
+        .run()
+}
@@ -0,0 +1,148 @@
+use iced::widget::{column, container, operation, row, scrollable, text, Space};
+use iced::{Color, Element, Length, Point, Rectangle, Size, Task, Theme};
+use widgets::minimap::minimap;
+
+const COLUMNS: usize = 24;
+const ROWS: usize = 18;
+const CELL: f32 = 90.0;
+
+#[derive(Debug, Clone)]
+enum Message {
+    Scrolled(scrollable::Viewport),
+    Navigate(Point),
+}
+
+struct MinimapExample {
+    board: iced::widget::Id,
+    visible: Rectangle,
+    filled: Vec<(usize, usize)>,
+}
+
+impl MinimapExample {
+    fn new() -> (Self, Task<Message>) {
+        // A scattering of filled cells, stable without pulling in a random number generator
+        let filled = (0..ROWS)
+            .flat_map(|y| (0..COLUMNS).map(move |x| (x, y)))
+            .filter(|(x, y)| (x * 7 + y * 13) % 5 == 0)
+            .collect();
+
+        (
+            Self {
+                board: iced::widget::Id::new("board"),
+                visible: Rectangle::new(Point::ORIGIN, Size::new(600.0, 400.0)),
+                filled,
+            },
+            Task::none(),
+        )
+    }
+
+    fn title(&self) -> String {
+        String::from("Minimap Example")
+    }
+
+    fn theme(&self) -> Theme {
+        Theme::Dark
+    }
+
+    fn update(&mut self, message: Message) -> Task<Message> {
+        match message {
+            Message::Scrolled(viewport) => {
+                let offset = viewport.absolute_offset();
+                self.visible = Rectangle::new(Point::new(offset.x, offset.y), viewport.bounds().size());
+                Task::none()
+            }
+            Message::Navigate(corner) => operation::scroll_to(
+                self.board.clone(),
+                scrollable::AbsoluteOffset {
+                    x: corner.x,
+                    y: corner.y,
+                },
+            ),
+        }
+    }
+
+    fn board_size() -> Size {
+        Size::new(COLUMNS as f32 * CELL, ROWS as f32 * CELL)
+    }
+
+    fn cell(filled: bool, x: usize, y: usize) -> Element<'static, Message> {
+        container(if filled { text(format!("{x},{y}")) } else { text("") })
+            .center(CELL)
+            .style(move |theme: &Theme| {
+                let palette = theme.extended_palette();
+                container::Style {
+                    background: filled.then(|| palette.primary.weak.color.into()),
+                    border: iced::Border {
+                        color: Color::from_rgba(1.0, 1.0, 1.0, 0.05),
+                        width: 1.0,
+                        radius: 0.0.into(),
+                    },
+                    ..container::Style::default()
+                }
+            })
+            .into()
+    }
+
+    fn board(&self) -> Element<'static, Message> {
+        (0..ROWS)
+            .fold(column![], |board, y| {
+                board.push((0..COLUMNS).fold(row![], |cells, x| {
+                    cells.push(Self::cell(self.filled.contains(&(x, y)), x, y))
+                }))
+            })
+            .into()
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let board = scrollable(self.board())
+            .id(self.board.clone())
+            .direction(scrollable::Direction::Both {
+                vertical: scrollable::Scrollbar::default(),
+                horizontal: scrollable::Scrollbar::default(),
+            })
+            .on_scroll(Message::Scrolled)
+            .width(Length::Fill)
+            .height(Length::Fill);
+
+        let shapes = self.filled.iter().map(|(x, y)| {
+            Rectangle::new(
+                Point::new(*x as f32 * CELL + 4.0, *y as f32 * CELL + 4.0),
+                Size::new(CELL - 8.0, CELL - 8.0),
+            )
+        });
+
+        let overview = column![
+            text("Markers").size(14),
+            minimap(Self::board_size(), self.visible, Message::Navigate)
+                .shapes(shapes)
+                .width(240)
+                .height(180),
+            text("Preview").size(14),
+            minimap(Self::board_size(), self.visible, Message::Navigate)
+                .preview(self.board())
+                .style(widgets::minimap::outline)
+                .width(240)
+                .height(180),
+            Space::new().height(Length::Fill),
+            text(format!("Showing {:.0}, {:.0}", self.visible.x, self.visible.y)),
+        ]
+        .spacing(8)
+        .width(240);
+
+        column![
+            text("Minimap Example").size(25),
+            text("Drag the highlighted rectangle or click anywhere on a minimap to scroll the board"),
+            row![board, overview].spacing(20).height(Length::Fill),
+        ]
+        .spacing(10)
+        .padding(20)
+        .into()
+    }
+}
+
+fn main() -> iced::Result {
+    iced::application(MinimapExample::new, MinimapExample::update, MinimapExample::view)
+        .theme(MinimapExample::theme)
+        .title(MinimapExample::title)
+        .run()
+}
@@ -0,0 +1,102 @@
+use iced::widget::{column, text};
+use iced::{Element, Length, Point, Theme};
+use widgets::node_graph::{node_graph, Change, Connection, Node, NodeId, PortRef};
+
+#[derive(Debug, Clone)]
+enum Message {
+    Graph(Change),
+}
+
+struct NodeGraphExample {
+    nodes: Vec<Node>,
+    connections: Vec<Connection>,
+    selected: Vec<NodeId>,
+    log: String,
+}
+
+impl NodeGraphExample {
+    fn new() -> (Self, iced::Task<Message>) {
+        let nodes = vec![
+            Node::new(1, "Texture", Point::new(40.0, 60.0))
+                .output("Color", "color")
+                .output("Alpha", "float"),
+            Node::new(2, "Number", Point::new(40.0, 220.0)).output("Value", "float"),
+            Node::new(3, "Multiply", Point::new(300.0, 120.0))
+                .input("Color", "color")
+                .input("Factor", "float")
+                .output("Result", "color"),
+            Node::new(4, "Output", Point::new(560.0, 140.0))
+                .input("Color", "color")
+                .input("Alpha", "float"),
+        ];
+        let connections = vec![
+            Connection::new(PortRef::new(1, 0), PortRef::new(3, 0)),
+            Connection::new(PortRef::new(3, 0), PortRef::new(4, 0)),
+        ];
+
+        (
+            Self {
+                nodes,
+                connections,
+                selected: Vec::new(),
+                log: String::new(),
+            },
+            iced::Task::none(),
+        )
+    }
+
+    fn title(&self) -> String {
+        String::from("Node Graph Example")
+    }
+
+    fn theme(&self) -> Theme {
+        Theme::Dark
+    }
+
+    fn update(&mut self, message: Message) {
+        let Message::Graph(change) = message;
+
+        match &change {
+            Change::Moved(positions) => {
+                for (id, position) in positions {
+                    if let Some(node) = self.nodes.iter_mut().find(|node| node.id == *id) {
+                        node.position = *position;
+                    }
+                }
+                return;
+            }
+            Change::Connected(connection) => self.connections.push(*connection),
+            Change::Disconnected(connection) => self.connections.retain(|existing| existing != connection),
+            Change::Selected(ids) => self.selected.clone_from(ids),
+            Change::Deleted(ids) => {
+                self.nodes.retain(|node| !ids.contains(&node.id));
+                self.connections
+                    .retain(|connection| !ids.contains(&connection.from.node) && !ids.contains(&connection.to.node));
+            }
+        }
+
+        self.log = format!("{change:?}");
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        column![
+            text("Node Graph Example").size(25),
+            text("Drag between ports to connect, middle-drag to pan, scroll to zoom, Delete removes the selection"),
+            node_graph(&self.nodes, &self.connections, Message::Graph)
+                .width(Length::Fill)
+                .height(Length::Fill),
+            text(format!("Selected: {:?}", self.selected)),
+            text(format!("Last change: {}", self.log)),
+        ]
+        .spacing(12)
+        .padding(20)
+        .into()
+    }
+}
+
+fn main() -> iced::Result {
+    iced::application(NodeGraphExample::new, NodeGraphExample::update, NodeGraphExample::view)
+        .theme(NodeGraphExample::theme)
+        .title(NodeGraphExample::title)
+        .run()
+}
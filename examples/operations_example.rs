@@ -0,0 +1,136 @@
+use iced::advanced::widget::{operate, operation::focusable};
+use iced::widget::{button, column, row, text};
+use iced::{Element, Length, Task, Theme};
+use widgets::collapsible::{self, collapsible};
+use widgets::color_picker::{self, color_button};
+use widgets::combobox::{self, combobox};
+use widgets::generic_overlay::{self, overlay_button};
+use widgets::tree::{self, branch, tree_handle};
+
+const TREE: &str = "tree";
+const SECTION: &str = "section";
+const OVERLAY: &str = "overlay";
+const PICKER: &str = "picker";
+const SEARCH: &str = "search";
+
+const FRUITS: [&str; 4] = ["Apple", "Banana", "Cherry", "Date"];
+
+#[derive(Debug, Clone)]
+enum Message {
+    ExpandAll,
+    CollapseAll,
+    SelectInvoices,
+    FocusTree,
+    ToggleSection,
+    ToggleOverlay,
+    OpenPicker,
+    OpenSearch,
+    Color(iced::Color),
+    Fruit(String),
+}
+
+struct OperationsExample {
+    color: iced::Color,
+    fruits: Vec<String>,
+    fruit: Option<String>,
+}
+
+impl OperationsExample {
+    fn new() -> (Self, Task<Message>) {
+        (
+            Self {
+                color: iced::Color::from_rgb(0.3, 0.5, 0.8),
+                fruits: FRUITS.map(String::from).to_vec(),
+                fruit: None,
+            },
+            Task::none(),
+        )
+    }
+
+    fn title(&self) -> String {
+        String::from("Operations Example")
+    }
+
+    fn theme(&self) -> Theme {
+        Theme::Dark
+    }
+
+    fn update(&mut self, message: Message) -> Task<Message> {
+        match message {
+            Message::ExpandAll => Task::batch([1, 3].map(|branch| operate(tree::expand(TREE.into(), branch)))),
+            Message::CollapseAll => Task::batch([1, 3].map(|branch| operate(tree::collapse(TREE.into(), branch)))),
+            Message::SelectInvoices => operate(tree::select(TREE.into(), [4, 5])),
+            Message::FocusTree => operate(focusable::focus(TREE.into())),
+            Message::ToggleSection => operate(collapsible::toggle(SECTION.into())),
+            Message::ToggleOverlay => operate(generic_overlay::toggle(OVERLAY.into())),
+            Message::OpenPicker => operate(color_picker::open(PICKER.into())),
+            Message::OpenSearch => operate(combobox::open(SEARCH.into())),
+            Message::Color(color) => {
+                self.color = color;
+                Task::none()
+            }
+            Message::Fruit(fruit) => {
+                self.fruit = Some(fruit);
+                Task::none()
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let tree = tree_handle(vec![
+            branch(text("Documents")).with_id(1).with_children(vec![
+                branch(text("Letters")).with_id(2),
+                branch(text("Invoices")).with_id(3).with_children(vec![
+                    branch(text("2025")).with_id(4),
+                    branch(text("2026")).with_id(5),
+                ]),
+            ]),
+            branch(text("Pictures")).with_id(6),
+        ])
+        .id(TREE)
+        .width(Length::Fill);
+
+        let section = collapsible("Driven from update", text("Opened by a button outside the section")).id(SECTION);
+
+        let details = overlay_button("Overlay", "Overlay", text("Opened and closed by an operation"))
+            .id(OVERLAY)
+            .overlay_width(300.0);
+
+        let picker = color_button(self.color).id(PICKER).on_change(Message::Color);
+
+        let search = combobox(&self.fruits, self.fruit.as_ref(), Message::Fruit)
+            .id(SEARCH)
+            .width(200);
+
+        column![
+            text("Operations Example").size(25),
+            row![
+                button("Expand all").on_press(Message::ExpandAll),
+                button("Collapse all").on_press(Message::CollapseAll),
+                button("Select invoices").on_press(Message::SelectInvoices),
+                button("Focus tree").on_press(Message::FocusTree),
+            ]
+            .spacing(10),
+            row![
+                button("Toggle section").on_press(Message::ToggleSection),
+                button("Toggle overlay").on_press(Message::ToggleOverlay),
+                button("Open picker").on_press(Message::OpenPicker),
+                button("Open search").on_press(Message::OpenSearch),
+            ]
+            .spacing(10),
+            tree,
+            section,
+            row![details, picker, search].spacing(10),
+        ]
+        .spacing(15)
+        .padding(20)
+        .into()
+    }
+}
+
+fn main() -> iced::Result {
+    iced::application(OperationsExample::new, OperationsExample::update, OperationsExample::view)
+        .theme(OperationsExample::theme)
+        .title(OperationsExample::title)
+        .run()
+}
@@ -0,0 +1,125 @@
+use iced::widget::{button, checkbox, column, container, row, text};
+use iced::{Element, Length, Point, Task, Theme};
+use widgets::pinboard::pinboard;
+
+#[derive(Debug, Clone)]
+enum Message {
+    NoteMoved(usize, Point),
+    NoteRaised(usize),
+    AddNote,
+    RemoveNote(usize),
+    SnapToggled(bool),
+}
+
+struct Note {
+    id: usize,
+    text: String,
+    position: Point,
+}
+
+struct PinboardExample {
+    notes: Vec<Note>,
+    next_id: usize,
+    snap: bool,
+    last_raised: Option<usize>,
+}
+
+impl PinboardExample {
+    fn new() -> (Self, Task<Message>) {
+        let notes = ["Buy milk", "Call the plumber", "Finish the report", "Book flights"]
+            .into_iter()
+            .enumerate()
+            .map(|(id, text)| Note {
+                id,
+                text: String::from(text),
+                position: Point::new(40.0 + id as f32 * 180.0, 40.0 + (id % 2) as f32 * 120.0),
+            })
+            .collect::<Vec<_>>();
+
+        (
+            Self {
+                next_id: notes.len(),
+                notes,
+                snap: true,
+                last_raised: None,
+            },
+            Task::none(),
+        )
+    }
+
+    fn title(&self) -> String {
+        String::from("Pinboard Example")
+    }
+
+    fn theme(&self) -> Theme {
+        Theme::Dark
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::NoteMoved(id, position) => {
+                if let Some(note) = self.notes.iter_mut().find(|note| note.id == id) {
+                    note.position = position;
+                }
+            }
+            Message::NoteRaised(id) => self.last_raised = Some(id),
+            Message::AddNote => {
+                self.notes.push(Note {
+                    id: self.next_id,
+                    text: format!("Note {}", self.next_id + 1),
+                    position: Point::new(20.0, 20.0),
+                });
+                self.next_id += 1;
+            }
+            Message::RemoveNote(id) => self.notes.retain(|note| note.id != id),
+            Message::SnapToggled(snap) => self.snap = snap,
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let board = self.notes.iter().fold(
+            pinboard(Message::NoteMoved).on_raise(Message::NoteRaised),
+            |board, note| {
+                let card = container(
+                    column![
+                        text(&note.text),
+                        button(text("Remove").size(12)).on_press(Message::RemoveNote(note.id)),
+                    ]
+                    .spacing(10),
+                )
+                .padding(12)
+                .width(160)
+                .style(container::rounded_box);
+
+                board.push(note.id, note.position, card)
+            },
+        );
+        let board = if self.snap { board.grid(20.0) } else { board };
+
+        let raised = match self.last_raised {
+            Some(id) => format!("Last raised: note {}", id + 1),
+            None => String::from("Press a note to raise it"),
+        };
+
+        column![
+            row![
+                text("Pinboard Example").size(25),
+                button("Add note").on_press(Message::AddNote),
+                checkbox(self.snap).label("Snap to grid").on_toggle(Message::SnapToggled),
+                text(raised),
+            ]
+            .spacing(20),
+            container(board).width(Length::Fill).height(Length::Fill),
+        ]
+        .spacing(20)
+        .padding(20)
+        .into()
+    }
+}
+
+fn main() -> iced::Result {
+    iced::application(PinboardExample::new, PinboardExample::update, PinboardExample::view)
+        .theme(PinboardExample::theme)
+        .title(PinboardExample::title)
+        .run()
+}
@@ -0,0 +1,117 @@
+use iced::time::{self, Duration};
+use iced::widget::{button, checkbox, column, row, scrollable, text};
+use iced::{Color, Element, Length, Subscription, Task, Theme};
+use widgets::color_picker::color_button;
+use widgets::profile::{self, Totals};
+use widgets::tree::{branch, tree_handle};
+
+const FOLDERS: usize = 50;
+const FILES: usize = 40;
+
+#[derive(Debug, Clone)]
+enum Message {
+    Tick,
+    Reset,
+    Virtualize(bool),
+    ColorChanged(Color),
+}
+
+struct ProfileExample {
+    totals: Vec<Totals>,
+    color: Color,
+    virtualized: bool,
+}
+
+impl ProfileExample {
+    fn new() -> (Self, Task<Message>) {
+        (
+            Self {
+                totals: Vec::new(),
+                color: Color::from_rgb(0.2, 0.5, 0.9),
+                virtualized: true,
+            },
+            Task::none(),
+        )
+    }
+
+    fn title(&self) -> String {
+        String::from("Profile Example")
+    }
+
+    fn theme(&self) -> Theme {
+        Theme::Dark
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Tick => self.totals = profile::totals(),
+            Message::Reset => {
+                profile::reset();
+                self.totals.clear();
+            }
+            Message::ColorChanged(color) => self.color = color,
+            Message::Virtualize(virtualized) => {
+                self.virtualized = virtualized;
+                profile::reset();
+            }
+        }
+    }
+
+    fn subscription(&self) -> Subscription<Message> {
+        time::every(Duration::from_millis(500)).map(|_| Message::Tick)
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let mut tree = tree_handle((0..FOLDERS).map(|folder| {
+            let id = folder * (FILES + 1) + 1;
+            branch(text(format!("Folder {folder}")))
+                .with_id(id)
+                .with_children((0..FILES).map(|file| branch(text(format!("File {file}"))).with_id(id + file + 1)).collect())
+        }))
+        .width(Length::Fill);
+
+        if self.virtualized {
+            tree = tree.virtualized();
+        }
+
+        let totals = column(self.totals.iter().map(|totals| {
+            text(format!(
+                "{} {}: {} passes, {:.2} ms average, {:.2} ms max, {} rows, {} quads",
+                totals.widget,
+                totals.phase,
+                totals.count,
+                totals.average().as_secs_f64() * 1000.0,
+                totals.max.as_secs_f64() * 1000.0,
+                totals.rows,
+                totals.quads,
+            ))
+            .size(13)
+            .into()
+        }))
+        .spacing(4);
+
+        column![
+            text("Profile Example").size(25),
+            text(format!("A tree of {} branches and a color picker, timed every pass", FOLDERS * (FILES + 1))),
+            row![
+                button("Reset").on_press(Message::Reset),
+                checkbox(self.virtualized).label("Virtualized").on_toggle(Message::Virtualize),
+                color_button(self.color).on_change(Message::ColorChanged).width(30).height(20),
+            ]
+            .spacing(10),
+            totals,
+            scrollable(tree).height(Length::Fill),
+        ]
+        .spacing(15)
+        .padding(20)
+        .into()
+    }
+}
+
+fn main() -> iced::Result {
+    iced::application(ProfileExample::new, ProfileExample::update, ProfileExample::view)
+        .theme(ProfileExample::theme)
+        .title(ProfileExample::title)
+        .subscription(ProfileExample::subscription)
+        .run()
+}
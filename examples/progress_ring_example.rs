@@ -0,0 +1,72 @@
+use iced::widget::{button, column, row, slider, text};
+use iced::{Alignment, Element, Theme};
+use widgets::progress_ring::{progress_ring, Progress};
+
+#[derive(Debug, Clone)]
+enum Message {
+    Changed(f32),
+    ToggleBusy,
+}
+
+struct ProgressRingExample {
+    value: f32,
+    busy: bool,
+}
+
+impl ProgressRingExample {
+    fn new() -> (Self, iced::Task<Message>) {
+        (Self { value: 0.35, busy: true }, iced::Task::none())
+    }
+
+    fn title(&self) -> String {
+        String::from("Progress Ring Example")
+    }
+
+    fn theme(&self) -> Theme {
+        Theme::Dark
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Changed(value) => self.value = value,
+            Message::ToggleBusy => self.busy = !self.busy,
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let percent = format!("{:.0}%", self.value * 100.0);
+
+        let rings = row![
+            progress_ring(self.value),
+            progress_ring(self.value)
+                .size(72)
+                .thickness(6)
+                .label(text(percent).size(16)),
+            progress_ring(self.value)
+                .size(72)
+                .thickness(10)
+                .style(widgets::progress_ring::success),
+            progress_ring(if self.busy { Progress::Indeterminate } else { Progress::Determinate(1.0) }),
+            progress_ring(Progress::Indeterminate).size(24).thickness(3),
+        ]
+        .spacing(30)
+        .align_y(Alignment::Center);
+
+        column![
+            text("Progress Ring Example").size(25),
+            rings,
+            slider(0.0..=1.0, self.value, Message::Changed).step(0.01).width(300),
+            button(if self.busy { "Finish" } else { "Restart" }).on_press(Message::ToggleBusy),
+        ]
+        .spacing(20)
+        .padding(20)
+        .into()
+    }
+}
+
+fn main() -> iced::Result {
+    iced::application(ProgressRingExample::new, ProgressRingExample::update, ProgressRingExample::view)
+        .theme(ProgressRingExample::theme)
+        .title(ProgressRingExample::title)
+        .run()
+}
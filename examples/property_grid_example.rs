@@ -0,0 +1,97 @@
+use iced::widget::{column, scrollable, text};
+use iced::{Color, Element, Length, Theme};
+use widgets::property_grid::{property_grid, Group, Property, Value};
+
+#[derive(Debug, Clone)]
+enum Message {
+    Changed(String, Value),
+}
+
+struct PropertyGridExample {
+    groups: Vec<Group>,
+    last_change: String,
+}
+
+impl PropertyGridExample {
+    fn new() -> (Self, iced::Task<Message>) {
+        let groups = vec![
+            Group::new(
+                "Object",
+                [
+                    Property::new("name", "Name", Value::Text(String::from("Player"))),
+                    Property::new("visible", "Visible", Value::Bool(true)),
+                    Property::new("layer", "Layer", Value::choice(["Background", "World", "UI"], 1)),
+                    Property::new("uuid", "Id", Value::Text(String::from("7f3a-19c2"))).disabled(true),
+                ],
+            ),
+            Group::new(
+                "Transform",
+                [
+                    Property::new("x", "X", Value::Number(120.0)),
+                    Property::new("y", "Y", Value::Number(48.0)),
+                    Property::new("rotation", "Rotation", Value::Number(0.0))
+                        .range(0.0, 360.0)
+                        .step(15.0),
+                    Property::new("scale", "Scale", Value::Number(1.0)).range(0.1, 10.0).step(0.1),
+                ],
+            ),
+            Group::new(
+                "Appearance",
+                [
+                    Property::new("tint", "Tint", Value::Color(Color::from_rgb(0.9, 0.4, 0.2))),
+                    Property::new("opacity", "Opacity", Value::Number(1.0)).range(0.0, 1.0).step(0.05),
+                ],
+            )
+            .collapsed(true),
+        ];
+
+        (
+            Self {
+                groups,
+                last_change: String::new(),
+            },
+            iced::Task::none(),
+        )
+    }
+
+    fn title(&self) -> String {
+        String::from("Property Grid Example")
+    }
+
+    fn theme(&self) -> Theme {
+        Theme::Dark
+    }
+
+    fn update(&mut self, message: Message) {
+        let Message::Changed(id, value) = message;
+
+        self.last_change = format!("{id} = {value:?}");
+
+        if let Some(property) = self
+            .groups
+            .iter_mut()
+            .flat_map(|group| group.properties.iter_mut())
+            .find(|property| property.id == id)
+        {
+            property.value = value;
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        column![
+            text("Property Grid Example").size(25),
+            scrollable(property_grid(self.groups.clone(), Message::Changed).width(360)).height(Length::Fill),
+            text(format!("Last change: {}", self.last_change)),
+        ]
+        .spacing(12)
+        .padding(20)
+        .into()
+    }
+}
+
+fn main() -> iced::Result {
+    iced::application(PropertyGridExample::new, PropertyGridExample::update, PropertyGridExample::view)
+        .theme(PropertyGridExample::theme)
+        .title(PropertyGridExample::title)
+        .run()
+}
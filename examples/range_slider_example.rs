@@ -0,0 +1,72 @@
+use iced::widget::{column, text};
+use iced::{Element, Theme};
+use widgets::range_slider::{self, range_slider};
+
+#[derive(Debug, Clone)]
+enum Message {
+    Price((f32, f32)),
+    Hours((f32, f32)),
+    Released,
+}
+
+struct RangeSliderExample {
+    price: (f32, f32),
+    hours: (f32, f32),
+    releases: usize,
+}
+
+impl RangeSliderExample {
+    fn new() -> (Self, iced::Task<Message>) {
+        (
+            Self {
+                price: (20.0, 80.0),
+                hours: (9.0, 17.0),
+                releases: 0,
+            },
+            iced::Task::none(),
+        )
+    }
+
+    fn title(&self) -> String {
+        String::from("Range Slider Example")
+    }
+
+    fn theme(&self) -> Theme {
+        Theme::Dark
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Price(price) => self.price = price,
+            Message::Hours(hours) => self.hours = hours,
+            Message::Released => self.releases += 1,
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        column![
+            text("Range Slider Example").size(25),
+            text(format!("Price: ${:.0} to ${:.0}", self.price.0, self.price.1)),
+            range_slider(0.0..=200.0, self.price, Message::Price)
+                .step(5.0)
+                .on_release(Message::Released),
+            text(format!("Opening hours: {:02.0}:00 to {:02.0}:00", self.hours.0, self.hours.1)),
+            range_slider(0.0..=24.0, self.hours, Message::Hours)
+                .ticks_every(1.0)
+                .snap_to_ticks(true)
+                .style(range_slider::outlined),
+            text(format!("Price slider released {} times", self.releases)),
+            text("Click a thumb, then use the arrow keys (Shift for bigger steps) and Tab."),
+        ]
+        .spacing(16)
+        .padding(20)
+        .into()
+    }
+}
+
+fn main() -> iced::Result {
+    iced::application(RangeSliderExample::new, RangeSliderExample::update, RangeSliderExample::view)
+        .theme(RangeSliderExample::theme)
+        .title(RangeSliderExample::title)
+        .run()
+}
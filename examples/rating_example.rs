@@ -0,0 +1,63 @@
+use iced::widget::{column, row, text};
+use iced::{Element, Theme};
+use widgets::rating::{self, rating};
+
+#[derive(Debug, Clone)]
+enum Message {
+    Stars(f32),
+    Hearts(f32),
+}
+
+struct RatingExample {
+    stars: f32,
+    hearts: f32,
+}
+
+impl RatingExample {
+    fn new() -> (Self, iced::Task<Message>) {
+        (Self { stars: 3.5, hearts: 2.0 }, iced::Task::none())
+    }
+
+    fn title(&self) -> String {
+        String::from("Rating Example")
+    }
+
+    fn theme(&self) -> Theme {
+        Theme::Dark
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Stars(stars) => self.stars = stars,
+            Message::Hearts(hearts) => self.hearts = hearts,
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        column![
+            text("Rating Example").size(25),
+            text(format!("Half stars: {}", self.stars)),
+            rating(self.stars).half_steps(true).size(32).on_change(Message::Stars),
+            text(format!("Hearts out of 10, click again to clear: {}", self.hearts)),
+            rating(self.hearts)
+                .count(10)
+                .icon("♥")
+                .allow_clear(true)
+                .style(rating::primary)
+                .on_change(Message::Hearts),
+            text("Read-only average"),
+            row![rating(4.2).size(16), text("4.2 from 1,024 reviews").size(14)].spacing(8),
+            text("Click a rating, then use the arrow keys, Home, End or digits."),
+        ]
+        .spacing(12)
+        .padding(20)
+        .into()
+    }
+}
+
+fn main() -> iced::Result {
+    iced::application(RatingExample::new, RatingExample::update, RatingExample::view)
+        .theme(RatingExample::theme)
+        .title(RatingExample::title)
+        .run()
+}
@@ -0,0 +1,117 @@
+use iced::keyboard::{self, Modifiers};
+use iced::widget::{column, row, text};
+use iced::{Element, Task, Theme};
+use widgets::shortcut_input::{shortcut_input, Shortcut};
+
+#[derive(Debug, Clone)]
+enum Message {
+    Recorded(usize, Shortcut),
+    Cleared(usize),
+}
+
+struct Binding {
+    action: &'static str,
+    shortcut: Option<Shortcut>,
+}
+
+struct ShortcutInputExample {
+    bindings: Vec<Binding>,
+}
+
+impl ShortcutInputExample {
+    fn new() -> (Self, Task<Message>) {
+        let key = |c: &str| keyboard::Key::Character(c.into());
+
+        (
+            Self {
+                bindings: vec![
+                    Binding {
+                        action: "Save",
+                        shortcut: Some(Shortcut::new(Modifiers::CTRL, key("s"))),
+                    },
+                    Binding {
+                        action: "Open",
+                        shortcut: Some(Shortcut::new(Modifiers::CTRL, key("o"))),
+                    },
+                    Binding {
+                        action: "Find",
+                        shortcut: Some(Shortcut::new(Modifiers::CTRL, key("f"))),
+                    },
+                    Binding {
+                        action: "Toggle sidebar",
+                        shortcut: None,
+                    },
+                ],
+            },
+            Task::none(),
+        )
+    }
+
+    fn title(&self) -> String {
+        String::from("Shortcut Input Example")
+    }
+
+    fn theme(&self) -> Theme {
+        Theme::Dark
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Recorded(index, shortcut) => self.bindings[index].shortcut = Some(shortcut),
+            Message::Cleared(index) => self.bindings[index].shortcut = None,
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let bindings = self
+            .bindings
+            .iter()
+            .enumerate()
+            .fold(column![].spacing(10), |bindings, (index, binding)| {
+                // Every other action's shortcut is taken
+                let conflicts = self
+                    .bindings
+                    .iter()
+                    .enumerate()
+                    .filter(|(other, _)| *other != index)
+                    .filter_map(|(_, other)| other.shortcut.clone().map(|shortcut| (shortcut, other.action)));
+
+                bindings.push(
+                    row![
+                        text(binding.action).width(160),
+                        shortcut_input(binding.shortcut.as_ref(), move |shortcut| Message::Recorded(index, shortcut))
+                            .conflicts(conflicts)
+                            .on_clear(Message::Cleared(index)),
+                    ]
+                    .spacing(20),
+                )
+            });
+
+        let summary = self
+            .bindings
+            .iter()
+            .map(|binding| match &binding.shortcut {
+                Some(shortcut) => format!("{}: {shortcut}", binding.action),
+                None => format!("{}: unbound", binding.action),
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        column![
+            text("Shortcut Input Example").size(25),
+            text("Click a field and press a key combination. Escape cancels, Backspace clears."),
+            bindings,
+            text(summary),
+        ]
+        .spacing(20)
+        .padding(20)
+        .into()
+    }
+}
+
+fn main() -> iced::Result {
+    iced::application(ShortcutInputExample::new, ShortcutInputExample::update, ShortcutInputExample::view)
+        .theme(ShortcutInputExample::theme)
+        .title(ShortcutInputExample::title)
+        .run()
+}
@@ -0,0 +1,106 @@
+use iced::widget::{button, column, container, row, text, Space};
+use iced::{Element, Length, Theme};
+use widgets::sidebar::{sidebar, Side};
+
+const PAGES: [(&str, &str); 4] = [("H", "Home"), ("S", "Search"), ("L", "Library"), ("P", "Preferences")];
+
+#[derive(Debug, Clone)]
+enum Message {
+    Resized(f32),
+    Toggled(bool),
+    Toggle,
+    Open(usize),
+}
+
+struct SidebarExample {
+    width: f32,
+    expanded: bool,
+    page: usize,
+}
+
+impl SidebarExample {
+    fn new() -> (Self, iced::Task<Message>) {
+        (
+            Self {
+                width: 220.0,
+                expanded: true,
+                page: 0,
+            },
+            iced::Task::none(),
+        )
+    }
+
+    fn title(&self) -> String {
+        String::from("Sidebar Example")
+    }
+
+    fn theme(&self) -> Theme {
+        Theme::Dark
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Resized(width) => self.width = width,
+            Message::Toggled(expanded) => self.expanded = expanded,
+            Message::Toggle => self.expanded = !self.expanded,
+            Message::Open(page) => self.page = page,
+        }
+    }
+
+    fn nav_button(&self, page: usize, label: &'static str) -> Element<'_, Message> {
+        let style = if page == self.page { button::primary } else { button::text };
+
+        button(text(label)).width(Length::Fill).style(style).on_press(Message::Open(page)).into()
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let panel = PAGES
+            .iter()
+            .enumerate()
+            .fold(column![text("Navigation").size(14)].spacing(4).padding(10), |panel, (page, (_, name))| {
+                panel.push(self.nav_button(page, name))
+            });
+
+        let rail = PAGES
+            .iter()
+            .enumerate()
+            .fold(column![].spacing(4).padding(6), |rail, (page, (icon, _))| {
+                rail.push(self.nav_button(page, icon))
+            });
+
+        let content = column![
+            row![
+                button(text(if self.expanded { "Collapse" } else { "Expand" })).on_press(Message::Toggle),
+                Space::new().width(Length::Fill),
+                text(format!("Panel width {:.0}", self.width)),
+            ]
+            .spacing(10),
+            text(PAGES[self.page].1).size(25),
+            text("Drag the handle to resize the panel, drag it to the edge or double-click it to collapse it to the rail"),
+        ]
+        .spacing(10)
+        .padding(20);
+
+        let left = sidebar(panel, content)
+            .rail(rail)
+            .panel_width(self.width)
+            .expanded(self.expanded)
+            .on_resize(Message::Resized)
+            .on_toggle(Message::Toggled);
+
+        // A second, unmanaged sidebar on the right keeps its own width and state
+        let right = sidebar(container(text("Details")).padding(10), left)
+            .side(Side::Right)
+            .min_width(120.0)
+            .style(widgets::sidebar::plain);
+
+        container(right).width(Length::Fill).height(Length::Fill).into()
+    }
+}
+
+fn main() -> iced::Result {
+    iced::application(SidebarExample::new, SidebarExample::update, SidebarExample::view)
+        .theme(SidebarExample::theme)
+        .title(SidebarExample::title)
+        .run()
+}
@@ -0,0 +1,115 @@
+use iced::widget::{button, column, container, row, text};
+use iced::{Element, Length, Task, Theme};
+use widgets::skeleton::{self, circle, line, loading, paragraph, rectangle};
+
+#[derive(Debug, Clone)]
+enum Message {
+    Reload,
+    Finish,
+}
+
+#[derive(Debug, Clone)]
+struct Post {
+    author: String,
+    title: String,
+    body: String,
+}
+
+struct SkeletonExample {
+    posts: Option<Vec<Post>>,
+}
+
+impl SkeletonExample {
+    fn new() -> (Self, Task<Message>) {
+        (Self { posts: None }, Task::none())
+    }
+
+    fn title(&self) -> String {
+        String::from("Skeleton Example")
+    }
+
+    fn theme(&self) -> Theme {
+        Theme::Dark
+    }
+
+    fn posts() -> Vec<Post> {
+        (1..=3)
+            .map(|index| Post {
+                author: format!("Author {index}"),
+                title: format!("Post number {index}"),
+                body: String::from("Skeletons stand in for content while it loads, then the real thing takes their place."),
+            })
+            .collect()
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Reload => self.posts = None,
+            Message::Finish => self.posts = Some(Self::posts()),
+        }
+    }
+
+    fn card(content: Element<'_, Message>) -> Element<'_, Message> {
+        container(content)
+            .padding(16)
+            .width(Length::Fill)
+            .style(container::rounded_box)
+            .into()
+    }
+
+    fn placeholder() -> Element<'static, Message> {
+        (0..3)
+            .fold(column![].spacing(12), |cards, _| {
+                cards.push(Self::card(
+                    row![
+                        circle(40),
+                        column![line().width(160), paragraph(3), rectangle(Length::Fill, 120)].spacing(10),
+                    ]
+                    .spacing(12)
+                    .into(),
+                ))
+            })
+            .into()
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let posts = loading(self.posts.is_none(), Self::placeholder(), || {
+            self.posts
+                .iter()
+                .flatten()
+                .fold(column![].spacing(12), |cards, post| {
+                    cards.push(Self::card(
+                        column![
+                            text(&post.author).size(12),
+                            text(&post.title).size(18),
+                            text(&post.body),
+                        ]
+                        .spacing(6)
+                        .into(),
+                    ))
+                })
+                .into()
+        });
+
+        column![
+            row![
+                text("Skeleton Example").size(25),
+                button("Finish loading").on_press_maybe(self.posts.is_none().then_some(Message::Finish)),
+                button("Reload").on_press(Message::Reload),
+            ]
+            .spacing(20),
+            posts,
+            row![line().width(200).animated(false), line().width(200).style(skeleton::subtle)].spacing(20),
+        ]
+        .spacing(20)
+        .padding(20)
+        .into()
+    }
+}
+
+fn main() -> iced::Result {
+    iced::application(SkeletonExample::new, SkeletonExample::update, SkeletonExample::view)
+        .theme(SkeletonExample::theme)
+        .title(SkeletonExample::title)
+        .run()
+}
@@ -0,0 +1,83 @@
+use iced::widget::{column, row, text};
+use iced::{Element, Task, Theme};
+use widgets::menu::Item;
+use widgets::split_button::{secondary, split_button};
+
+#[derive(Debug, Clone)]
+enum Message {
+    Save,
+    SaveAs,
+    SaveCopy,
+    SaveAll,
+    Merge,
+    Squash,
+    Rebase,
+}
+
+struct SplitButtonExample {
+    log: Vec<String>,
+}
+
+impl SplitButtonExample {
+    fn new() -> (Self, Task<Message>) {
+        (Self { log: Vec::new() }, Task::none())
+    }
+
+    fn title(&self) -> String {
+        String::from("Split Button Example")
+    }
+
+    fn theme(&self) -> Theme {
+        Theme::Dark
+    }
+
+    fn update(&mut self, message: Message) {
+        self.log.push(format!("{message:?}"));
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let save = split_button(
+            text("Save"),
+            [
+                Item::new("Save as…", Message::SaveAs).shortcut("Ctrl+Shift+S"),
+                Item::new("Save a copy", Message::SaveCopy),
+                Item::separator(),
+                Item::new("Save all", Message::SaveAll),
+            ],
+        )
+        .on_press(Message::Save);
+
+        let merge = split_button(
+            text("Merge"),
+            [
+                Item::new("Squash and merge", Message::Squash),
+                Item::new("Rebase and merge", Message::Rebase),
+            ],
+        )
+        .on_press(Message::Merge)
+        .style(secondary);
+
+        let disabled = split_button(text("Publish"), [Item::new("Schedule", Message::Save).disabled(true)]);
+
+        column![
+            text("Split Button Example").size(25),
+            text("Click a main half for its action, or an arrow for the alternatives"),
+            row![save, merge, disabled].spacing(20),
+            text(if self.log.is_empty() {
+                String::from("Nothing clicked yet")
+            } else {
+                self.log.join(", ")
+            }),
+        ]
+        .spacing(20)
+        .padding(20)
+        .into()
+    }
+}
+
+fn main() -> iced::Result {
+    iced::application(SplitButtonExample::new, SplitButtonExample::update, SplitButtonExample::view)
+        .theme(SplitButtonExample::theme)
+        .title(SplitButtonExample::title)
+        .run()
+}
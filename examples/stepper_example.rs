@@ -0,0 +1,117 @@
+use iced::widget::{checkbox, column, text, text_input};
+use iced::{Element, Theme};
+use widgets::stepper::{stepper, Step};
+
+#[derive(Debug, Clone)]
+enum Message {
+    Step(usize),
+    Name(String),
+    Email(String),
+    Newsletter(bool),
+    Terms(bool),
+    Finish,
+}
+
+#[derive(Default)]
+struct StepperExample {
+    step: usize,
+    name: String,
+    email: String,
+    newsletter: bool,
+    terms: bool,
+    finished: bool,
+}
+
+impl StepperExample {
+    fn new() -> (Self, iced::Task<Message>) {
+        (Self::default(), iced::Task::none())
+    }
+
+    fn title(&self) -> String {
+        String::from("Stepper Example")
+    }
+
+    fn theme(&self) -> Theme {
+        Theme::Dark
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Step(step) => self.step = step,
+            Message::Name(name) => self.name = name,
+            Message::Email(email) => self.email = email,
+            Message::Newsletter(newsletter) => self.newsletter = newsletter,
+            Message::Terms(terms) => self.terms = terms,
+            Message::Finish => self.finished = true,
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        if self.finished {
+            return column![
+                text("Stepper Example").size(25),
+                text(format!("Welcome aboard, {}!", self.name)),
+            ]
+            .spacing(10)
+            .padding(20)
+            .into();
+        }
+
+        let name = self.name.trim().to_string();
+        let email_valid = self.email.contains('@');
+        let terms = self.terms;
+
+        let wizard = stepper(self.step, Message::Step)
+            .push(
+                Step::new(
+                    "Account",
+                    column![
+                        text("Your name"),
+                        text_input("Jane Doe", &self.name).on_input(Message::Name),
+                        text("Email"),
+                        text_input("jane@example.com", &self.email).on_input(Message::Email),
+                    ]
+                    .spacing(6),
+                )
+                .description("Who you are"),
+            )
+            .push(
+                Step::new(
+                    "Preferences",
+                    checkbox(self.newsletter).label("Send me the monthly newsletter").on_toggle(Message::Newsletter),
+                )
+                .description("Optional"),
+            )
+            .push(Step::new("Billing", text("Free accounts skip billing")).disabled(true))
+            .push(
+                Step::new(
+                    "Confirm",
+                    column![
+                        text(format!("Name: {name}")),
+                        text(format!("Email: {}", self.email)),
+                        checkbox(self.terms).label("I accept the terms").on_toggle(Message::Terms),
+                    ]
+                    .spacing(6),
+                )
+                .description("Review and accept"),
+            )
+            .can_advance(move |step| match step {
+                0 => !name.is_empty() && email_valid,
+                3 => terms,
+                _ => true,
+            })
+            .on_finish(Message::Finish);
+
+        column![text("Stepper Example").size(25), wizard]
+            .spacing(10)
+            .padding(20)
+            .into()
+    }
+}
+
+fn main() -> iced::Result {
+    iced::application(StepperExample::new, StepperExample::update, StepperExample::view)
+        .theme(StepperExample::theme)
+        .title(StepperExample::title)
+        .run()
+}
@@ -0,0 +1,130 @@
+use iced::widget::{button, column, text};
+use iced::{Element, Length, Theme};
+use std::collections::HashSet;
+use widgets::table::{self, table, Column, Row, SelectionMode, SortOrder};
+
+#[derive(Debug, Clone)]
+enum Message {
+    Sort(usize, SortOrder),
+    Select(HashSet<usize>),
+    Resized(usize, f32),
+    Remove(usize),
+}
+
+struct Planet {
+    id: usize,
+    name: &'static str,
+    moons: u32,
+    radius_km: u32,
+}
+
+struct TableExample {
+    planets: Vec<Planet>,
+    sort: (usize, SortOrder),
+    selected: HashSet<usize>,
+}
+
+impl TableExample {
+    fn new() -> (Self, iced::Task<Message>) {
+        let planets = [
+            ("Mercury", 0, 2_440),
+            ("Venus", 0, 6_052),
+            ("Earth", 1, 6_371),
+            ("Mars", 2, 3_390),
+            ("Jupiter", 95, 69_911),
+            ("Saturn", 146, 58_232),
+            ("Uranus", 28, 25_362),
+            ("Neptune", 16, 24_622),
+        ]
+        .into_iter()
+        .enumerate()
+        .map(|(id, (name, moons, radius_km))| Planet { id, name, moons, radius_km })
+        .collect();
+
+        (
+            Self {
+                planets,
+                sort: (0, SortOrder::Ascending),
+                selected: HashSet::new(),
+            },
+            iced::Task::none(),
+        )
+    }
+
+    fn title(&self) -> String {
+        String::from("Table Example")
+    }
+
+    fn theme(&self) -> Theme {
+        Theme::Dark
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Sort(column, order) => {
+                self.sort = (column, order);
+                self.planets.sort_by(|a, b| {
+                    let ordering = match column {
+                        0 => a.name.cmp(b.name),
+                        1 => a.moons.cmp(&b.moons),
+                        _ => a.radius_km.cmp(&b.radius_km),
+                    };
+                    match order {
+                        SortOrder::Ascending => ordering,
+                        SortOrder::Descending => ordering.reverse(),
+                    }
+                });
+            }
+            Message::Select(selected) => self.selected = selected,
+            Message::Resized(column, width) => println!("Column {column} resized to {width}"),
+            Message::Remove(id) => {
+                self.planets.retain(|planet| planet.id != id);
+                self.selected.remove(&id);
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let rows = self.planets.iter().map(|planet| {
+            Row::new([
+                text(planet.name).into(),
+                text(planet.moons).into(),
+                text(planet.radius_km).into(),
+                button("Remove").on_press(Message::Remove(planet.id)).into(),
+            ])
+            .with_id(planet.id)
+        });
+
+        column![
+            text("Table Example").size(25),
+            text(format!("{} planet(s) selected (Ctrl/Shift-click for more)", self.selected.len())),
+            table(
+                [
+                    Column::new("Planet").width(160.0),
+                    Column::new("Moons").align_x(iced::Alignment::End),
+                    Column::new("Radius (km)").width(140.0).align_x(iced::Alignment::End),
+                    Column::new("").width(110.0).sortable(false),
+                ],
+                rows,
+            )
+            .height(Length::Fixed(240.0))
+            .sort(self.sort.0, self.sort.1)
+            .selection_mode(SelectionMode::Multiple)
+            .selected(self.selected.iter().copied())
+            .on_sort(Message::Sort)
+            .on_select(Message::Select)
+            .on_resize(Message::Resized)
+            .style(table::striped),
+        ]
+        .spacing(10)
+        .padding(20)
+        .into()
+    }
+}
+
+fn main() -> iced::Result {
+    iced::application(TableExample::new, TableExample::update, TableExample::view)
+        .theme(TableExample::theme)
+        .title(TableExample::title)
+        .run()
+}
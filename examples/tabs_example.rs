@@ -0,0 +1,104 @@
+use iced::widget::{button, column, container, text};
+use iced::{Element, Length, Theme};
+use widgets::tabs::{self, tab_bar, Tab};
+
+#[derive(Debug, Clone)]
+enum Message {
+    Select(usize),
+    Close(usize),
+    Reorder(Vec<usize>),
+    Open,
+}
+
+struct Document {
+    name: String,
+    pinned: bool,
+}
+
+struct TabsExample {
+    documents: Vec<Document>,
+    active: usize,
+    opened: usize,
+}
+
+impl TabsExample {
+    fn new() -> (Self, iced::Task<Message>) {
+        let documents = ["main.rs", "lib.rs", "Cargo.toml", "README.md"]
+            .into_iter()
+            .enumerate()
+            .map(|(i, name)| Document { name: name.to_string(), pinned: i == 0 })
+            .collect();
+
+        (Self { documents, active: 0, opened: 0 }, iced::Task::none())
+    }
+
+    fn title(&self) -> String {
+        String::from("Tabs Example")
+    }
+
+    fn theme(&self) -> Theme {
+        Theme::Dark
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Select(index) => self.active = index,
+            Message::Close(index) => {
+                self.documents.remove(index);
+                if self.active > index || self.active >= self.documents.len() {
+                    self.active = self.active.saturating_sub(1);
+                }
+            }
+            Message::Reorder(order) => {
+                let active = order.iter().position(|&old| old == self.active).unwrap_or(0);
+                let mut documents: Vec<Option<Document>> = self.documents.drain(..).map(Some).collect();
+                self.documents = order.iter().filter_map(|&old| documents[old].take()).collect();
+                self.active = active;
+            }
+            Message::Open => {
+                self.opened += 1;
+                self.documents.push(Document { name: format!("untitled-{}.rs", self.opened), pinned: false });
+                self.active = self.documents.len() - 1;
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let bar = tab_bar(
+            self.documents
+                .iter()
+                .map(|document| Tab::new(&document.name).closable(!document.pinned)),
+        )
+        .active(self.active)
+        .on_select(Message::Select)
+        .on_close(Message::Close)
+        .on_reorder(Message::Reorder);
+
+        let content = match self.documents.get(self.active) {
+            Some(document) => text(format!("Editing {}", document.name)),
+            None => text("No open documents"),
+        };
+
+        column![
+            text("Tabs Example").size(25),
+            button("Open another file").on_press(Message::Open),
+            bar,
+            container(content).padding(20).width(Length::Fill).height(Length::Fill),
+            text("Pill style"),
+            tab_bar(["Day", "Week", "Month"].map(|label| Tab::new(label).closable(false)))
+                .active(1)
+                .width(Length::Shrink)
+                .style(tabs::pills),
+        ]
+        .spacing(10)
+        .padding(20)
+        .into()
+    }
+}
+
+fn main() -> iced::Result {
+    iced::application(TabsExample::new, TabsExample::update, TabsExample::view)
+        .theme(TabsExample::theme)
+        .title(TabsExample::title)
+        .run()
+}
@@ -0,0 +1,70 @@
+use iced::widget::{column, text};
+use iced::{Element, Theme};
+use widgets::tag_input::{self, tag_input};
+
+#[derive(Debug, Clone)]
+enum Message {
+    Labels(Vec<String>),
+    Recipients(Vec<String>),
+}
+
+struct TagInputExample {
+    labels: Vec<String>,
+    recipients: Vec<String>,
+}
+
+impl TagInputExample {
+    fn new() -> (Self, iced::Task<Message>) {
+        (
+            Self {
+                labels: vec![String::from("bug"), String::from("ui")],
+                recipients: Vec::new(),
+            },
+            iced::Task::none(),
+        )
+    }
+
+    fn title(&self) -> String {
+        String::from("Tag Input Example")
+    }
+
+    fn theme(&self) -> Theme {
+        Theme::Dark
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Labels(labels) => self.labels = labels,
+            Message::Recipients(recipients) => self.recipients = recipients,
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        column![
+            text("Tag Input Example").size(25),
+            text("Labels"),
+            tag_input(&self.labels, Message::Labels)
+                .placeholder("Add a label")
+                .suggestions([
+                    "bug", "feature", "docs", "ui", "performance", "regression", "good first issue",
+                    "help wanted", "wontfix",
+                ]),
+            text(format!("{} labels: {}", self.labels.len(), self.labels.join(", "))),
+            text("Recipients (at most 3, try pasting a comma separated list)"),
+            tag_input(&self.recipients, Message::Recipients)
+                .placeholder("name@example.com")
+                .max_tags(3)
+                .style(tag_input::pills),
+        ]
+        .spacing(10)
+        .padding(20)
+        .into()
+    }
+}
+
+fn main() -> iced::Result {
+    iced::application(TagInputExample::new, TagInputExample::update, TagInputExample::view)
+        .theme(TagInputExample::theme)
+        .title(TagInputExample::title)
+        .run()
+}
@@ -0,0 +1,48 @@
+//! Drives a rating off-screen and checks the messages it produces, the way a test would.
+
+use iced::keyboard::key::Named;
+use iced::{Point, Size};
+use widgets::rating::rating;
+use widgets::testing::{Cache, Simulator};
+
+#[derive(Debug, Clone, PartialEq)]
+enum Message {
+    Rated(f32),
+}
+
+/// Mounts a rating of `value`, runs `interact` on it and applies the messages it produced.
+fn step(value: &mut f32, cache: Cache, interact: impl FnOnce(&mut Simulator<'_, Message>)) -> Cache {
+    let mut ui = Simulator::with_cache(
+        rating(*value).on_change(Message::Rated),
+        Size::new(200.0, 40.0),
+        (),
+        cache,
+    );
+
+    interact(&mut ui);
+
+    for Message::Rated(rated) in ui.take_messages() {
+        *value = rated;
+    }
+
+    ui.into_cache()
+}
+
+fn main() {
+    let mut value = 2.0;
+    let mut cache = Cache::default();
+
+    // Stars are 24 wide with 4 between them, so the fourth one spans 84 to 108
+    cache = step(&mut value, cache, |ui| ui.click(Point::new(100.0, 12.0)));
+    println!("Clicking the fourth star: {value}");
+    assert_eq!(value, 4.0);
+
+    // The click focused the rating, so the arrow keys now change it
+    cache = step(&mut value, cache, |ui| ui.tap(Named::ArrowRight));
+    println!("Pressing the right arrow: {value}");
+    assert_eq!(value, 5.0);
+
+    step(&mut value, cache, |ui| ui.tap(Named::ArrowLeft));
+    println!("Pressing the left arrow: {value}");
+    assert_eq!(value, 4.0);
+}
@@ -0,0 +1,139 @@
+use iced::widget::{column, pick_list, row, text};
+use iced::{Element, Length, Task, Theme};
+use widgets::collapsible::{self, collapsible};
+use widgets::date_range_picker::{self, date_range_picker, DateRange};
+use widgets::generic_overlay::{self, overlay_button};
+use widgets::theme::Preset;
+use widgets::tree::{self, branch, tree_handle};
+
+#[derive(Debug, Clone)]
+enum Message {
+    Preset(Preset),
+    DarkMode(bool),
+    Period(DateRange),
+}
+
+struct ThemeExample {
+    preset: Preset,
+    dark: bool,
+    period: Option<DateRange>,
+}
+
+impl ThemeExample {
+    fn new() -> (Self, Task<Message>) {
+        (
+            Self {
+                preset: Preset::Standard,
+                dark: true,
+                period: None,
+            },
+            Task::none(),
+        )
+    }
+
+    fn title(&self) -> String {
+        String::from("Theme Example")
+    }
+
+    fn theme(&self) -> Theme {
+        if self.dark { Theme::Dark } else { Theme::Light }
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Preset(preset) => self.preset = preset,
+            Message::DarkMode(dark) => self.dark = dark,
+            Message::Period(period) => self.period = Some(period),
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let tree = tree_handle(vec![
+            branch(text("Projects")).with_id(1).with_children(vec![
+                branch(text("Website")).with_id(2),
+                branch(text("Mobile app")).with_id(3),
+            ]),
+            branch(text("Archive")).with_id(4),
+        ])
+        .style(tree_style(self.preset))
+        .width(Length::Fill);
+
+        let section = collapsible(
+            "Settings",
+            column![text("Every widget here follows the preset above"), text("Switch presets to compare them")]
+                .spacing(5),
+        )
+        .style(collapsible_style(self.preset));
+
+        let details = overlay_button("Open overlay", "Overlay", text("Styled with the same palette"))
+            .overlay_style(overlay_style(self.preset))
+            .overlay_width(300.0);
+
+        column![
+            text("Theme Example").size(25),
+            row![
+                pick_list(Preset::ALL, Some(self.preset), Message::Preset),
+                pick_list([true, false].map(DarkMode), Some(DarkMode(self.dark)), |mode| Message::DarkMode(mode.0)),
+            ]
+            .spacing(10),
+            tree,
+            section,
+            row![details, date_range_picker(self.period, Message::Period).style(picker_style(self.preset))].spacing(10),
+        ]
+        .spacing(15)
+        .padding(20)
+        .into()
+    }
+}
+
+fn tree_style(preset: Preset) -> fn(&Theme) -> tree::Style {
+    match preset {
+        Preset::Standard => tree::default,
+        Preset::Compact => tree::compact,
+        Preset::Flat => tree::flat,
+        Preset::HighContrast => tree::high_contrast,
+    }
+}
+
+fn collapsible_style(preset: Preset) -> fn(&Theme, collapsible::Status) -> collapsible::Style {
+    match preset {
+        Preset::Standard => collapsible::default,
+        Preset::Compact => collapsible::compact,
+        Preset::Flat => collapsible::flat,
+        Preset::HighContrast => collapsible::high_contrast,
+    }
+}
+
+fn overlay_style(preset: Preset) -> fn(&Theme) -> generic_overlay::Style {
+    match preset {
+        Preset::Standard => generic_overlay::default,
+        Preset::Compact => generic_overlay::compact,
+        Preset::Flat => generic_overlay::flat,
+        Preset::HighContrast => generic_overlay::high_contrast,
+    }
+}
+
+fn picker_style(preset: Preset) -> fn(&Theme, date_range_picker::Status) -> date_range_picker::Style {
+    match preset {
+        Preset::Standard => date_range_picker::default,
+        Preset::Compact => date_range_picker::compact,
+        Preset::Flat => date_range_picker::flat,
+        Preset::HighContrast => date_range_picker::high_contrast,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct DarkMode(bool);
+
+impl std::fmt::Display for DarkMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(if self.0 { "Dark" } else { "Light" })
+    }
+}
+
+fn main() -> iced::Result {
+    iced::application(ThemeExample::new, ThemeExample::update, ThemeExample::view)
+        .theme(ThemeExample::theme)
+        .title(ThemeExample::title)
+        .run()
+}
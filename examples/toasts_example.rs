@@ -0,0 +1,92 @@
+use iced::widget::{button, column, row, text};
+use iced::{Element, Theme};
+use widgets::toasts::{self, toast_layer, Corner, Id, Toasts};
+
+#[derive(Debug, Clone)]
+enum Message {
+    Info,
+    Saved,
+    Warn,
+    Fail,
+    Dismiss(Id),
+    Action(Id, usize),
+}
+
+struct ToastsExample {
+    toasts: Toasts,
+    retries: usize,
+}
+
+impl ToastsExample {
+    fn new() -> (Self, iced::Task<Message>) {
+        (Self { toasts: Toasts::new().max_visible(4), retries: 0 }, iced::Task::none())
+    }
+
+    fn title(&self) -> String {
+        String::from("Toasts Example")
+    }
+
+    fn theme(&self) -> Theme {
+        Theme::Dark
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Info => {
+                self.toasts.push(toasts::info("Heads up").body("Hover a toast to pause its timer."));
+            }
+            Message::Saved => {
+                self.toasts.push(toasts::success("Saved").body("All changes were written to disk.").action("Undo"));
+            }
+            Message::Warn => {
+                self.toasts.push(toasts::warning("Low disk space"));
+            }
+            Message::Fail => {
+                self.toasts.push(
+                    toasts::error("Upload failed")
+                        .body("The server closed the connection. Errors stay until closed.")
+                        .action("Retry")
+                        .action("Details"),
+                );
+            }
+            Message::Dismiss(id) => {
+                self.toasts.dismiss(id);
+            }
+            Message::Action(id, index) => {
+                if self.toasts.get(id).is_some_and(|toast| toast.title() == "Upload failed") && index == 0 {
+                    self.retries += 1;
+                }
+                self.toasts.dismiss(id);
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let content = column![
+            text("Toasts Example").size(25),
+            text(format!("Retried {} time(s)", self.retries)),
+            row![
+                button("Info").on_press(Message::Info),
+                button("Success").on_press(Message::Saved),
+                button("Warning").on_press(Message::Warn),
+                button("Error").on_press(Message::Fail),
+            ]
+            .spacing(10),
+        ]
+        .spacing(10)
+        .padding(20);
+
+        toast_layer(content, &self.toasts)
+            .corner(Corner::BottomRight)
+            .on_dismiss(Message::Dismiss)
+            .on_action(Message::Action)
+            .into()
+    }
+}
+
+fn main() -> iced::Result {
+    iced::application(ToastsExample::new, ToastsExample::update, ToastsExample::view)
+        .theme(ToastsExample::theme)
+        .title(ToastsExample::title)
+        .run()
+}
@@ -0,0 +1,100 @@
+use iced::widget::{self, button, column, container, row, scrollable, text, text_input};
+use iced::{Element, Length, Theme};
+use widgets::tour::{tour, Step};
+
+#[derive(Debug, Clone)]
+enum Message {
+    Start,
+    Next,
+    Back,
+    Skip,
+    Search(String),
+}
+
+struct TourExample {
+    step: Option<usize>,
+    query: String,
+}
+
+impl TourExample {
+    fn new() -> (Self, iced::Task<Message>) {
+        (
+            Self {
+                step: Some(0),
+                query: String::new(),
+            },
+            iced::Task::none(),
+        )
+    }
+
+    fn title(&self) -> String {
+        String::from("Tour Example")
+    }
+
+    fn theme(&self) -> Theme {
+        Theme::Dark
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Start => self.step = Some(0),
+            // Past the last step the tour hides itself
+            Message::Next => self.step = self.step.map(|step| step + 1),
+            Message::Back => self.step = self.step.map(|step| step.saturating_sub(1)),
+            Message::Skip => self.step = None,
+            Message::Search(query) => self.query = query,
+        }
+    }
+
+    fn steps() -> Vec<Step> {
+        vec![
+            Step::new(
+                widget::Id::new("search"),
+                "Search",
+                "Type here to filter the documents below.",
+            ),
+            Step::new(
+                widget::Id::new("documents"),
+                "Documents",
+                "Everything you have written lives here. The spotlight follows the list when the window is resized.",
+            ),
+            Step::new(
+                widget::Id::new("restart"),
+                "Take the tour again",
+                "This button starts the tour from the beginning.",
+            ),
+        ]
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let documents = (1..=30)
+            .filter(|index| format!("Document {index}").contains(self.query.as_str()))
+            .fold(column![].spacing(6), |list, index| list.push(text(format!("Document {index}"))));
+
+        let content = column![
+            row![
+                text("Tour Example").size(25).width(Length::Fill),
+                container(button("Restart tour").on_press(Message::Start)).id("restart"),
+            ],
+            container(text_input("Search documents", &self.query).on_input(Message::Search)).id("search"),
+            container(scrollable(documents).height(Length::Fill).width(Length::Fill))
+                .id("documents")
+                .height(Length::Fill),
+        ]
+        .spacing(12)
+        .padding(20);
+
+        tour(content, Self::steps(), self.step)
+            .on_next(Message::Next)
+            .on_back(Message::Back)
+            .on_skip(Message::Skip)
+            .into()
+    }
+}
+
+fn main() -> iced::Result {
+    iced::application(TourExample::new, TourExample::update, TourExample::view)
+        .theme(TourExample::theme)
+        .title(TourExample::title)
+        .run()
+}
@@ -0,0 +1,99 @@
+use std::collections::HashSet;
+
+use iced::widget::{column, row, text};
+use iced::{Element, Task, Theme};
+use widgets::tree_select::{item, tree_select, Item};
+
+#[derive(Debug, Clone)]
+enum Message {
+    CategoryPicked(HashSet<usize>),
+    TagsChanged(HashSet<usize>),
+    QueryChanged(String),
+}
+
+struct TreeSelectExample {
+    categories: Vec<Item>,
+    category: HashSet<usize>,
+    tags: Vec<Item>,
+    selected_tags: HashSet<usize>,
+    query: String,
+}
+
+impl TreeSelectExample {
+    fn new() -> (Self, Task<Message>) {
+        let categories = vec![
+            item(1, "Electronics").with_children([
+                item(2, "Phones"),
+                item(3, "Laptops"),
+                item(4, "Audio").with_children([item(5, "Headphones"), item(6, "Speakers")]),
+            ]),
+            item(7, "Home").with_children([item(8, "Kitchen"), item(9, "Garden")]),
+            item(10, "Books"),
+        ];
+        let tags = vec![
+            item(101, "Colors").with_children([item(102, "Red"), item(103, "Green"), item(104, "Blue")]),
+            item(105, "Sizes").with_children([item(106, "Small"), item(107, "Medium"), item(108, "Large")]),
+            item(109, "On sale"),
+        ];
+
+        (
+            Self {
+                categories,
+                category: HashSet::new(),
+                tags,
+                selected_tags: HashSet::new(),
+                query: String::new(),
+            },
+            Task::none(),
+        )
+    }
+
+    fn title(&self) -> String {
+        String::from("Tree Select Example")
+    }
+
+    fn theme(&self) -> Theme {
+        Theme::Dark
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::CategoryPicked(category) => self.category = category,
+            Message::TagsChanged(tags) => self.selected_tags = tags,
+            Message::QueryChanged(query) => self.query = query,
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let mut selected: Vec<usize> = self.selected_tags.iter().copied().collect();
+        selected.sort_unstable();
+
+        column![
+            text("Tree Select Example").size(25),
+            row![
+                text("Category").width(100),
+                tree_select(&self.categories, &self.category, Message::CategoryPicked).placeholder("Pick a category"),
+            ]
+            .spacing(20),
+            row![
+                text("Tags").width(100),
+                tree_select(&self.tags, &self.selected_tags, Message::TagsChanged)
+                    .multiple()
+                    .search(&self.query, Message::QueryChanged)
+                    .placeholder("Pick tags"),
+            ]
+            .spacing(20),
+            text(format!("Category: {:?}, tags: {selected:?}", self.category)),
+        ]
+        .spacing(20)
+        .padding(20)
+        .into()
+    }
+}
+
+fn main() -> iced::Result {
+    iced::application(TreeSelectExample::new, TreeSelectExample::update, TreeSelectExample::view)
+        .theme(TreeSelectExample::theme)
+        .title(TreeSelectExample::title)
+        .run()
+}
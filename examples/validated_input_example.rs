@@ -0,0 +1,125 @@
+use iced::widget::{button, column, row, text, text_input};
+use iced::{Element, Task, Theme};
+use widgets::validated_input::{validated_input, Validation};
+
+#[derive(Debug, Clone)]
+enum Message {
+    EmailChanged(String),
+    UsernameChanged(String),
+    CheckUsername,
+    UsernameChecked,
+    PasswordChanged(String),
+}
+
+struct ValidatedInputExample {
+    email: String,
+    username: String,
+    username_validation: Validation,
+    password: String,
+}
+
+impl ValidatedInputExample {
+    fn new() -> (Self, Task<Message>) {
+        (
+            Self {
+                email: String::new(),
+                username: String::new(),
+                username_validation: Validation::Unchecked,
+                password: String::new(),
+            },
+            Task::none(),
+        )
+    }
+
+    fn title(&self) -> String {
+        String::from("Validated Input Example")
+    }
+
+    fn theme(&self) -> Theme {
+        Theme::Dark
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::EmailChanged(email) => self.email = email,
+            Message::UsernameChanged(username) => {
+                self.username = username;
+                self.username_validation = Validation::Unchecked;
+            }
+            Message::CheckUsername => self.username_validation = Validation::Pending,
+            // Stands in for the reply of a server
+            Message::UsernameChecked => {
+                self.username_validation = if self.username.is_empty() {
+                    Validation::Error(String::from("Pick a username"))
+                } else if self.username.eq_ignore_ascii_case("admin") {
+                    Validation::Error(String::from("That username is taken"))
+                } else {
+                    Validation::Success(Some(String::from("Available")))
+                }
+            }
+            Message::PasswordChanged(password) => self.password = password,
+        }
+    }
+
+    fn email_validation(&self) -> Validation {
+        match self.email.split_once('@') {
+            _ if self.email.is_empty() => Validation::Unchecked,
+            Some((name, domain)) if !name.is_empty() && domain.contains('.') => Validation::Success(None),
+            _ => Validation::Error(String::from("Enter an address like name@example.com")),
+        }
+    }
+
+    fn password_validation(&self) -> Validation {
+        match self.password.len() {
+            0 => Validation::Unchecked,
+            1..8 => Validation::Error(String::from("Use at least 8 characters")),
+            8..12 => Validation::Warning(String::from("Longer passwords are safer")),
+            _ => Validation::Success(Some(String::from("Strong password"))),
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let checking = self.username_validation == Validation::Pending;
+
+        column![
+            text("Validated Input Example").size(25),
+            text("Email"),
+            validated_input(
+                text_input("name@example.com", &self.email).on_input(Message::EmailChanged).padding(8),
+                &self.email_validation(),
+            )
+            .reserve_message(true),
+            text("Username"),
+            validated_input(
+                text_input("username", &self.username).on_input(Message::UsernameChanged).padding(8),
+                &self.username_validation,
+            )
+            .reserve_message(true),
+            row![
+                button("Check availability").on_press_maybe((!checking).then_some(Message::CheckUsername)),
+                button("Reply").on_press_maybe(checking.then_some(Message::UsernameChecked)),
+            ]
+            .spacing(10),
+            text("Password"),
+            validated_input(
+                text_input("password", &self.password)
+                    .on_input(Message::PasswordChanged)
+                    .secure(true)
+                    .padding(8),
+                &self.password_validation(),
+            )
+            .reserve_message(true),
+        ]
+        .spacing(10)
+        .padding(20)
+        .max_width(420)
+        .into()
+    }
+}
+
+fn main() -> iced::Result {
+    iced::application(ValidatedInputExample::new, ValidatedInputExample::update, ValidatedInputExample::view)
+        .theme(ValidatedInputExample::theme)
+        .title(ValidatedInputExample::title)
+        .run()
+}
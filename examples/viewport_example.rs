@@ -0,0 +1,88 @@
+use iced::widget::{button, column, container, row, text};
+use iced::{Element, Length, Theme};
+use widgets::viewport::{viewport, Transform};
+
+#[derive(Debug, Clone)]
+enum Message {
+    Transformed(Transform),
+    Reset,
+    Clicked(usize, usize),
+}
+
+struct ViewportExample {
+    transform: Transform,
+    clicked: Option<(usize, usize)>,
+}
+
+impl ViewportExample {
+    fn new() -> (Self, iced::Task<Message>) {
+        (
+            Self {
+                transform: Transform::IDENTITY,
+                clicked: None,
+            },
+            iced::Task::none(),
+        )
+    }
+
+    fn title(&self) -> String {
+        String::from("Viewport Example")
+    }
+
+    fn theme(&self) -> Theme {
+        Theme::Dark
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Transformed(transform) => self.transform = transform,
+            Message::Reset => self.transform = Transform::IDENTITY,
+            Message::Clicked(x, y) => self.clicked = Some((x, y)),
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let grid = (0..12).fold(column![].spacing(8), |grid, y| {
+            grid.push((0..12).fold(row![].spacing(8), |cells, x| {
+                cells.push(button(text(format!("{x},{y}"))).width(64).on_press(Message::Clicked(x, y)))
+            }))
+        });
+
+        let clicked = self
+            .clicked
+            .map_or_else(|| String::from("-"), |(x, y)| format!("{x},{y}"));
+
+        column![
+            text("Viewport Example").size(25),
+            text("Scroll to zoom around the cursor, drag with the middle button to pan"),
+            row![
+                text(format!(
+                    "Zoom {:.0}%, offset {:.0}, {:.0}, last clicked {clicked}",
+                    self.transform.scale * 100.0,
+                    self.transform.offset.x,
+                    self.transform.offset.y
+                ))
+                .width(Length::Fill),
+                button("Reset").on_press(Message::Reset),
+            ],
+            container(
+                viewport(container(grid).padding(16))
+                    .transform(self.transform)
+                    .on_transform(Message::Transformed)
+                    .min_zoom(0.25)
+                    .max_zoom(4.0)
+            )
+            .style(container::bordered_box),
+        ]
+        .spacing(12)
+        .padding(20)
+        .into()
+    }
+}
+
+fn main() -> iced::Result {
+    iced::application(ViewportExample::new, ViewportExample::update, ViewportExample::view)
+        .theme(ViewportExample::theme)
+        .title(ViewportExample::title)
+        .run()
+}
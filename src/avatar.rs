@@ -0,0 +1,936 @@
+//! Pictures of people, falling back to their initials, alone or stacked in a group.
+//!
+//! An [`Avatar`] shows an image when one is set and the person's initials on a color picked
+//! from their name otherwise, so the same person keeps the same color everywhere. A
+//! [`Presence`] dot can sit on its bottom right corner.
+//!
+//! An [`AvatarGroup`] overlaps a few avatars and sums up the rest in a `+N` chip. Clicking the
+//! chip lists everyone left out, with their names, in a dropdown.
+//!
+//! Images need a renderer that can draw them, which for iced means its `image` feature.
+
+use iced::{
+    advanced::{
+        image,
+        layout::{Limits, Node},
+        mouse, overlay, renderer, text,
+        widget::{self, tree::Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment, border, keyboard, Background, Border, Color, Element, Event, Length, Pixels, Point, Rectangle,
+    Shadow, Size, Vector,
+};
+
+use crate::pixel;
+
+const LIST_WIDTH: f32 = 220.0;
+const LIST_ROW_HEIGHT: f32 = 36.0;
+const LIST_AVATAR_SIZE: f32 = 24.0;
+const LIST_PADDING: f32 = 6.0;
+const LIST_OFFSET: f32 = 4.0;
+const LIST_MAX_ROWS: usize = 6;
+
+/// Creates a new [`Avatar`] for the person called `name`.
+pub fn avatar<'a, Theme, Renderer>(name: impl Into<String>) -> Avatar<'a, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer<Font = iced::Font>,
+{
+    Avatar::new(name)
+}
+
+/// Creates a new [`AvatarGroup`] stacking `avatars`.
+pub fn avatar_group<'a, Message, Theme, Renderer>(
+    avatars: impl IntoIterator<Item = Avatar<'a, Theme, Renderer>>,
+) -> AvatarGroup<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer<Font = iced::Font>,
+{
+    AvatarGroup::new(avatars)
+}
+
+/// The outline of an [`Avatar`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Shape {
+    #[default]
+    Circle,
+    /// A square with corners of the given radius
+    Rounded(f32),
+    Square,
+}
+
+/// Whether a person is around, shown as a dot on their [`Avatar`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Presence {
+    Online,
+    Away,
+    Busy,
+    Offline,
+}
+
+type DrawImage<'a, Renderer> = Box<dyn Fn(&mut Renderer, Rectangle, border::Radius) + 'a>;
+
+/// A picture of a person, or their initials.
+#[allow(missing_debug_implementations)]
+pub struct Avatar<'a, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Theme: Catalog,
+{
+    name: String,
+    initials: String,
+    image: Option<DrawImage<'a, Renderer>>,
+    presence: Option<Presence>,
+    size: f32,
+    shape: Shape,
+    font: iced::Font,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Theme, Renderer> Avatar<'a, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer<Font = iced::Font>,
+{
+    /// Creates a new [`Avatar`] for the person called `name`.
+    pub fn new(name: impl Into<String>) -> Self {
+        let name = name.into();
+
+        Self {
+            initials: initials(&name),
+            name,
+            image: None,
+            presence: None,
+            size: 40.0,
+            shape: Shape::default(),
+            font: iced::Font::default(),
+            class: Theme::default(),
+        }
+    }
+
+    /// Shows an image instead of the initials, stretched over the whole avatar.
+    pub fn image(mut self, handle: impl Into<image::Handle>) -> Self
+    where
+        Renderer: image::Renderer<Handle = image::Handle>,
+    {
+        let handle = handle.into();
+
+        self.image = Some(Box::new(move |renderer, bounds, radius| {
+            renderer.draw_image(
+                image::Image {
+                    border_radius: radius,
+                    ..image::Image::new(handle.clone())
+                },
+                bounds,
+                bounds,
+            );
+        }));
+        self
+    }
+
+    /// Replaces the initials taken from the name.
+    pub fn initials(mut self, initials: impl Into<String>) -> Self {
+        self.initials = initials.into();
+        self
+    }
+
+    /// Shows a presence dot.
+    pub fn presence(mut self, presence: impl Into<Option<Presence>>) -> Self {
+        self.presence = presence.into();
+        self
+    }
+
+    /// Sets the width and height of the avatar.
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = size.into().0;
+        self
+    }
+
+    /// Sets the outline of the avatar.
+    pub fn shape(mut self, shape: Shape) -> Self {
+        self.shape = shape;
+        self
+    }
+
+    /// Sets the font of the initials.
+    pub fn font(mut self, font: impl Into<iced::Font>) -> Self {
+        self.font = font.into();
+        self
+    }
+
+    /// Sets the style.
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class.
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    fn radius(&self, size: f32) -> border::Radius {
+        match self.shape {
+            Shape::Circle => (size / 2.0).into(),
+            Shape::Rounded(radius) => radius.min(size / 2.0).into(),
+            Shape::Square => 0.0.into(),
+        }
+    }
+
+    /// Draws the avatar filling `bounds`, with a ring around it when stacked.
+    fn draw_in(&self, renderer: &mut Renderer, theme: &Theme, bounds: Rectangle, ring: bool) {
+        let style = theme.style(&self.class);
+        let radius = self.radius(bounds.width);
+        let ring_width = if ring { style.ring_width } else { 0.0 };
+
+        // Each avatar gets a layer so the initials of one never show through the next
+        renderer.with_layer(bounds.expand(ring_width + 1.0), |renderer| {
+            if ring_width > 0.0 {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: pixel::snap(bounds.expand(ring_width)),
+                        border: pixel::border(Border {
+                            radius: match self.shape {
+                                Shape::Rounded(radius) => (radius.min(bounds.width / 2.0) + ring_width).into(),
+                                _ => self.radius(bounds.width + ring_width * 2.0),
+                            },
+                            ..Border::default()
+                        }),
+                        ..renderer::Quad::default()
+                    },
+                    style.ring,
+                );
+            }
+
+            match &self.image {
+                Some(draw_image) => draw_image(renderer, bounds, radius),
+                None => {
+                    let background = style.backgrounds[name_hash(&self.name) % style.backgrounds.len()];
+
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: pixel::snap(bounds),
+                            border: pixel::border(Border {
+                                radius,
+                                ..Border::default()
+                            }),
+                            ..renderer::Quad::default()
+                        },
+                        background,
+                    );
+                    renderer.fill_text(
+                        iced::advanced::Text {
+                            content: self.initials.clone(),
+                            bounds: bounds.size(),
+                            size: Pixels(bounds.height * 0.4),
+                            font: self.font,
+                            align_x: text::Alignment::Center,
+                            align_y: alignment::Vertical::Center,
+                            line_height: text::LineHeight::default(),
+                            shaping: text::Shaping::Advanced,
+                            wrapping: text::Wrapping::None,
+                        },
+                        bounds.center(),
+                        style.text,
+                        bounds,
+                    );
+                }
+            }
+        });
+
+        if let Some(presence) = self.presence {
+            let diameter = (bounds.width * 0.28).max(6.0);
+            let dot = Rectangle::new(
+                Point::new(
+                    bounds.x + bounds.width - diameter * 0.9,
+                    bounds.y + bounds.height - diameter * 0.9,
+                ),
+                Size::new(diameter, diameter),
+            );
+            let color = match presence {
+                Presence::Online => style.online,
+                Presence::Away => style.away,
+                Presence::Busy => style.busy,
+                Presence::Offline => style.offline,
+            };
+
+            renderer.with_layer(dot.expand(2.0), |renderer| {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: pixel::snap(dot),
+                        border: pixel::border(Border {
+                            color: style.ring,
+                            width: 2.0,
+                            radius: (diameter / 2.0).into(),
+                        }),
+                        ..renderer::Quad::default()
+                    },
+                    color,
+                );
+            });
+        }
+    }
+}
+
+/// The first letters of the first and last words of `name`.
+fn initials(name: &str) -> String {
+    let mut words = name.split_whitespace();
+    let first = words.next().and_then(|word| word.chars().next());
+    let last = words.next_back().and_then(|word| word.chars().next());
+
+    first.into_iter().chain(last).flat_map(char::to_uppercase).collect()
+}
+
+/// A stable hash of `name`, picking the same color for the same person across runs.
+fn name_hash(name: &str) -> usize {
+    name.bytes()
+        .fold(5381_usize, |hash, byte| hash.wrapping_mul(33).wrapping_add(byte as usize))
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Avatar<'_, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer<Font = iced::Font>,
+{
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: Length::Fixed(self.size),
+            height: Length::Fixed(self.size),
+        }
+    }
+
+    fn layout(&mut self, _tree: &mut Tree, _renderer: &Renderer, limits: &Limits) -> Node {
+        Node::new(limits.resolve(self.size, self.size, Size::ZERO))
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _defaults: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        self.draw_in(renderer, theme, layout.bounds(), false);
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Avatar<'a, Theme, Renderer>> for Element<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer<Font = iced::Font> + 'a,
+{
+    fn from(avatar: Avatar<'a, Theme, Renderer>) -> Self {
+        Element::new(avatar)
+    }
+}
+
+/// Overlapping avatars, with a chip counting and listing the ones left out.
+#[allow(missing_debug_implementations)]
+pub struct AvatarGroup<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Theme: Catalog,
+{
+    avatars: Vec<Avatar<'a, Theme, Renderer>>,
+    max: usize,
+    size: f32,
+    overlap: f32,
+    on_select: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    text_size: f32,
+    font: iced::Font,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> AvatarGroup<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer<Font = iced::Font>,
+{
+    /// Creates a new [`AvatarGroup`] stacking `avatars`.
+    pub fn new(avatars: impl IntoIterator<Item = Avatar<'a, Theme, Renderer>>) -> Self {
+        Self {
+            avatars: avatars.into_iter().collect(),
+            max: 4,
+            size: 32.0,
+            overlap: 0.3,
+            on_select: None,
+            text_size: 14.0,
+            font: iced::Font::default(),
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets how many avatars are shown before the rest go in the chip.
+    pub fn max(mut self, max: usize) -> Self {
+        self.max = max.max(1);
+        self
+    }
+
+    /// Sets the size of every avatar in the group.
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = size.into().0;
+        self
+    }
+
+    /// Sets how much each avatar covers the previous one, as a fraction of its size.
+    pub fn overlap(mut self, overlap: f32) -> Self {
+        self.overlap = overlap.clamp(0.0, 0.9);
+        self
+    }
+
+    /// Sets the message produced when a person in the overflow list is clicked, with their index.
+    pub fn on_select(mut self, on_select: impl Fn(usize) -> Message + 'a) -> Self {
+        self.on_select = Some(Box::new(on_select));
+        self
+    }
+
+    /// Sets the text size of the chip and the overflow list.
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = size.into().0;
+        self
+    }
+
+    /// Sets the font of the chip and the overflow list.
+    pub fn font(mut self, font: impl Into<iced::Font>) -> Self {
+        self.font = font.into();
+        self
+    }
+
+    /// Sets the style of the chip and the overflow list.
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the chip and the overflow list.
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    fn shown(&self) -> usize {
+        self.avatars.len().min(self.max)
+    }
+
+    fn hidden(&self) -> &[Avatar<'a, Theme, Renderer>] {
+        &self.avatars[self.shown()..]
+    }
+
+    /// The bounds of the avatar or chip in `slot`.
+    fn slot(&self, bounds: Rectangle, slot: usize) -> Rectangle {
+        let step = self.size * (1.0 - self.overlap);
+
+        Rectangle::new(
+            Point::new(bounds.x + slot as f32 * step, bounds.y),
+            Size::new(self.size, self.size),
+        )
+    }
+
+    fn chip(&self, bounds: Rectangle) -> Option<Rectangle> {
+        (!self.hidden().is_empty()).then(|| self.slot(bounds, self.shown()))
+    }
+
+    fn text(&self, content: String, bounds: Size, size: f32) -> iced::advanced::Text<String, iced::Font> {
+        iced::advanced::Text {
+            content,
+            bounds,
+            size: Pixels(size),
+            font: self.font,
+            align_x: text::Alignment::Center,
+            align_y: alignment::Vertical::Center,
+            line_height: text::LineHeight::default(),
+            shaping: text::Shaping::Advanced,
+            wrapping: text::Wrapping::None,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    open: bool,
+    /// First row of the overflow list shown
+    offset: usize,
+    hovered: Option<usize>,
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for AvatarGroup<'_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer<Font = iced::Font>,
+{
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: Length::Shrink,
+            height: Length::Fixed(self.size),
+        }
+    }
+
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(State::default())
+    }
+
+    fn layout(&mut self, _tree: &mut Tree, _renderer: &Renderer, limits: &Limits) -> Node {
+        let slots = self.shown() + usize::from(!self.hidden().is_empty());
+        let width = if slots == 0 {
+            0.0
+        } else {
+            self.size + (slots - 1) as f32 * self.size * (1.0 - self.overlap)
+        };
+
+        Node::new(limits.resolve(Length::Shrink, self.size, Size::new(width, self.size)))
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                if let Some(chip) = self.chip(layout.bounds())
+                    && cursor.is_over(chip)
+                {
+                    state.open = !state.open;
+                    state.offset = 0;
+                    state.hovered = None;
+                    shell.capture_event();
+                    shell.request_redraw();
+                } else if state.open {
+                    // A click anywhere else closes the list, the overlay has had its chance
+                    state.open = false;
+                    shell.request_redraw();
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                shell.request_redraw();
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _defaults: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_ref::<State>();
+
+        for (slot, avatar) in self.avatars.iter().take(self.shown()).enumerate() {
+            avatar.draw_in(renderer, theme, self.slot(bounds, slot), true);
+        }
+
+        let Some(chip) = self.chip(bounds) else {
+            return;
+        };
+        let style = theme.style(&self.class);
+        let hovered = state.open || cursor.is_over(chip);
+
+        renderer.with_layer(chip.expand(style.ring_width + 1.0), |renderer| {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: pixel::snap(chip),
+                    border: pixel::border(Border {
+                        color: style.ring,
+                        width: style.ring_width,
+                        radius: (self.size / 2.0).into(),
+                    }),
+                    ..renderer::Quad::default()
+                },
+                if hovered { style.chip_hovered_background } else { style.chip_background },
+            );
+            renderer.fill_text(
+                self.text(format!("+{}", self.hidden().len()), chip.size(), self.size * 0.38),
+                chip.center(),
+                style.chip_text,
+                chip,
+            );
+        });
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        match self.chip(layout.bounds()) {
+            Some(chip) if cursor.is_over(chip) => mouse::Interaction::Pointer,
+            _ => mouse::Interaction::None,
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        _renderer: &Renderer,
+        _viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_mut::<State>();
+        let chip = self.chip(layout.bounds())?;
+
+        if !state.open {
+            return None;
+        }
+
+        Some(overlay::Element::new(Box::new(List {
+            group: self,
+            state,
+            anchor: chip + translation,
+        })))
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<AvatarGroup<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer<Font = iced::Font> + 'a,
+{
+    fn from(group: AvatarGroup<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(group)
+    }
+}
+
+/// The dropdown listing the people left out of an [`AvatarGroup`].
+struct List<'a, 'b, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    group: &'a AvatarGroup<'b, Message, Theme, Renderer>,
+    state: &'a mut State,
+    anchor: Rectangle,
+}
+
+impl<Message, Theme, Renderer> List<'_, '_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer<Font = iced::Font>,
+{
+    fn rows(&self) -> usize {
+        self.group.hidden().len().min(LIST_MAX_ROWS)
+    }
+
+    /// The index into the hidden avatars of the row at `point`.
+    fn row_at(&self, bounds: Rectangle, point: Point) -> Option<usize> {
+        if !bounds.contains(point) {
+            return None;
+        }
+
+        let row = ((point.y - bounds.y - LIST_PADDING) / LIST_ROW_HEIGHT).floor();
+        (row >= 0.0 && (row as usize) < self.rows()).then(|| self.state.offset + row as usize)
+    }
+}
+
+impl<Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer> for List<'_, '_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer<Font = iced::Font>,
+{
+    fn layout(&mut self, _renderer: &Renderer, bounds: Size) -> Node {
+        let width = LIST_WIDTH.min(bounds.width);
+        let height = (self.rows() as f32 * LIST_ROW_HEIGHT + LIST_PADDING * 2.0).min(bounds.height);
+
+        // Below the chip, flipping above it when there is no room
+        let below = self.anchor.y + self.anchor.height + LIST_OFFSET;
+        let y = if below + height > bounds.height {
+            (self.anchor.y - LIST_OFFSET - height).max(0.0)
+        } else {
+            below
+        };
+        let x = self.anchor.x.clamp(0.0, (bounds.width - width).max(0.0));
+
+        Node::new(Size::new(width, height)).move_to(Point::new(x, y))
+    }
+
+    fn update(
+        &mut self,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                let hovered = self.row_at(bounds, *position);
+
+                if hovered != self.state.hovered {
+                    self.state.hovered = hovered;
+                    shell.request_redraw();
+                }
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) if cursor.is_over(bounds) => {
+                let rows = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => -y.signum() as isize,
+                    mouse::ScrollDelta::Pixels { y, .. } => -(y / LIST_ROW_HEIGHT).round() as isize,
+                };
+                let max = self.group.hidden().len().saturating_sub(LIST_MAX_ROWS);
+                self.state.offset = self.state.offset.saturating_add_signed(rows).min(max);
+                self.state.hovered = cursor.position().and_then(|position| self.row_at(bounds, position));
+
+                shell.capture_event();
+                shell.request_redraw();
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) if cursor.is_over(bounds) => {
+                if let Some(row) = cursor.position().and_then(|position| self.row_at(bounds, position)) {
+                    if let Some(on_select) = &self.group.on_select {
+                        shell.publish(on_select(self.group.shown() + row));
+                    }
+                    self.state.open = false;
+                }
+
+                shell.capture_event();
+                shell.request_redraw();
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(keyboard::key::Named::Escape),
+                ..
+            }) => {
+                self.state.open = false;
+                shell.capture_event();
+                shell.request_redraw();
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _defaults: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+    ) {
+        let bounds = layout.bounds();
+        let style = theme.style(&self.group.class);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: pixel::snap(bounds),
+                border: pixel::border(style.list_border),
+                shadow: style.list_shadow,
+                ..renderer::Quad::default()
+            },
+            style.list_background,
+        );
+
+        for (row, avatar) in self
+            .group
+            .hidden()
+            .iter()
+            .enumerate()
+            .skip(self.state.offset)
+            .take(LIST_MAX_ROWS)
+        {
+            let row_bounds = Rectangle::new(
+                Point::new(
+                    bounds.x + LIST_PADDING,
+                    bounds.y + LIST_PADDING + (row - self.state.offset) as f32 * LIST_ROW_HEIGHT,
+                ),
+                Size::new(bounds.width - LIST_PADDING * 2.0, LIST_ROW_HEIGHT),
+            );
+
+            if self.state.hovered == Some(row) && self.group.on_select.is_some() {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: pixel::snap(row_bounds),
+                        border: pixel::border(Border {
+                            radius: 4.0.into(),
+                            ..Border::default()
+                        }),
+                        ..renderer::Quad::default()
+                    },
+                    style.list_hovered_background,
+                );
+            }
+
+            let avatar_bounds = Rectangle::new(
+                Point::new(
+                    row_bounds.x + LIST_PADDING,
+                    row_bounds.center_y() - LIST_AVATAR_SIZE / 2.0,
+                ),
+                Size::new(LIST_AVATAR_SIZE, LIST_AVATAR_SIZE),
+            );
+            avatar.draw_in(renderer, theme, avatar_bounds, false);
+
+            let label_x = avatar_bounds.x + LIST_AVATAR_SIZE + LIST_PADDING * 1.5;
+            let label_width = (row_bounds.x + row_bounds.width - label_x).max(0.0);
+            renderer.fill_text(
+                iced::advanced::Text {
+                    align_x: text::Alignment::Left,
+                    ..self.group.text(
+                        avatar.name.clone(),
+                        Size::new(label_width, row_bounds.height),
+                        self.group.text_size,
+                    )
+                },
+                Point::new(label_x, row_bounds.center_y()),
+                style.list_text,
+                row_bounds,
+            );
+        }
+    }
+
+    fn mouse_interaction(&self, layout: Layout<'_>, cursor: mouse::Cursor, _renderer: &Renderer) -> mouse::Interaction {
+        match cursor.position() {
+            Some(position) if self.group.on_select.is_some() && self.row_at(layout.bounds(), position).is_some() => {
+                mouse::Interaction::Pointer
+            }
+            _ => mouse::Interaction::None,
+        }
+    }
+}
+
+/// The theme catalog of an [`Avatar`] and an [`AvatarGroup`].
+pub trait Catalog {
+    /// The style class
+    type Class<'a>;
+
+    /// Default style
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// Get the style for a class
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// The appearance of an [`Avatar`] and an [`AvatarGroup`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// Backgrounds behind initials, one picked by the name
+    pub backgrounds: [Color; 6],
+    /// Color of the initials
+    pub text: Color,
+    /// Ring separating stacked avatars and around presence dots, usually the color behind them
+    pub ring: Color,
+    /// Width of the ring around stacked avatars
+    pub ring_width: f32,
+    /// Dot of someone online
+    pub online: Color,
+    /// Dot of someone away
+    pub away: Color,
+    /// Dot of someone busy
+    pub busy: Color,
+    /// Dot of someone offline
+    pub offline: Color,
+    /// Background of the `+N` chip
+    pub chip_background: Background,
+    /// Background of the `+N` chip when hovered or open
+    pub chip_hovered_background: Background,
+    /// Text of the `+N` chip
+    pub chip_text: Color,
+    /// Background of the overflow list
+    pub list_background: Background,
+    /// Border of the overflow list
+    pub list_border: Border,
+    /// Shadow of the overflow list
+    pub list_shadow: Shadow,
+    /// Names in the overflow list
+    pub list_text: Color,
+    /// Background of the hovered row in the overflow list
+    pub list_hovered_background: Background,
+}
+
+/// Styling function
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for iced::Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default avatar style, with initials on muted colors.
+pub fn default(theme: &iced::Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        backgrounds: [
+            Color::from_rgb8(0x5b, 0x7d, 0xb1),
+            Color::from_rgb8(0x7a, 0x5c, 0xa8),
+            Color::from_rgb8(0x3f, 0x8f, 0x7d),
+            Color::from_rgb8(0xb0, 0x6a, 0x3b),
+            Color::from_rgb8(0xa8, 0x4f, 0x6a),
+            Color::from_rgb8(0x6b, 0x7f, 0x3a),
+        ],
+        text: Color::WHITE,
+        ring: palette.background.base.color,
+        ring_width: 2.0,
+        online: palette.success.base.color,
+        away: palette.warning.base.color,
+        busy: palette.danger.base.color,
+        offline: palette.background.strong.color,
+        chip_background: palette.background.strong.color.into(),
+        chip_hovered_background: palette.primary.weak.color.into(),
+        chip_text: palette.background.strong.text,
+        list_background: palette.background.base.color.into(),
+        list_border: Border {
+            color: palette.background.strong.color,
+            width: 1.0,
+            radius: 6.0.into(),
+        },
+        list_shadow: Shadow {
+            color: Color::BLACK.scale_alpha(0.3),
+            offset: Vector::new(0.0, 2.0),
+            blur_radius: 8.0,
+        },
+        list_text: palette.background.base.text,
+        list_hovered_background: palette.background.weak.color.into(),
+    }
+}
+
+/// Initials on the theme's primary color.
+pub fn primary(theme: &iced::Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        backgrounds: [palette.primary.base.color; 6],
+        text: palette.primary.base.text,
+        ..default(theme)
+    }
+}
@@ -0,0 +1,493 @@
+//! A small pill in a corner of any element, showing a count, a short label or just a dot.
+//!
+//! The pill is drawn in its own layer above the content instead of being stacked with it,
+//! so it can hang over the edge of the content without taking any space in the layout or
+//! changing how the content reacts to the mouse. Counts above [`Badge::max`] read `99+`,
+//! and a count of zero hides the badge unless [`Badge::show_zero`] is set.
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        mouse, overlay, renderer, text,
+        widget::{self, tree::Tree, Operation},
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment, Background, Border, Color, Element, Event, Length, Padding, Pixels, Point, Rectangle, Size, Vector,
+};
+
+use crate::pixel;
+
+/// Creates a new [`Badge`] showing `label` over a corner of `content`.
+pub fn badge<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    label: impl Into<Label>,
+) -> Badge<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer<Font = iced::Font>,
+{
+    Badge::new(content, label)
+}
+
+/// What a [`Badge`] shows.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Label {
+    Count(u32),
+    Text(String),
+    /// A dot without any text
+    Dot,
+}
+
+impl From<u32> for Label {
+    fn from(count: u32) -> Self {
+        Label::Count(count)
+    }
+}
+
+impl From<usize> for Label {
+    fn from(count: usize) -> Self {
+        Label::Count(u32::try_from(count).unwrap_or(u32::MAX))
+    }
+}
+
+impl From<&str> for Label {
+    fn from(text: &str) -> Self {
+        Label::Text(text.to_string())
+    }
+}
+
+impl From<String> for Label {
+    fn from(text: String) -> Self {
+        Label::Text(text)
+    }
+}
+
+/// The corner of the content a [`Badge`] sits on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Corner {
+    TopLeft,
+    #[default]
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+/// An element with a badge over one of its corners.
+#[allow(missing_debug_implementations)]
+pub struct Badge<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Theme: Catalog,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    label: Label,
+    dot: bool,
+    max: u32,
+    show_zero: bool,
+    corner: Corner,
+    offset: Vector,
+    text_size: f32,
+    dot_size: f32,
+    padding: Padding,
+    font: iced::Font,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> Badge<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer<Font = iced::Font>,
+{
+    /// Creates a new [`Badge`] showing `label` over a corner of `content`.
+    pub fn new(content: impl Into<Element<'a, Message, Theme, Renderer>>, label: impl Into<Label>) -> Self {
+        Self {
+            content: content.into(),
+            label: label.into(),
+            dot: false,
+            max: 99,
+            show_zero: false,
+            corner: Corner::default(),
+            offset: Vector::ZERO,
+            text_size: 11.0,
+            dot_size: 8.0,
+            padding: Padding::from([1, 5]),
+            font: iced::Font::default(),
+            class: Theme::default(),
+        }
+    }
+
+    /// Shows only a dot instead of the label, still hidden for a count of zero.
+    pub fn dot(mut self) -> Self {
+        self.dot = true;
+        self
+    }
+
+    /// Sets the largest count shown as is, larger ones read as this number followed by `+`.
+    pub fn max(mut self, max: u32) -> Self {
+        self.max = max;
+        self
+    }
+
+    /// Shows the badge when the count is zero.
+    pub fn show_zero(mut self, show_zero: bool) -> Self {
+        self.show_zero = show_zero;
+        self
+    }
+
+    /// Sets the corner the badge sits on.
+    pub fn corner(mut self, corner: Corner) -> Self {
+        self.corner = corner;
+        self
+    }
+
+    /// Moves the badge from where it sits, centered on the corner.
+    pub fn offset(mut self, offset: impl Into<Vector>) -> Self {
+        self.offset = offset.into();
+        self
+    }
+
+    /// Sets the text size of the label.
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = size.into().0;
+        self
+    }
+
+    /// Sets the diameter of the dot.
+    pub fn dot_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.dot_size = size.into().0;
+        self
+    }
+
+    /// Sets the padding around the label.
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the font of the label.
+    pub fn font(mut self, font: impl Into<iced::Font>) -> Self {
+        self.font = font.into();
+        self
+    }
+
+    /// Sets the style.
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class.
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// The text shown, `None` for a dot or when the badge is hidden.
+    fn content(&self) -> Option<Option<String>> {
+        match &self.label {
+            Label::Count(0) if !self.show_zero => None,
+            _ if self.dot => Some(None),
+            Label::Count(count) if *count > self.max => Some(Some(format!("{}+", self.max))),
+            Label::Count(count) => Some(Some(count.to_string())),
+            Label::Text(text) => Some(Some(text.clone())),
+            Label::Dot => Some(None),
+        }
+    }
+
+    fn text(&self, content: String, bounds: Size) -> iced::advanced::Text<String, iced::Font> {
+        iced::advanced::Text {
+            content,
+            bounds,
+            size: Pixels(self.text_size),
+            font: self.font,
+            align_x: text::Alignment::Center,
+            align_y: alignment::Vertical::Center,
+            line_height: text::LineHeight::default(),
+            shaping: text::Shaping::Basic,
+            wrapping: text::Wrapping::None,
+        }
+    }
+
+    /// The bounds of the pill for content in `bounds`.
+    fn pill(&self, bounds: Rectangle, size: Size) -> Rectangle {
+        let corner = match self.corner {
+            Corner::TopLeft => Point::new(bounds.x, bounds.y),
+            Corner::TopRight => Point::new(bounds.x + bounds.width, bounds.y),
+            Corner::BottomLeft => Point::new(bounds.x, bounds.y + bounds.height),
+            Corner::BottomRight => Point::new(bounds.x + bounds.width, bounds.y + bounds.height),
+        };
+
+        Rectangle::new(
+            corner + self.offset - Vector::new(size.width / 2.0, size.height / 2.0),
+            size,
+        )
+    }
+}
+
+fn measure<P: text::Paragraph<Font = iced::Font>>(text: &iced::advanced::Text<String, iced::Font>) -> Size {
+    P::with_text(iced::advanced::Text {
+        content: text.content.as_str(),
+        bounds: text.bounds,
+        size: text.size,
+        font: text.font,
+        align_x: text.align_x,
+        align_y: text.align_y,
+        line_height: text.line_height,
+        shaping: text.shaping,
+        wrapping: text.wrapping,
+    })
+    .min_bounds()
+}
+
+#[derive(Debug, Default)]
+struct State {
+    /// Size of the pill, measured when laid out
+    pill: Size,
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Badge<'_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer<Font = iced::Font>,
+{
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn layout(&mut self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let content = self.content.as_widget_mut().layout(&mut tree.children[0], renderer, limits);
+
+        let pill = match self.content() {
+            Some(Some(label)) => {
+                let text = self.text(label, Size::INFINITE);
+                let measured = measure::<Renderer::Paragraph>(&text);
+                let height = measured.height + self.padding.top + self.padding.bottom;
+
+                // Single digits stay round, anything longer stretches into a pill
+                Size::new((measured.width + self.padding.left + self.padding.right).max(height), height)
+            }
+            Some(None) => Size::new(self.dot_size, self.dot_size),
+            None => Size::ZERO,
+        };
+        tree.state.downcast_mut::<State>().pill = pill;
+
+        Node::with_children(content.size(), vec![content])
+    }
+
+    fn operate(&mut self, tree: &mut Tree, layout: Layout<'_>, renderer: &Renderer, operation: &mut dyn Operation) {
+        self.content.as_widget_mut().operate(
+            &mut tree.children[0],
+            layout.children().next().unwrap_or(layout),
+            renderer,
+            operation,
+        );
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout.children().next().unwrap_or(layout),
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        defaults: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let content_layout = layout.children().next().unwrap_or(layout);
+
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            defaults,
+            content_layout,
+            cursor,
+            viewport,
+        );
+
+        let Some(label) = self.content() else {
+            return;
+        };
+        let state = tree.state.downcast_ref::<State>();
+        let style = theme.style(&self.class);
+        let pill = self.pill(layout.bounds(), state.pill);
+        let Some(clip) = pill.expand(style.border.width).intersection(viewport) else {
+            return;
+        };
+
+        // A layer of its own keeps the pill above the content's text and images
+        renderer.with_layer(clip, |renderer| {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: pixel::snap(pill),
+                    border: pixel::border(Border {
+                        radius: (pill.height / 2.0).into(),
+                        ..style.border
+                    }),
+                    ..renderer::Quad::default()
+                },
+                style.background,
+            );
+
+            if let Some(label) = label {
+                renderer.fill_text(self.text(label, pill.size()), pill.center(), style.text, pill);
+            }
+        });
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout.children().next().unwrap_or(layout),
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout.children().next().unwrap_or(layout),
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Badge<'a, Message, Theme, Renderer>> for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer<Font = iced::Font> + 'a,
+{
+    fn from(badge: Badge<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(badge)
+    }
+}
+
+/// The theme catalog of a [`Badge`].
+pub trait Catalog {
+    /// The style class
+    type Class<'a>;
+
+    /// Default style
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// Get the style for a class
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// The appearance of a [`Badge`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// Fill of the pill
+    pub background: Background,
+    /// Color of the label
+    pub text: Color,
+    /// Ring around the pill, usually the color behind it to set it apart; the radius is ignored
+    pub border: Border,
+}
+
+/// Styling function
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for iced::Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default badge style, in the danger color.
+pub fn default(theme: &iced::Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: palette.danger.base.color.into(),
+        text: palette.danger.base.text,
+        border: Border {
+            color: palette.background.base.color,
+            width: 1.5,
+            ..Border::default()
+        },
+    }
+}
+
+/// A badge in the primary color.
+pub fn primary(theme: &iced::Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: palette.primary.base.color.into(),
+        text: palette.primary.base.text,
+        ..default(theme)
+    }
+}
@@ -0,0 +1,732 @@
+//! A row of filter chips.
+//!
+//! [`ChipBar`] shows one chip per filter, each with an optional count, that the user toggles
+//! on and off. Any number of chips can be on at once, or only one with [`ChipBar::single`].
+//! A clear button at the end of the row turns every chip off, and the row scrolls sideways
+//! when the chips do not fit. Right to left, the row starts at the right with the clear button
+//! on the left.
+//!
+//! The bar reports the whole set of active ids on every change, so the app only stores it.
+
+use std::collections::HashSet;
+use std::hash::Hash;
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        mouse, renderer, text,
+        widget::{self, tree::Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment, touch, Background, Border, Color, Element, Event, Length, Padding, Pixels, Point, Rectangle, Size,
+};
+
+use crate::direction::{self, Direction};
+use crate::pixel;
+
+const DEFAULT_HEIGHT: f32 = 30.0;
+const COUNT_SPACING: f32 = 6.0;
+const COUNT_PADDING: f32 = 6.0;
+const LINE_SCROLL: f32 = 40.0; // Pixels scrolled per wheel line
+
+/// Creates a new [`ChipBar`] of `chips`, producing `on_change` with the new set of active ids.
+pub fn chip_bar<'a, Id, Message, Theme>(
+    chips: impl IntoIterator<Item = Chip<Id>>,
+    active: &HashSet<Id>,
+    on_change: impl Fn(HashSet<Id>) -> Message + 'a,
+) -> ChipBar<'a, Id, Message, Theme>
+where
+    Id: Clone + Eq + Hash,
+    Theme: Catalog,
+{
+    ChipBar::new(chips, active, on_change)
+}
+
+/// Creates a new [`Chip`] with an id and a label.
+pub fn chip<Id>(id: Id, label: impl Into<String>) -> Chip<Id> {
+    Chip {
+        id,
+        label: label.into(),
+        count: None,
+    }
+}
+
+/// A filter of a [`ChipBar`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Chip<Id> {
+    id: Id,
+    label: String,
+    count: Option<usize>,
+}
+
+impl<Id> Chip<Id> {
+    /// Shows `count` in the chip, usually how many results the filter would match.
+    pub fn count(mut self, count: usize) -> Self {
+        self.count = Some(count);
+        self
+    }
+}
+
+/// A horizontal row of toggleable chips.
+#[allow(missing_debug_implementations)]
+pub struct ChipBar<'a, Id, Message, Theme = iced::Theme>
+where
+    Theme: Catalog,
+{
+    chips: Vec<Chip<Id>>,
+    active: HashSet<Id>,
+    on_change: Box<dyn Fn(HashSet<Id>) -> Message + 'a>,
+    single: bool,
+    clear_label: Option<String>,
+    width: Length,
+    height: f32,
+    spacing: f32,
+    padding: Padding,
+    text_size: Pixels,
+    font: iced::Font,
+    direction: Option<Direction>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Id, Message, Theme> ChipBar<'a, Id, Message, Theme>
+where
+    Id: Clone + Eq + Hash,
+    Theme: Catalog,
+{
+    /// Creates a new [`ChipBar`] of `chips`, producing `on_change` with the new set of active ids.
+    pub fn new(
+        chips: impl IntoIterator<Item = Chip<Id>>,
+        active: &HashSet<Id>,
+        on_change: impl Fn(HashSet<Id>) -> Message + 'a,
+    ) -> Self {
+        Self {
+            chips: chips.into_iter().collect(),
+            active: active.clone(),
+            on_change: Box::new(on_change),
+            single: false,
+            clear_label: Some(String::from("Clear")),
+            width: Length::Fill,
+            height: DEFAULT_HEIGHT,
+            spacing: 6.0,
+            padding: Padding::from([0, 12]),
+            text_size: Pixels(13.0),
+            font: iced::Font::default(),
+            direction: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Appends a chip.
+    pub fn push(mut self, chip: Chip<Id>) -> Self {
+        self.chips.push(chip);
+        self
+    }
+
+    /// Lets only one chip be on at a time; clicking the active chip turns it off.
+    pub fn single(mut self) -> Self {
+        self.single = true;
+        self
+    }
+
+    /// Sets the label of the clear button, or hides it with `None`.
+    pub fn clear_label(mut self, label: Option<impl Into<String>>) -> Self {
+        self.clear_label = label.map(Into::into);
+        self
+    }
+
+    /// Sets the width of the bar.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the bar and its chips.
+    pub fn height(mut self, height: f32) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the gap between chips.
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the padding inside every chip.
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the labels.
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = size.into();
+        self
+    }
+
+    /// Sets the font of the labels.
+    pub fn font(mut self, font: iced::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style.
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class.
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    fn label_text(&self, label: &str, size: Size, align_x: text::Alignment) -> iced::advanced::Text<String, iced::Font> {
+        iced::advanced::Text {
+            content: label.to_owned(),
+            bounds: size,
+            size: self.text_size,
+            font: self.font,
+            align_x,
+            align_y: alignment::Vertical::Center,
+            line_height: text::LineHeight::default(),
+            shaping: text::Shaping::Advanced,
+            wrapping: text::Wrapping::None,
+        }
+    }
+
+    fn label_width<P: text::Paragraph<Font = iced::Font>>(&self, label: &str) -> f32 {
+        P::with_text(iced::advanced::Text {
+            content: label,
+            bounds: Size::new(f32::INFINITY, self.height),
+            size: self.text_size,
+            font: self.font,
+            align_x: text::Alignment::Left,
+            align_y: alignment::Vertical::Center,
+            line_height: text::LineHeight::default(),
+            shaping: text::Shaping::Advanced,
+            wrapping: text::Wrapping::None,
+        })
+        .min_bounds()
+        .width
+    }
+
+    /// Sets the [`Direction`] of the bar, instead of the one set with [`direction::set`].
+    ///
+    /// Right to left lines the chips up from the right, with the clear button on the left.
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    // The bar is laid out left to right, and mirrored on the way in and out
+    fn layout_direction(&self) -> Direction {
+        self.direction.unwrap_or_else(direction::get)
+    }
+
+    fn clear_visible(&self) -> bool {
+        self.clear_label.is_some() && !self.active.is_empty()
+    }
+
+    /// The active set after clicking the chip at `index`.
+    fn toggled(&self, index: usize) -> HashSet<Id> {
+        let id = &self.chips[index].id;
+        let is_active = self.active.contains(id);
+
+        if self.single {
+            if is_active {
+                HashSet::new()
+            } else {
+                HashSet::from([id.clone()])
+            }
+        } else {
+            let mut active = self.active.clone();
+            if is_active {
+                active.remove(id);
+            } else {
+                active.insert(id.clone());
+            }
+            active
+        }
+    }
+}
+
+#[derive(Default)]
+struct State {
+    /// Width of every chip, and of the count badge inside it
+    widths: Vec<(f32, f32)>,
+    clear_width: f32,
+    scroll: f32,
+    hovered: Option<Target>,
+}
+
+/// The part of the bar under the cursor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Target {
+    Chip(usize),
+    Clear,
+}
+
+impl State {
+    /// The part of the bar holding the chips, excluding the clear button.
+    fn strip(&self, bounds: Rectangle, clear: bool, spacing: f32) -> Rectangle {
+        if clear {
+            Rectangle {
+                width: (bounds.width - self.clear_width - spacing).max(0.0),
+                ..bounds
+            }
+        } else {
+            bounds
+        }
+    }
+
+    fn clear_button(&self, bounds: Rectangle, clear: bool) -> Option<Rectangle> {
+        clear.then_some(Rectangle {
+            x: bounds.x + bounds.width - self.clear_width,
+            width: self.clear_width,
+            ..bounds
+        })
+    }
+
+    fn content_width(&self, spacing: f32) -> f32 {
+        let gaps = spacing * self.widths.len().saturating_sub(1) as f32;
+        self.widths.iter().map(|(width, _)| width).sum::<f32>() + gaps
+    }
+
+    /// The on-screen rectangle of every chip, shifted by the scroll offset.
+    fn chip_bounds(&self, bounds: Rectangle, spacing: f32) -> Vec<Rectangle> {
+        let mut x = bounds.x - self.scroll;
+
+        self.widths
+            .iter()
+            .map(|&(width, _)| {
+                let chip = Rectangle { x, width, ..bounds };
+                x += width + spacing;
+                chip
+            })
+            .collect()
+    }
+
+    fn target_at(&self, bounds: Rectangle, clear: bool, spacing: f32, point: Point) -> Option<Target> {
+        if !bounds.contains(point) {
+            return None;
+        }
+
+        if self.clear_button(bounds, clear).is_some_and(|button| button.contains(point)) {
+            return Some(Target::Clear);
+        }
+
+        if !self.strip(bounds, clear, spacing).contains(point) {
+            return None;
+        }
+
+        self.chip_bounds(bounds, spacing)
+            .iter()
+            .position(|chip| chip.contains(point))
+            .map(Target::Chip)
+    }
+}
+
+impl<Id, Message, Theme, Renderer> Widget<Message, Theme, Renderer> for ChipBar<'_, Id, Message, Theme>
+where
+    Id: Clone + Eq + Hash,
+    Theme: Catalog,
+    Renderer: text::Renderer<Font = iced::Font>,
+{
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Fixed(self.height),
+        }
+    }
+
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(State::default())
+    }
+
+    fn layout(&mut self, tree: &mut Tree, _renderer: &Renderer, limits: &Limits) -> Node {
+        let state = tree.state.downcast_mut::<State>();
+
+        state.widths = self
+            .chips
+            .iter()
+            .map(|chip| {
+                let label = self.label_width::<Renderer::Paragraph>(&chip.label);
+                let count = chip.count.map_or(0.0, |count| {
+                    self.label_width::<Renderer::Paragraph>(&count.to_string()) + COUNT_PADDING * 2.0
+                });
+                let count_spacing = if chip.count.is_some() { COUNT_SPACING } else { 0.0 };
+
+                (self.padding.x() + label + count_spacing + count, count)
+            })
+            .collect();
+        state.clear_width = self.clear_label.as_deref().map_or(0.0, |label| {
+            self.padding.x() + self.label_width::<Renderer::Paragraph>(label)
+        });
+
+        let clear = self.clear_visible();
+        let content_width = state.content_width(self.spacing)
+            + if clear { self.spacing + state.clear_width } else { 0.0 };
+        let size = limits
+            .height(self.height)
+            .resolve(self.width, self.height, Size::new(content_width, self.height));
+
+        let visible = state.strip(Rectangle::with_size(size), clear, self.spacing).width;
+        state.scroll = state
+            .scroll
+            .clamp(0.0, (state.content_width(self.spacing) - visible).max(0.0));
+
+        Node::new(size)
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+        let clear = self.clear_visible();
+        let dir = self.layout_direction();
+        let cursor = dir.mirror_cursor(cursor, bounds);
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. }) | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                let hovered = cursor
+                    .position()
+                    .and_then(|position| state.target_at(bounds, clear, self.spacing, position));
+
+                if hovered != state.hovered {
+                    state.hovered = hovered;
+                    shell.request_redraw();
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    return;
+                };
+
+                match state.target_at(bounds, clear, self.spacing, position) {
+                    Some(Target::Chip(index)) => shell.publish((self.on_change)(self.toggled(index))),
+                    Some(Target::Clear) => {
+                        state.hovered = None;
+                        shell.publish((self.on_change)(HashSet::new()));
+                    }
+                    None => return,
+                }
+
+                shell.capture_event();
+                shell.request_redraw();
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+                let strip = state.strip(bounds, clear, self.spacing);
+                let max = (state.content_width(self.spacing) - strip.width).max(0.0);
+
+                if max <= 0.0 || !cursor.is_over(bounds) {
+                    return;
+                }
+
+                // Vertical wheels scroll the row too, most mice have no horizontal wheel
+                let (x, y, unit) = match delta {
+                    mouse::ScrollDelta::Lines { x, y } => (*x, *y, LINE_SCROLL),
+                    mouse::ScrollDelta::Pixels { x, y } => (*x, *y, 1.0),
+                };
+                // A horizontal wheel moves the mirrored row the other way
+                let delta = unit
+                    * match (x != 0.0, dir) {
+                        (true, Direction::LeftToRight) => x,
+                        (true, Direction::RightToLeft) => -x,
+                        (false, _) => y,
+                    };
+                let scroll = (state.scroll - delta).clamp(0.0, max);
+
+                if scroll != state.scroll {
+                    state.scroll = scroll;
+                    state.hovered = None;
+                    shell.request_redraw();
+                }
+                shell.capture_event();
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _defaults: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let style = theme.style(&self.class);
+        let bounds = layout.bounds();
+        let clear = self.clear_visible();
+        let dir = self.layout_direction();
+        let strip = dir.mirror(state.strip(bounds, clear, self.spacing), bounds);
+        let chips = state.chip_bounds(bounds, self.spacing);
+
+        if let Some(clip) = strip.intersection(viewport) {
+            renderer.with_layer(clip, |renderer| {
+                for (index, ((chip, &unmirrored), &(_, count_width))) in
+                    self.chips.iter().zip(&chips).zip(&state.widths).enumerate()
+                {
+                    let chip_bounds = &dir.mirror(unmirrored, bounds);
+                    if chip_bounds.intersection(&clip).is_none() {
+                        continue;
+                    }
+
+                    let is_active = self.active.contains(&chip.id);
+                    let is_hovered = state.hovered == Some(Target::Chip(index));
+
+                    let (background, border, text_color) = if is_active {
+                        (style.active_background, style.active_border, style.active_text_color)
+                    } else if is_hovered {
+                        (style.hovered_background, style.border, style.text_color)
+                    } else {
+                        (style.background, style.border, style.text_color)
+                    };
+
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: pixel::snap(*chip_bounds),
+                            border: pixel::border(Border {
+                                radius: (chip_bounds.height / 2.0).into(),
+                                ..border
+                            }),
+                            ..renderer::Quad::default()
+                        },
+                        background,
+                    );
+
+                    renderer.fill_text(
+                        self.label_text(&chip.label, chip_bounds.size(), dir.start().into()),
+                        Point::new(dir.mirror_x(unmirrored.x + self.padding.left, bounds), chip_bounds.center_y()),
+                        text_color,
+                        clip,
+                    );
+
+                    if let Some(count) = chip.count {
+                        let badge_height = (chip_bounds.height - 10.0).max(0.0);
+                        let badge = dir.mirror(
+                            Rectangle {
+                                x: unmirrored.x + unmirrored.width - self.padding.right - count_width,
+                                y: chip_bounds.center_y() - badge_height / 2.0,
+                                width: count_width,
+                                height: badge_height,
+                            },
+                            bounds,
+                        );
+                        let (badge_background, badge_text) = if is_active {
+                            (style.active_count_background, style.active_count_text_color)
+                        } else {
+                            (style.count_background, style.count_text_color)
+                        };
+
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: pixel::snap(badge),
+                                border: pixel::border(Border {
+                                    radius: (badge_height / 2.0).into(),
+                                    ..Border::default()
+                                }),
+                                ..renderer::Quad::default()
+                            },
+                            badge_background,
+                        );
+                        renderer.fill_text(
+                            iced::advanced::Text {
+                                size: Pixels(self.text_size.0 * 0.85),
+                                ..self.label_text(&count.to_string(), badge.size(), text::Alignment::Center)
+                            },
+                            badge.center(),
+                            badge_text,
+                            clip,
+                        );
+                    }
+                }
+            });
+        }
+
+        if let Some(button) = state.clear_button(bounds, clear).map(|button| dir.mirror(button, bounds))
+            && let Some(label) = &self.clear_label
+        {
+            if state.hovered == Some(Target::Clear) {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: pixel::snap(button),
+                        border: pixel::border(Border {
+                            radius: (button.height / 2.0).into(),
+                            ..Border::default()
+                        }),
+                        ..renderer::Quad::default()
+                    },
+                    style.clear_hovered_background,
+                );
+            }
+
+            renderer.fill_text(
+                self.label_text(label, button.size(), text::Alignment::Center),
+                button.center(),
+                style.clear_text_color,
+                *viewport,
+            );
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+
+        match self
+            .layout_direction()
+            .mirror_cursor(cursor, bounds)
+            .position()
+            .and_then(|position| state.target_at(bounds, self.clear_visible(), self.spacing, position))
+        {
+            Some(_) => mouse::Interaction::Pointer,
+            None => mouse::Interaction::None,
+        }
+    }
+}
+
+impl<'a, Id, Message, Theme, Renderer> From<ChipBar<'a, Id, Message, Theme>> for Element<'a, Message, Theme, Renderer>
+where
+    Id: Clone + Eq + Hash + 'a,
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer<Font = iced::Font> + 'a,
+{
+    fn from(chip_bar: ChipBar<'a, Id, Message, Theme>) -> Self {
+        Element::new(chip_bar)
+    }
+}
+
+/// The theme catalog of a [`ChipBar`].
+pub trait Catalog {
+    /// The style class
+    type Class<'a>;
+
+    /// Default style
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// Get the style for a class
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// The appearance of a [`ChipBar`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// Background of chips that are off
+    pub background: Background,
+    /// Background of a hovered chip that is off
+    pub hovered_background: Background,
+    /// Background of chips that are on
+    pub active_background: Background,
+    /// Border of chips that are off, the radius is always fully round
+    pub border: Border,
+    /// Border of chips that are on
+    pub active_border: Border,
+    /// Label color of chips that are off
+    pub text_color: Color,
+    /// Label color of chips that are on
+    pub active_text_color: Color,
+    /// Background of the count in chips that are off
+    pub count_background: Color,
+    /// Text of the count in chips that are off
+    pub count_text_color: Color,
+    /// Background of the count in chips that are on
+    pub active_count_background: Color,
+    /// Text of the count in chips that are on
+    pub active_count_text_color: Color,
+    /// Label color of the clear button
+    pub clear_text_color: Color,
+    /// Background of the hovered clear button
+    pub clear_hovered_background: Color,
+}
+
+/// Styling function
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for iced::Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default chip bar style, filled chips that turn primary when on.
+pub fn default(theme: &iced::Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: palette.background.weak.color.into(),
+        hovered_background: palette.background.strong.color.into(),
+        active_background: palette.primary.base.color.into(),
+        border: Border::default(),
+        active_border: Border::default(),
+        text_color: palette.background.weak.text,
+        active_text_color: palette.primary.base.text,
+        count_background: palette.background.strong.color,
+        count_text_color: palette.background.strong.text,
+        active_count_background: palette.primary.strong.color,
+        active_count_text_color: palette.primary.strong.text,
+        clear_text_color: palette.primary.strong.color,
+        clear_hovered_background: palette.background.weak.color,
+    }
+}
+
+/// Outlined chips on a transparent background, tinted when on.
+pub fn outline(theme: &iced::Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: Color::TRANSPARENT.into(),
+        hovered_background: palette.background.weak.color.into(),
+        active_background: palette.primary.weak.color.scale_alpha(0.3).into(),
+        border: Border {
+            color: palette.background.strong.color,
+            width: 1.0,
+            radius: 0.0.into(),
+        },
+        active_border: Border {
+            color: palette.primary.base.color,
+            width: 1.0,
+            radius: 0.0.into(),
+        },
+        active_text_color: palette.background.base.text,
+        ..default(theme)
+    }
+}
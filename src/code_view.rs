@@ -0,0 +1,727 @@
+//! A read only block of code.
+//!
+//! [`CodeView`] shows text in a monospace font, with optional line numbers in a gutter,
+//! scrolling sideways when lines are longer than it is wide. Hovering it shows a copy button
+//! that puts the whole text on the clipboard and flashes "Copied!", like the copy chip of the
+//! color picker.
+//!
+//! Lines are never wrapped and tabs are shown as four spaces.
+
+use iced::{
+    advanced::{
+        clipboard,
+        layout::{Limits, Node},
+        mouse, renderer, text,
+        widget::{self, tree::Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment, border, keyboard,
+    time::{Duration, Instant},
+    window, Background, Border, Color, Element, Event, Length, Padding, Pixels, Point, Rectangle, Size,
+};
+
+use crate::pixel;
+
+const COPY_BUTTON: Size = Size::new(64.0, 22.0);
+const COPY_MARGIN: f32 = 6.0;
+const COPIED_FLASH: Duration = Duration::from_millis(1200);
+const SCROLLBAR_HEIGHT: f32 = 6.0;
+const LINE_SCROLL: f32 = 40.0;
+
+/// Creates a new [`CodeView`] of `code`.
+pub fn code_view<'a, Message, Theme>(code: impl Into<String>) -> CodeView<'a, Message, Theme>
+where
+    Theme: Catalog,
+{
+    CodeView::new(code)
+}
+
+/// A monospace block of code with line numbers and a copy button.
+#[allow(missing_debug_implementations)]
+pub struct CodeView<'a, Message, Theme = iced::Theme>
+where
+    Theme: Catalog,
+{
+    code: String,
+    numbers: String,
+    line_numbers: bool,
+    copy_button: bool,
+    on_copy: Option<Box<dyn Fn() -> Message + 'a>>,
+    width: Length,
+    height: Length,
+    padding: Padding,
+    text_size: Pixels,
+    font: iced::Font,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme> CodeView<'a, Message, Theme>
+where
+    Theme: Catalog,
+{
+    /// Creates a new [`CodeView`] of `code`.
+    pub fn new(code: impl Into<String>) -> Self {
+        let code = code.into().replace('\t', "    ");
+        let lines = code.lines().count().max(1);
+
+        Self {
+            numbers: (1..=lines).map(|line| line.to_string()).collect::<Vec<_>>().join("\n"),
+            code,
+            line_numbers: false,
+            copy_button: true,
+            on_copy: None,
+            width: Length::Fill,
+            height: Length::Shrink,
+            padding: Padding::new(10.0),
+            text_size: Pixels(13.0),
+            font: iced::Font::MONOSPACE,
+            class: Theme::default(),
+        }
+    }
+
+    /// Shows line numbers in a gutter on the left.
+    pub fn line_numbers(mut self, show: bool) -> Self {
+        self.line_numbers = show;
+        self
+    }
+
+    /// Sets whether the copy button is shown on hover, which it is by default.
+    pub fn copy_button(mut self, show: bool) -> Self {
+        self.copy_button = show;
+        self
+    }
+
+    /// Produces `message` after the code is copied.
+    pub fn on_copy(self, message: Message) -> Self
+    where
+        Message: Clone + 'a,
+    {
+        self.on_copy_with(move || message.clone())
+    }
+
+    /// Produces the message `on_copy` makes after the code is copied, for messages that can
+    /// not be cloned.
+    pub fn on_copy_with(mut self, on_copy: impl Fn() -> Message + 'a) -> Self {
+        self.on_copy = Some(Box::new(on_copy));
+        self
+    }
+
+    /// Sets the width.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height, the height of the code by default.
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the padding around the code.
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size.
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = size.into();
+        self
+    }
+
+    /// Sets the font, [`iced::Font::MONOSPACE`] by default.
+    pub fn font(mut self, font: iced::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style.
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class.
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    fn text<C>(
+        &self,
+        content: C,
+        bounds: Size,
+        align_x: text::Alignment,
+        align_y: alignment::Vertical,
+    ) -> iced::advanced::Text<C, iced::Font> {
+        iced::advanced::Text {
+            content,
+            bounds,
+            size: self.text_size,
+            font: self.font,
+            align_x,
+            align_y,
+            line_height: text::LineHeight::default(),
+            shaping: text::Shaping::Basic,
+            wrapping: text::Wrapping::None,
+        }
+    }
+
+    fn measure<P: text::Paragraph<Font = iced::Font>>(&self, content: &str) -> Size {
+        P::with_text(self.text(content, Size::INFINITE, text::Alignment::Left, alignment::Vertical::Top))
+            .min_bounds()
+    }
+}
+
+#[derive(Debug)]
+struct State {
+    /// Size of the code as laid out
+    content: Size,
+    /// Width of the widest line number
+    numbers: f32,
+    scroll: f32,
+    /// Where the scroller was grabbed, from its left edge
+    grab: Option<f32>,
+    hovered: bool,
+    copied_at: Option<Instant>,
+    now: Instant,
+    modifiers: keyboard::Modifiers,
+}
+
+impl State {
+    fn gutter(&self, padding: Padding, line_numbers: bool) -> f32 {
+        if line_numbers {
+            self.numbers + padding.left + padding.right
+        } else {
+            0.0
+        }
+    }
+
+    /// The visible part of the code, under which it scrolls.
+    fn viewport(&self, bounds: Rectangle, padding: Padding, line_numbers: bool) -> Rectangle {
+        let gutter = self.gutter(padding, line_numbers);
+
+        Rectangle {
+            x: bounds.x + gutter + padding.left,
+            y: bounds.y + padding.top,
+            width: (bounds.width - gutter - padding.x()).max(0.0),
+            height: (bounds.height - padding.y()).max(0.0),
+        }
+    }
+
+    fn max_scroll(&self, viewport: Rectangle) -> f32 {
+        (self.content.width - viewport.width).max(0.0)
+    }
+
+    /// The track and scroller of the scrollbar, when the code is wider than the view.
+    fn scrollbar(&self, bounds: Rectangle, viewport: Rectangle) -> Option<(Rectangle, Rectangle)> {
+        let max = self.max_scroll(viewport);
+        if max <= 0.0 {
+            return None;
+        }
+
+        let track = Rectangle {
+            x: viewport.x,
+            y: bounds.y + bounds.height - SCROLLBAR_HEIGHT - 2.0,
+            width: viewport.width,
+            height: SCROLLBAR_HEIGHT,
+        };
+        let width = (track.width * viewport.width / self.content.width).max(SCROLLBAR_HEIGHT * 4.0);
+        let scroller = Rectangle {
+            x: track.x + self.scroll / max * (track.width - width),
+            width,
+            ..track
+        };
+
+        Some((track, scroller))
+    }
+
+    fn copied(&self) -> bool {
+        self.copied_at.is_some_and(|at| self.now.saturating_duration_since(at) < COPIED_FLASH)
+    }
+}
+
+fn copy_bounds(bounds: Rectangle) -> Rectangle {
+    Rectangle::new(
+        Point::new(bounds.x + bounds.width - COPY_BUTTON.width - COPY_MARGIN, bounds.y + COPY_MARGIN),
+        COPY_BUTTON,
+    )
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for CodeView<'_, Message, Theme>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer<Font = iced::Font>,
+{
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(State {
+            content: Size::ZERO,
+            numbers: 0.0,
+            scroll: 0.0,
+            grab: None,
+            hovered: false,
+            copied_at: None,
+            now: Instant::now(),
+            modifiers: keyboard::Modifiers::default(),
+        })
+    }
+
+    fn layout(&mut self, tree: &mut Tree, _renderer: &Renderer, limits: &Limits) -> Node {
+        let state = tree.state.downcast_mut::<State>();
+        state.content = self.measure::<Renderer::Paragraph>(&self.code);
+        state.numbers = if self.line_numbers {
+            let widest = self.numbers.lines().next_back().unwrap_or("1");
+            self.measure::<Renderer::Paragraph>(widest).width
+        } else {
+            0.0
+        };
+
+        let gutter = state.gutter(self.padding, self.line_numbers);
+        let intrinsic = Size::new(
+            gutter + state.content.width + self.padding.x(),
+            state.content.height + self.padding.y(),
+        );
+        let size = limits.resolve(self.width, self.height, intrinsic);
+
+        // Room for the scrollbar under the last line
+        let bounds = Rectangle::new(Point::ORIGIN, size);
+        let size = if state.scrollbar(bounds, state.viewport(bounds, self.padding, self.line_numbers)).is_some() {
+            limits.resolve(
+                self.width,
+                self.height,
+                Size::new(intrinsic.width, intrinsic.height + SCROLLBAR_HEIGHT + 2.0),
+            )
+        } else {
+            size
+        };
+
+        let viewport = state.viewport(Rectangle::new(Point::ORIGIN, size), self.padding, self.line_numbers);
+        state.scroll = state.scroll.min(state.max_scroll(viewport));
+
+        Node::new(size)
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+        let viewport = state.viewport(bounds, self.padding, self.line_numbers);
+
+        match event {
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                state.now = *now;
+
+                if let Some(at) = state.copied_at {
+                    if state.copied() {
+                        shell.request_redraw_at(at + COPIED_FLASH);
+                    } else {
+                        state.copied_at = None;
+                    }
+                }
+            }
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.modifiers = *modifiers;
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                let hovered = cursor.is_over(bounds);
+                if hovered != state.hovered {
+                    state.hovered = hovered;
+                    shell.request_redraw();
+                }
+
+                if let Some(grab) = state.grab
+                    && let Some(position) = cursor.position()
+                    && let Some((track, scroller)) = state.scrollbar(bounds, viewport)
+                {
+                    let travel = (track.width - scroller.width).max(1.0);
+                    let ratio = ((position.x - grab - track.x) / travel).clamp(0.0, 1.0);
+                    state.scroll = ratio * state.max_scroll(viewport);
+                    shell.request_redraw();
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    return;
+                };
+
+                if self.copy_button && copy_bounds(bounds).contains(position) {
+                    clipboard.write(clipboard::Kind::Standard, self.code.clone());
+
+                    state.copied_at = Some(Instant::now());
+                    state.now = Instant::now();
+                    if let Some(on_copy) = &self.on_copy {
+                        shell.publish(on_copy());
+                    }
+                    shell.request_redraw();
+                    shell.capture_event();
+                } else if let Some((track, scroller)) = state.scrollbar(bounds, viewport)
+                    && position.y >= track.y - 2.0
+                {
+                    // Clicking the track centers the scroller there
+                    let grab = if scroller.contains(Point::new(position.x, scroller.center_y())) {
+                        position.x - scroller.x
+                    } else {
+                        scroller.width / 2.0
+                    };
+                    let travel = (track.width - scroller.width).max(1.0);
+                    let ratio = ((position.x - grab - track.x) / travel).clamp(0.0, 1.0);
+
+                    state.grab = Some(grab);
+                    state.scroll = ratio * state.max_scroll(viewport);
+                    shell.request_redraw();
+                    shell.capture_event();
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) if state.grab.is_some() => {
+                state.grab = None;
+                shell.capture_event();
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+                let max = state.max_scroll(viewport);
+                if max <= 0.0 || !cursor.is_over(bounds) {
+                    return;
+                }
+
+                // Vertical wheels only scroll sideways with shift, so the page still scrolls
+                let delta = match delta {
+                    mouse::ScrollDelta::Lines { x, y } => {
+                        (if *x != 0.0 || !state.modifiers.shift() { *x } else { *y }) * LINE_SCROLL
+                    }
+                    mouse::ScrollDelta::Pixels { x, y } => {
+                        if *x != 0.0 || !state.modifiers.shift() {
+                            *x
+                        } else {
+                            *y
+                        }
+                    }
+                };
+                if delta == 0.0 {
+                    return;
+                }
+
+                let scroll = (state.scroll - delta).clamp(0.0, max);
+                if scroll != state.scroll {
+                    state.scroll = scroll;
+                    shell.request_redraw();
+                }
+                shell.capture_event();
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _defaults: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let style = theme.style(&self.class);
+        let bounds = layout.bounds();
+        let code = state.viewport(bounds, self.padding, self.line_numbers);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: pixel::snap(bounds),
+                border: pixel::border(Border {
+                    radius: style.radius.into(),
+                    ..style.border
+                }),
+                ..renderer::Quad::default()
+            },
+            style.background,
+        );
+
+        if self.line_numbers {
+            let gutter = state.gutter(self.padding, true);
+
+            if let Some(background) = style.gutter_background {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: pixel::snap(Rectangle {
+                            width: gutter,
+                            ..bounds
+                        }),
+                        border: pixel::border(Border {
+                            radius: border::left(style.radius),
+                            ..Border::default()
+                        }),
+                        ..renderer::Quad::default()
+                    },
+                    background,
+                );
+            }
+
+            renderer.fill_text(
+                self.text(
+                    self.numbers.clone(),
+                    Size::new(state.numbers, state.content.height),
+                    text::Alignment::Right,
+                    alignment::Vertical::Top,
+                ),
+                Point::new(bounds.x + self.padding.left + state.numbers, code.y),
+                style.line_number,
+                bounds,
+            );
+        }
+
+        let Some(clip) = code.intersection(viewport) else {
+            return;
+        };
+
+        renderer.with_layer(clip, |renderer| {
+            renderer.fill_text(
+                self.text(
+                    self.code.clone(),
+                    state.content,
+                    text::Alignment::Left,
+                    alignment::Vertical::Top,
+                ),
+                Point::new(code.x - state.scroll, code.y),
+                style.text,
+                clip,
+            );
+        });
+
+        if let Some((track, scroller)) = state.scrollbar(bounds, code) {
+            let radius = (SCROLLBAR_HEIGHT / 2.0).into();
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: pixel::snap(track),
+                    border: pixel::border(Border {
+                        radius,
+                        ..Border::default()
+                    }),
+                    ..renderer::Quad::default()
+                },
+                style.scrollbar,
+            );
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: pixel::snap(scroller),
+                    border: pixel::border(Border {
+                        radius,
+                        ..Border::default()
+                    }),
+                    ..renderer::Quad::default()
+                },
+                style.scroller,
+            );
+        }
+
+        let copied = state.copied();
+        if self.copy_button && (copied || cursor.is_over(bounds)) {
+            let button = copy_bounds(bounds);
+            let hovered = cursor.is_over(button);
+            let (label, color) = if copied {
+                ("Copied!", style.copied)
+            } else {
+                ("Copy", style.button_text)
+            };
+
+            // Above the code, which is in a layer of its own
+            renderer.with_layer(bounds.intersection(viewport).unwrap_or(bounds), |renderer| {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: pixel::snap(button),
+                        border: pixel::border(Border {
+                            radius: 4.0.into(),
+                            ..style.button_border
+                        }),
+                        ..renderer::Quad::default()
+                    },
+                    if hovered {
+                        style.button_hovered_background
+                    } else {
+                        style.button_background
+                    },
+                );
+                renderer.fill_text(
+                    iced::advanced::Text {
+                        size: Pixels(12.0),
+                        font: iced::Font::default(),
+                        ..self.text(
+                            label.to_string(),
+                            button.size(),
+                            text::Alignment::Center,
+                            alignment::Vertical::Center,
+                        )
+                    },
+                    button.center(),
+                    color,
+                    button,
+                );
+            });
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+
+        if state.grab.is_some() {
+            return mouse::Interaction::Grabbing;
+        }
+
+        let Some(position) = cursor.position_over(bounds) else {
+            return mouse::Interaction::None;
+        };
+
+        if self.copy_button && copy_bounds(bounds).contains(position) {
+            mouse::Interaction::Pointer
+        } else if state
+            .scrollbar(bounds, state.viewport(bounds, self.padding, self.line_numbers))
+            .is_some_and(|(track, _)| position.y >= track.y - 2.0)
+        {
+            mouse::Interaction::Grab
+        } else {
+            mouse::Interaction::Text
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<CodeView<'a, Message, Theme>> for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer<Font = iced::Font> + 'a,
+{
+    fn from(view: CodeView<'a, Message, Theme>) -> Self {
+        Element::new(view)
+    }
+}
+
+/// The theme catalog of a [`CodeView`].
+pub trait Catalog {
+    /// The style class
+    type Class<'a>;
+
+    /// Default style
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// Get the style for a class
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// The appearance of a [`CodeView`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// Background behind the code
+    pub background: Background,
+    /// Border around the block, its radius is set by `radius`
+    pub border: Border,
+    /// Corner radius of the block
+    pub radius: f32,
+    /// Background of the line number gutter, if it differs
+    pub gutter_background: Option<Background>,
+    /// Color of the line numbers
+    pub line_number: Color,
+    /// Color of the code
+    pub text: Color,
+    /// Track of the scrollbar
+    pub scrollbar: Color,
+    /// Scroller of the scrollbar
+    pub scroller: Color,
+    /// Background of the copy button
+    pub button_background: Color,
+    /// Background of the copy button under the cursor
+    pub button_hovered_background: Color,
+    /// Border of the copy button
+    pub button_border: Border,
+    /// Text of the copy button
+    pub button_text: Color,
+    /// Text of the copy button once it copied
+    pub copied: Color,
+}
+
+/// Styling function
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for iced::Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default code view style.
+pub fn default(theme: &iced::Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: palette.background.weakest.color.into(),
+        border: Border {
+            color: palette.background.strong.color,
+            width: 1.0,
+            radius: 0.0.into(),
+        },
+        radius: 6.0,
+        gutter_background: Some(palette.background.weak.color.into()),
+        line_number: palette.background.strong.text.scale_alpha(0.5),
+        text: palette.background.base.text,
+        scrollbar: palette.background.weak.color,
+        scroller: palette.background.strong.color,
+        button_background: palette.background.base.color,
+        button_hovered_background: palette.background.weak.color,
+        button_border: Border {
+            color: palette.background.strong.color,
+            width: 1.0,
+            radius: 0.0.into(),
+        },
+        button_text: palette.background.base.text,
+        copied: palette.success.base.color,
+    }
+}
+
+/// No border and no separate gutter, for code inside a card or panel.
+pub fn plain(theme: &iced::Theme) -> Style {
+    Style {
+        border: Border::default(),
+        gutter_background: None,
+        ..default(theme)
+    }
+}
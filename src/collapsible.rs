@@ -2,11 +2,22 @@
 //! 
 //! Supports two modes:
 //! 1. Standalone: Self-managing expand/collapse state
-//! 2. Group: Wrap multiple collapsibles in `collapsible_group![]` for accordion behavior
+//! 2. Group: Wrap multiple collapsibles in `collapsible_group![]` or [`accordion`] for accordion behavior
 
 use iced::{alignment, Alignment};
 use iced::animation::{Animation, Easing};
 use iced::border::{self, Border};
+use std::collections::BTreeSet;
+
+use crate::direction;
+use crate::dnd;
+use crate::operation;
+use crate::focus::{self, Ring};
+use crate::reorder::{self, Axis, Reorder};
+use crate::theme::{Palette, Preset};
+use crate::pixel;
+
+use iced::keyboard;
 use iced::advanced::Clipboard;
 use iced::advanced::layout;
 use iced::advanced::Layout;
@@ -39,6 +50,19 @@ where
     Collapsible::new(title, content)
 }
 
+/// Creates an accordion of collapsible sections where opening one closes the others.
+/// 
+/// Equivalent to [`collapsible_group!`], but takes any iterator of elements.
+pub fn accordion<'a, Message, Theme, Renderer>(
+    items: impl IntoIterator<Item = Element<'a, Message, Theme, Renderer>>,
+) -> CollapsibleGroup<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    CollapsibleGroup::new(items.into_iter().collect())
+}
+
 /// Macro to create a collapsible group with cleaner syntax.
 /// 
 /// # Example
@@ -59,6 +83,10 @@ macro_rules! collapsible_group {
 /// The default height of the header.
 pub const DEFAULT_HEADER_HEIGHT: f32 = 32.0;
 
+/// Builds the content of a lazy [`Collapsible`] when it opens.
+type LazyContent<'a, Message, Theme, Renderer> =
+    Box<dyn Fn() -> Element<'a, Message, Theme, Renderer> + 'a>;
+
 /// A collapsible container with a clickable header and expandable content.
 /// 
 /// By default, manages its own expand/collapse state internally.
@@ -73,23 +101,36 @@ pub struct Collapsible<
     Renderer: text::Renderer,
 {
     title: String,
+    key: Option<String>,
+    id: Option<widget::Id>,
     content: Element<'a, Message, Theme, Renderer>,
+    lazy_content: Option<LazyContent<'a, Message, Theme, Renderer>>,
+    is_lazy: bool,
     on_toggle: Option<Box<dyn Fn(bool) -> Message + 'a>>,
     expand_icon: Option<Element<'a, Message, Theme, Renderer>>,
     collapse_icon: Option<Element<'a, Message, Theme, Renderer>>,
     action_icon: Option<Element<'a, Message, Theme, Renderer>>, // right aligned icon to use as a button
     on_action: Option<Box<dyn Fn() -> Message + 'a>>,   
+    leading: Option<Element<'a, Message, Theme, Renderer>>, // shown between the icon and the title
+    trailing: Option<Element<'a, Message, Theme, Renderer>>, // shown before the action icon, never toggles
     width: Length,
     height: Length,
     header_height: f32,
     title_alignment: Alignment,
     header_clickable: bool,
+    sticky_header: bool,
     padding: Padding,
     content_padding: Padding,
+    indent: f32,
+    guides: bool,
     text_size: Option<Pixels>,
     font: Option<Renderer::Font>,
     class: Theme::Class<'a>,
     initially_expanded: bool,
+    chevron_position: ChevronPosition,
+    chevron_animation: ChevronAnimation,
+    direction: Direction,
+    layout_direction: Option<direction::Direction>,
     easing: Easing,
     duration: Option<iced::time::Duration>,
 }
@@ -125,28 +166,73 @@ where
     ) -> Self {
         Self {
             title: title.into(),
+            key: None,
+            id: None,
             content: content.into(),
+            lazy_content: None,
+            is_lazy: false,
             on_toggle: None,
             expand_icon: None,
             collapse_icon: None,
             action_icon: None,
             on_action: None,
+            leading: None,
+            trailing: None,
             width: Length::Fill,
             height: Length::Shrink,
             header_height: DEFAULT_HEADER_HEIGHT,
             title_alignment: Alignment::Start,
             header_clickable: true,
+            sticky_header: false,
             padding: Self::DEFAULT_PADDING,
             content_padding: Self::DEFAULT_CONTENT_PADDING,
+            indent: 0.0,
+            guides: false,
             text_size: None,
             font: None,
             class: Theme::default(),
             initially_expanded: false,
+            chevron_position: ChevronPosition::Start,
+            chevron_animation: ChevronAnimation::Swap,
+            direction: Direction::Vertical,
+            layout_direction: None,
             easing: Easing::Linear,
             duration: None,
         }
     }
 
+    /// Creates a new [`Collapsible`] whose content is only built while it is open.
+    /// 
+    /// The closure is called during layout, so heavy bodies cost nothing while collapsed.
+    /// The content state is dropped when the section closes.
+    pub fn lazy(
+        title: impl Into<String>,
+        content: impl Fn() -> Element<'a, Message, Theme, Renderer> + 'a,
+    ) -> Self
+    where
+        Message: 'a,
+        Theme: 'a,
+        Renderer: 'a,
+    {
+        let mut collapsible = Self::new(title, iced::widget::space());
+        collapsible.lazy_content = Some(Box::new(content));
+        collapsible.is_lazy = true;
+        collapsible
+    }
+
+    /// Sets the key identifying the section in a group's [`OpenSections`].
+    pub fn key(mut self, key: impl Into<String>) -> Self {
+        self.key = Some(key.into());
+        self
+    }
+
+    /// Sets the [`widget::Id`] used to [`expand`], [`collapse`] or [`toggle`] the section
+    /// from `update`, and to focus it.
+    pub fn id(mut self, id: impl Into<widget::Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
     /// Sets the message that will be produced when toggled.
     pub fn on_toggle(
         mut self,
@@ -207,6 +293,32 @@ where
         self
     }
 
+    /// Uses a single custom chevron for both states.
+    pub fn chevron(
+        mut self,
+        icon: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        self.expand_icon = Some(icon.into());
+        self.collapse_icon = None;
+        self
+    }
+
+    /// Sets which end of the header the chevron is placed at.
+    /// 
+    /// Horizontal collapsibles always keep it at the start, it makes up their icon strip.
+    pub fn chevron_position(mut self, position: ChevronPosition) -> Self {
+        self.chevron_position = position;
+        self
+    }
+
+    /// Sets how the built-in chevron changes between states.
+    /// 
+    /// Custom icons always swap, as arbitrary elements can not be rotated.
+    pub fn chevron_animation(mut self, animation: ChevronAnimation) -> Self {
+        self.chevron_animation = animation;
+        self
+    }
+
     /// Sets the action icon on the right side of the header.
     pub fn action_icon(
         mut self,
@@ -216,18 +328,63 @@ where
         self
     }
 
+    /// Sets an element shown in the header between the icon and the title, e.g. an icon or badge.
+    pub fn leading(
+        mut self,
+        element: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        self.leading = Some(element.into());
+        self
+    }
+
+    /// Sets an element shown at the end of the header, e.g. action buttons.
+    /// 
+    /// Clicks on it are handled by the element and never toggle the section.
+    pub fn trailing(
+        mut self,
+        element: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        self.trailing = Some(element.into());
+        self
+    }
+
     /// Sets whether the entire header is clickable.
     pub fn header_clickable(mut self, clickable: bool) -> Self {
         self.header_clickable = clickable;
         self
     }
 
+    /// Pins the header to the top of the viewport while the open content scrolls underneath.
+    pub fn sticky_header(mut self, sticky: bool) -> Self {
+        self.sticky_header = sticky;
+        self
+    }
+
     /// Sets the padding.
     pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
         self.padding = padding.into();
         self
     }
 
+    /// Indents the content, nested collapsibles add up to a hierarchy.
+    pub fn indent(mut self, indent: impl Into<Pixels>) -> Self {
+        self.indent = indent.into().0;
+        self
+    }
+
+    /// Sets whether a guide line is drawn in the indent next to the content.
+    pub fn guides(mut self, guides: bool) -> Self {
+        self.guides = guides;
+        self
+    }
+
+    /// Indents the content by the same depth offset as the tree widget and draws guide lines.
+    pub fn nested(mut self) -> Self {
+        self.indent = crate::depth::DEFAULT_INDENT;
+        self.guides = true;
+        self
+    }
+
     /// Sets the text size.
     pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
         self.text_size = Some(size.into());
@@ -246,6 +403,26 @@ where
         self
     }
 
+    /// Sets the direction the content collapses in.
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Collapses horizontally to an icon strip, e.g. for sidebars and tool rails.
+    pub fn horizontal(mut self) -> Self {
+        self.direction = Direction::Horizontal;
+        self
+    }
+
+    /// Sets the [`direction::Direction`] of the header, instead of the one set with [`direction::set`].
+    /// 
+    /// Right to left puts the chevron at the right edge and points it left while collapsed.
+    pub fn layout_direction(mut self, direction: direction::Direction) -> Self {
+        self.layout_direction = Some(direction);
+        self
+    }
+
     /// Sets the easing function for animation.
     pub fn easing(mut self, easing: Easing) -> Self {
         self.easing = easing;
@@ -300,6 +477,15 @@ where
         self
     }
 
+    /// The configured animation duration, defaulting to [`Collapsible::quick`].
+    fn animation_duration(&self) -> Duration {
+        self.duration.unwrap_or(Duration::from_millis(200))
+    }
+
+    fn resolved_direction(&self) -> direction::Direction {
+        self.layout_direction.unwrap_or_else(direction::get)
+    }
+
     fn child_indices(&self) -> (Option<usize>, Option<usize>, Option<usize>, usize) {
         let mut index = 0;
         let expand_index = if self.expand_icon.is_some() {
@@ -331,6 +517,199 @@ where
         (expand_index, collapse_index, action_index, content_index)
     }
 
+    /// How far the header is pushed down to stay pinned at the top of the viewport.
+    fn sticky_offset(&self, bounds: Rectangle, state: &State, viewport: &Rectangle) -> f32 {
+        if !self.sticky_header || state.progress == 0.0 {
+            return 0.0;
+        }
+
+        (viewport.y - bounds.y).clamp(0.0, (bounds.height - state.header_height).max(0.0))
+    }
+
+    /// Splits the cursor into one for the header, mapped back from its pinned position,
+    /// and one for the content, which is hidden under a pinned header.
+    fn split_cursor(
+        &self,
+        cursor: mouse::Cursor,
+        bounds: Rectangle,
+        state: &State,
+        viewport: &Rectangle,
+    ) -> (mouse::Cursor, mouse::Cursor) {
+        let offset = self.sticky_offset(bounds, state, viewport);
+        let pinned_header = Rectangle {
+            x: bounds.x,
+            y: bounds.y + offset,
+            width: bounds.width,
+            height: state.header_height,
+        };
+
+        match cursor.position_over(pinned_header) {
+            Some(position) if offset > 0.0 => (
+                mouse::Cursor::Available(Point::new(position.x, position.y - offset)),
+                mouse::Cursor::Unavailable,
+            ),
+            _ => (cursor, cursor),
+        }
+    }
+
+    /// Draws the header background, icon, title, action icon and slots.
+    #[allow(clippy::too_many_arguments)]
+    fn draw_header(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        defaults: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        style: &Style,
+    ) {
+        let combined_state = tree.state.downcast_ref::<CombinedState<Renderer::Paragraph>>();
+        let state = &combined_state.animation;
+        let bounds = layout.bounds();
+        let header_bounds = Rectangle {
+            x: bounds.x,
+            y: bounds.y,
+            width: bounds.width,
+            height: state.header_height,
+        };
+
+        let mut layout_children = layout.children();
+        let icon_layout = layout_children.next().unwrap();
+        let title_layout = layout_children.next().unwrap();
+        let action_layout = layout_children.next().unwrap();
+        let _content_layout = layout_children.next();
+        let leading_layout = layout_children.next();
+        let trailing_layout = layout_children.next();
+        let header_border = header_border(style, state);
+
+        // Draw header background
+        if style.header_background.is_some() || header_border.width > 0.0 {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: pixel::snap(header_bounds),
+                    border: pixel::border(header_border),
+                    shadow: Shadow::default(),
+                    snap: false,
+                },
+                style
+                    .header_background
+                    .unwrap_or(Background::Color(Color::TRANSPARENT)),
+            );
+        }
+
+        if state.is_focused
+            && let Some(focus_color) = style.focus_outline
+        {
+            focus::draw_ring(renderer, header_bounds, Ring::new(focus_color).radius(header_border.radius));
+        }
+
+        let (expand_child, collapse_child, action_child, _) = self.child_indices();
+
+        // Draw icon - either default text, the rotating chevron or custom Element
+        if self.expand_icon.is_none() && self.collapse_icon.is_none()
+            && self.chevron_animation == ChevronAnimation::Rotate
+        {
+            draw_chevron(
+                renderer,
+                icon_layout.bounds(),
+                state.progress * std::f32::consts::FRAC_PI_2,
+                self.resolved_direction().is_rtl(),
+                style.title_text_color.unwrap_or(defaults.text_color),
+            );
+        } else if self.expand_icon.is_none() && self.collapse_icon.is_none() {
+            // Draw default text icon using icon_text state
+            widget::text::draw(
+                renderer,
+                defaults,
+                icon_layout.bounds(),
+                combined_state.icon_text.raw(),
+                iced::widget::text::Style {
+                    color: style.title_text_color,
+                },
+                viewport,
+            );
+        } else {
+            // Draw custom icon Element, falling back to the other icon like layout does
+            let (icon_element, icon_tree_index) = match (
+                state.animation.value(),
+                self.expand_icon.as_ref().zip(expand_child),
+                self.collapse_icon.as_ref().zip(collapse_child),
+            ) {
+                (true, _, Some(collapse)) | (false, None, Some(collapse)) => collapse,
+                (_, Some(expand), _) => expand,
+                (_, None, None) => unreachable!("a custom icon is present"),
+            };
+            
+            icon_element.as_widget().draw(
+                &tree.children[icon_tree_index],
+                renderer,
+                theme,
+                defaults,
+                icon_layout,
+                cursor,
+                viewport,
+            );
+        }
+
+        // Draw title using layout bounds and text state
+        let text_color = style.title_text_color.unwrap_or(defaults.text_color);
+        
+        widget::text::draw(
+            renderer,
+            defaults,
+            title_layout.bounds(),
+            combined_state.text.raw(),
+            iced::widget::text::Style {
+                color: Some(text_color),
+            },
+            viewport,
+        );
+
+        // Draw action icon if present (after drawing title, around line 854)
+        if let Some(ref action_icon) = self.action_icon {
+            action_icon.as_widget().draw(
+                &tree.children[action_child.unwrap()],
+                renderer,
+                theme,
+                defaults,
+                action_layout,
+                cursor,
+                viewport,
+            );
+        }
+
+        // Draw header slots
+        let (leading_child, trailing_child) = self.slot_indices();
+        for (element, index, slot_layout) in [
+            (&self.leading, leading_child, leading_layout),
+            (&self.trailing, trailing_child, trailing_layout),
+        ] {
+            if let (Some(element), Some(index), Some(slot_layout)) = (element, index, slot_layout) {
+                element.as_widget().draw(
+                    &tree.children[index],
+                    renderer,
+                    theme,
+                    defaults,
+                    slot_layout,
+                    cursor,
+                    viewport,
+                );
+            }
+        }
+    }
+
+    /// Tree indices of the leading and trailing header slots, stored after the content.
+    fn slot_indices(&self) -> (Option<usize>, Option<usize>) {
+        let (_, _, _, content_index) = self.child_indices();
+        let leading_index = self.leading.is_some().then_some(content_index + 1);
+        let trailing_index = self.trailing.is_some()
+            .then_some(content_index + 1 + usize::from(self.leading.is_some()));
+
+        (leading_index, trailing_index)
+    }
+
 }
 
 /// Internal state for standalone collapsible.
@@ -342,6 +721,22 @@ struct State {
     button_is_pressed: bool,
     header_is_hovered: bool,
     header_height: f32,
+    is_focused: bool,
+    key: Option<String>,
+}
+
+impl widget::operation::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
 }
 
 /// Combined state that includes both animation state and text state
@@ -363,6 +758,8 @@ impl Default for State {
             button_is_pressed: false,
             header_is_hovered: false,
             header_height: DEFAULT_HEADER_HEIGHT,
+            is_focused: false,
+            key: None,
         }
     }
 }
@@ -370,7 +767,7 @@ impl Default for State {
 impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
     for Collapsible<'a, Message, Theme, Renderer>
 where
-    Message: 'a + Clone,
+    Message: 'a,
     Theme: Catalog,
     Renderer: text::Renderer,
 {
@@ -379,15 +776,10 @@ where
     }
 
     fn state(&self) -> tree::State {
-        let mut animation = Animation::new(self.initially_expanded)
-            .easing(self.easing);
+        let animation = Animation::new(self.initially_expanded)
+            .easing(self.easing)
+            .duration(self.animation_duration());
 
-        animation = if let Some(duration) = self.duration {
-            animation.duration(duration)
-        } else {
-            animation.quick()
-        };
-        
         tree::State::new(CombinedState {
             animation: State {
                 animation,
@@ -396,6 +788,8 @@ where
                 button_is_pressed: false,
                 header_is_hovered: false,
                 header_height: self.header_height,
+                is_focused: false,
+                key: self.key.clone(),
             },
             text: widget::text::State::<Renderer::Paragraph>::default(),
             icon_text: widget::text::State::<Renderer::Paragraph>::default(),
@@ -418,13 +812,29 @@ where
         }
         
         children.push(Tree::new(&self.content));
+
+        if let Some(ref leading) = self.leading {
+            children.push(Tree::new(leading));
+        }
+
+        if let Some(ref trailing) = self.trailing {
+            children.push(Tree::new(trailing));
+        }
         
         children
     }
 
     fn diff(&self, tree: &mut Tree) {
+        // Keep the animation in sync with the builder, the state outlives each view call
+        let state = &mut tree.state.downcast_mut::<CombinedState<Renderer::Paragraph>>().animation;
+        state.animation = state.animation.clone()
+            .easing(self.easing)
+            .duration(self.animation_duration());
+        state.header_height = self.header_height;
+        state.key.clone_from(&self.key);
+
         let mut children = vec![];
-        
+
         if let Some(ref expand_icon) = self.expand_icon {
             children.push(expand_icon);
         }
@@ -437,9 +847,32 @@ where
             children.push(action_icon);
         }
         
+        let content_index = children.len();
         children.push(&self.content);
-        
-        tree.diff_children(&children);
+
+        if let Some(ref leading) = self.leading {
+            children.push(leading);
+        }
+
+        if let Some(ref trailing) = self.trailing {
+            children.push(trailing);
+        }
+
+        if !self.is_lazy {
+            tree.diff_children(&children);
+            return;
+        }
+
+        // Lazy content is built and diffed in layout, keep its tree untouched here
+        if tree.children.len() != children.len() {
+            tree.children = self.children();
+        }
+
+        for (index, (child_tree, child)) in tree.children.iter_mut().zip(children).enumerate() {
+            if index != content_index {
+                child_tree.diff(child);
+            }
+        }
     }
 
     fn size(&self) -> Size<Length> {
@@ -461,7 +894,11 @@ where
 
         let icon_node = if self.expand_icon.is_none() && self.collapse_icon.is_none() {
             // Use default text icon
-            let arrow = if state.animation.value() { "🠻" } else { "🠺" };
+            let arrow = match (state.animation.value(), self.resolved_direction()) {
+                (true, _) => "🠻",
+                (false, direction::Direction::LeftToRight) => "🠺",
+                (false, direction::Direction::RightToLeft) => "🠸",
+            };
             
             let icon_limits = layout::Limits::new(
                 Size::ZERO,
@@ -518,6 +955,13 @@ where
         };
 
         let icon_size = icon_node.size();
+        let chevron_at_end = self.chevron_position == ChevronPosition::End
+            && self.direction == Direction::Vertical;
+        let end_reserved = if chevron_at_end {
+            self.header_height + Self::ICON_SPACING
+        } else {
+            0.0
+        };
 
         // Layout action icon if present
         let (_, _, action_index, _) = self.child_indices();
@@ -544,9 +988,61 @@ where
             .map(|(node, _)| node.size().width + Self::ICON_SPACING)
             .unwrap_or(0.0);
 
-        // Layout title text after icon
-        let title_x = self.header_height + Self::ICON_SPACING;
-        let available_title_width = limits.max().width - title_x - self.padding.right - action_icon_width;
+        // Layout header slots, they shrink to their content within the header height
+        let (leading_index, trailing_index) = self.slot_indices();
+        let slot_limits = layout::Limits::new(
+            Size::ZERO,
+            Size::new(limits.max().width / 2.0, self.header_height),
+        );
+
+        let leading_node = match (&mut self.leading, leading_index) {
+            (Some(leading), Some(index)) => leading.as_widget_mut().layout(
+                &mut tree.children[index],
+                renderer,
+                &slot_limits,
+            ),
+            _ => layout::Node::new(Size::ZERO),
+        };
+
+        let trailing_node = match (&mut self.trailing, trailing_index) {
+            (Some(trailing), Some(index)) => trailing.as_widget_mut().layout(
+                &mut tree.children[index],
+                renderer,
+                &slot_limits,
+            ),
+            _ => layout::Node::new(Size::ZERO),
+        };
+
+        let leading_width = if self.leading.is_some() {
+            leading_node.size().width + Self::ICON_SPACING
+        } else {
+            0.0
+        };
+
+        let trailing_width = if self.trailing.is_some() {
+            trailing_node.size().width + Self::ICON_SPACING
+        } else {
+            0.0
+        };
+
+        let leading_x = if chevron_at_end {
+            self.padding.left
+        } else {
+            self.header_height + Self::ICON_SPACING
+        };
+        let positioned_leading = leading_node.clone().move_to(Point::new(
+            leading_x,
+            (self.header_height - leading_node.size().height) / 2.0,
+        ));
+
+        let positioned_trailing = trailing_node.clone().move_to(Point::new(
+            limits.max().width - self.padding.right - end_reserved - action_icon_width - trailing_node.size().width,
+            (self.header_height - trailing_node.size().height) / 2.0,
+        ));
+
+        // Layout title text after icon and leading slot
+        let title_x = leading_x + leading_width;
+        let available_title_width = limits.max().width - title_x - self.padding.right - end_reserved - action_icon_width - trailing_width;
         
         let title_limits = layout::Limits::new(
             Size::ZERO,
@@ -584,14 +1080,19 @@ where
         let content_height = icon_size.height.max(title_size.height);
         let header_offset = (self.header_height - content_height) / 2.0;
 
+        let icon_x = if chevron_at_end {
+            limits.max().width - self.padding.right - icon_size.width
+        } else {
+            self.padding.left
+        };
         let positioned_icon = icon_node.move_to(Point::new(
-            self.padding.left,
+            icon_x,
             header_offset + icon_y,
         ));
 
         // Always create action icon node (zero-sized if not present)
         let positioned_action = if let Some((node, _)) = action_node_opt {
-            let action_x = limits.max().width - self.padding.right - node.size().width;
+            let action_x = limits.max().width - self.padding.right - end_reserved - node.size().width;
             let action_size = node.size();
             let action_y = if action_size.height > title_size.height {
                 0.0
@@ -624,37 +1125,84 @@ where
             Size::new(available_title_width, content_height),
         );
 
-        // Layout content below header
+        // Layout content below header, closed sections skip it entirely
         let content_limits = limits
             .width(self.width)
             .height(Length::Shrink)
             .shrink(Size::new(
-                self.content_padding.x(),
+                self.content_padding.x() + self.indent,
                 self.content_padding.y(),
             ));
 
         let (_, _, _, content_index) = self.child_indices();
-        let mut content_node = self.content.as_widget_mut().layout(
-            &mut tree.children[content_index],
-            renderer,
-            &content_limits,
-        );
+        let is_open = state.animation.value() || state.progress > 0.0;
 
-        content_node.move_to_mut(Point::new(
-            self.content_padding.left,
+        if is_open
+            && let Some(build) = self.lazy_content.take()
+        {
+            self.content = build();
+            tree.children[content_index].diff(&self.content);
+        } else if !is_open && self.is_lazy {
+            // Drop the state of the body we no longer show
+            tree.children[content_index] = Tree::new(&self.content);
+        }
+
+        let mut content_node = if is_open {
+            self.content.as_widget_mut().layout(
+                &mut tree.children[content_index],
+                renderer,
+                &content_limits,
+            )
+        } else {
+            layout::Node::new(Size::ZERO)
+        };
+
+        content_node.move_to_mut(Point::new(
+            self.content_padding.left + self.indent,
             self.header_height + self.content_padding.top,
         ));
         
         let full_content_height = content_node.size().height + self.content_padding.y();
-        let animated_height = full_content_height * state.progress;
 
-        let total_height = self.header_height + animated_height;
+        let (total_width, total_height) = match self.direction {
+            Direction::Vertical => (
+                limits.max().width,
+                self.header_height + full_content_height * state.progress,
+            ),
+            Direction::Horizontal => {
+                // Collapsed to a strip just wide enough for the icon
+                let strip_width = (self.padding.left + icon_size.width + self.padding.right)
+                    .min(limits.max().width);
+                let width = strip_width + (limits.max().width - strip_width) * state.progress;
+                let height = if self.height == Length::Shrink {
+                    self.header_height + if is_open { full_content_height } else { 0.0 }
+                } else {
+                    limits.max().height
+                };
 
-        // Return node with icon, title, action_icon, and content as layout children
-        layout::Node::with_children(
-            Size::new(limits.max().width, total_height),
-            vec![positioned_icon, positioned_title, positioned_action, content_node],
-        )
+                (width, height)
+            }
+        };
+
+        // Right to left mirrors the children, they are placed left to right above
+        let size = Size::new(total_width, total_height);
+        let dir = self.resolved_direction();
+        let children = [
+            positioned_icon,
+            positioned_title,
+            positioned_action,
+            content_node,
+            positioned_leading,
+            positioned_trailing,
+        ]
+        .map(|node| {
+            let position = dir.mirror(node.bounds(), Rectangle::with_size(size)).position();
+
+            node.move_to(position)
+        });
+
+        // Return node with icon, title, action_icon, content, leading and trailing as layout children
+        layout::Node::with_children(size, children.into())
     }
 
     fn update(
@@ -679,18 +1227,27 @@ where
             height: state.header_height,
         };
 
+        // Header hit tests happen where the header is laid out, even while it is pinned
+        let (cursor, body_cursor) = self.split_cursor(cursor, bounds, state, viewport);
+
         // Icon bounds from first layout child
         let mut children = layout.children();
         let icon_layout = children.next().unwrap();
         let _title_layout = children.next();  
         let action_layout = children.next().unwrap();
         let content_layout = children.next();
+        let leading_layout = children.next();
+        let trailing_layout = children.next();
 
         let icon_bounds = icon_layout.bounds();
         let action_bounds = action_layout.bounds();
+        let is_over_trailing = self.trailing.is_some()
+            && trailing_layout.is_some_and(|layout| cursor.is_over(layout.bounds()));
 
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                state.is_focused = cursor.is_over(header_bounds);
+
                 if self.on_action.is_some() && cursor.is_over(action_bounds) {
                     if let Some(ref on_action) = self.on_action {
                         shell.publish(on_action());
@@ -712,19 +1269,19 @@ where
                         }                
                 } else if ((self.header_clickable && cursor.is_over(header_bounds)) 
                     || cursor.is_over(icon_bounds))
-                    && !cursor.is_over(action_bounds) {
-                    let now = Instant::now();
-                    let new_state = !state.animation.value();
-                    state.animation.go_mut(new_state, now);
-                    state.progress = state.animation.interpolate(0.0, 1.0, now);
-                    shell.invalidate_layout();
-                    shell.request_redraw();
-
-                    if let Some(ref on_toggle) = self.on_toggle {
-                        shell.publish(on_toggle(new_state));
-                    }
+                    && !cursor.is_over(action_bounds)
+                    && !is_over_trailing {
+                    flip(state, self.on_toggle.as_deref(), shell);
                 }
             }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(keyboard::key::Named::Enter | keyboard::key::Named::Space),
+                ..
+            }) if state.is_focused => {
+                flip(state, self.on_toggle.as_deref(), shell);
+                shell.capture_event();
+                return;
+            }
             Event::Mouse(mouse::Event::CursorMoved { .. }) => {
                 let is_over_header = if self.header_clickable {
                     cursor.is_over(header_bounds)
@@ -748,15 +1305,48 @@ where
             _ => {}
         }
 
+        // Forward events to the header slots
+        let (leading_index, trailing_index) = self.slot_indices();
+        if let (Some(leading), Some(index), Some(leading_layout)) =
+            (&mut self.leading, leading_index, leading_layout)
+        {
+            leading.as_widget_mut().update(
+                &mut tree.children[index],
+                event,
+                leading_layout,
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            );
+        }
+
+        if let (Some(trailing), Some(index), Some(trailing_layout)) =
+            (&mut self.trailing, trailing_index, trailing_layout)
+        {
+            trailing.as_widget_mut().update(
+                &mut tree.children[index],
+                event,
+                trailing_layout,
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            );
+        }
+
         // Forward events to content / children when expanded
         let (_, _, _, content_index) = self.child_indices();
         if state.progress > 0.0
             && let Some(content_layout) = content_layout {
+                let content_cursor = clipped_cursor(body_cursor, bounds, state);
                 self.content.as_widget_mut().update(
                     &mut tree.children[content_index],
                     event,
                     content_layout,
-                    cursor,
+                    content_cursor,
                     renderer,
                     clipboard,
                     shell,
@@ -790,12 +1380,18 @@ where
 
         let style = theme.style(&self.class, status);
 
+        // The header is laid out at full width, horizontal sections reveal it with the content
+        let clip_horizontal = self.direction == Direction::Horizontal;
+        if clip_horizontal {
+            renderer.start_layer(bounds);
+        }
+
         // Draw shadow
         if style.shadow.color.a > 0.0 {
             renderer.fill_quad(
                 renderer::Quad {
-                    bounds,
-                    border: style.border,
+                    bounds: pixel::snap(bounds),
+                    border: pixel::border(style.border),
                     shadow: style.shadow,
                     snap: false,
                 },
@@ -810,56 +1406,12 @@ where
             height: state.header_height,
         };
 
-        // Get layout children: icon, title, content
-        let mut layout_children = layout.children();
-        let icon_layout = layout_children.next().unwrap();
-        let title_layout = layout_children.next().unwrap();
-        let action_layout = layout_children.next().unwrap();
-        let content_layout_opt = layout_children.next();
-
-        let content_bounds = if state.progress > 0.0 {
-            content_layout_opt.map(|l| {
-                let full_bounds = l.bounds();
-                let animated_height = full_bounds.height * state.progress;
-                Rectangle {
-                    x: bounds.x,
-                    y: bounds.y + state.header_height,
-                    width: bounds.width,
-                    height: animated_height,
-                }
-            })
-        } else {
-            None
-        };
+        // Content is the fourth layout child, after icon, title and action icon
+        let content_layout_opt = layout.children().nth(3);
 
-        let header_border = if state.progress > 0.0 {
-            Border {
-                radius: border::Radius {
-                    top_left: style.border.radius.top_left,
-                    top_right: style.border.radius.top_right,
-                    bottom_left: 0.0,
-                    bottom_right: 0.0,
-                },
-                ..style.border
-            }
-        } else {
-            style.border
-        };
-
-        // Draw header background
-        if style.header_background.is_some() || header_border.width > 0.0 {
-            renderer.fill_quad(
-                renderer::Quad {
-                    bounds: header_bounds,
-                    border: header_border,
-                    shadow: Shadow::default(),
-                    snap: false,
-                },
-                style
-                    .header_background
-                    .unwrap_or(Background::Color(Color::TRANSPARENT)),
-            );
-        }
+        // The node height already follows the animation, the body is whatever lies below the header
+        let content_bounds = (state.progress > 0.0 && content_layout_opt.is_some())
+            .then(|| animated_content_bounds(bounds, state));
 
         // Draw header shadow
         if state.progress > 0.0 && style.header_shadow.color.a > 0.0 {
@@ -872,7 +1424,7 @@ where
 
             renderer.fill_quad(
                 renderer::Quad {
-                    bounds: shadow_bounds,
+                    bounds: pixel::snap(shadow_bounds),
                     border: Border::default(),
                     shadow: style.header_shadow,
                     snap: false,
@@ -896,8 +1448,8 @@ where
             if style.content_background.is_some() || content_border.width > 0.0 {
                 renderer.fill_quad(
                     renderer::Quad {
-                        bounds: content_bounds,
-                        border: content_border,
+                        bounds: pixel::snap(content_bounds),
+                        border: pixel::border(content_border),
                         shadow: Shadow::default(),
                         snap: false,
                     },
@@ -908,103 +1460,99 @@ where
             }
         }
 
-        let (expand_child, collapse_child, action_child, content_child) = self.child_indices();
-
-        // Draw icon - either default text or custom Element
-        if self.expand_icon.is_none() && self.collapse_icon.is_none() {
-            // Draw default text icon using icon_text state
-            widget::text::draw(
-                renderer,
-                defaults,
-                icon_layout.bounds(),
-                combined_state.icon_text.raw(),
-                iced::widget::text::Style {
-                    color: style.title_text_color,
-                },
-                viewport,
-            );
-        } else {
-            // Draw custom icon Element
-            let (icon_element, icon_tree_index) = if state.animation.value() {
-                (self.collapse_icon.as_ref().unwrap(), collapse_child.unwrap())
-            } else {
-                (self.expand_icon.as_ref().unwrap(), expand_child.unwrap())
-            };
-            
-            icon_element.as_widget().draw(
-                &tree.children[icon_tree_index],
-                renderer,
-                theme,
-                defaults,
-                icon_layout,
-                cursor,
-                viewport,
-            );
-        }
-
-        // Draw title using layout bounds and text state
-        let text_color = style.title_text_color.unwrap_or(defaults.text_color);
-        
-        widget::text::draw(
-            renderer,
-            defaults,
-            title_layout.bounds(),
-            combined_state.text.raw(),
-            iced::widget::text::Style {
-                color: Some(text_color),
-            },
-            viewport,
-        );
+        let (_, _, _, content_child) = self.child_indices();
+        let (header_cursor, body_cursor) = self.split_cursor(cursor, bounds, state, viewport);
+        let sticky_offset = self.sticky_offset(bounds, state, viewport);
 
-        // Draw action icon if present (after drawing title, around line 854)
-        if let Some(ref action_icon) = self.action_icon {
-            action_icon.as_widget().draw(
-                &tree.children[action_child.unwrap()],
-                renderer,
-                theme,
-                defaults,
-                action_layout,
-                cursor,
-                viewport,
-            );
+        if sticky_offset == 0.0 {
+            self.draw_header(tree, renderer, theme, defaults, layout, header_cursor, viewport, &style);
         }
 
         // Draw content
         if state.progress > 0.0
             && let Some(content_layout) = content_layout_opt {
-                let full_content_height = content_layout.bounds().height;
-                let animated_height = full_content_height * state.progress;
-                
-                let clip_bounds = Rectangle {
-                    x: bounds.x,
-                    y: bounds.y + self.header_height,
-                    width: bounds.width,
-                    height: animated_height,
-                };
+                // Content keeps its full layout and is revealed through the animated clip
+                let clip_bounds = animated_content_bounds(bounds, state);
+
+                // Guide line centered in the indent, like the tree widget's depth lines
+                if self.guides && self.indent > 0.0
+                    && let Some(guide_color) = style.guide_color
+                    && let Some(guide_bounds) = clip_bounds.intersection(&self.resolved_direction().mirror(
+                        Rectangle {
+                            x: bounds.x + self.content_padding.left + (self.indent - crate::depth::GUIDE_WIDTH) / 2.0,
+                            y: content_layout.bounds().y,
+                            width: crate::depth::GUIDE_WIDTH,
+                            height: content_layout.bounds().height,
+                        },
+                        bounds,
+                    ))
+                {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: pixel::snap(guide_bounds),
+                            border: Border::default(),
+                            shadow: Shadow::default(),
+                            snap: true,
+                        },
+                        guide_color,
+                    );
+                }
 
-                // Use intersection of clip_bounds with parent viewport
-                let effective_clip = if let Some(clipped) = viewport.intersection(&clip_bounds) {
-                    clipped
-                } else {
-                    return; // Not visible at all, don't draw
+                // Use intersection of clip_bounds with parent viewport, skip if not visible at all
+                if let Some(effective_clip) = viewport.intersection(&clip_bounds) {
+                    renderer.with_layer(effective_clip, |renderer| {
+                        self.content.as_widget().draw(
+                            &tree.children[content_child],
+                            renderer,
+                            theme,
+                            &renderer::Style {
+                                text_color: style
+                                    .content_text_color
+                                    .unwrap_or(defaults.text_color),
+                            },
+                            content_layout,
+                            clipped_cursor(body_cursor, bounds, state),
+                            &effective_clip,
+                        );
+                    });
+                }
+            }
+
+        // Draw the pinned header on top of the content scrolling underneath
+        if sticky_offset > 0.0 {
+            let pinned_header = Rectangle {
+                x: bounds.x,
+                y: bounds.y + sticky_offset,
+                width: bounds.width,
+                height: state.header_height,
+            };
+
+            if let Some(clip) = viewport.intersection(&pinned_header) {
+                let header_viewport = Rectangle {
+                    y: viewport.y - sticky_offset,
+                    ..*viewport
                 };
 
-                renderer.with_layer(effective_clip, |renderer| {
-                    self.content.as_widget().draw(
-                        &tree.children[content_child],
-                        renderer,
-                        theme,
-                        &renderer::Style {
-                            text_color: style
-                                .content_text_color
-                                .unwrap_or(defaults.text_color),
-                        },
-                        content_layout,
-                        cursor,
-                        &effective_clip,
-                    );
+                renderer.with_layer(clip, |renderer| {
+                    renderer.with_translation(Vector::new(0.0, sticky_offset), |renderer| {
+                        self.draw_header(
+                            tree,
+                            renderer,
+                            theme,
+                            defaults,
+                            layout,
+                            header_cursor,
+                            &header_viewport,
+                            &style,
+                        );
+                    });
                 });
             }
+        }
+
+        if clip_horizontal {
+            renderer.end_layer();
+        }
     }
 
     fn mouse_interaction(
@@ -1026,12 +1574,16 @@ where
             height: state.header_height,
         };
 
+        let (cursor, body_cursor) = self.split_cursor(cursor, bounds, state, viewport);
+
         // Get icon bounds from first layout child
         let mut children = layout.children();
         let icon_layout = children.next().unwrap();
         let _title_layout = children.next();
         let action_layout = children.next().unwrap();
         let content_layout = children.next();
+        let leading_layout = children.next();
+        let trailing_layout = children.next();
 
         let icon_bounds = icon_layout.bounds();
         let action_bounds = action_layout.bounds();
@@ -1040,6 +1592,31 @@ where
             return mouse::Interaction::Pointer;
         }
 
+        let (leading_index, trailing_index) = self.slot_indices();
+        for (element, index, slot_layout) in [
+            (&self.trailing, trailing_index, trailing_layout),
+            (&self.leading, leading_index, leading_layout),
+        ] {
+            if let (Some(element), Some(index), Some(slot_layout)) = (element, index, slot_layout) {
+                let interaction = element.as_widget().mouse_interaction(
+                    &tree.children[index],
+                    slot_layout,
+                    cursor,
+                    viewport,
+                    renderer,
+                );
+
+                if interaction != mouse::Interaction::default() {
+                    return interaction;
+                }
+            }
+        }
+
+        // The trailing slot never toggles, so it never shows the header pointer
+        if self.trailing.is_some() && trailing_layout.is_some_and(|layout| cursor.is_over(layout.bounds())) {
+            return mouse::Interaction::default();
+        }
+
         let is_over_clickable = if self.header_clickable {
             cursor.is_over(header_bounds)
         } else {
@@ -1054,7 +1631,7 @@ where
                 self.content.as_widget().mouse_interaction(
                     &tree.children[content_index],
                     content_layout,
-                    cursor,
+                    clipped_cursor(body_cursor, bounds, state),
                     viewport,
                     renderer,
                 )
@@ -1073,8 +1650,15 @@ where
         renderer: &Renderer,
         operation: &mut dyn widget::Operation,
     ) {
-        let combined_state = tree.state.downcast_ref::<CombinedState<Renderer::Paragraph>>();
-        let state = &combined_state.animation;
+        let combined_state = tree.state.downcast_mut::<CombinedState<Renderer::Paragraph>>();
+        let state = &mut combined_state.animation;
+        let header_bounds = Rectangle {
+            height: state.header_height,
+            ..layout.bounds()
+        };
+
+        operation.custom(self.id.as_ref(), header_bounds, state);
+        operation.focusable(self.id.as_ref(), header_bounds, state);
         
         if state.progress > 0.0 {
             let (_, _, _, content_index) = self.child_indices();
@@ -1112,9 +1696,34 @@ where
         let _title_layout = children.next();  
         let action_layout = children.next().unwrap();
         let content_layout = children.next();
+        let leading_layout = children.next();
+        let trailing_layout = children.next();
 
         // Split tree.children to get non-overlapping mutable slices
         let (left_tree, right_tree) = tree.children.split_at_mut(content_index);
+        let (content_tree, slot_trees) = right_tree.split_first_mut().unwrap();
+        let mut slot_trees = slot_trees.iter_mut();
+
+        // Header slots can open overlays too, e.g. a dropdown action button
+        for (element, slot_layout) in [
+            (&mut self.leading, leading_layout),
+            (&mut self.trailing, trailing_layout),
+        ] {
+            if let Some(element) = element {
+                let slot_tree = slot_trees.next().unwrap();
+
+                if let Some(slot_layout) = slot_layout
+                    && let Some(overlay) = element.as_widget_mut().overlay(
+                        slot_tree,
+                        slot_layout,
+                        renderer,
+                        viewport,
+                        translation,
+                    ) {
+                        return Some(overlay);
+                    }
+            }
+        }
         
         // Check action_icon for overlay first (if present)
         if let Some(ref mut action_icon) = self.action_icon
@@ -1133,7 +1742,7 @@ where
         if state.progress > 0.0
             && let Some(content_layout) = content_layout {
                 return self.content.as_widget_mut().overlay(
-                    &mut right_tree[0],
+                    content_tree,
                     content_layout,
                     renderer,
                     viewport,
@@ -1145,6 +1754,135 @@ where
     }
 }
 
+/// Flips the expanded state, starting the animation and publishing `on_toggle`.
+fn flip<Message>(
+    state: &mut State,
+    on_toggle: Option<&dyn Fn(bool) -> Message>,
+    shell: &mut Shell<'_, Message>,
+) {
+    let now = Instant::now();
+    let new_state = !state.animation.value();
+    state.animation.go_mut(new_state, now);
+    state.progress = state.animation.interpolate(0.0, 1.0, now);
+    shell.invalidate_layout();
+    shell.request_redraw();
+
+    if let Some(on_toggle) = on_toggle {
+        shell.publish(on_toggle(new_state));
+    }
+}
+
+/// Expands the collapsible with the given Id, without publishing `on_toggle`.
+pub fn expand<T>(id: widget::Id) -> impl widget::Operation<T> {
+    set_expanded(id, |_| true)
+}
+
+/// Collapses the collapsible with the given Id, without publishing `on_toggle`.
+pub fn collapse<T>(id: widget::Id) -> impl widget::Operation<T> {
+    set_expanded(id, |_| false)
+}
+
+/// Toggles the collapsible with the given Id, without publishing `on_toggle`.
+pub fn toggle<T>(id: widget::Id) -> impl widget::Operation<T> {
+    set_expanded(id, |expanded| !expanded)
+}
+
+/// Animates the section with `id` to the state `f` returns for its current one.
+fn set_expanded<T>(id: widget::Id, f: fn(bool) -> bool) -> impl widget::Operation<T> {
+    operation::on_state(id, move |state: &mut State| {
+        let expanded = f(state.animation.value());
+        if expanded != state.animation.value() {
+            state.animation.go_mut(expanded, Instant::now());
+            state.was_animating = true;
+        }
+    })
+}
+
+/// Draws the built-in chevron pointing right, rotated clockwise by `angle` radians.
+/// 
+/// The strokes are made of small round quads, since the renderer can not rotate glyphs.
+/// A `mirrored` chevron points left and turns the other way, ending up pointing down all the same.
+fn draw_chevron<Renderer: renderer::Renderer>(
+    renderer: &mut Renderer,
+    bounds: Rectangle,
+    angle: f32,
+    mirrored: bool,
+    color: Color,
+) {
+    let flip = if mirrored { -1.0 } else { 1.0 };
+    let center = bounds.center();
+    let size = bounds.width.min(bounds.height) * 0.5;
+    let thickness = (size * 0.15).max(1.5);
+    let (sin, cos) = angle.sin_cos();
+    let rotate = |x: f32, y: f32| Point::new(
+        center.x + flip * (x * cos - y * sin),
+        center.y + x * sin + y * cos,
+    );
+
+    let tip = rotate(size * 0.25, 0.0);
+    for arm in [rotate(-size * 0.25, -size * 0.5), rotate(-size * 0.25, size * 0.5)] {
+        let steps = (arm.distance(tip) / (thickness / 3.0)).ceil().max(1.0) as usize;
+
+        for step in 0..=steps {
+            let t = step as f32 / steps as f32;
+            let dot = Point::new(arm.x + (tip.x - arm.x) * t, arm.y + (tip.y - arm.y) * t);
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: pixel::snap(Rectangle {
+                        x: dot.x - thickness / 2.0,
+                        y: dot.y - thickness / 2.0,
+                        width: thickness,
+                        height: thickness,
+                    }),
+                    border: pixel::border(border::rounded(thickness / 2.0)),
+                    shadow: Shadow::default(),
+                    snap: false,
+                },
+                color,
+            );
+        }
+    }
+}
+
+/// The header border, squared off at the bottom while the content is visible.
+fn header_border(style: &Style, state: &State) -> Border {
+    if state.progress > 0.0 {
+        Border {
+            radius: border::Radius {
+                top_left: style.border.radius.top_left,
+                top_right: style.border.radius.top_right,
+                bottom_left: 0.0,
+                bottom_right: 0.0,
+            },
+            ..style.border
+        }
+    } else {
+        style.border
+    }
+}
+
+/// The visible part of the content area below the header, shrunk by the animation.
+fn animated_content_bounds(bounds: Rectangle, state: &State) -> Rectangle {
+    Rectangle {
+        x: bounds.x,
+        y: bounds.y + state.header_height,
+        width: bounds.width,
+        height: (bounds.height - state.header_height).max(0.0),
+    }
+}
+
+/// Hides the cursor from the content while it is over the clipped-away part.
+///
+/// Once fully expanded the cursor is passed through untouched so drags can leave the widget.
+fn clipped_cursor(cursor: mouse::Cursor, bounds: Rectangle, state: &State) -> mouse::Cursor {
+    if state.progress >= 1.0 || cursor.is_over(animated_content_bounds(bounds, state)) {
+        cursor
+    } else {
+        mouse::Cursor::Unavailable
+    }
+}
+
 // ============================================================================
 // COLLAPSIBLE GROUP - Accordion Container
 // ============================================================================
@@ -1160,6 +1898,10 @@ where
     width: Length,
     height: Length,
     spacing: f32,
+    on_change: Option<Box<dyn Fn(Option<usize>) -> Message + 'a>>,
+    on_reorder: Option<Box<dyn Fn(Vec<usize>) -> Message + 'a>>,
+    on_state_change: Option<Box<dyn Fn(OpenSections) -> Message + 'a>>,
+    initial_state: Option<OpenSections>,
 }
 
 impl<'a, Message, Theme, Renderer> CollapsibleGroup<'a, Message, Theme, Renderer>
@@ -1176,9 +1918,53 @@ where
             width: Length::Fill,
             height: Length::Shrink,
             spacing: 0.0,
+            on_change: None,
+            on_reorder: None,
+            on_state_change: None,
+            initial_state: None,
         }
     }
 
+    /// Restores the open sections from a snapshot when the group is first shown.
+    /// 
+    /// Sections are matched by their [`Collapsible::key`].
+    pub fn with_state(mut self, state: OpenSections) -> Self {
+        self.initial_state = Some(state);
+        self
+    }
+
+    /// Sets the message produced with a new snapshot whenever the open sections change.
+    pub fn on_state_change(
+        mut self,
+        on_state_change: impl Fn(OpenSections) -> Message + 'a,
+    ) -> Self {
+        self.on_state_change = Some(Box::new(on_state_change));
+        self
+    }
+
+    /// Sets the message produced when the open section changes.
+    /// 
+    /// Receives the index of the newly opened section, or `None` when all are closed.
+    pub fn on_change(
+        mut self,
+        on_change: impl Fn(Option<usize>) -> Message + 'a,
+    ) -> Self {
+        self.on_change = Some(Box::new(on_change));
+        self
+    }
+
+    /// Enables reordering sections by dragging their headers.
+    /// 
+    /// Receives the new order as indices into the current items, e.g. `[1, 0, 2]`
+    /// after dragging the second section above the first.
+    pub fn on_reorder(
+        mut self,
+        on_reorder: impl Fn(Vec<usize>) -> Message + 'a,
+    ) -> Self {
+        self.on_reorder = Some(Box::new(on_reorder));
+        self
+    }
+
     /// Sets the width of the group.
     pub fn width(mut self, width: impl Into<Length>) -> Self {
         self.width = width.into();
@@ -1203,13 +1989,80 @@ where
 #[derive(Default)]
 struct GroupState {
     expanded_index: Option<usize>,
+    drag: Option<SectionDrag>,
+    /// Snapshot to apply on the first layout, once the section keys are known
+    pending_restore: Option<OpenSections>,
 }
 
+/// The keys of the open sections of a [`CollapsibleGroup`].
+/// 
+/// A plain snapshot that can be stored with the app settings and passed back
+/// through [`CollapsibleGroup::with_state`] to restore the group.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OpenSections {
+    pub keys: BTreeSet<String>,
+}
+
+impl OpenSections {
+    /// Creates a snapshot with the given open section keys.
+    pub fn new(keys: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self {
+            keys: keys.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// Returns whether the section with the given key is open.
+    pub fn contains(&self, key: &str) -> bool {
+        self.keys.contains(key)
+    }
+}
+
+/// Collects the keys of the open sections from the children of a group.
+fn open_sections<P: text::Paragraph + 'static>(children: &[Tree], expanded_index: Option<usize>) -> OpenSections {
+    OpenSections {
+        keys: expanded_index
+            .and_then(|index| children.get(index))
+            .and_then(|child| child.state.downcast_ref::<CombinedState<P>>().animation.key.clone())
+            .into_iter()
+            .collect(),
+    }
+}
+
+/// A header being dragged to reorder the sections of a group.
+#[derive(Debug, Clone, Copy)]
+struct SectionDrag {
+    reorder: Reorder,
+    /// Expanded section before the press toggled it, restored once the press becomes a drag
+    expanded_before: Option<usize>,
+}
+
+/// Opens the section at `index`, closing the others, or closes it if already open.
+fn toggle_section<Message>(
+    group_state: &mut GroupState,
+    index: usize,
+    on_change: Option<&dyn Fn(Option<usize>) -> Message>,
+    shell: &mut Shell<'_, Message>,
+) {
+    if group_state.expanded_index == Some(index) {
+        group_state.expanded_index = None;
+    } else {
+        group_state.expanded_index = Some(index);
+    }
+
+    if let Some(on_change) = on_change {
+        shell.publish(on_change(group_state.expanded_index));
+    }
+
+    // Trigger smooth simultaneous animation for all items
+    shell.invalidate_layout();
+    shell.request_redraw();
+}
 
 impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
     for CollapsibleGroup<'a, Message, Theme, Renderer>
 where
-    Message: 'a + Clone,
+    Message: 'a,
     Theme: Catalog + 'a,
     Renderer: text::Renderer + 'a,
 {
@@ -1218,7 +2071,10 @@ where
     }
 
     fn state(&self) -> tree::State {
-        tree::State::new(GroupState::default())
+        tree::State::new(GroupState {
+            pending_restore: self.initial_state.clone(),
+            ..GroupState::default()
+        })
     }
 
     fn children(&self) -> Vec<Tree> {
@@ -1245,6 +2101,18 @@ where
         let group_state = tree.state.downcast_mut::<GroupState>();
         let limits = limits.width(self.width).height(self.height);
 
+        // Restored sections open in place instead of animating
+        let restoring = if let Some(snapshot) = group_state.pending_restore.take() {
+            group_state.expanded_index = tree.children.iter().position(|child| {
+                child.state.downcast_ref::<CombinedState<Renderer::Paragraph>>()
+                    .animation.key.as_deref()
+                    .is_some_and(|key| snapshot.contains(key))
+            });
+            true
+        } else {
+            false
+        };
+
         let mut nodes = Vec::new();
         let mut y_offset = 0.0;
 
@@ -1262,7 +2130,12 @@ where
             // If state changed, trigger animation - always reset timer for simultaneous animations
             if child_state.animation.value() != should_be_expanded {
                 let now = Instant::now();
-                child_state.animation.go_mut(should_be_expanded, now);
+                let start = if restoring {
+                    now.checked_sub(Duration::from_secs(60)).unwrap_or(now)
+                } else {
+                    now
+                };
+                child_state.animation.go_mut(should_be_expanded, start);
                 child_state.progress = child_state.animation.interpolate(0.0, 1.0, now);
             }
             header_heights.push(child_state.header_height);
@@ -1298,6 +2171,112 @@ where
         viewport: &Rectangle,
     ) {
         let group_state = tree.state.downcast_mut::<GroupState>();
+        let expanded_before = group_state.expanded_index;
+
+        // Keyboard navigation between the headers of the group
+        if let Event::Keyboard(keyboard::Event::KeyPressed {
+            key: keyboard::Key::Named(named),
+            ..
+        }) = event
+            && let Some(focused) = tree.children.iter().position(|child| {
+                child.state.downcast_ref::<CombinedState<Renderer::Paragraph>>().animation.is_focused
+            })
+        {
+            match named {
+                keyboard::key::Named::ArrowUp | keyboard::key::Named::ArrowDown => {
+                    let target = if *named == keyboard::key::Named::ArrowUp {
+                        focused.saturating_sub(1)
+                    } else {
+                        (focused + 1).min(tree.children.len() - 1)
+                    };
+
+                    for (index, child) in tree.children.iter_mut().enumerate() {
+                        child.state.downcast_mut::<CombinedState<Renderer::Paragraph>>()
+                            .animation.is_focused = index == target;
+                    }
+
+                    shell.capture_event();
+                    shell.request_redraw();
+                    return;
+                }
+                // The focused child toggles itself, the group keeps the others in sync
+                keyboard::key::Named::Enter | keyboard::key::Named::Space => {
+                    toggle_section(group_state, focused, self.on_change.as_deref(), shell);
+                }
+                _ => {}
+            }
+        }
+
+        // Dragging a header reorders the sections
+        if let Some(mut drag) = group_state.drag {
+            match event {
+                Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                    let sections = layout.children().map(|child| child.bounds());
+
+                    if drag.reorder.drag_to(*position, *position, sections, Axis::Vertical) {
+                        // A drag is not a click, undo the toggle from the press
+                        if group_state.expanded_index != drag.expanded_before {
+                            group_state.expanded_index = drag.expanded_before;
+                            if let Some(ref on_change) = self.on_change {
+                                shell.publish(on_change(group_state.expanded_index));
+                            }
+                            shell.invalidate_layout();
+                        }
+                    }
+
+                    if drag.reorder.is_active() {
+                        group_state.drag = Some(drag);
+
+                        if group_state.expanded_index != expanded_before
+                            && let Some(ref on_state_change) = self.on_state_change
+                        {
+                            shell.publish(on_state_change(open_sections::<Renderer::Paragraph>(
+                                &tree.children,
+                                group_state.expanded_index,
+                            )));
+                        }
+
+                        shell.capture_event();
+                        shell.request_redraw();
+                        return;
+                    }
+
+                    group_state.drag = Some(drag);
+                }
+                Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                    group_state.drag = None;
+
+                    if drag.reorder.is_active() {
+                        if let Some(order) = drag.reorder.release(self.items.len()) {
+                            // Move the child states along, so they match once the items are reordered
+                            reorder::apply(&mut tree.children, &order);
+                            group_state.expanded_index = group_state.expanded_index
+                                .and_then(|expanded| order.iter().position(|&index| index == expanded));
+
+                            if let Some(ref on_reorder) = self.on_reorder {
+                                shell.publish(on_reorder(order));
+                            }
+                            shell.invalidate_layout();
+                        }
+
+                        shell.capture_event();
+                        shell.request_redraw();
+                        return;
+                    }
+                }
+                Event::Keyboard(keyboard::Event::KeyPressed {
+                    key: keyboard::Key::Named(keyboard::key::Named::Escape),
+                    ..
+                }) if drag.reorder.is_active() => {
+                    drag.reorder.cancel();
+                    group_state.drag = None;
+                    shell.capture_event();
+                    shell.request_redraw();
+                    return;
+                }
+                _ => {}
+            }
+        }
 
         // Check if any child was clicked
         for (index, ((item, child_tree), child_layout)) in self.items.iter_mut()
@@ -1321,24 +2300,26 @@ where
                             let _icon = child_children.next(); 
                             let _title = child_children.next();
                             let action_layout = child_children.next();
+                            let _content = child_children.next();
+                            let _leading = child_children.next();
+                            let trailing_layout = child_children.next();
                             
-                            let is_over_action = if let Some(action_layout) = action_layout {
-                                cursor.is_over(action_layout.bounds())
-                            } else {
-                                false
-                            };
+                            let is_over_action = [action_layout, trailing_layout]
+                                .into_iter()
+                                .flatten()
+                                .any(|layout| cursor.is_over(layout.bounds()));
                             
-                            // Only toggle if not clicking action icon
+                            // Only toggle if not clicking action icon or trailing slot
                             if !is_over_action {
-                                if group_state.expanded_index == Some(index) {
-                                    group_state.expanded_index = None;
-                                } else {
-                                    group_state.expanded_index = Some(index);
+                                let expanded_before = group_state.expanded_index;
+                                toggle_section(group_state, index, self.on_change.as_deref(), shell);
+
+                                if self.on_reorder.is_some() {
+                                    group_state.drag = Some(SectionDrag {
+                                        reorder: Reorder::new(dnd::SECTION, index, pos),
+                                        expanded_before,
+                                    });
                                 }
-                                
-                                // Trigger smooth simultaneous animation for all items
-                                shell.invalidate_layout();
-                                shell.request_redraw();
                             }
                         }
                     }
@@ -1356,6 +2337,15 @@ where
                 viewport,
             );
         }
+
+        if group_state.expanded_index != expanded_before
+            && let Some(ref on_state_change) = self.on_state_change
+        {
+            shell.publish(on_state_change(open_sections::<Renderer::Paragraph>(
+                &tree.children,
+                group_state.expanded_index,
+            )));
+        }
     }
 
     fn draw(
@@ -1382,6 +2372,38 @@ where
                 viewport,
             );
         }
+
+        // Drop indicator in the gap the dragged section would move to
+        let group_state = tree.state.downcast_ref::<GroupState>();
+        if let Some(drag) = group_state.drag
+            && drag.reorder.is_active()
+        {
+            let sections: Vec<Rectangle> = layout.children().map(|child| child.bounds()).collect();
+            let y = reorder::gap_position(&sections, drag.reorder.target, Axis::Vertical);
+
+            let style = theme.style(&<Theme as Catalog>::default(), Status::Active);
+            if let (Some(y), Some(color)) = (y, style.drop_indicator_color) {
+                let bounds = layout.bounds();
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: pixel::snap(Rectangle {
+                            x: bounds.x,
+                            y: y - 2.0,
+                            width: bounds.width,
+                            height: 4.0,
+                        }),
+                        border: pixel::border(Border {
+                            color,
+                            width: 1.0,
+                            radius: border::Radius::from(2.0),
+                        }),
+                        shadow: Shadow::default(),
+                        snap: true,
+                    },
+                    color.scale_alpha(0.5),
+                );
+            }
+        }
     }
 
     fn mouse_interaction(
@@ -1392,6 +2414,10 @@ where
         viewport: &Rectangle,
         renderer: &Renderer,
     ) -> mouse::Interaction {
+        if tree.state.downcast_ref::<GroupState>().drag.is_some_and(|drag| drag.reorder.is_active()) {
+            return mouse::Interaction::Grabbing;
+        }
+
         self.items.iter()
             .zip(&tree.children)
             .zip(layout.children())
@@ -1450,7 +2476,7 @@ where
 impl<'a, Message, Theme, Renderer> From<Collapsible<'a, Message, Theme, Renderer>>
     for Element<'a, Message, Theme, Renderer>
 where
-    Message: 'a + Clone,
+    Message: 'a,
     Theme: Catalog + 'a,
     Renderer: text::Renderer + 'a,
 {
@@ -1464,7 +2490,7 @@ where
 impl<'a, Message, Theme, Renderer> From<CollapsibleGroup<'a, Message, Theme, Renderer>>
     for Element<'a, Message, Theme, Renderer>
 where
-    Message: 'a + Clone,
+    Message: 'a,
     Theme: Catalog + 'a,
     Renderer: text::Renderer + 'a,
 {
@@ -1475,6 +2501,36 @@ where
     }
 }
 
+/// Which end of the header the chevron of a [`Collapsible`] is placed at.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ChevronPosition {
+    /// Before the title.
+    #[default]
+    Start,
+    /// After the title and header actions.
+    End,
+}
+
+/// How the chevron of a [`Collapsible`] changes between states.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ChevronAnimation {
+    /// Swaps between the expand and collapse icons.
+    #[default]
+    Swap,
+    /// Rotates the built-in chevron along with the collapse animation.
+    Rotate,
+}
+
+/// The direction a [`Collapsible`] collapses in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Direction {
+    /// Animates the height, the header stays visible on top.
+    #[default]
+    Vertical,
+    /// Animates the width, shrinking the header to an icon strip.
+    Horizontal,
+}
+
 /// The possible statuses of a [`Collapsible`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Status {
@@ -1494,6 +2550,12 @@ pub struct Style {
     pub border: Border,
     pub shadow: Shadow,
     pub header_shadow: Shadow,
+    /// Outline drawn around the header while it has keyboard focus.
+    pub focus_outline: Option<Color>,
+    /// Color of the guide line drawn next to indented content.
+    pub guide_color: Option<Color>,
+    /// Color of the indicator shown where a dragged section will be dropped.
+    pub drop_indicator_color: Option<Color>,
 }
 
 
@@ -1518,17 +2580,42 @@ impl Catalog for iced::Theme {
     }
 }
 
+/// The default collapsible style.
 pub fn default(theme: &iced::Theme, _status: Status) -> Style {
-    let palette = theme.extended_palette();
+    from_palette(Palette::new(theme, Preset::Standard))
+}
+
+/// The [`Preset::Compact`] collapsible style.
+pub fn compact(theme: &iced::Theme, _status: Status) -> Style {
+    from_palette(Palette::new(theme, Preset::Compact))
+}
+
+/// The [`Preset::Flat`] collapsible style.
+pub fn flat(theme: &iced::Theme, _status: Status) -> Style {
+    from_palette(Palette::new(theme, Preset::Flat))
+}
+
+/// The [`Preset::HighContrast`] collapsible style.
+pub fn high_contrast(theme: &iced::Theme, _status: Status) -> Style {
+    from_palette(Palette::new(theme, Preset::HighContrast))
+}
 
+fn from_palette(palette: Palette) -> Style {
     Style {
-        title_text_color: Some(palette.background.strong.text),
-        header_background: Some(palette.background.strong.color.into()),
-        content_text_color: Some(palette.background.weakest.text),
-        content_background: Some(palette.background.weakest.color.into()),
-        border: border::rounded(4),
+        title_text_color: Some(palette.strong_text),
+        header_background: Some(palette.strong.into()),
+        content_text_color: Some(palette.inset_text),
+        content_background: Some(palette.inset.into()),
+        border: Border {
+            color: palette.border,
+            width: palette.outline_width,
+            radius: palette.radius.into(),
+        },
         shadow: Shadow::default(),
         header_shadow: Shadow::default(),
+        focus_outline: Some(palette.accent),
+        guide_color: Some(palette.accent_weak),
+        drop_indicator_color: Some(palette.accent),
     }
 }
 
@@ -1543,6 +2630,9 @@ pub fn primary(theme: &iced::Theme, _status: Status) -> Style {
         border: iced::border::rounded(8),
         shadow: iced::Shadow::default(),
         header_shadow: iced::Shadow::default(),
+        focus_outline: Some(palette.primary.strong.color),
+        guide_color: Some(palette.primary.weak.color),
+        drop_indicator_color: Some(palette.primary.strong.color),
     }
 }
 
@@ -1558,6 +2648,9 @@ pub fn success(theme: &iced::Theme, _status: Status) -> Style {
         border: iced::border::rounded(8),
         shadow: iced::Shadow::default(),
         header_shadow: iced::Shadow::default(),
+        focus_outline: Some(palette.success.strong.color),
+        guide_color: Some(palette.success.weak.color),
+        drop_indicator_color: Some(palette.success.strong.color),
     }
 }
 
@@ -1572,6 +2665,9 @@ pub fn danger(theme: &iced::Theme, _status: Status) -> Style {
         border: iced::border::rounded(8),
         shadow: iced::Shadow::default(),
         header_shadow: iced::Shadow::default(),
+        focus_outline: Some(palette.danger.strong.color),
+        guide_color: Some(palette.danger.weak.color),
+        drop_indicator_color: Some(palette.danger.strong.color),
     }
 }
 
@@ -1586,5 +2682,8 @@ pub fn warning(theme: &iced::Theme, _status: Status) -> Style {
         border: iced::border::rounded(8),
         shadow: iced::Shadow::default(),
         header_shadow: iced::Shadow::default(),
+        focus_outline: Some(palette.warning.strong.color),
+        guide_color: Some(palette.warning.weak.color),
+        drop_indicator_color: Some(palette.warning.strong.color),
     }
 }
\ No newline at end of file
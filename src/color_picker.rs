@@ -11,6 +11,10 @@ use iced::{
 use std::time::{Duration, Instant};
 use std::cell::{RefCell, Cell};
 
+use crate::operation;
+use crate::pixel;
+use crate::profile::{self, Phase, Span};
+
 static mut ACTIVE_COLOR_PICKER: Option<*mut bool> = None;
 
 const HEADER_HEIGHT: f32 = 32.0;
@@ -40,6 +44,7 @@ pub struct ColorButton<'a, Message> {
     title: String,
     text: Option<String>,
     show_hex: bool,
+    id: Option<widget::Id>,
 }
 
 impl<'a, Message> ColorButton<'a, Message> {
@@ -57,9 +62,16 @@ impl<'a, Message> ColorButton<'a, Message> {
             title: "Color".to_string(),
             text: None,
             show_hex: false,
+            id: None,
         }
     }
 
+    /// Sets the [`widget::Id`] used to [`open`] or [`close`] the picker from `update`
+    pub fn id(mut self, id: impl Into<widget::Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
     /// Sets the title for the color picker overlay
     pub fn title(mut self, title: impl Into<String>) -> Self {
         self.title = title.into();
@@ -168,7 +180,7 @@ struct PaletteRow {
     tones: Vec<(&'static str, Tone)>, // (label, tone)
 }
 
-impl<'a, Message: Clone + 'a> Widget<Message, iced::Theme, Renderer> for ColorButton<'a, Message> {
+impl<'a, Message: 'a> Widget<Message, iced::Theme, Renderer> for ColorButton<'a, Message> {
     fn tag(&self) -> widget::tree::Tag {
         widget::tree::Tag::of::<State>()
     }
@@ -223,8 +235,8 @@ impl<'a, Message: Clone + 'a> Widget<Message, iced::Theme, Renderer> for ColorBu
         // Draw the color button
         renderer.fill_quad(
             renderer::Quad {
-                bounds,
-                border: Border {
+                bounds: pixel::snap(bounds),
+                border: pixel::border(Border {
                     color: if state.is_open { 
                         theme.palette().primary 
                     } else { 
@@ -232,7 +244,7 @@ impl<'a, Message: Clone + 'a> Widget<Message, iced::Theme, Renderer> for ColorBu
                     },
                     width: self.border_width,
                     radius: self.border_radius.into(),
-                },
+                }),
                 shadow: Shadow::default(),
                 snap: true,
             },
@@ -324,6 +336,17 @@ impl<'a, Message: Clone + 'a> Widget<Message, iced::Theme, Renderer> for ColorBu
         }
     }
 
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn widget::Operation,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+        operation.custom(self.id.as_ref(), layout.bounds(), state);
+    }
+
     fn overlay<'b>(
         &'b mut self,
         state: &'b mut Tree,
@@ -385,12 +408,34 @@ impl<'a, Message: Clone + 'a> Widget<Message, iced::Theme, Renderer> for ColorBu
     }
 }
 
-impl<'a, Message: Clone + 'a> From<ColorButton<'a, Message>> for Element<'a, Message, iced::Theme, Renderer> {
+impl<'a, Message: 'a> From<ColorButton<'a, Message>> for Element<'a, Message, iced::Theme, Renderer> {
     fn from(button: ColorButton<'a, Message>) -> Self {
         Self::new(button)
     }
 }
 
+/// Opens the picker of the color button with the given Id
+pub fn open<T>(id: widget::Id) -> impl widget::Operation<T> {
+    set_open(id, |_| true)
+}
+
+/// Closes the picker of the color button with the given Id
+pub fn close<T>(id: widget::Id) -> impl widget::Operation<T> {
+    set_open(id, |_| false)
+}
+
+/// Opens the picker of the color button with the given Id if it is closed, and closes it otherwise
+pub fn toggle<T>(id: widget::Id) -> impl widget::Operation<T> {
+    set_open(id, |is_open| !is_open)
+}
+
+fn set_open<T>(id: widget::Id, f: fn(bool) -> bool) -> impl widget::Operation<T> {
+    operation::on_state(id, move |state: &mut State| {
+        state.is_open = f(state.is_open);
+        state.overlay_state.palette_cache_dirty.set(true);
+    })
+}
+
 
 // Modern overlay implementation with tabs
 #[derive(Debug, Clone)]
@@ -595,10 +640,7 @@ struct ModernColorPickerOverlay<'a, Message> {
     viewport_size: Size,
 }
 
-impl<'a, Message> ModernColorPickerOverlay<'a, Message> 
-where
-    Message: Clone
-{
+impl<'a, Message> ModernColorPickerOverlay<'a, Message> {
     fn overlay(self) -> overlay::Element<'a, Message, iced::Theme, Renderer> {
         overlay::Element::new(Box::new(self))
     }
@@ -613,7 +655,7 @@ where
     }
 }
 
-impl<'a, Message: Clone> Overlay<Message, iced::Theme, Renderer> for ModernColorPickerOverlay<'a, Message> {
+impl<'a, Message> Overlay<Message, iced::Theme, Renderer> for ModernColorPickerOverlay<'a, Message> {
     fn layout(&mut self, _renderer: &Renderer, bounds: Size) -> Node {
         self.viewport_size = bounds;
         let size = Size::new(320.0, 440.0);
@@ -630,6 +672,7 @@ impl<'a, Message: Clone> Overlay<Message, iced::Theme, Renderer> for ModernColor
         layout: Layout<'_>,
         cursor: mouse::Cursor,
     ) {
+        let span = profile::span("color_picker", Phase::Draw);
         let bounds = layout.bounds();
         let header_bounds = header_rect(bounds);
         let close_bounds = close_button_rect(bounds);
@@ -638,12 +681,12 @@ impl<'a, Message: Clone> Overlay<Message, iced::Theme, Renderer> for ModernColor
         // Draw background with shadow
         renderer.fill_quad(
             renderer::Quad {
-                bounds,
-                border: Border {
+                bounds: pixel::snap(bounds),
+                border: pixel::border(Border {
                     color: theme.extended_palette().background.weak.color,
                     width: 1.0,
                     radius: 12.0.into(),
-                },
+                }),
                 shadow: Shadow {
                     color: Color::from_rgba(0.0, 0.0, 0.0, 0.3),
                     offset: Vector::new(0.0, 4.0),
@@ -653,17 +696,18 @@ impl<'a, Message: Clone> Overlay<Message, iced::Theme, Renderer> for ModernColor
             },
             theme.extended_palette().background.base.color,
         );
+        span.quad();
 
         // Draw header background
         renderer.fill_quad(
             renderer::Quad {
-                bounds: Rectangle {
+                bounds: pixel::snap(Rectangle {
                     x: header_bounds.x,
                     y: header_bounds.y,
                     width: header_bounds.width,
                     height: header_bounds.height,
-                },
-                border: Border {
+                }),
+                border: pixel::border(Border {
                     radius: iced::border::Radius {
                         top_left: 12.0, 
                         top_right: 12.0, 
@@ -671,7 +715,7 @@ impl<'a, Message: Clone> Overlay<Message, iced::Theme, Renderer> for ModernColor
                         bottom_left: 0.0
                     },
                     ..Default::default()
-                },
+                }),
                 shadow: Shadow::default(),
                 snap: true,
             },
@@ -683,12 +727,12 @@ impl<'a, Message: Clone> Overlay<Message, iced::Theme, Renderer> for ModernColor
             let alpha = (1.0 - (i as f32 / 4.0)) * 0.15; // Fade out
             renderer.fill_quad(
                 renderer::Quad {
-                    bounds: Rectangle {
+                    bounds: pixel::snap(Rectangle {
                         x: bounds.x + 1.0, // Inset by border width
                         y: header_bounds.y + header_bounds.height + i as f32,
                         width: bounds.width - 2.0, // Account for borders
                         height: 1.0,
-                    },
+                    }),
                     border: Border::default(),
                     shadow: Shadow::default(),
                     snap: true,
@@ -714,20 +758,22 @@ impl<'a, Message: Clone> Overlay<Message, iced::Theme, Renderer> for ModernColor
             theme.extended_palette().background.weak.text,
             header_bounds,
         );
+        span.quad();
 
         if cursor.is_over(close_bounds) {
             renderer.fill_quad(
                 renderer::Quad {
-                    bounds: close_bounds,
-                    border: Border {
+                    bounds: pixel::snap(close_bounds),
+                    border: pixel::border(Border {
                         radius: 15.0.into(),
                         ..Default::default()
-                    },
+                    }),
                     shadow: Shadow::default(),
                     snap: true,
                 },
                 Color::from_rgba(0.0, 0.0, 0.0, 0.1),
             );
+            span.quad();
         }
 
         renderer.fill_text(
@@ -761,14 +807,15 @@ impl<'a, Message: Clone> Overlay<Message, iced::Theme, Renderer> for ModernColor
             let is_hovered = cursor.is_over(*tab_bounds);
             renderer.fill_quad(
                 renderer::Quad {
-                    bounds: *tab_bounds,
-                    border: Border { width: 1.0, radius: 8.0.into(), ..Default::default() },
+                    bounds: pixel::snap(*tab_bounds),
+                    border: pixel::border(Border { width: 1.0, radius: 8.0.into(), ..Default::default() }),
                     ..Default::default()
                 },
                 if is_active { theme.extended_palette().primary.base.color }
                 else if is_hovered { theme.extended_palette().background.weak.color }
                 else { Color::TRANSPARENT },
             );
+            span.quad();
             renderer.fill_text(
                 iced::advanced::Text {
                     content: label.to_string(),
@@ -788,10 +835,10 @@ impl<'a, Message: Clone> Overlay<Message, iced::Theme, Renderer> for ModernColor
         }
 
         match self.overlay_state.active_tab {
-            ColorPickerTab::Grid => self.draw_grid_tab(renderer, theme, content_bounds, cursor),
-            ColorPickerTab::Spectrum => self.draw_spectrum_tab(renderer, theme, content_bounds, cursor),
-            ColorPickerTab::Sliders => self.draw_sliders_tab(renderer, theme, style, content_bounds),
-            ColorPickerTab::Palette => self.draw_palette_tab(renderer, theme, content_bounds, cursor),
+            ColorPickerTab::Grid => self.draw_grid_tab(renderer, theme, content_bounds, cursor, &span),
+            ColorPickerTab::Spectrum => self.draw_spectrum_tab(renderer, theme, content_bounds, cursor, &span),
+            ColorPickerTab::Sliders => self.draw_sliders_tab(renderer, theme, style, content_bounds, &span),
+            ColorPickerTab::Palette => self.draw_palette_tab(renderer, theme, content_bounds, cursor, &span),
         }
 
         if self.overlay_state.active_tab != ColorPickerTab::Palette {
@@ -820,8 +867,8 @@ impl<'a, Message: Clone> Overlay<Message, iced::Theme, Renderer> for ModernColor
 
                 renderer.fill_quad(
                     renderer::Quad {
-                        bounds: preset_bounds,
-                        border: Border {
+                        bounds: pixel::snap(preset_bounds),
+                        border: pixel::border(Border {
                             color: if is_hovered {
                                 theme.palette().primary
                             } else {
@@ -829,12 +876,13 @@ impl<'a, Message: Clone> Overlay<Message, iced::Theme, Renderer> for ModernColor
                             },
                             width: if is_hovered { 2.0 } else { 1.0 },
                             radius: 15.0.into(),
-                        },
+                        }),
                         shadow: Shadow::default(),
                         snap: true,
                     },
                     *color,
                 );
+                span.quad();
             }
 
             // Add button (+)
@@ -853,17 +901,18 @@ impl<'a, Message: Clone> Overlay<Message, iced::Theme, Renderer> for ModernColor
 
                 renderer.fill_quad(
                     renderer::Quad {
-                        bounds: add_preset_bounds,
-                        border: Border {
+                        bounds: pixel::snap(add_preset_bounds),
+                        border: pixel::border(Border {
                             color: Color::from_rgba(0.0, 0.0, 0.0, 0.2),
                             width: 1.0,
                             radius: 20.0.into(),
-                        },
+                        }),
                         shadow: Shadow::default(),
                         snap: true,
                     },
                     theme.extended_palette().background.weak.color,
                 );
+                span.quad();
 
                 renderer.fill_text(
                     iced::advanced::Text {
@@ -896,6 +945,7 @@ impl<'a, Message: Clone> Overlay<Message, iced::Theme, Renderer> for ModernColor
         clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
     ) {
+        let _span = profile::span("color_picker", Phase::Update);
         let bounds = layout.bounds();
         let header_bounds = header_rect(bounds);
         let close_bounds = close_button_rect(bounds);
@@ -1133,13 +1183,14 @@ impl<'a, Message: Clone> Overlay<Message, iced::Theme, Renderer> for ModernColor
     }
 }
 
-impl<'a, Message: Clone> ModernColorPickerOverlay<'a, Message> {
+impl<'a, Message> ModernColorPickerOverlay<'a, Message> {
         fn draw_grid_tab(
         &self,
         renderer: &mut Renderer,
         _theme: &iced::Theme,
         bounds: Rectangle,
         cursor: mouse::Cursor,
+        span: &Span,
     ) {
         let cell_size = bounds.width / 12.0;
         let rows = 8;
@@ -1167,8 +1218,8 @@ impl<'a, Message: Clone> ModernColorPickerOverlay<'a, Message> {
 
                 renderer.fill_quad(
                     renderer::Quad {
-                        bounds: cell_bounds,
-                        border: if is_hovered {
+                        bounds: pixel::snap(cell_bounds),
+                        border: pixel::border(if is_hovered {
                             Border {
                                 color: Color::WHITE,
                                 width: 2.0,
@@ -1176,12 +1227,13 @@ impl<'a, Message: Clone> ModernColorPickerOverlay<'a, Message> {
                             }
                         } else {
                             Border::default()
-                        },
+                        }),
                         shadow: Shadow::default(),
                         snap: true,
                     },
                     color,
                 );
+                span.quad();
             }
         }
 
@@ -1203,8 +1255,8 @@ impl<'a, Message: Clone> ModernColorPickerOverlay<'a, Message> {
 
             renderer.fill_quad(
                 renderer::Quad {
-                    bounds: cell_bounds,
-                    border: if is_hovered {
+                    bounds: pixel::snap(cell_bounds),
+                    border: pixel::border(if is_hovered {
                         Border {
                             color: if gray_value > 0.5 { Color::BLACK } else { Color::WHITE },
                             width: 2.0,
@@ -1212,12 +1264,13 @@ impl<'a, Message: Clone> ModernColorPickerOverlay<'a, Message> {
                         }
                     } else {
                         Border::default()
-                    },
+                    }),
                     shadow: Shadow::default(),
                     snap: true,
                 },
                 color,
             );
+            span.quad();
         }
     }
 
@@ -1227,6 +1280,7 @@ impl<'a, Message: Clone> ModernColorPickerOverlay<'a, Message> {
         _theme: &iced::Theme,
         bounds: Rectangle,
         _cursor: mouse::Cursor,
+        span: &Span,
     ) {
         // Draw HSV spectrum
         let spectrum_height = bounds.height - 30.0;
@@ -1248,18 +1302,19 @@ impl<'a, Message: Clone> ModernColorPickerOverlay<'a, Message> {
                 
                 renderer.fill_quad(
                     renderer::Quad {
-                        bounds: Rectangle {
+                        bounds: pixel::snap(Rectangle {
                             x: spectrum_bounds.x + x as f32,
                             y: spectrum_bounds.y + y as f32,
                             width: 1.0,
                             height: 1.0,
-                        },
+                        }),
                         border: Border::default(),
                         shadow: Shadow::default(),
                         snap: true,
                     },
                     color,
                 );
+                span.quad();
             }
         }
 
@@ -1269,41 +1324,43 @@ impl<'a, Message: Clone> ModernColorPickerOverlay<'a, Message> {
         
         renderer.fill_quad(
             renderer::Quad {
-                bounds: Rectangle {
+                bounds: pixel::snap(Rectangle {
                     x: indicator_x - 8.0,
                     y: indicator_y - 8.0,
                     width: 16.0,
                     height: 16.0,
-                },
-                border: Border {
+                }),
+                border: pixel::border(Border {
                     color: Color::WHITE,
                     width: 2.0,
                     radius: 8.0.into(),
-                },
+                }),
                 shadow: Shadow::default(),
                 snap: true,
             },
             Color::TRANSPARENT,
         );
+        span.quad();
 
         renderer.fill_quad(
             renderer::Quad {
-                bounds: Rectangle {
+                bounds: pixel::snap(Rectangle {
                     x: indicator_x - 6.0,
                     y: indicator_y - 6.0,
                     width: 12.0,
                     height: 12.0,
-                },
-                border: Border {
+                }),
+                border: pixel::border(Border {
                     color: Color::BLACK,
                     width: 1.0,
                     radius: 6.0.into(),
-                },
+                }),
                 shadow: Shadow::default(),
                 snap: true,
             },
             Color::TRANSPARENT,
         );
+        span.quad();
 
         // Draw hue slider
         let hue_y = spectrum_bounds.y + spectrum_bounds.height + 10.0;
@@ -1321,18 +1378,19 @@ impl<'a, Message: Clone> ModernColorPickerOverlay<'a, Message> {
             
             renderer.fill_quad(
                 renderer::Quad {
-                    bounds: Rectangle {
+                    bounds: pixel::snap(Rectangle {
                         x: hue_bounds.x + x as f32,
                         y: hue_bounds.y,
                         width: 1.0,
                         height: hue_bounds.height,
-                    },
+                    }),
                     border: Border::default(),
                     shadow: Shadow::default(),
                     snap: true,
                 },
                 color,
             );
+            span.quad();
         }
 
         // Draw hue indicator
@@ -1340,22 +1398,23 @@ impl<'a, Message: Clone> ModernColorPickerOverlay<'a, Message> {
         
         renderer.fill_quad(
             renderer::Quad {
-                bounds: Rectangle {
+                bounds: pixel::snap(Rectangle {
                     x: hue_indicator_x - 2.0,
                     y: hue_bounds.y - 2.0,
                     width: 4.0,
                     height: hue_bounds.height + 4.0,
-                },
-                border: Border {
+                }),
+                border: pixel::border(Border {
                     color: Color::WHITE,
                     width: 2.0,
                     radius: 2.0.into(),
-                },
+                }),
                 shadow: Shadow::default(),
                 snap: true,
             },
             Color::BLACK,
         );
+        span.quad();
     }
 
     fn draw_sliders_tab(
@@ -1364,6 +1423,7 @@ impl<'a, Message: Clone> ModernColorPickerOverlay<'a, Message> {
         theme: &iced::Theme,
         style: &renderer::Style,
         bounds: Rectangle,
+        span: &Span,
     ) {
         let slider_height = 30.0;
         let spacing = 35.0;
@@ -1415,16 +1475,17 @@ impl<'a, Message: Clone> ModernColorPickerOverlay<'a, Message> {
 
             renderer.fill_quad(
                 renderer::Quad {
-                    bounds: track_bounds,
-                    border: Border {
+                    bounds: pixel::snap(track_bounds),
+                    border: pixel::border(Border {
                         radius: 2.0.into(),
                         ..Default::default()
-                    },
+                    }),
                     shadow: Shadow::default(),
                     snap: true,
                 },
                 theme.extended_palette().background.weak.color,
             );
+            span.quad();
 
             // Slider fill
             let fill_bounds = Rectangle {
@@ -1436,16 +1497,17 @@ impl<'a, Message: Clone> ModernColorPickerOverlay<'a, Message> {
 
             renderer.fill_quad(
                 renderer::Quad {
-                    bounds: fill_bounds,
-                    border: Border {
+                    bounds: pixel::snap(fill_bounds),
+                    border: pixel::border(Border {
                         radius: 2.0.into(),
                         ..Default::default()
-                    },
+                    }),
                     shadow: Shadow::default(),
                     snap: true,
                 },
                 *color,
             );
+            span.quad();
 
             // Slider handle
             let handle_x = track_bounds.x + track_bounds.width * value;
@@ -1458,17 +1520,18 @@ impl<'a, Message: Clone> ModernColorPickerOverlay<'a, Message> {
 
             renderer.fill_quad(
                 renderer::Quad {
-                    bounds: handle_bounds,
-                    border: Border {
+                    bounds: pixel::snap(handle_bounds),
+                    border: pixel::border(Border {
                         color: theme.extended_palette().background.weak.color,
                         width: 2.0,
                         radius: 8.0.into(),
-                    },
+                    }),
                     shadow: Shadow::default(),
                     snap: true,
                 },
                 Color::WHITE,
             );
+            span.quad();
 
             // Value text
             let value_text = format!("{}", (*value * 255.0).round() as u8);
@@ -1507,12 +1570,12 @@ impl<'a, Message: Clone> ModernColorPickerOverlay<'a, Message> {
         // Draw chip
         renderer.fill_quad(
             renderer::Quad {
-                bounds: chip_bounds,
-                border: Border {
+                bounds: pixel::snap(chip_bounds),
+                border: pixel::border(Border {
                     color: theme.extended_palette().primary.base.color,
                     width: 0.0,
                     radius: 10.0.into(),
-                },
+                }),
                 shadow: Shadow {
                     color: theme.extended_palette().background.strong.color,
                     offset: Vector::new(0.0, 0.0),
@@ -1569,6 +1632,7 @@ impl<'a, Message: Clone> ModernColorPickerOverlay<'a, Message> {
             text_color,
             chip_bounds,
         );
+        span.quad();
 
         // Second, smaller line under it
         if !show_copied{
@@ -1597,6 +1661,7 @@ impl<'a, Message: Clone> ModernColorPickerOverlay<'a, Message> {
         theme: &iced::Theme,
         bounds: Rectangle,
         cursor: mouse::Cursor,
+        span: &Span,
     ) {
         // Refresh cache if needed
         if self.overlay_state.palette_cache.borrow().is_empty()
@@ -1667,6 +1732,7 @@ impl<'a, Message: Clone> ModernColorPickerOverlay<'a, Message> {
             for i in 0..2 {
                 let r = Rectangle { x, y, width: long_w, height: g.pill_h };
                 draw_pill(renderer, r, bg.tones[i].1, cursor.is_over(r), theme);
+                span.quad();
                 draw_pill_label(renderer, r, bg.tones[i].0, bg.tones[i].1.text);
                 x += long_w + g.col_gap;
             }
@@ -1679,6 +1745,7 @@ impl<'a, Message: Clone> ModernColorPickerOverlay<'a, Message> {
             for i in 2..5 {
                 let r = Rectangle { x, y, width: g.eq_w3, height: g.pill_h };
                 draw_pill(renderer, r, bg.tones[i].1, cursor.is_over(r), theme);
+                span.quad();
                 draw_pill_label(renderer, r, bg.tones[i].0, bg.tones[i].1.text);
                 x += g.eq_w3 + g.col_gap;
             }
@@ -1691,6 +1758,7 @@ impl<'a, Message: Clone> ModernColorPickerOverlay<'a, Message> {
             for i in 5..8 {
                 let r = Rectangle { x, y, width: g.eq_w3, height: g.pill_h };
                 draw_pill(renderer, r, bg.tones[i].1, cursor.is_over(r), theme);
+                span.quad();
                 draw_pill_label(renderer, r, bg.tones[i].0, bg.tones[i].1.text);
                 x += g.eq_w3 + g.col_gap;
             }
@@ -1711,6 +1779,7 @@ impl<'a, Message: Clone> ModernColorPickerOverlay<'a, Message> {
                 for i in 0..3 {
                     let r = Rectangle { x, y, width: g.eq_w3, height: g.pill_h };
                     draw_pill(renderer, r, row.tones[i].1, cursor.is_over(r), theme);
+                    span.quad();
                     draw_pill_label(renderer, r, row.tones[i].0, row.tones[i].1.text);
                     x += g.eq_w3 + g.col_gap;
                 }
@@ -2245,13 +2314,13 @@ fn build_palette_rows_compact(
 fn draw_pill(renderer: &mut Renderer, r: Rectangle, tone: Tone, hovered: bool, theme: &iced::Theme) {
     renderer.fill_quad(
         renderer::Quad {
-            bounds: r,
-            border: Border {
+            bounds: pixel::snap(r),
+            border: pixel::border(Border {
                 color: if hovered { theme.palette().primary }
                        else { Color::from_rgba(0.0,0.0,0.0,0.25) },
                 width: if hovered { 2.0 } else { 1.0 },
                 radius: 8.0.into(),
-            },
+            }),
             ..Default::default()
         },
         tone.color,
@@ -0,0 +1,911 @@
+//! A text field with a dropdown of options, filtered by what is typed.
+//!
+//! Clicking the field focuses it and opens the dropdown; typing narrows the options down to
+//! those containing the text, with the matched part highlighted. Up and Down move through the
+//! options, Enter picks one and Escape first closes the dropdown, then leaves the field.
+//!
+//! By default only options can be picked: Enter takes the highlighted option, or the one whose
+//! label was typed exactly, or the only one left. With [`Combobox::free_text`] any typed text
+//! can be submitted as well.
+
+use iced::{
+    advanced::{
+        clipboard,
+        layout::{Limits, Node},
+        mouse, overlay, renderer, text,
+        widget::{self, tree::Tree},
+        Clipboard, Layout, Overlay, Shell, Widget,
+    },
+    alignment, keyboard, Background, Border, Color, Element, Event, Length, Padding, Pixels,
+    Point, Rectangle, Shadow, Size, Vector,
+};
+use std::fmt;
+use std::ops::Range;
+
+use crate::operation;
+use crate::pixel;
+use crate::search;
+
+const ARROW_SIZE: f32 = 20.0;
+const CARET_WIDTH: f32 = 1.0;
+const MENU_ITEM_HEIGHT: f32 = 28.0;
+const MENU_PADDING: f32 = 4.0;
+const MENU_OFFSET: f32 = 2.0;
+const ITEM_PADDING: f32 = 8.0;
+const DEFAULT_MAX_VISIBLE: usize = 8;
+
+/// Creates a new [`Combobox`] over `options` with `selected` picked.
+pub fn combobox<'a, T, Message, Theme>(
+    options: &'a [T],
+    selected: Option<&'a T>,
+    on_select: impl Fn(T) -> Message + 'a,
+) -> Combobox<'a, T, Message, Theme>
+where
+    T: fmt::Display + Clone,
+    Theme: Catalog,
+{
+    Combobox::new(options, selected, on_select)
+}
+
+/// A searchable dropdown.
+#[allow(missing_debug_implementations)]
+pub struct Combobox<'a, T, Message, Theme = iced::Theme>
+where
+    Theme: Catalog,
+{
+    options: &'a [T],
+    labels: Vec<String>,
+    selected: Option<String>,
+    on_select: Box<dyn Fn(T) -> Message + 'a>,
+    on_input: Option<Box<dyn Fn(String) -> Message + 'a>>,
+    on_submit: Option<Box<dyn Fn(String) -> Message + 'a>>,
+    max_visible: usize,
+    placeholder: String,
+    width: Length,
+    padding: Padding,
+    text_size: Pixels,
+    font: iced::Font,
+    class: Theme::Class<'a>,
+    id: Option<widget::Id>,
+}
+
+impl<'a, T, Message, Theme> Combobox<'a, T, Message, Theme>
+where
+    T: fmt::Display + Clone,
+    Theme: Catalog,
+{
+    /// Creates a new [`Combobox`] over `options` with `selected` picked.
+    pub fn new(options: &'a [T], selected: Option<&'a T>, on_select: impl Fn(T) -> Message + 'a) -> Self {
+        Self {
+            options,
+            labels: options.iter().map(ToString::to_string).collect(),
+            selected: selected.map(ToString::to_string),
+            on_select: Box::new(on_select),
+            on_input: None,
+            on_submit: None,
+            max_visible: DEFAULT_MAX_VISIBLE,
+            placeholder: String::from("Search…"),
+            width: Length::Fill,
+            padding: Padding::from([6, 10]),
+            text_size: Pixels(14.0),
+            font: iced::Font::default(),
+            class: Theme::default(),
+            id: None,
+        }
+    }
+
+    /// Sets the [`widget::Id`] used to focus the field, or [`open`] and [`close`] its dropdown
+    /// from `update`.
+    pub fn id(mut self, id: impl Into<widget::Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Sets the message produced whenever the typed text changes.
+    pub fn on_input(mut self, on_input: impl Fn(String) -> Message + 'a) -> Self {
+        self.on_input = Some(Box::new(on_input));
+        self
+    }
+
+    /// Accepts text matching no option, submitted with Enter.
+    pub fn free_text(mut self, on_submit: impl Fn(String) -> Message + 'a) -> Self {
+        self.on_submit = Some(Box::new(on_submit));
+        self
+    }
+
+    /// Sets how many options the dropdown shows before scrolling.
+    pub fn max_visible(mut self, max_visible: usize) -> Self {
+        self.max_visible = max_visible.max(1);
+        self
+    }
+
+    /// Sets the text shown while nothing is typed or picked.
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Sets the width of the field.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the padding of the field.
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size.
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = size.into();
+        self
+    }
+
+    /// Sets the font.
+    pub fn font(mut self, font: iced::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style.
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class.
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Indices of the options containing the draft, with where it matched.
+    fn matching(&self, draft: &str) -> Vec<(usize, Option<Range<usize>>)> {
+        self.labels
+            .iter()
+            .enumerate()
+            .filter_map(|(index, label)| {
+                if draft.trim().is_empty() {
+                    Some((index, None))
+                } else {
+                    search::find_ignore_case(label, draft).map(|range| (index, Some(range)))
+                }
+            })
+            .collect()
+    }
+
+    /// Picks the option or submits the draft for Enter, returning whether anything was taken.
+    fn submit(&self, state: &mut State, shell: &mut Shell<'_, Message>) -> bool {
+        let matching = self.matching(&state.draft);
+        let exact = self
+            .labels
+            .iter()
+            .position(|label| label.to_lowercase() == state.draft.trim().to_lowercase());
+
+        let picked = state
+            .highlighted
+            .and_then(|index| matching.get(index))
+            .map(|(option, _)| *option)
+            .or(exact)
+            .or_else(|| (matching.len() == 1 && !state.draft.trim().is_empty()).then(|| matching[0].0));
+
+        match (picked, &self.on_submit) {
+            (Some(option), _) => {
+                self.pick(state, option, shell);
+                true
+            }
+            (None, Some(on_submit)) if !state.draft.trim().is_empty() => {
+                shell.publish(on_submit(state.draft.trim().to_string()));
+                state.close();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn pick(&self, state: &mut State, option: usize, shell: &mut Shell<'_, Message>) {
+        if let Some(value) = self.options.get(option) {
+            shell.publish((self.on_select)(value.clone()));
+        }
+        state.close();
+    }
+
+    fn set_draft(&self, state: &mut State, draft: String, shell: &mut Shell<'_, Message>) {
+        if draft != state.draft {
+            state.draft = draft;
+            state.highlighted = None;
+            state.offset = 0;
+            state.is_open = true;
+
+            if let Some(on_input) = &self.on_input {
+                shell.publish(on_input(state.draft.clone()));
+            }
+        }
+    }
+
+    fn text(&self, content: impl Into<String>, bounds: Size) -> iced::advanced::Text<String, iced::Font> {
+        iced::advanced::Text {
+            content: content.into(),
+            bounds,
+            size: self.text_size,
+            font: self.font,
+            align_x: text::Alignment::Left,
+            align_y: alignment::Vertical::Center,
+            line_height: text::LineHeight::default(),
+            shaping: text::Shaping::Advanced,
+            wrapping: text::Wrapping::None,
+        }
+    }
+
+    fn measure<P: text::Paragraph<Font = iced::Font>>(&self, content: &str) -> Size {
+        P::with_text(iced::advanced::Text {
+            content,
+            bounds: Size::INFINITE,
+            size: self.text_size,
+            font: self.font,
+            align_x: text::Alignment::Left,
+            align_y: alignment::Vertical::Top,
+            line_height: text::LineHeight::default(),
+            shaping: text::Shaping::Advanced,
+            wrapping: text::Wrapping::None,
+        })
+        .min_bounds()
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    draft: String,
+    is_focused: bool,
+    is_open: bool,
+    /// Index into the matching options
+    highlighted: Option<usize>,
+    /// First matching option shown in the dropdown
+    offset: usize,
+}
+
+impl State {
+    fn close(&mut self) {
+        self.draft.clear();
+        self.is_focused = false;
+        self.is_open = false;
+        self.highlighted = None;
+        self.offset = 0;
+    }
+
+    /// Scrolls so the highlighted option is in view.
+    fn reveal(&mut self, max_visible: usize) {
+        if let Some(index) = self.highlighted {
+            if index < self.offset {
+                self.offset = index;
+            } else if index >= self.offset + max_visible {
+                self.offset = index + 1 - max_visible;
+            }
+        }
+    }
+}
+
+/// Focuses the combobox with the given Id and opens its dropdown.
+pub fn open<T>(id: widget::Id) -> impl widget::Operation<T> {
+    operation::on_state(id, |state: &mut State| {
+        state.is_focused = true;
+        state.is_open = true;
+    })
+}
+
+/// Closes the dropdown of the combobox with the given Id and leaves the field.
+pub fn close<T>(id: widget::Id) -> impl widget::Operation<T> {
+    operation::on_state(id, State::close)
+}
+
+impl widget::operation::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.close();
+    }
+}
+
+impl<T, Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Combobox<'_, T, Message, Theme>
+where
+    T: fmt::Display + Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer<Font = iced::Font>,
+{
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(State::default())
+    }
+
+    fn layout(&mut self, _tree: &mut Tree, _renderer: &Renderer, limits: &Limits) -> Node {
+        let label = self.measure::<Renderer::Paragraph>(&self.placeholder);
+        let intrinsic = Size::new(
+            label.width + self.padding.x() + ARROW_SIZE,
+            label.height + self.padding.y(),
+        );
+
+        Node::new(limits.resolve(self.width, Length::Shrink, intrinsic))
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    // Clicks on the dropdown are captured by it first
+                    if state.is_focused {
+                        state.close();
+                        shell.request_redraw();
+                    }
+                    return;
+                };
+
+                let on_arrow = position.x >= bounds.x + bounds.width - self.padding.right - ARROW_SIZE;
+                state.is_focused = true;
+                state.is_open = !(on_arrow && state.is_open);
+                shell.capture_event();
+                shell.request_redraw();
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                shell.request_redraw();
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key, modifiers, text, ..
+            }) if state.is_focused => {
+                let count = self.matching(&state.draft).len();
+
+                match key {
+                    keyboard::Key::Named(keyboard::key::Named::Enter) => {
+                        self.submit(state, shell);
+                    }
+                    keyboard::Key::Named(keyboard::key::Named::Backspace) => {
+                        let mut draft = state.draft.clone();
+                        draft.pop();
+                        self.set_draft(state, draft, shell);
+                    }
+                    keyboard::Key::Named(keyboard::key::Named::ArrowDown) if count > 0 => {
+                        state.is_open = true;
+                        state.highlighted = Some(state.highlighted.map_or(0, |index| (index + 1) % count));
+                        state.reveal(self.max_visible);
+                    }
+                    keyboard::Key::Named(keyboard::key::Named::ArrowUp) if count > 0 => {
+                        state.is_open = true;
+                        state.highlighted =
+                            Some(state.highlighted.map_or(count - 1, |index| (index + count - 1) % count));
+                        state.reveal(self.max_visible);
+                    }
+                    keyboard::Key::Named(keyboard::key::Named::Escape) => {
+                        if state.is_open {
+                            state.is_open = false;
+                        } else {
+                            state.close();
+                        }
+                    }
+                    keyboard::Key::Character(c) if modifiers.command() && c.as_str() == "v" => {
+                        let Some(pasted) = clipboard.read(clipboard::Kind::Standard) else {
+                            return;
+                        };
+                        let pasted: String = pasted.chars().filter(|c| !c.is_control()).collect();
+                        self.set_draft(state, format!("{}{pasted}", state.draft), shell);
+                    }
+                    _ => {
+                        let Some(text) = text.as_ref().filter(|_| !modifiers.command()) else {
+                            return;
+                        };
+
+                        let typed: String = text.chars().filter(|c| !c.is_control()).collect();
+                        if typed.is_empty() {
+                            return;
+                        }
+                        self.set_draft(state, format!("{}{typed}", state.draft), shell);
+                    }
+                }
+
+                shell.capture_event();
+                shell.request_redraw();
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _defaults: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let status = if state.is_focused {
+            Status::Focused
+        } else if cursor.is_over(bounds) {
+            Status::Hovered
+        } else {
+            Status::Active
+        };
+        let style = theme.style(&self.class, status);
+        let clip = bounds.intersection(viewport).unwrap_or(bounds);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: pixel::snap(bounds),
+                border: pixel::border(style.border),
+                ..renderer::Quad::default()
+            },
+            style.background,
+        );
+
+        let label = Rectangle {
+            x: bounds.x + self.padding.left,
+            width: (bounds.width - self.padding.x() - ARROW_SIZE).max(0.0),
+            ..bounds
+        };
+
+        // While typing the picked option stays visible as the placeholder
+        let (content, color) = match (&self.selected, state.draft.is_empty()) {
+            (_, false) => (state.draft.as_str(), style.text),
+            (Some(selected), true) if state.is_focused => (selected.as_str(), style.placeholder),
+            (Some(selected), true) => (selected.as_str(), style.text),
+            (None, true) => (self.placeholder.as_str(), style.placeholder),
+        };
+        renderer.fill_text(
+            self.text(content, label.size()),
+            Point::new(label.x, label.center_y()),
+            color,
+            label.intersection(&clip).unwrap_or(label),
+        );
+
+        if state.is_focused {
+            let width = self.measure::<Renderer::Paragraph>(&state.draft).width.min(label.width);
+            let line_height = self.text_size.0 * 1.2;
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: pixel::snap(Rectangle {
+                        x: label.x + width,
+                        y: label.center_y() - line_height / 2.0,
+                        width: CARET_WIDTH,
+                        height: line_height,
+                    }),
+                    ..renderer::Quad::default()
+                },
+                style.caret,
+            );
+        }
+
+        renderer.fill_text(
+            iced::advanced::Text {
+                align_x: text::Alignment::Center,
+                ..self.text(if state.is_open { "▴" } else { "▾" }, Size::new(ARROW_SIZE, bounds.height))
+            },
+            Point::new(bounds.x + bounds.width - self.padding.right - ARROW_SIZE / 2.0, bounds.center_y()),
+            style.arrow,
+            clip,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let bounds = layout.bounds();
+
+        match cursor.position_over(bounds) {
+            Some(position) if position.x >= bounds.x + bounds.width - self.padding.right - ARROW_SIZE => {
+                mouse::Interaction::Pointer
+            }
+            Some(_) => mouse::Interaction::Text,
+            None => mouse::Interaction::None,
+        }
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn widget::Operation,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+        operation.custom(self.id.as_ref(), layout.bounds(), state);
+        operation.focusable(self.id.as_ref(), layout.bounds(), state);
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        _renderer: &Renderer,
+        _viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_mut::<State>();
+
+        if !state.is_focused || !state.is_open {
+            return None;
+        }
+
+        Some(overlay::Element::new(Box::new(Dropdown {
+            matching: self.matching(&state.draft),
+            combobox: self,
+            state,
+            anchor: layout.bounds() + translation,
+        })))
+    }
+}
+
+/// The dropdown of options matching the draft.
+struct Dropdown<'a, 'b, T, Message, Theme>
+where
+    Theme: Catalog,
+{
+    combobox: &'a Combobox<'b, T, Message, Theme>,
+    state: &'a mut State,
+    matching: Vec<(usize, Option<Range<usize>>)>,
+    anchor: Rectangle,
+}
+
+impl<T, Message, Theme> Dropdown<'_, '_, T, Message, Theme>
+where
+    T: fmt::Display + Clone,
+    Theme: Catalog,
+{
+    fn visible(&self) -> usize {
+        self.matching.len().saturating_sub(self.state.offset).min(self.combobox.max_visible)
+    }
+
+    /// The index into the matching options under the cursor.
+    fn item_at(&self, bounds: Rectangle, point: Point) -> Option<usize> {
+        if !bounds.contains(point) {
+            return None;
+        }
+
+        let row = ((point.y - bounds.y - MENU_PADDING) / MENU_ITEM_HEIGHT).floor();
+        (row >= 0.0 && (row as usize) < self.visible()).then_some(self.state.offset + row as usize)
+    }
+}
+
+impl<T, Message, Theme, Renderer> Overlay<Message, Theme, Renderer> for Dropdown<'_, '_, T, Message, Theme>
+where
+    T: fmt::Display + Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer<Font = iced::Font>,
+{
+    fn layout(&mut self, _renderer: &Renderer, bounds: Size) -> Node {
+        let width = self.anchor.width.min(bounds.width);
+        let rows = self.visible().max(1);
+        let height = (rows as f32 * MENU_ITEM_HEIGHT + MENU_PADDING * 2.0).min(bounds.height);
+
+        // Below the field, flipping above it when there is no room
+        let below = self.anchor.y + self.anchor.height + MENU_OFFSET;
+        let y = if below + height > bounds.height {
+            (self.anchor.y - MENU_OFFSET - height).max(0.0)
+        } else {
+            below
+        };
+        let x = self.anchor.x.clamp(0.0, (bounds.width - width).max(0.0));
+
+        Node::new(Size::new(width, height)).move_to(Point::new(x, y))
+    }
+
+    fn update(
+        &mut self,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                if let Some(index) = self.item_at(bounds, *position)
+                    && self.state.highlighted != Some(index)
+                {
+                    self.state.highlighted = Some(index);
+                    shell.request_redraw();
+                }
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) if cursor.is_over(bounds) => {
+                let rows = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => -y.signum() as isize,
+                    mouse::ScrollDelta::Pixels { y, .. } => -(y / MENU_ITEM_HEIGHT).round() as isize,
+                };
+                let max = self.matching.len().saturating_sub(self.combobox.max_visible);
+                self.state.offset = self.state.offset.saturating_add_signed(rows).min(max);
+
+                shell.capture_event();
+                shell.request_redraw();
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let Some(position) = cursor.position().filter(|position| bounds.contains(*position)) else {
+                    return;
+                };
+
+                if let Some((option, _)) = self.item_at(bounds, position).and_then(|index| self.matching.get(index)) {
+                    self.combobox.pick(self.state, *option, shell);
+                }
+                shell.capture_event();
+                shell.request_redraw();
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _defaults: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+    ) {
+        let style = theme.style(&self.combobox.class, Status::Focused);
+        let bounds = layout.bounds();
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: pixel::snap(bounds),
+                border: pixel::border(style.menu_border),
+                shadow: style.menu_shadow,
+                snap: true,
+            },
+            style.menu_background,
+        );
+
+        if self.matching.is_empty() {
+            let message = if self.combobox.on_submit.is_some() { "Press Enter to use this text" } else { "No matches" };
+            renderer.fill_text(
+                self.combobox.text(message, Size::new(bounds.width, MENU_ITEM_HEIGHT)),
+                Point::new(bounds.x + MENU_PADDING + ITEM_PADDING, bounds.y + MENU_PADDING + MENU_ITEM_HEIGHT / 2.0),
+                style.placeholder,
+                bounds,
+            );
+            return;
+        }
+
+        renderer.with_layer(bounds, |renderer| {
+            for (row, (index, (option, range))) in self
+                .matching
+                .iter()
+                .enumerate()
+                .skip(self.state.offset)
+                .take(self.visible())
+                .enumerate()
+            {
+                let item = Rectangle {
+                    x: bounds.x + MENU_PADDING,
+                    y: bounds.y + MENU_PADDING + row as f32 * MENU_ITEM_HEIGHT,
+                    width: bounds.width - MENU_PADDING * 2.0,
+                    height: MENU_ITEM_HEIGHT,
+                };
+                let highlighted = self.state.highlighted == Some(index);
+
+                if highlighted {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: pixel::snap(item),
+                            border: pixel::border(Border {
+                                radius: style.menu_border.radius,
+                                ..Border::default()
+                            }),
+                            ..renderer::Quad::default()
+                        },
+                        style.menu_highlighted_background,
+                    );
+                }
+
+                let label = &self.combobox.labels[*option];
+                let color = if highlighted { style.menu_highlighted_text } else { style.menu_text };
+                let x = item.x + ITEM_PADDING;
+
+                renderer.fill_text(
+                    self.combobox.text(label.clone(), item.size()),
+                    Point::new(x, item.center_y()),
+                    color,
+                    item,
+                );
+
+                // The matched part is drawn again on top, in its own color
+                if let Some(range) = range {
+                    let before = self.combobox.measure::<Renderer::Paragraph>(&label[..range.start]).width;
+                    let matched = self.combobox.measure::<Renderer::Paragraph>(&label[range.clone()]);
+
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: pixel::snap(Rectangle {
+                                x: x + before,
+                                y: item.center_y() + matched.height / 2.0 - 1.0,
+                                width: matched.width,
+                                height: 1.0,
+                            }),
+                            ..renderer::Quad::default()
+                        },
+                        style.match_text,
+                    );
+                    renderer.fill_text(
+                        self.combobox.text(label[range.clone()].to_string(), item.size()),
+                        Point::new(x + before, item.center_y()),
+                        style.match_text,
+                        item,
+                    );
+                }
+            }
+        });
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let bounds = layout.bounds();
+
+        match cursor.position() {
+            Some(position) if self.item_at(bounds, position).is_some() => mouse::Interaction::Pointer,
+            Some(position) if bounds.contains(position) => mouse::Interaction::Idle,
+            _ => mouse::Interaction::None,
+        }
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer> From<Combobox<'a, T, Message, Theme>> for Element<'a, Message, Theme, Renderer>
+where
+    T: fmt::Display + Clone + 'a,
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer<Font = iced::Font> + 'a,
+{
+    fn from(combobox: Combobox<'a, T, Message, Theme>) -> Self {
+        Element::new(combobox)
+    }
+}
+
+/// The state of a [`Combobox`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Active,
+    Hovered,
+    Focused,
+}
+
+/// The theme catalog of a [`Combobox`].
+pub trait Catalog {
+    /// The style class
+    type Class<'a>;
+
+    /// Default style
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// Get the style for a class and status
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+/// The appearance of a [`Combobox`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// Background of the field
+    pub background: Background,
+    /// Border of the field
+    pub border: Border,
+    /// Color of the typed text and the picked option
+    pub text: Color,
+    /// Color of the placeholder
+    pub placeholder: Color,
+    /// Color of the caret
+    pub caret: Color,
+    /// Color of the dropdown arrow
+    pub arrow: Color,
+    /// Background of the dropdown
+    pub menu_background: Background,
+    /// Border of the dropdown
+    pub menu_border: Border,
+    /// Shadow of the dropdown
+    pub menu_shadow: Shadow,
+    /// Labels of the options
+    pub menu_text: Color,
+    /// Background of the highlighted option
+    pub menu_highlighted_background: Background,
+    /// Label of the highlighted option
+    pub menu_highlighted_text: Color,
+    /// The part of a label matching the typed text, and its underline
+    pub match_text: Color,
+}
+
+/// Styling function
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for iced::Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default combobox style.
+pub fn default(theme: &iced::Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: palette.background.base.color.into(),
+        border: Border {
+            color: match status {
+                Status::Active => palette.background.strong.color,
+                Status::Hovered => palette.background.base.text,
+                Status::Focused => palette.primary.strong.color,
+            },
+            width: 1.0,
+            radius: 4.0.into(),
+        },
+        text: palette.background.base.text,
+        placeholder: palette.background.strong.color,
+        caret: palette.background.base.text,
+        arrow: palette.background.strong.text,
+        menu_background: palette.background.base.color.into(),
+        menu_border: Border {
+            color: palette.background.strong.color,
+            width: 1.0,
+            radius: 4.0.into(),
+        },
+        menu_shadow: Shadow {
+            color: Color::BLACK.scale_alpha(0.3),
+            offset: Vector::new(0.0, 2.0),
+            blur_radius: 8.0,
+        },
+        menu_text: palette.background.base.text,
+        menu_highlighted_background: palette.primary.weak.color.into(),
+        menu_highlighted_text: palette.primary.weak.text,
+        match_text: palette.primary.strong.color,
+    }
+}
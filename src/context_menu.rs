@@ -0,0 +1,384 @@
+//! A menu opened by right-clicking any content.
+//!
+//! The menu is built from the [`menu`](crate::menu) model, with each action's id being the
+//! message it produces. Entries can carry icons, check marks, radio dots and shortcut hints,
+//! can be disabled, and submenus open on hover or with the arrow keys. The whole menu is
+//! usable from the keyboard: Up and Down move, Right and Enter open a submenu, Left closes
+//! it, Enter picks an action and Escape closes the menu. Right to left the menu opens to the
+//! left of the cursor, mirrored like every [`menu`](crate::menu).
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        mouse, overlay, renderer, text,
+        widget::{self, tree::Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    Element, Event, Length, Pixels, Point, Rectangle, Size, Vector,
+};
+
+use crate::direction::{self, Direction};
+use crate::menu::{Origin, Outcome, Panels, Session};
+pub use crate::menu::{default, flat, Catalog, Item, Style, StyleFn};
+
+/// Creates a new [`ContextMenu`] opening `items` when `content` is right-clicked.
+pub fn context_menu<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    items: impl IntoIterator<Item = Item<Message>>,
+) -> ContextMenu<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer<Font = iced::Font>,
+{
+    ContextMenu::new(content, items)
+}
+
+/// Content that opens a menu where it is right-clicked.
+#[allow(missing_debug_implementations)]
+pub struct ContextMenu<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer<Font = iced::Font>,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    items: Vec<Item<Message>>,
+    text_size: Pixels,
+    font: iced::Font,
+    direction: Option<Direction>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> ContextMenu<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer<Font = iced::Font>,
+{
+    /// Creates a new [`ContextMenu`] opening `items` when `content` is right-clicked.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        items: impl IntoIterator<Item = Item<Message>>,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            items: items.into_iter().collect(),
+            text_size: Pixels(14.0),
+            font: iced::Font::default(),
+            direction: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the text size of the menu.
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = size.into();
+        self
+    }
+
+    /// Sets the font of the menu.
+    pub fn font(mut self, font: iced::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the [`Direction`] of the menu, instead of the one set with [`direction::set`].
+    ///
+    /// Right to left opens the menu and its submenus to the left, with the arrow keys swapped.
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    /// Sets the style.
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class.
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Shows the shortcuts `keymap` binds to the entries.
+    #[cfg(feature = "hotkeys")]
+    #[must_use]
+    pub fn hotkeys(mut self, keymap: &crate::hotkeys::Keymap<Message>) -> Self
+    where
+        Message: PartialEq,
+    {
+        self.items = self.items.into_iter().map(|item| item.hotkeys(keymap)).collect();
+        self
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    /// Where the menu was opened, relative to the content
+    open_at: Option<Vector>,
+    session: Session,
+}
+
+impl State {
+    fn close(&mut self) {
+        self.open_at = None;
+        self.session = Session::default();
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for ContextMenu<'_, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer<Font = iced::Font>,
+{
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn layout(&mut self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let content = self
+            .content
+            .as_widget_mut()
+            .layout(&mut tree.children[0], renderer, limits);
+
+        Node::with_children(content.size(), vec![content])
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout.children().next().unwrap_or(layout),
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        if shell.is_event_captured() {
+            return;
+        }
+
+        if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Right)) = event
+            && let Some(position) = cursor.position_over(layout.bounds())
+            && !self.items.is_empty()
+        {
+            let state = tree.state.downcast_mut::<State>();
+            state.close();
+            state.open_at = Some(position - layout.bounds().position());
+            shell.capture_event();
+            shell.request_redraw();
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        defaults: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            defaults,
+            layout.children().next().unwrap_or(layout),
+            cursor,
+            viewport,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout.children().next().unwrap_or(layout),
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn widget::Operation,
+    ) {
+        self.content.as_widget_mut().operate(
+            &mut tree.children[0],
+            layout.children().next().unwrap_or(layout),
+            renderer,
+            operation,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let Tree { state, children, .. } = tree;
+        let state = state.downcast_mut::<State>();
+        let origin = layout.bounds().position() + translation;
+
+        let content = self.content.as_widget_mut().overlay(
+            &mut children[0],
+            layout.children().next().unwrap_or(layout),
+            renderer,
+            viewport,
+            translation,
+        );
+
+        let menu = state.open_at.map(|open_at| {
+            overlay::Element::new(Box::new(Overlay {
+                panels: Panels::new(&self.items, self.text_size, self.font, self.direction.unwrap_or_else(direction::get)),
+                state,
+                position: origin + open_at,
+                class: &self.class,
+            }))
+        });
+
+        match (content, menu) {
+            (Some(content), Some(menu)) => Some(overlay::Group::with_children(vec![content, menu]).overlay()),
+            (content, menu) => content.or(menu),
+        }
+    }
+}
+
+/// The open menu of a [`ContextMenu`].
+struct Overlay<'a, 'b, Message, Theme>
+where
+    Theme: Catalog,
+{
+    panels: Panels<'a, Message>,
+    state: &'a mut State,
+    position: Point,
+    class: &'a Theme::Class<'b>,
+}
+
+impl<Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer> for Overlay<'_, '_, Message, Theme>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer<Font = iced::Font>,
+{
+    fn layout(&mut self, _renderer: &Renderer, bounds: Size) -> Node {
+        self.panels
+            .layout::<Renderer::Paragraph>(&self.state.session, Origin::Point(self.position), bounds);
+
+        Node::new(bounds)
+    }
+
+    fn update(
+        &mut self,
+        event: &Event,
+        _layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        match self.panels.update(&mut self.state.session, event, cursor, &|message| message, shell) {
+            Outcome::None => return,
+            Outcome::Close => self.state.close(),
+            Outcome::Outside(button) => {
+                self.state.close();
+
+                // Let a right click through so it can open the menu again where it landed
+                if button == mouse::Button::Right {
+                    shell.invalidate_layout();
+                    shell.request_redraw();
+                    return;
+                }
+            }
+            Outcome::Handled | Outcome::Previous | Outcome::Next => {}
+        }
+
+        shell.capture_event();
+        shell.invalidate_layout();
+        shell.request_redraw();
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _defaults: &renderer::Style,
+        _layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+    ) {
+        self.panels.draw(renderer, &theme.style(self.class), &self.state.session);
+    }
+
+    fn mouse_interaction(
+        &self,
+        _layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.panels.mouse_interaction(&self.state.session, cursor)
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<ContextMenu<'a, Message, Theme, Renderer>> for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer<Font = iced::Font> + 'a,
+{
+    fn from(menu: ContextMenu<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(menu)
+    }
+}
@@ -0,0 +1,1115 @@
+//! A date range picker: a field that opens a two month calendar with preset ranges.
+//!
+//! The first click in the calendar picks the start of the range, the days up to the hovered
+//! one are previewed, and the second click picks the end and closes the calendar. Presets
+//! such as "Last 7 days" pick a whole range at once.
+//!
+//! Dates are plain proleptic Gregorian calendar days without a time zone, see [`Date`].
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        mouse, overlay, renderer, text,
+        widget::{self, tree::Tree},
+        Clipboard, Layout, Overlay, Shell, Widget,
+    },
+    alignment, keyboard, Background, Border, Color, Element, Event, Length, Padding, Pixels,
+    Point, Rectangle, Shadow, Size, Vector,
+};
+use std::fmt;
+
+use crate::theme::{self, Palette};
+use crate::pixel;
+
+const CELL_SIZE: f32 = 32.0;
+const MONTH_HEADER_HEIGHT: f32 = 32.0;
+const WEEKDAY_HEIGHT: f32 = 24.0;
+const WEEKS: usize = 6;
+const MONTH_SPACING: f32 = 16.0;
+const PRESET_WIDTH: f32 = 130.0;
+const PRESET_HEIGHT: f32 = 28.0;
+const POPUP_PADDING: f32 = 12.0;
+const POPUP_OFFSET: f32 = 4.0;
+const NAV_SIZE: f32 = 24.0;
+
+const MONTH_NAMES: [&str; 12] = [
+    "January", "February", "March", "April", "May", "June", "July", "August", "September",
+    "October", "November", "December",
+];
+const WEEKDAY_NAMES: [&str; 7] = ["Su", "Mo", "Tu", "We", "Th", "Fr", "Sa"];
+
+/// A calendar day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Date {
+    year: i32,
+    month: u32,
+    day: u32,
+}
+
+impl Date {
+    /// Creates a date, or returns `None` if the day does not exist.
+    pub fn new(year: i32, month: u32, day: u32) -> Option<Self> {
+        ((1..=12).contains(&month) && day >= 1 && day <= days_in_month(year, month))
+            .then_some(Self { year, month, day })
+    }
+
+    /// The current day in UTC.
+    ///
+    /// Apps that care about the local day should pass it to [`DateRangePicker::today`].
+    pub fn today() -> Self {
+        let seconds = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_secs() as i64);
+
+        Self::from_days(seconds.div_euclid(86_400))
+    }
+
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    pub fn month(&self) -> u32 {
+        self.month
+    }
+
+    pub fn day(&self) -> u32 {
+        self.day
+    }
+
+    /// The date the given number of days later (or earlier, when negative).
+    pub fn add_days(self, days: i64) -> Self {
+        Self::from_days(self.to_days() + days)
+    }
+
+    /// The number of days from `self` to `other`.
+    pub fn days_until(self, other: Date) -> i64 {
+        other.to_days() - self.to_days()
+    }
+
+    /// The day of the week, `0` being Sunday.
+    pub fn weekday(self) -> u32 {
+        (self.to_days() + 4).rem_euclid(7) as u32
+    }
+
+    /// The first day of the month of this date.
+    pub fn first_of_month(self) -> Self {
+        Self { day: 1, ..self }
+    }
+
+    /// The last day of the month of this date.
+    pub fn last_of_month(self) -> Self {
+        Self {
+            day: days_in_month(self.year, self.month),
+            ..self
+        }
+    }
+
+    /// The same day in a month `months` later, clamped to the end of shorter months.
+    pub fn add_months(self, months: i32) -> Self {
+        let index = self.year * 12 + self.month as i32 - 1 + months;
+        let (year, month) = (index.div_euclid(12), index.rem_euclid(12) as u32 + 1);
+
+        Self {
+            year,
+            month,
+            day: self.day.min(days_in_month(year, month)),
+        }
+    }
+
+    /// Days since 1970-01-01.
+    fn to_days(self) -> i64 {
+        // Howard Hinnant's days_from_civil
+        let year = i64::from(self.year) - i64::from(self.month <= 2);
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let month = i64::from(self.month);
+        let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + i64::from(self.day) - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+        era * 146_097 + day_of_era - 719_468
+    }
+
+    fn from_days(days: i64) -> Self {
+        let days = days + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days - era * 146_097;
+        let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let mp = (5 * day_of_year + 2) / 153;
+        let day = (day_of_year - (153 * mp + 2) / 5 + 1) as u32;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+        let year = (year_of_era + era * 400 + i64::from(month <= 2)) as i32;
+
+        Self { year, month, day }
+    }
+}
+
+/// Checks the day exists, like [`Date::new`].
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Date {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(serde::Deserialize)]
+        struct Parts {
+            year: i32,
+            month: u32,
+            day: u32,
+        }
+
+        let Parts { year, month, day } = Parts::deserialize(deserializer)?;
+
+        Date::new(year, month, day)
+            .ok_or_else(|| serde::de::Error::custom(format!("no such day: {year:04}-{month:02}-{day:02}")))
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        _ => 0,
+    }
+}
+
+/// An inclusive range of days.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DateRange {
+    pub start: Date,
+    pub end: Date,
+}
+
+impl DateRange {
+    /// Creates a range, swapping the ends if needed so `start <= end`.
+    pub fn new(a: Date, b: Date) -> Self {
+        Self {
+            start: a.min(b),
+            end: a.max(b),
+        }
+    }
+
+    /// Whether the day is inside the range.
+    pub fn contains(&self, date: Date) -> bool {
+        self.start <= date && date <= self.end
+    }
+
+    /// The number of days in the range.
+    pub fn days(&self) -> i64 {
+        self.start.days_until(self.end) + 1
+    }
+}
+
+impl fmt::Display for DateRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} – {}", self.start, self.end)
+    }
+}
+
+/// A named range listed next to the calendar.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Preset {
+    label: String,
+    range: DateRange,
+}
+
+impl Preset {
+    /// Creates a preset with the given label and range.
+    pub fn new(label: impl Into<String>, range: DateRange) -> Self {
+        Self {
+            label: label.into(),
+            range,
+        }
+    }
+
+    /// A preset for the `days` days ending on `today`, e.g. "Last 7 days".
+    pub fn last_days(days: u32, today: Date) -> Self {
+        Self::new(
+            format!("Last {days} days"),
+            DateRange::new(today.add_days(1 - i64::from(days.max(1))), today),
+        )
+    }
+
+    /// Today, last 7 days, last 30 days, this month and last month.
+    pub fn defaults(today: Date) -> Vec<Self> {
+        let last_month = today.first_of_month().add_months(-1);
+
+        vec![
+            Self::new("Today", DateRange::new(today, today)),
+            Self::last_days(7, today),
+            Self::last_days(30, today),
+            Self::new("This month", DateRange::new(today.first_of_month(), today)),
+            Self::new("Last month", DateRange::new(last_month, last_month.last_of_month())),
+        ]
+    }
+}
+
+/// Creates a new [`DateRangePicker`] showing the given range.
+pub fn date_range_picker<'a, Message, Theme>(
+    value: Option<DateRange>,
+    on_change: impl Fn(DateRange) -> Message + 'a,
+) -> DateRangePicker<'a, Message, Theme>
+where
+    Theme: Catalog,
+{
+    DateRangePicker::new(value, on_change)
+}
+
+/// A field that opens a two month calendar to pick a range of days.
+#[allow(missing_debug_implementations)]
+pub struct DateRangePicker<'a, Message, Theme = iced::Theme>
+where
+    Theme: Catalog,
+{
+    value: Option<DateRange>,
+    on_change: Box<dyn Fn(DateRange) -> Message + 'a>,
+    presets: Vec<Preset>,
+    today: Date,
+    first_weekday: u32,
+    placeholder: String,
+    width: Length,
+    padding: Padding,
+    text_size: Pixels,
+    font: iced::Font,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme> DateRangePicker<'a, Message, Theme>
+where
+    Theme: Catalog,
+{
+    /// Creates a new [`DateRangePicker`] showing the given range.
+    pub fn new(value: Option<DateRange>, on_change: impl Fn(DateRange) -> Message + 'a) -> Self {
+        let today = Date::today();
+
+        Self {
+            value,
+            on_change: Box::new(on_change),
+            presets: Preset::defaults(today),
+            today,
+            first_weekday: 1,
+            placeholder: String::from("Select dates"),
+            width: Length::Shrink,
+            padding: Padding::from([6, 10]),
+            text_size: Pixels(14.0),
+            font: iced::Font::default(),
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the day highlighted as today, which the default presets are relative to.
+    pub fn today(mut self, today: Date) -> Self {
+        self.today = today;
+        self.presets = Preset::defaults(today);
+        self
+    }
+
+    /// Replaces the preset ranges listed next to the calendar.
+    pub fn presets(mut self, presets: impl IntoIterator<Item = Preset>) -> Self {
+        self.presets = presets.into_iter().collect();
+        self
+    }
+
+    /// Starts weeks on Sunday instead of Monday.
+    pub fn sunday_first(mut self) -> Self {
+        self.first_weekday = 0;
+        self
+    }
+
+    /// Sets the text shown while no range is selected.
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Sets the width of the field.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the padding of the field.
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size.
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = size.into();
+        self
+    }
+
+    /// Sets the font.
+    pub fn font(mut self, font: iced::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style.
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class.
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    fn label(&self) -> String {
+        self.value.map_or_else(|| self.placeholder.clone(), |range| range.to_string())
+    }
+
+    fn text(&self, content: impl Into<String>, bounds: Size, align_x: text::Alignment) -> iced::advanced::Text<String, iced::Font> {
+        iced::advanced::Text {
+            content: content.into(),
+            bounds,
+            size: self.text_size,
+            font: self.font,
+            align_x,
+            align_y: alignment::Vertical::Center,
+            line_height: text::LineHeight::default(),
+            shaping: text::Shaping::Advanced,
+            wrapping: text::Wrapping::None,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    is_open: bool,
+    /// First day of the left month shown
+    month: Option<Date>,
+    /// Start picked by the first click, waiting for the end
+    pending: Option<Date>,
+    hovered: Option<Date>,
+    hovered_preset: Option<usize>,
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for DateRangePicker<'_, Message, Theme>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer<Font = iced::Font>,
+{
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(State::default())
+    }
+
+    fn layout(&mut self, _tree: &mut Tree, _renderer: &Renderer, limits: &Limits) -> Node {
+        use text::Paragraph as _;
+
+        // Wide enough for a full range so the field does not jump when one is picked
+        let sample = DateRange::new(self.today, self.today).to_string();
+        let label_width = [sample.as_str(), self.placeholder.as_str()]
+            .into_iter()
+            .map(|content| {
+                Renderer::Paragraph::with_text(iced::advanced::Text {
+                    content,
+                    bounds: Size::INFINITE,
+                    size: self.text_size,
+                    font: self.font,
+                    align_x: text::Alignment::Left,
+                    align_y: alignment::Vertical::Top,
+                    line_height: text::LineHeight::default(),
+                    shaping: text::Shaping::Advanced,
+                    wrapping: text::Wrapping::None,
+                })
+                .min_bounds()
+            })
+            .fold(Size::ZERO, |a, b| Size::new(a.width.max(b.width), a.height.max(b.height)));
+
+        let intrinsic = Size::new(
+            label_width.width + self.padding.x() + NAV_SIZE,
+            label_width.height + self.padding.y(),
+        );
+
+        Node::new(limits.resolve(self.width, Length::Shrink, intrinsic))
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) if cursor.is_over(layout.bounds()) => {
+                state.is_open = !state.is_open;
+
+                if state.is_open {
+                    let anchor = self.value.map_or(self.today, |range| range.start);
+                    state.month = Some(anchor.first_of_month());
+                    state.pending = None;
+                }
+
+                shell.capture_event();
+                shell.request_redraw();
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                shell.request_redraw();
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _defaults: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let status = if state.is_open {
+            Status::Opened
+        } else if cursor.is_over(bounds) {
+            Status::Hovered
+        } else {
+            Status::Active
+        };
+        let style = theme.style(&self.class, status);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: pixel::snap(bounds),
+                border: pixel::border(style.field_border),
+                ..renderer::Quad::default()
+            },
+            style.field_background,
+        );
+
+        let color = if self.value.is_some() { style.field_text } else { style.placeholder };
+        renderer.fill_text(
+            self.text(self.label(), bounds.size(), text::Alignment::Left),
+            Point::new(bounds.x + self.padding.left, bounds.center_y()),
+            color,
+            bounds.intersection(viewport).unwrap_or(bounds),
+        );
+
+        renderer.fill_text(
+            self.text("📅", Size::new(NAV_SIZE, bounds.height), text::Alignment::Center),
+            Point::new(bounds.x + bounds.width - self.padding.right - NAV_SIZE / 2.0, bounds.center_y()),
+            style.field_text,
+            *viewport,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::None
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        _renderer: &Renderer,
+        _viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_mut::<State>();
+
+        if !state.is_open {
+            return None;
+        }
+
+        Some(overlay::Element::new(Box::new(Calendar {
+            picker: self,
+            state,
+            field: layout.bounds() + translation,
+        })))
+    }
+}
+
+/// The calendar popup below the field.
+struct Calendar<'a, 'b, Message, Theme>
+where
+    Theme: Catalog,
+{
+    picker: &'a DateRangePicker<'b, Message, Theme>,
+    state: &'a mut State,
+    field: Rectangle,
+}
+
+/// The part of the calendar under the cursor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Hit {
+    Day(Date),
+    Preset(usize),
+    Previous,
+    Next,
+}
+
+impl<Message, Theme> Calendar<'_, '_, Message, Theme>
+where
+    Theme: Catalog,
+{
+    fn presets_width(&self) -> f32 {
+        if self.picker.presets.is_empty() { 0.0 } else { PRESET_WIDTH + MONTH_SPACING }
+    }
+
+    fn size(&self) -> Size {
+        let month_width = CELL_SIZE * 7.0;
+        let month_height = MONTH_HEADER_HEIGHT + WEEKDAY_HEIGHT + CELL_SIZE * WEEKS as f32;
+        let presets_height = self.picker.presets.len() as f32 * PRESET_HEIGHT;
+
+        Size::new(
+            POPUP_PADDING * 2.0 + self.presets_width() + month_width * 2.0 + MONTH_SPACING,
+            POPUP_PADDING * 2.0 + month_height.max(presets_height),
+        )
+    }
+
+    /// The bounds of the left or right month grid, below its header.
+    fn month_bounds(&self, bounds: Rectangle, index: usize) -> Rectangle {
+        let width = CELL_SIZE * 7.0;
+
+        Rectangle {
+            x: bounds.x + POPUP_PADDING + self.presets_width() + index as f32 * (width + MONTH_SPACING),
+            y: bounds.y + POPUP_PADDING,
+            width,
+            height: MONTH_HEADER_HEIGHT + WEEKDAY_HEIGHT + CELL_SIZE * WEEKS as f32,
+        }
+    }
+
+    fn first_month(&self) -> Date {
+        self.state.month.unwrap_or_else(|| self.picker.today.first_of_month())
+    }
+
+    /// The days of a month with their cells.
+    fn days(&self, month: Rectangle, first: Date) -> impl Iterator<Item = (Date, Rectangle)> {
+        let offset = (first.weekday() + 7 - self.picker.first_weekday) % 7;
+        let grid_y = month.y + MONTH_HEADER_HEIGHT + WEEKDAY_HEIGHT;
+
+        (0..days_in_month(first.year, first.month)).map(move |day| {
+            let index = offset + day;
+            let cell = Rectangle {
+                x: month.x + (index % 7) as f32 * CELL_SIZE,
+                y: grid_y + (index / 7) as f32 * CELL_SIZE,
+                width: CELL_SIZE,
+                height: CELL_SIZE,
+            };
+
+            (first.add_days(i64::from(day)), cell)
+        })
+    }
+
+    fn nav_bounds(&self, bounds: Rectangle) -> (Rectangle, Rectangle) {
+        let left = self.month_bounds(bounds, 0);
+        let right = self.month_bounds(bounds, 1);
+        let y = left.y + (MONTH_HEADER_HEIGHT - NAV_SIZE) / 2.0;
+
+        (
+            Rectangle { x: left.x, y, width: NAV_SIZE, height: NAV_SIZE },
+            Rectangle { x: right.x + right.width - NAV_SIZE, y, width: NAV_SIZE, height: NAV_SIZE },
+        )
+    }
+
+    fn preset_bounds(&self, bounds: Rectangle, index: usize) -> Rectangle {
+        Rectangle {
+            x: bounds.x + POPUP_PADDING,
+            y: bounds.y + POPUP_PADDING + index as f32 * PRESET_HEIGHT,
+            width: PRESET_WIDTH,
+            height: PRESET_HEIGHT,
+        }
+    }
+
+    fn hit(&self, bounds: Rectangle, point: Point) -> Option<Hit> {
+        let (previous, next) = self.nav_bounds(bounds);
+
+        if previous.contains(point) {
+            return Some(Hit::Previous);
+        }
+        if next.contains(point) {
+            return Some(Hit::Next);
+        }
+
+        if let Some(index) = (0..self.picker.presets.len()).find(|&i| self.preset_bounds(bounds, i).contains(point)) {
+            return Some(Hit::Preset(index));
+        }
+
+        let first = self.first_month();
+        (0..2).find_map(|index| {
+            self.days(self.month_bounds(bounds, index), first.add_months(index as i32))
+                .find(|(_, cell)| cell.contains(point))
+                .map(|(date, _)| Hit::Day(date))
+        })
+    }
+
+    /// The range to highlight: the pending one previewed up to the hovered day, or the value.
+    fn highlighted(&self) -> Option<DateRange> {
+        match self.state.pending {
+            Some(start) => Some(DateRange::new(start, self.state.hovered.unwrap_or(start))),
+            None => self.picker.value,
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> Overlay<Message, Theme, Renderer> for Calendar<'_, '_, Message, Theme>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer<Font = iced::Font>,
+{
+    fn layout(&mut self, _renderer: &Renderer, bounds: Size) -> Node {
+        let size = self.size();
+
+        // Below the field, or above it when there is no room, kept inside the window
+        let below = self.field.y + self.field.height + POPUP_OFFSET;
+        let y = if below + size.height > bounds.height && self.field.y - POPUP_OFFSET - size.height >= 0.0 {
+            self.field.y - POPUP_OFFSET - size.height
+        } else {
+            below
+        };
+        let x = self.field.x.min(bounds.width - size.width).max(0.0);
+
+        Node::new(size).move_to(Point::new(x, y))
+    }
+
+    fn update(
+        &mut self,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                let hit = self.hit(bounds, *position);
+                let hovered = match hit {
+                    Some(Hit::Day(date)) => Some(date),
+                    _ => None,
+                };
+                let hovered_preset = match hit {
+                    Some(Hit::Preset(index)) => Some(index),
+                    _ => None,
+                };
+
+                if hovered != self.state.hovered || hovered_preset != self.state.hovered_preset {
+                    self.state.hovered = hovered;
+                    self.state.hovered_preset = hovered_preset;
+                    shell.request_redraw();
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let Some(position) = cursor.position() else {
+                    return;
+                };
+
+                if !bounds.contains(position) {
+                    // The field toggles the calendar itself
+                    if !self.field.contains(position) {
+                        self.state.is_open = false;
+                        self.state.pending = None;
+                        shell.request_redraw();
+                    }
+                    return;
+                }
+
+                match self.hit(bounds, position) {
+                    Some(Hit::Previous) => {
+                        self.state.month = Some(self.first_month().add_months(-1));
+                    }
+                    Some(Hit::Next) => {
+                        self.state.month = Some(self.first_month().add_months(1));
+                    }
+                    Some(Hit::Preset(index)) => {
+                        shell.publish((self.picker.on_change)(self.picker.presets[index].range));
+                        self.state.is_open = false;
+                        self.state.pending = None;
+                    }
+                    Some(Hit::Day(date)) => match self.state.pending.take() {
+                        Some(start) => {
+                            shell.publish((self.picker.on_change)(DateRange::new(start, date)));
+                            self.state.is_open = false;
+                        }
+                        None => self.state.pending = Some(date),
+                    },
+                    None => {}
+                }
+
+                shell.capture_event();
+                shell.request_redraw();
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(keyboard::key::Named::Escape),
+                ..
+            }) => {
+                // Escape first drops a half picked range, then closes
+                if self.state.pending.take().is_none() {
+                    self.state.is_open = false;
+                }
+                shell.capture_event();
+                shell.request_redraw();
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _defaults: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        let style = theme.style(&self.picker.class, Status::Opened);
+        let bounds = layout.bounds();
+        let highlighted = self.highlighted();
+        let hit = cursor.position().and_then(|position| self.hit(bounds, position));
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: pixel::snap(bounds),
+                border: pixel::border(style.popup_border),
+                shadow: style.popup_shadow,
+                snap: true,
+            },
+            style.popup_background,
+        );
+
+        for (index, preset) in self.picker.presets.iter().enumerate() {
+            let preset_bounds = self.preset_bounds(bounds, index);
+            let active = self.picker.value == Some(preset.range);
+
+            if hit == Some(Hit::Preset(index)) || active {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: pixel::snap(preset_bounds),
+                        border: pixel::border(Border { radius: 4.0.into(), ..Border::default() }),
+                        ..renderer::Quad::default()
+                    },
+                    if active { style.range_background } else { style.day_hovered_background },
+                );
+            }
+
+            renderer.fill_text(
+                self.picker.text(preset.label.clone(), preset_bounds.size(), text::Alignment::Left),
+                Point::new(preset_bounds.x + 8.0, preset_bounds.center_y()),
+                style.day_text,
+                preset_bounds,
+            );
+        }
+
+        let (previous, next) = self.nav_bounds(bounds);
+        for (nav, glyph, target) in [(previous, "‹", Hit::Previous), (next, "›", Hit::Next)] {
+            if hit == Some(target) {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: pixel::snap(nav),
+                        border: pixel::border(Border { radius: (NAV_SIZE / 2.0).into(), ..Border::default() }),
+                        ..renderer::Quad::default()
+                    },
+                    style.day_hovered_background,
+                );
+            }
+
+            renderer.fill_text(
+                iced::advanced::Text {
+                    size: Pixels(self.picker.text_size.0 * 1.4),
+                    ..self.picker.text(glyph, nav.size(), text::Alignment::Center)
+                },
+                nav.center(),
+                style.header_text,
+                bounds,
+            );
+        }
+
+        let first = self.first_month();
+
+        for index in 0..2 {
+            let month = self.month_bounds(bounds, index);
+            let first_day = first.add_months(index as i32);
+
+            renderer.fill_text(
+                self.picker.text(
+                    format!("{} {}", MONTH_NAMES[first_day.month as usize - 1], first_day.year),
+                    Size::new(month.width, MONTH_HEADER_HEIGHT),
+                    text::Alignment::Center,
+                ),
+                Point::new(month.center_x(), month.y + MONTH_HEADER_HEIGHT / 2.0),
+                style.header_text,
+                bounds,
+            );
+
+            for column in 0..7 {
+                let weekday = (self.picker.first_weekday + column) % 7;
+
+                renderer.fill_text(
+                    iced::advanced::Text {
+                        size: Pixels(self.picker.text_size.0 * 0.85),
+                        ..self.picker.text(
+                            WEEKDAY_NAMES[weekday as usize],
+                            Size::new(CELL_SIZE, WEEKDAY_HEIGHT),
+                            text::Alignment::Center,
+                        )
+                    },
+                    Point::new(
+                        month.x + (column as f32 + 0.5) * CELL_SIZE,
+                        month.y + MONTH_HEADER_HEIGHT + WEEKDAY_HEIGHT / 2.0,
+                    ),
+                    style.weekday_text,
+                    bounds,
+                );
+            }
+
+            for (date, cell) in self.days(month, first_day) {
+                let is_endpoint = highlighted.is_some_and(|range| range.start == date || range.end == date);
+                let in_range = highlighted.is_some_and(|range| range.contains(date));
+
+                if in_range && !is_endpoint {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: pixel::snap(cell.shrink(Padding::from([2, 0]))),
+                            ..renderer::Quad::default()
+                        },
+                        style.range_background,
+                    );
+                }
+
+                let circle = cell.shrink(2.0);
+                let background = if is_endpoint {
+                    Some(style.endpoint_background)
+                } else if hit == Some(Hit::Day(date)) {
+                    Some(style.day_hovered_background)
+                } else {
+                    None
+                };
+
+                if let Some(background) = background {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: pixel::snap(circle),
+                            border: pixel::border(Border { radius: (circle.width / 2.0).into(), ..Border::default() }),
+                            ..renderer::Quad::default()
+                        },
+                        background,
+                    );
+                }
+
+                if date == self.picker.today && !is_endpoint {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: pixel::snap(circle),
+                            border: pixel::border(Border {
+                                color: style.today_border,
+                                width: 1.0,
+                                radius: (circle.width / 2.0).into(),
+                            }),
+                            ..renderer::Quad::default()
+                        },
+                        Color::TRANSPARENT,
+                    );
+                }
+
+                renderer.fill_text(
+                    self.picker.text(date.day.to_string(), cell.size(), text::Alignment::Center),
+                    cell.center(),
+                    if is_endpoint { style.endpoint_text } else { style.day_text },
+                    bounds,
+                );
+            }
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let bounds = layout.bounds();
+
+        match cursor.position() {
+            Some(position) if self.hit(bounds, position).is_some() => mouse::Interaction::Pointer,
+            Some(position) if bounds.contains(position) => mouse::Interaction::Idle,
+            _ => mouse::Interaction::None,
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<DateRangePicker<'a, Message, Theme>> for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer<Font = iced::Font> + 'a,
+{
+    fn from(picker: DateRangePicker<'a, Message, Theme>) -> Self {
+        Element::new(picker)
+    }
+}
+
+/// The state of the field of a [`DateRangePicker`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Active,
+    Hovered,
+    Opened,
+}
+
+/// The theme catalog of a [`DateRangePicker`].
+pub trait Catalog {
+    /// The style class
+    type Class<'a>;
+
+    /// Default style
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// Get the style for a class and status
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+/// The appearance of a [`DateRangePicker`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// Background of the field
+    pub field_background: Background,
+    /// Border of the field
+    pub field_border: Border,
+    /// Text color of the field
+    pub field_text: Color,
+    /// Color of the placeholder
+    pub placeholder: Color,
+    /// Background of the calendar popup
+    pub popup_background: Background,
+    /// Border of the calendar popup
+    pub popup_border: Border,
+    /// Shadow of the calendar popup
+    pub popup_shadow: Shadow,
+    /// Month names and navigation arrows
+    pub header_text: Color,
+    /// Weekday names
+    pub weekday_text: Color,
+    /// Day numbers and preset labels
+    pub day_text: Color,
+    /// Background of the hovered day or preset
+    pub day_hovered_background: Background,
+    /// Background of the days between the ends of the range
+    pub range_background: Background,
+    /// Background of the first and last day of the range
+    pub endpoint_background: Background,
+    /// Day numbers of the first and last day of the range
+    pub endpoint_text: Color,
+    /// Ring around today
+    pub today_border: Color,
+}
+
+/// Styling function
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for iced::Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default date range picker style.
+pub fn default(theme: &iced::Theme, status: Status) -> Style {
+    from_palette(Palette::new(theme, theme::Preset::Standard), status)
+}
+
+/// The [`theme::Preset::Compact`] date range picker style.
+pub fn compact(theme: &iced::Theme, status: Status) -> Style {
+    from_palette(Palette::new(theme, theme::Preset::Compact), status)
+}
+
+/// The [`theme::Preset::Flat`] date range picker style.
+pub fn flat(theme: &iced::Theme, status: Status) -> Style {
+    from_palette(Palette::new(theme, theme::Preset::Flat), status)
+}
+
+/// The [`theme::Preset::HighContrast`] date range picker style.
+pub fn high_contrast(theme: &iced::Theme, status: Status) -> Style {
+    from_palette(Palette::new(theme, theme::Preset::HighContrast), status)
+}
+
+fn from_palette(palette: Palette, status: Status) -> Style {
+    Style {
+        field_background: palette.background.into(),
+        field_border: Border {
+            color: match status {
+                Status::Active => palette.border,
+                Status::Hovered | Status::Opened => palette.accent,
+            },
+            width: palette.border_width,
+            radius: palette.radius.into(),
+        },
+        field_text: palette.text,
+        placeholder: palette.border,
+        popup_background: palette.background.into(),
+        popup_border: Border {
+            color: palette.border,
+            width: palette.border_width,
+            radius: (palette.radius * 1.5).into(),
+        },
+        popup_shadow: palette.shadow,
+        header_text: palette.text,
+        weekday_text: palette.border,
+        day_text: palette.text,
+        day_hovered_background: palette.highlight.into(),
+        range_background: palette.accent_weak.scale_alpha(0.4).into(),
+        endpoint_background: palette.accent.into(),
+        endpoint_text: palette.accent_text,
+        today_border: palette.accent,
+    }
+}
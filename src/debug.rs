@@ -0,0 +1,422 @@
+//! Inspector overlay showing what the widgets keep to themselves.
+//!
+//! Wrapping the view in [`inspector`] and pressing F12 turns the inspector on for every
+//! widget. Trees outline each row they lay out with its internal and external ids, depth and
+//! expansion, and the branch a drag would drop on. Overlays outline their bounds, numbered in
+//! the order they are stacked from the bottom. A panel in the corner of the window sums up
+//! the trees, including how many of their branches are hidden under collapsed parents, and
+//! the drag under way.
+//!
+//! With the `profile` feature on as well, the panel also lists the timings of each kind of
+//! widget, as kept by [`profile::totals`](crate::profile::totals).
+//!
+//! Widgets draw their outlines in their own coordinates, so they line up inside scrollables.
+//! While the inspector is off they skip all of it.
+
+// Which parts the widgets use depends on the widgets enabled
+#![cfg_attr(not(all(feature = "tree", feature = "generic_overlay")), allow(dead_code))]
+
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use iced::{
+    advanced::{
+        layout::{self, Limits, Node},
+        mouse, overlay, renderer,
+        text,
+        widget::{self, tree::Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment::Vertical,
+    keyboard, Border, Color, Element, Event, Length, Pixels, Point, Rectangle, Size, Vector,
+};
+
+use crate::pixel;
+
+const LABEL_SIZE: f32 = 11.0;
+const LINE_HEIGHT: f32 = 15.0;
+const PANEL_PADDING: f32 = 8.0;
+const PANEL_WIDTH: f32 = 360.0;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+    static FRAME: RefCell<Frame> = const { RefCell::new(Frame { lines: Vec::new(), overlays: 0 }) };
+}
+
+/// What the widgets reported while drawing the current frame.
+struct Frame {
+    lines: Vec<String>,
+    overlays: usize,
+}
+
+/// Turns the inspector on or off for every widget.
+pub fn set_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// Whether the inspector is on.
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Turns the inspector on if it is off, and off if it is on.
+pub fn toggle() {
+    ENABLED.fetch_xor(true, Ordering::Relaxed);
+}
+
+/// What an outline shows, picking its color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Kind {
+    /// A whole widget
+    Widget,
+    /// A row or item inside a widget
+    Row,
+    /// Where a drag would drop
+    Drop,
+    /// An open overlay
+    Overlay,
+}
+
+impl Kind {
+    fn color(self) -> Color {
+        match self {
+            Self::Widget => Color::from_rgb(0.2, 0.6, 1.0),
+            Self::Row => Color::from_rgb(1.0, 0.55, 0.0),
+            Self::Drop => Color::from_rgb(0.1, 0.8, 0.3),
+            Self::Overlay => Color::from_rgb(0.9, 0.2, 0.8),
+        }
+    }
+}
+
+/// Outlines `bounds` with `label` in its top left corner.
+pub(crate) fn outline<Renderer>(renderer: &mut Renderer, bounds: Rectangle, kind: Kind, label: &str)
+where
+    Renderer: text::Renderer<Font = iced::Font>,
+{
+    let color = kind.color();
+
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds: pixel::snap(bounds),
+            border: Border {
+                color,
+                width: pixel::hairline(),
+                radius: 0.0.into(),
+            },
+            ..Default::default()
+        },
+        Color::TRANSPARENT,
+    );
+
+    if !label.is_empty() {
+        let position = Point::new(bounds.x + 2.0, bounds.y + 1.0);
+        draw_text(renderer, label, position, bounds.width - 4.0, color, bounds);
+    }
+}
+
+/// Adds a line to the inspector panel for the current frame.
+pub(crate) fn report(line: impl Into<String>) {
+    FRAME.with_borrow_mut(|frame| frame.lines.push(line.into()));
+}
+
+/// Numbers an overlay as it is drawn, counting from 1 at the bottom of the stack.
+pub(crate) fn stack() -> usize {
+    FRAME.with_borrow_mut(|frame| {
+        frame.overlays += 1;
+        frame.overlays
+    })
+}
+
+fn draw_text<Renderer>(renderer: &mut Renderer, content: &str, position: Point, width: f32, color: Color, clip: Rectangle)
+where
+    Renderer: text::Renderer<Font = iced::Font>,
+{
+    renderer.fill_text(
+        iced::advanced::Text {
+            content: content.to_owned(),
+            bounds: Size::new(width.max(0.0), LINE_HEIGHT),
+            size: Pixels(LABEL_SIZE),
+            font: iced::Font::MONOSPACE,
+            align_x: text::Alignment::Left,
+            align_y: Vertical::Top,
+            line_height: text::LineHeight::Absolute(Pixels(LINE_HEIGHT)),
+            shaping: text::Shaping::Basic,
+            wrapping: text::Wrapping::None,
+        },
+        position,
+        color,
+        clip,
+    );
+}
+
+/// Creates a new [`Inspector`] over `content`.
+pub fn inspector<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Inspector<'a, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer<Font = iced::Font>,
+{
+    Inspector::new(content)
+}
+
+/// Toggles the inspector with a hotkey and shows its panel over `content`.
+///
+/// Usually wraps the whole view. The hotkey works wherever focus is, and is not passed on to
+/// the content.
+#[allow(missing_debug_implementations)]
+pub struct Inspector<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+    key: keyboard::Key,
+    modifiers: keyboard::Modifiers,
+}
+
+impl<'a, Message, Theme, Renderer> Inspector<'a, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer<Font = iced::Font>,
+{
+    /// Creates a new [`Inspector`] over `content`, toggled with F12.
+    pub fn new(content: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        Self {
+            content: content.into(),
+            key: keyboard::Key::Named(keyboard::key::Named::F12),
+            modifiers: keyboard::Modifiers::empty(),
+        }
+    }
+
+    /// Sets the key and modifiers toggling the inspector, F12 without modifiers by default.
+    #[must_use]
+    pub fn hotkey(mut self, key: keyboard::Key, modifiers: keyboard::Modifiers) -> Self {
+        self.key = key;
+        self.modifiers = modifiers;
+        self
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Inspector<'_, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer<Font = iced::Font>,
+{
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn layout(&mut self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        self.content
+            .as_widget_mut()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        if let Event::Keyboard(keyboard::Event::KeyPressed { key, modifiers, .. }) = event
+            && *key == self.key
+            && *modifiers == self.modifiers
+        {
+            toggle();
+            shell.capture_event();
+            shell.request_redraw();
+            return;
+        }
+
+        self.content.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        // The widgets report again as they draw
+        FRAME.with_borrow_mut(|frame| {
+            frame.lines.clear();
+            frame.overlays = 0;
+        });
+
+        self.content
+            .as_widget()
+            .draw(&tree.children[0], renderer, theme, style, layout, cursor, viewport);
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content
+            .as_widget()
+            .mouse_interaction(&tree.children[0], layout, cursor, viewport, renderer)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn widget::Operation,
+    ) {
+        self.content
+            .as_widget_mut()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let content = self
+            .content
+            .as_widget_mut()
+            .overlay(&mut tree.children[0], layout, renderer, viewport, translation);
+
+        if !is_enabled() {
+            return content;
+        }
+
+        // The panel comes last, so the overlays below it have reported when it is drawn
+        let panel = overlay::Element::new(Box::new(Panel));
+
+        Some(
+            overlay::Group::with_children(content.into_iter().chain(std::iter::once(panel)).collect())
+                .overlay(),
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Inspector<'a, Message, Theme, Renderer>> for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: text::Renderer<Font = iced::Font> + 'a,
+{
+    fn from(inspector: Inspector<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(inspector)
+    }
+}
+
+/// The summary in the top right corner of the window.
+struct Panel;
+
+impl<Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer> for Panel
+where
+    Renderer: text::Renderer<Font = iced::Font>,
+{
+    fn layout(&mut self, _renderer: &Renderer, bounds: Size) -> Node {
+        layout::Node::new(bounds)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+    ) {
+        let window = layout.bounds();
+
+        let mut lines = FRAME.with_borrow(|frame| frame.lines.clone());
+        #[cfg(feature = "dnd")]
+        if let Some(drag) = crate::dnd::active() {
+            lines.push(format!(
+                "drag: {} {:?} at ({:.0}, {:.0}){}",
+                drag.payload.kind,
+                drag.payload.items,
+                drag.position.x,
+                drag.position.y,
+                if crate::dnd::is_over_target() { ", over a drop target" } else { "" },
+            ));
+        }
+        #[cfg(feature = "profile")]
+        for totals in crate::profile::totals() {
+            lines.push(format!(
+                "{} {}: {:.2} ms avg, {:.2} ms max, {} rows, {} quads",
+                totals.widget,
+                totals.phase,
+                totals.average().as_secs_f64() * 1000.0,
+                totals.max.as_secs_f64() * 1000.0,
+                totals.rows,
+                totals.quads,
+            ));
+        }
+        if lines.is_empty() {
+            lines.push("inspector: nothing reported".to_owned());
+        }
+
+        let panel = Rectangle {
+            x: window.x + window.width - PANEL_WIDTH - PANEL_PADDING,
+            y: window.y + PANEL_PADDING,
+            width: PANEL_WIDTH,
+            height: lines.len() as f32 * LINE_HEIGHT + PANEL_PADDING * 2.0,
+        };
+
+        renderer.with_layer(window, |renderer| {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: pixel::snap(panel),
+                    border: Border {
+                        color: Kind::Widget.color(),
+                        width: pixel::hairline(),
+                        radius: 4.0.into(),
+                    },
+                    ..Default::default()
+                },
+                Color::from_rgba(0.0, 0.0, 0.0, 0.8),
+            );
+
+            for (index, line) in lines.iter().enumerate() {
+                let position = Point::new(
+                    panel.x + PANEL_PADDING,
+                    panel.y + PANEL_PADDING + index as f32 * LINE_HEIGHT,
+                );
+                draw_text(renderer, line, position, panel.width - PANEL_PADDING * 2.0, Color::WHITE, panel);
+            }
+        });
+    }
+
+    fn index(&self) -> f32 {
+        f32::MAX
+    }
+}
@@ -0,0 +1,12 @@
+//! Depth metrics shared by the hierarchical widgets, so a [`Tree`] and nested
+//! collapsibles line up when used together. Their guide lines share a color through
+//! [`Palette::accent_weak`].
+//!
+//! [`Tree`]: crate::tree
+//! [`Palette::accent_weak`]: crate::theme::Palette::accent_weak
+
+/// The default horizontal offset applied per depth level.
+pub(crate) const DEFAULT_INDENT: f32 = 20.0;
+
+/// The width of the guide lines drawn alongside nested content.
+pub(crate) const GUIDE_WIDTH: f32 = 2.0;
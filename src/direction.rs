@@ -0,0 +1,105 @@
+//! Left-to-right and right-to-left layout.
+//!
+//! Widgets lay out horizontally in the app's [`Direction`], set once with [`set`], unless one
+//! is given its own with its `direction` method. Right to left mirrors what the widget draws
+//! itself, such as tree indentation and arrows, overlay header buttons, the order of tabs,
+//! table columns and chips, menu submenus, sidebar edges and slider tracks, for apps in
+//! languages like Arabic or Hebrew. The text inside is shaped by iced as usual. Widgets
+//! without a `direction` method are laid out left to right either way.
+//!
+//! Widgets keep their logic in left-to-right coordinates and mirror at the edges: the cursor
+//! on the way in with [`Direction::mirror_cursor`], and what they draw on the way out with
+//! [`Direction::mirror`].
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use iced::{advanced::mouse, alignment::Horizontal, Point, Rectangle};
+
+static RIGHT_TO_LEFT: AtomicBool = AtomicBool::new(false);
+
+/// Sets the direction of every widget without one of its own.
+pub fn set(direction: Direction) {
+    RIGHT_TO_LEFT.store(direction == Direction::RightToLeft, Ordering::Relaxed);
+}
+
+/// The direction set with [`set`], left to right by default.
+pub fn get() -> Direction {
+    if RIGHT_TO_LEFT.load(Ordering::Relaxed) {
+        Direction::RightToLeft
+    } else {
+        Direction::LeftToRight
+    }
+}
+
+/// The horizontal direction of a layout.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Direction {
+    /// Starting at the left, as in English
+    #[default]
+    LeftToRight,
+    /// Starting at the right, as in Arabic or Hebrew
+    RightToLeft,
+}
+
+impl Direction {
+    /// Whether this is [`Direction::RightToLeft`].
+    pub fn is_rtl(self) -> bool {
+        self == Self::RightToLeft
+    }
+
+    /// The side layouts start from.
+    pub fn start(self) -> Horizontal {
+        match self {
+            Self::LeftToRight => Horizontal::Left,
+            Self::RightToLeft => Horizontal::Right,
+        }
+    }
+
+    /// The side layouts end at.
+    pub fn end(self) -> Horizontal {
+        match self {
+            Self::LeftToRight => Horizontal::Right,
+            Self::RightToLeft => Horizontal::Left,
+        }
+    }
+
+    /// Mirrors a horizontal alignment, so left becomes right when right to left.
+    pub fn align(self, alignment: Horizontal) -> Horizontal {
+        match (self, alignment) {
+            (Self::RightToLeft, Horizontal::Left) => Horizontal::Right,
+            (Self::RightToLeft, Horizontal::Right) => Horizontal::Left,
+            (_, alignment) => alignment,
+        }
+    }
+
+    /// Mirrors `x` across the middle of `bounds` when right to left.
+    pub fn mirror_x(self, x: f32, bounds: Rectangle) -> f32 {
+        match self {
+            Self::LeftToRight => x,
+            Self::RightToLeft => bounds.x * 2.0 + bounds.width - x,
+        }
+    }
+
+    /// Mirrors `point` across the middle of `bounds` when right to left.
+    pub fn mirror_point(self, point: Point, bounds: Rectangle) -> Point {
+        Point::new(self.mirror_x(point.x, bounds), point.y)
+    }
+
+    /// Mirrors `rectangle` across the middle of `bounds` when right to left.
+    pub fn mirror(self, rectangle: Rectangle, bounds: Rectangle) -> Rectangle {
+        Rectangle {
+            x: self.mirror_x(rectangle.x, bounds) - if self.is_rtl() { rectangle.width } else { 0.0 },
+            ..rectangle
+        }
+    }
+
+    /// Mirrors the position of `cursor` across the middle of `bounds` when right to left.
+    pub fn mirror_cursor(self, cursor: mouse::Cursor, bounds: Rectangle) -> mouse::Cursor {
+        match cursor {
+            mouse::Cursor::Available(point) => mouse::Cursor::Available(self.mirror_point(point, bounds)),
+            mouse::Cursor::Levitating(point) => mouse::Cursor::Levitating(self.mirror_point(point, bounds)),
+            mouse::Cursor::Unavailable => mouse::Cursor::Unavailable,
+        }
+    }
+}
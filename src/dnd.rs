@@ -0,0 +1,1008 @@
+//! Drag and drop shared between widgets.
+//!
+//! A drag carries a [`Payload`]: a kind naming what is dragged, such as `"tree"` or `"tab"`,
+//! and the ids or indices of the dragged items. While one is under way it is held in a
+//! session every widget can see, so what is picked up in one widget can be dropped on another.
+//!
+//! [`DragSource`] makes any content draggable and shows a ghost of it under the cursor,
+//! [`DropTarget`] lights up while a payload it accepts is held over it and produces a message
+//! when one is dropped there. The tree, tab bar and collapsible group publish their own drags
+//! the same way, with the kinds [`TREE`], [`TAB`] and [`SECTION`]; a drop claimed by a target
+//! leaves their own order alone.
+//!
+//! Positions are in window coordinates, as the cursor is reported outside of scrollables.
+
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use iced::{
+    advanced::{
+        layout::{self, Limits, Node},
+        mouse, overlay, renderer,
+        widget::{self, tree::Tree, Operation},
+        Clipboard, Layout, Shell, Widget,
+    },
+    keyboard, Background, Border, Color, Element, Event, Length, Point, Rectangle, Shadow, Size, Vector,
+};
+
+use crate::pixel;
+
+/// Minimum distance the cursor has to travel before a press becomes a drag.
+pub const DRAG_THRESHOLD: f32 = 5.0;
+
+/// Payload kind of branches dragged out of a tree, carrying their external ids.
+pub const TREE: &str = "tree";
+/// Payload kind of a tab dragged out of a tab bar, carrying its index.
+pub const TAB: &str = "tab";
+/// Payload kind of a section dragged out of a collapsible group, carrying its index.
+pub const SECTION: &str = "section";
+
+/// Source of unique tokens identifying drop targets
+static NEXT_TARGET_TOKEN: AtomicU64 = AtomicU64::new(1);
+
+thread_local! {
+    static SESSION: RefCell<Option<Session>> = const { RefCell::new(None) };
+}
+
+/// What is being dragged.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Payload {
+    /// What kind of items are dragged, for targets to pick the drags they take
+    pub kind: String,
+    /// Ids or indices of the dragged items, as given by the source
+    pub items: Vec<usize>,
+}
+
+impl Payload {
+    /// Creates a payload of the given kind.
+    pub fn new(kind: impl Into<String>, items: impl IntoIterator<Item = usize>) -> Self {
+        Self {
+            kind: kind.into(),
+            items: items.into_iter().collect(),
+        }
+    }
+
+    /// Returns whether the payload is of the given kind.
+    pub fn is(&self, kind: &str) -> bool {
+        self.kind == kind
+    }
+}
+
+/// A drag under way, as returned by [`active`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Drag {
+    pub payload: Payload,
+    /// Last known cursor position
+    pub position: Point,
+}
+
+#[derive(Debug)]
+struct Session {
+    payload: Payload,
+    position: Point,
+    /// Drop target currently under the cursor
+    target: Option<u64>,
+    /// The source has seen the button go up, the hovered target still has to claim the drop
+    released: bool,
+    /// A target claimed the drop before the source saw the button go up
+    dropped: bool,
+}
+
+/// Starts a drag of `payload` at `position`, replacing any drag left over.
+pub fn start(payload: Payload, position: Point) {
+    SESSION.with_borrow_mut(|session| {
+        *session = Some(Session {
+            payload,
+            position,
+            target: None,
+            released: false,
+            dropped: false,
+        });
+    });
+}
+
+/// Moves the drag under way to `position`.
+pub fn update(position: Point) {
+    SESSION.with_borrow_mut(|session| {
+        if let Some(session) = session {
+            session.position = position;
+        }
+    });
+}
+
+/// Ends the drag from the source once the button is released.
+///
+/// Returns `true` if a drop target takes the payload, in which case the source should not
+/// act on the drop itself.
+pub fn finish() -> bool {
+    SESSION.with_borrow_mut(|current| {
+        let Some(session) = current else {
+            return false;
+        };
+
+        if session.dropped || session.target.is_none() {
+            let dropped = session.dropped;
+            *current = None;
+            return dropped;
+        }
+
+        session.released = true;
+        true
+    })
+}
+
+/// Abandons the drag under way.
+pub fn cancel() {
+    SESSION.with_borrow_mut(|session| *session = None);
+}
+
+/// The drag under way, if any.
+pub fn active() -> Option<Drag> {
+    SESSION.with_borrow(|session| {
+        session
+            .as_ref()
+            .filter(|session| !session.released && !session.dropped)
+            .map(|session| Drag {
+                payload: session.payload.clone(),
+                position: session.position,
+            })
+    })
+}
+
+/// Returns whether a drop target is under the cursor of the drag under way.
+pub fn is_over_target() -> bool {
+    SESSION.with_borrow(|session| {
+        session
+            .as_ref()
+            .is_some_and(|session| !session.released && session.target.is_some())
+    })
+}
+
+/// A press on a drag source, turning into a drag once the cursor has travelled
+/// [`DRAG_THRESHOLD`] away from where the button went down.
+///
+/// The tree, tab bar and collapsible group keep one per press, so they pick up, drop and
+/// cancel their drags the same way.
+#[allow(dead_code)] // Builds without the tree, tab bar or collapsible group never press
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Press {
+    /// Where the button went down, in window coordinates
+    pub origin: Point,
+    /// The press has turned into a drag
+    pub active: bool,
+}
+
+#[allow(dead_code)]
+impl Press {
+    pub fn new(origin: Point) -> Self {
+        Self { origin, active: false }
+    }
+
+    /// Follows the cursor, starting a drag of `payload` the moment the press turns into one
+    /// and returning `true` then. The payload is only built at that moment.
+    pub fn drag_to(&mut self, position: Point, payload: impl FnOnce() -> Payload) -> bool {
+        let started = !self.active && self.origin.distance(position) > DRAG_THRESHOLD;
+
+        if started {
+            self.active = true;
+            start(payload(), position);
+        } else if self.active {
+            update(position);
+        }
+
+        started
+    }
+
+    /// Ends the press once the button is released.
+    ///
+    /// Returns `true` if the source should act on the drop itself, which is when the press
+    /// turned into a drag and no drop target took the payload.
+    pub fn release(&self) -> bool {
+        self.active && !finish()
+    }
+
+    /// Abandons the press, and the drag it turned into if any.
+    pub fn cancel(&self) {
+        if self.active {
+            cancel();
+        }
+    }
+}
+
+/// A token identifying a new drop target.
+pub(crate) fn target_token() -> u64 {
+    NEXT_TARGET_TOKEN.fetch_add(1, Ordering::Relaxed)
+}
+
+/// Marks the target with `token` as under the cursor, or no longer under it.
+pub(crate) fn hover(token: u64, hovered: bool) {
+    SESSION.with_borrow_mut(|session| {
+        if let Some(session) = session {
+            if hovered {
+                session.target = Some(token);
+            } else if session.target == Some(token) {
+                session.target = None;
+            }
+        }
+    });
+}
+
+/// Claims the drop for the target with `token`, if it is the one under the cursor.
+pub(crate) fn take(token: u64) -> Option<Payload> {
+    SESSION.with_borrow_mut(|current| {
+        let session = current.as_mut()?;
+
+        if session.target != Some(token) || session.dropped {
+            return None;
+        }
+
+        let payload = session.payload.clone();
+        if session.released {
+            *current = None;
+        } else {
+            // The source has yet to see the release, it learns of the drop from `finish`
+            session.dropped = true;
+        }
+
+        Some(payload)
+    })
+}
+
+/// Creates a new [`DragSource`] around `content`, dragging `payload` when picked up.
+pub fn drag_source<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    payload: Payload,
+) -> DragSource<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    DragSource::new(content, payload)
+}
+
+/// Content that can be picked up and dropped on a [`DropTarget`].
+#[allow(missing_debug_implementations)]
+pub struct DragSource<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Theme: Catalog,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    payload: Payload,
+    enabled: bool,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> DragSource<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    /// Creates a new [`DragSource`] around `content`, dragging `payload` when picked up.
+    pub fn new(content: impl Into<Element<'a, Message, Theme, Renderer>>, payload: Payload) -> Self {
+        Self {
+            content: content.into(),
+            payload,
+            enabled: true,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets whether the content can be picked up.
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// Sets the style of the ghost following the cursor.
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class.
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+#[derive(Debug, Default)]
+struct SourceState {
+    /// Where the button went down, relative to the content
+    grab: Option<Vector>,
+    /// Where the button went down, before the press became a drag
+    origin: Point,
+    dragging: bool,
+    /// Cursor position during the drag, in window coordinates
+    position: Point,
+    /// Offset of the content from window coordinates, as of the last overlay request
+    translation: Vector,
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for DragSource<'_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<SourceState>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(SourceState::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn layout(&mut self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let content = self
+            .content
+            .as_widget_mut()
+            .layout(&mut tree.children[0], renderer, limits);
+
+        Node::with_children(content.size(), vec![content])
+    }
+
+    fn operate(&mut self, tree: &mut Tree, layout: Layout<'_>, renderer: &Renderer, operation: &mut dyn Operation) {
+        if let Some(content) = layout.children().next() {
+            self.content
+                .as_widget_mut()
+                .operate(&mut tree.children[0], content, renderer, operation);
+        }
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_mut::<SourceState>();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) if self.enabled => {
+                state.grab = cursor
+                    .position_over(bounds)
+                    .map(|position| position - bounds.position());
+                state.origin = cursor.position().unwrap_or_default();
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+                if state.grab.is_some()
+                    && !state.dragging
+                    && cursor
+                        .position()
+                        .is_some_and(|position| state.origin.distance(position) > DRAG_THRESHOLD) =>
+            {
+                state.dragging = true;
+                state.position = cursor.position().unwrap_or(state.origin) + state.translation;
+                start(self.payload.clone(), state.position);
+
+                shell.invalidate_layout();
+                shell.request_redraw();
+                shell.capture_event();
+                return;
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                state.grab = None;
+            }
+            _ => {}
+        }
+
+        if let Some(content) = layout.children().next() {
+            self.content.as_widget_mut().update(
+                &mut tree.children[0],
+                event,
+                content,
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            );
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        defaults: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        if let Some(content) = layout.children().next() {
+            self.content
+                .as_widget()
+                .draw(&tree.children[0], renderer, theme, defaults, content, cursor, viewport);
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let interaction = layout.children().next().map_or(mouse::Interaction::None, |content| {
+            self.content
+                .as_widget()
+                .mouse_interaction(&tree.children[0], content, cursor, viewport, renderer)
+        });
+
+        if interaction == mouse::Interaction::None && self.enabled && cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Grab
+        } else {
+            interaction
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_mut::<SourceState>();
+        state.translation = translation;
+
+        if state.dragging {
+            let content = layout.children().next()?;
+
+            return Some(overlay::Element::new(Box::new(Ghost {
+                content: &self.content,
+                tree,
+                layout: content,
+                class: &self.class,
+                viewport: *viewport,
+            })));
+        }
+
+        let content = layout.children().next()?;
+        self.content
+            .as_widget_mut()
+            .overlay(&mut tree.children[0], content, renderer, viewport, translation)
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<DragSource<'a, Message, Theme, Renderer>> for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(source: DragSource<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(source)
+    }
+}
+
+/// A copy of the dragged content following the cursor.
+struct Ghost<'a, 'b, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    content: &'a Element<'b, Message, Theme, Renderer>,
+    tree: &'a mut Tree,
+    layout: Layout<'a>,
+    class: &'a Theme::Class<'b>,
+    viewport: Rectangle,
+}
+
+impl<Message, Theme, Renderer> Ghost<'_, '_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    /// Where the content is drawn, in window coordinates.
+    fn bounds(&self) -> Rectangle {
+        let state = self.tree.state.downcast_ref::<SourceState>();
+        let grab = state.grab.unwrap_or_default();
+
+        Rectangle::new(state.position - grab, self.layout.bounds().size())
+    }
+
+    fn end(&mut self, shell: &mut Shell<'_, Message>) {
+        let state = self.tree.state.downcast_mut::<SourceState>();
+        state.dragging = false;
+        state.grab = None;
+
+        shell.invalidate_layout();
+        shell.request_redraw();
+        shell.capture_event();
+    }
+}
+
+impl<Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer> for Ghost<'_, '_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    fn layout(&mut self, _renderer: &Renderer, _bounds: Size) -> Node {
+        let bounds = self.bounds();
+
+        Node::new(bounds.size()).move_to(bounds.position())
+    }
+
+    fn update(
+        &mut self,
+        event: &Event,
+        _layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                if let Some(position) = cursor.position() {
+                    self.tree.state.downcast_mut::<SourceState>().position = position;
+                    update(position);
+
+                    shell.invalidate_layout();
+                    shell.request_redraw();
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                finish();
+                self.end(shell);
+            }
+            Event::Mouse(mouse::Event::CursorLeft)
+            | Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(keyboard::key::Named::Escape),
+                ..
+            }) => {
+                cancel();
+                self.end(shell);
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        defaults: &renderer::Style,
+        _layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        let bounds = self.bounds();
+        let style = theme.style(self.class, Status::Dragging);
+        let offset = bounds.position() - self.layout.bounds().position();
+
+        renderer.with_layer(self.viewport.union(&bounds), |renderer| {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: pixel::snap(bounds),
+                    border: pixel::border(style.border),
+                    shadow: style.shadow,
+                    ..renderer::Quad::default()
+                },
+                style.background.unwrap_or(Background::Color(Color::TRANSPARENT)),
+            );
+
+            renderer.with_translation(offset, |renderer| {
+                self.content.as_widget().draw(
+                    &self.tree.children[0],
+                    renderer,
+                    theme,
+                    defaults,
+                    self.layout,
+                    cursor,
+                    &Rectangle::new(self.layout.bounds().position(), bounds.size()),
+                );
+            });
+        });
+    }
+
+    fn mouse_interaction(&self, _layout: Layout<'_>, _cursor: mouse::Cursor, _renderer: &Renderer) -> mouse::Interaction {
+        mouse::Interaction::Grabbing
+    }
+}
+
+type Accepts<'a> = Box<dyn Fn(&Payload) -> bool + 'a>;
+
+/// Creates a new [`DropTarget`] around `content`, producing `on_drop` with dropped payloads.
+pub fn drop_target<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    on_drop: impl Fn(Payload) -> Message + 'a,
+) -> DropTarget<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    DropTarget::new(content, on_drop)
+}
+
+/// An area accepting payloads dragged from any widget.
+#[allow(missing_debug_implementations)]
+pub struct DropTarget<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Theme: Catalog,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    on_drop: Box<dyn Fn(Payload) -> Message + 'a>,
+    accepts: Option<Accepts<'a>>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> DropTarget<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    /// Creates a new [`DropTarget`] around `content`, producing `on_drop` with dropped payloads.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        on_drop: impl Fn(Payload) -> Message + 'a,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            on_drop: Box::new(on_drop),
+            accepts: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Only accepts the payloads for which `accepts` returns `true`, every payload otherwise.
+    pub fn accepts(mut self, accepts: impl Fn(&Payload) -> bool + 'a) -> Self {
+        self.accepts = Some(Box::new(accepts));
+        self
+    }
+
+    /// Only accepts payloads of one of `kinds`.
+    pub fn kinds(self, kinds: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        let kinds: Vec<String> = kinds.into_iter().map(Into::into).collect();
+
+        self.accepts(move |payload| kinds.contains(&payload.kind))
+    }
+
+    /// Sets the style.
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class.
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    fn takes(&self, payload: &Payload) -> bool {
+        self.accepts.as_ref().is_none_or(|accepts| accepts(payload))
+    }
+}
+
+#[derive(Debug)]
+struct TargetState {
+    token: u64,
+    status: Status,
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for DropTarget<'_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<TargetState>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(TargetState {
+            token: target_token(),
+            status: Status::Idle,
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn layout(&mut self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let content = self
+            .content
+            .as_widget_mut()
+            .layout(&mut tree.children[0], renderer, limits);
+
+        layout::Node::with_children(content.size(), vec![content])
+    }
+
+    fn operate(&mut self, tree: &mut Tree, layout: Layout<'_>, renderer: &Renderer, operation: &mut dyn Operation) {
+        if let Some(content) = layout.children().next() {
+            self.content
+                .as_widget_mut()
+                .operate(&mut tree.children[0], content, renderer, operation);
+        }
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        if let Some(content) = layout.children().next() {
+            self.content.as_widget_mut().update(
+                &mut tree.children[0],
+                event,
+                content,
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            );
+        }
+
+        let state = tree.state.downcast_mut::<TargetState>();
+
+        if let Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) = event
+            && state.status == Status::Hovered
+        {
+            if let Some(payload) = take(state.token) {
+                shell.publish((self.on_drop)(payload));
+                shell.capture_event();
+            }
+            state.status = Status::Idle;
+            shell.request_redraw();
+            return;
+        }
+
+        if !matches!(event, Event::Mouse(_) | Event::Keyboard(_)) {
+            return;
+        }
+
+        let status = match active() {
+            Some(drag) if self.takes(&drag.payload) => {
+                // An overlay drawing the drag hides the cursor from widgets
+                let position = cursor.position().unwrap_or(drag.position);
+                let hovered = layout.bounds().contains(position);
+                hover(state.token, hovered);
+
+                if hovered { Status::Hovered } else { Status::Available }
+            }
+            _ => {
+                hover(state.token, false);
+                Status::Idle
+            }
+        };
+
+        if status != state.status {
+            state.status = status;
+            shell.request_redraw();
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        defaults: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<TargetState>();
+        let style = theme.style(&self.class, state.status);
+        let bounds = layout.bounds();
+
+        if let Some(content) = layout.children().next() {
+            self.content
+                .as_widget()
+                .draw(&tree.children[0], renderer, theme, defaults, content, cursor, viewport);
+        }
+
+        // Drawn over the content, which usually has a background of its own
+        if style.background.is_some() || style.border.width > 0.0 {
+            renderer.with_layer(bounds, |renderer| {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: pixel::snap(bounds),
+                        border: pixel::border(style.border),
+                        shadow: style.shadow,
+                        ..renderer::Quad::default()
+                    },
+                    style.background.unwrap_or(Background::Color(Color::TRANSPARENT)),
+                );
+            });
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        layout.children().next().map_or(mouse::Interaction::None, |content| {
+            self.content
+                .as_widget()
+                .mouse_interaction(&tree.children[0], content, cursor, viewport, renderer)
+        })
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let content = layout.children().next()?;
+
+        self.content
+            .as_widget_mut()
+            .overlay(&mut tree.children[0], content, renderer, viewport, translation)
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<DropTarget<'a, Message, Theme, Renderer>> for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(target: DropTarget<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(target)
+    }
+}
+
+/// The state of a [`DropTarget`], or the ghost of a [`DragSource`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// Nothing the target accepts is being dragged
+    Idle,
+    /// A payload the target accepts is being dragged elsewhere
+    Available,
+    /// A payload the target accepts is held over it
+    Hovered,
+    /// The ghost of a [`DragSource`] following the cursor
+    Dragging,
+}
+
+/// The theme catalog of the drag and drop widgets.
+pub trait Catalog {
+    /// The style class
+    type Class<'a>;
+
+    /// Default style
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// Get the style for a class and status
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+/// The appearance of a [`DropTarget`] or the ghost of a [`DragSource`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// Tint drawn over the target, or behind the ghost
+    pub background: Option<Background>,
+    /// Border around the target or the ghost
+    pub border: Border,
+    /// Shadow under the target or the ghost
+    pub shadow: Shadow,
+}
+
+/// Styling function
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for iced::Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default style, outlining available targets and tinting the hovered one.
+pub fn default(theme: &iced::Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+
+    match status {
+        Status::Idle => Style {
+            background: None,
+            border: Border::default(),
+            shadow: Shadow::default(),
+        },
+        Status::Available => Style {
+            background: None,
+            border: Border {
+                color: palette.primary.weak.color,
+                width: 1.0,
+                radius: 6.0.into(),
+            },
+            shadow: Shadow::default(),
+        },
+        Status::Hovered => Style {
+            background: Some(palette.primary.weak.color.scale_alpha(0.2).into()),
+            border: Border {
+                color: palette.primary.base.color,
+                width: 2.0,
+                radius: 6.0.into(),
+            },
+            shadow: Shadow::default(),
+        },
+        Status::Dragging => Style {
+            background: Some(palette.background.weak.color.into()),
+            border: Border {
+                color: palette.primary.base.color,
+                width: 1.0,
+                radius: 6.0.into(),
+            },
+            shadow: Shadow {
+                color: Color::BLACK.scale_alpha(0.3),
+                offset: Vector::new(0.0, 4.0),
+                blur_radius: 12.0,
+            },
+        },
+    }
+}
+
+/// Only marks the hovered target, leaving the others as they are during a drag.
+pub fn subtle(theme: &iced::Theme, status: Status) -> Style {
+    match status {
+        Status::Available => default(theme, Status::Idle),
+        status => default(theme, status),
+    }
+}
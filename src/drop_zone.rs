@@ -0,0 +1,474 @@
+//! An area that accepts files dragged in from outside the app.
+//!
+//! [`DropZone`] listens to the window's [`FileHovered`] and [`FileDropped`] events, lights up
+//! while files are held over it and produces a message with the paths once they are dropped
+//! there. With [`DropZone::extensions`] it only takes files with those extensions, and shows
+//! files it would refuse differently.
+//!
+//! Files dropped at once arrive as one message, even though the window reports them one by
+//! one. Not every platform reports the cursor while files are dragged; when it is unknown the
+//! zone counts as under the files.
+//!
+//! [`FileHovered`]: iced::window::Event::FileHovered
+//! [`FileDropped`]: iced::window::Event::FileDropped
+
+use std::path::{Path, PathBuf};
+
+use iced::{
+    advanced::{
+        layout::{self, Limits, Node},
+        mouse, overlay, renderer,
+        widget::{self, tree::Tree, Operation},
+        Clipboard, Layout, Shell, Widget,
+    },
+    window, Alignment, Background, Border, Color, Element, Event, Length, Padding, Rectangle, Size, Vector,
+};
+
+use crate::pixel;
+
+/// Creates a new [`DropZone`] around `content`, producing `on_drop` with the dropped paths.
+pub fn drop_zone<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    on_drop: impl Fn(Vec<PathBuf>) -> Message + 'a,
+) -> DropZone<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    DropZone::new(content, on_drop)
+}
+
+/// An area accepting dropped files.
+#[allow(missing_debug_implementations)]
+pub struct DropZone<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Theme: Catalog,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    on_drop: Box<dyn Fn(Vec<PathBuf>) -> Message + 'a>,
+    on_reject: Option<Box<dyn Fn(Vec<PathBuf>) -> Message + 'a>>,
+    extensions: Vec<String>,
+    width: Length,
+    height: Length,
+    padding: Padding,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> DropZone<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    /// Creates a new [`DropZone`] around `content`, producing `on_drop` with the dropped paths.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        on_drop: impl Fn(Vec<PathBuf>) -> Message + 'a,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            on_drop: Box::new(on_drop),
+            on_reject: None,
+            extensions: Vec::new(),
+            width: Length::Fill,
+            height: Length::Shrink,
+            padding: Padding::new(20.0),
+            class: Theme::default(),
+        }
+    }
+
+    /// Only accepts files with one of `extensions`, given without the dot and matched
+    /// regardless of case.
+    pub fn extensions(mut self, extensions: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.extensions = extensions
+            .into_iter()
+            .map(|extension| extension.into().trim_start_matches('.').to_lowercase())
+            .collect();
+        self
+    }
+
+    /// Produces `on_reject` with the dropped files that were refused by
+    /// [`DropZone::extensions`].
+    pub fn on_reject(mut self, on_reject: impl Fn(Vec<PathBuf>) -> Message + 'a) -> Self {
+        self.on_reject = Some(Box::new(on_reject));
+        self
+    }
+
+    /// Sets the width.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height.
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the padding around the content, which is centered in the zone.
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the style.
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class.
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    fn accepts(&self, path: &Path) -> bool {
+        self.extensions.is_empty()
+            || path
+                .extension()
+                .and_then(|extension| extension.to_str())
+                .is_some_and(|extension| self.extensions.contains(&extension.to_lowercase()))
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    /// Files held over the window
+    hovered: Vec<PathBuf>,
+    /// Whether the files are over the zone
+    is_over: bool,
+    /// Files of the current drop reported so far
+    dropped: Vec<PathBuf>,
+}
+
+impl<Message, Theme, Renderer> DropZone<'_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    fn status(&self, state: &State) -> Status {
+        if !state.is_over || state.hovered.is_empty() {
+            Status::Idle
+        } else if state.hovered.iter().any(|path| self.accepts(path)) {
+            Status::Hovered
+        } else {
+            Status::Rejected
+        }
+    }
+
+    fn finish_drop(&self, state: &mut State, shell: &mut Shell<'_, Message>) {
+        let (accepted, rejected): (Vec<_>, Vec<_>) =
+            std::mem::take(&mut state.dropped).into_iter().partition(|path| self.accepts(path));
+        state.hovered.clear();
+        state.is_over = false;
+
+        if !accepted.is_empty() {
+            shell.publish((self.on_drop)(accepted));
+        }
+        if !rejected.is_empty()
+            && let Some(on_reject) = &self.on_reject
+        {
+            shell.publish(on_reject(rejected));
+        }
+        shell.request_redraw();
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for DropZone<'_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn layout(&mut self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        layout::positioned(
+            limits,
+            self.width,
+            self.height,
+            self.padding,
+            |limits| {
+                self.content
+                    .as_widget_mut()
+                    .layout(&mut tree.children[0], renderer, limits)
+            },
+            |content, size| content.align(Alignment::Center, Alignment::Center, size),
+        )
+    }
+
+    fn operate(&mut self, tree: &mut Tree, layout: Layout<'_>, renderer: &Renderer, operation: &mut dyn Operation) {
+        if let Some(content) = layout.children().next() {
+            self.content
+                .as_widget_mut()
+                .operate(&mut tree.children[0], content, renderer, operation);
+        }
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        if let Some(content) = layout.children().next() {
+            self.content.as_widget_mut().update(
+                &mut tree.children[0],
+                event,
+                content,
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            );
+        }
+
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+        let is_over = cursor.position().is_none_or(|position| bounds.contains(position));
+
+        match event {
+            Event::Window(window::Event::FileHovered(path)) => {
+                // A new drag starts while the last drop is still being reported
+                if !state.dropped.is_empty() {
+                    self.finish_drop(state, shell);
+                }
+
+                state.hovered.push(path.clone());
+                state.is_over = is_over;
+                shell.request_redraw();
+            }
+            Event::Window(window::Event::FilesHoveredLeft) => {
+                state.hovered.clear();
+                state.is_over = false;
+                shell.request_redraw();
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+                if !state.hovered.is_empty() && is_over != state.is_over =>
+            {
+                state.is_over = is_over;
+                shell.request_redraw();
+            }
+            Event::Window(window::Event::FileDropped(path)) => {
+                if !is_over {
+                    state.hovered.retain(|hovered| hovered != path);
+                    state.is_over = false;
+                    return;
+                }
+
+                state.dropped.push(path.clone());
+
+                // The whole drop is in once every hovered file has been dropped
+                if state.dropped.len() >= state.hovered.len() {
+                    self.finish_drop(state, shell);
+                }
+            }
+            Event::Window(window::Event::RedrawRequested(_)) if !state.dropped.is_empty() => {
+                self.finish_drop(state, shell);
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        defaults: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let style = theme.style(&self.class, self.status(state));
+        let bounds = layout.bounds();
+
+        if style.background.is_some() || style.border.width > 0.0 {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: pixel::snap(bounds),
+                    border: pixel::border(style.border),
+                    ..renderer::Quad::default()
+                },
+                style.background.unwrap_or(Background::Color(Color::TRANSPARENT)),
+            );
+        }
+
+        if let Some(content) = layout.children().next() {
+            self.content.as_widget().draw(
+                &tree.children[0],
+                renderer,
+                theme,
+                &renderer::Style {
+                    text_color: style.text_color.unwrap_or(defaults.text_color),
+                },
+                content,
+                cursor,
+                viewport,
+            );
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        layout.children().next().map_or(mouse::Interaction::None, |content| {
+            self.content
+                .as_widget()
+                .mouse_interaction(&tree.children[0], content, cursor, viewport, renderer)
+        })
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let content = layout.children().next()?;
+
+        self.content
+            .as_widget_mut()
+            .overlay(&mut tree.children[0], content, renderer, viewport, translation)
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<DropZone<'a, Message, Theme, Renderer>> for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(zone: DropZone<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(zone)
+    }
+}
+
+/// The state of a [`DropZone`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// No files are held over the zone
+    Idle,
+    /// Files the zone accepts are held over it
+    Hovered,
+    /// Only files the zone refuses are held over it
+    Rejected,
+}
+
+/// The theme catalog of a [`DropZone`].
+pub trait Catalog {
+    /// The style class
+    type Class<'a>;
+
+    /// Default style
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// Get the style for a class and status
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+/// The appearance of a [`DropZone`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// Background of the zone
+    pub background: Option<Background>,
+    /// Border of the zone
+    pub border: Border,
+    /// Text color given to the content, the inherited one otherwise
+    pub text_color: Option<Color>,
+}
+
+/// Styling function
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for iced::Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default drop zone style, a bordered panel tinted while files hover.
+pub fn default(theme: &iced::Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+    let (background, border, text_color) = match status {
+        Status::Idle => (palette.background.weakest.color, palette.background.strong.color, None),
+        Status::Hovered => (
+            palette.primary.weak.color.scale_alpha(0.25),
+            palette.primary.base.color,
+            Some(palette.primary.strong.color),
+        ),
+        Status::Rejected => (
+            palette.danger.weak.color.scale_alpha(0.25),
+            palette.danger.base.color,
+            Some(palette.danger.strong.color),
+        ),
+    };
+
+    Style {
+        background: Some(background.into()),
+        border: Border {
+            color: border,
+            width: if status == Status::Idle { 1.0 } else { 2.0 },
+            radius: 8.0.into(),
+        },
+        text_color,
+    }
+}
+
+/// Invisible until files hover, for turning a whole screen into a drop target.
+pub fn hidden(theme: &iced::Theme, status: Status) -> Style {
+    match status {
+        Status::Idle => Style {
+            background: None,
+            border: Border::default(),
+            text_color: None,
+        },
+        Status::Hovered | Status::Rejected => default(theme, status),
+    }
+}
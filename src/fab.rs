@@ -0,0 +1,851 @@
+//! A floating action button in a corner of the app, opening into a speed dial of actions.
+//!
+//! A [`Fab`] wraps the app's content and keeps a round button over one of its corners. Clicking
+//! it, or hovering it with [`Fab::open_on_hover`], stacks small labeled buttons for each
+//! [`Action`] away from the corner; picking one produces its message and closes the dial.
+//! Escape or a click anywhere else closes it too. With a single action, or none, the button
+//! does not open anything and produces [`Fab::on_press`] instead.
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        mouse, overlay, renderer, text,
+        widget::{self, tree::Tree, Operation},
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment, keyboard, Background, Border, Color, Element, Event, Length, Pixels, Point, Rectangle, Shadow, Size,
+    Vector,
+};
+
+use crate::pixel;
+
+const LABEL_HEIGHT: f32 = 28.0;
+const LABEL_PADDING: f32 = 10.0;
+const LABEL_GAP: f32 = 12.0;
+
+/// Creates a new [`Fab`] floating over `content`.
+pub fn fab<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Fab<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer<Font = iced::Font>,
+{
+    Fab::new(content)
+}
+
+/// The corner of the content a [`Fab`] floats in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Corner {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    #[default]
+    BottomRight,
+}
+
+/// An entry of the speed dial of a [`Fab`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Action<Message> {
+    icon: String,
+    label: String,
+    message: Message,
+}
+
+impl<Message> Action<Message> {
+    /// Creates a new [`Action`] with a short `icon` text, such as a symbol or an icon font glyph.
+    pub fn new(icon: impl Into<String>, label: impl Into<String>, message: Message) -> Self {
+        Self {
+            icon: icon.into(),
+            label: label.into(),
+            message,
+        }
+    }
+}
+
+/// A floating button over some content, with a speed dial of actions.
+#[allow(missing_debug_implementations)]
+pub struct Fab<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Theme: Catalog,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    button: Button<'a, Message, Theme>,
+}
+
+/// Everything but the content, borrowed by the speed dial while the content lends its overlay.
+struct Button<'a, Message, Theme>
+where
+    Theme: Catalog,
+{
+    actions: Vec<Action<Message>>,
+    on_press: Option<Message>,
+    icon: String,
+    close_icon: String,
+    corner: Corner,
+    margin: f32,
+    size: f32,
+    mini_size: f32,
+    spacing: f32,
+    open_on_hover: bool,
+    text_size: f32,
+    font: iced::Font,
+    icon_font: iced::Font,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> Fab<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer<Font = iced::Font>,
+{
+    /// Creates a new [`Fab`] floating over `content`.
+    pub fn new(content: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        Self {
+            content: content.into(),
+            button: Button {
+                actions: Vec::new(),
+                on_press: None,
+                icon: String::from("+"),
+                close_icon: String::from("×"),
+                corner: Corner::default(),
+                margin: 24.0,
+                size: 56.0,
+                mini_size: 40.0,
+                spacing: 12.0,
+                open_on_hover: false,
+                text_size: 14.0,
+                font: iced::Font::default(),
+                icon_font: iced::Font::default(),
+                class: Theme::default(),
+            },
+        }
+    }
+
+    /// Adds an action to the speed dial, listed from the button outwards.
+    pub fn action(mut self, action: Action<Message>) -> Self {
+        self.button.actions.push(action);
+        self
+    }
+
+    /// Adds several actions to the speed dial.
+    pub fn actions(mut self, actions: impl IntoIterator<Item = Action<Message>>) -> Self {
+        self.button.actions.extend(actions);
+        self
+    }
+
+    /// Sets the message produced by the button when it has no speed dial to open.
+    pub fn on_press(mut self, message: Message) -> Self {
+        self.button.on_press = Some(message);
+        self
+    }
+
+    /// Sets the text of the button, and of the button while the dial is open.
+    pub fn icon(mut self, icon: impl Into<String>, close_icon: impl Into<String>) -> Self {
+        self.button.icon = icon.into();
+        self.button.close_icon = close_icon.into();
+        self
+    }
+
+    /// Sets the corner the button floats in.
+    pub fn corner(mut self, corner: Corner) -> Self {
+        self.button.corner = corner;
+        self
+    }
+
+    /// Sets the distance from the button to the edges of the content.
+    pub fn margin(mut self, margin: impl Into<Pixels>) -> Self {
+        self.button.margin = margin.into().0;
+        self
+    }
+
+    /// Sets the diameter of the button.
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.button.size = size.into().0;
+        self
+    }
+
+    /// Sets the diameter of the buttons in the speed dial.
+    pub fn mini_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.button.mini_size = size.into().0;
+        self
+    }
+
+    /// Sets the space between the buttons of the speed dial.
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.button.spacing = spacing.into().0;
+        self
+    }
+
+    /// Opens the speed dial when the button is hovered, closing it once the cursor leaves.
+    pub fn open_on_hover(mut self, open_on_hover: bool) -> Self {
+        self.button.open_on_hover = open_on_hover;
+        self
+    }
+
+    /// Sets the text size of the labels.
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.button.text_size = size.into().0;
+        self
+    }
+
+    /// Sets the font of the labels.
+    pub fn font(mut self, font: impl Into<iced::Font>) -> Self {
+        self.button.font = font.into();
+        self
+    }
+
+    /// Sets the font of the icons, such as an icon font.
+    pub fn icon_font(mut self, font: impl Into<iced::Font>) -> Self {
+        self.button.icon_font = font.into();
+        self
+    }
+
+    /// Sets the style.
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.button.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class.
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.button.class = class.into();
+        self
+    }
+}
+
+impl<Message, Theme> Button<'_, Message, Theme>
+where
+    Message: Clone,
+    Theme: Catalog,
+{
+    fn has_dial(&self) -> bool {
+        self.actions.len() > 1
+    }
+
+    /// Where the button sits over content in `bounds`.
+    fn bounds(&self, bounds: Rectangle) -> Rectangle {
+        let x = match self.corner {
+            Corner::TopLeft | Corner::BottomLeft => bounds.x + self.margin,
+            Corner::TopRight | Corner::BottomRight => bounds.x + bounds.width - self.margin - self.size,
+        };
+        let y = match self.corner {
+            Corner::TopLeft | Corner::TopRight => bounds.y + self.margin,
+            Corner::BottomLeft | Corner::BottomRight => bounds.y + bounds.height - self.margin - self.size,
+        };
+
+        Rectangle::new(Point::new(x, y), Size::new(self.size, self.size))
+    }
+
+    fn press(&self, state: &mut State, shell: &mut Shell<'_, Message>) {
+        if self.has_dial() {
+            state.open = !state.open;
+            state.hovered = None;
+        } else if let Some(message) = self
+            .actions
+            .first()
+            .map(|action| action.message.clone())
+            .or_else(|| self.on_press.clone())
+        {
+            shell.publish(message);
+        }
+    }
+
+    fn text(&self, content: &str, font: iced::Font, size: f32, bounds: Size) -> iced::advanced::Text<String, iced::Font> {
+        iced::advanced::Text {
+            content: content.to_string(),
+            bounds,
+            size: Pixels(size),
+            font,
+            align_x: text::Alignment::Center,
+            align_y: alignment::Vertical::Center,
+            line_height: text::LineHeight::default(),
+            shaping: text::Shaping::Advanced,
+            wrapping: text::Wrapping::None,
+        }
+    }
+
+    /// Draws a round button with `icon` in the middle.
+    fn draw_button<Renderer: text::Renderer<Font = iced::Font>>(
+        &self,
+        renderer: &mut Renderer,
+        bounds: Rectangle,
+        icon: &str,
+        background: Background,
+        color: Color,
+        shadow: Shadow,
+    ) {
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: pixel::snap(bounds),
+                border: pixel::border(Border {
+                    radius: (bounds.width / 2.0).into(),
+                    ..Border::default()
+                }),
+                shadow,
+                ..renderer::Quad::default()
+            },
+            background,
+        );
+        renderer.fill_text(
+            self.text(icon, self.icon_font, bounds.height * 0.45, bounds.size()),
+            bounds.center(),
+            color,
+            bounds,
+        );
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    open: bool,
+    /// The action under the cursor
+    hovered: Option<usize>,
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Fab<'_, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer<Font = iced::Font>,
+{
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn layout(&mut self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let content = self.content.as_widget_mut().layout(&mut tree.children[0], renderer, limits);
+
+        Node::with_children(content.size(), vec![content])
+    }
+
+    fn operate(&mut self, tree: &mut Tree, layout: Layout<'_>, renderer: &Renderer, operation: &mut dyn Operation) {
+        if let Some(content_layout) = layout.children().next() {
+            self.content
+                .as_widget_mut()
+                .operate(&mut tree.children[0], content_layout, renderer, operation);
+        }
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+        let button = self.button.bounds(layout.bounds());
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) if cursor.is_over(button) => {
+                self.button.press(state, shell);
+                shell.capture_event();
+                shell.request_redraw();
+                return;
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                if self.button.open_on_hover && self.button.has_dial() && !state.open && cursor.is_over(button) {
+                    state.open = true;
+                    state.hovered = None;
+                }
+                shell.request_redraw();
+            }
+            _ => {}
+        }
+
+        // The content does not see the cursor through the button
+        let cursor = if cursor.is_over(button) {
+            mouse::Cursor::Unavailable
+        } else {
+            cursor
+        };
+
+        if let Some(content_layout) = layout.children().next() {
+            self.content.as_widget_mut().update(
+                &mut tree.children[0],
+                event,
+                content_layout,
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            );
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        defaults: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let button = self.button.bounds(bounds);
+        let state = tree.state.downcast_ref::<State>();
+
+        if let Some(content_layout) = layout.children().next() {
+            let cursor = if cursor.is_over(button) {
+                mouse::Cursor::Unavailable
+            } else {
+                cursor
+            };
+
+            self.content.as_widget().draw(
+                &tree.children[0],
+                renderer,
+                theme,
+                defaults,
+                content_layout,
+                cursor,
+                viewport,
+            );
+        }
+
+        // While open the dial draws the button, above its backdrop
+        if state.open {
+            return;
+        }
+
+        let status = if cursor.is_over(button) {
+            Status::Hovered
+        } else {
+            Status::Active
+        };
+        let style = theme.style(&self.button.class, status);
+
+        renderer.with_layer(button.expand(style.shadow.blur_radius * 2.0 + 8.0), |renderer| {
+            self.button.draw_button(renderer, button, &self.button.icon, style.background, style.icon, style.shadow);
+        });
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if cursor.is_over(self.button.bounds(layout.bounds())) {
+            return mouse::Interaction::Pointer;
+        }
+
+        layout.children().next().map_or(mouse::Interaction::None, |content_layout| {
+            self.content
+                .as_widget()
+                .mouse_interaction(&tree.children[0], content_layout, cursor, viewport, renderer)
+        })
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let button = self.button.bounds(layout.bounds()) + translation;
+        let (state, children) = (&mut tree.state, &mut tree.children);
+        let state = state.downcast_mut::<State>();
+
+        let content = layout.children().next().and_then(|content_layout| {
+            self.content
+                .as_widget_mut()
+                .overlay(&mut children[0], content_layout, renderer, viewport, translation)
+        });
+
+        let dial = (state.open && self.button.has_dial()).then(|| {
+            overlay::Element::new(Box::new(Dial {
+                fab: &self.button,
+                state,
+                button,
+                items: Vec::new(),
+            }))
+        });
+
+        match (content, dial) {
+            (Some(content), Some(dial)) => Some(overlay::Group::with_children(vec![content, dial]).overlay()),
+            (content, dial) => content.or(dial),
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Fab<'a, Message, Theme, Renderer>> for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer<Font = iced::Font> + 'a,
+{
+    fn from(fab: Fab<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(fab)
+    }
+}
+
+/// The open speed dial of a [`Fab`], over the whole window.
+struct Dial<'a, 'b, Message, Theme>
+where
+    Theme: Catalog,
+{
+    fab: &'a Button<'b, Message, Theme>,
+    state: &'a mut State,
+    button: Rectangle,
+    /// The mini button and label of each action, laid out with the window
+    items: Vec<(Rectangle, Rectangle)>,
+}
+
+impl<Message, Theme> Dial<'_, '_, Message, Theme>
+where
+    Message: Clone,
+    Theme: Catalog,
+{
+    fn item_at(&self, point: Point) -> Option<usize> {
+        self.items
+            .iter()
+            .position(|(mini, label)| mini.contains(point) || label.contains(point))
+    }
+
+    /// Whether `point` is over the button, an action or the gaps between them.
+    fn is_over(&self, point: Point) -> bool {
+        self.items
+            .iter()
+            .fold(self.button, |area, (mini, label)| area.union(mini).union(label))
+            .expand(self.fab.spacing / 2.0)
+            .contains(point)
+    }
+
+    fn close(&mut self, shell: &mut Shell<'_, Message>) {
+        self.state.open = false;
+        self.state.hovered = None;
+        shell.request_redraw();
+    }
+}
+
+impl<Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer> for Dial<'_, '_, Message, Theme>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer<Font = iced::Font>,
+{
+    fn layout(&mut self, _renderer: &Renderer, bounds: Size) -> Node {
+        let fab = self.fab;
+        let upwards = matches!(fab.corner, Corner::BottomLeft | Corner::BottomRight);
+        let labels_left = matches!(fab.corner, Corner::TopRight | Corner::BottomRight);
+        let step = fab.mini_size + fab.spacing;
+        let first = fab.size / 2.0 + fab.spacing + fab.mini_size / 2.0;
+
+        self.items = fab
+            .actions
+            .iter()
+            .enumerate()
+            .map(|(index, action)| {
+                let distance = first + index as f32 * step;
+                let center = Point::new(
+                    self.button.center_x(),
+                    if upwards {
+                        self.button.center_y() - distance
+                    } else {
+                        self.button.center_y() + distance
+                    },
+                );
+                let mini = Rectangle::new(
+                    Point::new(center.x - fab.mini_size / 2.0, center.y - fab.mini_size / 2.0),
+                    Size::new(fab.mini_size, fab.mini_size),
+                );
+
+                let text = fab.text(&action.label, fab.font, fab.text_size, Size::INFINITE);
+                let width = measure::<Renderer::Paragraph>(&text).width + LABEL_PADDING * 2.0;
+                let x = if labels_left {
+                    self.button.center_x() - fab.size / 2.0 - LABEL_GAP - width
+                } else {
+                    self.button.center_x() + fab.size / 2.0 + LABEL_GAP
+                };
+                let label = Rectangle::new(
+                    Point::new(x, center.y - LABEL_HEIGHT / 2.0),
+                    Size::new(width, LABEL_HEIGHT),
+                );
+
+                (mini, label)
+            })
+            .collect();
+
+        Node::new(bounds)
+    }
+
+    fn update(
+        &mut self,
+        event: &Event,
+        _layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                if self.fab.open_on_hover && !self.is_over(*position) {
+                    self.close(shell);
+                    return;
+                }
+
+                let hovered = self.item_at(*position);
+                if hovered != self.state.hovered {
+                    self.state.hovered = hovered;
+                    shell.request_redraw();
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let Some(position) = cursor.position() else {
+                    return;
+                };
+
+                if let Some(index) = self.item_at(position) {
+                    shell.publish(self.fab.actions[index].message.clone());
+                    self.close(shell);
+                    shell.capture_event();
+                } else if self.button.contains(position) {
+                    self.close(shell);
+                    shell.capture_event();
+                } else {
+                    self.close(shell);
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(keyboard::key::Named::Escape),
+                ..
+            }) => {
+                self.close(shell);
+                shell.capture_event();
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _defaults: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        let fab = self.fab;
+        let style = theme.style(&fab.class, Status::Open);
+
+        if style.backdrop.a > 0.0 {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: pixel::snap(layout.bounds()),
+                    ..renderer::Quad::default()
+                },
+                style.backdrop,
+            );
+        }
+
+        for (index, (action, (mini, label))) in fab.actions.iter().zip(&self.items).enumerate() {
+            let hovered = self.state.hovered == Some(index);
+
+            renderer.with_layer(mini.union(label).expand(style.shadow.blur_radius * 2.0 + 8.0), |renderer| {
+                fab.draw_button(
+                    renderer,
+                    *mini,
+                    &action.icon,
+                    if hovered { style.mini_hovered_background } else { style.mini_background },
+                    style.mini_icon,
+                    style.shadow,
+                );
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: pixel::snap(*label),
+                        border: pixel::border(Border {
+                            radius: 4.0.into(),
+                            ..Border::default()
+                        }),
+                        shadow: style.shadow,
+                        ..renderer::Quad::default()
+                    },
+                    style.label_background,
+                );
+                renderer.fill_text(
+                    fab.text(&action.label, fab.font, fab.text_size, label.size()),
+                    label.center(),
+                    style.label_text,
+                    *label,
+                );
+            });
+        }
+
+        let status = if cursor.is_over(self.button) {
+            Status::Hovered
+        } else {
+            Status::Open
+        };
+        let button_style = theme.style(&fab.class, status);
+
+        renderer.with_layer(self.button.expand(style.shadow.blur_radius * 2.0 + 8.0), |renderer| {
+            fab.draw_button(
+                renderer,
+                self.button,
+                &fab.close_icon,
+                button_style.background,
+                button_style.icon,
+                button_style.shadow,
+            );
+        });
+    }
+
+    fn mouse_interaction(&self, _layout: Layout<'_>, cursor: mouse::Cursor, _renderer: &Renderer) -> mouse::Interaction {
+        match cursor.position() {
+            Some(position) if self.item_at(position).is_some() || self.button.contains(position) => {
+                mouse::Interaction::Pointer
+            }
+            _ => mouse::Interaction::None,
+        }
+    }
+}
+
+fn measure<P: text::Paragraph<Font = iced::Font>>(text: &iced::advanced::Text<String, iced::Font>) -> Size {
+    P::with_text(iced::advanced::Text {
+        content: text.content.as_str(),
+        bounds: text.bounds,
+        size: text.size,
+        font: text.font,
+        align_x: text.align_x,
+        align_y: text.align_y,
+        line_height: text.line_height,
+        shaping: text.shaping,
+        wrapping: text.wrapping,
+    })
+    .min_bounds()
+}
+
+/// The state of the button of a [`Fab`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Active,
+    Hovered,
+    /// The speed dial is open
+    Open,
+}
+
+/// The theme catalog of a [`Fab`].
+pub trait Catalog {
+    /// The style class
+    type Class<'a>;
+
+    /// Default style
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// Get the style for a class and status
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+/// The appearance of a [`Fab`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// Background of the button
+    pub background: Background,
+    /// Icon of the button
+    pub icon: Color,
+    /// Shadow under the button, the mini buttons and the labels
+    pub shadow: Shadow,
+    /// Background of the buttons in the speed dial
+    pub mini_background: Background,
+    /// Background of the hovered button in the speed dial
+    pub mini_hovered_background: Background,
+    /// Icons of the buttons in the speed dial
+    pub mini_icon: Color,
+    /// Background of the labels next to the speed dial
+    pub label_background: Background,
+    /// Text of the labels next to the speed dial
+    pub label_text: Color,
+    /// Color laid over the content while the dial is open
+    pub backdrop: Color,
+}
+
+/// Styling function
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for iced::Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default floating button style, in the primary color with a light backdrop.
+pub fn default(theme: &iced::Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: match status {
+            Status::Active => palette.primary.base.color,
+            Status::Hovered => palette.primary.strong.color,
+            Status::Open => palette.primary.strong.color,
+        }
+        .into(),
+        icon: palette.primary.base.text,
+        shadow: Shadow {
+            color: Color::BLACK.scale_alpha(0.35),
+            offset: Vector::new(0.0, 3.0),
+            blur_radius: 8.0,
+        },
+        mini_background: palette.background.strong.color.into(),
+        mini_hovered_background: palette.primary.weak.color.into(),
+        mini_icon: palette.background.strong.text,
+        label_background: palette.background.base.color.into(),
+        label_text: palette.background.base.text,
+        backdrop: Color::BLACK.scale_alpha(0.2),
+    }
+}
+
+/// No backdrop behind the open speed dial.
+pub fn clear(theme: &iced::Theme, status: Status) -> Style {
+    Style {
+        backdrop: Color::TRANSPARENT,
+        ..default(theme, status)
+    }
+}
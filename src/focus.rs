@@ -0,0 +1,461 @@
+//! Shared keyboard focus handling: focus rings, Tab traversal and focus scopes.
+//!
+//! Any widget that reports itself through [`Operation::focusable`](widget::Operation::focusable)
+//! takes part in traversal, which includes the focusable widgets of this crate and iced's own
+//! text inputs and editors.
+//!
+//! - [`next`] and [`previous`] are tasks moving focus through the whole window, for apps that
+//!   listen to the keyboard themselves.
+//! - A [`Scope`] handles Tab and Shift+Tab for its content. Wrapping the whole view in a
+//!   trapping scope gives the app Tab navigation without any messages.
+//! - A trapping scope keeps focus inside it, cycling from its last widget back to its first.
+//!   Open overlay buttons act as one around their content, so Tab stays inside them.
+//! - [`draw_ring`] draws the ring around a focused widget, so they all look alike.
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        mouse, overlay, renderer,
+        widget::{self, operation::Outcome, tree::Tree, Operation},
+        Clipboard, Layout, Shell, Widget,
+    },
+    border::Radius,
+    keyboard::{self, key::Named},
+    Border, Color, Element, Event, Length, Rectangle, Size, Task, Vector,
+};
+use std::ops::Range;
+
+use crate::pixel;
+
+/// The ring drawn around a focused widget.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ring {
+    /// Color of the ring
+    pub color: Color,
+    /// Width of the ring
+    pub width: f32,
+    /// How far the ring sits outside the bounds of the widget
+    pub offset: f32,
+    /// Corner radius of the ring
+    pub radius: Radius,
+}
+
+impl Ring {
+    /// Creates a 2px ring of the given color, drawn on the bounds of the widget.
+    pub fn new(color: Color) -> Self {
+        Self {
+            color,
+            width: 2.0,
+            offset: 0.0,
+            radius: Radius::default(),
+        }
+    }
+
+    /// Sets the width of the ring.
+    #[must_use]
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets how far the ring sits outside the bounds of the widget.
+    #[must_use]
+    pub fn offset(mut self, offset: f32) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Sets the corner radius of the ring.
+    #[must_use]
+    pub fn radius(mut self, radius: impl Into<Radius>) -> Self {
+        self.radius = radius.into();
+        self
+    }
+}
+
+/// The default ring, in the strong primary color, 2px outside the widget.
+pub fn ring(theme: &iced::Theme) -> Ring {
+    Ring::new(theme.extended_palette().primary.strong.color)
+        .offset(2.0)
+        .radius(4.0)
+}
+
+/// Draws `ring` around `bounds`.
+pub fn draw_ring<Renderer: renderer::Renderer>(renderer: &mut Renderer, bounds: Rectangle, ring: Ring) {
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds: pixel::snap(bounds.expand(ring.offset)),
+            border: pixel::border(Border {
+                color: ring.color,
+                width: ring.width,
+                radius: ring.radius,
+            }),
+            ..renderer::Quad::default()
+        },
+        Color::TRANSPARENT,
+    );
+}
+
+/// Moves focus to the next focusable widget of the window, wrapping around at the end.
+///
+/// While an overlay with a trapping [`Scope`] is open, focus moves into and stays inside it.
+pub fn next<T: Send + 'static>() -> Task<T> {
+    widget::operate(Collect::new(true))
+}
+
+/// Moves focus to the previous focusable widget of the window, wrapping around at the start.
+pub fn previous<T: Send + 'static>() -> Task<T> {
+    widget::operate(Collect::new(false))
+}
+
+/// The direction of a Tab press, `None` for any other event.
+pub fn tab_direction(event: &Event) -> Option<bool> {
+    match event {
+        Event::Keyboard(keyboard::Event::KeyPressed {
+            key: keyboard::Key::Named(Named::Tab),
+            modifiers,
+            ..
+        }) => Some(!modifiers.shift()),
+        _ => None,
+    }
+}
+
+/// Creates a new [`Scope`] handling Tab for `content`.
+pub fn scope<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Scope<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    Scope::new(content)
+}
+
+/// Moves focus through its content when Tab or Shift+Tab is pressed.
+///
+/// Widgets that use Tab themselves, like a range slider moving between its thumbs, get the
+/// press first. An open scope only handles Tab while focus is inside it, and lets the press
+/// through to an enclosing scope past its last widget. A trapping scope wraps back to its
+/// first widget instead, and also takes Tab while nothing inside it is focused, which suits
+/// the whole view and overlays.
+#[allow(missing_debug_implementations)]
+pub struct Scope<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+    trap: bool,
+}
+
+impl<'a, Message, Theme, Renderer> Scope<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    /// Creates a new open [`Scope`] over `content`.
+    pub fn new(content: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        Self {
+            content: content.into(),
+            trap: false,
+        }
+    }
+
+    /// Sets whether focus is kept inside the scope.
+    #[must_use]
+    pub fn trap(mut self, trap: bool) -> Self {
+        self.trap = trap;
+        self
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Scope<'_, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn layout(&mut self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        self.content
+            .as_widget_mut()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        if !shell.is_event_captured() && traverse(&mut self.content, &mut tree.children[0], layout, renderer, event, self.trap) {
+            shell.capture_event();
+            shell.request_redraw();
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content
+            .as_widget()
+            .draw(&tree.children[0], renderer, theme, style, layout, cursor, viewport);
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content
+            .as_widget()
+            .mouse_interaction(&tree.children[0], layout, cursor, viewport, renderer)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let content = &mut self.content;
+        let content_tree = &mut tree.children[0];
+        let mut operate = |operation: &mut dyn Operation| {
+            content.as_widget_mut().operate(content_tree, layout, renderer, operation);
+        };
+
+        if self.trap {
+            trapped(layout.bounds(), operation, &mut operate);
+        } else {
+            operate(operation);
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content
+            .as_widget_mut()
+            .overlay(&mut tree.children[0], layout, renderer, viewport, translation)
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Scope<'a, Message, Theme, Renderer>> for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(scope: Scope<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(scope)
+    }
+}
+
+/// Moves focus within `content` for a Tab press, the way a [`Scope`] does, returning whether
+/// focus moved. Widgets that hold content outside the widget tree, like overlays, use it to
+/// act as a scope themselves.
+pub(crate) fn traverse<Message, Theme, Renderer>(
+    content: &mut Element<'_, Message, Theme, Renderer>,
+    tree: &mut Tree,
+    layout: Layout<'_>,
+    renderer: &Renderer,
+    event: &Event,
+    trap: bool,
+) -> bool
+where
+    Renderer: renderer::Renderer,
+{
+    let Some(forward) = tab_direction(event) else {
+        return false;
+    };
+
+    let mut collect = Collect::new(forward);
+    content.as_widget_mut().operate(tree, layout, renderer, &mut collect);
+
+    let Some(target) = collect.step(0..collect.focused.len(), trap) else {
+        return false;
+    };
+
+    content.as_widget_mut().operate(tree, layout, renderer, &mut Apply::new(target));
+    true
+}
+
+/// Runs `operate` inside the edges of a trapping scope, so [`next`] and [`previous`] keep
+/// focus within whatever it operates on.
+pub(crate) fn trapped(bounds: Rectangle, operation: &mut dyn Operation, operate: &mut dyn FnMut(&mut dyn Operation)) {
+    operation.custom(None, bounds, &mut Edge::Enter);
+    operate(operation);
+    operation.custom(None, bounds, &mut Edge::Exit);
+}
+
+/// Marks where a trapping [`Scope`] starts and ends during an operation.
+enum Edge {
+    Enter,
+    Exit,
+}
+
+/// Records every focusable widget and the trapping scopes around them.
+struct Collect {
+    forward: bool,
+    /// Whether each focusable widget is focused, in tree order
+    focused: Vec<bool>,
+    /// The focusable widgets inside each trapping scope, with its depth
+    scopes: Vec<(Range<usize>, usize)>,
+    /// The scopes entered but not yet exited
+    open: Vec<usize>,
+}
+
+impl Collect {
+    fn new(forward: bool) -> Self {
+        Self {
+            forward,
+            focused: Vec::new(),
+            scopes: Vec::new(),
+            open: Vec::new(),
+        }
+    }
+
+    /// The widgets focus moves between: the innermost trapping scope around the focused
+    /// widget, or the last top-level one when focus is outside them all, such as when an
+    /// overlay has just opened.
+    fn active(&self) -> Range<usize> {
+        let focused = self.focused.iter().position(|&focused| focused);
+
+        focused
+            .and_then(|index| {
+                self.scopes
+                    .iter()
+                    .filter(|(range, _)| range.contains(&index))
+                    .max_by_key(|(_, depth)| *depth)
+            })
+            .or_else(|| {
+                self.scopes
+                    .iter()
+                    .rev()
+                    .find(|(range, depth)| *depth == 0 && !range.is_empty())
+            })
+            .map_or(0..self.focused.len(), |(range, _)| range.clone())
+    }
+
+    /// The widget to focus within `range`. Without `wrap`, `None` when focus is not already
+    /// inside the range or would leave it.
+    fn step(&self, range: Range<usize>, wrap: bool) -> Option<usize> {
+        if range.is_empty() {
+            return None;
+        }
+
+        let current = self.focused[range.clone()].iter().position(|&focused| focused);
+        let last = range.len() - 1;
+
+        let offset = match (current, self.forward) {
+            (None, _) if !wrap => return None,
+            (None, true) => 0,
+            (None, false) => last,
+            (Some(index), true) if index < last => index + 1,
+            (Some(index), false) if index > 0 => index - 1,
+            (Some(_), true) => wrap.then_some(0)?,
+            (Some(_), false) => wrap.then_some(last)?,
+        };
+
+        Some(range.start + offset)
+    }
+}
+
+impl<T> Operation<T> for Collect {
+    fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation<T>)) {
+        operate(self);
+    }
+
+    fn focusable(&mut self, _id: Option<&widget::Id>, _bounds: Rectangle, state: &mut dyn widget::operation::Focusable) {
+        self.focused.push(state.is_focused());
+    }
+
+    fn custom(&mut self, _id: Option<&widget::Id>, _bounds: Rectangle, state: &mut dyn std::any::Any) {
+        match state.downcast_ref::<Edge>() {
+            Some(Edge::Enter) => {
+                self.open.push(self.scopes.len());
+                self.scopes.push((self.focused.len()..self.focused.len(), self.open.len() - 1));
+            }
+            Some(Edge::Exit) => {
+                if let Some(index) = self.open.pop() {
+                    self.scopes[index].0.end = self.focused.len();
+                }
+            }
+            None => {}
+        }
+    }
+
+    fn finish(&self) -> Outcome<T> {
+        match self.step(self.active(), true) {
+            Some(target) => Outcome::Chain(Box::new(Apply::new(target))),
+            None => Outcome::None,
+        }
+    }
+}
+
+/// Focuses the focusable widget at `target`, in tree order, and unfocuses the rest.
+struct Apply {
+    target: usize,
+    index: usize,
+}
+
+impl Apply {
+    fn new(target: usize) -> Self {
+        Self { target, index: 0 }
+    }
+}
+
+impl<T> Operation<T> for Apply {
+    fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation<T>)) {
+        operate(self);
+    }
+
+    fn focusable(&mut self, _id: Option<&widget::Id>, _bounds: Rectangle, state: &mut dyn widget::operation::Focusable) {
+        if self.index == self.target {
+            state.focus();
+        } else {
+            state.unfocus();
+        }
+
+        self.index += 1;
+    }
+}
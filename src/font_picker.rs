@@ -0,0 +1,989 @@
+//! A font picker: a field that opens a searchable list of fonts, each previewed in itself.
+//!
+//! The list holds every font family known to the renderer, the installed ones and those
+//! loaded by the app, unless replaced with [`FontPicker::fonts`]. Typing while the list is
+//! open filters it, Up and Down move the highlight, Enter picks and Escape first clears the
+//! search, then closes. Recently picked families are listed first; apps keep them between
+//! runs with [`FontPicker::on_recent_change`] and [`FontPicker::recent`].
+//!
+//! Picked fonts are reported as [`iced::Font`], whose family name has to be `'static`, so
+//! listed names are interned for the lifetime of the app, see [`font`].
+
+use iced::{
+    advanced::{
+        graphics,
+        layout::{Limits, Node},
+        mouse, overlay, renderer, text,
+        widget::{self, tree::Tree},
+        Clipboard, Layout, Overlay, Shell, Widget,
+    },
+    alignment, font, keyboard, Background, Border, Color, Element, Event, Length, Padding,
+    Pixels, Point, Rectangle, Shadow, Size, Vector,
+};
+use std::collections::HashSet;
+use std::sync::{Mutex, OnceLock};
+
+use crate::pixel;
+
+const POPUP_WIDTH: f32 = 300.0;
+const POPUP_PADDING: f32 = 8.0;
+const POPUP_OFFSET: f32 = 4.0;
+const SEARCH_HEIGHT: f32 = 32.0;
+const LIST_HEIGHT: f32 = 320.0;
+const ROW_HEIGHT: f32 = 48.0;
+const SECTION_HEIGHT: f32 = 24.0;
+const ROW_PADDING: f32 = 10.0;
+const ARROW_SIZE: f32 = 20.0;
+const DEFAULT_RECENT: usize = 5;
+
+/// The family names of every font the renderer knows, sorted and without duplicates.
+pub fn installed_fonts() -> Vec<String> {
+    let mut font_system = graphics::text::font_system()
+        .write()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+
+    let mut families: Vec<String> = font_system
+        .raw()
+        .db()
+        .faces()
+        .filter_map(|face| face.families.first().map(|(name, _)| name.clone()))
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect();
+
+    families.sort_by_key(|family| family.to_lowercase());
+    families
+}
+
+/// A font of the given family, interning the name so it can live in an [`iced::Font`].
+pub fn font(family: &str) -> iced::Font {
+    iced::Font::with_name(intern(family))
+}
+
+fn intern(family: &str) -> &'static str {
+    static NAMES: OnceLock<Mutex<HashSet<&'static str>>> = OnceLock::new();
+
+    let mut names = NAMES
+        .get_or_init(Mutex::default)
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+
+    match names.get(family) {
+        Some(name) => name,
+        None => {
+            let name: &'static str = Box::leak(family.to_owned().into_boxed_str());
+            names.insert(name);
+            name
+        }
+    }
+}
+
+/// The display name of a font's family.
+fn family_name(font: iced::Font) -> &'static str {
+    match font.family {
+        font::Family::Name(name) => name,
+        font::Family::Serif => "Serif",
+        font::Family::SansSerif => "Sans Serif",
+        font::Family::Cursive => "Cursive",
+        font::Family::Fantasy => "Fantasy",
+        font::Family::Monospace => "Monospace",
+    }
+}
+
+/// Creates a new [`FontPicker`] showing the given font.
+pub fn font_picker<'a, Message, Theme>(
+    value: Option<iced::Font>,
+    on_select: impl Fn(iced::Font) -> Message + 'a,
+) -> FontPicker<'a, Message, Theme>
+where
+    Theme: Catalog,
+{
+    FontPicker::new(value, on_select)
+}
+
+/// A field that opens a list of fonts to pick from.
+#[allow(missing_debug_implementations)]
+pub struct FontPicker<'a, Message, Theme = iced::Theme>
+where
+    Theme: Catalog,
+{
+    value: Option<iced::Font>,
+    on_select: Box<dyn Fn(iced::Font) -> Message + 'a>,
+    fonts: Option<Vec<&'static str>>,
+    preview: String,
+    max_recent: usize,
+    recent: Option<Vec<&'static str>>,
+    on_recent_change: Option<Box<dyn Fn(Vec<String>) -> Message + 'a>>,
+    placeholder: String,
+    width: Length,
+    padding: Padding,
+    text_size: Pixels,
+    font: iced::Font,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme> FontPicker<'a, Message, Theme>
+where
+    Theme: Catalog,
+{
+    /// Creates a new [`FontPicker`] showing the given font.
+    pub fn new(value: Option<iced::Font>, on_select: impl Fn(iced::Font) -> Message + 'a) -> Self {
+        Self {
+            value,
+            on_select: Box::new(on_select),
+            fonts: None,
+            preview: String::from("The quick brown fox jumps over the lazy dog"),
+            max_recent: DEFAULT_RECENT,
+            recent: None,
+            on_recent_change: None,
+            placeholder: String::from("Select a font"),
+            width: Length::Fixed(220.0),
+            padding: Padding::from([6, 10]),
+            text_size: Pixels(14.0),
+            font: iced::Font::default(),
+            class: Theme::default(),
+        }
+    }
+
+    /// Lists only the given families instead of every known font.
+    pub fn fonts(mut self, families: impl IntoIterator<Item = impl AsRef<str>>) -> Self {
+        self.fonts = Some(families.into_iter().map(|family| intern(family.as_ref())).collect());
+        self
+    }
+
+    /// Sets the sample text each font is previewed with.
+    pub fn preview(mut self, preview: impl Into<String>) -> Self {
+        self.preview = preview.into();
+        self
+    }
+
+    /// Sets how many recently picked families are listed first, 0 hides the section.
+    pub fn max_recent(mut self, max_recent: usize) -> Self {
+        self.max_recent = max_recent;
+        self
+    }
+
+    /// Lists these families as recently picked, most recent first, such as the ones saved from
+    /// [`on_recent_change`](Self::on_recent_change) in an earlier session. Applied when the
+    /// picker is first shown and whenever they differ from the last ones passed.
+    pub fn recent(mut self, families: impl IntoIterator<Item = impl AsRef<str>>) -> Self {
+        self.recent = Some(families.into_iter().map(|family| intern(family.as_ref())).collect());
+        self
+    }
+
+    /// Sets the message to emit with the recently picked families, most recent first, whenever
+    /// a pick changes them.
+    pub fn on_recent_change(mut self, on_recent_change: impl Fn(Vec<String>) -> Message + 'a) -> Self {
+        self.on_recent_change = Some(Box::new(on_recent_change));
+        self
+    }
+
+    /// Sets the text shown while no font is selected.
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Sets the width of the field.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the padding of the field.
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size.
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = size.into();
+        self
+    }
+
+    /// Sets the font of the field's placeholder, the search and the section titles.
+    pub fn font(mut self, font: iced::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style.
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class.
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    fn text(&self, content: impl Into<String>, font: iced::Font, size: f32, bounds: Size) -> iced::advanced::Text<String, iced::Font> {
+        iced::advanced::Text {
+            content: content.into(),
+            bounds,
+            size: Pixels(size),
+            font,
+            align_x: text::Alignment::Left,
+            align_y: alignment::Vertical::Center,
+            line_height: text::LineHeight::default(),
+            shaping: text::Shaping::Advanced,
+            wrapping: text::Wrapping::None,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    is_open: bool,
+    /// Families listed, read from the renderer when the list opens
+    fonts: Vec<&'static str>,
+    /// Recently picked families, most recent first
+    recent: Vec<&'static str>,
+    /// The families last passed with [`FontPicker::recent`]
+    synced_recent: Option<Vec<&'static str>>,
+    query: String,
+    /// Index into the rows of the highlighted font
+    highlighted: Option<usize>,
+    hovered: Option<usize>,
+    scroll: f32,
+}
+
+impl State {
+    fn close(&mut self) {
+        self.is_open = false;
+        self.query.clear();
+        self.highlighted = None;
+        self.hovered = None;
+        self.scroll = 0.0;
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Row {
+    Section(&'static str),
+    Font(&'static str),
+}
+
+impl Row {
+    fn height(self) -> f32 {
+        match self {
+            Row::Section(_) => SECTION_HEIGHT,
+            Row::Font(_) => ROW_HEIGHT,
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for FontPicker<'_, Message, Theme>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer<Font = iced::Font>,
+{
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(State {
+            recent: self.recent.clone().unwrap_or_default(),
+            synced_recent: self.recent.clone(),
+            ..State::default()
+        })
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+
+        if self.recent.is_some() && state.synced_recent != self.recent {
+            state.recent = self.recent.clone().unwrap_or_default();
+            state.synced_recent = self.recent.clone();
+        }
+    }
+
+    fn layout(&mut self, _tree: &mut Tree, _renderer: &Renderer, limits: &Limits) -> Node {
+        use text::Paragraph as _;
+
+        let label = Renderer::Paragraph::with_text(iced::advanced::Text {
+            content: self.placeholder.as_str(),
+            bounds: Size::INFINITE,
+            size: self.text_size,
+            font: self.font,
+            align_x: text::Alignment::Left,
+            align_y: alignment::Vertical::Top,
+            line_height: text::LineHeight::default(),
+            shaping: text::Shaping::Advanced,
+            wrapping: text::Wrapping::None,
+        })
+        .min_bounds();
+
+        let intrinsic = Size::new(
+            label.width + self.padding.x() + ARROW_SIZE,
+            label.height + self.padding.y(),
+        );
+
+        Node::new(limits.resolve(self.width, Length::Shrink, intrinsic))
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) if cursor.is_over(layout.bounds()) => {
+                if state.is_open {
+                    state.close();
+                } else {
+                    state.is_open = true;
+                    state.fonts = match &self.fonts {
+                        Some(fonts) => fonts.clone(),
+                        None => installed_fonts().iter().map(|family| intern(family)).collect(),
+                    };
+                }
+
+                shell.capture_event();
+                shell.request_redraw();
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                shell.request_redraw();
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _defaults: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let status = if state.is_open {
+            Status::Opened
+        } else if cursor.is_over(bounds) {
+            Status::Hovered
+        } else {
+            Status::Active
+        };
+        let style = theme.style(&self.class, status);
+        let size = self.text_size.0;
+        let clip = bounds.intersection(viewport).unwrap_or(bounds);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: pixel::snap(bounds),
+                border: pixel::border(style.field_border),
+                ..renderer::Quad::default()
+            },
+            style.field_background,
+        );
+
+        // The selected family is shown in itself
+        let (label, font, color) = match self.value {
+            Some(font) => (family_name(font), font, style.field_text),
+            None => (self.placeholder.as_str(), self.font, style.placeholder),
+        };
+        let label_bounds = Size::new(bounds.width - self.padding.x() - ARROW_SIZE, bounds.height);
+        renderer.fill_text(
+            self.text(label, font, size, label_bounds),
+            Point::new(bounds.x + self.padding.left, bounds.center_y()),
+            color,
+            clip,
+        );
+
+        renderer.fill_text(
+            self.text("▾", self.font, size, Size::new(ARROW_SIZE, bounds.height)),
+            Point::new(bounds.x + bounds.width - self.padding.right - ARROW_SIZE / 2.0, bounds.center_y()),
+            style.field_text,
+            clip,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::None
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        _renderer: &Renderer,
+        _viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_mut::<State>();
+
+        if !state.is_open {
+            return None;
+        }
+
+        Some(overlay::Element::new(Box::new(List {
+            picker: self,
+            state,
+            field: layout.bounds() + translation,
+        })))
+    }
+}
+
+/// The popup below the field with the search and the fonts.
+struct List<'a, 'b, Message, Theme>
+where
+    Theme: Catalog,
+{
+    picker: &'a FontPicker<'b, Message, Theme>,
+    state: &'a mut State,
+    field: Rectangle,
+}
+
+impl<Message, Theme> List<'_, '_, Message, Theme>
+where
+    Theme: Catalog,
+{
+    /// The sections and fonts listed for the current search.
+    fn rows(&self) -> Vec<Row> {
+        let query = self.state.query.to_lowercase();
+
+        if !query.is_empty() {
+            return self
+                .state
+                .fonts
+                .iter()
+                .filter(|family| family.to_lowercase().contains(&query))
+                .map(|family| Row::Font(family))
+                .collect();
+        }
+
+        let mut rows = Vec::with_capacity(self.state.fonts.len() + self.state.recent.len() + 2);
+        let recent = &self.state.recent[..self.state.recent.len().min(self.picker.max_recent)];
+
+        if !recent.is_empty() {
+            rows.push(Row::Section("Recently used"));
+            rows.extend(recent.iter().map(|family| Row::Font(family)));
+            rows.push(Row::Section("All fonts"));
+        }
+
+        rows.extend(self.state.fonts.iter().map(|family| Row::Font(family)));
+        rows
+    }
+
+    fn content_height(rows: &[Row]) -> f32 {
+        rows.iter().map(|row| row.height()).sum()
+    }
+
+    fn list_height(rows: &[Row]) -> f32 {
+        Self::content_height(rows).clamp(ROW_HEIGHT, LIST_HEIGHT)
+    }
+
+    fn search_bounds(bounds: Rectangle) -> Rectangle {
+        Rectangle {
+            x: bounds.x + POPUP_PADDING,
+            y: bounds.y + POPUP_PADDING,
+            width: bounds.width - POPUP_PADDING * 2.0,
+            height: SEARCH_HEIGHT,
+        }
+    }
+
+    fn list_bounds(bounds: Rectangle) -> Rectangle {
+        let top = POPUP_PADDING * 2.0 + SEARCH_HEIGHT;
+
+        Rectangle {
+            x: bounds.x + POPUP_PADDING,
+            y: bounds.y + top,
+            width: bounds.width - POPUP_PADDING * 2.0,
+            height: bounds.height - top - POPUP_PADDING,
+        }
+    }
+
+    /// Bounds of each row, scrolled.
+    fn row_bounds(&self, list: Rectangle, rows: &[Row]) -> Vec<Rectangle> {
+        let mut y = list.y - self.state.scroll;
+
+        rows.iter()
+            .map(|row| {
+                let bounds = Rectangle {
+                    x: list.x,
+                    y,
+                    width: list.width,
+                    height: row.height(),
+                };
+                y += row.height();
+                bounds
+            })
+            .collect()
+    }
+
+    fn row_at(&self, bounds: Rectangle, rows: &[Row], point: Point) -> Option<usize> {
+        let list = Self::list_bounds(bounds);
+
+        if !list.contains(point) {
+            return None;
+        }
+
+        self.row_bounds(list, rows)
+            .iter()
+            .zip(rows)
+            .position(|(row, kind)| matches!(kind, Row::Font(_)) && row.contains(point))
+    }
+
+    fn scroll_by(&mut self, rows: &[Row], delta: f32) {
+        let max = (Self::content_height(rows) - Self::list_height(rows)).max(0.0);
+        self.state.scroll = (self.state.scroll + delta).clamp(0.0, max);
+    }
+
+    /// Scrolls so the row is fully visible.
+    fn reveal(&mut self, rows: &[Row], index: usize) {
+        let top = rows[..index].iter().map(|row| row.height()).sum::<f32>();
+        let bottom = top + rows[index].height();
+        let height = Self::list_height(rows);
+
+        if top < self.state.scroll {
+            self.state.scroll = top;
+        } else if bottom > self.state.scroll + height {
+            self.state.scroll = bottom - height;
+        }
+    }
+
+    /// Moves the highlight to the next font row in `direction`, stopping at the ends.
+    fn step(&mut self, rows: &[Row], direction: isize) {
+        let fonts: Vec<usize> = (0..rows.len()).filter(|&index| matches!(rows[index], Row::Font(_))).collect();
+
+        let next = match self.state.highlighted.and_then(|current| fonts.iter().position(|&index| index == current)) {
+            Some(position) => fonts.get((position as isize + direction).max(0) as usize).or(fonts.last()),
+            None if direction > 0 => fonts.first(),
+            None => fonts.last(),
+        };
+
+        if let Some(&index) = next {
+            self.state.highlighted = Some(index);
+            self.reveal(rows, index);
+        }
+    }
+
+    fn pick(&mut self, family: &'static str, shell: &mut Shell<'_, Message>) {
+        shell.publish((self.picker.on_select)(iced::Font::with_name(family)));
+
+        let before = self.state.recent.clone();
+        self.state.recent.retain(|recent| *recent != family);
+        self.state.recent.insert(0, family);
+        self.state.recent.truncate(self.picker.max_recent);
+
+        if self.state.recent != before
+            && let Some(on_recent_change) = &self.picker.on_recent_change
+        {
+            shell.publish(on_recent_change(self.state.recent.iter().map(|family| family.to_string()).collect()));
+        }
+        self.state.close();
+    }
+
+    fn filter_changed(&mut self) {
+        self.state.scroll = 0.0;
+        self.state.highlighted = None;
+        self.state.hovered = None;
+    }
+}
+
+impl<Message, Theme, Renderer> Overlay<Message, Theme, Renderer> for List<'_, '_, Message, Theme>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer<Font = iced::Font>,
+{
+    fn layout(&mut self, _renderer: &Renderer, bounds: Size) -> Node {
+        let rows = self.rows();
+        let size = Size::new(
+            POPUP_WIDTH.max(self.field.width),
+            POPUP_PADDING * 3.0 + SEARCH_HEIGHT + Self::list_height(&rows),
+        );
+
+        // Below the field, or above it when there is no room, kept inside the window
+        let below = self.field.y + self.field.height + POPUP_OFFSET;
+        let y = if below + size.height > bounds.height && self.field.y - POPUP_OFFSET - size.height >= 0.0 {
+            self.field.y - POPUP_OFFSET - size.height
+        } else {
+            below
+        };
+        let x = self.field.x.min(bounds.width - size.width).max(0.0);
+
+        Node::new(size).move_to(Point::new(x, y))
+    }
+
+    fn update(
+        &mut self,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let bounds = layout.bounds();
+        let rows = self.rows();
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                let hovered = self.row_at(bounds, &rows, *position);
+
+                if hovered != self.state.hovered {
+                    self.state.hovered = hovered;
+                    shell.request_redraw();
+                }
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) if cursor.is_over(bounds) => {
+                let delta = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => -y * ROW_HEIGHT,
+                    mouse::ScrollDelta::Pixels { y, .. } => -y,
+                };
+                self.scroll_by(&rows, delta);
+                self.state.hovered = cursor.position().and_then(|position| self.row_at(bounds, &rows, position));
+
+                shell.capture_event();
+                shell.request_redraw();
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let Some(position) = cursor.position() else {
+                    return;
+                };
+
+                if !bounds.contains(position) {
+                    // The field toggles the list itself
+                    if !self.field.contains(position) {
+                        self.state.close();
+                        shell.request_redraw();
+                    }
+                    return;
+                }
+
+                if let Some(Row::Font(family)) = self.row_at(bounds, &rows, position).map(|index| rows[index]) {
+                    self.pick(family, shell);
+                }
+
+                shell.capture_event();
+                shell.request_redraw();
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed { key, modifiers, text, .. }) => {
+                match key {
+                    keyboard::Key::Named(keyboard::key::Named::ArrowDown) => self.step(&rows, 1),
+                    keyboard::Key::Named(keyboard::key::Named::ArrowUp) => self.step(&rows, -1),
+                    keyboard::Key::Named(keyboard::key::Named::Enter) => {
+                        if let Some(Row::Font(family)) = self.state.highlighted.and_then(|index| rows.get(index).copied()) {
+                            self.pick(family, shell);
+                        }
+                    }
+                    keyboard::Key::Named(keyboard::key::Named::Escape) => {
+                        // Escape first clears the search, then closes
+                        if self.state.query.is_empty() {
+                            self.state.close();
+                        } else {
+                            self.state.query.clear();
+                            self.filter_changed();
+                        }
+                    }
+                    keyboard::Key::Named(keyboard::key::Named::Backspace) => {
+                        if self.state.query.pop().is_some() {
+                            self.filter_changed();
+                        }
+                    }
+                    _ => match text {
+                        Some(text) if !(modifiers.control() || modifiers.command() || text.chars().any(char::is_control)) => {
+                            self.state.query.push_str(text);
+                            self.filter_changed();
+                        }
+                        _ => return,
+                    },
+                }
+
+                shell.capture_event();
+                shell.invalidate_layout();
+                shell.request_redraw();
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _defaults: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+    ) {
+        let style = theme.style(&self.picker.class, Status::Opened);
+        let bounds = layout.bounds();
+        let size = self.picker.text_size.0;
+        let ui_font = self.picker.font;
+        let rows = self.rows();
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: pixel::snap(bounds),
+                border: pixel::border(style.popup_border),
+                shadow: style.popup_shadow,
+                snap: true,
+            },
+            style.popup_background,
+        );
+
+        let search = Self::search_bounds(bounds);
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: pixel::snap(search),
+                border: pixel::border(style.search_border),
+                ..renderer::Quad::default()
+            },
+            style.search_background,
+        );
+
+        let (query, color) = if self.state.query.is_empty() {
+            ("Search fonts", style.placeholder)
+        } else {
+            (self.state.query.as_str(), style.search_text)
+        };
+        renderer.fill_text(
+            self.picker.text(format!("🔍 {query}"), ui_font, size, search.size()),
+            Point::new(search.x + ROW_PADDING, search.center_y()),
+            color,
+            search,
+        );
+
+        let list = Self::list_bounds(bounds);
+        let selected = self.picker.value.map(family_name);
+
+        if rows.is_empty() {
+            renderer.fill_text(
+                self.picker.text("No fonts found", ui_font, size, Size::new(list.width, ROW_HEIGHT)),
+                Point::new(list.x + ROW_PADDING, list.y + ROW_HEIGHT / 2.0),
+                style.section_text,
+                list,
+            );
+            return;
+        }
+
+        renderer.with_layer(list, |renderer| {
+            for (index, (row, row_bounds)) in rows.iter().zip(self.row_bounds(list, &rows)).enumerate() {
+                // Only the rows in view are drawn, even hundreds of fonts stay cheap
+                if row_bounds.y + row_bounds.height < list.y || row_bounds.y > list.y + list.height {
+                    continue;
+                }
+
+                match *row {
+                    Row::Section(title) => {
+                        renderer.fill_text(
+                            self.picker.text(title, ui_font, size * 0.85, row_bounds.size()),
+                            Point::new(row_bounds.x + ROW_PADDING, row_bounds.center_y()),
+                            style.section_text,
+                            list,
+                        );
+                    }
+                    Row::Font(family) => {
+                        let highlighted = [self.state.highlighted, self.state.hovered].contains(&Some(index));
+
+                        if highlighted || selected == Some(family) {
+                            renderer.fill_quad(
+                                renderer::Quad {
+                                    bounds: pixel::snap(row_bounds),
+                                    border: pixel::border(Border {
+                                        radius: style.search_border.radius,
+                                        ..Border::default()
+                                    }),
+                                    ..renderer::Quad::default()
+                                },
+                                if highlighted { style.hovered_background } else { style.selected_background },
+                            );
+                        }
+
+                        let half = Size::new(row_bounds.width - ROW_PADDING * 2.0, row_bounds.height / 2.0);
+                        renderer.fill_text(
+                            self.picker.text(family, ui_font, size * 0.85, half),
+                            Point::new(row_bounds.x + ROW_PADDING, row_bounds.y + row_bounds.height * 0.3),
+                            style.name_text,
+                            row_bounds,
+                        );
+                        renderer.fill_text(
+                            self.picker.text(self.picker.preview.as_str(), iced::Font::with_name(family), size * 1.2, half),
+                            Point::new(row_bounds.x + ROW_PADDING, row_bounds.y + row_bounds.height * 0.68),
+                            style.preview_text,
+                            row_bounds,
+                        );
+                    }
+                }
+            }
+        });
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let bounds = layout.bounds();
+        let rows = self.rows();
+
+        match cursor.position() {
+            Some(position) if self.row_at(bounds, &rows, position).is_some() => mouse::Interaction::Pointer,
+            Some(position) if bounds.contains(position) => mouse::Interaction::Idle,
+            _ => mouse::Interaction::None,
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<FontPicker<'a, Message, Theme>> for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer<Font = iced::Font> + 'a,
+{
+    fn from(picker: FontPicker<'a, Message, Theme>) -> Self {
+        Element::new(picker)
+    }
+}
+
+/// The state of the field of a [`FontPicker`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Active,
+    Hovered,
+    Opened,
+}
+
+/// The theme catalog of a [`FontPicker`].
+pub trait Catalog {
+    /// The style class
+    type Class<'a>;
+
+    /// Default style
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// Get the style for a class and status
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+/// The appearance of a [`FontPicker`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// Background of the field
+    pub field_background: Background,
+    /// Border of the field
+    pub field_border: Border,
+    /// Text color of the field
+    pub field_text: Color,
+    /// Color of the field placeholder and the empty search
+    pub placeholder: Color,
+    /// Background of the popup
+    pub popup_background: Background,
+    /// Border of the popup
+    pub popup_border: Border,
+    /// Shadow of the popup
+    pub popup_shadow: Shadow,
+    /// Background of the search box
+    pub search_background: Background,
+    /// Border of the search box, its radius is also used for highlighted rows
+    pub search_border: Border,
+    /// Text typed into the search box
+    pub search_text: Color,
+    /// Section titles such as "Recently used"
+    pub section_text: Color,
+    /// Family names above the previews
+    pub name_text: Color,
+    /// Preview text
+    pub preview_text: Color,
+    /// Background of the hovered or highlighted row
+    pub hovered_background: Background,
+    /// Background of the row of the selected font
+    pub selected_background: Background,
+}
+
+/// Styling function
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for iced::Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default font picker style.
+pub fn default(theme: &iced::Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        field_background: palette.background.base.color.into(),
+        field_border: Border {
+            color: match status {
+                Status::Active => palette.background.strong.color,
+                Status::Hovered | Status::Opened => palette.primary.strong.color,
+            },
+            width: 1.0,
+            radius: 4.0.into(),
+        },
+        field_text: palette.background.base.text,
+        placeholder: palette.background.strong.color,
+        popup_background: palette.background.base.color.into(),
+        popup_border: Border {
+            color: palette.background.strong.color,
+            width: 1.0,
+            radius: 6.0.into(),
+        },
+        popup_shadow: Shadow {
+            color: Color::BLACK.scale_alpha(0.3),
+            offset: Vector::new(0.0, 3.0),
+            blur_radius: 10.0,
+        },
+        search_background: palette.background.weak.color.into(),
+        search_border: Border {
+            color: palette.background.strong.color,
+            width: 1.0,
+            radius: 4.0.into(),
+        },
+        search_text: palette.background.weak.text,
+        section_text: palette.background.strong.color,
+        name_text: palette.background.strong.text,
+        preview_text: palette.background.base.text,
+        hovered_background: palette.background.weak.color.into(),
+        selected_background: palette.primary.weak.color.scale_alpha(0.4).into(),
+    }
+}
@@ -0,0 +1,328 @@
+//! A [`TreeHandle`] fed from a directory on disk.
+//!
+//! [`FsTree`] keeps the listing of a directory and builds the tree from it. Directories are
+//! read lazily: their contents are loaded the first time they are expanded, which the app
+//! forwards from [`TreeHandle::on_toggle`] to [`FsTree::toggle`]. Hidden entries are
+//! filtered out unless enabled, each entry gets an icon for its file type, and
+//! [`FsTree::refresh`] re-reads the directories that changed, driven by [`FsTree::watch`].
+//!
+//! Every entry keeps its id for as long as it exists, so selections reported by
+//! [`TreeHandle::on_select`] can be resolved with [`FsTree::path`].
+
+use std::collections::{HashMap, HashSet};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime};
+
+use iced::advanced::text;
+use iced::widget::{row, text as label};
+use iced::Subscription;
+
+use crate::tree::{branch, tree_handle, Branch, Catalog, TreeHandle};
+
+/// Whether an entry is a directory or a file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EntryKind {
+    Directory,
+    File,
+}
+
+/// Picks the icon of an entry from its path, kind and whether it is expanded.
+pub type IconFn = fn(&Path, EntryKind, bool) -> &'static str;
+
+#[derive(Debug, Clone)]
+struct Entry {
+    path: PathBuf,
+    name: String,
+    kind: EntryKind,
+    /// Ids of the contents, `None` until the directory is first expanded
+    children: Option<Vec<usize>>,
+    /// Modification time when the contents were read
+    modified: Option<SystemTime>,
+}
+
+impl Entry {
+    fn is_hidden(&self) -> bool {
+        self.name.starts_with('.')
+    }
+}
+
+/// The listing of a directory, shown with [`FsTree::view`].
+#[derive(Debug, Clone)]
+pub struct FsTree {
+    root: usize,
+    entries: HashMap<usize, Entry>,
+    ids: HashMap<PathBuf, usize>,
+    next_id: usize,
+    expanded: HashSet<usize>,
+    show_hidden: bool,
+    icon: IconFn,
+}
+
+impl FsTree {
+    /// Reads the contents of `root`; its subdirectories are read when expanded.
+    pub fn new(root: impl Into<PathBuf>) -> io::Result<Self> {
+        let path = root.into();
+        let name = path
+            .file_name()
+            .map_or_else(|| path.to_string_lossy().into_owned(), |name| name.to_string_lossy().into_owned());
+
+        let mut tree = Self {
+            root: 1,
+            entries: HashMap::new(),
+            ids: HashMap::new(),
+            // Ids start at 1, the tree treats 0 as "no id"
+            next_id: 1,
+            expanded: HashSet::new(),
+            show_hidden: false,
+            icon: default_icon,
+        };
+
+        tree.root = tree.insert(path, name, EntryKind::Directory);
+        tree.load(tree.root)?;
+        tree.expanded.insert(tree.root);
+
+        Ok(tree)
+    }
+
+    /// Shows entries whose name starts with a dot.
+    pub fn show_hidden(mut self, show: bool) -> Self {
+        self.show_hidden = show;
+        self
+    }
+
+    /// Shows or hides entries whose name starts with a dot.
+    pub fn set_show_hidden(&mut self, show: bool) {
+        self.show_hidden = show;
+    }
+
+    /// Sets the function picking each entry's icon, see [`default_icon`].
+    pub fn icons(mut self, icon: IconFn) -> Self {
+        self.icon = icon;
+        self
+    }
+
+    /// The directory the tree lists.
+    pub fn root(&self) -> &Path {
+        &self.entries[&self.root].path
+    }
+
+    /// The path of the entry with the given id.
+    pub fn path(&self, id: usize) -> Option<&Path> {
+        self.entries.get(&id).map(|entry| entry.path.as_path())
+    }
+
+    /// The kind of the entry with the given id.
+    pub fn kind(&self, id: usize) -> Option<EntryKind> {
+        self.entries.get(&id).map(|entry| entry.kind)
+    }
+
+    /// Records a branch being expanded or collapsed, reading a directory's contents the
+    /// first time it is expanded.
+    pub fn toggle(&mut self, id: usize, expanded: bool) -> io::Result<()> {
+        if !expanded {
+            self.expanded.remove(&id);
+            return Ok(());
+        }
+
+        self.expanded.insert(id);
+
+        match self.entries.get(&id) {
+            Some(entry) if entry.kind == EntryKind::Directory && entry.children.is_none() => self.load(id),
+            _ => Ok(()),
+        }
+    }
+
+    /// Re-reads every loaded directory whose modification time changed, returning whether
+    /// anything did. Ids of entries that still exist are kept.
+    pub fn refresh(&mut self) -> io::Result<bool> {
+        let loaded: Vec<usize> = self
+            .entries
+            .iter()
+            .filter(|(_, entry)| entry.children.is_some())
+            .map(|(&id, _)| id)
+            .collect();
+        let mut changed = false;
+
+        for id in loaded {
+            // Removed along with an ancestor earlier in this pass
+            let Some(entry) = self.entries.get(&id) else {
+                continue;
+            };
+
+            let modified = match std::fs::metadata(&entry.path) {
+                Ok(metadata) => metadata.modified().ok(),
+                // Gone; its parent's listing drops it
+                Err(error) if error.kind() == io::ErrorKind::NotFound && id != self.root => continue,
+                Err(error) => return Err(error),
+            };
+
+            if modified.is_none() || modified != entry.modified {
+                self.load(id)?;
+                changed = true;
+            }
+        }
+
+        Ok(changed)
+    }
+
+    /// Ticks every `interval`, for calling [`FsTree::refresh`].
+    ///
+    /// Only directories whose modification time changed are read again, so a short
+    /// interval stays cheap.
+    pub fn watch(interval: Duration) -> Subscription<Instant> {
+        iced::time::every(interval)
+    }
+
+    /// Builds the tree of the root's contents, expanded as recorded by [`FsTree::toggle`].
+    pub fn view<'a, Message, Theme, Renderer>(&'a self) -> TreeHandle<'a, Message, Theme, Renderer>
+    where
+        Message: 'a,
+        Theme: Catalog + iced::widget::text::Catalog + 'a,
+        Renderer: iced::advanced::Renderer + text::Renderer<Font = iced::Font> + 'a,
+    {
+        tree_handle(self.branches(self.root)).expanded(self.expanded.iter().copied())
+    }
+
+    fn branches<'a, Message, Theme, Renderer>(&'a self, id: usize) -> Vec<Branch<'a, Message, Theme, Renderer>>
+    where
+        Message: 'a,
+        Theme: iced::widget::text::Catalog + 'a,
+        Renderer: iced::advanced::Renderer + text::Renderer<Font = iced::Font> + 'a,
+    {
+        let Some(children) = self.entries.get(&id).and_then(|entry| entry.children.as_ref()) else {
+            return Vec::new();
+        };
+
+        children
+            .iter()
+            .filter_map(|id| self.entries.get(id).map(|entry| (*id, entry)))
+            .filter(|(_, entry)| self.show_hidden || !entry.is_hidden())
+            .map(|(id, entry)| {
+                let icon = (self.icon)(&entry.path, entry.kind, self.expanded.contains(&id));
+                let content = row![label(icon), label(entry.name.as_str())].spacing(6);
+                let branch = branch(content).with_id(id).block_dragging();
+
+                match entry.kind {
+                    EntryKind::Directory => branch.lazy().with_children(self.branches(id)),
+                    EntryKind::File => branch,
+                }
+            })
+            .collect()
+    }
+
+    fn insert(&mut self, path: PathBuf, name: String, kind: EntryKind) -> usize {
+        if let Some(&id) = self.ids.get(&path) {
+            if let Some(entry) = self.entries.get_mut(&id)
+                && entry.kind != kind
+            {
+                entry.kind = kind;
+                entry.children = None;
+            }
+            return id;
+        }
+
+        let id = self.next_id;
+        self.next_id += 1;
+        self.ids.insert(path.clone(), id);
+        self.entries.insert(
+            id,
+            Entry {
+                path,
+                name,
+                kind,
+                children: None,
+                modified: None,
+            },
+        );
+
+        id
+    }
+
+    fn remove(&mut self, id: usize) {
+        self.expanded.remove(&id);
+
+        if let Some(entry) = self.entries.remove(&id) {
+            self.ids.remove(&entry.path);
+
+            for child in entry.children.into_iter().flatten() {
+                self.remove(child);
+            }
+        }
+    }
+
+    /// Reads the contents of a directory: directories first, then by name.
+    fn load(&mut self, id: usize) -> io::Result<()> {
+        let Some(path) = self.entries.get(&id).map(|entry| entry.path.clone()) else {
+            return Ok(());
+        };
+
+        let modified = std::fs::metadata(&path)?.modified().ok();
+        let mut listing = Vec::new();
+
+        for dir_entry in std::fs::read_dir(&path)? {
+            let dir_entry = dir_entry?;
+            let path = dir_entry.path();
+            // Follows symlinks, so a link to a directory expands like one
+            let is_dir = std::fs::metadata(&path).map_or_else(
+                |_| dir_entry.file_type().is_ok_and(|kind| kind.is_dir()),
+                |metadata| metadata.is_dir(),
+            );
+            let kind = if is_dir { EntryKind::Directory } else { EntryKind::File };
+
+            listing.push((path, dir_entry.file_name().to_string_lossy().into_owned(), kind));
+        }
+
+        listing.sort_by(|(_, a, a_kind), (_, b, b_kind)| {
+            (*b_kind == EntryKind::Directory)
+                .cmp(&(*a_kind == EntryKind::Directory))
+                .then_with(|| a.to_lowercase().cmp(&b.to_lowercase()))
+        });
+
+        let children: Vec<usize> = listing
+            .into_iter()
+            .map(|(path, name, kind)| self.insert(path, name, kind))
+            .collect();
+
+        let previous = self
+            .entries
+            .get_mut(&id)
+            .and_then(|entry| {
+                entry.modified = modified;
+                entry.children.replace(children.clone())
+            })
+            .unwrap_or_default();
+
+        for child in previous {
+            if !children.contains(&child) {
+                self.remove(child);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Folders, then a handful of common file types by extension.
+pub fn default_icon(path: &Path, kind: EntryKind, expanded: bool) -> &'static str {
+    if kind == EntryKind::Directory {
+        return if expanded { "📂" } else { "📁" };
+    }
+
+    let extension = path
+        .extension()
+        .map(|extension| extension.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+
+    match extension.as_str() {
+        "rs" => "🦀",
+        "md" | "txt" | "rst" => "📝",
+        "toml" | "json" | "yaml" | "yml" | "ini" | "cfg" => "⚙",
+        "png" | "jpg" | "jpeg" | "gif" | "svg" | "webp" | "bmp" | "ico" => "🖼",
+        "mp3" | "wav" | "flac" | "ogg" => "🎵",
+        "mp4" | "mkv" | "mov" | "webm" => "🎞",
+        "zip" | "tar" | "gz" | "xz" | "7z" | "rar" => "📦",
+        "lock" => "🔒",
+        _ => "📄",
+    }
+}
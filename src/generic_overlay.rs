@@ -1,3 +1,6 @@
+use std::cell::Cell;
+
+use iced::time::{Duration, Instant};
 use iced::{
     advanced::{
         layout::{self, Limits, Node},
@@ -8,15 +11,31 @@ use iced::{
         widget::{self, tree::Tree},
         widget::operation::Operation,
         Clipboard, Layout, Overlay as _, Renderer as _, Shell, Widget,
-    }, alignment::Vertical, border::Radius, keyboard, mouse, touch, widget::button, Border, Color, Element, Event, Length, Padding, Pixels, Point, Rectangle, Shadow, Size, Vector, Background, Alignment
+    }, alignment::{Horizontal, Vertical}, border::Radius, keyboard, mouse, touch, widget::button, Border, Color, Element, Event, Length, Padding, Pixels, Point, Rectangle, Shadow, Size, Vector, Background, Alignment
 };
 
+use crate::direction::{self, Direction};
+use crate::focus;
+use crate::theme::{Palette, Preset};
+use crate::gestures::{self, Gesture};
+use crate::operation;
+use crate::pixel;
+#[cfg(feature = "debug")]
+use crate::debug;
+
 const HEADER_HEIGHT: f32 = 32.0;
 const CLOSE_BUTTON_SIZE: f32 = 30.0;
 const CLOSE_BUTTON_OFFSET: f32 = 1.0;
 const CONTENT_PADDING: f32 = 15.0;
 const RESIZE_HANDLE_SIZE: f32 = 8.0;  // Size of resize hit areas
+const TOUCH_RESIZE_HANDLE_SIZE: f32 = 24.0; // Size of resize hit areas for fingers
 const MIN_OVERLAY_SIZE: f32 = 100.0;   // Minimum overlay dimensions
+const KEYBOARD_STEP: f32 = 10.0;       // Alt+Arrow move/resize increment
+const SPOTLIGHT_PADDING: f32 = 6.0;    // Space between the spotlight target and the backdrop
+const HEADER_STATUS_SPACING: f32 = 6.0; // Space between the header status and the close button
+
+/// Source of unique tokens identifying overlays within a [`HoverGroup`]
+static NEXT_HOVER_TOKEN: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(1);
 
 
 /// Helper function to create an overlay button
@@ -89,6 +108,63 @@ where
         .hover_alignment(Alignment::Start)
 }
 
+/// Helper function to create a context menu area ( right click content to open menu at cursor )
+pub fn context_area<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    menu: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> OverlayButton<'a, Message, Theme, Renderer> 
+where 
+    Renderer: iced::advanced::Renderer + text::Renderer,
+    Theme: Catalog + button::Catalog,
+{
+    OverlayButton::new(content, "", menu)
+        .hide_header()
+        .close_on_click_outside()
+        .overlay_width(Length::Fixed(150.0))
+        .overlay_padding(1.0)
+        .overlay_radius(0.0)
+        .padding(0.0)
+        .interactive_base(true)
+        .open_on_right_click()
+        .keyboard_navigation()
+        .close_on_select()
+}
+
+/// Helper function to create a standalone confirmation dialog with Yes/No buttons
+///
+/// The dialog has no button of its own, drive it with [`OverlayButton::is_open`].
+/// Enter confirms, Escape and the close button cancel.
+pub fn confirm_dialog<'a, Message, Theme, Renderer>(
+    title: impl Into<String>,
+    body: impl iced::widget::text::IntoFragment<'a>,
+    on_confirm: Message,
+    on_cancel: Message,
+) -> OverlayButton<'a, Message, Theme, Renderer> 
+where 
+    Message: Clone + 'a,
+    Renderer: iced::advanced::Renderer + text::Renderer + 'a,
+    Theme: Catalog + button::Catalog + iced::widget::text::Catalog + 'a,
+{
+    let content = iced::widget::column![
+        iced::widget::text(body),
+        iced::widget::row![
+            iced::widget::space::horizontal(),
+            iced::widget::button("No").on_press(on_cancel.clone()),
+            iced::widget::button("Yes").on_press(on_confirm.clone()),
+        ]
+        .spacing(10.0),
+    ]
+    .spacing(15.0);
+
+    OverlayButton::new(iced::widget::space(), title, content)
+        .padding(0.0)
+        .opaque(true)
+        .overlay_width(Length::Fixed(360.0))
+        .reset_on_close()
+        .on_submit(move || on_confirm.clone())
+        .on_close(move || on_cancel.clone())
+}
+
 /// A button that opens a draggable overlay with custom content
 #[allow(missing_debug_implementations)]
 pub struct OverlayButton<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer> 
@@ -106,6 +182,8 @@ where
     title_text_size: Option<Pixels>,
     /// font for title text
     title_font: Option<Renderer::Font>,
+    /// horizontal alignment of the title text
+    title_align: Horizontal,
     /// Function to create the overlay content (called each time)
     content: Element<'a, Message, Theme, Renderer>,
     /// Sets the radius of the overlay
@@ -114,6 +192,8 @@ where
     overlay_width: Option<SizeStrategy<'a>>,
     /// Optional height for the overlay (defaults to content height)
     overlay_height: Option<SizeStrategy<'a>>,
+    /// Optional maximum height for content sized overlays (defaults to the window height)
+    overlay_max_height: Option<f32>,
     /// Optional padding for the overlay (defaults to CONTENT_PADDING)
     overlay_padding: f32,
     /// Button width
@@ -130,8 +210,22 @@ where
     on_close: Option<Box<dyn Fn() -> Message + 'a>>,
     /// Callback when the overlay is opened/closed
     on_toggle: Option<Box<dyn Fn(bool) -> Message + 'a>>,
+    /// Callback when the overlay has been dragged to a new position
+    on_move: Option<Box<dyn Fn(Point) -> Message + 'a>>,
+    /// Callback when the overlay is maximized/restored
+    on_maximize: Option<Box<dyn Fn(bool) -> Message + 'a>>,
+    /// Callback when Enter is pressed while the overlay is open
+    on_submit: Option<Box<dyn Fn() -> Message + 'a>>,
+    /// Key identifying the content when the overlay is torn off
+    tear_off_key: String,
+    /// Callback when the overlay is dragged out of the window
+    on_tear_off: Option<Box<dyn Fn(TearOff) -> Message + 'a>>,
     /// Hover Config
     hover: Hover,
+    /// Shared coordinator keeping a single hover overlay of the group visible
+    hover_group: Option<&'a HoverGroup>,
+    /// Small element shown in the header, left of the close button
+    header_status: Option<Element<'a, Message, Theme, Renderer>>,
     /// Use Hover layout with click to open.
     hover_positions_on_click: bool,
     /// Class of the Overlay
@@ -150,14 +244,36 @@ where
     hide_header: bool,
     /// If true, removes the X button from header
     hide_close_button: bool,
+    /// Dims everything except this target while open
+    spotlight: Option<Spotlight>,
+    /// Optional glyph replacing the default "×" close button
+    close_icon: Option<CloseIcon<Renderer::Font>>,
     /// Resize mode for the overlay
     resizable: ResizeMode,
+    /// If true, double-clicking the header toggles maximize/restore
+    double_click_maximize: bool,
+    /// If true, a quick swipe starting on the overlay closes it
+    swipe_to_dismiss: bool,
+    /// Direction of the header and anchored alignment, the global one if unset
+    direction: Option<Direction>,
+    /// If true, resizing shows an outline and relayouts the content on release
+    resize_outline: bool,
+    /// Grid increment resized width/height snap to
+    resize_step: Option<f32>,
     /// reset size and position on overlay closure
     reset_on_close: bool,
     /// Externally controlled open state
     external_is_open: Option<bool>,
+    /// If true, interactions only publish open/close requests and never change the open state
+    controlled: bool,
     /// Forward all updates to base Element
     interactive_base: bool,
+    /// Open at the cursor on right click instead of on left click
+    open_on_right_click: bool,
+    /// Arrow keys highlight the direct children of the content, Enter/Space activates them
+    keyboard_navigation: bool,
+    /// Close once the content publishes a message from a click
+    close_on_select: bool,
 }
 
 impl<'a, Message, Theme, Renderer> OverlayButton<'a, Message, Theme, Renderer> 
@@ -187,12 +303,14 @@ where
             title: title.into(),
             title_text_size: None,
             title_font: None,
+            title_align: Horizontal::Center,
 
             // Overlay Content
             content: content.into(),
             overlay_radius: 12.0,
             overlay_width: None,
             overlay_height: None,
+            overlay_max_height: None,
             overlay_padding: CONTENT_PADDING,
             clip: false,
             class: <Theme as Catalog>::default(),
@@ -201,20 +319,38 @@ where
             // Callbacks
             on_open: None,
             on_close: None,
-            on_toggle: None,            
+            on_toggle: None,
+            on_move: None,
+            on_maximize: None,
+            on_submit: None,
+            tear_off_key: String::new(),
+            on_tear_off: None,
             
             // Overlay behavior options
             hover: Hover::default(),
+            hover_group: None,
+            header_status: None,
             hover_positions_on_click: false,
             is_pressed: false,
             opaque: false,
             close_on_click_outside: false,
             hide_header: false,
             hide_close_button: false,
+            close_icon: None,
+            spotlight: None,
             resizable: ResizeMode::None,
+            double_click_maximize: false,
+            swipe_to_dismiss: false,
+            direction: None,
+            resize_outline: false,
+            resize_step: None,
             reset_on_close: false,
             external_is_open: None,
+            controlled: false,
             interactive_base: false,
+            open_on_right_click: false,
+            keyboard_navigation: false,
+            close_on_select: false,
         }
     }
 
@@ -233,6 +369,34 @@ where
         self
     }
 
+    /// Sets the text size of the header title
+    pub fn title_text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.title_text_size = Some(size.into());
+        self
+    }
+
+    /// Sets the font of the header title
+    pub fn title_font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.title_font = Some(font.into());
+        self
+    }
+
+    /// Sets the horizontal alignment of the header title (defaults to Center)
+    pub fn title_align(mut self, align: Horizontal) -> Self {
+        self.title_align = align;
+        self
+    }
+
+    /// Drives the overlay visibility entirely from application state.
+    ///
+    /// Unlike [`OverlayButton::is_open`], user interactions never open or close the overlay themselves,
+    /// they only publish `on_open`/`on_close`/`on_toggle` so the application can decide.
+    pub fn open(mut self, open: bool) -> Self {
+        self.external_is_open = Some(open);
+        self.controlled = true;
+        self
+    }
+
     /// Sets the overlay width
     pub fn overlay_width(mut self, width: impl Into<SizeStrategy<'a>>) -> Self {
         self.overlay_width = Some(width.into());
@@ -245,6 +409,12 @@ where
         self
     }
 
+    /// Sets the maximum height an overlay sized to its content may grow to while open
+    pub fn overlay_max_height(mut self, max_height: f32) -> Self {
+        self.overlay_max_height = Some(max_height);
+        self
+    }
+
     // "Rule Style" convenience method for dynamic width
     // Usage: .overlay_width_dynamic(|available| Length::Fixed(available * 0.8))
     pub fn overlay_width_dynamic(mut self, calc: impl Fn(f32) -> Length + 'a) -> Self {
@@ -311,6 +481,36 @@ where
         self
     }
 
+    /// Sets a callback for when the overlay is dropped after dragging the header.
+    ///
+    /// Reports the final top-left position, complementing the initial position from `on_open`.
+    pub fn on_move(mut self, moved: impl Fn(Point) -> Message + 'a) -> Self {
+        self.on_move = Some(Box::new(moved));
+        self
+    }
+
+    /// Sets a callback for when the overlay is maximized (`true`) or restored (`false`)
+    pub fn on_maximize(mut self, maximized: impl Fn(bool) -> Message + 'a) -> Self {
+        self.on_maximize = Some(Box::new(maximized));
+        self
+    }
+
+    /// Sets a callback for when Enter is pressed while the overlay is open
+    pub fn on_submit(mut self, submit: impl Fn() -> Message + 'a) -> Self {
+        self.on_submit = Some(Box::new(submit));
+        self
+    }
+
+    /// Enables tearing the overlay off by dragging its header out of the window.
+    ///
+    /// The overlay closes and `on_tear_off` receives the `key` identifying its content together with
+    /// its geometry, so the application can show the same content in a new window.
+    pub fn tear_off(mut self, key: impl Into<String>, on_tear_off: impl Fn(TearOff) -> Message + 'a) -> Self {
+        self.tear_off_key = key.into();
+        self.on_tear_off = Some(Box::new(on_tear_off));
+        self
+    }
+
     /// Enable hover positions on_click - to use in menus :D
     #[must_use]
     pub fn hover_positions_on_click(mut self) -> Self {
@@ -325,6 +525,16 @@ where
         self
     }
 
+    /// Joins a [`HoverGroup`] stored in the application state.
+    ///
+    /// Only one overlay of the group is visible at a time, and once one has opened the others
+    /// open instantly when hovered instead of waiting for the group delay.
+    #[must_use]
+    pub fn hover_group(mut self, group: &'a HoverGroup) -> Self {
+        self.hover_group = Some(group);
+        self
+    }
+
       #[must_use]
     pub fn hover_position(mut self, position: Position) -> Self {
         self.hover.config.position = position;
@@ -355,6 +565,20 @@ where
         self
     }
 
+    /// Move the overlay to another side of the button if clamping to the viewport would cover it (defaults to true)
+    #[must_use]
+    pub fn hover_avoid_anchor(mut self, avoid: bool) -> Self {
+        self.hover.config.avoid_anchor = avoid;
+        self
+    }
+
+    /// Try the opposite [`Position`] before clamping when the overlay would leave the viewport (defaults to true)
+    #[must_use]
+    pub fn hover_flip(mut self, flip: bool) -> Self {
+        self.hover.config.flip = flip;
+        self
+    }
+
     /// Sets whether the contents of the [`Button`] should be clipped on
     /// overflow.
     pub fn button_clip(mut self, clip: bool) -> Self {
@@ -424,6 +648,28 @@ where
         self
     }
 
+    /// Dims everything except a cut-out around the target and places the overlay next to it,
+    /// using the `hover_position`, `hover_gap` and `hover_alignment` settings. Useful for product tours.
+    #[must_use]
+    pub fn spotlight(mut self, target: Spotlight) -> Self {
+        self.spotlight = Some(target);
+        self
+    }
+
+    /// Shows a small element, e.g. a spinner or status dot, in the header left of the close button
+    #[must_use]
+    pub fn header_status(mut self, status: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        self.header_status = Some(status.into());
+        self
+    }
+
+    /// Replaces the default "×" close button with a custom glyph, e.g. from an icon font
+    #[must_use]
+    pub fn close_icon(mut self, icon: CloseIcon<Renderer::Font>) -> Self {
+        self.close_icon = Some(icon);
+        self
+    }
+
     /// Sets the resize mode for the overlay
     #[must_use]
     pub fn resizable(mut self, mode: ResizeMode) -> Self {
@@ -431,6 +677,45 @@ where
         self
     }
 
+    /// Shows a lightweight outline while resizing and only relayouts the content on release,
+    /// which keeps resizing smooth with heavy content
+    #[must_use]
+    pub fn resize_outline(mut self) -> Self {
+        self.resize_outline = true;
+        self
+    }
+
+    /// Snaps the resized width and height to multiples of `step`, e.g. the app's spacing unit
+    #[must_use]
+    pub fn resize_step(mut self, step: f32) -> Self {
+        self.resize_step = (step > 0.0).then_some(step);
+        self
+    }
+
+    /// Double-clicking the header toggles between filling the window and the previous size/position
+    #[must_use]
+    pub fn double_click_maximize(mut self) -> Self {
+        self.double_click_maximize = true;
+        self
+    }
+
+    /// A quick swipe of a finger starting on the overlay closes it, except while dragging the header
+    #[must_use]
+    pub fn swipe_to_dismiss(mut self, swipe_to_dismiss: bool) -> Self {
+        self.swipe_to_dismiss = swipe_to_dismiss;
+        self
+    }
+
+    /// Sets the [`Direction`] of the overlay, instead of the one set with [`direction::set`].
+    ///
+    /// Right to left puts the close button and header status on the left, mirrors the title
+    /// alignment and aligns anchored overlays and context menus from the right.
+    #[must_use]
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
     /// Reset the position and size of the [`Generic Overlay`] each time it's closed.
     pub fn reset_on_close(mut self) -> Self {
         self.reset_on_close = true;
@@ -441,6 +726,74 @@ where
         self.interactive_base = interactive;
         self
     }
+
+    /// Opens the overlay at the cursor position when the base is right clicked, like a context menu.
+    /// Left clicks are left to the base content.
+    #[must_use]
+    pub fn open_on_right_click(mut self) -> Self {
+        self.open_on_right_click = true;
+        self
+    }
+
+    /// Enables ArrowUp/ArrowDown to highlight the direct children of the overlay content
+    /// (e.g. the buttons of a `column`), with Enter/Space clicking the highlighted child.
+    #[must_use]
+    pub fn keyboard_navigation(mut self) -> Self {
+        self.keyboard_navigation = true;
+        self
+    }
+
+    /// Closes the overlay once a click in its content publishes a message, e.g. a menu item was chosen
+    #[must_use]
+    pub fn close_on_select(mut self) -> Self {
+        self.close_on_select = true;
+        self
+    }
+}
+
+/// An overlay that was dragged out of the window with [`OverlayButton::tear_off`]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TearOff {
+    /// The key given to [`OverlayButton::tear_off`] identifying the content
+    pub key: String,
+    /// Where the overlay would be if it had followed the cursor, relative to the window.
+    /// Add the window position to get screen coordinates.
+    #[cfg_attr(feature = "serde", serde(with = "crate::persist::Rectangle"))]
+    pub bounds: Rectangle,
+    /// The cursor position relative to the window when the overlay was torn off
+    #[cfg_attr(feature = "serde", serde(with = "crate::persist::Point"))]
+    pub cursor: Point,
+}
+
+/// The target left uncovered by the backdrop of a [`OverlayButton::spotlight`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Spotlight {
+    /// The button that opens the overlay
+    Anchor,
+    /// An area in window coordinates, e.g. the bounds of another widget
+    Area(Rectangle),
+}
+
+/// The glyph drawn for the close button in the overlay header
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CloseIcon<Font> {
+    /// The font used to draw the glyph
+    pub font: Font,
+    /// The unicode code point of the glyph
+    pub code_point: char,
+    /// The size of the glyph (defaults to 24px)
+    pub size: Option<Pixels>,
+}
+
+impl Default for CloseIcon<iced::Font> {
+    fn default() -> Self {
+        Self {
+            font: iced::Font::default(),
+            code_point: '×',
+            size: None,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -452,6 +805,16 @@ pub enum Position {
 }
 
 impl Position {
+    /// The position on the opposite side of the anchor
+    pub fn opposite(self) -> Self {
+        match self {
+            Self::Top => Self::Bottom,
+            Self::Bottom => Self::Top,
+            Self::Left => Self::Right,
+            Self::Right => Self::Left,
+        }
+    }
+
     pub const ALL: &'static [Self] = &[
         Self::Top,
         Self::Right,
@@ -487,6 +850,8 @@ pub struct HoverConfig {
     alignment: Alignment,
     buffer: f32,
     mode: PositionMode,
+    flip: bool,
+    avoid_anchor: bool,
 }
 
 impl Default for HoverConfig {
@@ -498,8 +863,76 @@ impl Default for HoverConfig {
             alignment: Alignment::Center,
             buffer: 10.0,
             mode: PositionMode::Outside,
+            flip: true,
+            avoid_anchor: true,
+        }
+    }
+}
+
+/// Coordinates the hover overlays of many buttons, e.g. the tooltips of a toolbar.
+///
+/// Store it in your application state and pass it to each [`OverlayButton::hover_group`].
+/// The first overlay opens after the group delay, and while any overlay of the group is open
+/// (or was closed less than a delay ago) hovering another anchor switches to it instantly.
+#[derive(Debug)]
+pub struct HoverGroup {
+    delay: Duration,
+    active: Cell<Option<u64>>,
+    last_closed: Cell<Option<Instant>>,
+}
+
+impl HoverGroup {
+    /// Creates a group whose first overlay opens after hovering for `delay`
+    pub fn new(delay: Duration) -> Self {
+        Self {
+            delay,
+            active: Cell::new(None),
+            last_closed: Cell::new(None),
+        }
+    }
+
+    /// Whether an overlay of the group is open or was just closed
+    fn is_warm(&self, now: Instant) -> bool {
+        self.active.get().is_some()
+            || self.last_closed.get().is_some_and(|closed| now.duration_since(closed) < self.delay)
+    }
+
+    /// Whether the overlay hovered since `hover_start` may open, scheduling a redraw for when it can
+    fn ready<Message>(&self, hover_start: &mut Option<Instant>, shell: &mut Shell<'_, Message>) -> bool {
+        let now = Instant::now();
+        if self.is_warm(now) {
+            return true;
+        }
+
+        let start = *hover_start.get_or_insert(now);
+        if now.duration_since(start) >= self.delay {
+            true
+        } else {
+            shell.request_redraw_at(start + self.delay);
+            false
+        }
+    }
+
+    fn activate(&self, token: u64) {
+        self.active.set(Some(token));
+    }
+
+    fn release(&self, token: u64) {
+        if self.active.get() == Some(token) {
+            self.active.set(None);
+            self.last_closed.set(Some(Instant::now()));
         }
     }
+
+    fn is_claimed_by_other(&self, token: u64) -> bool {
+        self.active.get().is_some_and(|active| active != token)
+    }
+}
+
+impl Default for HoverGroup {
+    fn default() -> Self {
+        Self::new(Duration::from_millis(500))
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -540,8 +973,7 @@ enum ResizeEdge {
 }
 
 impl ResizeEdge {
-    fn from_position(cursor_pos: Point, bounds: Rectangle) -> Self {
-        let handle = RESIZE_HANDLE_SIZE;
+    fn from_position(cursor_pos: Point, bounds: Rectangle, handle: f32) -> Self {
         
         let on_left = cursor_pos.x >= bounds.x && cursor_pos.x <= bounds.x + handle;
         let on_right = cursor_pos.x >= bounds.x + bounds.width - handle && cursor_pos.x <= bounds.x + bounds.width;
@@ -571,11 +1003,31 @@ impl ResizeEdge {
         }
     }
 
+    /// Returns which sides (left, right, top, bottom) this edge touches
+    fn sides(&self) -> (bool, bool, bool, bool) {
+        match self {
+            Self::None => (false, false, false, false),
+            Self::Top => (false, false, true, false),
+            Self::Bottom => (false, false, false, true),
+            Self::Left => (true, false, false, false),
+            Self::Right => (false, true, false, false),
+            Self::TopLeft => (true, false, true, false),
+            Self::TopRight => (false, true, true, false),
+            Self::BottomLeft => (true, false, false, true),
+            Self::BottomRight => (false, true, false, true),
+        }
+    }
+
     fn affects_height(&self) -> bool {
         matches!(self, Self::Top | Self::Bottom | Self::TopLeft | Self::TopRight | Self::BottomLeft | Self::BottomRight)
     }
 }
 
+/// Rounds a size to the nearest multiple of `step`, never below the minimum overlay size
+fn snap_to_step(size: f32, step: f32) -> f32 {
+    ((size / step).round() * step).max((MIN_OVERLAY_SIZE / step).ceil() * step)
+}
+
 /// Helper function to check if any descendant OverlayButton has an open overlay.
 /// This enables parent overlays to stay open while nested (child) overlays are active.
 fn has_open_descendant_overlays<P>(tree: &Tree) -> bool
@@ -618,13 +1070,75 @@ where
     suppress_hover_reopen: bool,
     reset_on_close: bool,
     external_is_open: Option<bool>,
+    controlled: bool,
+    /// Whether a nested overlay was open when this overlay was last laid out.
+    /// Nested overlays see events first, so this lets the topmost one consume click-outside.
+    nested_open: bool,
+    is_maximized: bool,
+    restore_bounds: Rectangle,
+    restore_height_auto: bool,
+    last_click: Option<iced::advanced::mouse::Click>,
+    /// Pending bounds while resizing in outline mode
+    resize_preview: Option<Rectangle>,
+    /// Receives Alt+Arrow move/resize, set when opened or clicked
+    focused: bool,
+    /// Identifies this overlay within a [`HoverGroup`]
+    hover_token: u64,
+    /// When the cursor started hovering the button of a grouped overlay
+    hover_start: Option<Instant>,
+    /// Where the base was right clicked, in widget coordinates
+    context_position: Point,
+    /// Child of the content highlighted with the keyboard
+    highlighted_item: Option<usize>,
+    /// Touch gestures over the overlay, for swipe to dismiss
+    gestures: gestures::Recognizer,
 }
 
 impl<P: iced::advanced::text::Paragraph> State<P> {
+    /// Opens the overlay in response to a user interaction, unless the application controls it
+    fn request_open(&mut self) {
+        if !self.controlled {
+            self.is_open = true;
+        }
+        self.focused = true;
+    }
+
+    /// Closes the overlay in response to a user interaction, unless the application controls it
+    fn request_close(&mut self) {
+        if !self.controlled {
+            self.reset();
+        }
+    }
+
+    /// Toggles between filling the window and the bounds the overlay had before maximizing.
+    /// Returns the new maximized state.
+    fn toggle_maximize(&mut self) -> bool {
+        if self.is_maximized {
+            self.position = self.restore_bounds.position();
+            self.current_width = self.restore_bounds.width;
+            self.current_height = self.restore_bounds.height;
+            self.height_auto = self.restore_height_auto;
+            self.is_maximized = false;
+        } else {
+            self.restore_bounds = Rectangle::new(
+                self.position,
+                Size::new(self.current_width, self.current_height),
+            );
+            self.restore_height_auto = self.height_auto;
+            self.position = self.window_bounds.position();
+            self.current_width = self.window_bounds.width;
+            self.current_height = self.window_bounds.height;
+            self.height_auto = false;
+            self.is_maximized = true;
+        }
+        self.is_maximized
+    }
+
     /// Resets the state to default values, effectively closing the overlay
     /// and forcing a recalculation of size/position on the next open.
     fn reset(&mut self) {
         self.is_open = false;
+        self.highlighted_item = None;
         
         if self.reset_on_close {
             // Resetting position to ORIGIN triggers the centering logic in `overlay::layout`
@@ -638,6 +1152,8 @@ impl<P: iced::advanced::text::Paragraph> State<P> {
             self.is_dragging = false;
             self.is_resizing = false;
             self.resize_edge = ResizeEdge::None;
+            self.resize_preview = None;
+            self.is_maximized = false;
         }
     }
 }
@@ -645,7 +1161,7 @@ impl<P: iced::advanced::text::Paragraph> State<P> {
 impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer> 
     for OverlayButton<'a, Message, Theme, Renderer>
 where
-    Message: Clone + 'a,
+    Message: 'a,
     Theme: iced::widget::button::Catalog + iced::widget::text::Catalog + iced::widget::container::Catalog + Catalog,
     Renderer: iced::advanced::Renderer + iced::advanced::text::Renderer<Font = iced::Font>,
 {
@@ -656,7 +1172,7 @@ where
     fn state(&self) -> widget::tree::State {
         widget::tree::State::new(
             State {
-                is_open: false,
+                is_open: self.external_is_open.unwrap_or(false),
                 position: Point::new(0.0, 0.0),
                 is_dragging: false,
                 drag_offset: Vector::new(0.0, 0.0),
@@ -676,28 +1192,50 @@ where
                 suppress_hover_reopen: false,
                 reset_on_close: self.reset_on_close,
                 external_is_open: self.external_is_open,
+                controlled: self.controlled,
+                nested_open: false,
+                is_maximized: false,
+                restore_bounds: Rectangle::with_size(Size::ZERO),
+                restore_height_auto: false,
+                last_click: None,
+                resize_preview: None,
+                focused: false,
+                hover_token: NEXT_HOVER_TOKEN.fetch_add(1, std::sync::atomic::Ordering::Relaxed),
+                hover_start: None,
+                context_position: Point::ORIGIN,
+                highlighted_item: None,
+                gestures: gestures::Recognizer::new(),
             }
         )
     }
 
     fn children(&self) -> Vec<Tree> {
-        vec![Tree::new(&(self.content)), Tree::new(&(self.button_content))]
+        let mut children = vec![Tree::new(&(self.content)), Tree::new(&(self.button_content))];
+        if let Some(status) = &self.header_status {
+            children.push(Tree::new(status));
+        }
+        children
     }
 
     fn diff(&self, tree: &mut Tree) {
         // Sync external is_open state with internal state
-        if let Some(external_open) = self.external_is_open {
-            let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
-            if state.is_open != external_open {
-                if !external_open {
-                    state.reset();
-                } else {
-                    state.is_open = true;
-                }
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        state.controlled = self.controlled;
+        if let Some(external_open) = self.external_is_open
+            && state.is_open != external_open
+        {
+            if !external_open {
+                state.reset();
+            } else {
+                state.is_open = true;
+                state.focused = true;
             }
         }
 
-        tree.diff_children(&[&self.content, &self.button_content]);
+        match &self.header_status {
+            Some(status) => tree.diff_children(&[&self.content, &self.button_content, status]),
+            None => tree.diff_children(&[&self.content, &self.button_content]),
+        }
     }
 
     fn size(&self) -> Size<Length> {
@@ -730,7 +1268,7 @@ where
         tree: &Tree,
         renderer: &mut Renderer,
         theme: &Theme,
-        _style: &renderer::Style,
+        renderer_style: &renderer::Style,
         layout: Layout<'_>,
         cursor: mouse::Cursor,
         viewport: &Rectangle,
@@ -742,14 +1280,18 @@ where
         let button_content_layout = layout.children().next().unwrap();
         let style = <Theme as button::Catalog>::style(theme, &self.button_class, self.status.unwrap_or(button::Status::Active));
 
-        if style.background.is_some()
+        // Context areas are invisible wrappers around their content
+        let text_color = if self.open_on_right_click { renderer_style.text_color } else { style.text_color };
+
+        if !self.open_on_right_click
+            && (style.background.is_some()
             || style.border.width > 0.0
-            || style.shadow.color.a > 0.0
+            || style.shadow.color.a > 0.0)
         {
             renderer.fill_quad(
                 renderer::Quad {
-                    bounds,
-                    border: style.border,
+                    bounds: pixel::snap(bounds),
+                    border: pixel::border(style.border),
                     shadow: style.shadow,
                     snap: style.snap,
                 },
@@ -770,7 +1312,7 @@ where
             renderer,
             theme,
             &renderer::Style {
-                text_color: style.text_color,
+                text_color,
             },
             button_content_layout,
             cursor,
@@ -792,6 +1334,18 @@ where
         let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
         let bounds = layout.bounds();
 
+        // Sync with the hover group, another overlay of the group may have taken over
+        if let Some(group) = self.hover_group {
+            if !state.is_open {
+                group.release(state.hover_token);
+            } else if group.is_claimed_by_other(state.hover_token) {
+                state.reset();
+                shell.invalidate_layout();
+            } else {
+                group.activate(state.hover_token);
+            }
+        }
+
         if self.interactive_base {
             self.button_content.as_widget_mut().update(
                 &mut tree.children[1],
@@ -824,9 +1378,30 @@ where
                     shell.invalidate_layout();
                 }
             }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Right)) if self.open_on_right_click => {
+                if let Some(position) = cursor.position_over(bounds) {
+                    state.context_position = position;
+                    state.highlighted_item = None;
+
+                    if !state.is_open {
+                        state.request_open();
+                        if let Some(on_open) = &self.on_open {
+                            shell.publish(on_open(position, Size::new(state.current_width, state.current_height)));
+                        }
+                        if let Some(on_toggle) = &self.on_toggle {
+                            shell.publish(on_toggle(true))
+                        }
+                    }
+
+                    shell.capture_event();
+                    shell.invalidate_layout();
+                    shell.request_redraw();
+                    return;
+                }
+            }
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
-                if cursor.is_over(bounds) {
+                if cursor.is_over(bounds) && !self.open_on_right_click {
                     self.status = Some(button::Status::Pressed);
                     
                     let should_open = if !self.hover.enabled { // Normal click mode - open, close is handled in overlay
@@ -839,15 +1414,22 @@ where
                         true
                     };
                     
-                    state.is_open = should_open;
-                    
                     if should_open {
+                        state.request_open();
                         if let Some(on_open) = &self.on_open {
                             shell.publish(on_open(state.position, Size::new(state.current_width, state.current_height)));
                         }
                         if let Some(on_toggle) = &self.on_toggle {
                             shell.publish(on_toggle(true))
                         }
+                    } else if state.is_open {
+                        state.request_close();
+                        if let Some(on_close) = &self.on_close {
+                            shell.publish(on_close());
+                        }
+                        if let Some(on_toggle) = &self.on_toggle {
+                            shell.publish(on_toggle(false))
+                        }
                     }
                     
                     if !(self.hover.config.mode == PositionMode::Inside) || self.external_is_open.is_none() {
@@ -883,9 +1465,19 @@ where
             let cursor_over_button = cursor.is_over(bounds);
             state.cursor_over_button = cursor_over_button;
 
+            if !cursor_over_button {
+                state.hover_start = None;
+            }
+
             // Open on hover
-            if cursor_over_button && !state.is_open && !state.suppress_hover_reopen {
-                state.is_open = true;
+            if cursor_over_button && !state.is_open && !state.suppress_hover_reopen
+                && self.hover_group.is_none_or(|group| group.ready(&mut state.hover_start, shell))
+            {
+                state.request_open();
+                state.hover_start = None;
+                if let Some(group) = self.hover_group {
+                    group.activate(state.hover_token);
+                }
                 if let Some(on_open) = &self.on_open {
                     shell.publish(on_open(state.position, Size::new(state.current_width, state.current_height)));
                 }
@@ -898,7 +1490,7 @@ where
 
             // Close when cursor exits both button and overlay
             if !state.cursor_over_button && !state.cursor_over_overlay && state.is_open {
-                state.reset();
+                state.request_close();
                 shell.invalidate_layout();
                 shell.request_redraw();
             }
@@ -936,9 +1528,16 @@ where
             return None;
         }
 
+        if let Some(group) = self.hover_group
+            && group.is_claimed_by_other(state.hover_token)
+        {
+            return None;
+        }
+
         let header_height = if self.hide_header { 0.0 } else { HEADER_HEIGHT };
         let padding = self.overlay_padding * 2.0;
-        let content_tree = &mut tree.children[0];
+        let (content_tree, other_trees) = tree.children.split_first_mut()?;
+        let status_tree = other_trees.get_mut(1);
 
         let mut content_node: Node;
         let mut computed_content_h: f32;
@@ -1034,6 +1633,30 @@ where
             computed_content_h = content_node.size().height;
         }
 
+        // Content sized overlays follow their content while open, up to the max height
+        if state.height_auto {
+            let window_height = if state.window_bounds.height > 0.0 {
+                state.window_bounds.height
+            } else {
+                f32::INFINITY
+            };
+            let max_height = self.overlay_max_height
+                .unwrap_or(f32::INFINITY)
+                .min(window_height);
+
+            if state.current_height > max_height {
+                state.current_height = max_height;
+
+                let capped_limits = Limits::new(
+                    Size::ZERO,
+                    Size::new(state.current_width - padding, (max_height - header_height - padding).max(0.0)),
+                );
+                content_node = self.content
+                    .as_widget_mut()
+                    .layout(content_tree, renderer, &capped_limits);
+            }
+        }
+
         let total_w = state.current_width;
         let total_h = state.current_height;
 
@@ -1041,9 +1664,29 @@ where
         button_bounds.x += offset.x;
         button_bounds.y += offset.y;
 
+        let anchor = self.open_on_right_click.then(|| state.context_position + offset);
+        let header_status = match (&mut self.header_status, status_tree) {
+            (Some(element), Some(tree)) if !self.hide_header => {
+                let limits = Limits::new(
+                    Size::ZERO,
+                    Size::new(total_w / 3.0, HEADER_HEIGHT - 8.0),
+                );
+                let node = element.as_widget_mut().layout(tree, renderer, &limits);
+                Some(HeaderStatus { element, tree, node })
+            }
+            _ => None,
+        };
+        let spotlight = self.spotlight.map(|spotlight| match spotlight {
+            Spotlight::Anchor => button_bounds,
+            Spotlight::Area(area) => area,
+        }.expand(SPOTLIGHT_PADDING));
+
         Some(overlay::Element::new(Box::new(Overlay {
             state,
             title: &self.title,
+            title_text_size: self.title_text_size.unwrap_or(Pixels(16.0)),
+            title_font: self.title_font.unwrap_or_default(),
+            title_align: self.title_align,
             class: &self.class,
             content: &mut self.content,
             radius: self.overlay_radius,
@@ -1053,6 +1696,11 @@ where
             padding: self.overlay_padding,
             on_close: self.on_close.as_deref(),
             on_toggle: self.on_toggle.as_deref(),
+            on_move: self.on_move.as_deref(),
+            on_maximize: self.on_maximize.as_deref(),
+            on_submit: self.on_submit.as_deref(),
+            tear_off_key: &self.tear_off_key,
+            on_tear_off: self.on_tear_off.as_deref(),
             button_bounds,
             button_padding: self.padding,
             hover: &self.hover,
@@ -1062,7 +1710,18 @@ where
             close_on_click_outside: self.close_on_click_outside,
             hide_header: self.hide_header,
             hide_close_button: self.hide_close_button,
+            close_icon: self.close_icon.unwrap_or_default(),
             resizable: self.resizable,
+            double_click_maximize: self.double_click_maximize,
+            swipe_to_dismiss: self.swipe_to_dismiss,
+            direction: self.direction.unwrap_or_else(direction::get),
+            resize_outline: self.resize_outline,
+            resize_step: self.resize_step,
+            anchor,
+            spotlight,
+            keyboard_navigation: self.keyboard_navigation,
+            close_on_select: self.close_on_select,
+            header_status,
         })))
     }
 
@@ -1103,6 +1762,9 @@ where
     state: &'a mut State<Renderer::Paragraph>,
     class: &'a Theme::Class<'b>,
     title: &'a str,
+    title_text_size: Pixels,
+    title_font: Renderer::Font,
+    title_align: Horizontal,
     content: &'a mut Element<'b, Message, Theme, Renderer>,
     tree: &'a mut Tree,
     width: f32,
@@ -1111,6 +1773,11 @@ where
     radius: f32,
     on_close: Option<&'a dyn Fn() -> Message>,
     on_toggle: Option<&'a dyn Fn(bool) -> Message>,
+    on_move: Option<&'a dyn Fn(Point) -> Message>,
+    on_maximize: Option<&'a dyn Fn(bool) -> Message>,
+    on_submit: Option<&'a dyn Fn() -> Message>,
+    tear_off_key: &'a str,
+    on_tear_off: Option<&'a dyn Fn(TearOff) -> Message>,
     button_bounds: Rectangle,
     button_padding: Padding,
     hover: &'a Hover,
@@ -1120,127 +1787,311 @@ where
     close_on_click_outside: bool,
     hide_header: bool,
     hide_close_button: bool,
+    close_icon: CloseIcon<Renderer::Font>,
     resizable: ResizeMode,
+    double_click_maximize: bool,
+    swipe_to_dismiss: bool,
+    direction: Direction,
+    resize_outline: bool,
+    resize_step: Option<f32>,
+    /// Fixed top-left position, used by context menus opened at the cursor
+    anchor: Option<Point>,
+    /// Area left uncovered by the backdrop, in window coordinates
+    spotlight: Option<Rectangle>,
+    keyboard_navigation: bool,
+    close_on_select: bool,
+    header_status: Option<HeaderStatus<'a, 'b, Message, Theme, Renderer>>,
 }
 
-impl<Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer>
-    for Overlay<'_, '_, Message, Theme, Renderer>
+/// Header status element of an open overlay, laid out at the origin
+struct HeaderStatus<'a, 'b, Message, Theme, Renderer> {
+    element: &'a mut Element<'b, Message, Theme, Renderer>,
+    tree: &'a mut Tree,
+    node: Node,
+}
+
+impl<Message, Theme, Renderer> Overlay<'_, '_, Message, Theme, Renderer>
 where
-    Message: Clone,
-    Theme: iced::widget::container::Catalog 
-        + iced::widget::button::Catalog 
-        + iced::widget::text::Catalog
-        + Catalog,
-    Renderer: iced::advanced::Renderer + iced::advanced::text::Renderer<Font = iced::Font>
+    Renderer: text::Renderer,
+    Theme: Catalog,
 {
-    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> Node {
-        self.state.window_bounds = Rectangle::with_size(bounds);
-        let size = Size::new(self.width, self.height);
-
-        if self.state.position == Point::ORIGIN {
-            self.state.position = Point::new(
-                (bounds.width - size.width) / 2.0,
-                (bounds.height - size.height) / 2.0,
-            );
+    fn can_resize(&self) -> bool {
+        match self.resizable {
+            ResizeMode::None => false,
+            ResizeMode::Always => true,
+            ResizeMode::WithCtrl => self.state.ctrl_pressed,
         }
+    }
 
-        if self.hover.enabled  || self.hover_positions_on_click {
-            let overlay_width = self.state.current_width;
-            let overlay_height = self.state.current_height;
-            
-            // Calculate position based on Position enum and mode
-            let mut calculated_position = match self.hover.config.mode {
-                PositionMode::Outside => {
-                    // Current behavior - overlay adjacent to button
-                    match self.hover.config.position {
-                        Position::Top | Position::Bottom => {
-                            let x = match self.hover.config.alignment {
-                                Alignment::Start => self.button_bounds.x,
-                                Alignment::Center => self.button_bounds.x 
-                                    + (self.button_bounds.width - overlay_width) / 2.0,
-                                Alignment::End => self.button_bounds.x 
-                                    + self.button_bounds.width - overlay_width,
-                            };
-                            
-                            let y = if self.hover.config.position == Position::Top {
-                                self.button_bounds.y - overlay_height - self.hover.config.gap
-                            } else {
-                                self.button_bounds.y + self.button_bounds.height + self.hover.config.gap
-                            };
-
-                            Point::new(x, y)
-                        }
-                        Position::Left | Position::Right => {
-                            let y = match self.hover.config.alignment {
-                                Alignment::Start => self.button_bounds.y,
-                                Alignment::Center => self.button_bounds.y 
-                                    + (self.button_bounds.height - overlay_height) / 2.0,
-                                Alignment::End => self.button_bounds.y 
-                                    + self.button_bounds.height - overlay_height,
-                            };
-                            
-                            let x = if self.hover.config.position == Position::Left {
-                                self.button_bounds.x - overlay_width - self.hover.config.gap
-                            } else {
-                                self.button_bounds.x + self.button_bounds.width + self.hover.config.gap
-                            };
-                            
-                            Point::new(x, y)
-                        }
+    /// Closes the overlay and publishes the close callbacks
+    fn close(&mut self, shell: &mut Shell<'_, Message>) {
+        self.state.request_close();
+        if let Some(on_close) = self.on_close {
+            shell.publish(on_close());
+        }
+        if let Some(on_toggle) = self.on_toggle {
+            shell.publish(on_toggle(false))
+        }
+        shell.invalidate_layout();
+        shell.request_redraw();
+    }
+
+    /// Top-left position of the overlay when anchored to the button at `position`
+    fn anchored_position(&self, position: Position, overlay_size: Size) -> Point {
+        let anchor = self.anchor_bounds();
+        // Start and end swap sides right to left, along the top and bottom
+        let alignment = match (self.hover.config.alignment, position) {
+            (Alignment::Start, Position::Top | Position::Bottom) if self.direction.is_rtl() => Alignment::End,
+            (Alignment::End, Position::Top | Position::Bottom) if self.direction.is_rtl() => Alignment::Start,
+            (alignment, _) => alignment,
+        };
+        match self.hover.config.mode {
+            PositionMode::Outside => {
+                // Current behavior - overlay adjacent to button
+                match position {
+                    Position::Top | Position::Bottom => {
+                        let x = match alignment {
+                            Alignment::Start => anchor.x,
+                            Alignment::Center => anchor.x 
+                                + (anchor.width - overlay_size.width) / 2.0,
+                            Alignment::End => anchor.x 
+                                + anchor.width - overlay_size.width,
+                        };
+                        
+                        let y = if position == Position::Top {
+                            anchor.y - overlay_size.height - self.hover.config.gap
+                        } else {
+                            anchor.y + anchor.height + self.hover.config.gap
+                        };
+
+                        Point::new(x, y)
+                    }
+                    Position::Left | Position::Right => {
+                        let y = match alignment {
+                            Alignment::Start => anchor.y,
+                            Alignment::Center => anchor.y 
+                                + (anchor.height - overlay_size.height) / 2.0,
+                            Alignment::End => anchor.y 
+                                + anchor.height - overlay_size.height,
+                        };
+                        
+                        let x = if position == Position::Left {
+                            anchor.x - overlay_size.width - self.hover.config.gap
+                        } else {
+                            anchor.x + anchor.width + self.hover.config.gap
+                        };
+                        
+                        Point::new(x, y)
                     }
                 }
-                PositionMode::Inside => {
-                    let content_bounds = Rectangle {
-                        x: self.button_bounds.x + self.button_padding.left,
-                        y: self.button_bounds.y + self.button_padding.top,
-                        width: self.button_bounds.width - self.button_padding.left - self.button_padding.right,
-                        height: self.button_bounds.height - self.button_padding.top - self.button_padding.bottom,
-                    };
+            }
+            PositionMode::Inside => {
+                let content_bounds = Rectangle {
+                    x: anchor.x + self.button_padding.left,
+                    y: anchor.y + self.button_padding.top,
+                    width: anchor.width - self.button_padding.left - self.button_padding.right,
+                    height: anchor.height - self.button_padding.top - self.button_padding.bottom,
+                };
 
-                    // New behavior - overlay anchored inside button bounds
-                    match self.hover.config.position {
-                        Position::Top | Position::Bottom => {
-                            // Horizontal positioning from content edges
-                            let x = match self.hover.config.alignment {
-                                Alignment::Start => content_bounds.x + self.hover.config.gap,
-                                Alignment::Center => content_bounds.x 
-                                    + (content_bounds.width - overlay_width) / 2.0,
-                                Alignment::End => content_bounds.x 
-                                    + content_bounds.width - overlay_width - self.hover.config.gap,
-                            };
-                            
-                            // Vertical positioning from content edges (inward)
-                            let y = if self.hover.config.position == Position::Top {
-                                content_bounds.y + self.hover.config.gap
-                            } else {
-                                content_bounds.y + content_bounds.height - overlay_height - self.hover.config.gap
-                            };
-
-                            Point::new(x, y)
-                        }
-                        Position::Left | Position::Right => {
-                            // Vertical positioning from content edges
-                            let y = match self.hover.config.alignment {
-                                Alignment::Start => content_bounds.y + self.hover.config.gap,
-                                Alignment::Center => content_bounds.y 
-                                    + (content_bounds.height - overlay_height) / 2.0,
-                                Alignment::End => content_bounds.y 
-                                    + content_bounds.height - overlay_height - self.hover.config.gap,
-                            };
-                            
-                            // Horizontal positioning from content edges (inward)
-                            let x = if self.hover.config.position == Position::Left {
-                                content_bounds.x + self.hover.config.gap
-                            } else {
-                                content_bounds.x + content_bounds.width - overlay_width - self.hover.config.gap
-                            };
-                            
-                            Point::new(x, y)
-                        }
+                // New behavior - overlay anchored inside button bounds
+                match position {
+                    Position::Top | Position::Bottom => {
+                        // Horizontal positioning from content edges
+                        let x = match alignment {
+                            Alignment::Start => content_bounds.x + self.hover.config.gap,
+                            Alignment::Center => content_bounds.x 
+                                + (content_bounds.width - overlay_size.width) / 2.0,
+                            Alignment::End => content_bounds.x 
+                                + content_bounds.width - overlay_size.width - self.hover.config.gap,
+                        };
+                        
+                        // Vertical positioning from content edges (inward)
+                        let y = if position == Position::Top {
+                            content_bounds.y + self.hover.config.gap
+                        } else {
+                            content_bounds.y + content_bounds.height - overlay_size.height - self.hover.config.gap
+                        };
+
+                        Point::new(x, y)
+                    }
+                    Position::Left | Position::Right => {
+                        // Vertical positioning from content edges
+                        let y = match alignment {
+                            Alignment::Start => content_bounds.y + self.hover.config.gap,
+                            Alignment::Center => content_bounds.y 
+                                + (content_bounds.height - overlay_size.height) / 2.0,
+                            Alignment::End => content_bounds.y 
+                                + content_bounds.height - overlay_size.height - self.hover.config.gap,
+                        };
+                        
+                        // Horizontal positioning from content edges (inward)
+                        let x = if position == Position::Left {
+                            content_bounds.x + self.hover.config.gap
+                        } else {
+                            content_bounds.x + content_bounds.width - overlay_size.width - self.hover.config.gap
+                        };
+                        
+                        Point::new(x, y)
                     }
                 }
-            };
+            }
+        }
+    }
+
+    /// Finds a position next to the anchor that stays inside the window without covering the anchor.
+    /// Sides are tried in the order preferred, opposite, then the remaining two.
+    fn anchor_free_position(&self, overlay_size: Size) -> Option<Point> {
+        let preferred = self.hover.config.position;
+        let window = self.state.window_bounds;
+        let candidates = std::iter::once(preferred)
+            .chain(std::iter::once(preferred.opposite()))
+            .chain(Position::ALL.iter().copied().filter(|side| *side != preferred && *side != preferred.opposite()));
+
+        candidates
+            .map(|side| {
+                // Slide along the anchor edge to fit, keeping the gap on the main axis
+                let mut origin = self.anchored_position(side, overlay_size);
+                match side {
+                    Position::Top | Position::Bottom => {
+                        origin.x = origin.x
+                            .min(window.x + window.width - overlay_size.width)
+                            .max(window.x);
+                    }
+                    Position::Left | Position::Right => {
+                        origin.y = origin.y
+                            .min(window.y + window.height - overlay_size.height)
+                            .max(window.y);
+                    }
+                }
+                origin
+            })
+            .find(|origin| {
+                let candidate = Rectangle::new(*origin, overlay_size);
+                candidate.x >= window.x
+                    && candidate.y >= window.y
+                    && candidate.x + candidate.width <= window.x + window.width
+                    && candidate.y + candidate.height <= window.y + window.height
+                    && !candidate.intersects(&self.anchor_bounds())
+            })
+    }
+
+    /// Whether an overlay at `origin` extends past the window on the side of `position`
+    fn overflows(&self, position: Position, origin: Point, overlay_size: Size) -> bool {
+        let window = self.state.window_bounds;
+        match position {
+            Position::Top => origin.y < window.y,
+            Position::Bottom => origin.y + overlay_size.height > window.y + window.height,
+            Position::Left => origin.x < window.x,
+            Position::Right => origin.x + overlay_size.width > window.x + window.width,
+        }
+    }
+
+    /// What the overlay is placed next to, the spotlight area if any, otherwise the button
+    fn anchor_bounds(&self) -> Rectangle {
+        self.spotlight.unwrap_or(self.button_bounds)
+    }
+
+    /// Whether an opaque overlay keeps the event from reaching the widgets below,
+    /// the spotlight cut-out stays interactive
+    fn blocks(&self, cursor: mouse::Cursor, bounds: Rectangle) -> bool {
+        self.opaque
+            && !cursor.is_over(bounds)
+            && !self.spotlight.is_some_and(|area| cursor.is_over(area))
+    }
+
+    /// Bounds of the close button, at the end of the header
+    fn close_button_bounds(&self, bounds: Rectangle) -> Rectangle {
+        self.direction.mirror(
+            Rectangle {
+                x: bounds.x + bounds.width - CLOSE_BUTTON_SIZE - CLOSE_BUTTON_OFFSET * 2.0,
+                y: bounds.y + (HEADER_HEIGHT - CLOSE_BUTTON_SIZE) / 2.0,
+                width: CLOSE_BUTTON_SIZE,
+                height: CLOSE_BUTTON_SIZE,
+            },
+            bounds,
+        )
+    }
+
+    /// Bounds of the header status element, at the end of the header next to the close button
+    fn header_status_bounds(&self, bounds: Rectangle) -> Option<Rectangle> {
+        let size = self.header_status.as_ref()?.node.size();
+        let close_space = if self.hide_close_button { 0.0 } else { CLOSE_BUTTON_SIZE + CLOSE_BUTTON_OFFSET * 2.0 };
+        Some(self.direction.mirror(
+            Rectangle {
+                x: bounds.x + bounds.width - close_space - HEADER_STATUS_SPACING - size.width,
+                y: bounds.y + (HEADER_HEIGHT - size.height) / 2.0,
+                width: size.width,
+                height: size.height,
+            },
+            bounds,
+        ))
+    }
+
+    /// Bounds of the content area inside the overlay bounds
+    fn content_bounds(&self, bounds: Rectangle) -> Rectangle {
+        let header_height = if self.hide_header { 0.0 } else { HEADER_HEIGHT };
+        Rectangle {
+            x: bounds.x + self.padding,
+            y: bounds.y + header_height + self.padding,
+            width: bounds.width - self.padding * 2.0,
+            height: bounds.height - header_height - self.padding * 2.0,
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer>
+    for Overlay<'_, '_, Message, Theme, Renderer>
+where
+    Theme: iced::widget::container::Catalog 
+        + iced::widget::button::Catalog 
+        + iced::widget::text::Catalog
+        + Catalog,
+    Renderer: iced::advanced::Renderer + iced::advanced::text::Renderer<Font = iced::Font>
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> Node {
+        self.state.window_bounds = Rectangle::with_size(bounds);
+        self.state.nested_open = has_open_descendant_overlays::<Renderer::Paragraph>(self.tree);
+        let size = Size::new(self.width, self.height);
+
+        if let Some(anchor) = self.anchor {
+            // Keep the menu inside the window while opening at the cursor, to its left right to left
+            let x = if self.direction.is_rtl() { anchor.x - size.width } else { anchor.x };
+            self.state.position = Point::new(
+                x.min(bounds.width - size.width).max(0.0),
+                anchor.y.min(bounds.height - size.height).max(0.0),
+            );
+        } else if self.state.position == Point::ORIGIN {
+            self.state.position = Point::new(
+                (bounds.width - size.width) / 2.0,
+                (bounds.height - size.height) / 2.0,
+            );
+        } else if self.state.height_auto {
+            // Content grew while open, keep the bottom edge inside the window
+            self.state.position.y = self.state.position.y
+                .min(bounds.height - size.height)
+                .max(0.0);
+        }
+
+        if self.hover.enabled  || self.hover_positions_on_click || self.spotlight.is_some() {
+            let overlay_width = self.state.current_width;
+            let overlay_height = self.state.current_height;
             
+            // Calculate position based on Position enum and mode
+            let overlay_size = Size::new(overlay_width, overlay_height);
+            let mut calculated_position = self.anchored_position(self.hover.config.position, overlay_size);
+
+            // Flip to the opposite side when the preferred side overflows the viewport
+            if self.hover.config.flip
+                && self.hover.config.mode == PositionMode::Outside
+                && self.overflows(self.hover.config.position, calculated_position, overlay_size)
+            {
+                let opposite = self.hover.config.position.opposite();
+                let flipped = self.anchored_position(opposite, overlay_size);
+                if !self.overflows(opposite, flipped, overlay_size) {
+                    calculated_position = flipped;
+                }
+            }
+
             // Snap within viewport if enabled
             if self.hover.config.snap_within_viewport {
                 // Horizontal bounds checking
@@ -1257,6 +2108,15 @@ where
                     calculated_position.y = self.state.window_bounds.y + self.state.window_bounds.height - overlay_height;
                 }
             }
+
+            // Clamping may have pushed the overlay over its own anchor, move it to a side that fits
+            if self.hover.config.avoid_anchor
+                && self.hover.config.mode == PositionMode::Outside
+                && Rectangle::new(calculated_position, overlay_size).intersects(&self.anchor_bounds())
+                && let Some(clear) = self.anchor_free_position(overlay_size)
+            {
+                calculated_position = clear;
+            }
             
             // Override the state position with calculated position
             self.state.position = calculated_position;
@@ -1278,11 +2138,45 @@ where
 
         // Use layer rendering for proper overlay isolation
         renderer.with_layer(self.state.window_bounds, |renderer| {
-            // Draw opaque backdrop if requested
-            if self.opaque {
+            // Dim everything but the spotlight cut-out
+            if let Some(area) = self.spotlight {
+                let window = self.state.window_bounds;
+                let backdrop = [
+                    Rectangle { height: (area.y - window.y).max(0.0), ..window },
+                    Rectangle { y: area.y + area.height, height: (window.y + window.height - area.y - area.height).max(0.0), ..window },
+                    Rectangle { x: window.x, y: area.y, width: (area.x - window.x).max(0.0), height: area.height },
+                    Rectangle { x: area.x + area.width, y: area.y, width: (window.x + window.width - area.x - area.width).max(0.0), height: area.height },
+                ];
+                for part in backdrop {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: pixel::snap(part),
+                            border: Border::default(),
+                            shadow: Shadow::default(),
+                            snap: true,
+                        },
+                        Color::from_rgba(0.0, 0.0, 0.0, 0.3),
+                    );
+                }
+
                 renderer.fill_quad(
                     renderer::Quad {
-                        bounds: self.state.window_bounds,
+                        bounds: pixel::snap(area),
+                        border: pixel::border(Border {
+                            color: draw_style.border_color,
+                            width: 2.0,
+                            radius: 4.0.into(),
+                        }),
+                        shadow: Shadow::default(),
+                        snap: true,
+                    },
+                    Color::TRANSPARENT,
+                );
+            } else if self.opaque {
+                // Draw opaque backdrop if requested
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: pixel::snap(self.state.window_bounds),
                         border: Border::default(),
                         shadow: Shadow::default(),
                         snap: false,
@@ -1294,12 +2188,12 @@ where
             // Draw background with shadow
             renderer.fill_quad(
                 renderer::Quad {
-                    bounds,
-                    border: Border {
+                    bounds: pixel::snap(bounds),
+                    border: pixel::border(Border {
                         color: draw_style.border_color,
                         width: 1.0,
                         radius: self.radius.into(),
-                    },
+                    }),
                     shadow: draw_style.shadow,
                     snap: true,
                 },
@@ -1318,8 +2212,8 @@ where
 
                 renderer.fill_quad(
                     renderer::Quad {
-                        bounds: header_bounds,
-                        border: Border {
+                        bounds: pixel::snap(header_bounds),
+                        border: pixel::border(Border {
                             color: draw_style.border_color,
                             width: 1.0,
                             radius: Radius {
@@ -1328,61 +2222,102 @@ where
                                 bottom_left: 0.0,
                                 bottom_right: 0.0,
                             },
-                        },
+                        }),
                         shadow: Shadow::default(),
                         snap: true,
                     },
                     draw_style.header_background,
                 );
 
-                // Draw title
+                // Draw title, leaving room for the status and close button at the end
+                let status_bounds = self.header_status_bounds(bounds);
+                let close_space = if self.hide_close_button { 0.0 } else { CLOSE_BUTTON_SIZE + CLOSE_BUTTON_OFFSET * 2.0 }
+                    + status_bounds.map_or(0.0, |status| status.width + HEADER_STATUS_SPACING);
+                let title_margin = 10.0;
+                let title_width = (header_bounds.width - close_space - title_margin * 2.0).max(0.0);
+                let title_area = self.direction.mirror(
+                    Rectangle {
+                        x: header_bounds.x + title_margin,
+                        width: title_width,
+                        ..header_bounds
+                    },
+                    header_bounds,
+                );
+                let title_align = self.direction.align(self.title_align);
+                let title_x = match title_align {
+                    Horizontal::Left => title_area.x,
+                    Horizontal::Center => title_area.center_x(),
+                    Horizontal::Right => title_area.x + title_area.width,
+                };
+
                 renderer.fill_text(
                     iced::advanced::Text {
                         content: self.title.to_string(),
-                        bounds: Size::new(header_bounds.width - CLOSE_BUTTON_SIZE - 20.0, header_bounds.height),
-                        size: iced::Pixels(16.0),
-                        font: iced::Font::default(),
-                        align_x: iced::advanced::text::Alignment::Center,
+                        bounds: Size::new(title_width, header_bounds.height),
+                        size: self.title_text_size,
+                        font: self.title_font,
+                        align_x: title_align.into(),
                         align_y: Vertical::Center,
                         line_height: iced::advanced::text::LineHeight::default(),
                         shaping: iced::advanced::text::Shaping::Advanced,
                         wrapping: iced::advanced::text::Wrapping::default(),
                     },
-                    Point::new(header_bounds.center_x() - (CLOSE_BUTTON_SIZE / 2.0), header_bounds.center_y()),
+                    Point::new(title_x, header_bounds.center_y()),
                     draw_style.text_color,
                     header_bounds,
                 );
 
+                if let (Some(status), Some(status_bounds)) = (&self.header_status, status_bounds) {
+                    renderer.with_translation(
+                        Vector::new(status_bounds.x, status_bounds.y),
+                        |renderer| {
+                            let adjusted_cursor = cursor.position().map(|position| {
+                                mouse::Cursor::Available(Point::new(
+                                    position.x - status_bounds.x,
+                                    position.y - status_bounds.y,
+                                ))
+                            }).unwrap_or(mouse::Cursor::Unavailable);
+
+                            status.element.as_widget().draw(
+                                status.tree,
+                                renderer,
+                                theme,
+                                &renderer::Style {
+                                    text_color: draw_style.text_color,
+                                },
+                                Layout::new(&status.node),
+                                adjusted_cursor,
+                                &Rectangle::with_size(status_bounds.size()),
+                            );
+                        },
+                    );
+                }
+
                 if !self.hide_close_button {
                     // Draw close button - centered vertically in header
-                    let close_bounds = Rectangle {
-                        x: bounds.x + bounds.width - CLOSE_BUTTON_SIZE - CLOSE_BUTTON_OFFSET * 2.0,
-                        y: bounds.y + (HEADER_HEIGHT - CLOSE_BUTTON_SIZE) / 2.0,
-                        width: CLOSE_BUTTON_SIZE,
-                        height: CLOSE_BUTTON_SIZE,
-                    };
+                    let close_bounds = self.close_button_bounds(bounds);
 
                     if cursor.is_over(close_bounds) {
                         renderer.fill_quad(
                             renderer::Quad {
-                                bounds: close_bounds,
-                                border: Border {
-                                    radius: (CLOSE_BUTTON_SIZE / 2.0).into(),
+                                bounds: pixel::snap(close_bounds),
+                                border: pixel::border(Border {
+                                    radius: draw_style.close_button_radius,
                                     ..Default::default()
-                                },
+                                }),
                                 shadow: Shadow::default(),
                                 snap: true,
                             },
-                            Color::from_rgba(0.0, 0.0, 0.0, 0.1),
+                            draw_style.close_hover_background,
                         );
                     }
 
                     renderer.fill_text(
                         iced::advanced::Text {
-                            content: "×".to_string(),
+                            content: self.close_icon.code_point.to_string(),
                             bounds: Size::new(close_bounds.width, close_bounds.height),
-                            size: iced::Pixels(24.0),
-                            font: iced::Font::default(),
+                            size: self.close_icon.size.unwrap_or(Pixels(24.0)),
+                            font: self.close_icon.font,
                             align_x: iced::advanced::text::Alignment::Center,
                             align_y: Vertical::Center,
                             line_height: iced::advanced::text::LineHeight::default(),
@@ -1416,6 +2351,21 @@ where
                         ))
                     }).unwrap_or(mouse::Cursor::Unavailable);
 
+                    if self.keyboard_navigation
+                        && let Some(item) = self.state.highlighted_item
+                            .and_then(|index| Layout::new(&self.content_layout).children().nth(index))
+                    {
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: pixel::snap(item.bounds()),
+                                border: Border::default(),
+                                shadow: Shadow::default(),
+                                snap: true,
+                            },
+                            draw_style.item_highlight,
+                        );
+                    }
+
                     self.content.as_widget().draw(
                         self.tree,
                         renderer,
@@ -1429,6 +2379,106 @@ where
                     );
                 },
             );
+
+            // Draw resize affordances on top of the content
+            if self.can_resize() {
+                // Triangle of dots in the bottom-right corner
+                const GRIP_DOT: f32 = 2.0;
+                const GRIP_SPACING: f32 = 4.0;
+                let inset = (self.radius * 0.3).max(3.0);
+                for column in 0..3 {
+                    for row in 0..3 {
+                        if column + row < 2 {
+                            continue;
+                        }
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: pixel::snap(Rectangle {
+                                    x: bounds.x + bounds.width - inset - (3 - column) as f32 * GRIP_SPACING,
+                                    y: bounds.y + bounds.height - inset - (3 - row) as f32 * GRIP_SPACING,
+                                    width: GRIP_DOT,
+                                    height: GRIP_DOT,
+                                }),
+                                border: Border::default(),
+                                shadow: Shadow::default(),
+                                snap: true,
+                            },
+                            draw_style.resize_grip_color,
+                        );
+                    }
+                }
+
+                // Highlight the edge that would be grabbed
+                if let Some(highlight) = draw_style.resize_highlight
+                    && !self.state.is_dragging
+                {
+                    let edge = if self.state.is_resizing {
+                        self.state.resize_edge
+                    } else {
+                        cursor.position()
+                            .map(|position| ResizeEdge::from_position(position, bounds, RESIZE_HANDLE_SIZE))
+                            .unwrap_or(ResizeEdge::None)
+                    };
+                    let (left, right, top, bottom) = edge.sides();
+                    let thickness = 2.0;
+                    let strips = [
+                        (left, Rectangle { width: thickness, ..bounds }),
+                        (right, Rectangle { x: bounds.x + bounds.width - thickness, width: thickness, ..bounds }),
+                        (top, Rectangle { height: thickness, ..bounds }),
+                        (bottom, Rectangle { y: bounds.y + bounds.height - thickness, height: thickness, ..bounds }),
+                    ];
+                    for (_, strip) in strips.into_iter().filter(|(active, _)| *active) {
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: pixel::snap(strip),
+                                border: Border::default(),
+                                shadow: Shadow::default(),
+                                snap: true,
+                            },
+                            highlight,
+                        );
+                    }
+                }
+            }
+
+            // Outline of the pending size while resizing in outline mode
+            if let Some(preview) = self.state.resize_preview {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: pixel::snap(preview),
+                        border: pixel::border(Border {
+                            color: draw_style.border_color,
+                            width: 2.0,
+                            radius: self.radius.into(),
+                        }),
+                        shadow: Shadow::default(),
+                        snap: true,
+                    },
+                    Color::TRANSPARENT,
+                );
+            }
+
+            #[cfg(feature = "debug")]
+            if debug::is_enabled() {
+                let layer = debug::stack();
+                let title = if self.title.is_empty() { String::new() } else { format!(" \"{}\"", self.title) };
+                debug::outline(
+                    renderer,
+                    bounds,
+                    debug::Kind::Overlay,
+                    &format!("overlay {layer}{title} {:.0}x{:.0} at ({:.0}, {:.0})", bounds.width, bounds.height, bounds.x, bounds.y),
+                );
+                debug::report(format!(
+                    "overlay {layer}{title}: {:.0}x{:.0} at ({:.0}, {:.0}){}{}{}",
+                    bounds.width,
+                    bounds.height,
+                    bounds.x,
+                    bounds.y,
+                    if self.state.nested_open { ", under a nested overlay" } else { "" },
+                    if self.state.is_dragging { ", moving" } else { "" },
+                    if self.state.is_resizing { ", resizing" } else { "" },
+                ));
+            }
         });
     }
 
@@ -1443,6 +2493,41 @@ where
     ) {
         let bounds = layout.bounds();
 
+        if self.swipe_to_dismiss
+            && let Some(Gesture::Swipe { start, .. }) = self.state.gestures.update(event)
+            && bounds.contains(start)
+            && !self.state.is_dragging
+            && !self.state.is_resizing
+        {
+            self.close(shell);
+            shell.capture_event();
+            return;
+        }
+
+        // Fingers drive the same drag/resize logic as the mouse, the content still gets the touch event
+        let content_event = event;
+        let touch_event = match event {
+            Event::Touch(touch::Event::FingerPressed { position, .. }) => Some((
+                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+                *position,
+            )),
+            Event::Touch(touch::Event::FingerMoved { position, .. }) => Some((
+                Event::Mouse(mouse::Event::CursorMoved { position: *position }),
+                *position,
+            )),
+            Event::Touch(touch::Event::FingerLifted { position, .. })
+            | Event::Touch(touch::Event::FingerLost { position, .. }) => Some((
+                Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+                *position,
+            )),
+            _ => None,
+        };
+        let is_touch = touch_event.is_some();
+        let (event, cursor) = match &touch_event {
+            Some((mouse_event, position)) => (mouse_event, mouse::Cursor::Available(*position)),
+            None => (event, cursor),
+        };
+
         // Track Ctrl key state
         match event {
             Event::Keyboard(keyboard::Event::KeyPressed { 
@@ -1465,47 +2550,37 @@ where
             _ => {}
         }
 
-        let can_resize = match self.resizable {
-            ResizeMode::None => false,
-            ResizeMode::Always => true,
-            ResizeMode::WithCtrl => self.state.ctrl_pressed,
-        };
+        let can_resize = self.can_resize();
 
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) 
             | Event::Touch(touch::Event::FingerPressed { .. }) => { 
                 let cursor_over_overlay = cursor.is_over(bounds);
-                if cursor.is_over(self.button_bounds) && self.state.is_open && !(self.hover.config.mode == PositionMode::Inside) && self.state.external_is_open.is_none() {
-                    self.state.reset();
-                    shell.invalidate_layout();
-                    shell.request_redraw();
+                self.state.focused = cursor_over_overlay;
+                if cursor.is_over(self.button_bounds) && self.state.is_open && !(self.hover.config.mode == PositionMode::Inside) && (self.state.external_is_open.is_none() || self.state.controlled) && self.anchor.is_none() {
+                    self.close(shell);
                     shell.capture_event();
                     return
                 }
 
-                if self.close_on_click_outside && !cursor_over_overlay && self.state.is_open {
-                    self.state.reset();
-                    if let Some(on_close) = self.on_close {
-                        shell.publish(on_close());
-                    }
-                    if let Some(on_toggle) = &self.on_toggle {
-                        shell.publish(on_toggle(false))
-                    }
-                    shell.invalidate_layout();
-                    shell.request_redraw();
+                // A nested overlay already handled this click, only the topmost closes per click
+                if self.close_on_click_outside && !cursor_over_overlay && self.state.is_open && !self.state.nested_open {
+                    self.close(shell);
                     return;
                 }
                 
                 // If opaque and clicking outside, consume the event without forwarding
-                if self.opaque && !cursor_over_overlay {
+                if self.blocks(cursor, bounds) {
                     return;  // Block event from reaching widgets below
                 }
 
                 if let Some(position) = cursor.position() {
                     if can_resize && cursor_over_overlay {
-                        let resize_edge = ResizeEdge::from_position(position, bounds);
+                        let handle = if is_touch { TOUCH_RESIZE_HANDLE_SIZE } else { RESIZE_HANDLE_SIZE };
+                        let resize_edge = ResizeEdge::from_position(position, bounds, handle);
                         if resize_edge != ResizeEdge::None {
                             self.state.is_resizing = true;
+                            self.state.is_maximized = false;
                             self.state.resize_edge = resize_edge;
                             self.state.resize_start_size = bounds.size();
                             self.state.resize_start_position = self.state.position;
@@ -1523,23 +2598,10 @@ where
                     // Handle close button
                     if !self.hide_header {
                         if !self.hide_close_button {
-                            let close_bounds = Rectangle {
-                                x: bounds.x + bounds.width - CLOSE_BUTTON_SIZE - CLOSE_BUTTON_OFFSET * 2.0,
-                                y: bounds.y + (HEADER_HEIGHT - CLOSE_BUTTON_SIZE) / 2.0,
-                                width: CLOSE_BUTTON_SIZE,
-                                height: CLOSE_BUTTON_SIZE,
-                            };
+                            let close_bounds = self.close_button_bounds(bounds);
 
                             if cursor.is_over(close_bounds) {
-                                self.state.reset();
-                                if let Some(on_close) = self.on_close {
-                                    shell.publish(on_close());
-                                }
-                                if let Some(on_toggle) = &self.on_toggle {
-                                    shell.publish(on_toggle(false))
-                                }
-                                shell.invalidate_layout();
-                                shell.request_redraw();
+                                self.close(shell);
                                 return;
                             }
                         }
@@ -1553,6 +2615,19 @@ where
                         };
 
                         if cursor.is_over(header_bounds) {
+                            let click = iced::advanced::mouse::Click::new(position, mouse::Button::Left, self.state.last_click);
+                            self.state.last_click = Some(click);
+
+                            if self.double_click_maximize && click.kind() == iced::advanced::mouse::click::Kind::Double {
+                                let maximized = self.state.toggle_maximize();
+                                if let Some(on_maximize) = self.on_maximize {
+                                    shell.publish(on_maximize(maximized));
+                                }
+                                shell.invalidate_layout();
+                                shell.request_redraw();
+                                return;
+                            }
+
                             self.state.is_dragging = true;
                             self.state.drag_offset = Vector::new(
                                 position.x - bounds.x,
@@ -1578,7 +2653,18 @@ where
                 }
             }
             Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
-                let cursor_over_overlay = cursor.is_over(bounds);
+                if self.state.is_dragging
+                    && let Some(on_move) = self.on_move {
+                        shell.publish(on_move(self.state.position));
+                    }
+                if let Some(preview) = self.state.resize_preview.take() {
+                    self.state.current_width = preview.width;
+                    self.state.current_height = preview.height;
+                    if self.state.resize_edge.affects_height() {
+                        self.state.height_auto = false;
+                    }
+                    self.state.position = preview.position();
+                }
                 self.state.is_dragging = false;
                 self.state.is_resizing = false;
                 self.state.resize_edge = ResizeEdge::None;
@@ -1586,11 +2672,16 @@ where
                 shell.request_redraw();
                 
                 // If opaque, consume the event
-                if self.opaque && !cursor_over_overlay {
+                if self.blocks(cursor, bounds) {
                     return;
                 }
             }
             Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                // Mouse takes over from keyboard highlighting
+                if self.keyboard_navigation && cursor.is_over(bounds) {
+                    self.state.highlighted_item = None;
+                }
+
                 // handle hover first
                 if self.hover.enabled || self.hover_positions_on_click {
                     self.state.cursor_over_overlay = cursor.is_over(layout.bounds().expand(self.hover.config.buffer));
@@ -1599,7 +2690,7 @@ where
                     // Close if cursor over neither button nor overlay
                     if !self.state.cursor_over_button && !self.state.cursor_over_overlay && !has_open_descendant_overlays::<Renderer::Paragraph>(self.tree) {
                         if self.state.external_is_open.is_none() {
-                            self.state.reset();
+                            self.state.request_close();
                         }
                         if let Some(on_close) = self.on_close {
                             shell.publish(on_close());
@@ -1650,6 +2741,36 @@ where
                             _ => {}
                         }
 
+                        // Snap to the resize grid, keeping the opposite edge in place
+                        if let Some(step) = self.resize_step {
+                            new_width = snap_to_step(new_width, step);
+                            new_height = snap_to_step(new_height, step);
+                            let start = self.state.resize_start_position;
+                            let start_size = self.state.resize_start_size;
+                            if matches!(self.state.resize_edge, ResizeEdge::Left | ResizeEdge::TopLeft | ResizeEdge::BottomLeft) {
+                                new_x = start.x + start_size.width - new_width;
+                            }
+                            if matches!(self.state.resize_edge, ResizeEdge::Top | ResizeEdge::TopLeft | ResizeEdge::TopRight) {
+                                new_y = start.y + start_size.height - new_height;
+                            }
+                        }
+
+                        // Clamp position to viewport
+                        new_x = new_x.max(0.0).min(self.state.window_bounds.width - new_width);
+                        new_y = new_y.max(0.0).min(self.state.window_bounds.height - new_height);
+
+                        // Only move the outline, the content is laid out once on release
+                        if self.resize_outline {
+                            self.state.resize_preview = Some(Rectangle {
+                                x: new_x,
+                                y: new_y,
+                                width: new_width,
+                                height: new_height,
+                            });
+                            shell.request_redraw();
+                            return;
+                        }
+
                         // Store in state
                         self.state.current_width = new_width;
                         self.state.current_height = new_height;
@@ -1659,9 +2780,6 @@ where
                             self.state.height_auto = false;
                         }
 
-                        // Clamp position to viewport
-                        new_x = new_x.max(0.0).min(self.state.window_bounds.width - new_width);
-                        new_y = new_y.max(0.0).min(self.state.window_bounds.height - new_height);
                         self.state.position = Point::new(new_x, new_y);
                         
                         shell.invalidate_layout();
@@ -1674,6 +2792,23 @@ where
                         let new_x = position.x - self.state.drag_offset.x;
                         let new_y = position.y - self.state.drag_offset.y;
 
+                        // Dragging past the window edge tears the overlay off
+                        if let Some(on_tear_off) = self.on_tear_off
+                            && !self.state.window_bounds.contains(position)
+                        {
+                            shell.publish(on_tear_off(TearOff {
+                                key: self.tear_off_key.to_string(),
+                                bounds: Rectangle::new(
+                                    Point::new(new_x, new_y),
+                                    Size::new(self.state.current_width, self.state.current_height),
+                                ),
+                                cursor: position,
+                            }));
+                            self.state.is_dragging = false;
+                            self.close(shell);
+                            return;
+                        }
+
                         self.state.position.x = new_x
                             .max(0.0)
                             .min(self.state.window_bounds.width - self.state.current_width);
@@ -1687,36 +2822,192 @@ where
                     }
                 }
                 
-                if self.opaque && !cursor.is_over(bounds) {
+                if self.blocks(cursor, bounds) {
                     return;
                 }
             }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Right))
+                if self.anchor.is_some() && !cursor.is_over(bounds) =>
+            {
+                // Let the event through so the base can reopen at the new cursor position
+                self.close(shell);
+                return;
+            }
             Event::Keyboard(keyboard::Event::KeyPressed {
-                key: keyboard::Key::Named(keyboard::key::Named::Escape),
+                key: keyboard::Key::Named(named),
+                modifiers,
                 ..
-            }) => {
-                self.state.reset();
-                if let Some(on_close) = self.on_close {
-                    shell.publish(on_close());
+            }) if modifiers.alt()
+                && self.state.focused
+                && !self.state.nested_open
+                && matches!(
+                    named,
+                    keyboard::key::Named::ArrowLeft
+                        | keyboard::key::Named::ArrowRight
+                        | keyboard::key::Named::ArrowUp
+                        | keyboard::key::Named::ArrowDown
+                ) => {
+                let (dx, dy) = match named {
+                    keyboard::key::Named::ArrowLeft => (-KEYBOARD_STEP, 0.0),
+                    keyboard::key::Named::ArrowRight => (KEYBOARD_STEP, 0.0),
+                    keyboard::key::Named::ArrowUp => (0.0, -KEYBOARD_STEP),
+                    _ => (0.0, KEYBOARD_STEP),
+                };
+                let window = self.state.window_bounds;
+
+                if modifiers.shift() {
+                    // Alt+Shift+Arrow resizes from the bottom-right corner
+                    if self.resizable == ResizeMode::None {
+                        return;
+                    }
+                    // A step of its own along the arrow's axis, leaving the other one as it is
+                    let step = |delta: f32| match self.resize_step {
+                        Some(step) if delta != 0.0 => delta.signum() * step,
+                        _ => delta,
+                    };
+                    let (dx, dy) = (step(dx), step(dy));
+                    let snap = |size: f32| self.resize_step.map_or(size, |step| snap_to_step(size, step));
+                    if dx != 0.0 {
+                        self.state.current_width = snap(self.state.current_width + dx)
+                            .min(window.width - self.state.position.x)
+                            .max(MIN_OVERLAY_SIZE);
+                    }
+                    if dy != 0.0 {
+                        self.state.current_height = snap(self.state.current_height + dy)
+                            .min(window.height - self.state.position.y)
+                            .max(MIN_OVERLAY_SIZE);
+                        self.state.height_auto = false;
+                    }
+                    self.state.is_maximized = false;
+                } else {
+                    // Alt+Arrow nudges, like dragging the header
+                    if self.hover.enabled || self.anchor.is_some() {
+                        return;
+                    }
+                    self.state.position.x = (self.state.position.x + dx)
+                        .max(0.0)
+                        .min(window.width - self.state.current_width);
+                    self.state.position.y = (self.state.position.y + dy)
+                        .max(0.0)
+                        .min(window.height - self.state.current_height);
+                    if let Some(on_move) = self.on_move {
+                        shell.publish(on_move(self.state.position));
+                    }
                 }
-                if let Some(on_toggle) = &self.on_toggle {
-                    shell.publish(on_toggle(false))
+
+                shell.capture_event();
+                shell.invalidate_layout();
+                shell.request_redraw();
+                return;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(named),
+                ..
+            }) if self.keyboard_navigation && matches!(
+                named,
+                keyboard::key::Named::ArrowDown
+                    | keyboard::key::Named::ArrowUp
+                    | keyboard::key::Named::Enter
+                    | keyboard::key::Named::Space
+            ) => {
+                let content_bounds = self.content_bounds(bounds);
+                let content_layout_node = self.content_layout.clone()
+                    .move_to(Point::new(content_bounds.x, content_bounds.y));
+                let items: Vec<Rectangle> = Layout::new(&content_layout_node)
+                    .children()
+                    .map(|item| item.bounds())
+                    .collect();
+
+                if items.is_empty() {
+                    return;
                 }
+
+                match named {
+                    keyboard::key::Named::ArrowDown => {
+                        self.state.highlighted_item = Some(
+                            self.state.highlighted_item.map_or(0, |index| (index + 1) % items.len())
+                        );
+                    }
+                    keyboard::key::Named::ArrowUp => {
+                        self.state.highlighted_item = Some(
+                            self.state.highlighted_item
+                                .map_or(items.len() - 1, |index| (index + items.len() - 1) % items.len())
+                        );
+                    }
+                    _ => {
+                        if let Some(item) = self.state.highlighted_item.and_then(|index| items.get(index)) {
+                            // Simulate a click in the middle of the highlighted child
+                            let item_cursor = mouse::Cursor::Available(item.center());
+                            let mut messages = Vec::new();
+                            let mut item_shell = Shell::new(&mut messages);
+
+                            for click in [mouse::Event::ButtonPressed(mouse::Button::Left), mouse::Event::ButtonReleased(mouse::Button::Left)] {
+                                self.content.as_widget_mut().update(
+                                    self.tree,
+                                    &Event::Mouse(click),
+                                    Layout::new(&content_layout_node),
+                                    item_cursor,
+                                    renderer,
+                                    clipboard,
+                                    &mut item_shell,
+                                    &bounds,
+                                );
+                            }
+
+                            let selected = !item_shell.is_empty();
+                            shell.merge(item_shell, std::convert::identity);
+
+                            if selected && self.close_on_select {
+                                self.close(shell);
+                            }
+                        }
+                    }
+                }
+
+                shell.capture_event();
+                shell.request_redraw();
+                return;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(keyboard::key::Named::Enter),
+                ..
+            }) if self.on_submit.is_some() => {
+                if let Some(on_submit) = self.on_submit {
+                    shell.publish(on_submit());
+                }
+                shell.capture_event();
+                return;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(keyboard::key::Named::Escape),
+                ..
+            }) => {
+                self.close(shell);
                 return;
             }
             _ => {}
         }
 
         // If opaque, consume ALL mouse/touch events that are outside the overlay
-        if self.opaque {
-            match event {
-                Event::Mouse(_) | Event::Touch(_) => {
-                    if !cursor.is_over(bounds) {
-                        return;
-                    }
-                }
-                _ => {}
-            }
+        if matches!(event, Event::Mouse(_) | Event::Touch(_)) && self.blocks(cursor, bounds) {
+            return;
+        }
+
+        // Status elements such as spinners need events to animate
+        if let Some(status_bounds) = self.header_status_bounds(bounds)
+            && let Some(status) = &mut self.header_status
+        {
+            let status_node = status.node.clone().move_to(status_bounds.position());
+            status.element.as_widget_mut().update(
+                status.tree,
+                content_event,
+                Layout::new(&status_node),
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                &bounds,
+            );
         }
 
         // Forward events to content
@@ -1734,18 +3025,49 @@ where
 
         // Only forward events to content if not dragging and if cursor is in content area
         if !self.state.is_dragging && !self.state.is_resizing {
-            self.content.as_widget_mut().update(
-                self.tree,
-                event,
-                content_layout,
-                cursor,
-                renderer,
-                clipboard,
-                shell,
-                &layout.bounds(),
-            );
-        }
+            if self.close_on_select {
+                let mut messages = Vec::new();
+                let mut content_shell = Shell::new(&mut messages);
+
+                self.content.as_widget_mut().update(
+                    self.tree,
+                    content_event,
+                    content_layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    &mut content_shell,
+                    &layout.bounds(),
+                );
+
+                let selected = !content_shell.is_empty()
+                    && matches!(event, Event::Mouse(mouse::Event::ButtonReleased(_)));
+                shell.merge(content_shell, std::convert::identity);
+
+                if selected && !has_open_descendant_overlays::<Renderer::Paragraph>(self.tree) {
+                    self.close(shell);
+                }
+            } else {
+                self.content.as_widget_mut().update(
+                    self.tree,
+                    content_event,
+                    content_layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    &layout.bounds(),
+                );
+            }
 
+            // Tab stays inside the open overlay
+            if !shell.is_event_captured()
+                && focus::traverse(self.content, self.tree, content_layout, renderer, content_event, true)
+            {
+                shell.capture_event();
+                shell.request_redraw();
+            }
+        }
     }
 
     fn mouse_interaction(
@@ -1758,16 +3080,12 @@ where
 
         if cursor.is_over(bounds) {
             // Determine if we should be resizable
-            let can_resize = match self.resizable {
-                ResizeMode::None => false,
-                ResizeMode::Always => true,
-                ResizeMode::WithCtrl => self.state.ctrl_pressed,
-            };
+            let can_resize = self.can_resize();
 
             // Show resize cursors if resizable
             if can_resize
                 && let Some(position) = cursor.position() {
-                    let resize_edge = ResizeEdge::from_position(position, bounds);
+                    let resize_edge = ResizeEdge::from_position(position, bounds, RESIZE_HANDLE_SIZE);
                     if resize_edge != ResizeEdge::None {
                         return resize_edge.cursor_icon();
                     }
@@ -1776,18 +3094,30 @@ where
             // Show pointer when over close button (if header is visible)
             if !self.hide_header {
                 if !self.hide_close_button {
-                    let close_bounds = Rectangle {
-                        x: bounds.x + bounds.width - CLOSE_BUTTON_SIZE - CLOSE_BUTTON_OFFSET * 2.0,
-                        y: bounds.y + (HEADER_HEIGHT - CLOSE_BUTTON_SIZE) / 2.0,
-                        width: CLOSE_BUTTON_SIZE,
-                        height: CLOSE_BUTTON_SIZE,
-                    };
+                    let close_bounds = self.close_button_bounds(bounds);
 
                     if cursor.is_over(close_bounds) {
                         return mouse::Interaction::Pointer;
                     }
                 }
 
+                if let Some(status_bounds) = self.header_status_bounds(bounds)
+                    && let Some(status) = &self.header_status
+                    && cursor.is_over(status_bounds)
+                {
+                    let status_node = status.node.clone().move_to(status_bounds.position());
+                    let interaction = status.element.as_widget().mouse_interaction(
+                        status.tree,
+                        Layout::new(&status_node),
+                        cursor,
+                        &bounds,
+                        renderer,
+                    );
+                    if interaction != mouse::Interaction::default() {
+                        return interaction;
+                    }
+                }
+
                 // Show grab cursor when over header
                 let header_bounds = Rectangle {
                     x: bounds.x,
@@ -1887,16 +3217,18 @@ where
         renderer: &Renderer,
         operation: &mut dyn widget::Operation,
     ) {
-        self.content
-            .as_widget_mut()
-            .operate(self.tree, layout, renderer, operation);       
+        focus::trapped(layout.bounds(), operation, &mut |operation| {
+            self.content
+                .as_widget_mut()
+                .operate(self.tree, layout, renderer, operation);
+        });
     }
 }
 
 impl<'a, Message, Theme, Renderer> From<OverlayButton<'a, Message, Theme, Renderer>>
     for Element<'a, Message, Theme, Renderer>
 where
-    Message: Clone + 'a,
+    Message: 'a,
     Theme: iced::widget::button::Catalog + iced::widget::text::Catalog + iced::widget::container::Catalog + Catalog + 'a,
     Renderer: iced::advanced::Renderer + iced::advanced::text::Renderer<Font = iced::Font> + 'a,
 {
@@ -1905,35 +3237,31 @@ where
     }
 }
 
+type DefaultState = State<<iced::Renderer as iced::advanced::text::Renderer>::Paragraph>;
+
+/// Opens an overlay button with the given Id
+pub fn open<T>(id: widget::Id) -> impl Operation<T> {
+    operation::on_state(id, |state: &mut DefaultState| {
+        state.is_open = true;
+        state.focused = true;
+    })
+}
+
 /// Closes an overlay button with the given Id
 pub fn close<T>(id: widget::Id) -> impl Operation<T> {
-    struct Close {
-        id: widget::Id,
-    }
-    
-    impl<T> Operation<T> for Close {
-        fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation<T>)) {
-            // Continue traversing the tree
-            operate(self);
-        }
-        
-        fn custom(
-            &mut self,
-            widget_id: Option<&widget::Id>,
-            _bounds: Rectangle,
-            state: &mut dyn std::any::Any,
-        ) {
-            if widget_id == Some(&self.id) {
-                type DefaultParagraph = <iced::Renderer as iced::advanced::text::Renderer>::Paragraph;
-                
-                if let Some(state) = state.downcast_mut::<State<DefaultParagraph>>() {
-                    state.reset();
-                }
-            }
+    operation::on_state(id, |state: &mut DefaultState| state.reset())
+}
+
+/// Opens an overlay button with the given Id if it is closed, and closes it otherwise
+pub fn toggle<T>(id: widget::Id) -> impl Operation<T> {
+    operation::on_state(id, |state: &mut DefaultState| {
+        if state.is_open {
+            state.reset();
+        } else {
+            state.is_open = true;
+            state.focused = true;
         }
-    }
-    
-    Close { id }
+    })
 }
 
 /// Strategy for sizing the overlay
@@ -1984,6 +3312,16 @@ pub struct Style {
     pub text_color: Color,
     /// Shadow
     pub shadow: Shadow,
+    /// Background of the close button while hovered
+    pub close_hover_background: Color,
+    /// Border radius of the close button hover background
+    pub close_button_radius: Radius,
+    /// Color of the grip drawn in the bottom-right corner of resizable overlays
+    pub resize_grip_color: Color,
+    /// Optional highlight drawn along the edge under the cursor while it can be resized
+    pub resize_highlight: Option<Color>,
+    /// Background of the content child highlighted with keyboard navigation
+    pub item_highlight: Color,
 }
 
 impl Default for Style {
@@ -1998,6 +3336,11 @@ impl Default for Style {
                 offset: Vector::new(0.0, 4.0),
                 blur_radius: 16.0,
             },
+            close_hover_background: Color::from_rgba(0.0, 0.0, 0.0, 0.1),
+            close_button_radius: (CLOSE_BUTTON_SIZE / 2.0).into(),
+            resize_grip_color: Color::from_rgb8(200, 200, 200),
+            resize_highlight: None,
+            item_highlight: Color::from_rgba(0.0, 0.0, 0.0, 0.1),
         }
     }
 }
@@ -2009,20 +3352,7 @@ impl Catalog for iced::Theme {
     type Class<'a> = StyleFn<'a, Self>;
     
     fn default<'a>() -> Self::Class<'a> {
-        Box::new(|theme| {
-            let palette = theme.extended_palette();
-            Style {
-                background: palette.background.base.color,
-                header_background: palette.background.weak.color,
-                border_color: palette.background.strong.color,
-                text_color: palette.background.base.text,
-                shadow: Shadow {
-                    color: Color::from_rgba(0.0, 0.0, 0.0, 0.3),
-                    offset: Vector::new(0.0, 4.0),
-                    blur_radius: 16.0,
-                },
-            }
-        })
+        Box::new(default)
     }
     
     fn style(&self, class: &Self::Class<'_>) -> Style {
@@ -2030,6 +3360,41 @@ impl Catalog for iced::Theme {
     }
 }
 
+/// The default overlay style.
+pub fn default(theme: &iced::Theme) -> Style {
+    from_palette(Palette::new(theme, Preset::Standard))
+}
+
+/// The [`Preset::Compact`] overlay style.
+pub fn compact(theme: &iced::Theme) -> Style {
+    from_palette(Palette::new(theme, Preset::Compact))
+}
+
+/// The [`Preset::Flat`] overlay style.
+pub fn flat(theme: &iced::Theme) -> Style {
+    from_palette(Palette::new(theme, Preset::Flat))
+}
+
+/// The [`Preset::HighContrast`] overlay style.
+pub fn high_contrast(theme: &iced::Theme) -> Style {
+    from_palette(Palette::new(theme, Preset::HighContrast))
+}
+
+fn from_palette(palette: Palette) -> Style {
+    Style {
+        background: palette.background,
+        header_background: palette.raised,
+        border_color: palette.border,
+        text_color: palette.text,
+        shadow: palette.shadow,
+        close_hover_background: palette.highlight,
+        close_button_radius: (CLOSE_BUTTON_SIZE / 2.0).into(),
+        resize_grip_color: palette.border,
+        resize_highlight: None,
+        item_highlight: palette.highlight,
+    }
+}
+
 pub fn primary(theme: &iced::Theme) -> Style {
     let palette = theme.extended_palette();
 
@@ -2043,6 +3408,11 @@ pub fn primary(theme: &iced::Theme) -> Style {
             offset: Vector::new(0.0, 4.0),
             blur_radius: 16.0,
         },
+        close_hover_background: Color::from_rgba(0.0, 0.0, 0.0, 0.1),
+        close_button_radius: (CLOSE_BUTTON_SIZE / 2.0).into(),
+        resize_grip_color: palette.primary.strong.color,
+        resize_highlight: None,
+        item_highlight: Color::from_rgba(0.0, 0.0, 0.0, 0.1),
     }
 }
 
@@ -2059,6 +3429,11 @@ pub fn success(theme: &iced::Theme) -> Style {
             offset: Vector::new(0.0, 4.0),
             blur_radius: 16.0,
         },
+        close_hover_background: Color::from_rgba(0.0, 0.0, 0.0, 0.1),
+        close_button_radius: (CLOSE_BUTTON_SIZE / 2.0).into(),
+        resize_grip_color: palette.success.strong.color,
+        resize_highlight: None,
+        item_highlight: Color::from_rgba(0.0, 0.0, 0.0, 0.1),
     }
 }
 
@@ -2075,6 +3450,11 @@ pub fn danger(theme: &iced::Theme) -> Style {
             offset: Vector::new(0.0, 4.0),
             blur_radius: 16.0,
         },
+        close_hover_background: Color::from_rgba(0.0, 0.0, 0.0, 0.1),
+        close_button_radius: (CLOSE_BUTTON_SIZE / 2.0).into(),
+        resize_grip_color: palette.danger.strong.color,
+        resize_highlight: None,
+        item_highlight: Color::from_rgba(0.0, 0.0, 0.0, 0.1),
     }
 }
 
@@ -2091,6 +3471,11 @@ pub fn warning(theme: &iced::Theme) -> Style {
             offset: Vector::new(0.0, 4.0),
             blur_radius: 16.0,
         },
+        close_hover_background: Color::from_rgba(0.0, 0.0, 0.0, 0.1),
+        close_button_radius: (CLOSE_BUTTON_SIZE / 2.0).into(),
+        resize_grip_color: palette.warning.strong.color,
+        resize_highlight: None,
+        item_highlight: Color::from_rgba(0.0, 0.0, 0.0, 0.1),
     }
 }
 
@@ -2102,6 +3487,11 @@ pub fn blank(theme: &iced::Theme) -> Style {
         header_background: Color::TRANSPARENT,
         border_color: Color::TRANSPARENT,
         text_color: Color::TRANSPARENT,
-        shadow: Shadow::default()
+        shadow: Shadow::default(),
+        close_hover_background: Color::TRANSPARENT,
+        close_button_radius: Radius::default(),
+        resize_grip_color: Color::TRANSPARENT,
+        resize_highlight: None,
+        item_highlight: Color::TRANSPARENT,
     }
 }
\ No newline at end of file
@@ -0,0 +1,420 @@
+//! Touch gesture recognition shared by the widgets.
+//!
+//! A [`Recognizer`] is kept in a widget's state and fed every event. It turns the touch events
+//! into taps, double taps, long presses, swipes and pinches, and ignores everything else.
+//! [`GestureArea`] wraps any content and reports the gestures made on it, such as swipes
+//! paging through a carousel.
+//!
+//! Long presses happen while the finger is still down, so a widget holding a finger asks for a
+//! redraw at [`Recognizer::deadline`]; the redraw event is what fires the long press:
+//!
+//! ```ignore
+//! let gesture = state.gestures.update(event);
+//!
+//! if let Some(deadline) = state.gestures.deadline() {
+//!     shell.request_redraw_at(deadline);
+//! }
+//! ```
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        mouse, overlay, renderer,
+        widget::{self, tree::Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    time::{Duration, Instant},
+    touch, window, Element, Event, Length, Point, Rectangle, Size, Vector,
+};
+
+/// How far a finger may move and still tap or long press.
+pub const SLOP: f32 = 10.0;
+/// The longest a finger may be down for a tap.
+pub const TAP_TIME: Duration = Duration::from_millis(300);
+/// The longest time between the taps of a double tap.
+pub const DOUBLE_TAP_TIME: Duration = Duration::from_millis(300);
+/// How long a finger is held still for a long press.
+pub const LONG_PRESS_TIME: Duration = Duration::from_millis(500);
+/// The shortest distance of a swipe.
+pub const SWIPE_DISTANCE: f32 = 50.0;
+/// The longest a finger may be down for a swipe.
+pub const SWIPE_TIME: Duration = Duration::from_millis(500);
+
+/// A recognized gesture.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Gesture {
+    /// A short touch where the finger went down and up.
+    Tap(Point),
+    /// A second tap close to the first, reported instead of its [`Gesture::Tap`].
+    DoubleTap(Point),
+    /// A finger held still, reported while it is still down.
+    LongPress(Point),
+    /// A quick stroke, reported when the finger lifts.
+    Swipe {
+        /// Where the finger went down
+        start: Point,
+        /// The main direction of the stroke
+        direction: Direction,
+        /// From where the finger went down to where it lifted
+        distance: Vector,
+    },
+    /// Two fingers moving apart or together, reported as they move.
+    Pinch {
+        /// The point between the fingers
+        center: Point,
+        /// The distance between the fingers relative to when the second one went down
+        scale: f32,
+    },
+}
+
+/// The main direction of a [`Gesture::Swipe`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Left,
+    Right,
+    Up,
+    Down,
+}
+
+impl Direction {
+    fn of(distance: Vector) -> Self {
+        if distance.x.abs() >= distance.y.abs() {
+            if distance.x < 0.0 { Self::Left } else { Self::Right }
+        } else if distance.y < 0.0 {
+            Self::Up
+        } else {
+            Self::Down
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Finger {
+    id: touch::Finger,
+    start: Point,
+    position: Point,
+    pressed_at: Instant,
+    /// Went further than [`SLOP`] from the start
+    moved: bool,
+    long_pressed: bool,
+}
+
+/// Turns touch events into [`Gesture`]s.
+#[derive(Debug, Clone, Default)]
+pub struct Recognizer {
+    fingers: Vec<Finger>,
+    /// More than one finger went down since all were last up; no taps, presses or swipes then
+    multi: bool,
+    /// The distance between the first two fingers when the second went down
+    pinch: Option<f32>,
+    last_tap: Option<(Point, Instant)>,
+}
+
+impl Recognizer {
+    /// Creates a new [`Recognizer`] with no fingers down.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether any finger is down.
+    pub fn is_active(&self) -> bool {
+        !self.fingers.is_empty()
+    }
+
+    /// When the finger held down becomes a long press, if it still can.
+    pub fn deadline(&self) -> Option<Instant> {
+        match self.fingers.as_slice() {
+            [finger] if !self.multi && !finger.moved && !finger.long_pressed => {
+                Some(finger.pressed_at + LONG_PRESS_TIME)
+            }
+            _ => None,
+        }
+    }
+
+    /// Forgets the fingers down, such as when another gesture took over.
+    pub fn reset(&mut self) {
+        self.fingers.clear();
+        self.multi = false;
+        self.pinch = None;
+    }
+
+    /// Processes `event`, returning the gesture it completes.
+    pub fn update(&mut self, event: &Event) -> Option<Gesture> {
+        match event {
+            Event::Touch(touch::Event::FingerPressed { id, position }) => {
+                self.fingers.retain(|finger| finger.id != *id);
+                self.fingers.push(Finger {
+                    id: *id,
+                    start: *position,
+                    position: *position,
+                    pressed_at: Instant::now(),
+                    moved: false,
+                    long_pressed: false,
+                });
+
+                if let [first, second, ..] = self.fingers.as_slice() {
+                    self.multi = true;
+                    self.pinch.get_or_insert(first.position.distance(second.position));
+                }
+
+                None
+            }
+            Event::Touch(touch::Event::FingerMoved { id, position }) => {
+                let finger = self.fingers.iter_mut().find(|finger| finger.id == *id)?;
+                finger.position = *position;
+                finger.moved |= finger.start.distance(*position) > SLOP;
+
+                let (Some(start), [first, second, ..]) = (self.pinch, self.fingers.as_slice()) else {
+                    return None;
+                };
+
+                (start > 0.0).then(|| Gesture::Pinch {
+                    center: Point::new(
+                        (first.position.x + second.position.x) / 2.0,
+                        (first.position.y + second.position.y) / 2.0,
+                    ),
+                    scale: first.position.distance(second.position) / start,
+                })
+            }
+            Event::Touch(touch::Event::FingerLifted { id, position }) => {
+                let index = self.fingers.iter().position(|finger| finger.id == *id)?;
+                let finger = self.fingers.remove(index);
+                let multi = self.multi;
+
+                if self.fingers.len() < 2 {
+                    self.pinch = None;
+                }
+                if self.fingers.is_empty() {
+                    self.multi = false;
+                }
+                if multi || finger.long_pressed {
+                    return None;
+                }
+
+                let now = Instant::now();
+                let held = now.duration_since(finger.pressed_at);
+                let distance = *position - finger.start;
+
+                if !finger.moved && held <= TAP_TIME {
+                    let double = self.last_tap.take().is_some_and(|(at, time)| {
+                        now.duration_since(time) <= DOUBLE_TAP_TIME && at.distance(*position) <= SLOP * 2.0
+                    });
+
+                    if double {
+                        Some(Gesture::DoubleTap(*position))
+                    } else {
+                        self.last_tap = Some((*position, now));
+                        Some(Gesture::Tap(*position))
+                    }
+                } else if held <= SWIPE_TIME && distance.x.hypot(distance.y) >= SWIPE_DISTANCE {
+                    Some(Gesture::Swipe {
+                        start: finger.start,
+                        direction: Direction::of(distance),
+                        distance,
+                    })
+                } else {
+                    None
+                }
+            }
+            Event::Touch(touch::Event::FingerLost { id, .. }) => {
+                self.fingers.retain(|finger| finger.id != *id);
+                if self.fingers.len() < 2 {
+                    self.pinch = None;
+                }
+                if self.fingers.is_empty() {
+                    self.multi = false;
+                }
+
+                None
+            }
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                let deadline = self.deadline()?;
+                if *now < deadline {
+                    return None;
+                }
+
+                let finger = self.fingers.first_mut()?;
+                finger.long_pressed = true;
+
+                Some(Gesture::LongPress(finger.position))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Creates a new [`GestureArea`] reporting the gestures made on `content`.
+pub fn gesture_area<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    on_gesture: impl Fn(Gesture) -> Message + 'a,
+) -> GestureArea<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    GestureArea::new(content, on_gesture)
+}
+
+/// A transparent wrapper reporting the gestures of fingers that went down on it.
+///
+/// The content still gets every touch event.
+#[allow(missing_debug_implementations)]
+pub struct GestureArea<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+    on_gesture: Box<dyn Fn(Gesture) -> Message + 'a>,
+}
+
+impl<'a, Message, Theme, Renderer> GestureArea<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    /// Creates a new [`GestureArea`] reporting the gestures made on `content`.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        on_gesture: impl Fn(Gesture) -> Message + 'a,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            on_gesture: Box::new(on_gesture),
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for GestureArea<'_, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<Recognizer>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(Recognizer::new())
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn layout(&mut self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        self.content
+            .as_widget_mut()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        // Fingers going down elsewhere are not ours
+        if let Event::Touch(touch::Event::FingerPressed { position, .. }) = event
+            && !layout.bounds().contains(*position)
+        {
+            return;
+        }
+
+        let recognizer = tree.state.downcast_mut::<Recognizer>();
+
+        if let Some(gesture) = recognizer.update(event) {
+            shell.publish((self.on_gesture)(gesture));
+        }
+
+        if let Some(deadline) = recognizer.deadline() {
+            shell.request_redraw_at(deadline);
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content
+            .as_widget()
+            .draw(&tree.children[0], renderer, theme, style, layout, cursor, viewport);
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content
+            .as_widget()
+            .mouse_interaction(&tree.children[0], layout, cursor, viewport, renderer)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn widget::Operation,
+    ) {
+        self.content
+            .as_widget_mut()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content
+            .as_widget_mut()
+            .overlay(&mut tree.children[0], layout, renderer, viewport, translation)
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<GestureArea<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(area: GestureArea<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(area)
+    }
+}
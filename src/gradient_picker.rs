@@ -0,0 +1,825 @@
+//! An inline editor for linear gradients.
+//!
+//! A [`GradientPicker`] shows the gradient on a bar with a handle for each color stop. Handles
+//! are dragged along the bar to move their stop, or dragged off it to remove one; clicking the
+//! bar elsewhere adds a stop with the color already there. Below the bar each stop has a
+//! [`ColorButton`](crate::color_picker::ColorButton) to change its color, and a dial beside it sets the angle, snapping to 15°
+//! steps with Shift held or the mouse wheel.
+//!
+//! The edited value is a [`Gradient`], produced through `on_change` on every edit for the app
+//! to store and turn into a [`Background`] wherever it is used.
+
+use std::rc::Rc;
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        mouse, overlay, renderer,
+        text::{self, Renderer as _},
+        widget::{self, tree::Tree, Operation},
+        Clipboard, Layout, Renderer as _, Shell, Widget,
+    },
+    alignment, gradient::{self, ColorStop}, keyboard, Background, Border, Color, Element, Event, Length, Pixels, Point,
+    Radians, Rectangle, Renderer, Shadow, Size, Vector,
+};
+
+use crate::color_picker::color_button;
+use crate::pixel;
+
+const BAR_HEIGHT: f32 = 24.0;
+const HANDLE_WIDTH: f32 = 12.0;
+const HANDLE_OVERHANG: f32 = 4.0;
+const SPACING: f32 = 8.0;
+const BUTTON_SPACING: f32 = 6.0;
+const DIAL_SIZE: f32 = 56.0;
+const CHECKER_SIZE: f32 = 6.0;
+/// How far a handle is dragged off the bar to remove its stop
+const REMOVE_DISTANCE: f32 = 32.0;
+/// The most stops an iced gradient holds
+const MAX_STOPS: usize = 8;
+const ANGLE_STEP: f32 = std::f32::consts::PI / 12.0;
+
+/// Creates a new [`GradientPicker`] editing `gradient`, producing `on_change` on every edit.
+pub fn gradient_picker<'a, Message>(
+    gradient: &Gradient,
+    on_change: impl Fn(Gradient) -> Message + 'a,
+) -> GradientPicker<'a, Message>
+where
+    Message: 'a,
+{
+    GradientPicker::new(gradient, on_change)
+}
+
+/// A linear gradient, as edited by a [`GradientPicker`].
+///
+/// Stops keep the order they were added in, so a dragged stop keeps its index; they are sorted
+/// by offset when turned into an iced gradient.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Gradient {
+    /// Direction of the gradient, clockwise from pointing up
+    #[cfg_attr(feature = "serde", serde(with = "crate::persist::Radians"))]
+    pub angle: Radians,
+    /// The color stops, in any order
+    #[cfg_attr(feature = "serde", serde(with = "crate::persist::color_stops"))]
+    pub stops: Vec<ColorStop>,
+}
+
+impl Gradient {
+    /// Creates a new [`Gradient`] without stops, pointing at `angle`.
+    pub fn new(angle: impl Into<Radians>) -> Self {
+        Self {
+            angle: angle.into(),
+            stops: Vec::new(),
+        }
+    }
+
+    /// Adds a stop of `color` at `offset`, between 0 and 1.
+    pub fn add_stop(mut self, offset: f32, color: Color) -> Self {
+        self.stops.push(ColorStop {
+            offset: offset.clamp(0.0, 1.0),
+            color,
+        });
+        self
+    }
+
+    /// The stops sorted by offset.
+    pub fn sorted_stops(&self) -> Vec<ColorStop> {
+        let mut stops = self.stops.clone();
+        stops.sort_by(|a, b| a.offset.total_cmp(&b.offset));
+        stops
+    }
+
+    /// The color of the gradient at `offset`, between 0 and 1.
+    pub fn color_at(&self, offset: f32) -> Color {
+        let stops = self.sorted_stops();
+        let (Some(first), Some(last)) = (stops.first(), stops.last()) else {
+            return Color::TRANSPARENT;
+        };
+
+        if offset <= first.offset {
+            return first.color;
+        }
+
+        stops
+            .windows(2)
+            .find(|pair| offset <= pair[1].offset)
+            .map_or(last.color, |pair| {
+                let span = pair[1].offset - pair[0].offset;
+                let amount = if span > 0.0 { (offset - pair[0].offset) / span } else { 1.0 };
+
+                mix(pair[0].color, pair[1].color, amount)
+            })
+    }
+
+    /// The iced gradient, with stops past the eighth left out.
+    pub fn to_linear(&self) -> gradient::Linear {
+        self.with_angle(self.angle)
+    }
+
+    /// The same stops pointing at `angle`.
+    fn with_angle(&self, angle: Radians) -> gradient::Linear {
+        gradient::Linear::new(angle).add_stops(self.sorted_stops())
+    }
+}
+
+impl Default for Gradient {
+    fn default() -> Self {
+        Self::new(Radians(std::f32::consts::FRAC_PI_2))
+            .add_stop(0.0, Color::BLACK)
+            .add_stop(1.0, Color::WHITE)
+    }
+}
+
+impl From<&Gradient> for iced::Gradient {
+    fn from(gradient: &Gradient) -> Self {
+        gradient.to_linear().into()
+    }
+}
+
+impl From<&Gradient> for Background {
+    fn from(gradient: &Gradient) -> Self {
+        Background::Gradient(gradient.into())
+    }
+}
+
+fn mix(a: Color, b: Color, amount: f32) -> Color {
+    Color {
+        r: a.r + (b.r - a.r) * amount,
+        g: a.g + (b.g - a.g) * amount,
+        b: a.b + (b.b - a.b) * amount,
+        a: a.a + (b.a - a.a) * amount,
+    }
+}
+
+/// An inline editor for a linear [`Gradient`].
+#[allow(missing_debug_implementations)]
+pub struct GradientPicker<'a, Message, Theme = iced::Theme>
+where
+    Theme: Catalog,
+{
+    gradient: Gradient,
+    on_change: Rc<dyn Fn(Gradient) -> Message + 'a>,
+    buttons: Vec<Element<'a, Message, iced::Theme, Renderer>>,
+    angle_control: bool,
+    width: Length,
+    text_size: f32,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message> GradientPicker<'a, Message>
+where
+    Message: 'a,
+{
+    /// Creates a new [`GradientPicker`] editing `gradient`, producing `on_change` on every edit.
+    pub fn new(gradient: &Gradient, on_change: impl Fn(Gradient) -> Message + 'a) -> Self {
+        let on_change: Rc<dyn Fn(Gradient) -> Message + 'a> = Rc::new(on_change);
+
+        let buttons = gradient
+            .stops
+            .iter()
+            .enumerate()
+            .map(|(index, stop)| {
+                let on_change = on_change.clone();
+                let gradient = gradient.clone();
+
+                color_button(stop.color)
+                    .title(format!("Stop {}", index + 1))
+                    .width(28)
+                    .height(20)
+                    .on_change(move |color| {
+                        let mut gradient = gradient.clone();
+                        gradient.stops[index].color = color;
+                        on_change(gradient)
+                    })
+                    .into()
+            })
+            .collect();
+
+        Self {
+            gradient: gradient.clone(),
+            on_change,
+            buttons,
+            angle_control: true,
+            width: Length::Fill,
+            text_size: 12.0,
+            class: <iced::Theme as Catalog>::default(),
+        }
+    }
+}
+
+impl<'a, Message, Theme> GradientPicker<'a, Message, Theme>
+where
+    Theme: Catalog,
+{
+    /// Sets whether the angle dial is shown, which it is by default.
+    pub fn angle_control(mut self, shown: bool) -> Self {
+        self.angle_control = shown;
+        self
+    }
+
+    /// Sets the width of the picker.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the text size of the angle in the dial.
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = size.into().0;
+        self
+    }
+
+    /// Sets the style.
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class.
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// The bar and the dial within `bounds`.
+    fn regions(&self, bounds: Rectangle) -> (Rectangle, Option<Rectangle>) {
+        let dial_room = if self.angle_control { DIAL_SIZE + SPACING } else { 0.0 };
+        let bar = Rectangle::new(
+            Point::new(bounds.x + HANDLE_WIDTH / 2.0, bounds.y + HANDLE_OVERHANG),
+            Size::new((bounds.width - HANDLE_WIDTH - dial_room).max(0.0), BAR_HEIGHT),
+        );
+        let dial = self.angle_control.then(|| {
+            Rectangle::new(
+                Point::new(
+                    bounds.x + bounds.width - DIAL_SIZE,
+                    bounds.y + ((bounds.height - DIAL_SIZE) / 2.0).max(0.0),
+                ),
+                Size::new(DIAL_SIZE, DIAL_SIZE),
+            )
+        });
+
+        (bar, dial)
+    }
+
+    fn handle(bar: Rectangle, offset: f32) -> Rectangle {
+        Rectangle::new(
+            Point::new(bar.x + offset * bar.width - HANDLE_WIDTH / 2.0, bar.y - HANDLE_OVERHANG),
+            Size::new(HANDLE_WIDTH, BAR_HEIGHT + HANDLE_OVERHANG * 2.0),
+        )
+    }
+
+    /// The stop whose handle is closest to `position`, if it is over one.
+    fn handle_at(&self, bar: Rectangle, position: Point) -> Option<usize> {
+        self.gradient
+            .stops
+            .iter()
+            .enumerate()
+            .filter(|(_, stop)| Self::handle(bar, stop.offset).contains(position))
+            .min_by(|(_, a), (_, b)| {
+                let a = (bar.x + a.offset * bar.width - position.x).abs();
+                let b = (bar.x + b.offset * bar.width - position.x).abs();
+                a.total_cmp(&b)
+            })
+            .map(|(index, _)| index)
+    }
+
+    fn offset(bar: Rectangle, x: f32) -> f32 {
+        if bar.width > 0.0 {
+            ((x - bar.x) / bar.width).clamp(0.0, 1.0)
+        } else {
+            0.0
+        }
+    }
+
+    /// The angle pointing from the center of `dial` to `position`, clockwise from up.
+    fn angle(dial: Rectangle, position: Point, snap: bool) -> Radians {
+        let direction = position - dial.center();
+        let angle = (direction.y.atan2(direction.x) + std::f32::consts::FRAC_PI_2).rem_euclid(std::f32::consts::TAU);
+        let step = if snap { ANGLE_STEP } else { 1f32.to_radians() };
+
+        Radians(((angle / step).round() * step).rem_euclid(std::f32::consts::TAU))
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    drag: Option<Drag>,
+    modifiers: keyboard::Modifiers,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Drag {
+    Stop(usize),
+    Angle,
+}
+
+impl<'a, Message> Widget<Message, iced::Theme, Renderer> for GradientPicker<'a, Message>
+where
+    Message: 'a,
+{
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.buttons.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.buttons);
+    }
+
+    fn layout(&mut self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let width = limits.width(self.width).max().width;
+        let width = if width.is_finite() { width } else { 320.0 };
+
+        // The color buttons follow their stops along the bar, left to right
+        let mut order: Vec<usize> = (0..self.buttons.len()).collect();
+        order.sort_by(|a, b| {
+            self.gradient.stops[*a]
+                .offset
+                .total_cmp(&self.gradient.stops[*b].offset)
+        });
+
+        let top = BAR_HEIGHT + HANDLE_OVERHANG * 2.0 + SPACING;
+        let mut nodes: Vec<Node> = self
+            .buttons
+            .iter_mut()
+            .zip(&mut tree.children)
+            .map(|(button, tree)| {
+                button
+                    .as_widget_mut()
+                    .layout(tree, renderer, &Limits::new(Size::ZERO, Size::new(width, f32::INFINITY)))
+            })
+            .collect();
+
+        let mut x = 0.0;
+        let mut row_height: f32 = 0.0;
+        for index in order {
+            let size = nodes[index].size();
+            nodes[index] = nodes[index].clone().move_to(Point::new(x, top));
+            x += size.width + BUTTON_SPACING;
+            row_height = row_height.max(size.height);
+        }
+
+        let dial = if self.angle_control { DIAL_SIZE } else { 0.0 };
+        let height = (top + row_height).max(dial);
+
+        Node::with_children(limits.resolve(self.width, Length::Shrink, Size::new(width, height)), nodes)
+    }
+
+    fn operate(&mut self, tree: &mut Tree, layout: Layout<'_>, renderer: &Renderer, operation: &mut dyn Operation) {
+        operation.container(None, layout.bounds());
+        operation.traverse(&mut |operation| {
+            for ((button, tree), layout) in self.buttons.iter_mut().zip(&mut tree.children).zip(layout.children()) {
+                button.as_widget_mut().operate(tree, layout, renderer, operation);
+            }
+        });
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        for ((button, tree), layout) in self.buttons.iter_mut().zip(&mut tree.children).zip(layout.children()) {
+            button
+                .as_widget_mut()
+                .update(tree, event, layout, cursor, renderer, clipboard, shell, viewport);
+        }
+
+        if shell.is_event_captured() {
+            return;
+        }
+
+        let state = tree.state.downcast_mut::<State>();
+        let (bar, dial) = self.regions(layout.bounds());
+
+        match event {
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.modifiers = *modifiers;
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let Some(position) = cursor.position() else {
+                    return;
+                };
+
+                if let Some(index) = self.handle_at(bar, position) {
+                    state.drag = Some(Drag::Stop(index));
+                    shell.capture_event();
+                } else if bar.contains(position) && self.gradient.stops.len() < MAX_STOPS {
+                    let offset = Self::offset(bar, position.x);
+                    let color = self.gradient.color_at(offset);
+
+                    state.drag = Some(Drag::Stop(self.gradient.stops.len()));
+                    shell.publish((self.on_change)(self.gradient.clone().add_stop(offset, color)));
+                    shell.capture_event();
+                } else if let Some(dial) = dial
+                    && cursor.is_over(dial)
+                {
+                    let angle = Self::angle(dial, position, state.modifiers.shift());
+
+                    state.drag = Some(Drag::Angle);
+                    if angle != self.gradient.angle {
+                        shell.publish((self.on_change)(Gradient {
+                            angle,
+                            ..self.gradient.clone()
+                        }));
+                    }
+                    shell.capture_event();
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position }) => match state.drag {
+                Some(Drag::Stop(index)) => {
+                    let Some(stop) = self.gradient.stops.get(index) else {
+                        return;
+                    };
+
+                    let off_bar = (position.y - bar.center_y()).abs() > BAR_HEIGHT / 2.0 + REMOVE_DISTANCE;
+                    if off_bar && self.gradient.stops.len() > 2 {
+                        let mut gradient = self.gradient.clone();
+                        gradient.stops.remove(index);
+
+                        state.drag = None;
+                        shell.publish((self.on_change)(gradient));
+                    } else {
+                        let offset = Self::offset(bar, position.x);
+
+                        if offset != stop.offset {
+                            let mut gradient = self.gradient.clone();
+                            gradient.stops[index].offset = offset;
+
+                            shell.publish((self.on_change)(gradient));
+                        }
+                    }
+                    shell.capture_event();
+                }
+                Some(Drag::Angle) => {
+                    if let Some(dial) = dial {
+                        let angle = Self::angle(dial, *position, state.modifiers.shift());
+
+                        if angle != self.gradient.angle {
+                            shell.publish((self.on_change)(Gradient {
+                                angle,
+                                ..self.gradient.clone()
+                            }));
+                        }
+                    }
+                    shell.capture_event();
+                }
+                None => {}
+            },
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) if state.drag.take().is_some() => {
+                shell.capture_event();
+                shell.request_redraw();
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+                if let Some(dial) = dial
+                    && cursor.is_over(dial)
+                {
+                    let y = match delta {
+                        mouse::ScrollDelta::Lines { y, .. } => *y,
+                        mouse::ScrollDelta::Pixels { y, .. } => *y / 20.0,
+                    };
+
+                    if y != 0.0 {
+                        let step = (self.gradient.angle.0 / ANGLE_STEP).round() - y.signum();
+                        let angle = Radians((step * ANGLE_STEP).rem_euclid(std::f32::consts::TAU));
+
+                        shell.publish((self.on_change)(Gradient {
+                            angle,
+                            ..self.gradient.clone()
+                        }));
+                    }
+                    shell.capture_event();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &iced::Theme,
+        defaults: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let style = Catalog::style(theme, &self.class);
+        let (bar, dial) = self.regions(layout.bounds());
+
+        // A checkerboard behind the bar shows through transparent stops
+        let mut y = 0;
+        while (y as f32) * CHECKER_SIZE < bar.height {
+            let mut x = 0;
+            while (x as f32) * CHECKER_SIZE < bar.width {
+                let square = Rectangle::new(
+                    Point::new(bar.x + x as f32 * CHECKER_SIZE, bar.y + y as f32 * CHECKER_SIZE),
+                    Size::new(
+                        CHECKER_SIZE.min(bar.width - x as f32 * CHECKER_SIZE),
+                        CHECKER_SIZE.min(bar.height - y as f32 * CHECKER_SIZE),
+                    ),
+                );
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: pixel::snap(square),
+                        ..renderer::Quad::default()
+                    },
+                    style.checker[(x + y) % 2],
+                );
+                x += 1;
+            }
+            y += 1;
+        }
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: pixel::snap(bar),
+                border: pixel::border(Border {
+                    color: style.bar_border,
+                    width: 1.0,
+                    radius: 0.0.into(),
+                }),
+                ..renderer::Quad::default()
+            },
+            Background::Gradient(self.gradient.with_angle(Radians(std::f32::consts::FRAC_PI_2)).into()),
+        );
+
+        let hovered = cursor.position().and_then(|position| self.handle_at(bar, position));
+        for (index, stop) in self.gradient.stops.iter().enumerate() {
+            let active = state.drag == Some(Drag::Stop(index)) || (state.drag.is_none() && hovered == Some(index));
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: pixel::snap(Self::handle(bar, stop.offset)),
+                    border: pixel::border(Border {
+                        color: if active { style.handle_active } else { style.handle },
+                        width: 2.0,
+                        radius: 3.0.into(),
+                    }),
+                    shadow: style.handle_shadow,
+                    ..renderer::Quad::default()
+                },
+                Color { a: 1.0, ..stop.color },
+            );
+        }
+
+        if let Some(dial) = dial {
+            let radius = DIAL_SIZE / 2.0;
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: pixel::snap(dial),
+                    border: pixel::border(Border {
+                        color: style.dial_border,
+                        width: 1.0,
+                        radius: radius.into(),
+                    }),
+                    ..renderer::Quad::default()
+                },
+                &self.gradient,
+            );
+
+            let inner = DIAL_SIZE * 0.55;
+            let center = dial.center();
+            let inner_bounds = Rectangle::new(
+                Point::new(center.x - inner / 2.0, center.y - inner / 2.0),
+                Size::new(inner, inner),
+            );
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: pixel::snap(inner_bounds),
+                    border: pixel::border(Border {
+                        radius: (inner / 2.0).into(),
+                        ..Border::default()
+                    }),
+                    ..renderer::Quad::default()
+                },
+                style.dial_background,
+            );
+
+            renderer.fill_text(
+                iced::advanced::Text {
+                    content: format!("{:.0}°", self.gradient.angle.0.to_degrees()),
+                    bounds: inner_bounds.size(),
+                    size: Pixels(self.text_size),
+                    font: renderer.default_font(),
+                    align_x: text::Alignment::Center,
+                    align_y: alignment::Vertical::Center,
+                    line_height: text::LineHeight::default(),
+                    shaping: text::Shaping::Basic,
+                    wrapping: text::Wrapping::None,
+                },
+                center,
+                style.text,
+                dial,
+            );
+
+            let knob = 10.0;
+            let angle = self.gradient.angle.0 - std::f32::consts::FRAC_PI_2;
+            let rim = radius - (DIAL_SIZE - inner) / 4.0;
+            let knob_center = center + Vector::new(angle.cos(), angle.sin()) * rim;
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: pixel::snap(Rectangle::new(
+                        Point::new(knob_center.x - knob / 2.0, knob_center.y - knob / 2.0),
+                        Size::new(knob, knob),
+                    )),
+                    border: pixel::border(Border {
+                        color: style.handle,
+                        width: 2.0,
+                        radius: (knob / 2.0).into(),
+                    }),
+                    shadow: style.handle_shadow,
+                    ..renderer::Quad::default()
+                },
+                style.knob,
+            );
+        }
+
+        for ((button, tree), layout) in self.buttons.iter().zip(&tree.children).zip(layout.children()) {
+            button
+                .as_widget()
+                .draw(tree, renderer, theme, defaults, layout, cursor, viewport);
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let (bar, dial) = self.regions(layout.bounds());
+
+        if state.drag.is_some() {
+            return mouse::Interaction::Grabbing;
+        }
+
+        if let Some(position) = cursor.position() {
+            if self.handle_at(bar, position).is_some() {
+                return mouse::Interaction::Grab;
+            }
+            if bar.contains(position) && self.gradient.stops.len() < MAX_STOPS {
+                return mouse::Interaction::Crosshair;
+            }
+            if dial.is_some_and(|dial| dial.contains(position)) {
+                return mouse::Interaction::Pointer;
+            }
+        }
+
+        self.buttons
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((button, tree), layout)| {
+                button
+                    .as_widget()
+                    .mouse_interaction(tree, layout, cursor, viewport, renderer)
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, iced::Theme, Renderer>> {
+        let overlays: Vec<_> = self
+            .buttons
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .filter_map(|((button, tree), layout)| {
+                button
+                    .as_widget_mut()
+                    .overlay(tree, layout, renderer, viewport, translation)
+            })
+            .collect();
+
+        (!overlays.is_empty()).then(|| overlay::Group::with_children(overlays).overlay())
+    }
+}
+
+impl<'a, Message> From<GradientPicker<'a, Message>> for Element<'a, Message, iced::Theme, Renderer>
+where
+    Message: 'a,
+{
+    fn from(picker: GradientPicker<'a, Message>) -> Self {
+        Element::new(picker)
+    }
+}
+
+/// The theme catalog of a [`GradientPicker`].
+pub trait Catalog {
+    /// The style class
+    type Class<'a>;
+
+    /// Default style
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// Get the style for a class
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// The appearance of a [`GradientPicker`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The two colors of the checkerboard behind the bar
+    pub checker: [Color; 2],
+    /// Border of the bar
+    pub bar_border: Color,
+    /// Outline of the stop handles and the dial knob
+    pub handle: Color,
+    /// Outline of a hovered or dragged handle
+    pub handle_active: Color,
+    /// Shadow under the handles and the knob
+    pub handle_shadow: Shadow,
+    /// Middle of the dial, behind the angle
+    pub dial_background: Color,
+    /// Border of the dial
+    pub dial_border: Color,
+    /// Fill of the dial knob
+    pub knob: Color,
+    /// Color of the angle
+    pub text: Color,
+}
+
+/// Styling function
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for iced::Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default gradient picker style.
+pub fn default(theme: &iced::Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        checker: [Color::from_rgb(0.8, 0.8, 0.8), Color::from_rgb(0.6, 0.6, 0.6)],
+        bar_border: palette.background.strong.color,
+        handle: Color::WHITE,
+        handle_active: palette.primary.strong.color,
+        handle_shadow: Shadow {
+            color: Color::BLACK.scale_alpha(0.4),
+            offset: Vector::new(0.0, 1.0),
+            blur_radius: 3.0,
+        },
+        dial_background: palette.background.base.color,
+        dial_border: palette.background.strong.color,
+        knob: palette.primary.base.color,
+        text: palette.background.base.text,
+    }
+}
+
+/// Dark handles, for light gradients.
+pub fn contrast(theme: &iced::Theme) -> Style {
+    Style {
+        handle: Color::from_rgb(0.1, 0.1, 0.1),
+        ..default(theme)
+    }
+}
@@ -0,0 +1,285 @@
+//! Keyboard shortcuts declared in one place.
+//!
+//! A [`Keymap`] lists which [`Shortcut`] triggers which message. Wrapping a view in
+//! [`hotkeys`] publishes the message when its shortcut is pressed and no widget inside used
+//! the key press, so a focused text input still gets its letters.
+//!
+//! The same keymap reports shortcuts bound more than once with [`Keymap::conflicts`] and
+//! gives the hints shown in menus: menu bars, context menus and split buttons take it
+//! through their `hotkeys` method and show each entry's shortcut next to it.
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        mouse, overlay, renderer,
+        widget::{self, tree::Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    keyboard, Element, Event, Length, Rectangle, Size, Vector,
+};
+
+pub use crate::shortcut_input::Shortcut;
+
+/// Shortcuts and the ids they trigger, usually the app's messages.
+#[derive(Debug, Clone)]
+pub struct Keymap<T> {
+    bindings: Vec<(Shortcut, T)>,
+}
+
+impl<T> Default for Keymap<T> {
+    fn default() -> Self {
+        Self { bindings: Vec::new() }
+    }
+}
+
+impl<T> Keymap<T> {
+    /// Creates an empty [`Keymap`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Binds the shortcut written as `shortcut`, such as "Ctrl+S" or "Mod+Shift+Z", to `id`.
+    ///
+    /// # Panics
+    ///
+    /// If `shortcut` cannot be parsed by [`Shortcut::parse`].
+    #[must_use]
+    pub fn bind(self, shortcut: &str, id: T) -> Self {
+        let parsed = Shortcut::parse(shortcut).unwrap_or_else(|| panic!("invalid shortcut `{shortcut}`"));
+
+        self.bind_shortcut(parsed, id)
+    }
+
+    /// Binds `shortcut` to `id`, such as one recorded by a shortcut input.
+    #[must_use]
+    pub fn bind_shortcut(mut self, shortcut: Shortcut, id: T) -> Self {
+        self.bindings.push((shortcut, id));
+        self
+    }
+
+    /// The id triggered by pressing `key` with `modifiers` held.
+    ///
+    /// When a shortcut is bound more than once, the first binding wins.
+    pub fn get(&self, key: &keyboard::Key, modifiers: keyboard::Modifiers) -> Option<&T> {
+        self.bindings
+            .iter()
+            .find(|(shortcut, _)| shortcut.matches(key, modifiers))
+            .map(|(_, id)| id)
+    }
+
+    /// The shortcut bound to `id`, the first one if there are several.
+    pub fn shortcut(&self, id: &T) -> Option<&Shortcut>
+    where
+        T: PartialEq,
+    {
+        self.bindings
+            .iter()
+            .find(|(_, bound)| bound == id)
+            .map(|(shortcut, _)| shortcut)
+    }
+
+    /// The hint shown for `id`, such as "Ctrl+S".
+    pub fn hint(&self, id: &T) -> Option<String>
+    where
+        T: PartialEq,
+    {
+        self.shortcut(id).map(Shortcut::to_string)
+    }
+
+    /// Every binding, in the order they were added.
+    pub fn iter(&self) -> impl Iterator<Item = (&Shortcut, &T)> {
+        self.bindings.iter().map(|(shortcut, id)| (shortcut, id))
+    }
+
+    /// The shortcuts bound to more than one id, in the order they were first bound.
+    pub fn conflicts(&self) -> Vec<Conflict<'_, T>> {
+        let mut conflicts: Vec<Conflict<'_, T>> = Vec::new();
+
+        for (index, (shortcut, id)) in self.bindings.iter().enumerate() {
+            if let Some(conflict) = conflicts.iter_mut().find(|conflict| conflict.shortcut == shortcut) {
+                conflict.ids.push(id);
+            } else if self.bindings[index + 1..].iter().any(|(other, _)| other == shortcut) {
+                conflicts.push(Conflict { shortcut, ids: vec![id] });
+            }
+        }
+
+        conflicts
+    }
+}
+
+/// A shortcut bound to more than one id.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Conflict<'a, T> {
+    /// The shortcut
+    pub shortcut: &'a Shortcut,
+    /// Every id bound to it, the first being the one it triggers
+    pub ids: Vec<&'a T>,
+}
+
+/// Creates a new [`Hotkeys`] publishing the messages of `keymap` over `content`.
+pub fn hotkeys<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    keymap: &'a Keymap<Message>,
+) -> Hotkeys<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: renderer::Renderer,
+{
+    Hotkeys::new(content, keymap)
+}
+
+/// A transparent wrapper publishing a message when one of its shortcuts is pressed.
+#[allow(missing_debug_implementations)]
+pub struct Hotkeys<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+    keymap: &'a Keymap<Message>,
+    enabled: bool,
+}
+
+impl<'a, Message, Theme, Renderer> Hotkeys<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: renderer::Renderer,
+{
+    /// Creates a new [`Hotkeys`] publishing the messages of `keymap` over `content`.
+    pub fn new(content: impl Into<Element<'a, Message, Theme, Renderer>>, keymap: &'a Keymap<Message>) -> Self {
+        Self {
+            content: content.into(),
+            keymap,
+            enabled: true,
+        }
+    }
+
+    /// Sets whether the shortcuts are active, such as while a modal dialog is open.
+    #[must_use]
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Hotkeys<'_, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: renderer::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn layout(&mut self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        self.content
+            .as_widget_mut()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        if !self.enabled || shell.is_event_captured() {
+            return;
+        }
+
+        if let Event::Keyboard(keyboard::Event::KeyPressed { key, modifiers, .. }) = event
+            && let Some(message) = self.keymap.get(key, *modifiers)
+        {
+            shell.publish(message.clone());
+            shell.capture_event();
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content
+            .as_widget()
+            .draw(&tree.children[0], renderer, theme, style, layout, cursor, viewport);
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content
+            .as_widget()
+            .mouse_interaction(&tree.children[0], layout, cursor, viewport, renderer)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn widget::Operation,
+    ) {
+        self.content
+            .as_widget_mut()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content
+            .as_widget_mut()
+            .overlay(&mut tree.children[0], layout, renderer, viewport, translation)
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Hotkeys<'a, Message, Theme, Renderer>> for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(hotkeys: Hotkeys<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(hotkeys)
+    }
+}
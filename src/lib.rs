@@ -8,4 +8,158 @@ pub mod color_picker;
 pub mod collapsible;
 
 #[cfg(feature = "generic_overlay")]
-pub mod generic_overlay;
\ No newline at end of file
+pub mod generic_overlay;
+
+#[cfg(feature = "table")]
+pub mod table;
+
+#[cfg(feature = "tabs")]
+pub mod tabs;
+
+#[cfg(feature = "toasts")]
+pub mod toasts;
+
+#[cfg(feature = "date_range_picker")]
+pub mod date_range_picker;
+
+#[cfg(feature = "range_slider")]
+pub mod range_slider;
+
+#[cfg(feature = "tag_input")]
+pub mod tag_input;
+
+#[cfg(feature = "stepper")]
+pub mod stepper;
+
+#[cfg(feature = "context_menu")]
+pub mod context_menu;
+
+#[cfg(feature = "menu")]
+pub mod menu;
+
+#[cfg(feature = "fs")]
+pub mod fs_tree;
+
+#[cfg(feature = "font_picker")]
+pub mod font_picker;
+
+#[cfg(feature = "rating")]
+pub mod rating;
+
+#[cfg(feature = "combobox")]
+pub mod combobox;
+
+#[cfg(feature = "tour")]
+pub mod tour;
+
+#[cfg(feature = "property_grid")]
+pub mod property_grid;
+
+#[cfg(feature = "viewport")]
+pub mod viewport;
+
+#[cfg(feature = "node_graph")]
+pub mod node_graph;
+
+#[cfg(feature = "minimap")]
+pub mod minimap;
+
+#[cfg(feature = "sidebar")]
+pub mod sidebar;
+
+#[cfg(feature = "badge")]
+pub mod badge;
+
+#[cfg(feature = "avatar")]
+pub mod avatar;
+
+#[cfg(feature = "progress_ring")]
+pub mod progress_ring;
+
+#[cfg(feature = "skeleton")]
+pub mod skeleton;
+
+#[cfg(feature = "fab")]
+pub mod fab;
+
+#[cfg(feature = "pinboard")]
+pub mod pinboard;
+
+#[cfg(feature = "masonry")]
+pub mod masonry;
+
+#[cfg(feature = "gradient_picker")]
+pub mod gradient_picker;
+
+#[cfg(feature = "shortcut_input")]
+pub mod shortcut_input;
+
+#[cfg(feature = "tree_select")]
+pub mod tree_select;
+
+#[cfg(feature = "validated_input")]
+pub mod validated_input;
+
+#[cfg(feature = "code_view")]
+pub mod code_view;
+
+#[cfg(feature = "drop_zone")]
+pub mod drop_zone;
+
+#[cfg(feature = "chip_bar")]
+pub mod chip_bar;
+
+#[cfg(feature = "split_button")]
+pub mod split_button;
+
+#[cfg(feature = "dnd")]
+pub mod dnd;
+
+#[cfg(feature = "focus")]
+pub mod focus;
+
+#[cfg(feature = "hotkeys")]
+pub mod hotkeys;
+
+#[cfg(feature = "gestures")]
+pub mod gestures;
+
+#[cfg(feature = "direction")]
+pub mod direction;
+
+#[cfg(feature = "theme")]
+pub mod theme;
+
+pub mod pixel;
+
+#[cfg(feature = "debug")]
+pub mod debug;
+
+#[cfg(feature = "profile")]
+pub mod profile;
+#[cfg(all(not(feature = "profile"), any(feature = "tree", feature = "color_picker")))]
+mod profile;
+
+#[cfg(feature = "serde")]
+mod persist;
+
+#[cfg(feature = "testing")]
+pub mod testing;
+
+#[cfg(any(feature = "tree", feature = "collapsible"))]
+mod depth;
+
+#[cfg(any(feature = "collapsible", feature = "tabs"))]
+mod reorder;
+
+#[cfg(any(feature = "tree", feature = "combobox"))]
+mod search;
+
+#[cfg(any(
+    feature = "tree",
+    feature = "collapsible",
+    feature = "generic_overlay",
+    feature = "color_picker",
+    feature = "combobox"
+))]
+mod operation;
@@ -0,0 +1,304 @@
+//! A layout packing children of different heights into columns.
+//!
+//! A [`Masonry`] fits as many columns as it can into the width it has, each at least the minimum
+//! item width, and places every child at the bottom of the shortest column so far. The columns
+//! are worked out again on every layout, so the items reflow as the window is resized.
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        mouse, overlay, renderer,
+        widget::{tree::Tree, Operation},
+        Clipboard, Layout, Shell, Widget,
+    },
+    Element, Event, Length, Padding, Pixels, Point, Rectangle, Size, Vector,
+};
+
+/// Creates a new [`Masonry`] with the given children.
+pub fn masonry<'a, Message, Theme, Renderer>(
+    children: impl IntoIterator<Item = Element<'a, Message, Theme, Renderer>>,
+) -> Masonry<'a, Message, Theme, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    Masonry::with_children(children)
+}
+
+/// Children of different heights packed into as many columns as fit.
+#[allow(missing_debug_implementations)]
+pub struct Masonry<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer> {
+    children: Vec<Element<'a, Message, Theme, Renderer>>,
+    min_item_width: f32,
+    max_columns: Option<usize>,
+    spacing: f32,
+    row_spacing: Option<f32>,
+    padding: Padding,
+    width: Length,
+    height: Length,
+}
+
+impl<'a, Message, Theme, Renderer> Masonry<'a, Message, Theme, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    /// Creates a new empty [`Masonry`].
+    pub fn new() -> Self {
+        Self::with_children(Vec::new())
+    }
+
+    /// Creates a new [`Masonry`] with the given children.
+    pub fn with_children(children: impl IntoIterator<Item = Element<'a, Message, Theme, Renderer>>) -> Self {
+        Self {
+            children: children.into_iter().collect(),
+            min_item_width: 200.0,
+            max_columns: None,
+            spacing: 10.0,
+            row_spacing: None,
+            padding: Padding::ZERO,
+            width: Length::Fill,
+            height: Length::Shrink,
+        }
+    }
+
+    /// Adds a child.
+    pub fn push(mut self, child: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        self.children.push(child.into());
+        self
+    }
+
+    /// Sets the narrowest a column can be, which decides how many fit.
+    pub fn min_item_width(mut self, width: impl Into<Pixels>) -> Self {
+        self.min_item_width = width.into().0.max(1.0);
+        self
+    }
+
+    /// Sets the most columns there can be, however wide the layout gets.
+    pub fn max_columns(mut self, columns: usize) -> Self {
+        self.max_columns = Some(columns.max(1));
+        self
+    }
+
+    /// Sets the space between columns, and between items unless [`row_spacing`](Self::row_spacing) is set.
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets the space between items in a column.
+    pub fn row_spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.row_spacing = Some(spacing.into().0);
+        self
+    }
+
+    /// Sets the padding around the items.
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the width of the layout.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the layout.
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// How many columns fit in `width`, and how wide each one is.
+    fn columns(&self, width: f32) -> (usize, f32) {
+        let fit = ((width + self.spacing) / (self.min_item_width + self.spacing)).floor().max(1.0) as usize;
+        let columns = self
+            .max_columns
+            .map_or(fit, |max| fit.min(max))
+            .min(self.children.len().max(1));
+        let column_width = ((width - self.spacing * (columns - 1) as f32) / columns as f32).max(0.0);
+
+        (columns, column_width)
+    }
+}
+
+impl<Message, Theme, Renderer> Default for Masonry<'_, Message, Theme, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Masonry<'_, Message, Theme, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.children.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.children);
+    }
+
+    fn layout(&mut self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let limits = limits.width(self.width).height(self.height).shrink(self.padding);
+
+        // Without a width to fill, like in a horizontal scrollable, there is one column
+        let available = limits.max().width;
+        let available = if available.is_finite() {
+            available
+        } else {
+            self.min_item_width
+        };
+
+        let (columns, column_width) = self.columns(available);
+        let row_spacing = self.row_spacing.unwrap_or(self.spacing);
+        let mut heights = vec![0.0_f32; columns];
+
+        let children = self
+            .children
+            .iter_mut()
+            .zip(&mut tree.children)
+            .map(|(child, tree)| {
+                let (column, top) = heights
+                    .iter()
+                    .copied()
+                    .enumerate()
+                    .min_by(|(_, a), (_, b)| a.total_cmp(b))
+                    .unwrap_or((0, 0.0));
+
+                let node = child.as_widget_mut().layout(
+                    tree,
+                    renderer,
+                    &Limits::new(Size::new(column_width, 0.0), Size::new(column_width, f32::INFINITY)),
+                );
+                let y = if top > 0.0 { top + row_spacing } else { top };
+                heights[column] = y + node.size().height;
+
+                node.move_to(Point::new(
+                    self.padding.left + column as f32 * (column_width + self.spacing),
+                    self.padding.top + y,
+                ))
+            })
+            .collect();
+
+        let content = Size::new(available, heights.into_iter().fold(0.0, f32::max));
+        let size = limits.resolve(self.width, self.height, content).expand(self.padding);
+
+        Node::with_children(size, children)
+    }
+
+    fn operate(&mut self, tree: &mut Tree, layout: Layout<'_>, renderer: &Renderer, operation: &mut dyn Operation) {
+        operation.container(None, layout.bounds());
+        operation.traverse(&mut |operation| {
+            for ((child, tree), layout) in self.children.iter_mut().zip(&mut tree.children).zip(layout.children()) {
+                child.as_widget_mut().operate(tree, layout, renderer, operation);
+            }
+        });
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        for ((child, tree), layout) in self.children.iter_mut().zip(&mut tree.children).zip(layout.children()) {
+            child
+                .as_widget_mut()
+                .update(tree, event, layout, cursor, renderer, clipboard, shell, viewport);
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        defaults: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let Some(visible) = layout.bounds().intersection(viewport) else {
+            return;
+        };
+
+        for ((child, tree), layout) in self.children.iter().zip(&tree.children).zip(layout.children()) {
+            if layout.bounds().intersects(&visible) {
+                child
+                    .as_widget()
+                    .draw(tree, renderer, theme, defaults, layout, cursor, &visible);
+            }
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((child, tree), layout)| {
+                child
+                    .as_widget()
+                    .mouse_interaction(tree, layout, cursor, viewport, renderer)
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let overlays: Vec<_> = self
+            .children
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .filter_map(|((child, tree), layout)| {
+                child
+                    .as_widget_mut()
+                    .overlay(tree, layout, renderer, viewport, translation)
+            })
+            .collect();
+
+        (!overlays.is_empty()).then(|| overlay::Group::with_children(overlays).overlay())
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Masonry<'a, Message, Theme, Renderer>> for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(masonry: Masonry<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(masonry)
+    }
+}
@@ -0,0 +1,1277 @@
+//! Structured menus: a model of entries and the widgets rendering it.
+//!
+//! An [`Item`] is an action, a separator or a submenu, and actions can carry an icon, a
+//! check mark or radio dot and a shortcut hint. Picking an action reports its id, any
+//! `Clone` value such as an enum of commands.
+//!
+//! The same model drives the [`MenuBar`] here and the right-click menus of
+//! [`context_menu`](crate::context_menu) when that feature is enabled. Open menus are used
+//! with the mouse or the keyboard: Up and Down move, Right and Enter open a submenu, Left
+//! closes it, Enter picks an action and Escape closes the menu. In a menu bar, Left and
+//! Right on the first level move to the neighbouring menu.
+//!
+//! Right to left, see [`direction`](crate::direction), menus open from the right edge of
+//! their title or button, submenus open to the left with the Left and Right keys swapped,
+//! and entries put their icon and label on the right and their shortcut on the left.
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        mouse, overlay, renderer, text,
+        widget::{self, tree::Tree},
+        Clipboard, Layout, Overlay, Shell, Widget,
+    },
+    alignment, keyboard, Background, Border, Color, Element, Event, Length, Padding, Pixels,
+    Point, Rectangle, Shadow, Size, Vector,
+};
+
+use crate::direction::{self, Direction};
+use crate::pixel;
+
+const ITEM_HEIGHT: f32 = 28.0;
+const SEPARATOR_HEIGHT: f32 = 9.0;
+const MENU_PADDING: f32 = 4.0;
+const ITEM_PADDING: f32 = 12.0;
+const GUTTER_WIDTH: f32 = 20.0;
+const SHORTCUT_SPACING: f32 = 24.0;
+const ARROW_WIDTH: f32 = 16.0;
+const MIN_MENU_WIDTH: f32 = 160.0;
+
+/// An entry of a menu, reporting `T` when picked.
+#[derive(Debug, Clone)]
+pub struct Item<T> {
+    kind: Kind<T>,
+    icon: Option<String>,
+    shortcut: Option<String>,
+    mark: Mark,
+    disabled: bool,
+}
+
+#[derive(Debug, Clone)]
+enum Kind<T> {
+    Action { label: String, id: T },
+    Submenu { label: String, items: Vec<Item<T>> },
+    Separator,
+}
+
+/// The check mark or radio dot in front of an action.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Mark {
+    None,
+    Check(bool),
+    Radio(bool),
+}
+
+impl<T> Item<T> {
+    /// An action reporting `id` when picked.
+    pub fn new(label: impl Into<String>, id: T) -> Self {
+        Self::with_kind(Kind::Action {
+            label: label.into(),
+            id,
+        })
+    }
+
+    /// An entry opening a nested menu.
+    pub fn submenu(label: impl Into<String>, items: impl IntoIterator<Item = Item<T>>) -> Self {
+        Self::with_kind(Kind::Submenu {
+            label: label.into(),
+            items: items.into_iter().collect(),
+        })
+    }
+
+    /// A line between groups of entries.
+    pub fn separator() -> Self {
+        Self {
+            disabled: true,
+            ..Self::with_kind(Kind::Separator)
+        }
+    }
+
+    /// A set of mutually exclusive actions, the one whose id equals `selected` carrying a dot.
+    pub fn radio_group(
+        options: impl IntoIterator<Item = (impl Into<String>, T)>,
+        selected: &T,
+    ) -> Vec<Self>
+    where
+        T: PartialEq,
+    {
+        options
+            .into_iter()
+            .map(|(label, id)| {
+                let is_selected = id == *selected;
+                Self::new(label, id).radio(is_selected)
+            })
+            .collect()
+    }
+
+    fn with_kind(kind: Kind<T>) -> Self {
+        Self {
+            kind,
+            icon: None,
+            shortcut: None,
+            mark: Mark::None,
+            disabled: false,
+        }
+    }
+
+    /// Shows a glyph, such as an emoji or an icon font character, before the label.
+    pub fn icon(mut self, icon: impl Into<String>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Shows a shortcut hint such as "Ctrl+S" at the end of the entry.
+    ///
+    /// The hint is only drawn, binding the keys is up to the app.
+    pub fn shortcut(mut self, shortcut: impl Into<String>) -> Self {
+        self.shortcut = Some(shortcut.into());
+        self
+    }
+
+    /// Makes the entry a toggle, drawing a check mark while `checked`.
+    pub fn checked(mut self, checked: bool) -> Self {
+        self.mark = Mark::Check(checked);
+        self
+    }
+
+    /// Makes the entry part of a radio group, drawing a dot while `selected`.
+    pub fn radio(mut self, selected: bool) -> Self {
+        self.mark = Mark::Radio(selected);
+        self
+    }
+
+    /// Greys the entry out; it cannot be picked or opened.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        if !matches!(self.kind, Kind::Separator) {
+            self.disabled = disabled;
+        }
+        self
+    }
+
+    /// Shows the shortcut `keymap` binds to this action, and to those of a submenu, unless
+    /// the entry already has a hint.
+    #[cfg(feature = "hotkeys")]
+    pub fn hotkeys(mut self, keymap: &crate::hotkeys::Keymap<T>) -> Self
+    where
+        T: PartialEq,
+    {
+        match &mut self.kind {
+            Kind::Action { id, .. } => {
+                if self.shortcut.is_none() {
+                    self.shortcut = keymap.hint(id);
+                }
+            }
+            Kind::Submenu { items, .. } => {
+                *items = std::mem::take(items).into_iter().map(|item| item.hotkeys(keymap)).collect();
+            }
+            Kind::Separator => {}
+        }
+        self
+    }
+
+    fn label(&self) -> &str {
+        match &self.kind {
+            Kind::Action { label, .. } | Kind::Submenu { label, .. } => label,
+            Kind::Separator => "",
+        }
+    }
+
+    fn children(&self) -> Option<&[Item<T>]> {
+        match &self.kind {
+            Kind::Submenu { items, .. } if !self.disabled && !items.is_empty() => Some(items),
+            _ => None,
+        }
+    }
+
+    fn height(&self) -> f32 {
+        match self.kind {
+            Kind::Separator => SEPARATOR_HEIGHT,
+            _ => ITEM_HEIGHT,
+        }
+    }
+
+    fn is_selectable(&self) -> bool {
+        !self.disabled
+    }
+
+    fn has_gutter(&self) -> bool {
+        self.icon.is_some() || self.mark != Mark::None
+    }
+}
+
+/// The first selectable entry from `start`, stepping by `direction` and wrapping around.
+fn first_selectable<T>(items: &[Item<T>], start: usize, direction: isize) -> Option<usize> {
+    let len = items.len() as isize;
+
+    (0..len)
+        .map(|offset| (start as isize + offset * direction).rem_euclid(len) as usize)
+        .find(|&index| items[index].is_selectable())
+}
+
+/// Which submenus are open and which entry is highlighted.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct Session {
+    /// Index of the open submenu entry on each level
+    path: Vec<usize>,
+    /// Highlighted entry of the deepest open level
+    highlighted: Option<usize>,
+}
+
+/// Where the first level of a menu opens.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Origin {
+    /// At a point, such as the cursor
+    #[cfg(feature = "context_menu")]
+    Point(Point),
+    /// Below a rectangle, such as a menu bar title
+    Below(Rectangle),
+}
+
+/// What an event did to an open menu.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Outcome {
+    None,
+    Handled,
+    /// An action was picked or Escape pressed on the first level
+    Close,
+    /// A press outside every panel
+    Outside(mouse::Button),
+    /// Left on the first level
+    Previous,
+    /// Right on an entry without a submenu
+    Next,
+}
+
+/// An open menu and its open submenus, each drawn as a panel.
+pub(crate) struct Panels<'a, T> {
+    items: &'a [Item<T>],
+    text_size: Pixels,
+    font: iced::Font,
+    direction: Direction,
+    /// Bounds of every open level, from the last layout
+    bounds: Vec<Rectangle>,
+}
+
+impl<'a, T: Clone> Panels<'a, T> {
+    pub fn new(items: &'a [Item<T>], text_size: Pixels, font: iced::Font, direction: Direction) -> Self {
+        Self {
+            items,
+            text_size,
+            font,
+            direction,
+            bounds: Vec::new(),
+        }
+    }
+
+    /// The entries of each open level, the root menu first.
+    fn levels(&self, session: &Session) -> Vec<&'a [Item<T>]> {
+        let mut levels = vec![self.items];
+
+        for &index in &session.path {
+            match levels.last().and_then(|items| items.get(index)).and_then(Item::children) {
+                Some(children) => levels.push(children),
+                None => break,
+            }
+        }
+
+        levels
+    }
+
+    /// The highlighted entry of a level: the open submenu, or the keyboard/hover highlight.
+    fn highlight(session: &Session, level: usize) -> Option<usize> {
+        session.path.get(level).copied().or(if level == session.path.len() {
+            session.highlighted
+        } else {
+            None
+        })
+    }
+
+    fn item_bounds(items: &[Item<T>], panel: Rectangle, index: usize) -> Rectangle {
+        let y = items[..index].iter().map(Item::height).sum::<f32>();
+
+        Rectangle {
+            x: panel.x + MENU_PADDING,
+            y: panel.y + MENU_PADDING + y,
+            width: panel.width - MENU_PADDING * 2.0,
+            height: items[index].height(),
+        }
+    }
+
+    /// Whether the point is over any open panel.
+    pub fn contains(&self, point: Point) -> bool {
+        self.bounds.iter().any(|panel| panel.contains(point))
+    }
+
+    /// The level and entry under the cursor.
+    fn item_at(&self, session: &Session, point: Point) -> Option<(usize, usize)> {
+        let levels = self.levels(session);
+
+        self.bounds.iter().enumerate().rev().find_map(|(level, panel)| {
+            let items = levels.get(level)?;
+            (0..items.len())
+                .find(|&index| Self::item_bounds(items, *panel, index).contains(point))
+                .map(|index| (level, index))
+        })
+    }
+
+    /// Opens the submenu at `level`/`index`, or just highlights the entry.
+    fn hover(&self, session: &mut Session, level: usize, index: usize) {
+        let Some(item) = self.levels(session).get(level).and_then(|items| items.get(index)) else {
+            return;
+        };
+
+        session.path.truncate(level);
+
+        if item.children().is_some() {
+            session.path.push(index);
+            session.highlighted = None;
+        } else {
+            session.highlighted = item.is_selectable().then_some(index);
+        }
+    }
+
+    /// Picks an entry: publishes actions, opens submenus.
+    fn activate<Message>(
+        &self,
+        session: &mut Session,
+        level: usize,
+        index: usize,
+        on_select: &dyn Fn(T) -> Message,
+        shell: &mut Shell<'_, Message>,
+    ) -> Outcome {
+        let Some(item) = self.levels(session).get(level).and_then(|items| items.get(index)) else {
+            return Outcome::None;
+        };
+
+        match &item.kind {
+            Kind::Action { id, .. } if !item.disabled => {
+                shell.publish(on_select(id.clone()));
+                Outcome::Close
+            }
+            Kind::Submenu { .. } => match item.children() {
+                Some(children) => {
+                    session.path.truncate(level);
+                    session.path.push(index);
+                    session.highlighted = first_selectable(children, 0, 1);
+                    Outcome::Handled
+                }
+                None => Outcome::None,
+            },
+            _ => Outcome::None,
+        }
+    }
+
+    /// Places every open level: the root at `origin`, submenus next to their entry, all
+    /// flipping when they would leave the window. Right to left, they start from the other side.
+    pub fn layout<P: text::Paragraph<Font = iced::Font>>(&mut self, session: &Session, origin: Origin, bounds: Size) {
+        let measure = |content: &str| {
+            P::with_text(iced::advanced::Text {
+                content,
+                bounds: Size::INFINITE,
+                size: self.text_size,
+                font: self.font,
+                align_x: text::Alignment::Left,
+                align_y: alignment::Vertical::Top,
+                line_height: text::LineHeight::default(),
+                shaping: text::Shaping::Advanced,
+                wrapping: text::Wrapping::None,
+            })
+            .min_bounds()
+            .width
+        };
+
+        let levels = self.levels(session);
+        let mut panels: Vec<Rectangle> = Vec::with_capacity(levels.len());
+
+        for (level, items) in levels.iter().enumerate() {
+            let gutter = if items.iter().any(Item::has_gutter) { GUTTER_WIDTH } else { 0.0 };
+            let label = items.iter().map(|item| measure(item.label())).fold(0.0, f32::max);
+            let shortcut = items
+                .iter()
+                .filter_map(|item| item.shortcut.as_deref())
+                .map(measure)
+                .fold(0.0, f32::max);
+            let shortcut = if shortcut > 0.0 { shortcut + SHORTCUT_SPACING } else { 0.0 };
+
+            let width = (gutter + label + shortcut + ITEM_PADDING * 2.0 + ARROW_WIDTH).max(MIN_MENU_WIDTH)
+                + MENU_PADDING * 2.0;
+            let height = items.iter().map(Item::height).sum::<f32>() + MENU_PADDING * 2.0;
+
+            // After `start` if it fits, else before `end`, or the other way around right to left
+            let place = |start: f32, end: f32| match self.direction {
+                Direction::LeftToRight if start + width > bounds.width => end - width,
+                Direction::LeftToRight => start,
+                Direction::RightToLeft if end - width < 0.0 => start,
+                Direction::RightToLeft => end - width,
+            };
+
+            let (x, y) = match (panels.last(), origin) {
+                #[cfg(feature = "context_menu")]
+                (None, Origin::Point(point)) => (
+                    place(point.x, point.x),
+                    if point.y + height > bounds.height { point.y - height } else { point.y },
+                ),
+                (None, Origin::Below(anchor)) => {
+                    let below = anchor.y + anchor.height;
+                    (
+                        match self.direction {
+                            Direction::LeftToRight => anchor.x,
+                            Direction::RightToLeft => anchor.x + anchor.width - width,
+                        },
+                        if below + height > bounds.height { anchor.y - height } else { below },
+                    )
+                }
+                (Some(parent), _) => {
+                    let entry = Self::item_bounds(levels[level - 1], *parent, session.path[level - 1]);
+                    (place(parent.x + parent.width, parent.x), entry.y - MENU_PADDING)
+                }
+            };
+
+            panels.push(Rectangle {
+                x: x.clamp(0.0, (bounds.width - width).max(0.0)),
+                y: y.clamp(0.0, (bounds.height - height).max(0.0)),
+                width,
+                height,
+            });
+        }
+
+        self.bounds = panels;
+    }
+
+    pub fn update<Message>(
+        &self,
+        session: &mut Session,
+        event: &Event,
+        cursor: mouse::Cursor,
+        on_select: &dyn Fn(T) -> Message,
+        shell: &mut Shell<'_, Message>,
+    ) -> Outcome {
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                let Some((level, index)) = self.item_at(session, *position) else {
+                    return Outcome::None;
+                };
+                let before = session.clone();
+                self.hover(session, level, index);
+
+                if *session == before { Outcome::None } else { Outcome::Handled }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(button)) => {
+                let Some(position) = cursor.position() else {
+                    return Outcome::None;
+                };
+
+                match self.item_at(session, position) {
+                    Some((level, index)) if *button == mouse::Button::Left => {
+                        match self.activate(session, level, index, on_select, shell) {
+                            Outcome::None => Outcome::Handled,
+                            outcome => outcome,
+                        }
+                    }
+                    _ if self.contains(position) => Outcome::Handled,
+                    _ => Outcome::Outside(*button),
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed { key, .. }) => {
+                // Right to left, submenus open to the left, so the arrows swap
+                let key = match key {
+                    keyboard::Key::Named(keyboard::key::Named::ArrowLeft) if self.direction.is_rtl() => {
+                        &keyboard::Key::Named(keyboard::key::Named::ArrowRight)
+                    }
+                    keyboard::Key::Named(keyboard::key::Named::ArrowRight) if self.direction.is_rtl() => {
+                        &keyboard::Key::Named(keyboard::key::Named::ArrowLeft)
+                    }
+                    key => key,
+                };
+                let levels = self.levels(session);
+                let level = levels.len() - 1;
+                let items = levels[level];
+
+                match key {
+                    keyboard::Key::Named(keyboard::key::Named::ArrowDown) => {
+                        let start = session.highlighted.map_or(0, |index| index + 1);
+                        session.highlighted = first_selectable(items, start, 1);
+                        Outcome::Handled
+                    }
+                    keyboard::Key::Named(keyboard::key::Named::ArrowUp) => {
+                        let start = session
+                            .highlighted
+                            .map_or(items.len().saturating_sub(1), |index| (index + items.len() - 1) % items.len());
+                        session.highlighted = first_selectable(items, start, -1);
+                        Outcome::Handled
+                    }
+                    keyboard::Key::Named(keyboard::key::Named::Enter) => match session.highlighted {
+                        Some(index) => match self.activate(session, level, index, on_select, shell) {
+                            Outcome::None => Outcome::Handled,
+                            outcome => outcome,
+                        },
+                        None => Outcome::Handled,
+                    },
+                    keyboard::Key::Named(keyboard::key::Named::ArrowRight) => {
+                        let opens = session
+                            .highlighted
+                            .and_then(|index| items.get(index))
+                            .is_some_and(|item| item.children().is_some());
+
+                        match session.highlighted {
+                            Some(index) if opens => self.activate(session, level, index, on_select, shell),
+                            _ => Outcome::Next,
+                        }
+                    }
+                    keyboard::Key::Named(keyboard::key::Named::ArrowLeft) => match session.path.pop() {
+                        Some(parent) => {
+                            session.highlighted = Some(parent);
+                            Outcome::Handled
+                        }
+                        None => Outcome::Previous,
+                    },
+                    keyboard::Key::Named(keyboard::key::Named::Escape) => match session.path.pop() {
+                        Some(parent) => {
+                            session.highlighted = Some(parent);
+                            Outcome::Handled
+                        }
+                        None => Outcome::Close,
+                    },
+                    _ => Outcome::None,
+                }
+            }
+            _ => Outcome::None,
+        }
+    }
+
+    fn text(&self, content: &str, bounds: Size, align_x: text::Alignment) -> iced::advanced::Text<String, iced::Font> {
+        iced::advanced::Text {
+            content: content.to_string(),
+            bounds,
+            size: self.text_size,
+            font: self.font,
+            align_x,
+            align_y: alignment::Vertical::Center,
+            line_height: text::LineHeight::default(),
+            shaping: text::Shaping::Advanced,
+            wrapping: text::Wrapping::None,
+        }
+    }
+
+    pub fn draw<Renderer>(&self, renderer: &mut Renderer, style: &Style, session: &Session)
+    where
+        Renderer: text::Renderer<Font = iced::Font>,
+    {
+        let levels = self.levels(session);
+        let dir = self.direction;
+        let start = text::Alignment::from(dir.start());
+        let end = text::Alignment::from(dir.end());
+
+        for (level, (items, panel)) in levels.iter().zip(&self.bounds).enumerate() {
+            let highlight = Self::highlight(session, level);
+            let gutter = if items.iter().any(Item::has_gutter) { GUTTER_WIDTH } else { 0.0 };
+
+            // Each panel is its own layer so submenus draw above their parent
+            renderer.with_layer(*panel, |renderer| {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: pixel::snap(*panel),
+                        border: pixel::border(style.border),
+                        shadow: style.shadow,
+                        snap: true,
+                    },
+                    style.background,
+                );
+
+                for (index, item) in items.iter().enumerate() {
+                    let bounds = Self::item_bounds(items, *panel, index);
+
+                    if let Kind::Separator = item.kind {
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: pixel::snap(Rectangle {
+                                    x: bounds.x + ITEM_PADDING / 2.0,
+                                    y: bounds.center_y().floor(),
+                                    width: bounds.width - ITEM_PADDING,
+                                    height: 1.0,
+                                }),
+                                ..renderer::Quad::default()
+                            },
+                            style.separator,
+                        );
+                        continue;
+                    }
+
+                    let highlighted = highlight == Some(index) && !item.disabled;
+                    if highlighted {
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: pixel::snap(bounds),
+                                border: pixel::border(Border {
+                                    radius: style.border.radius,
+                                    ..Border::default()
+                                }),
+                                ..renderer::Quad::default()
+                            },
+                            style.highlighted_background,
+                        );
+                    }
+
+                    let color = if item.disabled {
+                        style.disabled_text
+                    } else if highlighted {
+                        style.highlighted_text
+                    } else {
+                        style.text
+                    };
+
+                    // The mark wins over the icon, a checked entry needs to show its state
+                    let glyph = match item.mark {
+                        Mark::Check(true) => Some("✓"),
+                        Mark::Radio(true) => Some("•"),
+                        Mark::Check(false) | Mark::Radio(false) => None,
+                        Mark::None => item.icon.as_deref(),
+                    };
+                    if let Some(glyph) = glyph {
+                        renderer.fill_text(
+                            self.text(glyph, Size::new(GUTTER_WIDTH, bounds.height), text::Alignment::Center),
+                            Point::new(
+                                dir.mirror_x(bounds.x + ITEM_PADDING / 2.0 + GUTTER_WIDTH / 2.0, bounds),
+                                bounds.center_y(),
+                            ),
+                            color,
+                            bounds,
+                        );
+                    }
+
+                    renderer.fill_text(
+                        self.text(item.label(), bounds.size(), start),
+                        Point::new(dir.mirror_x(bounds.x + ITEM_PADDING + gutter, bounds), bounds.center_y()),
+                        color,
+                        bounds,
+                    );
+
+                    let end_x = bounds.x + bounds.width - ITEM_PADDING / 2.0;
+                    if let Kind::Submenu { .. } = item.kind {
+                        renderer.fill_text(
+                            self.text(
+                                if dir.is_rtl() { "‹" } else { "›" },
+                                Size::new(ARROW_WIDTH, bounds.height),
+                                text::Alignment::Center,
+                            ),
+                            Point::new(dir.mirror_x(end_x - ARROW_WIDTH / 2.0, bounds), bounds.center_y()),
+                            color,
+                            bounds,
+                        );
+                    } else if let Some(shortcut) = &item.shortcut {
+                        renderer.fill_text(
+                            self.text(shortcut, bounds.size(), end),
+                            Point::new(dir.mirror_x(end_x - ARROW_WIDTH, bounds), bounds.center_y()),
+                            if highlighted { style.highlighted_text } else { style.shortcut_text },
+                            bounds,
+                        );
+                    }
+                }
+            });
+        }
+    }
+
+    pub fn mouse_interaction(&self, session: &Session, cursor: mouse::Cursor) -> mouse::Interaction {
+        let Some(position) = cursor.position() else {
+            return mouse::Interaction::None;
+        };
+        let levels = self.levels(session);
+
+        match self.item_at(session, position) {
+            Some((level, index)) if levels[level][index].is_selectable() => mouse::Interaction::Pointer,
+            _ if self.contains(position) => mouse::Interaction::Idle,
+            _ => mouse::Interaction::None,
+        }
+    }
+}
+
+/// Creates a new [`MenuBar`] with the given menus.
+pub fn menu_bar<'a, T, Message, Theme>(
+    menus: impl IntoIterator<Item = Menu<T>>,
+    on_select: impl Fn(T) -> Message + 'a,
+) -> MenuBar<'a, T, Message, Theme>
+where
+    Theme: Catalog,
+{
+    MenuBar::new(menus, on_select)
+}
+
+/// A titled menu of a [`MenuBar`].
+#[derive(Debug, Clone)]
+pub struct Menu<T> {
+    title: String,
+    items: Vec<Item<T>>,
+}
+
+impl<T> Menu<T> {
+    /// Creates a menu with a title and its entries.
+    pub fn new(title: impl Into<String>, items: impl IntoIterator<Item = Item<T>>) -> Self {
+        Self {
+            title: title.into(),
+            items: items.into_iter().collect(),
+        }
+    }
+
+    /// Shows the shortcuts `keymap` binds to the entries, see [`Item::hotkeys`].
+    #[cfg(feature = "hotkeys")]
+    #[must_use]
+    pub fn hotkeys(mut self, keymap: &crate::hotkeys::Keymap<T>) -> Self
+    where
+        T: PartialEq,
+    {
+        self.items = self.items.into_iter().map(|item| item.hotkeys(keymap)).collect();
+        self
+    }
+}
+
+/// A horizontal bar of menus, such as File, Edit and View.
+#[allow(missing_debug_implementations)]
+pub struct MenuBar<'a, T, Message, Theme = iced::Theme>
+where
+    Theme: Catalog,
+{
+    menus: Vec<Menu<T>>,
+    on_select: Box<dyn Fn(T) -> Message + 'a>,
+    width: Length,
+    padding: Padding,
+    spacing: f32,
+    text_size: Pixels,
+    font: iced::Font,
+    direction: Option<Direction>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, T, Message, Theme> MenuBar<'a, T, Message, Theme>
+where
+    Theme: Catalog,
+{
+    /// Creates a new [`MenuBar`] with the given menus.
+    pub fn new(menus: impl IntoIterator<Item = Menu<T>>, on_select: impl Fn(T) -> Message + 'a) -> Self {
+        Self {
+            menus: menus.into_iter().collect(),
+            on_select: Box::new(on_select),
+            width: Length::Fill,
+            padding: Padding::from([4, 10]),
+            spacing: 0.0,
+            text_size: Pixels(14.0),
+            font: iced::Font::default(),
+            direction: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Adds a menu.
+    pub fn push(mut self, menu: Menu<T>) -> Self {
+        self.menus.push(menu);
+        self
+    }
+
+    /// Shows the shortcuts `keymap` binds to the entries of every menu.
+    #[cfg(feature = "hotkeys")]
+    #[must_use]
+    pub fn hotkeys(mut self, keymap: &crate::hotkeys::Keymap<T>) -> Self
+    where
+        T: PartialEq,
+    {
+        self.menus = self.menus.into_iter().map(|menu| menu.hotkeys(keymap)).collect();
+        self
+    }
+
+    /// Sets the width of the bar.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the padding around each menu title.
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the space between menu titles.
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets the text size of the titles and menus.
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = size.into();
+        self
+    }
+
+    /// Sets the font of the titles and menus.
+    pub fn font(mut self, font: iced::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the [`Direction`] of the bar, instead of the one set with [`direction::set`].
+    ///
+    /// Right to left lines the titles up from the right, and opens the menus from there.
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    /// Sets the style.
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class.
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    fn title_text(&self, content: &str, bounds: Size) -> iced::advanced::Text<String, iced::Font> {
+        iced::advanced::Text {
+            content: content.to_string(),
+            bounds,
+            size: self.text_size,
+            font: self.font,
+            align_x: text::Alignment::Center,
+            align_y: alignment::Vertical::Center,
+            line_height: text::LineHeight::default(),
+            shaping: text::Shaping::Advanced,
+            wrapping: text::Wrapping::None,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct BarState {
+    open: Option<usize>,
+    session: Session,
+    /// Title bounds relative to the bar, from the last layout
+    titles: Vec<Rectangle>,
+}
+
+impl BarState {
+    fn open(&mut self, menu: Option<usize>) {
+        self.open = menu;
+        self.session = Session::default();
+    }
+
+    fn title_at(&self, origin: Point, point: Point) -> Option<usize> {
+        let relative = point - (origin - Point::ORIGIN);
+        self.titles.iter().position(|title| title.contains(relative))
+    }
+}
+
+impl<T, Message, Theme, Renderer> Widget<Message, Theme, Renderer> for MenuBar<'_, T, Message, Theme>
+where
+    T: Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer<Font = iced::Font>,
+{
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<BarState>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(BarState::default())
+    }
+
+    fn layout(&mut self, tree: &mut Tree, _renderer: &Renderer, limits: &Limits) -> Node {
+        use text::Paragraph as _;
+
+        let state = tree.state.downcast_mut::<BarState>();
+        let measure = |content: &str| {
+            Renderer::Paragraph::with_text(iced::advanced::Text {
+                content,
+                bounds: Size::INFINITE,
+                size: self.text_size,
+                font: self.font,
+                align_x: text::Alignment::Left,
+                align_y: alignment::Vertical::Top,
+                line_height: text::LineHeight::default(),
+                shaping: text::Shaping::Advanced,
+                wrapping: text::Wrapping::None,
+            })
+            .min_bounds()
+        };
+
+        let height = measure("Ag").height + self.padding.y();
+        let mut x = 0.0;
+
+        state.titles = self
+            .menus
+            .iter()
+            .map(|menu| {
+                let width = measure(&menu.title).width + self.padding.x();
+                let title = Rectangle {
+                    x,
+                    y: 0.0,
+                    width,
+                    height,
+                };
+                x += width + self.spacing;
+                title
+            })
+            .collect();
+
+        if state.open.is_some_and(|open| open >= self.menus.len()) {
+            state.open(None);
+        }
+
+        let size = limits.resolve(self.width, Length::Shrink, Size::new(x - self.spacing, height));
+        let dir = self.direction.unwrap_or_else(direction::get);
+        for title in &mut state.titles {
+            *title = dir.mirror(*title, Rectangle::with_size(size));
+        }
+
+        Node::new(size)
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<BarState>();
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                if let Some(index) = cursor
+                    .position_over(bounds)
+                    .and_then(|position| state.title_at(bounds.position(), position))
+                {
+                    state.open((state.open != Some(index)).then_some(index));
+                    shell.capture_event();
+                    shell.request_redraw();
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                shell.request_redraw();
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _defaults: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<BarState>();
+        let style = theme.style(&self.class);
+        let bounds = layout.bounds();
+        let hovered = cursor.position().and_then(|position| state.title_at(bounds.position(), position));
+
+        if let Some(background) = style.bar_background {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: pixel::snap(bounds),
+                    ..renderer::Quad::default()
+                },
+                background,
+            );
+        }
+
+        for (index, (menu, title)) in self.menus.iter().zip(&state.titles).enumerate() {
+            let title = *title + Vector::new(bounds.x, bounds.y);
+            let is_open = state.open == Some(index);
+
+            if is_open || hovered == Some(index) {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: pixel::snap(title),
+                        border: pixel::border(Border {
+                            radius: style.border.radius,
+                            ..Border::default()
+                        }),
+                        ..renderer::Quad::default()
+                    },
+                    if is_open { style.highlighted_background } else { style.title_hovered_background },
+                );
+            }
+
+            renderer.fill_text(
+                self.title_text(&menu.title, title.size()),
+                title.center(),
+                if is_open { style.highlighted_text } else { style.text },
+                title.intersection(viewport).unwrap_or(title),
+            );
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<BarState>();
+        let bounds = layout.bounds();
+
+        match cursor.position().and_then(|position| state.title_at(bounds.position(), position)) {
+            Some(_) => mouse::Interaction::Pointer,
+            None => mouse::Interaction::None,
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        _renderer: &Renderer,
+        _viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_mut::<BarState>();
+        let open = state.open?;
+        let menu = self.menus.get(open)?;
+
+        Some(overlay::Element::new(Box::new(BarOverlay {
+            panels: Panels::new(&menu.items, self.text_size, self.font, self.direction.unwrap_or_else(direction::get)),
+            on_select: self.on_select.as_ref(),
+            class: &self.class,
+            origin: layout.bounds().position() + translation,
+            state,
+        })))
+    }
+}
+
+/// The open menu of a [`MenuBar`], which also follows the cursor across the titles.
+struct BarOverlay<'a, 'b, T, Message, Theme>
+where
+    Theme: Catalog,
+{
+    panels: Panels<'a, T>,
+    on_select: &'a dyn Fn(T) -> Message,
+    class: &'a Theme::Class<'b>,
+    origin: Point,
+    state: &'a mut BarState,
+}
+
+impl<T, Message, Theme, Renderer> Overlay<Message, Theme, Renderer> for BarOverlay<'_, '_, T, Message, Theme>
+where
+    T: Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer<Font = iced::Font>,
+{
+    fn layout(&mut self, _renderer: &Renderer, bounds: Size) -> Node {
+        if let Some(title) = self.state.open.and_then(|open| self.state.titles.get(open)) {
+            let anchor = *title + (self.origin - Point::ORIGIN);
+            self.panels
+                .layout::<Renderer::Paragraph>(&self.state.session, Origin::Below(anchor), bounds);
+        }
+
+        Node::new(bounds)
+    }
+
+    fn update(
+        &mut self,
+        event: &Event,
+        _layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let title = cursor
+            .position()
+            .filter(|position| !self.panels.contains(*position))
+            .and_then(|position| self.state.title_at(self.origin, position));
+        let count = self.state.titles.len();
+
+        // Titles keep working while a menu is open: hovering switches, clicking closes
+        let outcome = match (event, title) {
+            (Event::Mouse(mouse::Event::CursorMoved { .. }), Some(index)) => {
+                if self.state.open != Some(index) {
+                    self.state.open(Some(index));
+                }
+                Outcome::Handled
+            }
+            (Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)), Some(_)) => Outcome::Close,
+            _ => self.panels.update(&mut self.state.session, event, cursor, self.on_select, shell),
+        };
+
+        match outcome {
+            Outcome::None => return,
+            Outcome::Handled => {}
+            Outcome::Close | Outcome::Outside(_) => self.state.open(None),
+            Outcome::Previous | Outcome::Next if count > 0 => {
+                let open = self.state.open.unwrap_or(0);
+                let next = if outcome == Outcome::Next { (open + 1) % count } else { (open + count - 1) % count };
+
+                self.state.open(Some(next));
+            }
+            Outcome::Previous | Outcome::Next => {}
+        }
+
+        shell.capture_event();
+        shell.invalidate_layout();
+        shell.request_redraw();
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _defaults: &renderer::Style,
+        _layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+    ) {
+        self.panels.draw(renderer, &theme.style(self.class), &self.state.session);
+    }
+
+    fn mouse_interaction(
+        &self,
+        _layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        // Claim the titles too, so the bar does not toggle the menu a second time
+        if cursor
+            .position()
+            .and_then(|position| self.state.title_at(self.origin, position))
+            .is_some()
+        {
+            return mouse::Interaction::Pointer;
+        }
+
+        self.panels.mouse_interaction(&self.state.session, cursor)
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer> From<MenuBar<'a, T, Message, Theme>> for Element<'a, Message, Theme, Renderer>
+where
+    T: Clone + 'a,
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer<Font = iced::Font> + 'a,
+{
+    fn from(bar: MenuBar<'a, T, Message, Theme>) -> Self {
+        Element::new(bar)
+    }
+}
+
+/// The theme catalog of menus.
+pub trait Catalog {
+    /// The style class
+    type Class<'a>;
+
+    /// Default style
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// Get the style for a class
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// The appearance of menus and menu bars.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// Background of a menu panel
+    pub background: Background,
+    /// Border of a menu panel, its radius is also used for highlighted entries
+    pub border: Border,
+    /// Shadow of a menu panel
+    pub shadow: Shadow,
+    /// Entry labels and menu bar titles
+    pub text: Color,
+    /// Labels of disabled entries
+    pub disabled_text: Color,
+    /// Shortcut hints
+    pub shortcut_text: Color,
+    /// Background of the highlighted entry and of the open menu bar title
+    pub highlighted_background: Background,
+    /// Label of the highlighted entry and of the open menu bar title
+    pub highlighted_text: Color,
+    /// Separator lines
+    pub separator: Color,
+    /// Background behind the menu bar
+    pub bar_background: Option<Background>,
+    /// Background of a hovered menu bar title
+    pub title_hovered_background: Background,
+}
+
+/// Styling function
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for iced::Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default menu style.
+pub fn default(theme: &iced::Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: palette.background.base.color.into(),
+        border: Border {
+            color: palette.background.strong.color,
+            width: 1.0,
+            radius: 4.0.into(),
+        },
+        shadow: Shadow {
+            color: Color::BLACK.scale_alpha(0.3),
+            offset: Vector::new(0.0, 3.0),
+            blur_radius: 10.0,
+        },
+        text: palette.background.base.text,
+        disabled_text: palette.background.strong.color,
+        shortcut_text: palette.background.strong.color,
+        highlighted_background: palette.primary.base.color.into(),
+        highlighted_text: palette.primary.base.text,
+        separator: palette.background.strong.color,
+        bar_background: Some(palette.background.weak.color.into()),
+        title_hovered_background: palette.background.strong.color.into(),
+    }
+}
+
+/// Square panels with a subtle highlight.
+pub fn flat(theme: &iced::Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        border: Border {
+            color: palette.background.strong.color,
+            width: 1.0,
+            radius: 0.0.into(),
+        },
+        shadow: Shadow::default(),
+        highlighted_background: palette.background.weak.color.into(),
+        highlighted_text: palette.background.weak.text,
+        bar_background: None,
+        ..default(theme)
+    }
+}
@@ -0,0 +1,494 @@
+//! A scaled-down overview of a large area with a handle for the part in view.
+//!
+//! A [`Minimap`] knows nothing about the view it controls: the host tells it how big the
+//! whole area is and which part of it is visible, and gets told where to move the view when
+//! the visible rectangle is dragged or the overview is clicked. That works for anything that
+//! scrolls or pans, like a [`scrollable`](iced::widget::scrollable) (from its
+//! `on_scroll` viewport, moved with `scroll_to`) or the `viewport` container (from
+//! `Transform::visible`, moved with `Transform::looking_at`).
+//!
+//! The overview shows a preview element scaled to fit, rectangles marking items in the area
+//! (such as nodes in a graph), or both. The wheel over the minimap asks for zooming through
+//! [`Minimap::on_zoom`].
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        mouse, renderer,
+        widget::{self, tree::Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    Background, Border, Color, Element, Event, Length, Point, Rectangle, Size, Transformation, Vector,
+};
+
+use crate::pixel;
+
+/// Creates a new [`Minimap`] of an area of `size` with `visible` in view.
+pub fn minimap<'a, Message, Theme, Renderer>(
+    size: Size,
+    visible: Rectangle,
+    on_navigate: impl Fn(Point) -> Message + 'a,
+) -> Minimap<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    Minimap::new(size, visible, on_navigate)
+}
+
+/// An overview controlling the scroll or pan of another view.
+#[allow(missing_debug_implementations)]
+pub struct Minimap<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Theme: Catalog,
+{
+    size: Size,
+    visible: Rectangle,
+    on_navigate: Box<dyn Fn(Point) -> Message + 'a>,
+    on_zoom: Option<Box<dyn Fn(f32) -> Message + 'a>>,
+    preview: Option<Element<'a, Message, Theme, Renderer>>,
+    shapes: Vec<Rectangle>,
+    width: Length,
+    height: Length,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> Minimap<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    /// Creates a new [`Minimap`] of an area of `size` with `visible` in view.
+    ///
+    /// `on_navigate` gets the new top left corner of the visible part, in area coordinates.
+    pub fn new(size: Size, visible: Rectangle, on_navigate: impl Fn(Point) -> Message + 'a) -> Self {
+        Self {
+            size,
+            visible,
+            on_navigate: Box::new(on_navigate),
+            on_zoom: None,
+            preview: None,
+            shapes: Vec::new(),
+            width: Length::Fixed(200.0),
+            height: Length::Fixed(150.0),
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the message produced by the wheel, with the factor to zoom the view by.
+    pub fn on_zoom(mut self, on_zoom: impl Fn(f32) -> Message + 'a) -> Self {
+        self.on_zoom = Some(Box::new(on_zoom));
+        self
+    }
+
+    /// Shows `preview`, laid out at the size of the area and scaled down to fit.
+    ///
+    /// The preview is only drawn, it does not react to the cursor.
+    pub fn preview(mut self, preview: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        self.preview = Some(preview.into());
+        self
+    }
+
+    /// Marks items of the area, in its coordinates.
+    pub fn shapes(mut self, shapes: impl IntoIterator<Item = Rectangle>) -> Self {
+        self.shapes = shapes.into_iter().collect();
+        self
+    }
+
+    /// Sets the width of the minimap.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the minimap.
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the style.
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class.
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// The scale and position the area is drawn at to fit `bounds`, centered.
+    fn fit(&self, bounds: Rectangle) -> (f32, Point) {
+        if self.size.width <= 0.0 || self.size.height <= 0.0 {
+            return (1.0, bounds.position());
+        }
+
+        let scale = (bounds.width / self.size.width).min(bounds.height / self.size.height);
+        let origin = Point::new(
+            bounds.x + (bounds.width - self.size.width * scale) / 2.0,
+            bounds.y + (bounds.height - self.size.height * scale) / 2.0,
+        );
+
+        (scale, origin)
+    }
+
+    fn to_screen(&self, bounds: Rectangle, area: Rectangle) -> Rectangle {
+        let (scale, origin) = self.fit(bounds);
+
+        Rectangle {
+            x: origin.x + area.x * scale,
+            y: origin.y + area.y * scale,
+            width: area.width * scale,
+            height: area.height * scale,
+        }
+    }
+
+    fn to_area(&self, bounds: Rectangle, point: Point) -> Point {
+        let (scale, origin) = self.fit(bounds);
+
+        Point::new((point.x - origin.x) / scale, (point.y - origin.y) / scale)
+    }
+
+    /// Moves the visible part so its corner is at `corner`, staying within the area.
+    fn navigate(&self, corner: Point, shell: &mut Shell<'_, Message>) {
+        let clamped = Point::new(
+            corner.x.clamp(0.0, (self.size.width - self.visible.width).max(0.0)),
+            corner.y.clamp(0.0, (self.size.height - self.visible.height).max(0.0)),
+        );
+
+        if clamped != self.visible.position() {
+            shell.publish((self.on_navigate)(clamped));
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    /// Where the visible rectangle was grabbed, relative to its corner in area coordinates
+    grab: Option<Vector>,
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Minimap<'_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.preview.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(self.preview.as_slice());
+    }
+
+    fn layout(&mut self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let size = limits.resolve(self.width, self.height, Size::ZERO);
+
+        let children = match &mut self.preview {
+            Some(preview) => {
+                vec![preview
+                    .as_widget_mut()
+                    .layout(&mut tree.children[0], renderer, &Limits::new(Size::ZERO, self.size))]
+            }
+            None => Vec::new(),
+        };
+
+        Node::with_children(size, children)
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    return;
+                };
+                let point = self.to_area(bounds, position);
+
+                // Clicking outside the visible rectangle centers it there, then drags it
+                let grab = if self.visible.contains(point) {
+                    point - self.visible.position()
+                } else {
+                    let grab = Vector::new(self.visible.width / 2.0, self.visible.height / 2.0);
+                    self.navigate(point - grab, shell);
+                    grab
+                };
+
+                state.grab = Some(grab);
+                shell.capture_event();
+                shell.request_redraw();
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                if let Some(grab) = state.grab {
+                    self.navigate(self.to_area(bounds, *position) - grab, shell);
+                    shell.capture_event();
+                }
+                shell.request_redraw();
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) if state.grab.is_some() => {
+                state.grab = None;
+                shell.capture_event();
+                shell.request_redraw();
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) if cursor.is_over(bounds) => {
+                let Some(on_zoom) = &self.on_zoom else {
+                    return;
+                };
+                let notches = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => *y,
+                    mouse::ScrollDelta::Pixels { y, .. } => y / 50.0,
+                };
+
+                shell.publish(on_zoom(1.1_f32.powf(notches)));
+                shell.capture_event();
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        defaults: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let style = theme.style(&self.class);
+        let Some(clip) = bounds.intersection(viewport) else {
+            return;
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: pixel::snap(bounds),
+                border: pixel::border(style.border),
+                ..renderer::Quad::default()
+            },
+            style.background,
+        );
+
+        renderer.with_layer(clip, |renderer| {
+            if let (Some(preview), Some(preview_layout)) = (&self.preview, layout.children().next()) {
+                let (scale, origin) = self.fit(bounds);
+                let content = preview_layout.bounds();
+                let transformation = Transformation::translate(origin.x, origin.y)
+                    * Transformation::scale(scale)
+                    * Transformation::translate(-content.x, -content.y);
+
+                renderer.with_transformation(transformation, |renderer| {
+                    preview.as_widget().draw(
+                        &tree.children[0],
+                        renderer,
+                        theme,
+                        defaults,
+                        preview_layout,
+                        mouse::Cursor::Unavailable,
+                        &content,
+                    );
+                });
+            }
+
+            for shape in &self.shapes {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: pixel::snap(self.to_screen(bounds, *shape)),
+                        border: pixel::border(Border {
+                            radius: 1.0.into(),
+                            ..Border::default()
+                        }),
+                        ..renderer::Quad::default()
+                    },
+                    style.shape,
+                );
+            }
+
+            let visible = self.to_screen(bounds, self.visible);
+
+            // Everything outside the visible part is dimmed, in four bands around it
+            if style.mask.a > 0.0 {
+                let top = visible.y.clamp(bounds.y, bounds.y + bounds.height);
+                let bottom = (visible.y + visible.height).clamp(bounds.y, bounds.y + bounds.height);
+                let left = visible.x.clamp(bounds.x, bounds.x + bounds.width);
+                let right = (visible.x + visible.width).clamp(bounds.x, bounds.x + bounds.width);
+
+                for band in [
+                    Rectangle::new(bounds.position(), Size::new(bounds.width, top - bounds.y)),
+                    Rectangle::new(
+                        Point::new(bounds.x, bottom),
+                        Size::new(bounds.width, bounds.y + bounds.height - bottom),
+                    ),
+                    Rectangle::new(Point::new(bounds.x, top), Size::new(left - bounds.x, bottom - top)),
+                    Rectangle::new(
+                        Point::new(right, top),
+                        Size::new(bounds.x + bounds.width - right, bottom - top),
+                    ),
+                ] {
+                    if band.width > 0.0 && band.height > 0.0 {
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: pixel::snap(band),
+                                ..renderer::Quad::default()
+                            },
+                            style.mask,
+                        );
+                    }
+                }
+            }
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: pixel::snap(visible),
+                    border: pixel::border(style.visible_border),
+                    ..renderer::Quad::default()
+                },
+                style.visible_background,
+            );
+        });
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+
+        if state.grab.is_some() {
+            return mouse::Interaction::Grabbing;
+        }
+
+        match cursor.position_over(bounds) {
+            Some(position) if self.visible.contains(self.to_area(bounds, position)) => mouse::Interaction::Grab,
+            Some(_) => mouse::Interaction::Pointer,
+            None => mouse::Interaction::None,
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Minimap<'a, Message, Theme, Renderer>> for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(minimap: Minimap<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(minimap)
+    }
+}
+
+/// The theme catalog of a [`Minimap`].
+pub trait Catalog {
+    /// The style class
+    type Class<'a>;
+
+    /// Default style
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// Get the style for a class
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// The appearance of a [`Minimap`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// Background of the minimap
+    pub background: Background,
+    /// Border of the minimap
+    pub border: Border,
+    /// Color of the shapes marking items
+    pub shape: Color,
+    /// Color laid over what is out of view, transparent to leave it as is
+    pub mask: Color,
+    /// Fill of the visible rectangle
+    pub visible_background: Background,
+    /// Border of the visible rectangle
+    pub visible_border: Border,
+}
+
+/// Styling function
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for iced::Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default minimap style.
+pub fn default(theme: &iced::Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: palette.background.weakest.color.into(),
+        border: Border {
+            color: palette.background.strong.color,
+            width: 1.0,
+            radius: 4.0.into(),
+        },
+        shape: palette.background.strong.color,
+        mask: Color::BLACK.scale_alpha(0.25),
+        visible_background: palette.primary.base.color.scale_alpha(0.15).into(),
+        visible_border: Border {
+            color: palette.primary.strong.color,
+            width: 1.5,
+            radius: 2.0.into(),
+        },
+    }
+}
+
+/// Only an outline around the visible part.
+pub fn outline(theme: &iced::Theme) -> Style {
+    Style {
+        mask: Color::TRANSPARENT,
+        visible_background: Color::TRANSPARENT.into(),
+        ..default(theme)
+    }
+}
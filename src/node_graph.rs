@@ -0,0 +1,1080 @@
+//! An editor for graphs of nodes wired together through typed ports.
+//!
+//! The app owns the [`Node`]s and [`Connection`]s; the editor shows them and reports what the
+//! user does as [`Change`]s for the app to apply:
+//!
+//! - Dragging a node moves it, along with the other selected nodes
+//! - Dragging from a port draws a wire that connects when dropped on a compatible port of the
+//!   other direction; kinds must match unless [`NodeGraph::validate`] says otherwise. Wires
+//!   are colored by whether they would connect
+//! - Dragging a wire off an input disconnects it and picks it up again
+//! - Clicking selects a node, Shift adds to the selection and dragging on empty space selects
+//!   every node touching the box. Ctrl+A selects everything and Delete removes the selection
+//! - The wheel zooms around the cursor and dragging with the middle button pans
+//!
+//! Each input takes a single wire; connecting another one replaces it.
+
+use std::collections::HashSet;
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node as LayoutNode},
+        mouse, renderer, text,
+        widget::{self, tree::Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment, keyboard, Background, Border, Color, Element, Event, Length, Pixels, Point, Rectangle, Shadow,
+    Size, Vector,
+};
+
+use crate::viewport::Transform;
+use crate::pixel;
+
+const HEADER_HEIGHT: f32 = 26.0;
+const ROW_HEIGHT: f32 = 22.0;
+const NODE_PADDING: f32 = 6.0;
+const PORT_RADIUS: f32 = 5.0;
+const PORT_HIT_RADIUS: f32 = 9.0;
+const PORT_LABEL_GAP: f32 = 10.0;
+const WIRE_WIDTH: f32 = 2.5;
+const GRID_SPACING: f32 = 24.0;
+const DRAG_THRESHOLD: f32 = 3.0;
+
+/// Identifies a [`Node`].
+pub type NodeId = usize;
+
+/// Creates a new [`NodeGraph`] of `nodes` wired by `connections`.
+pub fn node_graph<'a, Message, Theme>(
+    nodes: &'a [Node],
+    connections: &'a [Connection],
+    on_change: impl Fn(Change) -> Message + 'a,
+) -> NodeGraph<'a, Message, Theme>
+where
+    Theme: Catalog,
+{
+    NodeGraph::new(nodes, connections, on_change)
+}
+
+/// An input or output of a [`Node`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Port {
+    pub name: String,
+    /// The type of value flowing through, which decides what it connects to
+    pub kind: String,
+}
+
+impl Port {
+    /// Creates a new [`Port`].
+    pub fn new(name: impl Into<String>, kind: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            kind: kind.into(),
+        }
+    }
+}
+
+/// A box in the graph with inputs on its left and outputs on its right.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Node {
+    pub id: NodeId,
+    pub title: String,
+    /// Top left corner, in graph coordinates
+    #[cfg_attr(feature = "serde", serde(with = "crate::persist::Point"))]
+    pub position: Point,
+    pub inputs: Vec<Port>,
+    pub outputs: Vec<Port>,
+}
+
+impl Node {
+    /// Creates a new [`Node`] without ports.
+    pub fn new(id: NodeId, title: impl Into<String>, position: Point) -> Self {
+        Self {
+            id,
+            title: title.into(),
+            position,
+            inputs: Vec::new(),
+            outputs: Vec::new(),
+        }
+    }
+
+    /// Adds an input.
+    pub fn input(mut self, name: impl Into<String>, kind: impl Into<String>) -> Self {
+        self.inputs.push(Port::new(name, kind));
+        self
+    }
+
+    /// Adds an output.
+    pub fn output(mut self, name: impl Into<String>, kind: impl Into<String>) -> Self {
+        self.outputs.push(Port::new(name, kind));
+        self
+    }
+
+    fn bounds(&self, width: f32) -> Rectangle {
+        let rows = self.inputs.len().max(self.outputs.len()) as f32;
+
+        Rectangle::new(
+            self.position,
+            Size::new(width, HEADER_HEIGHT + rows * ROW_HEIGHT + NODE_PADDING),
+        )
+    }
+
+    fn port_position(&self, width: f32, side: Side, index: usize) -> Point {
+        let y = self.position.y + HEADER_HEIGHT + (index as f32 + 0.5) * ROW_HEIGHT;
+
+        match side {
+            Side::Input => Point::new(self.position.x, y),
+            Side::Output => Point::new(self.position.x + width, y),
+        }
+    }
+}
+
+/// A port of a node, by its index among the node's inputs or outputs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PortRef {
+    pub node: NodeId,
+    pub port: usize,
+}
+
+impl PortRef {
+    /// Creates a new [`PortRef`].
+    pub fn new(node: NodeId, port: usize) -> Self {
+        Self { node, port }
+    }
+}
+
+/// A wire from an output to an input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Connection {
+    pub from: PortRef,
+    pub to: PortRef,
+}
+
+impl Connection {
+    /// Creates a new [`Connection`] from the output `from` to the input `to`.
+    pub fn new(from: PortRef, to: PortRef) -> Self {
+        Self { from, to }
+    }
+}
+
+/// An edit made in a [`NodeGraph`], for the app to apply.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Change {
+    /// Nodes were dragged to new positions
+    Moved(Vec<(NodeId, Point)>),
+    /// A wire was connected
+    Connected(Connection),
+    /// A wire was removed
+    Disconnected(Connection),
+    /// The selection changed
+    Selected(Vec<NodeId>),
+    /// The selected nodes should be deleted, along with their wires
+    Deleted(Vec<NodeId>),
+}
+
+/// Decides whether an output can connect to an input.
+pub type ValidateFn = fn(&Port, &Port) -> bool;
+
+/// An editor for a graph of nodes.
+#[allow(missing_debug_implementations)]
+pub struct NodeGraph<'a, Message, Theme = iced::Theme>
+where
+    Theme: Catalog,
+{
+    nodes: &'a [Node],
+    connections: &'a [Connection],
+    on_change: Box<dyn Fn(Change) -> Message + 'a>,
+    validate: ValidateFn,
+    width: Length,
+    height: Length,
+    node_width: f32,
+    min_zoom: f32,
+    max_zoom: f32,
+    text_size: Pixels,
+    font: iced::Font,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme> NodeGraph<'a, Message, Theme>
+where
+    Theme: Catalog,
+{
+    /// Creates a new [`NodeGraph`] of `nodes` wired by `connections`.
+    pub fn new(nodes: &'a [Node], connections: &'a [Connection], on_change: impl Fn(Change) -> Message + 'a) -> Self {
+        Self {
+            nodes,
+            connections,
+            on_change: Box::new(on_change),
+            validate: |output, input| output.kind == input.kind,
+            width: Length::Fill,
+            height: Length::Fill,
+            node_width: 160.0,
+            min_zoom: 0.25,
+            max_zoom: 4.0,
+            text_size: Pixels(13.0),
+            font: iced::Font::default(),
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets which outputs can connect to which inputs; by default their kinds must be equal.
+    pub fn validate(mut self, validate: ValidateFn) -> Self {
+        self.validate = validate;
+        self
+    }
+
+    /// Sets the width of the editor.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the editor.
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the width of every node.
+    pub fn node_width(mut self, width: f32) -> Self {
+        self.node_width = width;
+        self
+    }
+
+    /// Sets the smallest and largest zoom.
+    pub fn zoom_range(mut self, min: f32, max: f32) -> Self {
+        self.min_zoom = min.max(f32::EPSILON);
+        self.max_zoom = max.max(self.min_zoom);
+        self
+    }
+
+    /// Sets the text size.
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = size.into();
+        self
+    }
+
+    /// Sets the font.
+    pub fn font(mut self, font: iced::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style.
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class.
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    fn node(&self, id: NodeId) -> Option<&'a Node> {
+        self.nodes.iter().find(|node| node.id == id)
+    }
+
+    /// The topmost node at a graph point; later nodes are drawn on top.
+    fn node_at(&self, point: Point) -> Option<&'a Node> {
+        self.nodes
+            .iter()
+            .rev()
+            .find(|node| node.bounds(self.node_width).contains(point))
+    }
+
+    fn port_at(&self, point: Point) -> Option<(Side, PortRef)> {
+        self.nodes.iter().rev().find_map(|node| {
+            let near = |side: Side, index: usize| {
+                node.port_position(self.node_width, side, index).distance(point) <= PORT_HIT_RADIUS
+            };
+
+            (0..node.outputs.len())
+                .find(|&index| near(Side::Output, index))
+                .map(|index| (Side::Output, PortRef::new(node.id, index)))
+                .or_else(|| {
+                    (0..node.inputs.len())
+                        .find(|&index| near(Side::Input, index))
+                        .map(|index| (Side::Input, PortRef::new(node.id, index)))
+                })
+        })
+    }
+
+    fn port(&self, side: Side, port: PortRef) -> Option<&'a Port> {
+        let node = self.node(port.node)?;
+
+        match side {
+            Side::Input => node.inputs.get(port.port),
+            Side::Output => node.outputs.get(port.port),
+        }
+    }
+
+    fn port_position(&self, side: Side, port: PortRef) -> Option<Point> {
+        self.node(port.node)
+            .map(|node| node.port_position(self.node_width, side, port.port))
+    }
+
+    /// The connection a wire dragged from `anchor` would make when dropped at `point`.
+    fn connection_to(&self, anchor: (Side, PortRef), point: Point) -> Option<Connection> {
+        let (side, target) = self.port_at(point)?;
+        let connection = match (anchor.0, side) {
+            (Side::Output, Side::Input) => Connection::new(anchor.1, target),
+            (Side::Input, Side::Output) => Connection::new(target, anchor.1),
+            _ => return None,
+        };
+
+        let output = self.port(Side::Output, connection.from)?;
+        let input = self.port(Side::Input, connection.to)?;
+
+        (connection.from.node != connection.to.node
+            && !self.connections.contains(&connection)
+            && (self.validate)(output, input))
+        .then_some(connection)
+    }
+
+    fn select(&self, state: &mut State, selection: HashSet<NodeId>, shell: &mut Shell<'_, Message>) {
+        if selection != state.selection {
+            state.selection = selection;
+
+            let mut ids: Vec<NodeId> = state.selection.iter().copied().collect();
+            ids.sort_unstable();
+            shell.publish((self.on_change)(Change::Selected(ids)));
+        }
+    }
+
+    fn to_graph(state: &State, bounds: Rectangle, position: Point) -> Point {
+        state
+            .transform
+            .to_content(Point::new(position.x - bounds.x, position.y - bounds.y))
+    }
+
+    fn text(&self, content: &str, bounds: Size, align_x: text::Alignment) -> iced::advanced::Text<String, iced::Font> {
+        iced::advanced::Text {
+            content: content.to_owned(),
+            bounds,
+            size: self.text_size,
+            font: self.font,
+            align_x,
+            align_y: alignment::Vertical::Center,
+            line_height: text::LineHeight::default(),
+            shaping: text::Shaping::Advanced,
+            wrapping: text::Wrapping::None,
+        }
+    }
+}
+
+/// Which side of a node a port is on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Side {
+    Input,
+    Output,
+}
+
+#[derive(Debug, Clone, Default)]
+enum Drag {
+    #[default]
+    None,
+    /// Pressed on a node, moving it once the cursor passes the threshold
+    Nodes {
+        start: Point,
+        origins: Vec<(NodeId, Point)>,
+        moved: bool,
+    },
+    /// A wire hanging from a port, the cursor in graph coordinates
+    Wire { anchor: (Side, PortRef), cursor: Point },
+    /// A selection box between two graph points
+    Select { start: Point, current: Point, additive: bool },
+    /// Panning, with the last screen position
+    Pan { last: Point },
+}
+
+#[derive(Debug, Default)]
+struct State {
+    transform: Transform,
+    selection: HashSet<NodeId>,
+    drag: Drag,
+    modifiers: keyboard::Modifiers,
+    is_focused: bool,
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for NodeGraph<'_, Message, Theme>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer<Font = iced::Font>,
+{
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(State::default())
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        // Forget nodes the app has removed
+        let state = tree.state.downcast_mut::<State>();
+        state.selection.retain(|id| self.nodes.iter().any(|node| node.id == *id));
+    }
+
+    fn layout(&mut self, _tree: &mut Tree, _renderer: &Renderer, limits: &Limits) -> LayoutNode {
+        LayoutNode::new(limits.resolve(self.width, self.height, Size::ZERO))
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.modifiers = *modifiers;
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+                let Some(position) = cursor.position_in(bounds) else {
+                    return;
+                };
+                let notches = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => *y,
+                    mouse::ScrollDelta::Pixels { y, .. } => y / 50.0,
+                };
+                let scale = (state.transform.scale * 1.1_f32.powf(notches)).clamp(self.min_zoom, self.max_zoom);
+
+                state.transform = state.transform.zoom_at(position, scale);
+                shell.capture_event();
+                shell.request_redraw();
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Middle)) => {
+                if let Some(position) = cursor.position_over(bounds) {
+                    state.drag = Drag::Pan { last: position };
+                    shell.capture_event();
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    state.is_focused = false;
+                    return;
+                };
+                let point = Self::to_graph(state, bounds, position);
+                state.is_focused = true;
+
+                if let Some((side, port)) = self.port_at(point) {
+                    // Pulling a wire off an input picks it up by its output
+                    let attached = (side == Side::Input)
+                        .then(|| self.connections.iter().find(|connection| connection.to == port))
+                        .flatten();
+
+                    state.drag = match attached {
+                        Some(connection) => {
+                            shell.publish((self.on_change)(Change::Disconnected(*connection)));
+                            Drag::Wire {
+                                anchor: (Side::Output, connection.from),
+                                cursor: point,
+                            }
+                        }
+                        None => Drag::Wire {
+                            anchor: (side, port),
+                            cursor: point,
+                        },
+                    };
+                } else if let Some(node) = self.node_at(point) {
+                    let mut selection = state.selection.clone();
+
+                    if state.modifiers.shift() {
+                        if !selection.remove(&node.id) {
+                            selection.insert(node.id);
+                        }
+                    } else if !selection.contains(&node.id) {
+                        selection = HashSet::from([node.id]);
+                    }
+                    self.select(state, selection, shell);
+
+                    state.drag = Drag::Nodes {
+                        start: point,
+                        origins: self
+                            .nodes
+                            .iter()
+                            .filter(|node| state.selection.contains(&node.id))
+                            .map(|node| (node.id, node.position))
+                            .collect(),
+                        moved: false,
+                    };
+                } else {
+                    state.drag = Drag::Select {
+                        start: point,
+                        current: point,
+                        additive: state.modifiers.shift(),
+                    };
+                }
+
+                shell.capture_event();
+                shell.request_redraw();
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                let point = Self::to_graph(state, bounds, *position);
+
+                match &mut state.drag {
+                    Drag::None => return,
+                    Drag::Pan { last } => {
+                        let delta = *position - *last;
+                        *last = *position;
+                        state.transform.offset += delta;
+                    }
+                    Drag::Nodes { start, origins, moved } => {
+                        let delta = point - *start;
+
+                        if !*moved && delta.x.hypot(delta.y) * state.transform.scale < DRAG_THRESHOLD {
+                            return;
+                        }
+                        *moved = true;
+
+                        // From where the drag started, so events the app has yet to apply don't add up
+                        let positions = origins.iter().map(|(id, origin)| (*id, *origin + delta)).collect();
+                        shell.publish((self.on_change)(Change::Moved(positions)));
+                    }
+                    Drag::Wire { cursor, .. } => *cursor = point,
+                    Drag::Select { current, .. } => *current = point,
+                }
+
+                shell.request_redraw();
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(button)) => {
+                match (std::mem::take(&mut state.drag), button) {
+                    (Drag::Wire { anchor, cursor }, mouse::Button::Left) => {
+                        if let Some(connection) = self.connection_to(anchor, cursor) {
+                            // Inputs take a single wire
+                            if let Some(existing) = self.connections.iter().find(|existing| existing.to == connection.to) {
+                                shell.publish((self.on_change)(Change::Disconnected(*existing)));
+                            }
+                            shell.publish((self.on_change)(Change::Connected(connection)));
+                        }
+                    }
+                    (Drag::Select { start, current, additive }, mouse::Button::Left) => {
+                        let area = Rectangle::new(
+                            Point::new(start.x.min(current.x), start.y.min(current.y)),
+                            Size::new((start.x - current.x).abs(), (start.y - current.y).abs()),
+                        );
+                        let mut selection = if additive { state.selection.clone() } else { HashSet::new() };
+
+                        selection.extend(
+                            self.nodes
+                                .iter()
+                                .filter(|node| node.bounds(self.node_width).intersects(&area))
+                                .map(|node| node.id),
+                        );
+                        self.select(state, selection, shell);
+                    }
+                    (Drag::Pan { .. }, mouse::Button::Middle) | (Drag::Nodes { .. }, mouse::Button::Left) => {}
+                    // Released some other button, keep going
+                    (drag, _) => {
+                        state.drag = drag;
+                        return;
+                    }
+                }
+
+                shell.request_redraw();
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed { key, modifiers, .. }) if state.is_focused => {
+                match key {
+                    keyboard::Key::Named(keyboard::key::Named::Delete | keyboard::key::Named::Backspace) => {
+                        if state.selection.is_empty() {
+                            return;
+                        }
+
+                        let mut ids: Vec<NodeId> = state.selection.drain().collect();
+                        ids.sort_unstable();
+                        shell.publish((self.on_change)(Change::Deleted(ids)));
+                    }
+                    keyboard::Key::Named(keyboard::key::Named::Escape) => {
+                        if matches!(state.drag, Drag::None) {
+                            self.select(state, HashSet::new(), shell);
+                        } else {
+                            state.drag = Drag::None;
+                        }
+                    }
+                    keyboard::Key::Character(c) if modifiers.command() && c.as_str() == "a" => {
+                        self.select(state, self.nodes.iter().map(|node| node.id).collect(), shell);
+                    }
+                    _ => return,
+                }
+
+                shell.capture_event();
+                shell.request_redraw();
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _defaults: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let style = theme.style(&self.class);
+        let Some(clip) = bounds.intersection(viewport) else {
+            return;
+        };
+
+        renderer.with_layer(clip, |renderer| {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: pixel::snap(bounds),
+                    ..renderer::Quad::default()
+                },
+                style.background,
+            );
+            draw_grid(renderer, bounds, state.transform, style.grid);
+
+            renderer.with_transformation(state.transform.transformation(bounds.position()), |renderer| {
+                // Graph coordinates start at the top left corner of the editor
+                let origin = Vector::new(bounds.x, bounds.y);
+
+                for connection in self.connections {
+                    let (Some(from), Some(to)) = (
+                        self.port_position(Side::Output, connection.from),
+                        self.port_position(Side::Input, connection.to),
+                    ) else {
+                        continue;
+                    };
+                    let color = self
+                        .port(Side::Output, connection.from)
+                        .map_or(style.wire, |port| kind_color(&port.kind, &style));
+
+                    draw_wire(renderer, from + origin, to + origin, color);
+                }
+
+                if let Drag::Wire { anchor, cursor: end } = &state.drag
+                    && let Some(start) = self.port_position(anchor.0, anchor.1)
+                {
+                    let color = match (self.port_at(*end), self.connection_to(*anchor, *end)) {
+                        (_, Some(_)) => style.valid_wire,
+                        (Some(_), None) => style.invalid_wire,
+                        (None, None) => style.wire,
+                    };
+                    let (from, to) = match anchor.0 {
+                        Side::Output => (start, *end),
+                        Side::Input => (*end, start),
+                    };
+
+                    draw_wire(renderer, from + origin, to + origin, color);
+                }
+
+                let hovered = cursor
+                    .position_over(bounds)
+                    .map(|position| Self::to_graph(state, bounds, position));
+
+                for node in self.nodes {
+                    self.draw_node(renderer, &style, node, origin, state.selection.contains(&node.id), hovered);
+                }
+            });
+
+            if let Drag::Select { start, current, .. } = &state.drag {
+                let to_screen = |point: Point| {
+                    let point = state.transform.to_viewport(point);
+                    Point::new(point.x + bounds.x, point.y + bounds.y)
+                };
+                let (start, current) = (to_screen(*start), to_screen(*current));
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: pixel::snap(Rectangle::new(
+                            Point::new(start.x.min(current.x), start.y.min(current.y)),
+                            Size::new((start.x - current.x).abs(), (start.y - current.y).abs()),
+                        )),
+                        border: pixel::border(style.selection_border),
+                        ..renderer::Quad::default()
+                    },
+                    style.selection_background,
+                );
+            }
+        });
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+
+        match &state.drag {
+            Drag::Pan { .. } => return mouse::Interaction::Grabbing,
+            Drag::Nodes { moved: true, .. } => return mouse::Interaction::Grabbing,
+            Drag::Wire { .. } | Drag::Select { .. } => return mouse::Interaction::Crosshair,
+            Drag::None | Drag::Nodes { .. } => {}
+        }
+
+        let Some(position) = cursor.position_over(bounds) else {
+            return mouse::Interaction::None;
+        };
+        let point = Self::to_graph(state, bounds, position);
+
+        if self.port_at(point).is_some() {
+            mouse::Interaction::Crosshair
+        } else if self.node_at(point).is_some() {
+            mouse::Interaction::Grab
+        } else {
+            mouse::Interaction::Idle
+        }
+    }
+}
+
+impl<Message, Theme> NodeGraph<'_, Message, Theme>
+where
+    Theme: Catalog,
+{
+    fn draw_node<Renderer>(
+        &self,
+        renderer: &mut Renderer,
+        style: &Style,
+        node: &Node,
+        origin: Vector,
+        selected: bool,
+        hovered: Option<Point>,
+    ) where
+        Renderer: text::Renderer<Font = iced::Font>,
+    {
+        let bounds = node.bounds(self.node_width) + origin;
+        let radius = style.node_border.radius;
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: pixel::snap(bounds),
+                border: pixel::border(if selected { style.selected_border } else { style.node_border }),
+                shadow: style.node_shadow,
+                snap: false,
+            },
+            style.node_background,
+        );
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: pixel::snap(Rectangle {
+                    height: HEADER_HEIGHT,
+                    ..bounds
+                }),
+                border: pixel::border(Border {
+                    radius: iced::border::Radius {
+                        bottom_left: 0.0,
+                        bottom_right: 0.0,
+                        ..radius
+                    },
+                    ..Border::default()
+                }),
+                ..renderer::Quad::default()
+            },
+            style.header_background,
+        );
+
+        let title = Rectangle {
+            x: bounds.x + NODE_PADDING * 2.0,
+            width: bounds.width - NODE_PADDING * 4.0,
+            height: HEADER_HEIGHT,
+            ..bounds
+        };
+        renderer.fill_text(
+            self.text(&node.title, title.size(), text::Alignment::Left),
+            Point::new(title.x, title.center_y()),
+            style.title,
+            title,
+        );
+
+        let label_width = bounds.width / 2.0 - PORT_LABEL_GAP;
+        let ports = node
+            .inputs
+            .iter()
+            .enumerate()
+            .map(|(index, port)| (Side::Input, index, port))
+            .chain(node.outputs.iter().enumerate().map(|(index, port)| (Side::Output, index, port)));
+
+        for (side, index, port) in ports {
+            let center = node.port_position(self.node_width, side, index) + origin;
+            let is_hovered = hovered.is_some_and(|point| point.distance(center - origin) <= PORT_HIT_RADIUS);
+            let radius = if is_hovered { PORT_RADIUS + 1.5 } else { PORT_RADIUS };
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: pixel::snap(Rectangle::new(
+                        Point::new(center.x - radius, center.y - radius),
+                        Size::new(radius * 2.0, radius * 2.0),
+                    )),
+                    border: pixel::border(Border {
+                        color: style.node_background_color(),
+                        width: 1.5,
+                        radius: radius.into(),
+                    }),
+                    ..renderer::Quad::default()
+                },
+                kind_color(&port.kind, style),
+            );
+
+            let (x, align) = match side {
+                Side::Input => (center.x + PORT_LABEL_GAP, text::Alignment::Left),
+                Side::Output => (center.x - PORT_LABEL_GAP, text::Alignment::Right),
+            };
+            let area = Rectangle {
+                x: if side == Side::Input { x } else { x - label_width },
+                y: center.y - ROW_HEIGHT / 2.0,
+                width: label_width,
+                height: ROW_HEIGHT,
+            };
+
+            renderer.fill_text(
+                self.text(&port.name, area.size(), align),
+                Point::new(x, center.y),
+                style.port_text,
+                area,
+            );
+        }
+    }
+}
+
+/// Grid lines across the editor, following the pan and zoom.
+fn draw_grid<Renderer: renderer::Renderer>(renderer: &mut Renderer, bounds: Rectangle, transform: Transform, color: Color) {
+    let spacing = GRID_SPACING * transform.scale;
+
+    // Too dense to be useful when zoomed far out
+    if spacing < 8.0 || color.a == 0.0 {
+        return;
+    }
+
+    let line = |renderer: &mut Renderer, bounds: Rectangle| {
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: pixel::snap(bounds),
+                ..renderer::Quad::default()
+            },
+            color,
+        );
+    };
+
+    let mut x = bounds.x + transform.offset.x.rem_euclid(spacing);
+    while x < bounds.x + bounds.width {
+        line(renderer, Rectangle::new(Point::new(x, bounds.y), Size::new(1.0, bounds.height)));
+        x += spacing;
+    }
+
+    let mut y = bounds.y + transform.offset.y.rem_euclid(spacing);
+    while y < bounds.y + bounds.height {
+        line(renderer, Rectangle::new(Point::new(bounds.x, y), Size::new(bounds.width, 1.0)));
+        y += spacing;
+    }
+}
+
+/// A horizontal bezier from an output to an input, drawn as a run of round dots.
+fn draw_wire<Renderer: renderer::Renderer>(renderer: &mut Renderer, from: Point, to: Point, color: Color) {
+    let reach = ((to.x - from.x).abs() / 2.0).max(40.0);
+    let control_a = Point::new(from.x + reach, from.y);
+    let control_b = Point::new(to.x - reach, to.y);
+
+    let length = from.distance(control_a) + control_a.distance(control_b) + control_b.distance(to);
+    let steps = ((length / (WIRE_WIDTH * 0.4)).ceil() as usize).clamp(8, 2000);
+
+    for step in 0..=steps {
+        let t = step as f32 / steps as f32;
+        let u = 1.0 - t;
+        let weights = [u * u * u, 3.0 * u * u * t, 3.0 * u * t * t, t * t * t];
+        let point = [from, control_a, control_b, to]
+            .iter()
+            .zip(weights)
+            .fold(Point::ORIGIN, |sum, (point, weight)| {
+                Point::new(sum.x + point.x * weight, sum.y + point.y * weight)
+            });
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: pixel::snap(Rectangle::new(
+                    Point::new(point.x - WIRE_WIDTH / 2.0, point.y - WIRE_WIDTH / 2.0),
+                    Size::new(WIRE_WIDTH, WIRE_WIDTH),
+                )),
+                border: pixel::border(Border {
+                    radius: (WIRE_WIDTH / 2.0).into(),
+                    ..Border::default()
+                }),
+                ..renderer::Quad::default()
+            },
+            color,
+        );
+    }
+}
+
+/// A stable color for each port kind, picked from the style's palette.
+fn kind_color(kind: &str, style: &Style) -> Color {
+    let hash = kind
+        .bytes()
+        .fold(0_u32, |hash, byte| hash.wrapping_mul(31).wrapping_add(u32::from(byte)));
+
+    style.port_colors[hash as usize % style.port_colors.len()]
+}
+
+impl<'a, Message, Theme, Renderer> From<NodeGraph<'a, Message, Theme>> for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer<Font = iced::Font> + 'a,
+{
+    fn from(graph: NodeGraph<'a, Message, Theme>) -> Self {
+        Element::new(graph)
+    }
+}
+
+/// The theme catalog of a [`NodeGraph`].
+pub trait Catalog {
+    /// The style class
+    type Class<'a>;
+
+    /// Default style
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// Get the style for a class
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// The appearance of a [`NodeGraph`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// Background of the editor
+    pub background: Background,
+    /// Color of the grid lines, transparent to hide them
+    pub grid: Color,
+    /// Background of nodes
+    pub node_background: Background,
+    /// Border of nodes
+    pub node_border: Border,
+    /// Border of selected nodes
+    pub selected_border: Border,
+    /// Shadow of nodes
+    pub node_shadow: Shadow,
+    /// Background of node headers
+    pub header_background: Background,
+    /// Color of node titles
+    pub title: Color,
+    /// Color of port names
+    pub port_text: Color,
+    /// Colors of ports and their wires, picked by port kind
+    pub port_colors: [Color; 6],
+    /// Color of a dragged wire over empty space
+    pub wire: Color,
+    /// Color of a dragged wire over a port it can connect to
+    pub valid_wire: Color,
+    /// Color of a dragged wire over a port it cannot connect to
+    pub invalid_wire: Color,
+    /// Background of the selection box
+    pub selection_background: Background,
+    /// Border of the selection box
+    pub selection_border: Border,
+}
+
+impl Style {
+    /// The node background as a color, for the ring around ports.
+    fn node_background_color(&self) -> Color {
+        match self.node_background {
+            Background::Color(color) => color,
+            Background::Gradient(_) => Color::BLACK,
+        }
+    }
+}
+
+/// Styling function
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for iced::Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default node graph style.
+pub fn default(theme: &iced::Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: palette.background.weakest.color.into(),
+        grid: palette.background.weak.color.scale_alpha(0.5),
+        node_background: palette.background.base.color.into(),
+        node_border: Border {
+            color: palette.background.strong.color,
+            width: 1.0,
+            radius: 6.0.into(),
+        },
+        selected_border: Border {
+            color: palette.primary.strong.color,
+            width: 2.0,
+            radius: 6.0.into(),
+        },
+        node_shadow: Shadow {
+            color: Color::BLACK.scale_alpha(0.3),
+            offset: Vector::new(0.0, 2.0),
+            blur_radius: 6.0,
+        },
+        header_background: palette.background.strong.color.into(),
+        title: palette.background.strong.text,
+        port_text: palette.background.base.text,
+        port_colors: [
+            palette.primary.base.color,
+            palette.success.base.color,
+            palette.warning.base.color,
+            palette.danger.base.color,
+            palette.secondary.base.color,
+            Color::from_rgb(0.6, 0.4, 0.9),
+        ],
+        wire: palette.background.strong.text,
+        valid_wire: palette.success.strong.color,
+        invalid_wire: palette.danger.strong.color,
+        selection_background: palette.primary.weak.color.scale_alpha(0.2).into(),
+        selection_border: Border {
+            color: palette.primary.strong.color,
+            width: 1.0,
+            radius: 0.0.into(),
+        },
+    }
+}
+
+/// No grid and flat nodes.
+pub fn plain(theme: &iced::Theme) -> Style {
+    Style {
+        grid: Color::TRANSPARENT,
+        node_shadow: Shadow::default(),
+        ..default(theme)
+    }
+}
@@ -0,0 +1,91 @@
+//! Operations on the state of one widget, found by its Id.
+
+use std::marker::PhantomData;
+
+use iced::advanced::widget::{self, operation::Outcome, Operation};
+use iced::Rectangle;
+
+/// Runs `f` on the state of the widget with `id`, if its state is an `S`.
+pub(crate) fn on_state<S, T>(id: widget::Id, f: impl FnMut(&mut S) + Send + 'static) -> impl Operation<T>
+where
+    S: 'static,
+{
+    struct OnState<S, F> {
+        id: widget::Id,
+        f: F,
+        state: PhantomData<fn(&mut S)>,
+    }
+
+    impl<S: 'static, F: FnMut(&mut S) + Send, T> Operation<T> for OnState<S, F> {
+        fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation<T>)) {
+            operate(self);
+        }
+
+        fn custom(
+            &mut self,
+            id: Option<&widget::Id>,
+            _bounds: Rectangle,
+            state: &mut dyn std::any::Any,
+        ) {
+            if id == Some(&self.id)
+                && let Some(state) = state.downcast_mut::<S>()
+            {
+                (self.f)(state);
+            }
+        }
+    }
+
+    OnState {
+        id,
+        f,
+        state: PhantomData,
+    }
+}
+
+/// Reads `f` off the state of the widget with `id`, if its state is an `S`, as the outcome.
+#[cfg_attr(not(feature = "tree"), allow(dead_code))]
+pub(crate) fn query<S, T>(id: widget::Id, f: impl Fn(&S) -> T + Send + 'static) -> impl Operation<T>
+where
+    S: 'static,
+    T: Clone + Send + 'static,
+{
+    struct Query<S, F, T> {
+        id: widget::Id,
+        f: F,
+        value: Option<T>,
+        state: PhantomData<fn(&S)>,
+    }
+
+    impl<S: 'static, F: Fn(&S) -> T + Send, T: Clone + Send> Operation<T> for Query<S, F, T> {
+        fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation<T>)) {
+            operate(self);
+        }
+
+        fn custom(
+            &mut self,
+            id: Option<&widget::Id>,
+            _bounds: Rectangle,
+            state: &mut dyn std::any::Any,
+        ) {
+            if id == Some(&self.id)
+                && let Some(state) = state.downcast_ref::<S>()
+            {
+                self.value = Some((self.f)(state));
+            }
+        }
+
+        fn finish(&self) -> Outcome<T> {
+            match &self.value {
+                Some(value) => Outcome::Some(value.clone()),
+                None => Outcome::None,
+            }
+        }
+    }
+
+    Query {
+        id,
+        f,
+        value: None,
+        state: PhantomData,
+    }
+}
@@ -0,0 +1,73 @@
+//! Serde definitions for the iced types held by the persistable widget states.
+//!
+//! Used through `#[serde(with = "...")]` on the fields, since iced itself only derives serde
+//! for a few of its types.
+
+#![allow(dead_code)] // Each definition is only used when a widget holding that type is enabled
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "iced::Point")]
+pub(crate) struct Point {
+    pub x: f32,
+    pub y: f32,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "iced::Vector")]
+pub(crate) struct Vector {
+    pub x: f32,
+    pub y: f32,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "iced::Rectangle")]
+pub(crate) struct Rectangle {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "iced::Color")]
+pub(crate) struct Color {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+    pub a: f32,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "iced::Radians")]
+pub(crate) struct Radians(pub f32);
+
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "iced::gradient::ColorStop")]
+pub(crate) struct ColorStop {
+    pub offset: f32,
+    #[serde(with = "Color")]
+    pub color: iced::Color,
+}
+
+/// A list of [`iced::gradient::ColorStop`]s.
+pub(crate) mod color_stops {
+    use super::*;
+
+    #[derive(Serialize, Deserialize)]
+    struct Stop(#[serde(with = "ColorStop")] iced::gradient::ColorStop);
+
+    pub(crate) fn serialize<S: Serializer>(stops: &[iced::gradient::ColorStop], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(stops.iter().map(|stop| Stop(*stop)))
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<iced::gradient::ColorStop>, D::Error> {
+        Ok(Vec::<Stop>::deserialize(deserializer)?
+            .into_iter()
+            .map(|Stop(stop)| stop)
+            .collect())
+    }
+}
@@ -0,0 +1,606 @@
+//! A free-form board of cards placed anywhere and dragged around.
+//!
+//! A [`Pinboard`] lays its cards out at the positions the app gives them, at their own size.
+//! Pressing a card raises it above the others; dragging it from anywhere its content does not
+//! take the press itself (so buttons on a card still work) moves it, snapping to a grid if one
+//! is set. The new position is reported through the `on_move` message while dragging,
+//! for the app to store.
+//!
+//! The stacking order lives in the board: new cards go on top, and raising one is reported
+//! through [`Pinboard::on_raise`] for apps that keep it.
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        mouse, overlay, renderer,
+        widget::{self, tree::Tree, Operation},
+        Clipboard, Layout, Shell, Widget,
+    },
+    Background, Border, Color, Element, Event, Length, Point, Rectangle, Shadow, Size, Vector,
+};
+
+use crate::pixel;
+
+/// Creates a new empty [`Pinboard`], producing `on_move` while a card is dragged.
+pub fn pinboard<'a, Id, Message, Theme, Renderer>(
+    on_move: impl Fn(Id, Point) -> Message + 'a,
+) -> Pinboard<'a, Id, Message, Theme, Renderer>
+where
+    Id: Clone + Eq + 'static,
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    Pinboard::new(on_move)
+}
+
+struct Card<'a, Id, Message, Theme, Renderer> {
+    id: Id,
+    position: Point,
+    content: Element<'a, Message, Theme, Renderer>,
+}
+
+/// A board of cards at absolute positions that can be dragged around.
+#[allow(missing_debug_implementations)]
+pub struct Pinboard<'a, Id, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Theme: Catalog,
+{
+    cards: Vec<Card<'a, Id, Message, Theme, Renderer>>,
+    on_move: Box<dyn Fn(Id, Point) -> Message + 'a>,
+    on_raise: Option<Box<dyn Fn(Id) -> Message + 'a>>,
+    grid: Option<f32>,
+    bounded: bool,
+    width: Length,
+    height: Length,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Id, Message, Theme, Renderer> Pinboard<'a, Id, Message, Theme, Renderer>
+where
+    Id: Clone + Eq + 'static,
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    /// Creates a new empty [`Pinboard`], producing `on_move` while a card is dragged.
+    pub fn new(on_move: impl Fn(Id, Point) -> Message + 'a) -> Self {
+        Self {
+            cards: Vec::new(),
+            on_move: Box::new(on_move),
+            on_raise: None,
+            grid: None,
+            bounded: true,
+            width: Length::Fill,
+            height: Length::Fill,
+            class: Theme::default(),
+        }
+    }
+
+    /// Adds a card with its top left corner at `position`, relative to the board.
+    pub fn push(
+        mut self,
+        id: Id,
+        position: impl Into<Point>,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        self.cards.push(Card {
+            id,
+            position: position.into(),
+            content: content.into(),
+        });
+        self
+    }
+
+    /// Sets the message produced when a card is raised above the others.
+    pub fn on_raise(mut self, on_raise: impl Fn(Id) -> Message + 'a) -> Self {
+        self.on_raise = Some(Box::new(on_raise));
+        self
+    }
+
+    /// Snaps dragged cards to a grid of `size`, drawn as dots behind them.
+    pub fn grid(mut self, size: f32) -> Self {
+        self.grid = (size > 0.0).then_some(size);
+        self
+    }
+
+    /// Sets whether cards are kept within the board, which they are by default.
+    pub fn bounded(mut self, bounded: bool) -> Self {
+        self.bounded = bounded;
+        self
+    }
+
+    /// Sets the width of the board.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the board.
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the style.
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class.
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// The indices of the cards from the bottom of the stack to the top.
+    fn stack(&self, state: &State<Id>) -> Vec<usize> {
+        let mut indices: Vec<usize> = (0..self.cards.len()).collect();
+        indices.sort_by_key(|index| {
+            state
+                .order
+                .iter()
+                .position(|id| *id == self.cards[*index].id)
+                .unwrap_or(usize::MAX)
+        });
+        indices
+    }
+
+    /// The topmost card under `position`.
+    fn card_at(&self, state: &State<Id>, layouts: &[Layout<'_>], position: Point) -> Option<usize> {
+        self.stack(state)
+            .into_iter()
+            .rev()
+            .find(|index| layouts[*index].bounds().contains(position))
+    }
+
+    /// Where a card of `size` goes when its corner is dragged to `corner`.
+    fn place(&self, corner: Point, size: Size, board: Size) -> Point {
+        let mut corner = match self.grid {
+            Some(grid) => Point::new((corner.x / grid).round() * grid, (corner.y / grid).round() * grid),
+            None => corner,
+        };
+
+        if self.bounded {
+            corner.x = corner.x.clamp(0.0, (board.width - size.width).max(0.0));
+            corner.y = corner.y.clamp(0.0, (board.height - size.height).max(0.0));
+        }
+
+        corner
+    }
+}
+
+#[derive(Debug)]
+struct State<Id> {
+    /// Card ids from the bottom of the stack to the top
+    order: Vec<Id>,
+    drag: Option<Drag<Id>>,
+}
+
+#[derive(Debug, Clone)]
+struct Drag<Id> {
+    id: Id,
+    /// Where the card was grabbed, from its corner
+    grab: Vector,
+    /// Where the card is while dragged, relative to the board
+    position: Point,
+}
+
+impl<Id, Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Pinboard<'_, Id, Message, Theme, Renderer>
+where
+    Id: Clone + Eq + 'static,
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<State<Id>>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(State::<Id> {
+            order: self.cards.iter().map(|card| card.id.clone()).collect(),
+            drag: None,
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.cards.iter().map(|card| Tree::new(&card.content)).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        // Raising a card only changes the stack here, so card trees keep the app's order
+        let state = tree.state.downcast_mut::<State<Id>>();
+        let ids: Vec<&Id> = self.cards.iter().map(|card| &card.id).collect();
+        state.order.retain(|id| ids.contains(&id));
+        for id in ids {
+            if !state.order.contains(id) {
+                state.order.push(id.clone());
+            }
+        }
+
+        let contents: Vec<_> = self.cards.iter().map(|card| &card.content).collect();
+        tree.diff_children(&contents);
+    }
+
+    fn layout(&mut self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let size = limits.resolve(self.width, self.height, Size::ZERO);
+        let drag = tree.state.downcast_ref::<State<Id>>().drag.clone();
+
+        let children = self
+            .cards
+            .iter_mut()
+            .zip(&mut tree.children)
+            .map(|(card, tree)| {
+                let node = card
+                    .content
+                    .as_widget_mut()
+                    .layout(tree, renderer, &Limits::new(Size::ZERO, size));
+                let position = match &drag {
+                    Some(drag) if drag.id == card.id => drag.position,
+                    _ => card.position,
+                };
+                let position = if self.bounded {
+                    Point::new(
+                        position.x.clamp(0.0, (size.width - node.size().width).max(0.0)),
+                        position.y.clamp(0.0, (size.height - node.size().height).max(0.0)),
+                    )
+                } else {
+                    position
+                };
+
+                node.move_to(position)
+            })
+            .collect();
+
+        Node::with_children(size, children)
+    }
+
+    fn operate(&mut self, tree: &mut Tree, layout: Layout<'_>, renderer: &Renderer, operation: &mut dyn Operation) {
+        operation.container(None, layout.bounds());
+        operation.traverse(&mut |operation| {
+            for ((card, tree), layout) in self.cards.iter_mut().zip(&mut tree.children).zip(layout.children()) {
+                card.content.as_widget_mut().operate(tree, layout, renderer, operation);
+            }
+        });
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let layouts: Vec<Layout<'_>> = layout.children().collect();
+        let state = tree.state.downcast_mut::<State<Id>>();
+
+        if let Some(drag) = &state.drag {
+            match event {
+                Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                    let Some(index) = self.cards.iter().position(|card| card.id == drag.id) else {
+                        state.drag = None;
+                        return;
+                    };
+                    let corner = *position - bounds.position() - drag.grab;
+                    let placed = self.place(Point::new(corner.x, corner.y), layouts[index].bounds().size(), bounds.size());
+
+                    if placed != drag.position {
+                        shell.publish((self.on_move)(drag.id.clone(), placed));
+                        if let Some(drag) = &mut state.drag {
+                            drag.position = placed;
+                        }
+                        shell.invalidate_layout();
+                        shell.request_redraw();
+                    }
+                    shell.capture_event();
+                    return;
+                }
+                Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                    state.drag = None;
+                    shell.capture_event();
+                    shell.request_redraw();
+                    return;
+                }
+                _ => {}
+            }
+        }
+
+        // Only the topmost card under the cursor sees it, cards below are covered
+        let top = cursor.position().and_then(|position| self.card_at(state, &layouts, position));
+        for index in self.stack(state).into_iter().rev() {
+            let card_cursor = if Some(index) == top {
+                cursor
+            } else {
+                mouse::Cursor::Unavailable
+            };
+
+            self.cards[index].content.as_widget_mut().update(
+                &mut tree.children[index],
+                event,
+                layouts[index],
+                card_cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            );
+        }
+
+        if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) = event
+            && let Some(index) = top
+            && let Some(position) = cursor.position()
+        {
+            let id = self.cards[index].id.clone();
+
+            if state.order.last() != Some(&id) {
+                state.order.retain(|other| *other != id);
+                state.order.push(id.clone());
+
+                if let Some(on_raise) = &self.on_raise {
+                    shell.publish(on_raise(id.clone()));
+                }
+                shell.request_redraw();
+            }
+
+            // A press the content took for itself, like a button, does not drag the card
+            if !shell.is_event_captured() {
+                let card = layouts[index].bounds();
+
+                state.drag = Some(Drag {
+                    id,
+                    grab: position - card.position(),
+                    position: Point::ORIGIN + (card.position() - bounds.position()),
+                });
+                shell.capture_event();
+            }
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        defaults: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_ref::<State<Id>>();
+        let style = theme.style(&self.class);
+        let layouts: Vec<Layout<'_>> = layout.children().collect();
+        let Some(visible) = bounds.intersection(viewport) else {
+            return;
+        };
+
+        if let Some(background) = style.background {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: pixel::snap(bounds),
+                    ..renderer::Quad::default()
+                },
+                background,
+            );
+        }
+
+        if let Some(grid) = self.grid
+            && style.grid.a > 0.0
+        {
+            let first = Point::new(
+                ((visible.x - bounds.x) / grid).ceil() * grid,
+                ((visible.y - bounds.y) / grid).ceil() * grid,
+            );
+            let mut y = first.y;
+
+            while bounds.y + y <= visible.y + visible.height {
+                let mut x = first.x;
+
+                while bounds.x + x <= visible.x + visible.width {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: pixel::snap(Rectangle::new(Point::new(bounds.x + x - 1.0, bounds.y + y - 1.0), Size::new(2.0, 2.0))),
+                            ..renderer::Quad::default()
+                        },
+                        style.grid,
+                    );
+                    x += grid;
+                }
+                y += grid;
+            }
+        }
+
+        let top = cursor.position().and_then(|position| self.card_at(state, &layouts, position));
+        let dragged = state.drag.as_ref().map(|drag| &drag.id);
+
+        // Each card gets a layer so a card above covers the text of the ones below
+        for index in self.stack(state) {
+            let card = &self.cards[index];
+            let card_bounds = layouts[index].bounds();
+            let is_dragged = dragged == Some(&card.id);
+            let shadow_room = if is_dragged {
+                style.drag_shadow.blur_radius * 2.0 + style.drag_shadow.offset.x.abs().max(style.drag_shadow.offset.y.abs())
+            } else {
+                0.0
+            };
+            let Some(clip) = card_bounds.expand(shadow_room).intersection(&visible) else {
+                continue;
+            };
+
+            renderer.with_layer(clip, |renderer| {
+                if is_dragged {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: pixel::snap(card_bounds),
+                            border: pixel::border(Border {
+                                radius: style.radius.into(),
+                                ..Border::default()
+                            }),
+                            shadow: style.drag_shadow,
+                            ..renderer::Quad::default()
+                        },
+                        Color::TRANSPARENT,
+                    );
+                }
+
+                card.content.as_widget().draw(
+                    &tree.children[index],
+                    renderer,
+                    theme,
+                    defaults,
+                    layouts[index],
+                    if Some(index) == top { cursor } else { mouse::Cursor::Unavailable },
+                    &clip,
+                );
+            });
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State<Id>>();
+
+        if state.drag.is_some() {
+            return mouse::Interaction::Grabbing;
+        }
+
+        let layouts: Vec<Layout<'_>> = layout.children().collect();
+        let Some(index) = cursor.position().and_then(|position| self.card_at(state, &layouts, position)) else {
+            return mouse::Interaction::None;
+        };
+
+        match self.cards[index].content.as_widget().mouse_interaction(
+            &tree.children[index],
+            layouts[index],
+            cursor,
+            viewport,
+            renderer,
+        ) {
+            mouse::Interaction::None | mouse::Interaction::Idle => mouse::Interaction::Grab,
+            interaction => interaction,
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let overlays: Vec<_> = self
+            .cards
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .filter_map(|((card, tree), layout)| {
+                card.content
+                    .as_widget_mut()
+                    .overlay(tree, layout, renderer, viewport, translation)
+            })
+            .collect();
+
+        (!overlays.is_empty()).then(|| overlay::Group::with_children(overlays).overlay())
+    }
+}
+
+impl<'a, Id, Message, Theme, Renderer> From<Pinboard<'a, Id, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Id: Clone + Eq + 'static,
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(pinboard: Pinboard<'a, Id, Message, Theme, Renderer>) -> Self {
+        Element::new(pinboard)
+    }
+}
+
+/// The theme catalog of a [`Pinboard`].
+pub trait Catalog {
+    /// The style class
+    type Class<'a>;
+
+    /// Default style
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// Get the style for a class
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// The appearance of a [`Pinboard`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// Background of the board
+    pub background: Option<Background>,
+    /// Color of the grid dots, when snapping to a grid
+    pub grid: Color,
+    /// Shadow under the card being dragged
+    pub drag_shadow: Shadow,
+    /// Corner radius of the drag shadow, to match the cards
+    pub radius: f32,
+}
+
+/// Styling function
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for iced::Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default pinboard style.
+pub fn default(theme: &iced::Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: Some(palette.background.weakest.color.into()),
+        grid: palette.background.strong.color.scale_alpha(0.6),
+        drag_shadow: Shadow {
+            color: Color::BLACK.scale_alpha(0.4),
+            offset: Vector::new(0.0, 6.0),
+            blur_radius: 16.0,
+        },
+        radius: 4.0,
+    }
+}
+
+/// No background or grid, for boards over other content.
+pub fn transparent(theme: &iced::Theme) -> Style {
+    Style {
+        background: None,
+        grid: Color::TRANSPARENT,
+        ..default(theme)
+    }
+}
@@ -0,0 +1,101 @@
+//! Snapping to physical pixels.
+//!
+//! On fractional scale factors such as 1.25 or 1.5, a line one logical pixel wide lands
+//! between physical pixels and is drawn blurry, and lines of the same width come out uneven.
+//! The widgets snap what they draw themselves, such as tree handle stripes, drop indicators,
+//! hairline borders and slider tracks, to whole physical pixels with [`snap`] and [`border`].
+//!
+//! The scale factor is shared by every widget. Add [`subscription`] to the app's
+//! subscriptions to follow [`window::Event::Rescaled`], which is only sent when it changes, and
+//! set it once with [`set_scale_factor`] when starting on a scaled display, e.g. from
+//! `window::scale_factor` when the window opens.
+
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use iced::{window, Border, Event, Rectangle, Subscription};
+
+/// The bits of `1.0_f32`.
+static SCALE_FACTOR: AtomicU32 = AtomicU32::new(0x3f80_0000);
+
+/// Sets the number of physical pixels per logical pixel, the window's scale factor times
+/// the app's own `scale_factor`, if any.
+pub fn set_scale_factor(scale_factor: f32) {
+    if scale_factor.is_finite() && scale_factor > 0.0 {
+        SCALE_FACTOR.store(scale_factor.to_bits(), Ordering::Relaxed);
+    }
+}
+
+/// The scale factor set with [`set_scale_factor`] or last seen by a widget, 1 by default.
+pub fn scale_factor() -> f32 {
+    f32::from_bits(SCALE_FACTOR.load(Ordering::Relaxed))
+}
+
+/// Rounds a logical coordinate to the nearest physical pixel edge.
+pub fn round(value: f32) -> f32 {
+    let scale = scale_factor();
+    (value * scale).round() / scale
+}
+
+/// Rounds a stroke width to whole physical pixels, keeping widths above zero at least one.
+pub fn width(width: f32) -> f32 {
+    if width <= 0.0 {
+        return width;
+    }
+
+    let scale = scale_factor();
+    (width * scale).round().max(1.0) / scale
+}
+
+/// The width of one physical pixel, in logical pixels.
+pub fn hairline() -> f32 {
+    1.0 / scale_factor()
+}
+
+/// Moves the edges of `rectangle` to the nearest physical pixel edges, keeping thin shapes at
+/// least one physical pixel wide and tall.
+pub fn snap(rectangle: Rectangle) -> Rectangle {
+    let scale = scale_factor();
+
+    let left = (rectangle.x * scale).round();
+    let top = (rectangle.y * scale).round();
+    let mut right = ((rectangle.x + rectangle.width) * scale).round();
+    let mut bottom = ((rectangle.y + rectangle.height) * scale).round();
+
+    if rectangle.width > 0.0 {
+        right = right.max(left + 1.0);
+    }
+    if rectangle.height > 0.0 {
+        bottom = bottom.max(top + 1.0);
+    }
+
+    Rectangle {
+        x: left / scale,
+        y: top / scale,
+        width: (right - left) / scale,
+        height: (bottom - top) / scale,
+    }
+}
+
+/// Rounds the width of `border` to whole physical pixels.
+pub fn border(border: Border) -> Border {
+    Border {
+        width: width(border.width),
+        ..border
+    }
+}
+
+/// Records the scale factor of a [`window::Event::Rescaled`], for apps listening to window
+/// events themselves.
+pub fn observe(event: &Event) {
+    if let Event::Window(window::Event::Rescaled(scale_factor)) = event {
+        set_scale_factor(*scale_factor);
+    }
+}
+
+/// Follows the scale factor of the windows, to add to the app's subscriptions.
+pub fn subscription<Message: Send + 'static>() -> Subscription<Message> {
+    iced::event::listen_with(|event, _status, _window| {
+        observe(&event);
+        None
+    })
+}
@@ -0,0 +1,224 @@
+//! Timings and counters of the widgets' layout, update and draw passes.
+//!
+//! With the `profile` feature, the tree and the color picker time each pass and count the
+//! rows they lay out or draw and the quads they emit, so a slow pass can be measured before
+//! and after a change. Each pass is handed to the callback set with [`on_sample`] as a
+//! [`Sample`], and summed up per widget and phase in [`totals`]. The debug inspector lists the
+//! totals in its panel when both features are on.
+//!
+//! Without the feature the widgets still call into this module, but nothing is timed or kept.
+
+// Which parts the widgets use depends on the widgets enabled
+#![cfg_attr(not(all(feature = "tree", feature = "color_picker")), allow(dead_code))]
+
+#[cfg(feature = "profile")]
+use std::cell::{Cell, RefCell};
+#[cfg(feature = "profile")]
+use std::rc::Rc;
+#[cfg(feature = "profile")]
+use std::time::{Duration, Instant};
+
+#[cfg(feature = "profile")]
+thread_local! {
+    static CALLBACK: RefCell<Option<Callback>> = const { RefCell::new(None) };
+    static TOTALS: RefCell<Vec<Totals>> = const { RefCell::new(Vec::new()) };
+}
+
+#[cfg(feature = "profile")]
+type Callback = Rc<dyn Fn(&Sample)>;
+
+/// A pass a widget goes through.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Phase {
+    /// Measuring and placing its content
+    Layout,
+    /// Handling an event
+    Update,
+    /// Drawing itself and its content
+    Draw,
+}
+
+impl std::fmt::Display for Phase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Layout => "layout",
+            Self::Update => "update",
+            Self::Draw => "draw",
+        })
+    }
+}
+
+/// One pass of one widget.
+#[cfg(feature = "profile")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sample {
+    /// The kind of widget, such as `"tree"` or `"color_picker"`
+    pub widget: &'static str,
+    /// The pass
+    pub phase: Phase,
+    /// How long the pass took
+    pub duration: Duration,
+    /// Rows or items laid out or drawn
+    pub rows: usize,
+    /// Quads emitted while drawing
+    pub quads: usize,
+}
+
+/// Every pass of one phase of one kind of widget, summed up since the last [`reset`].
+#[cfg(feature = "profile")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Totals {
+    /// The kind of widget
+    pub widget: &'static str,
+    /// The pass
+    pub phase: Phase,
+    /// How many passes were made
+    pub count: u64,
+    /// Time spent in every pass together
+    pub total: Duration,
+    /// The longest pass
+    pub max: Duration,
+    /// Rows or items of the last pass
+    pub rows: usize,
+    /// Quads of the last pass
+    pub quads: usize,
+}
+
+#[cfg(feature = "profile")]
+impl Totals {
+    /// The average time of a pass.
+    pub fn average(&self) -> Duration {
+        if self.count == 0 {
+            Duration::ZERO
+        } else {
+            self.total.div_f64(self.count as f64)
+        }
+    }
+}
+
+/// Calls `callback` with every pass as it ends, replacing the callback set before.
+#[cfg(feature = "profile")]
+pub fn on_sample(callback: impl Fn(&Sample) + 'static) {
+    CALLBACK.with_borrow_mut(|current| *current = Some(Rc::new(callback)));
+}
+
+/// Stops calling the callback set with [`on_sample`].
+#[cfg(feature = "profile")]
+pub fn clear_callback() {
+    CALLBACK.with_borrow_mut(|current| *current = None);
+}
+
+/// The passes made since the last [`reset`], per kind of widget and phase.
+#[cfg(feature = "profile")]
+pub fn totals() -> Vec<Totals> {
+    TOTALS.with_borrow(Clone::clone)
+}
+
+/// Forgets the passes summed up in [`totals`].
+#[cfg(feature = "profile")]
+pub fn reset() {
+    TOTALS.with_borrow_mut(Vec::clear);
+}
+
+/// Starts timing a pass of `widget`, which ends when the returned span is dropped.
+#[cfg_attr(not(feature = "profile"), allow(unused_variables))]
+pub(crate) fn span(widget: &'static str, phase: Phase) -> Span {
+    Span {
+        #[cfg(feature = "profile")]
+        widget,
+        #[cfg(feature = "profile")]
+        phase,
+        #[cfg(feature = "profile")]
+        start: Instant::now(),
+        #[cfg(feature = "profile")]
+        rows: Cell::new(0),
+        #[cfg(feature = "profile")]
+        quads: Cell::new(0),
+    }
+}
+
+/// A pass under way, counting as it goes.
+pub(crate) struct Span {
+    #[cfg(feature = "profile")]
+    widget: &'static str,
+    #[cfg(feature = "profile")]
+    phase: Phase,
+    #[cfg(feature = "profile")]
+    start: Instant,
+    #[cfg(feature = "profile")]
+    rows: Cell<usize>,
+    #[cfg(feature = "profile")]
+    quads: Cell<usize>,
+}
+
+#[cfg_attr(not(feature = "profile"), allow(unused_variables))]
+impl Span {
+    /// Counts one row or item.
+    #[inline]
+    pub(crate) fn row(&self) {
+        self.rows(1);
+    }
+
+    /// Counts `count` rows or items.
+    #[inline]
+    pub(crate) fn rows(&self, count: usize) {
+        #[cfg(feature = "profile")]
+        self.rows.set(self.rows.get() + count);
+    }
+
+    /// Counts one quad.
+    #[inline]
+    pub(crate) fn quad(&self) {
+        self.quads(1);
+    }
+
+    /// Counts `count` quads.
+    #[inline]
+    pub(crate) fn quads(&self, count: usize) {
+        #[cfg(feature = "profile")]
+        self.quads.set(self.quads.get() + count);
+    }
+}
+
+#[cfg(feature = "profile")]
+impl Drop for Span {
+    fn drop(&mut self) {
+        let sample = Sample {
+            widget: self.widget,
+            phase: self.phase,
+            duration: self.start.elapsed(),
+            rows: self.rows.get(),
+            quads: self.quads.get(),
+        };
+
+        TOTALS.with_borrow_mut(|totals| {
+            let index = match totals.iter().position(|t| t.widget == sample.widget && t.phase == sample.phase) {
+                Some(index) => index,
+                None => {
+                    totals.push(Totals {
+                        widget: sample.widget,
+                        phase: sample.phase,
+                        count: 0,
+                        total: Duration::ZERO,
+                        max: Duration::ZERO,
+                        rows: 0,
+                        quads: 0,
+                    });
+                    totals.len() - 1
+                }
+            };
+
+            let totals = &mut totals[index];
+            totals.count += 1;
+            totals.total += sample.duration;
+            totals.max = totals.max.max(sample.duration);
+            totals.rows = sample.rows;
+            totals.quads = sample.quads;
+        });
+
+        // Cloned out first, so the callback may set another one
+        if let Some(callback) = CALLBACK.with_borrow(Clone::clone) {
+            callback(&sample);
+        }
+    }
+}
@@ -0,0 +1,649 @@
+//! An inspector panel of named values, each edited with a control suited to its type.
+//!
+//! Properties are arranged in [`Group`]s shown as [`collapsible`] sections, one row per
+//! property with its name on the left and an editor on the right: a text input for text, a
+//! number field for numbers, a checkbox for booleans, a [`ColorButton`] for colors and a
+//! dropdown for choices. The grid keeps no values of its own; every edit produces the
+//! message given to [`property_grid`] with the property's id and its new [`Value`], for the
+//! app to store and pass back on the next view.
+//!
+//! Number fields commit what was typed on Enter or when focus leaves them, and step the
+//! value with the Up and Down keys. Escape discards the edit.
+
+use std::rc::Rc;
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        mouse, renderer,
+        text::{self, Paragraph as _, Renderer as _},
+        widget::{self, tree::Tree},
+        Clipboard, Layout, Renderer as _, Shell, Widget,
+    },
+    alignment, keyboard,
+    widget::{checkbox, column, container, pick_list, row, text as label, text_input},
+    Background, Color, Element, Event, Length, Padding, Pixels, Point, Rectangle, Renderer, Size, Theme,
+};
+
+use crate::collapsible::collapsible;
+use crate::color_picker::{color_button, ColorButton};
+use crate::pixel;
+
+const CARET_WIDTH: f32 = 1.0;
+
+/// Creates a new [`PropertyGrid`] of `groups`, reporting edits with `on_change`.
+pub fn property_grid<'a, Message>(
+    groups: impl IntoIterator<Item = Group>,
+    on_change: impl Fn(String, Value) -> Message + 'a,
+) -> PropertyGrid<'a, Message> {
+    PropertyGrid::new(groups, on_change)
+}
+
+/// The value of a [`Property`], which decides its editor.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Text(String),
+    Number(f64),
+    Bool(bool),
+    Color(Color),
+    /// One of `options`, picked from a dropdown
+    Choice { options: Vec<String>, selected: usize },
+}
+
+impl Value {
+    /// A choice between `options` with `selected` picked.
+    pub fn choice(options: impl IntoIterator<Item = impl Into<String>>, selected: usize) -> Self {
+        Self::Choice {
+            options: options.into_iter().map(Into::into).collect(),
+            selected,
+        }
+    }
+}
+
+/// A named value in a [`Group`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Property {
+    pub id: String,
+    pub name: String,
+    pub value: Value,
+    pub disabled: bool,
+    /// Bounds of a number
+    pub range: Option<(f64, f64)>,
+    /// How much Up and Down change a number
+    pub step: f64,
+}
+
+impl Property {
+    /// Creates a new [`Property`] reported as `id` when edited.
+    pub fn new(id: impl Into<String>, name: impl Into<String>, value: Value) -> Self {
+        Self {
+            id: id.into(),
+            name: name.into(),
+            value,
+            disabled: false,
+            range: None,
+            step: 1.0,
+        }
+    }
+
+    /// Shows the value without letting it be edited.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Keeps a number between `min` and `max`.
+    pub fn range(mut self, min: f64, max: f64) -> Self {
+        self.range = Some((min.min(max), max.max(min)));
+        self
+    }
+
+    /// Sets how much Up and Down change a number.
+    pub fn step(mut self, step: f64) -> Self {
+        self.step = step;
+        self
+    }
+}
+
+/// A titled section of a [`PropertyGrid`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Group {
+    pub name: String,
+    pub properties: Vec<Property>,
+    pub collapsed: bool,
+}
+
+impl Group {
+    /// Creates a new, expanded [`Group`].
+    pub fn new(name: impl Into<String>, properties: impl IntoIterator<Item = Property>) -> Self {
+        Self {
+            name: name.into(),
+            properties: properties.into_iter().collect(),
+            collapsed: false,
+        }
+    }
+
+    /// Starts the group collapsed.
+    pub fn collapsed(mut self, collapsed: bool) -> Self {
+        self.collapsed = collapsed;
+        self
+    }
+}
+
+/// An inspector panel of grouped properties.
+#[allow(missing_debug_implementations)]
+pub struct PropertyGrid<'a, Message> {
+    groups: Vec<Group>,
+    on_change: Rc<dyn Fn(String, Value) -> Message + 'a>,
+    name_width: f32,
+    width: Length,
+    spacing: f32,
+    text_size: Pixels,
+    class: <Theme as Catalog>::Class<'a>,
+}
+
+impl<'a, Message> PropertyGrid<'a, Message> {
+    /// Creates a new [`PropertyGrid`] of `groups`, reporting edits with `on_change`.
+    pub fn new(groups: impl IntoIterator<Item = Group>, on_change: impl Fn(String, Value) -> Message + 'a) -> Self {
+        Self {
+            groups: groups.into_iter().collect(),
+            on_change: Rc::new(on_change),
+            name_width: 120.0,
+            width: Length::Fill,
+            spacing: 4.0,
+            text_size: Pixels(14.0),
+            class: <Theme as Catalog>::default(),
+        }
+    }
+
+    /// Sets the width of the names column.
+    pub fn name_width(mut self, width: f32) -> Self {
+        self.name_width = width;
+        self
+    }
+
+    /// Sets the width of the grid.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the spacing between groups.
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the text size of names and editors.
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = size.into();
+        self
+    }
+
+    /// Sets the style.
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self {
+        self.class = Box::new(style);
+        self
+    }
+
+    /// Sets the style class.
+    #[must_use]
+    pub fn class(mut self, class: impl Into<<Theme as Catalog>::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+impl<'a, Message: Clone + 'a> PropertyGrid<'a, Message> {
+    fn editor(&self, property: Property) -> Element<'a, Message> {
+        let on_change = Rc::clone(&self.on_change);
+        let id = property.id;
+        let change = move |value: Value| on_change(id.clone(), value);
+        let size = self.text_size;
+
+        match property.value {
+            Value::Text(value) => {
+                let input = text_input("", &value).size(size).padding([4, 8]);
+
+                if property.disabled {
+                    input.into()
+                } else {
+                    input.on_input(move |text| change(Value::Text(text))).into()
+                }
+            }
+            Value::Number(value) => Element::new(NumberField {
+                value,
+                range: property.range,
+                step: property.step,
+                on_change: (!property.disabled).then(|| Box::new(change) as Box<dyn Fn(Value) -> Message + 'a>),
+                text_size: size,
+                padding: Padding::from([4, 8]),
+            }),
+            Value::Bool(checked) => {
+                let toggle = checkbox(checked).size(size.0 + 2.0);
+
+                if property.disabled {
+                    toggle.into()
+                } else {
+                    toggle.on_toggle(move |checked| change(Value::Bool(checked))).into()
+                }
+            }
+            Value::Color(color) => {
+                let button: ColorButton<'a, Message> = color_button(color)
+                    .title(property.name)
+                    .show_hex()
+                    .width(Length::Fill)
+                    .height(text::LineHeight::default().to_absolute(size).0 + 8.0);
+
+                if property.disabled {
+                    button.into()
+                } else {
+                    button.on_change(move |color| change(Value::Color(color))).into()
+                }
+            }
+            Value::Choice { options, selected } => {
+                let current = options.get(selected).cloned();
+
+                if property.disabled {
+                    return label(current.unwrap_or_default()).size(size).into();
+                }
+
+                let choices = options.clone();
+                pick_list(options, current, move |picked: String| {
+                    let selected = choices.iter().position(|option| *option == picked).unwrap_or(selected);
+                    change(Value::Choice {
+                        options: choices.clone(),
+                        selected,
+                    })
+                })
+                .text_size(size)
+                .padding([4, 8])
+                .width(Length::Fill)
+                .into()
+            }
+        }
+    }
+}
+
+impl<'a, Message: Clone + 'a> From<PropertyGrid<'a, Message>> for Element<'a, Message> {
+    fn from(mut grid: PropertyGrid<'a, Message>) -> Self {
+        let class = Rc::new(std::mem::replace(&mut grid.class, <Theme as Catalog>::default()));
+        let groups = std::mem::take(&mut grid.groups);
+
+        let sections = groups.into_iter().map(|group| {
+            let rows = group.properties.into_iter().enumerate().map(|(index, property)| {
+                let class = Rc::clone(&class);
+                let disabled = property.disabled;
+                let name = label(property.name.clone()).size(grid.text_size).width(grid.name_width).style({
+                    let class = Rc::clone(&class);
+                    move |theme: &Theme| label::Style {
+                        color: Some({
+                            let style = Catalog::style(theme, &class);
+                            if disabled { style.disabled_text } else { style.name_text }
+                        }),
+                    }
+                });
+
+                container(row![name, grid.editor(property)].spacing(8).align_y(alignment::Vertical::Center))
+                    .padding([2, 6])
+                    .width(Length::Fill)
+                    .style(move |theme: &Theme| {
+                        let style = Catalog::style(theme, &class);
+                        container::Style {
+                            background: if index % 2 == 0 { style.row_background } else { style.alternate_row_background },
+                            ..container::Style::default()
+                        }
+                    })
+                    .into()
+            });
+
+            collapsible(group.name.clone(), column(rows))
+                .key(group.name)
+                .expanded(!group.collapsed)
+                .text_size(grid.text_size)
+                .into()
+        });
+
+        column(sections).spacing(grid.spacing).width(grid.width).into()
+    }
+}
+
+/// A field editing a number as text.
+struct NumberField<'a, Message> {
+    value: f64,
+    range: Option<(f64, f64)>,
+    step: f64,
+    /// `None` when disabled
+    on_change: Option<Box<dyn Fn(Value) -> Message + 'a>>,
+    text_size: Pixels,
+    padding: Padding,
+}
+
+/// A number without trailing zeros, as shown in a [`NumberField`].
+fn format_number(value: f64) -> String {
+    let text = format!("{value:.6}");
+    text.trim_end_matches('0').trim_end_matches('.').to_string()
+}
+
+/// The text being typed into a focused [`NumberField`].
+#[derive(Debug, Default)]
+struct NumberState {
+    draft: Option<String>,
+}
+
+impl widget::operation::Focusable for NumberState {
+    fn is_focused(&self) -> bool {
+        self.draft.is_some()
+    }
+
+    fn focus(&mut self) {
+        self.draft = Some(String::new());
+    }
+
+    fn unfocus(&mut self) {
+        self.draft = None;
+    }
+}
+
+impl<Message> NumberField<'_, Message> {
+    fn clamp(&self, value: f64) -> f64 {
+        match self.range {
+            Some((min, max)) => value.clamp(min, max),
+            None => value,
+        }
+    }
+
+    /// Parses the draft and reports it if it changed the value.
+    fn commit(&self, draft: &str, shell: &mut Shell<'_, Message>) {
+        if let (Ok(value), Some(on_change)) = (draft.trim().parse::<f64>(), &self.on_change) {
+            let value = self.clamp(value);
+            if value != self.value {
+                shell.publish(on_change(Value::Number(value)));
+            }
+        }
+    }
+
+    fn text(&self, content: String, bounds: Size) -> iced::advanced::Text<String, iced::Font> {
+        iced::advanced::Text {
+            content,
+            bounds,
+            size: self.text_size,
+            font: iced::Font::default(),
+            align_x: text::Alignment::Left,
+            align_y: alignment::Vertical::Center,
+            line_height: text::LineHeight::default(),
+            shaping: text::Shaping::Basic,
+            wrapping: text::Wrapping::None,
+        }
+    }
+
+    fn status(&self, state: &NumberState, hovered: bool) -> text_input::Status {
+        match (&self.on_change, &state.draft) {
+            (None, _) => text_input::Status::Disabled,
+            (Some(_), Some(_)) => text_input::Status::Focused { is_hovered: hovered },
+            (Some(_), None) if hovered => text_input::Status::Hovered,
+            (Some(_), None) => text_input::Status::Active,
+        }
+    }
+}
+
+impl<Message> Widget<Message, Theme, Renderer> for NumberField<'_, Message> {
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: Length::Fill,
+            height: Length::Shrink,
+        }
+    }
+
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<NumberState>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(NumberState::default())
+    }
+
+    fn layout(&mut self, _tree: &mut Tree, _renderer: &Renderer, limits: &Limits) -> Node {
+        let height = text::LineHeight::default().to_absolute(self.text_size).0 + self.padding.y();
+
+        Node::new(limits.resolve(Length::Fill, Length::Shrink, Size::new(0.0, height)))
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<NumberState>();
+
+        if self.on_change.is_none() {
+            return;
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                if cursor.is_over(layout.bounds()) {
+                    if state.draft.is_none() {
+                        state.draft = Some(format_number(self.value));
+                    }
+                    shell.capture_event();
+                } else if let Some(draft) = state.draft.take() {
+                    self.commit(&draft, shell);
+                }
+                shell.request_redraw();
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                shell.request_redraw();
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key, modifiers, text, ..
+            }) => {
+                let Some(draft) = &mut state.draft else {
+                    return;
+                };
+
+                match key {
+                    keyboard::Key::Named(keyboard::key::Named::Enter) => {
+                        let draft = std::mem::take(draft);
+                        state.draft = None;
+                        self.commit(&draft, shell);
+                    }
+                    keyboard::Key::Named(keyboard::key::Named::Escape) => {
+                        state.draft = None;
+                    }
+                    keyboard::Key::Named(keyboard::key::Named::Backspace) => {
+                        draft.pop();
+                    }
+                    keyboard::Key::Named(named @ (keyboard::key::Named::ArrowUp | keyboard::key::Named::ArrowDown)) => {
+                        let current = draft.trim().parse::<f64>().unwrap_or(self.value);
+                        let step = if *named == keyboard::key::Named::ArrowUp { self.step } else { -self.step };
+                        // Rounded so repeated fractional steps don't drift
+                        let value = self.clamp(((current + step) * 1e9).round() / 1e9);
+
+                        *draft = format_number(value);
+                        if let Some(on_change) = &self.on_change {
+                            shell.publish(on_change(Value::Number(value)));
+                        }
+                    }
+                    _ => {
+                        let Some(text) = text.as_ref().filter(|_| !modifiers.command()) else {
+                            return;
+                        };
+
+                        let typed: String = text
+                            .chars()
+                            .filter(|c| c.is_ascii_digit() || matches!(c, '.' | '-' | '+' | 'e' | 'E'))
+                            .collect();
+                        if typed.is_empty() {
+                            return;
+                        }
+                        draft.push_str(&typed);
+                    }
+                }
+
+                shell.capture_event();
+                shell.request_redraw();
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _defaults: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<NumberState>();
+        let bounds = layout.bounds();
+        let status = self.status(state, cursor.is_over(bounds));
+        let style = <Theme as text_input::Catalog>::style(theme, &<Theme as text_input::Catalog>::default(), status);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: pixel::snap(bounds),
+                border: pixel::border(style.border),
+                ..renderer::Quad::default()
+            },
+            style.background,
+        );
+
+        let content = state.draft.clone().unwrap_or_else(|| format_number(self.value));
+        let area = Rectangle {
+            x: bounds.x + self.padding.left,
+            width: (bounds.width - self.padding.x()).max(0.0),
+            ..bounds
+        };
+        let clip = area.intersection(viewport).unwrap_or(area);
+
+        if state.draft.is_some() {
+            let width = <Renderer as text::Renderer>::Paragraph::with_text(iced::advanced::Text {
+                content: content.as_str(),
+                bounds: Size::INFINITE,
+                size: self.text_size,
+                font: iced::Font::default(),
+                align_x: text::Alignment::Left,
+                align_y: alignment::Vertical::Top,
+                line_height: text::LineHeight::default(),
+                shaping: text::Shaping::Basic,
+                wrapping: text::Wrapping::None,
+            })
+            .min_bounds()
+            .width
+            .min(area.width);
+            let line_height = text::LineHeight::default().to_absolute(self.text_size).0;
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: pixel::snap(Rectangle {
+                        x: area.x + width,
+                        y: area.center_y() - line_height / 2.0,
+                        width: CARET_WIDTH,
+                        height: line_height,
+                    }),
+                    ..renderer::Quad::default()
+                },
+                style.value,
+            );
+        }
+
+        let color = if self.on_change.is_some() { style.value } else { style.placeholder };
+        renderer.fill_text(self.text(content, area.size()), Point::new(area.x, area.center_y()), color, clip);
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        match (&self.on_change, cursor.is_over(layout.bounds())) {
+            (Some(_), true) => mouse::Interaction::Text,
+            (None, true) => mouse::Interaction::NotAllowed,
+            (_, false) => mouse::Interaction::None,
+        }
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn widget::Operation,
+    ) {
+        operation.focusable(None, layout.bounds(), tree.state.downcast_mut::<NumberState>());
+    }
+}
+
+/// The theme catalog of a [`PropertyGrid`].
+pub trait Catalog {
+    /// The style class
+    type Class<'a>;
+
+    /// Default style
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// Get the style for a class
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// The appearance of a [`PropertyGrid`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// Color of property names
+    pub name_text: Color,
+    /// Color of the names of disabled properties
+    pub disabled_text: Color,
+    /// Background of odd rows
+    pub row_background: Option<Background>,
+    /// Background of even rows
+    pub alternate_row_background: Option<Background>,
+}
+
+/// Styling function
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for iced::Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default property grid style, with striped rows.
+pub fn default(theme: &iced::Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        name_text: palette.background.base.text,
+        disabled_text: palette.background.strong.color,
+        row_background: None,
+        alternate_row_background: Some(palette.background.weak.color.scale_alpha(0.4).into()),
+    }
+}
+
+/// Rows without stripes.
+pub fn plain(theme: &iced::Theme) -> Style {
+    Style {
+        alternate_row_background: None,
+        ..default(theme)
+    }
+}
@@ -0,0 +1,739 @@
+//! A slider with two thumbs selecting an interval.
+//!
+//! Both thumbs are dragged with the mouse and never cross. Pressing the rail moves the
+//! nearest thumb there. Once clicked, the focused thumb follows the arrow keys, Home and
+//! End, and Tab (or Shift + Tab) moves focus between the low and high thumb.
+//!
+//! Right to left, values grow towards the left and the Left and Right keys swap.
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        mouse, renderer,
+        widget::{self, tree::Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    keyboard, Background, Border, Color, Element, Event, Length, Pixels, Point, Rectangle, Size,
+};
+use std::ops::RangeInclusive;
+
+use crate::direction::{self, Direction};
+use crate::pixel;
+
+const DEFAULT_HEIGHT: f32 = 20.0;
+const DEFAULT_THUMB_SIZE: f32 = 16.0;
+const TICK_LENGTH: f32 = 6.0;
+const TICK_SPACING: f32 = 4.0;
+const PAGE_STEPS: f32 = 10.0; // Steps moved by Shift + arrow
+
+/// Creates a new [`RangeSlider`].
+pub fn range_slider<'a, Message, Theme>(
+    range: RangeInclusive<f32>,
+    value: (f32, f32),
+    on_change: impl Fn((f32, f32)) -> Message + 'a,
+) -> RangeSlider<'a, Message, Theme>
+where
+    Theme: Catalog,
+{
+    RangeSlider::new(range, value, on_change)
+}
+
+/// One of the two thumbs of a [`RangeSlider`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Thumb {
+    Low,
+    High,
+}
+
+impl Thumb {
+    fn other(self) -> Self {
+        match self {
+            Thumb::Low => Thumb::High,
+            Thumb::High => Thumb::Low,
+        }
+    }
+}
+
+/// A slider selecting a `(low, high)` interval.
+#[allow(missing_debug_implementations)]
+pub struct RangeSlider<'a, Message, Theme = iced::Theme>
+where
+    Theme: Catalog,
+{
+    range: RangeInclusive<f32>,
+    value: (f32, f32),
+    step: Option<f32>,
+    ticks: Vec<f32>,
+    snap_to_ticks: bool,
+    on_change: Box<dyn Fn((f32, f32)) -> Message + 'a>,
+    on_release: Option<Box<dyn Fn() -> Message + 'a>>,
+    width: Length,
+    height: f32,
+    thumb_size: f32,
+    direction: Option<Direction>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme> RangeSlider<'a, Message, Theme>
+where
+    Theme: Catalog,
+{
+    /// Creates a new [`RangeSlider`] over `range` with `value` selected.
+    ///
+    /// A reversed range is taken the right way round.
+    pub fn new(
+        range: RangeInclusive<f32>,
+        value: (f32, f32),
+        on_change: impl Fn((f32, f32)) -> Message + 'a,
+    ) -> Self {
+        let (start, end) = (range.start().min(*range.end()), range.start().max(*range.end()));
+        let (low, high) = (value.0.min(value.1), value.0.max(value.1));
+
+        Self {
+            value: (low.clamp(start, end), high.clamp(start, end)),
+            range: start..=end,
+            step: None,
+            ticks: Vec::new(),
+            snap_to_ticks: false,
+            on_change: Box::new(on_change),
+            on_release: None,
+            width: Length::Fill,
+            height: DEFAULT_HEIGHT,
+            thumb_size: DEFAULT_THUMB_SIZE,
+            direction: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Rounds values to multiples of `step`, counted from the start of the range.
+    ///
+    /// Also sets how far the arrow keys move a thumb.
+    pub fn step(mut self, step: f32) -> Self {
+        self.step = (step > 0.0).then_some(step);
+        self
+    }
+
+    /// Draws tick marks below the rail at the given values.
+    pub fn ticks(mut self, ticks: impl IntoIterator<Item = f32>) -> Self {
+        self.ticks = ticks.into_iter().collect();
+        self
+    }
+
+    /// Draws a tick mark at every `step` along the range.
+    pub fn ticks_every(mut self, step: f32) -> Self {
+        let (start, end) = (*self.range.start(), *self.range.end());
+
+        if step > 0.0 {
+            let count = ((end - start) / step).floor() as usize;
+            self.ticks = (0..=count).map(|i| start + i as f32 * step).collect();
+        }
+        self
+    }
+
+    /// Makes the thumbs snap to the closest tick mark instead of the step.
+    pub fn snap_to_ticks(mut self, snap: bool) -> Self {
+        self.snap_to_ticks = snap;
+        self
+    }
+
+    /// Sets the message produced when a thumb is released after dragging.
+    pub fn on_release(self, message: Message) -> Self
+    where
+        Message: Clone + 'a,
+    {
+        self.on_release_with(move || message.clone())
+    }
+
+    /// Sets the function making the message produced when a thumb is released after dragging,
+    /// for messages that can not be cloned.
+    pub fn on_release_with(mut self, on_release: impl Fn() -> Message + 'a) -> Self {
+        self.on_release = Some(Box::new(on_release));
+        self
+    }
+
+    /// Sets the width of the slider.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the rail area, not counting tick marks.
+    pub fn height(mut self, height: impl Into<Pixels>) -> Self {
+        self.height = height.into().0;
+        self
+    }
+
+    /// Sets the diameter of the thumbs.
+    pub fn thumb_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.thumb_size = size.into().0;
+        self
+    }
+
+    /// Sets the style.
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class.
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Sets the [`Direction`] of the slider, instead of the one set with [`direction::set`].
+    ///
+    /// Right to left puts the start of the range on the right.
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    fn layout_direction(&self) -> Direction {
+        self.direction.unwrap_or_else(direction::get)
+    }
+
+    /// The usable part of the rail, inset so the thumbs stay inside the bounds.
+    fn rail(&self, bounds: Rectangle) -> (f32, f32) {
+        let inset = self.thumb_size / 2.0;
+        (bounds.x + inset, (bounds.width - self.thumb_size).max(1.0))
+    }
+
+    fn position_of(&self, bounds: Rectangle, value: f32) -> f32 {
+        let (start, end) = (*self.range.start(), *self.range.end());
+        let (x, width) = self.rail(bounds);
+        let ratio = if end > start { (value - start) / (end - start) } else { 0.0 };
+
+        self.layout_direction().mirror_x(x + ratio.clamp(0.0, 1.0) * width, bounds)
+    }
+
+    fn value_at(&self, bounds: Rectangle, x: f32) -> f32 {
+        let (start, end) = (*self.range.start(), *self.range.end());
+        let (rail_x, width) = self.rail(bounds);
+        let x = self.layout_direction().mirror_x(x, bounds);
+        let ratio = ((x - rail_x) / width).clamp(0.0, 1.0);
+
+        self.snap(start + ratio * (end - start))
+    }
+
+    /// Rounds a raw value to a tick or step, inside the range.
+    fn snap(&self, value: f32) -> f32 {
+        let (start, end) = (*self.range.start(), *self.range.end());
+
+        let snapped = if self.snap_to_ticks && !self.ticks.is_empty() {
+            self.ticks
+                .iter()
+                .copied()
+                .min_by(|a, b| (a - value).abs().total_cmp(&(b - value).abs()))
+                .unwrap_or(value)
+        } else if let Some(step) = self.step {
+            start + ((value - start) / step).round() * step
+        } else {
+            value
+        };
+
+        snapped.clamp(start, end)
+    }
+
+    fn thumb_center(&self, bounds: Rectangle, thumb: Thumb) -> Point {
+        let value = match thumb {
+            Thumb::Low => self.value.0,
+            Thumb::High => self.value.1,
+        };
+
+        Point::new(
+            self.position_of(bounds, value),
+            bounds.y + self.height.max(self.thumb_size) / 2.0,
+        )
+    }
+
+    fn thumb_at(&self, bounds: Rectangle, point: Point) -> Option<Thumb> {
+        let radius = self.thumb_size / 2.0;
+
+        // The high thumb is drawn on top, so it wins when both overlap
+        [Thumb::High, Thumb::Low]
+            .into_iter()
+            .find(|&thumb| self.thumb_center(bounds, thumb).distance(point) <= radius)
+    }
+
+    /// The thumb closest to a point on the rail.
+    fn nearest_thumb(&self, bounds: Rectangle, x: f32) -> Thumb {
+        let low = (self.thumb_center(bounds, Thumb::Low).x - x).abs();
+        let high = (self.thumb_center(bounds, Thumb::High).x - x).abs();
+
+        // Thumbs on top of each other: pick the one that can move that way
+        if low == high {
+            let dir = self.layout_direction();
+            let towards_start = dir.mirror_x(x, bounds) < dir.mirror_x(self.thumb_center(bounds, Thumb::Low).x, bounds);
+            if towards_start { Thumb::Low } else { Thumb::High }
+        } else if low < high {
+            Thumb::Low
+        } else {
+            Thumb::High
+        }
+    }
+
+    /// Moves one thumb, keeping it on its side of the other, and publishes the change.
+    fn set(&self, thumb: Thumb, value: f32, shell: &mut Shell<'_, Message>) {
+        let (low, high) = self.value;
+        let next = match thumb {
+            Thumb::Low => (value.min(high), high),
+            Thumb::High => (low, value.max(low)),
+        };
+
+        if next != self.value {
+            shell.publish((self.on_change)(next));
+        }
+    }
+
+    fn keyboard_step(&self) -> f32 {
+        self.step
+            .unwrap_or_else(|| (*self.range.end() - *self.range.start()) / 100.0)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    dragging: Option<Thumb>,
+    focused: Option<Thumb>,
+    modifiers: keyboard::Modifiers,
+}
+
+impl widget::operation::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.focused.is_some()
+    }
+
+    fn focus(&mut self) {
+        self.focused.get_or_insert(Thumb::Low);
+    }
+
+    fn unfocus(&mut self) {
+        self.focused = None;
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for RangeSlider<'_, Message, Theme>
+where
+    Theme: Catalog,
+    Renderer: renderer::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(State::default())
+    }
+
+    fn layout(&mut self, _tree: &mut Tree, _renderer: &Renderer, limits: &Limits) -> Node {
+        let ticks = if self.ticks.is_empty() { 0.0 } else { TICK_SPACING + TICK_LENGTH };
+        let height = self.height.max(self.thumb_size) + ticks;
+
+        Node::new(limits.resolve(self.width, Length::Shrink, Size::new(0.0, height)))
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    state.focused = None;
+                    return;
+                };
+
+                let thumb = self
+                    .thumb_at(bounds, position)
+                    .unwrap_or_else(|| self.nearest_thumb(bounds, position.x));
+
+                if self.thumb_at(bounds, position).is_none() {
+                    self.set(thumb, self.value_at(bounds, position.x), shell);
+                }
+
+                state.dragging = Some(thumb);
+                state.focused = Some(thumb);
+                shell.capture_event();
+                shell.request_redraw();
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                if let Some(thumb) = state.dragging {
+                    self.set(thumb, self.value_at(bounds, position.x), shell);
+                    shell.capture_event();
+                }
+                shell.request_redraw();
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) if state.dragging.is_some() => {
+                state.dragging = None;
+
+                if let Some(on_release) = &self.on_release {
+                    shell.publish(on_release());
+                }
+                shell.capture_event();
+                shell.request_redraw();
+            }
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.modifiers = *modifiers;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed { key, .. }) => {
+                let Some(thumb) = state.focused else {
+                    return;
+                };
+
+                let current = match thumb {
+                    Thumb::Low => self.value.0,
+                    Thumb::High => self.value.1,
+                };
+                let step = self.keyboard_step()
+                    * if state.modifiers.shift() { PAGE_STEPS } else { 1.0 };
+
+                let (start, end) = (*self.range.start(), *self.range.end());
+                // Right to left, the values grow towards the left
+                let key = match key {
+                    keyboard::Key::Named(keyboard::key::Named::ArrowLeft) if self.layout_direction().is_rtl() => {
+                        &keyboard::Key::Named(keyboard::key::Named::ArrowRight)
+                    }
+                    keyboard::Key::Named(keyboard::key::Named::ArrowRight) if self.layout_direction().is_rtl() => {
+                        &keyboard::Key::Named(keyboard::key::Named::ArrowLeft)
+                    }
+                    key => key,
+                };
+                let target = match key {
+                    keyboard::Key::Named(keyboard::key::Named::ArrowLeft | keyboard::key::Named::ArrowDown) => {
+                        Some(current - step)
+                    }
+                    keyboard::Key::Named(keyboard::key::Named::ArrowRight | keyboard::key::Named::ArrowUp) => {
+                        Some(current + step)
+                    }
+                    keyboard::Key::Named(keyboard::key::Named::Home) => Some(start),
+                    keyboard::Key::Named(keyboard::key::Named::End) => Some(end),
+                    // Tab moves to the high thumb and Shift + Tab back, past those focus leaves
+                    keyboard::Key::Named(keyboard::key::Named::Tab)
+                        if (thumb == Thumb::Low) != state.modifiers.shift() =>
+                    {
+                        state.focused = Some(thumb.other());
+                        shell.capture_event();
+                        shell.request_redraw();
+                        None
+                    }
+                    _ => None,
+                };
+
+                if let Some(target) = target {
+                    // Snap to ticks only when stepping would never reach another one
+                    let value = if self.snap_to_ticks {
+                        self.snap(target)
+                    } else {
+                        target.clamp(start, end)
+                    };
+                    self.set(thumb, value, shell);
+                    shell.capture_event();
+                    shell.request_redraw();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _defaults: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let hovered = cursor.position().and_then(|position| self.thumb_at(bounds, position));
+
+        let status_of = |thumb: Thumb| {
+            if state.dragging == Some(thumb) {
+                Status::Dragged
+            } else if hovered == Some(thumb) {
+                Status::Hovered
+            } else {
+                Status::Active
+            }
+        };
+        let style = theme.style(&self.class, status_of(Thumb::Low));
+
+        let (rail_x, rail_width) = self.rail(bounds);
+        let rail = Rectangle {
+            x: rail_x,
+            y: self.thumb_center(bounds, Thumb::Low).y - style.rail.width / 2.0,
+            width: rail_width,
+            height: style.rail.width,
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: pixel::snap(rail),
+                border: pixel::border(Border {
+                    radius: style.rail.border_radius.into(),
+                    ..Border::default()
+                }),
+                ..renderer::Quad::default()
+            },
+            style.rail.background,
+        );
+
+        let low_x = self.position_of(bounds, self.value.0);
+        let high_x = self.position_of(bounds, self.value.1);
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: pixel::snap(Rectangle {
+                    x: low_x.min(high_x),
+                    width: (high_x - low_x).abs(),
+                    ..rail
+                }),
+                ..renderer::Quad::default()
+            },
+            style.rail.selected,
+        );
+
+        if let Some(tick_color) = style.tick {
+            let tick_y = bounds.y + self.height.max(self.thumb_size) + TICK_SPACING;
+
+            for &tick in &self.ticks {
+                let x = self.position_of(bounds, tick);
+                let in_range = tick >= self.value.0 && tick <= self.value.1;
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: pixel::snap(Rectangle {
+                            x: x - 0.5,
+                            y: tick_y,
+                            width: 1.0,
+                            height: if in_range { TICK_LENGTH } else { TICK_LENGTH * 0.6 },
+                        }),
+                        ..renderer::Quad::default()
+                    },
+                    tick_color,
+                );
+            }
+        }
+
+        for thumb in [Thumb::Low, Thumb::High] {
+            let style = theme.style(&self.class, status_of(thumb));
+            let center = self.thumb_center(bounds, thumb);
+            let radius = self.thumb_size / 2.0;
+            let thumb_bounds = Rectangle {
+                x: center.x - radius,
+                y: center.y - radius,
+                width: self.thumb_size,
+                height: self.thumb_size,
+            };
+
+            if state.focused == Some(thumb) && let Some(ring) = style.focus_ring {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: pixel::snap(thumb_bounds.expand(3.0)),
+                        border: pixel::border(Border {
+                            color: ring,
+                            width: 2.0,
+                            radius: (radius + 3.0).into(),
+                        }),
+                        ..renderer::Quad::default()
+                    },
+                    Color::TRANSPARENT,
+                );
+            }
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: pixel::snap(thumb_bounds),
+                    border: pixel::border(Border {
+                        color: style.thumb.border_color,
+                        width: style.thumb.border_width,
+                        radius: radius.into(),
+                    }),
+                    ..renderer::Quad::default()
+                },
+                style.thumb.background,
+            );
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+
+        if state.dragging.is_some() {
+            mouse::Interaction::Grabbing
+        } else if cursor.position().is_some_and(|position| self.thumb_at(bounds, position).is_some()) {
+            mouse::Interaction::Grab
+        } else if cursor.is_over(bounds) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn widget::Operation,
+    ) {
+        operation.focusable(None, layout.bounds(), tree.state.downcast_mut::<State>());
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<RangeSlider<'a, Message, Theme>> for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(slider: RangeSlider<'a, Message, Theme>) -> Self {
+        Element::new(slider)
+    }
+}
+
+/// The state of a thumb of a [`RangeSlider`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Active,
+    Hovered,
+    Dragged,
+}
+
+/// The theme catalog of a [`RangeSlider`].
+pub trait Catalog {
+    /// The style class
+    type Class<'a>;
+
+    /// Default style
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// Get the style for a class and status
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+/// The appearance of a [`RangeSlider`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The rail the thumbs move along
+    pub rail: Rail,
+    /// The thumbs
+    pub thumb: Handle,
+    /// Color of the tick marks, `None` hides them
+    pub tick: Option<Color>,
+    /// Ring drawn around the focused thumb
+    pub focus_ring: Option<Color>,
+}
+
+/// The appearance of the rail of a [`RangeSlider`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rail {
+    /// Background outside the selected interval
+    pub background: Background,
+    /// Background between the thumbs
+    pub selected: Background,
+    /// Thickness of the rail
+    pub width: f32,
+    pub border_radius: f32,
+}
+
+/// The appearance of a thumb of a [`RangeSlider`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Handle {
+    pub background: Background,
+    pub border_color: Color,
+    pub border_width: f32,
+}
+
+/// Styling function
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for iced::Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default range slider style.
+pub fn default(theme: &iced::Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+
+    let thumb = match status {
+        Status::Active => palette.primary.base.color,
+        Status::Hovered => palette.primary.strong.color,
+        Status::Dragged => palette.primary.weak.color,
+    };
+
+    Style {
+        rail: Rail {
+            background: palette.background.strong.color.into(),
+            selected: palette.primary.base.color.into(),
+            width: 4.0,
+            border_radius: 2.0,
+        },
+        thumb: Handle {
+            background: thumb.into(),
+            border_color: palette.background.base.color,
+            border_width: 2.0,
+        },
+        tick: Some(palette.background.strong.color),
+        focus_ring: Some(palette.primary.weak.color),
+    }
+}
+
+/// A thin rail with hollow thumbs.
+pub fn outlined(theme: &iced::Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+    let base = default(theme, status);
+
+    Style {
+        rail: Rail { width: 2.0, border_radius: 1.0, ..base.rail },
+        thumb: Handle {
+            background: palette.background.base.color.into(),
+            border_color: match status {
+                Status::Active => palette.primary.base.color,
+                Status::Hovered | Status::Dragged => palette.primary.strong.color,
+            },
+            border_width: 2.0,
+        },
+        ..base
+    }
+}
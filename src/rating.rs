@@ -0,0 +1,471 @@
+//! A row of stars, or any other icon, showing and picking a rating.
+//!
+//! Hovering previews the rating under the cursor and clicking picks it, in whole or, with
+//! [`Rating::half_steps`], half icons. Once clicked, the arrow keys move the rating by one
+//! step, Home and End pick none or all, and the digit keys pick that many icons. Without
+//! [`Rating::on_change`] the widget only displays the value.
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        mouse, renderer, text,
+        widget::{self, tree::Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment, keyboard, Color, Element, Event, Length, Pixels, Rectangle, Size,
+};
+
+use crate::focus::{self, Ring};
+
+const DEFAULT_COUNT: usize = 5;
+const DEFAULT_SIZE: f32 = 24.0;
+const DEFAULT_SPACING: f32 = 4.0;
+
+/// Creates a new [`Rating`] showing `value` icons filled.
+pub fn rating<'a, Message, Theme>(value: f32) -> Rating<'a, Message, Theme>
+where
+    Theme: Catalog,
+{
+    Rating::new(value)
+}
+
+/// A row of icons filled up to a rating.
+#[allow(missing_debug_implementations)]
+pub struct Rating<'a, Message, Theme = iced::Theme>
+where
+    Theme: Catalog,
+{
+    value: f32,
+    count: usize,
+    half_steps: bool,
+    allow_clear: bool,
+    on_change: Option<Box<dyn Fn(f32) -> Message + 'a>>,
+    icon: String,
+    empty_icon: Option<String>,
+    size: f32,
+    spacing: f32,
+    font: iced::Font,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme> Rating<'a, Message, Theme>
+where
+    Theme: Catalog,
+{
+    /// Creates a new [`Rating`] showing `value` icons filled.
+    pub fn new(value: f32) -> Self {
+        Self {
+            value,
+            count: DEFAULT_COUNT,
+            half_steps: false,
+            allow_clear: false,
+            on_change: None,
+            icon: String::from("★"),
+            empty_icon: None,
+            size: DEFAULT_SIZE,
+            spacing: DEFAULT_SPACING,
+            font: iced::Font::default(),
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the message produced when a rating is picked, making the widget interactive.
+    pub fn on_change(mut self, on_change: impl Fn(f32) -> Message + 'a) -> Self {
+        self.on_change = Some(Box::new(on_change));
+        self
+    }
+
+    /// Sets the number of icons, the highest rating.
+    pub fn count(mut self, count: usize) -> Self {
+        self.count = count.max(1);
+        self
+    }
+
+    /// Allows picking half icons.
+    pub fn half_steps(mut self, half_steps: bool) -> Self {
+        self.half_steps = half_steps;
+        self
+    }
+
+    /// Lets a click on the current rating reset it to zero.
+    pub fn allow_clear(mut self, allow_clear: bool) -> Self {
+        self.allow_clear = allow_clear;
+        self
+    }
+
+    /// Sets the icon, a glyph such as "♥" or a character of an icon font.
+    pub fn icon(mut self, icon: impl Into<String>) -> Self {
+        self.icon = icon.into();
+        self
+    }
+
+    /// Sets a different icon for the unfilled part, such as "☆".
+    pub fn empty_icon(mut self, icon: impl Into<String>) -> Self {
+        self.empty_icon = Some(icon.into());
+        self
+    }
+
+    /// Sets the size of each icon.
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = size.into().0;
+        self
+    }
+
+    /// Sets the space between icons.
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets the font of the icons.
+    pub fn font(mut self, font: iced::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style.
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class.
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    fn step(&self) -> f32 {
+        if self.half_steps { 0.5 } else { 1.0 }
+    }
+
+    fn max(&self) -> f32 {
+        self.count as f32
+    }
+
+    fn icon_bounds(&self, bounds: Rectangle, index: usize) -> Rectangle {
+        Rectangle {
+            x: bounds.x + index as f32 * (self.size + self.spacing),
+            y: bounds.y,
+            width: self.size,
+            height: self.size,
+        }
+    }
+
+    /// The rating picked by a click at `x`: the icon under it, or half of it.
+    fn value_at(&self, bounds: Rectangle, x: f32) -> f32 {
+        let relative = (x - bounds.x).max(0.0);
+        let index = ((relative / (self.size + self.spacing)).floor() as usize).min(self.count - 1);
+        let within = (relative - index as f32 * (self.size + self.spacing)) / self.size;
+
+        let fill = if self.half_steps && within <= 0.5 { 0.5 } else { 1.0 };
+        index as f32 + fill
+    }
+
+    fn set(&self, value: f32, shell: &mut Shell<'_, Message>) {
+        let value = value.clamp(0.0, self.max());
+
+        if let Some(on_change) = &self.on_change
+            && value != self.value
+        {
+            shell.publish(on_change(value));
+        }
+    }
+
+    fn text(&self, content: &str) -> iced::advanced::Text<String, iced::Font> {
+        iced::advanced::Text {
+            content: content.to_string(),
+            bounds: Size::new(self.size, self.size),
+            size: Pixels(self.size),
+            font: self.font,
+            align_x: text::Alignment::Center,
+            align_y: alignment::Vertical::Center,
+            line_height: text::LineHeight::Absolute(Pixels(self.size)),
+            shaping: text::Shaping::Advanced,
+            wrapping: text::Wrapping::None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    is_focused: bool,
+}
+
+impl widget::operation::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Rating<'_, Message, Theme>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer<Font = iced::Font>,
+{
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: Length::Shrink,
+            height: Length::Shrink,
+        }
+    }
+
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(State::default())
+    }
+
+    fn layout(&mut self, _tree: &mut Tree, _renderer: &Renderer, limits: &Limits) -> Node {
+        let width = self.count as f32 * self.size + (self.count - 1) as f32 * self.spacing;
+
+        Node::new(limits.resolve(Length::Shrink, Length::Shrink, Size::new(width, self.size)))
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        if self.on_change.is_none() {
+            return;
+        }
+
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    state.is_focused = false;
+                    return;
+                };
+
+                let value = self.value_at(bounds, position.x);
+                let value = if self.allow_clear && value == self.value { 0.0 } else { value };
+
+                self.set(value, shell);
+                state.is_focused = true;
+                shell.capture_event();
+                shell.request_redraw();
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. } | mouse::Event::CursorLeft) => {
+                // Redraw the hover preview
+                shell.request_redraw();
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed { key, .. }) if state.is_focused => {
+                let value = match key {
+                    keyboard::Key::Named(keyboard::key::Named::ArrowLeft | keyboard::key::Named::ArrowDown) => {
+                        self.value - self.step()
+                    }
+                    keyboard::Key::Named(keyboard::key::Named::ArrowRight | keyboard::key::Named::ArrowUp) => {
+                        self.value + self.step()
+                    }
+                    keyboard::Key::Named(keyboard::key::Named::Home) => 0.0,
+                    keyboard::Key::Named(keyboard::key::Named::End) => self.max(),
+                    keyboard::Key::Character(c) => match c.parse::<u8>() {
+                        Ok(digit) if f32::from(digit) <= self.max() => f32::from(digit),
+                        _ => return,
+                    },
+                    _ => return,
+                };
+
+                self.set(value, shell);
+                shell.capture_event();
+                shell.request_redraw();
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _defaults: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let preview = cursor
+            .position_over(bounds)
+            .filter(|_| self.on_change.is_some())
+            .map(|position| self.value_at(bounds, position.x));
+
+        let status = match (&self.on_change, preview) {
+            (None, _) => Status::ReadOnly,
+            (Some(_), Some(_)) => Status::Hovered,
+            (Some(_), None) => Status::Active,
+        };
+        let style = theme.style(&self.class, status);
+
+        let value = preview.unwrap_or(self.value).clamp(0.0, self.max());
+        let fill_color = if preview.is_some() { style.hovered } else { style.filled };
+        let empty_icon = self.empty_icon.as_deref().unwrap_or(&self.icon);
+
+        for index in 0..self.count {
+            let icon = self.icon_bounds(bounds, index);
+            let fill = (value - index as f32).clamp(0.0, 1.0);
+            let center = icon.center();
+
+            if fill < 1.0 {
+                renderer.fill_text(self.text(empty_icon), center, style.empty, icon);
+            }
+
+            // Partially filled icons are the filled glyph clipped to the filled part
+            if fill > 0.0 {
+                let clip = Rectangle {
+                    width: icon.width * fill,
+                    ..icon
+                };
+                renderer.fill_text(self.text(&self.icon), center, fill_color, clip);
+            }
+        }
+
+        if state.is_focused
+            && let Some(color) = style.focus_ring
+        {
+            focus::draw_ring(renderer, bounds, Ring::new(color).width(1.0).offset(2.0).radius(4.0));
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self.on_change.is_some() && cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::None
+        }
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn widget::Operation,
+    ) {
+        if self.on_change.is_some() {
+            operation.focusable(None, layout.bounds(), tree.state.downcast_mut::<State>());
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Rating<'a, Message, Theme>> for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer<Font = iced::Font> + 'a,
+{
+    fn from(rating: Rating<'a, Message, Theme>) -> Self {
+        Element::new(rating)
+    }
+}
+
+/// The state of a [`Rating`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Active,
+    Hovered,
+    /// Displaying only, without [`Rating::on_change`]
+    ReadOnly,
+}
+
+/// The theme catalog of a [`Rating`].
+pub trait Catalog {
+    /// The style class
+    type Class<'a>;
+
+    /// Default style
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// Get the style for a class and status
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+/// The appearance of a [`Rating`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// Filled icons
+    pub filled: Color,
+    /// Unfilled icons
+    pub empty: Color,
+    /// Filled icons while previewing the hovered rating
+    pub hovered: Color,
+    /// Ring drawn around the focused rating
+    pub focus_ring: Option<Color>,
+}
+
+/// Styling function
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for iced::Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// Gold stars.
+pub fn default(theme: &iced::Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        filled: match status {
+            Status::ReadOnly => palette.warning.weak.color,
+            Status::Active | Status::Hovered => palette.warning.base.color,
+        },
+        empty: palette.background.strong.color,
+        hovered: palette.warning.strong.color,
+        focus_ring: Some(palette.primary.weak.color),
+    }
+}
+
+/// Icons in the primary color, such as hearts.
+pub fn primary(theme: &iced::Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        filled: match status {
+            Status::ReadOnly => palette.primary.weak.color,
+            Status::Active | Status::Hovered => palette.primary.base.color,
+        },
+        hovered: palette.primary.strong.color,
+        ..default(theme, status)
+    }
+}
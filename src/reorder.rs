@@ -0,0 +1,153 @@
+//! Drag-to-reorder shared by the widgets that let a row or column of items be rearranged,
+//! such as collapsible groups and tab bars.
+//!
+//! Drags are published to [`crate::dnd`], so an item can also be dropped on another widget.
+
+use iced::{Point, Rectangle};
+
+use crate::dnd::{Payload, Press};
+
+/// The direction the items are laid out in.
+#[allow(dead_code)] // Builds with a single reordering widget only use one axis
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Axis {
+    Horizontal,
+    Vertical,
+}
+
+impl Axis {
+    fn center(self, bounds: &Rectangle) -> f32 {
+        match self {
+            Axis::Horizontal => bounds.center_x(),
+            Axis::Vertical => bounds.center_y(),
+        }
+    }
+
+    fn start(self, bounds: &Rectangle) -> f32 {
+        match self {
+            Axis::Horizontal => bounds.x,
+            Axis::Vertical => bounds.y,
+        }
+    }
+
+    fn end(self, bounds: &Rectangle) -> f32 {
+        match self {
+            Axis::Horizontal => bounds.x + bounds.width,
+            Axis::Vertical => bounds.y + bounds.height,
+        }
+    }
+
+    fn coordinate(self, point: Point) -> f32 {
+        match self {
+            Axis::Horizontal => point.x,
+            Axis::Vertical => point.y,
+        }
+    }
+}
+
+/// An item being dragged to a new position.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Reorder {
+    /// Payload kind the drag is published as
+    pub kind: &'static str,
+    pub index: usize,
+    pub press: Press,
+    /// Insertion slot, between `0` (before the first) and the number of items (after the last)
+    pub target: usize,
+}
+
+impl Reorder {
+    /// Starts following a press on the item at `index`, at `origin` in window coordinates.
+    pub fn new(kind: &'static str, index: usize, origin: Point) -> Self {
+        Self {
+            kind,
+            index,
+            press: Press::new(origin),
+            target: index,
+        }
+    }
+
+    /// Follows the cursor, returning `true` the moment the press turns into a drag.
+    ///
+    /// `position` is in the coordinates of `items`, `window` is where the cursor is on screen.
+    /// They only differ for items the widget mirrors right to left.
+    pub fn drag_to(
+        &mut self,
+        position: Point,
+        window: Point,
+        items: impl IntoIterator<Item = Rectangle>,
+        axis: Axis,
+    ) -> bool {
+        let started = self.press.drag_to(window, || Payload::new(self.kind, [self.index]));
+
+        if self.press.active {
+            let cursor = axis.coordinate(position);
+            self.target = items
+                .into_iter()
+                .filter(|bounds| axis.center(bounds) < cursor)
+                .count();
+        }
+
+        started
+    }
+
+    /// Ends the drag once the button is released, returning the new order of `len` items
+    /// like [`Reorder::order`], or `None` if a drop target elsewhere took the item.
+    pub fn release(&self, len: usize) -> Option<Vec<usize>> {
+        if !self.press.release() {
+            return None;
+        }
+
+        self.order(len)
+    }
+
+    /// Abandons the drag, leaving the items in place.
+    pub fn cancel(&self) {
+        self.press.cancel();
+    }
+
+    /// Returns whether the press has turned into a drag.
+    pub fn is_active(&self) -> bool {
+        self.press.active
+    }
+
+    /// The index the dragged item ends up at once dropped.
+    pub fn slot(&self) -> usize {
+        if self.target > self.index {
+            self.target - 1
+        } else {
+            self.target
+        }
+    }
+
+    /// The new order of `len` items, or `None` if dropping leaves them in place.
+    ///
+    /// `order[i]` is the previous index of the item now at `i`.
+    pub fn order(&self, len: usize) -> Option<Vec<usize>> {
+        if !self.press.active || self.index >= len || self.slot() == self.index {
+            return None;
+        }
+
+        let mut order: Vec<usize> = (0..len).collect();
+        let moved = order.remove(self.index);
+        order.insert(self.slot().min(len - 1), moved);
+
+        Some(order)
+    }
+}
+
+/// Where the drop indicator goes along `axis`: the gap before the item at `target`.
+pub(crate) fn gap_position(items: &[Rectangle], target: usize, axis: Axis) -> Option<f32> {
+    match target {
+        0 => items.first().map(|first| axis.start(first)),
+        target if target >= items.len() => items.last().map(|last| axis.end(last)),
+        target => Some((axis.end(&items[target - 1]) + axis.start(&items[target])) / 2.0),
+    }
+}
+
+/// Rearranges `items` into `order`, as returned by [`Reorder::order`].
+pub(crate) fn apply<T>(items: &mut Vec<T>, order: &[usize]) {
+    let mut taken: Vec<Option<T>> = std::mem::take(items).into_iter().map(Some).collect();
+
+    items.extend(order.iter().filter_map(|&index| taken[index].take()));
+}
@@ -0,0 +1,29 @@
+//! Case-insensitive text search, shared by the widgets that filter by what is typed so a
+//! tree filter and a combobox match the same way.
+
+use std::ops::Range;
+
+/// The byte range of the first occurrence of `query` in `text` ignoring case, with the
+/// query trimmed. Characters are compared by their lowercase forms, which may be longer
+/// than one char. An empty query matches nothing.
+pub(crate) fn find_ignore_case(text: &str, query: &str) -> Option<Range<usize>> {
+    let query: Vec<char> = query.trim().chars().flat_map(char::to_lowercase).collect();
+    if query.is_empty() {
+        return None;
+    }
+
+    text.char_indices().find_map(|(start, _)| {
+        let mut rest = query.iter();
+        for (offset, c) in text[start..].char_indices() {
+            for lower in c.to_lowercase() {
+                if rest.next() != Some(&lower) {
+                    return None;
+                }
+            }
+            if rest.as_slice().is_empty() {
+                return Some(start..start + offset + c.len_utf8());
+            }
+        }
+        None
+    })
+}
@@ -0,0 +1,754 @@
+//! A field that records a keyboard shortcut.
+//!
+//! Clicking a [`ShortcutInput`] (or focusing it) starts recording: the next key pressed,
+//! together with the modifiers held, becomes the shortcut and is shown as a row of key caps.
+//! Modifiers alone are shown while held but are not recorded. Escape cancels recording and
+//! Backspace clears the shortcut, if [`ShortcutInput::on_clear`] is set.
+//!
+//! A recorded shortcut that is already taken by an entry of the conflict list is not reported;
+//! the field shows what uses it instead and keeps recording.
+
+use std::fmt;
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        mouse, renderer, text,
+        widget::{self, tree::Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment, keyboard, Background, Border, Color, Element, Event, Length, Padding, Pixels, Point, Rectangle, Size,
+};
+
+use crate::pixel;
+
+const CAP_PADDING: Padding = Padding {
+    top: 1.0,
+    right: 6.0,
+    bottom: 1.0,
+    left: 6.0,
+};
+const CAP_SPACING: f32 = 4.0;
+const CLEAR_SIZE: f32 = 16.0;
+
+/// Creates a new [`ShortcutInput`] showing `shortcut`, producing `on_record` for a new one.
+pub fn shortcut_input<'a, Message, Theme>(
+    shortcut: Option<&Shortcut>,
+    on_record: impl Fn(Shortcut) -> Message + 'a,
+) -> ShortcutInput<'a, Message, Theme>
+where
+    Theme: Catalog,
+{
+    ShortcutInput::new(shortcut, on_record)
+}
+
+/// A key together with the modifiers held with it.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Shortcut {
+    /// The modifiers held
+    pub modifiers: keyboard::Modifiers,
+    /// The key pressed, without the modifiers applied
+    pub key: keyboard::Key,
+}
+
+impl Shortcut {
+    /// Creates a new [`Shortcut`] of `key` with `modifiers` held.
+    ///
+    /// Characters are kept in lowercase, so the same keys are the same shortcut.
+    pub fn new(modifiers: keyboard::Modifiers, key: keyboard::Key) -> Self {
+        let key = match key {
+            keyboard::Key::Character(c) => keyboard::Key::Character(c.to_lowercase().into()),
+            key => key,
+        };
+
+        Self { modifiers, key }
+    }
+
+    /// Whether a key press of `key` with `modifiers` held triggers the shortcut.
+    pub fn matches(&self, key: &keyboard::Key, modifiers: keyboard::Modifiers) -> bool {
+        *self == Self::new(modifiers, key.clone())
+    }
+
+    /// Parses a shortcut written as modifiers and a key joined by `+`, such as "Ctrl+Shift+S".
+    ///
+    /// Modifiers are "Ctrl", "Alt" (or "Option"), "Shift", "Super" (or "Cmd") and "Mod", which is
+    /// Cmd on macOS and Ctrl elsewhere. Keys are single characters or names like "Enter", "Esc",
+    /// "Up" or "F5", all case insensitive. `None` if any part is unknown.
+    pub fn parse(text: &str) -> Option<Self> {
+        let (names, key) = match text.strip_suffix("++") {
+            Some(names) => (names, "+"),
+            None if text == "+" => ("", "+"),
+            None => text.rsplit_once('+').unwrap_or(("", text)),
+        };
+
+        let mut modifiers = keyboard::Modifiers::empty();
+        for name in names.split('+') {
+            modifiers |= match name.trim().to_ascii_lowercase().as_str() {
+                "ctrl" | "control" => keyboard::Modifiers::CTRL,
+                "alt" | "option" => keyboard::Modifiers::ALT,
+                "shift" => keyboard::Modifiers::SHIFT,
+                "super" | "cmd" | "logo" | "meta" | "win" => keyboard::Modifiers::LOGO,
+                "mod" | "command" => keyboard::Modifiers::COMMAND,
+                "" if names.is_empty() => keyboard::Modifiers::empty(),
+                _ => return None,
+            };
+        }
+
+        parse_key(key.trim()).map(|key| Self::new(modifiers, key))
+    }
+
+    /// The names of the modifiers and the key, in the order they are written.
+    pub fn parts(&self) -> Vec<String> {
+        modifier_names(self.modifiers)
+            .into_iter()
+            .map(String::from)
+            .chain([key_name(&self.key)])
+            .collect()
+    }
+}
+
+impl fmt::Display for Shortcut {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.parts().join("+"))
+    }
+}
+
+fn modifier_names(modifiers: keyboard::Modifiers) -> Vec<&'static str> {
+    [
+        (modifiers.control(), "Ctrl"),
+        (modifiers.alt(), if cfg!(target_os = "macos") { "Option" } else { "Alt" }),
+        (modifiers.shift(), "Shift"),
+        (modifiers.logo(), if cfg!(target_os = "macos") { "Cmd" } else { "Super" }),
+    ]
+    .into_iter()
+    .filter_map(|(held, name)| held.then_some(name))
+    .collect()
+}
+
+fn key_name(key: &keyboard::Key) -> String {
+    use keyboard::key::Named;
+
+    match key {
+        keyboard::Key::Character(c) => c.to_uppercase(),
+        keyboard::Key::Named(named) => match named {
+            Named::ArrowUp => String::from("Up"),
+            Named::ArrowDown => String::from("Down"),
+            Named::ArrowLeft => String::from("Left"),
+            Named::ArrowRight => String::from("Right"),
+            Named::Escape => String::from("Esc"),
+            Named::Delete => String::from("Del"),
+            Named::PageUp => String::from("PgUp"),
+            Named::PageDown => String::from("PgDn"),
+            named => format!("{named:?}"),
+        },
+        keyboard::Key::Unidentified => String::from("?"),
+    }
+}
+
+/// The key named `name`, either a single character or one of the names [`key_name`] writes.
+fn parse_key(name: &str) -> Option<keyboard::Key> {
+    use keyboard::key::Named;
+
+    let mut chars = name.chars();
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+        return Some(keyboard::Key::Character(c.to_string().into()));
+    }
+
+    [
+        Named::ArrowUp, Named::ArrowDown, Named::ArrowLeft, Named::ArrowRight, Named::Escape,
+        Named::Delete, Named::PageUp, Named::PageDown, Named::Enter, Named::Tab, Named::Space,
+        Named::Backspace, Named::Home, Named::End, Named::Insert, Named::F1, Named::F2, Named::F3,
+        Named::F4, Named::F5, Named::F6, Named::F7, Named::F8, Named::F9, Named::F10, Named::F11,
+        Named::F12,
+    ]
+    .into_iter()
+    .map(keyboard::Key::Named)
+    .find(|key| {
+        let keyboard::Key::Named(named) = key else {
+            return false;
+        };
+
+        key_name(key).eq_ignore_ascii_case(name) || format!("{named:?}").eq_ignore_ascii_case(name)
+    })
+}
+
+fn is_modifier(key: &keyboard::Key) -> bool {
+    use keyboard::key::Named;
+
+    matches!(
+        key,
+        keyboard::Key::Named(
+            Named::Shift | Named::Control | Named::Alt | Named::AltGraph | Named::Super | Named::Meta | Named::Hyper
+        )
+    )
+}
+
+/// Whether `key` is one of F1 to F35.
+fn is_function_key(key: &keyboard::Key) -> bool {
+    let keyboard::Key::Named(named) = key else {
+        return false;
+    };
+    let name = format!("{named:?}");
+
+    name.len() > 1 && name.starts_with('F') && name[1..].bytes().all(|byte| byte.is_ascii_digit())
+}
+
+/// A field recording the next key combination pressed while it is focused.
+#[allow(missing_debug_implementations)]
+pub struct ShortcutInput<'a, Message, Theme = iced::Theme>
+where
+    Theme: Catalog,
+{
+    shortcut: Option<Shortcut>,
+    on_record: Box<dyn Fn(Shortcut) -> Message + 'a>,
+    on_clear: Option<Box<dyn Fn() -> Message + 'a>>,
+    conflicts: Vec<(Shortcut, String)>,
+    require_modifier: bool,
+    placeholder: String,
+    width: Length,
+    padding: Padding,
+    text_size: Pixels,
+    font: iced::Font,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme> ShortcutInput<'a, Message, Theme>
+where
+    Theme: Catalog,
+{
+    /// Creates a new [`ShortcutInput`] showing `shortcut`, producing `on_record` for a new one.
+    pub fn new(shortcut: Option<&Shortcut>, on_record: impl Fn(Shortcut) -> Message + 'a) -> Self {
+        Self {
+            shortcut: shortcut.cloned(),
+            on_record: Box::new(on_record),
+            on_clear: None,
+            conflicts: Vec::new(),
+            require_modifier: false,
+            placeholder: String::from("Click to record"),
+            width: Length::Fixed(220.0),
+            padding: Padding::from([4, 8]),
+            text_size: Pixels(14.0),
+            font: iced::Font::default(),
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the message produced when the shortcut is cleared, with Backspace or the × button.
+    pub fn on_clear(self, message: Message) -> Self
+    where
+        Message: Clone + 'a,
+    {
+        self.on_clear_with(move || message.clone())
+    }
+
+    /// Sets the function making the message produced when the shortcut is cleared, for
+    /// messages that can not be cloned.
+    pub fn on_clear_with(mut self, on_clear: impl Fn() -> Message + 'a) -> Self {
+        self.on_clear = Some(Box::new(on_clear));
+        self
+    }
+
+    /// Sets the shortcuts already taken, each with the name of what uses it.
+    pub fn conflicts(mut self, conflicts: impl IntoIterator<Item = (Shortcut, impl Into<String>)>) -> Self {
+        self.conflicts = conflicts.into_iter().map(|(shortcut, name)| (shortcut, name.into())).collect();
+        self
+    }
+
+    /// Sets whether keys other than function keys need a modifier held to be recorded.
+    pub fn require_modifier(mut self, require: bool) -> Self {
+        self.require_modifier = require;
+        self
+    }
+
+    /// Sets the text shown while there is no shortcut.
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Sets the width of the field.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the padding of the field.
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size.
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = size.into();
+        self
+    }
+
+    /// Sets the font.
+    pub fn font(mut self, font: iced::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style.
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class.
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// What already uses `shortcut`, other than this field.
+    fn conflict(&self, shortcut: &Shortcut) -> Option<&str> {
+        if self.shortcut.as_ref() == Some(shortcut) {
+            return None;
+        }
+
+        self.conflicts
+            .iter()
+            .find(|(taken, _)| taken == shortcut)
+            .map(|(_, name)| name.as_str())
+    }
+
+    fn clear_bounds(&self, bounds: Rectangle) -> Option<Rectangle> {
+        (self.on_clear.is_some() && self.shortcut.is_some()).then(|| Rectangle {
+            x: bounds.x + bounds.width - self.padding.right - CLEAR_SIZE,
+            y: bounds.center_y() - CLEAR_SIZE / 2.0,
+            width: CLEAR_SIZE,
+            height: CLEAR_SIZE,
+        })
+    }
+
+    fn text(&self, content: impl Into<String>, bounds: Size) -> iced::advanced::Text<String, iced::Font> {
+        iced::advanced::Text {
+            content: content.into(),
+            bounds,
+            size: self.text_size,
+            font: self.font,
+            align_x: text::Alignment::Left,
+            align_y: alignment::Vertical::Center,
+            line_height: text::LineHeight::default(),
+            shaping: text::Shaping::Basic,
+            wrapping: text::Wrapping::None,
+        }
+    }
+
+    fn measure<P: text::Paragraph<Font = iced::Font>>(&self, content: &str) -> Size {
+        P::with_text(iced::advanced::Text {
+            content,
+            bounds: Size::INFINITE,
+            size: self.text_size,
+            font: self.font,
+            align_x: text::Alignment::Left,
+            align_y: alignment::Vertical::Top,
+            line_height: text::LineHeight::default(),
+            shaping: text::Shaping::Basic,
+            wrapping: text::Wrapping::None,
+        })
+        .min_bounds()
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    is_recording: bool,
+    modifiers: keyboard::Modifiers,
+    /// The last shortcut pressed that was taken, and what takes it
+    conflict: Option<(Shortcut, String)>,
+}
+
+impl widget::operation::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_recording
+    }
+
+    fn focus(&mut self) {
+        self.is_recording = true;
+        self.conflict = None;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_recording = false;
+        self.conflict = None;
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for ShortcutInput<'_, Message, Theme>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer<Font = iced::Font>,
+{
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(State::default())
+    }
+
+    fn layout(&mut self, _tree: &mut Tree, _renderer: &Renderer, limits: &Limits) -> Node {
+        let line = text::LineHeight::default().to_absolute(self.text_size).0;
+        let height = line + CAP_PADDING.top + CAP_PADDING.bottom + self.padding.top + self.padding.bottom;
+
+        Node::new(limits.resolve(self.width, Length::Shrink, Size::new(0.0, height)))
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                if let Some(clear) = self.clear_bounds(bounds)
+                    && cursor.is_over(clear)
+                    && let Some(on_clear) = &self.on_clear
+                {
+                    shell.publish(on_clear());
+                    state.is_recording = false;
+                    state.conflict = None;
+                    shell.capture_event();
+                } else if cursor.is_over(bounds) {
+                    state.is_recording = true;
+                    state.conflict = None;
+                    shell.capture_event();
+                } else if state.is_recording {
+                    state.is_recording = false;
+                    state.conflict = None;
+                }
+                shell.request_redraw();
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                shell.request_redraw();
+            }
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.modifiers = *modifiers;
+                if state.is_recording {
+                    shell.request_redraw();
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key, modifiers, repeat, ..
+            }) if state.is_recording => {
+                shell.capture_event();
+                shell.request_redraw();
+
+                if *repeat || is_modifier(key) {
+                    return;
+                }
+
+                let bare = modifiers.is_empty();
+                match key {
+                    keyboard::Key::Named(keyboard::key::Named::Escape) if bare => {
+                        state.is_recording = false;
+                        state.conflict = None;
+                        return;
+                    }
+                    keyboard::Key::Named(keyboard::key::Named::Backspace) if bare && self.on_clear.is_some() => {
+                        if let Some(on_clear) = &self.on_clear {
+                            shell.publish(on_clear());
+                        }
+                        state.is_recording = false;
+                        state.conflict = None;
+                        return;
+                    }
+                    _ => {}
+                }
+
+                if self.require_modifier && bare && !is_function_key(key) {
+                    return;
+                }
+
+                let shortcut = Shortcut::new(*modifiers, key.clone());
+                if let Some(name) = self.conflict(&shortcut) {
+                    state.conflict = Some((shortcut, name.to_string()));
+                } else {
+                    state.conflict = None;
+                    state.is_recording = false;
+                    shell.publish((self.on_record)(shortcut));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _defaults: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let status = if state.conflict.is_some() {
+            Status::Conflict
+        } else if state.is_recording {
+            Status::Recording
+        } else if cursor.is_over(bounds) {
+            Status::Hovered
+        } else {
+            Status::Active
+        };
+        let style = theme.style(&self.class, status);
+        let clip = bounds.intersection(viewport).unwrap_or(bounds);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: pixel::snap(bounds),
+                border: pixel::border(style.border),
+                ..renderer::Quad::default()
+            },
+            style.background,
+        );
+
+        // While recording, the held modifiers; after a conflict, the taken shortcut
+        let (parts, trailing) = match (&state.conflict, state.is_recording) {
+            (Some((shortcut, name)), _) => (shortcut.parts(), Some((format!("Used by {name}"), style.conflict))),
+            (None, true) => {
+                let held: Vec<String> = modifier_names(state.modifiers).into_iter().map(String::from).collect();
+                let prompt = if held.is_empty() { "Press a shortcut" } else { "…" };
+
+                (held, Some((String::from(prompt), style.placeholder)))
+            }
+            (None, false) => match &self.shortcut {
+                Some(shortcut) => (shortcut.parts(), None),
+                None => (Vec::new(), Some((self.placeholder.clone(), style.placeholder))),
+            },
+        };
+
+        let line = text::LineHeight::default().to_absolute(self.text_size).0;
+        let cap_height = line + CAP_PADDING.top + CAP_PADDING.bottom;
+        let mut x = bounds.x + self.padding.left;
+
+        for part in parts {
+            let width = self.measure::<Renderer::Paragraph>(&part).width + CAP_PADDING.left + CAP_PADDING.right;
+            let cap = Rectangle::new(Point::new(x, bounds.center_y() - cap_height / 2.0), Size::new(width, cap_height));
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: pixel::snap(cap),
+                    border: pixel::border(style.cap_border),
+                    ..renderer::Quad::default()
+                },
+                style.cap_background,
+            );
+            renderer.fill_text(
+                iced::advanced::Text {
+                    align_x: text::Alignment::Center,
+                    ..self.text(part, cap.size())
+                },
+                cap.center(),
+                style.cap_text,
+                clip,
+            );
+            x += width + CAP_SPACING;
+        }
+
+        if let Some((content, color)) = trailing {
+            let end = self.clear_bounds(bounds).map_or(bounds.x + bounds.width - self.padding.right, |clear| clear.x);
+            let area = Rectangle::new(
+                Point::new(x, bounds.y),
+                Size::new((end - x).max(0.0), bounds.height),
+            );
+
+            renderer.fill_text(
+                self.text(content, area.size()),
+                Point::new(area.x, area.center_y()),
+                color,
+                area.intersection(&clip).unwrap_or(area),
+            );
+        }
+
+        if let Some(clear) = self.clear_bounds(bounds) {
+            if cursor.is_over(clear) {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: pixel::snap(clear),
+                        border: pixel::border(Border {
+                            radius: (CLEAR_SIZE / 2.0).into(),
+                            ..Border::default()
+                        }),
+                        ..renderer::Quad::default()
+                    },
+                    style.clear_hovered_background,
+                );
+            }
+            renderer.fill_text(
+                iced::advanced::Text {
+                    align_x: text::Alignment::Center,
+                    ..self.text("×", clear.size())
+                },
+                clear.center(),
+                style.placeholder,
+                clip,
+            );
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::None
+        }
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn widget::Operation,
+    ) {
+        operation.focusable(None, layout.bounds(), tree.state.downcast_mut::<State>());
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<ShortcutInput<'a, Message, Theme>> for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer<Font = iced::Font> + 'a,
+{
+    fn from(input: ShortcutInput<'a, Message, Theme>) -> Self {
+        Element::new(input)
+    }
+}
+
+/// The state of a [`ShortcutInput`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Active,
+    Hovered,
+    Recording,
+    /// Recording, after a shortcut that is already taken
+    Conflict,
+}
+
+/// The theme catalog of a [`ShortcutInput`].
+pub trait Catalog {
+    /// The style class
+    type Class<'a>;
+
+    /// Default style
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// Get the style for a class and status
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+/// The appearance of a [`ShortcutInput`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// Background of the field
+    pub background: Background,
+    /// Border of the field
+    pub border: Border,
+    /// Color of the placeholder and prompts
+    pub placeholder: Color,
+    /// Color of the message naming a conflict
+    pub conflict: Color,
+    /// Background of a key cap
+    pub cap_background: Background,
+    /// Border of a key cap
+    pub cap_border: Border,
+    /// Label of a key cap
+    pub cap_text: Color,
+    /// Background behind the hovered × button
+    pub clear_hovered_background: Background,
+}
+
+/// Styling function
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for iced::Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default shortcut input style.
+pub fn default(theme: &iced::Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: palette.background.base.color.into(),
+        border: Border {
+            color: match status {
+                Status::Active => palette.background.strong.color,
+                Status::Hovered => palette.background.base.text,
+                Status::Recording => palette.primary.strong.color,
+                Status::Conflict => palette.danger.base.color,
+            },
+            width: 1.0,
+            radius: 4.0.into(),
+        },
+        placeholder: palette.background.strong.color,
+        conflict: palette.danger.base.color,
+        cap_background: palette.background.weak.color.into(),
+        cap_border: Border {
+            color: palette.background.strong.color,
+            width: 1.0,
+            radius: 3.0.into(),
+        },
+        cap_text: palette.background.weak.text,
+        clear_hovered_background: palette.danger.weak.color.into(),
+    }
+}
+
+/// Key caps in the primary color.
+pub fn primary(theme: &iced::Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        cap_background: palette.primary.base.color.into(),
+        cap_border: Border {
+            radius: 3.0.into(),
+            ..Border::default()
+        },
+        cap_text: palette.primary.base.text,
+        ..default(theme, status)
+    }
+}
@@ -0,0 +1,736 @@
+//! A side panel next to the main content, resized by dragging and collapsed to a rail.
+//!
+//! The panel keeps its width while collapsed, so expanding it again brings back the width it
+//! had. Dragging the handle narrower than about half the minimum width collapses the panel,
+//! dragging it back out expands it, and double-clicking the handle toggles it. While collapsed
+//! the [`Sidebar::rail`] is shown instead of the panel, typically a column of icon buttons.
+//!
+//! Width and expansion live in the widget, and are reported through [`Sidebar::on_resize`]
+//! and [`Sidebar::on_toggle`]. Passing them back with [`Sidebar::panel_width`] and
+//! [`Sidebar::expanded`] remembers them across views, or sets them from the app.
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        mouse::{self, click},
+        overlay, renderer,
+        widget::{self, tree::Tree, Operation},
+        Clipboard, Layout, Shell, Widget,
+    },
+    Background, Border, Color, Element, Event, Length, Point, Rectangle, Size, Vector,
+};
+
+use crate::direction::{self, Direction};
+use crate::pixel;
+
+/// Creates a new [`Sidebar`] with `panel` beside `content`.
+pub fn sidebar<'a, Message, Theme, Renderer>(
+    panel: impl Into<Element<'a, Message, Theme, Renderer>>,
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Sidebar<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    Sidebar::new(panel, content)
+}
+
+/// The edge of the container the panel sits on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Side {
+    #[default]
+    Left,
+    Right,
+}
+
+/// A resizable, collapsible side panel next to some content.
+#[allow(missing_debug_implementations)]
+pub struct Sidebar<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Theme: Catalog,
+{
+    /// The panel, the content and the rail, if any, in that order
+    children: Vec<Element<'a, Message, Theme, Renderer>>,
+    side: Side,
+    direction: Option<Direction>,
+    panel_width: Option<f32>,
+    expanded: Option<bool>,
+    on_resize: Option<Box<dyn Fn(f32) -> Message + 'a>>,
+    on_toggle: Option<Box<dyn Fn(bool) -> Message + 'a>>,
+    min_width: f32,
+    max_width: f32,
+    rail_width: f32,
+    handle_width: f32,
+    width: Length,
+    height: Length,
+    class: Theme::Class<'a>,
+}
+
+const PANEL: usize = 0;
+const CONTENT: usize = 1;
+const RAIL: usize = 2;
+
+impl<'a, Message, Theme, Renderer> Sidebar<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    /// Creates a new [`Sidebar`] with `panel` beside `content`.
+    pub fn new(
+        panel: impl Into<Element<'a, Message, Theme, Renderer>>,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            children: vec![panel.into(), content.into()],
+            side: Side::Left,
+            direction: None,
+            panel_width: None,
+            expanded: None,
+            on_resize: None,
+            on_toggle: None,
+            min_width: 160.0,
+            max_width: 480.0,
+            rail_width: 48.0,
+            handle_width: 6.0,
+            width: Length::Fill,
+            height: Length::Fill,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets what is shown in place of the panel while it is collapsed.
+    pub fn rail(mut self, rail: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        self.children.truncate(RAIL);
+        self.children.push(rail.into());
+        self
+    }
+
+    /// Sets the edge the panel sits on.
+    pub fn side(mut self, side: Side) -> Self {
+        self.side = side;
+        self
+    }
+
+    /// Sets the [`Direction`] of the [`Sidebar`], instead of the one set with [`direction::set`].
+    ///
+    /// Right to left puts a panel on the [`Side::Left`] at the right edge, and the other way around.
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    /// Sets the width of the panel, applied whenever it differs from the last one passed.
+    pub fn panel_width(mut self, width: f32) -> Self {
+        self.panel_width = Some(width);
+        self
+    }
+
+    /// Expands or collapses the panel, applied whenever it differs from the last value passed.
+    pub fn expanded(mut self, expanded: bool) -> Self {
+        self.expanded = Some(expanded);
+        self
+    }
+
+    /// Sets the message produced with the new width while the panel is resized.
+    pub fn on_resize(mut self, on_resize: impl Fn(f32) -> Message + 'a) -> Self {
+        self.on_resize = Some(Box::new(on_resize));
+        self
+    }
+
+    /// Sets the message produced when the panel is expanded or collapsed, with whether it is expanded.
+    pub fn on_toggle(mut self, on_toggle: impl Fn(bool) -> Message + 'a) -> Self {
+        self.on_toggle = Some(Box::new(on_toggle));
+        self
+    }
+
+    /// Sets the narrowest the panel can be resized to.
+    pub fn min_width(mut self, width: f32) -> Self {
+        self.min_width = width;
+        self
+    }
+
+    /// Sets the widest the panel can be resized to.
+    pub fn max_width(mut self, width: f32) -> Self {
+        self.max_width = width;
+        self
+    }
+
+    /// Sets the width of the rail shown while collapsed.
+    pub fn rail_width(mut self, width: f32) -> Self {
+        self.rail_width = width;
+        self
+    }
+
+    /// Sets the width of the handle between the panel and the content.
+    pub fn handle_width(mut self, width: f32) -> Self {
+        self.handle_width = width;
+        self
+    }
+
+    /// Sets the width of the whole container.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the whole container.
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the style.
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class.
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// The edge the panel is on, mirrored right to left.
+    fn edge(&self) -> Side {
+        match (self.side, self.direction.unwrap_or_else(direction::get)) {
+            (Side::Left, Direction::RightToLeft) => Side::Right,
+            (Side::Right, Direction::RightToLeft) => Side::Left,
+            (side, Direction::LeftToRight) => side,
+        }
+    }
+
+    fn clamp(&self, width: f32) -> f32 {
+        width.clamp(self.min_width, self.max_width.max(self.min_width))
+    }
+
+    /// The width taken by the panel or the rail.
+    fn side_width(&self, state: &State) -> f32 {
+        if state.expanded {
+            state.width
+        } else {
+            self.collapsed_width()
+        }
+    }
+
+    fn collapsed_width(&self) -> f32 {
+        if self.children.len() > RAIL {
+            self.rail_width
+        } else {
+            0.0
+        }
+    }
+
+    /// The children laid out and shown, the panel or the rail, then the content.
+    fn shown(&self, state: &State) -> [Option<usize>; 2] {
+        let side = if state.expanded {
+            Some(PANEL)
+        } else {
+            (self.children.len() > RAIL).then_some(RAIL)
+        };
+
+        [side, Some(CONTENT)]
+    }
+
+    fn handle_bounds(&self, state: &State, bounds: Rectangle) -> Rectangle {
+        let side = self.side_width(state).min((bounds.width - self.handle_width).max(0.0));
+        let x = match self.edge() {
+            Side::Left => bounds.x + side,
+            Side::Right => bounds.x + bounds.width - side - self.handle_width,
+        };
+
+        Rectangle::new(Point::new(x, bounds.y), Size::new(self.handle_width, bounds.height))
+    }
+
+    /// How far `position` is from the edge the panel sits on.
+    fn distance(&self, bounds: Rectangle, position: Point) -> f32 {
+        match self.edge() {
+            Side::Left => position.x - bounds.x,
+            Side::Right => bounds.x + bounds.width - position.x,
+        }
+    }
+
+    fn toggle(&self, state: &mut State, shell: &mut Shell<'_, Message>) {
+        state.expanded = !state.expanded;
+
+        if let Some(on_toggle) = &self.on_toggle {
+            shell.publish(on_toggle(state.expanded));
+        }
+        shell.invalidate_layout();
+        shell.request_redraw();
+    }
+
+    fn resize(&self, state: &mut State, width: f32, shell: &mut Shell<'_, Message>) {
+        if width == state.width {
+            return;
+        }
+
+        state.width = width;
+        if let Some(on_resize) = &self.on_resize {
+            shell.publish(on_resize(width));
+        }
+        shell.invalidate_layout();
+        shell.request_redraw();
+    }
+}
+
+#[derive(Debug)]
+struct State {
+    width: f32,
+    expanded: bool,
+    /// The width and expansion last passed by the app
+    synced_width: Option<f32>,
+    synced_expanded: Option<bool>,
+    drag: Option<Drag>,
+    last_click: Option<mouse::Click>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Drag {
+    /// How far into the handle it was grabbed
+    grab: f32,
+    /// The width before the drag, kept if the drag collapses the panel
+    width: f32,
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Sidebar<'_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(State {
+            width: self.clamp(self.panel_width.unwrap_or(240.0)),
+            expanded: self.expanded.unwrap_or(true),
+            synced_width: self.panel_width,
+            synced_expanded: self.expanded,
+            drag: None,
+            last_click: None,
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.children.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.children);
+
+        let state = tree.state.downcast_mut::<State>();
+        if let Some(width) = self.panel_width
+            && state.synced_width != Some(width)
+        {
+            state.width = self.clamp(width);
+            state.synced_width = Some(width);
+        }
+        if let Some(expanded) = self.expanded
+            && state.synced_expanded != Some(expanded)
+        {
+            state.expanded = expanded;
+            state.synced_expanded = Some(expanded);
+        }
+    }
+
+    fn layout(&mut self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let size = limits.resolve(self.width, self.height, Size::ZERO);
+        let state = tree.state.downcast_ref::<State>();
+
+        let side = self.side_width(state).min((size.width - self.handle_width).max(0.0));
+        let content = (size.width - side - self.handle_width).max(0.0);
+        let (side_x, content_x) = match self.edge() {
+            Side::Left => (0.0, side + self.handle_width),
+            Side::Right => (content + self.handle_width, 0.0),
+        };
+        let shown = self.shown(state);
+
+        // Hidden children keep an empty node so layouts line up with the children
+        let children = self
+            .children
+            .iter_mut()
+            .zip(&mut tree.children)
+            .enumerate()
+            .map(|(index, (child, tree))| {
+                if !shown.contains(&Some(index)) {
+                    return Node::new(Size::ZERO);
+                }
+
+                let (width, x) = if index == CONTENT {
+                    (content, content_x)
+                } else {
+                    (side, side_x)
+                };
+                let size = Size::new(width, size.height);
+
+                child
+                    .as_widget_mut()
+                    .layout(tree, renderer, &Limits::new(size, size))
+                    .move_to(Point::new(x, 0.0))
+            })
+            .collect();
+
+        Node::with_children(size, children)
+    }
+
+    fn operate(&mut self, tree: &mut Tree, layout: Layout<'_>, renderer: &Renderer, operation: &mut dyn Operation) {
+        let shown = self.shown(tree.state.downcast_ref::<State>());
+
+        operation.container(None, layout.bounds());
+        operation.traverse(&mut |operation| {
+            for (index, ((child, tree), layout)) in self
+                .children
+                .iter_mut()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .enumerate()
+            {
+                if shown.contains(&Some(index)) {
+                    child.as_widget_mut().operate(tree, layout, renderer, operation);
+                }
+            }
+        });
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_mut::<State>();
+        let handle = self.handle_bounds(state, bounds);
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                if let Some(position) = cursor.position_over(handle) {
+                    let click = mouse::Click::new(position, mouse::Button::Left, state.last_click);
+                    state.last_click = Some(click);
+
+                    if click.kind() == click::Kind::Double {
+                        state.drag = None;
+                        self.toggle(state, shell);
+                    } else {
+                        state.drag = Some(Drag {
+                            grab: self.distance(bounds, position) - self.side_width(state),
+                            width: state.width,
+                        });
+                    }
+
+                    shell.capture_event();
+                    shell.request_redraw();
+                    return;
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                if let Some(drag) = state.drag {
+                    let width = self.distance(bounds, *position) - drag.grab;
+                    let threshold = (self.min_width + self.collapsed_width()) / 2.0;
+
+                    // Below the threshold the panel collapses and keeps the width it had before
+                    if width < threshold {
+                        if state.expanded {
+                            self.resize(state, drag.width, shell);
+                            self.toggle(state, shell);
+                        }
+                    } else {
+                        if !state.expanded {
+                            self.toggle(state, shell);
+                        }
+                        self.resize(state, self.clamp(width), shell);
+                    }
+
+                    shell.capture_event();
+                    return;
+                }
+
+                // Hovering the handle changes how it is drawn
+                shell.request_redraw();
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) if state.drag.is_some() => {
+                state.drag = None;
+                shell.capture_event();
+                shell.request_redraw();
+                return;
+            }
+            _ => {}
+        }
+
+        let shown = self.shown(state);
+        for (index, ((child, tree), layout)) in self
+            .children
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .enumerate()
+        {
+            if shown.contains(&Some(index)) {
+                child
+                    .as_widget_mut()
+                    .update(tree, event, layout, cursor, renderer, clipboard, shell, viewport);
+            }
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        defaults: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_ref::<State>();
+        let handle = self.handle_bounds(state, bounds);
+        let status = if state.drag.is_some() {
+            Status::Dragging
+        } else if cursor.is_over(handle) {
+            Status::Hovered
+        } else {
+            Status::Idle
+        };
+        let style = theme.style(&self.class, status);
+        let shown = self.shown(state);
+
+        if let Some(background) = style.background {
+            let (x, width) = match self.edge() {
+                Side::Left => (bounds.x, handle.x - bounds.x),
+                Side::Right => (handle.x + handle.width, bounds.x + bounds.width - handle.x - handle.width),
+            };
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: pixel::snap(Rectangle::new(Point::new(x, bounds.y), Size::new(width, bounds.height))),
+                    ..renderer::Quad::default()
+                },
+                background,
+            );
+        }
+
+        for (index, (child, (tree, layout))) in self
+            .children
+            .iter()
+            .zip(tree.children.iter().zip(layout.children()))
+            .enumerate()
+        {
+            if !shown.contains(&Some(index)) {
+                continue;
+            }
+
+            if let Some(clip) = layout.bounds().intersection(viewport) {
+                renderer.with_layer(clip, |renderer| {
+                    child
+                        .as_widget()
+                        .draw(tree, renderer, theme, defaults, layout, cursor, &clip);
+                });
+            }
+        }
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: pixel::snap(handle),
+                ..renderer::Quad::default()
+            },
+            style.handle,
+        );
+
+        // A hairline on the content side of the handle, and a grip in its middle
+        let divider_x = match self.edge() {
+            Side::Left => handle.x + handle.width - 1.0,
+            Side::Right => handle.x,
+        };
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: pixel::snap(Rectangle::new(Point::new(divider_x, handle.y), Size::new(1.0, handle.height))),
+                ..renderer::Quad::default()
+            },
+            style.divider,
+        );
+
+        let grip = Size::new((handle.width - 2.0).clamp(1.0, 4.0), 32.0_f32.min(handle.height));
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: pixel::snap(Rectangle::new(
+                    Point::new(
+                        handle.center_x() - grip.width / 2.0,
+                        handle.center_y() - grip.height / 2.0,
+                    ),
+                    grip,
+                )),
+                border: pixel::border(Border {
+                    radius: (grip.width / 2.0).into(),
+                    ..Border::default()
+                }),
+                ..renderer::Quad::default()
+            },
+            style.grip,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        if state.drag.is_some() || cursor.is_over(self.handle_bounds(state, layout.bounds())) {
+            return mouse::Interaction::ResizingHorizontally;
+        }
+
+        let shown = self.shown(state);
+        self.children
+            .iter()
+            .zip(tree.children.iter().zip(layout.children()))
+            .enumerate()
+            .filter(|(index, _)| shown.contains(&Some(*index)))
+            .map(|(_, (child, (tree, layout)))| {
+                child
+                    .as_widget()
+                    .mouse_interaction(tree, layout, cursor, viewport, renderer)
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let shown = self.shown(tree.state.downcast_ref::<State>());
+
+        let overlays: Vec<_> = self
+            .children
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .enumerate()
+            .filter(|(index, _)| shown.contains(&Some(*index)))
+            .filter_map(|(_, ((child, tree), layout))| {
+                child
+                    .as_widget_mut()
+                    .overlay(tree, layout, renderer, viewport, translation)
+            })
+            .collect();
+
+        (!overlays.is_empty()).then(|| overlay::Group::with_children(overlays).overlay())
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Sidebar<'a, Message, Theme, Renderer>> for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(sidebar: Sidebar<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(sidebar)
+    }
+}
+
+/// The state of the handle of a [`Sidebar`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Idle,
+    Hovered,
+    Dragging,
+}
+
+/// The theme catalog of a [`Sidebar`].
+pub trait Catalog {
+    /// The style class
+    type Class<'a>;
+
+    /// Default style
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// Get the style for a class and status
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+/// The appearance of a [`Sidebar`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// Background behind the panel or the rail
+    pub background: Option<Background>,
+    /// Background of the handle
+    pub handle: Background,
+    /// Line between the handle and the content
+    pub divider: Color,
+    /// Grip in the middle of the handle
+    pub grip: Color,
+}
+
+/// Styling function
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for iced::Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default sidebar style.
+pub fn default(theme: &iced::Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: Some(palette.background.weak.color.into()),
+        handle: match status {
+            Status::Idle => Color::TRANSPARENT.into(),
+            Status::Hovered => palette.background.strong.color.scale_alpha(0.5).into(),
+            Status::Dragging => palette.primary.weak.color.into(),
+        },
+        divider: palette.background.strong.color,
+        grip: match status {
+            Status::Idle => Color::TRANSPARENT,
+            Status::Hovered => palette.background.strong.text,
+            Status::Dragging => palette.primary.weak.text,
+        },
+    }
+}
+
+/// No background behind the panel, only the divider line.
+pub fn plain(theme: &iced::Theme, status: Status) -> Style {
+    Style {
+        background: None,
+        ..default(theme, status)
+    }
+}
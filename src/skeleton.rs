@@ -0,0 +1,394 @@
+//! Placeholder shapes shown while content loads, with a shimmer sweeping over them.
+//!
+//! A [`Skeleton`] is a line, a few lines of text, a circle or a rectangle standing in for
+//! content that is not there yet. Every skeleton on screen shares the same clock and sweeps
+//! the shimmer across the window together, so a screen full of them reads as one.
+//!
+//! [`loading`] picks between a skeleton layout and the real content, building the content
+//! only once it is needed.
+
+use std::sync::OnceLock;
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        mouse, renderer,
+        widget::{self, tree::Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    gradient,
+    time::{Duration, Instant},
+    window, Background, Border, Color, Element, Event, Length, Pixels, Point, Rectangle, Size,
+};
+
+use crate::pixel;
+
+const LINE_HEIGHT: f32 = 12.0;
+const LINE_SPACING: f32 = 8.0;
+const SHIMMER_WIDTH: f32 = 160.0;
+
+/// Creates a new [`Skeleton`] line, as wide as it can be.
+pub fn line<'a, Theme>() -> Skeleton<'a, Theme>
+where
+    Theme: Catalog,
+{
+    Skeleton::new(Shape::Line)
+}
+
+/// Creates a new [`Skeleton`] of `lines` lines of text, the last one shorter.
+pub fn paragraph<'a, Theme>(lines: usize) -> Skeleton<'a, Theme>
+where
+    Theme: Catalog,
+{
+    Skeleton::new(Shape::Lines(lines.max(1)))
+}
+
+/// Creates a new [`Skeleton`] circle of `diameter`.
+pub fn circle<'a, Theme>(diameter: impl Into<Pixels>) -> Skeleton<'a, Theme>
+where
+    Theme: Catalog,
+{
+    let diameter = diameter.into().0;
+
+    Skeleton::new(Shape::Circle).width(diameter).height(diameter)
+}
+
+/// Creates a new [`Skeleton`] rectangle.
+pub fn rectangle<'a, Theme>(width: impl Into<Length>, height: impl Into<Length>) -> Skeleton<'a, Theme>
+where
+    Theme: Catalog,
+{
+    Skeleton::new(Shape::Rectangle).width(width).height(height)
+}
+
+/// Shows `skeleton` while `is_loading`, and the element built by `content` once it is not.
+pub fn loading<'a, Message, Theme, Renderer>(
+    is_loading: bool,
+    skeleton: impl Into<Element<'a, Message, Theme, Renderer>>,
+    content: impl FnOnce() -> Element<'a, Message, Theme, Renderer>,
+) -> Element<'a, Message, Theme, Renderer> {
+    if is_loading { skeleton.into() } else { content() }
+}
+
+/// The outline of a [`Skeleton`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Shape {
+    Line,
+    /// Lines of text, the last one shorter
+    Lines(usize),
+    Circle,
+    Rectangle,
+}
+
+/// A placeholder for content that is loading.
+#[allow(missing_debug_implementations)]
+pub struct Skeleton<'a, Theme = iced::Theme>
+where
+    Theme: Catalog,
+{
+    shape: Shape,
+    width: Length,
+    height: Length,
+    animated: bool,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Theme> Skeleton<'a, Theme>
+where
+    Theme: Catalog,
+{
+    /// Creates a new [`Skeleton`] of `shape`.
+    pub fn new(shape: Shape) -> Self {
+        let height = match shape {
+            Shape::Line => LINE_HEIGHT,
+            Shape::Lines(lines) => lines as f32 * LINE_HEIGHT + (lines.saturating_sub(1)) as f32 * LINE_SPACING,
+            Shape::Circle | Shape::Rectangle => 40.0,
+        };
+
+        Self {
+            shape,
+            width: if shape == Shape::Circle {
+                Length::Fixed(height)
+            } else {
+                Length::Fill
+            },
+            height: Length::Fixed(height),
+            animated: true,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the width of the skeleton.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the skeleton.
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets whether the shimmer sweeps over the skeleton.
+    pub fn animated(mut self, animated: bool) -> Self {
+        self.animated = animated;
+        self
+    }
+
+    /// Sets the style.
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class.
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// The pieces to fill and their corner radius.
+    fn pieces(&self, bounds: Rectangle, style: &Style) -> Vec<(Rectangle, f32)> {
+        match self.shape {
+            Shape::Line => vec![(bounds, style.radius.min(bounds.height / 2.0))],
+            Shape::Rectangle => vec![(bounds, style.radius)],
+            Shape::Circle => {
+                let diameter = bounds.width.min(bounds.height);
+                let circle = Rectangle::new(
+                    Point::new(
+                        bounds.center_x() - diameter / 2.0,
+                        bounds.center_y() - diameter / 2.0,
+                    ),
+                    Size::new(diameter, diameter),
+                );
+
+                vec![(circle, diameter / 2.0)]
+            }
+            Shape::Lines(lines) => {
+                let height = ((bounds.height - (lines - 1) as f32 * LINE_SPACING) / lines as f32).max(1.0);
+
+                (0..lines)
+                    .map(|line| {
+                        let width = if line + 1 == lines && lines > 1 {
+                            bounds.width * 0.6
+                        } else {
+                            bounds.width
+                        };
+                        let line = Rectangle::new(
+                            Point::new(bounds.x, bounds.y + line as f32 * (height + LINE_SPACING)),
+                            Size::new(width, height),
+                        );
+
+                        (line, style.radius.min(height / 2.0))
+                    })
+                    .collect()
+            }
+        }
+    }
+}
+
+/// The clock every skeleton shares, so their shimmers line up.
+fn epoch() -> Instant {
+    static EPOCH: OnceLock<Instant> = OnceLock::new();
+
+    *EPOCH.get_or_init(Instant::now)
+}
+
+/// The fill of `piece` with the shimmer centered at `x`, in window coordinates.
+fn shimmer(piece: Rectangle, x: f32, style: &Style) -> Background {
+    if piece.width <= 0.0 || x + SHIMMER_WIDTH / 2.0 < piece.x || x - SHIMMER_WIDTH / 2.0 > piece.x + piece.width {
+        return style.base.into();
+    }
+
+    // Gradient stops are relative to the piece, and have to stay within it
+    let center = (x - piece.x) / piece.width;
+    let half = SHIMMER_WIDTH / 2.0 / piece.width;
+    let color_at = |offset: f32| {
+        let amount = (1.0 - (offset - center).abs() / half).max(0.0);
+
+        Color {
+            r: style.base.r + (style.highlight.r - style.base.r) * amount,
+            g: style.base.g + (style.highlight.g - style.base.g) * amount,
+            b: style.base.b + (style.highlight.b - style.base.b) * amount,
+            a: style.base.a + (style.highlight.a - style.base.a) * amount,
+        }
+    };
+
+    let stops = [0.0, center - half, center, center + half, 1.0];
+    let linear = stops
+        .into_iter()
+        .filter(|offset| (0.0..=1.0).contains(offset))
+        .fold(gradient::Linear::new(std::f32::consts::FRAC_PI_2), |linear, offset| {
+            linear.add_stop(offset, color_at(offset))
+        });
+
+    Background::Gradient(linear.into())
+}
+
+#[derive(Debug)]
+struct State {
+    now: Instant,
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Skeleton<'_, Theme>
+where
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(State { now: Instant::now() })
+    }
+
+    fn layout(&mut self, _tree: &mut Tree, _renderer: &Renderer, limits: &Limits) -> Node {
+        Node::new(limits.resolve(self.width, self.height, Size::ZERO))
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        _layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        if let Event::Window(window::Event::RedrawRequested(now)) = event
+            && self.animated
+        {
+            tree.state.downcast_mut::<State>().now = *now;
+            shell.request_redraw();
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _defaults: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let style = theme.style(&self.class);
+
+        // The shimmer crosses the whole visible area, entering and leaving it off screen
+        let x = if self.animated {
+            let state = tree.state.downcast_ref::<State>();
+            let period = style.period.as_secs_f32().max(0.1);
+            let phase = (state.now.saturating_duration_since(epoch()).as_secs_f32() / period).fract();
+
+            viewport.x - SHIMMER_WIDTH + phase * (viewport.width + SHIMMER_WIDTH * 2.0)
+        } else {
+            f32::NEG_INFINITY
+        };
+
+        for (piece, radius) in self.pieces(bounds, &style) {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: pixel::snap(piece),
+                    border: pixel::border(Border {
+                        radius: radius.into(),
+                        ..Border::default()
+                    }),
+                    ..renderer::Quad::default()
+                },
+                shimmer(piece, x, &style),
+            );
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Skeleton<'a, Theme>> for Element<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog + 'a,
+    Renderer: iced::advanced::Renderer,
+{
+    fn from(skeleton: Skeleton<'a, Theme>) -> Self {
+        Element::new(skeleton)
+    }
+}
+
+/// The theme catalog of a [`Skeleton`].
+pub trait Catalog {
+    /// The style class
+    type Class<'a>;
+
+    /// Default style
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// Get the style for a class
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// The appearance of a [`Skeleton`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// Color of the shapes
+    pub base: Color,
+    /// Color at the middle of the shimmer
+    pub highlight: Color,
+    /// Corner radius of lines and rectangles
+    pub radius: f32,
+    /// How long the shimmer takes to cross the window
+    pub period: Duration,
+}
+
+/// Styling function
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for iced::Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default skeleton style.
+pub fn default(theme: &iced::Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        base: palette.background.weak.color,
+        highlight: palette.background.strong.color,
+        radius: 4.0,
+        period: Duration::from_millis(1600),
+    }
+}
+
+/// A fainter skeleton with square corners.
+pub fn subtle(theme: &iced::Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        base: palette.background.weakest.color,
+        highlight: palette.background.weak.color,
+        radius: 0.0,
+        ..default(theme)
+    }
+}
@@ -0,0 +1,736 @@
+//! A button with a main action and a menu of alternatives.
+//!
+//! [`SplitButton`] has two halves: clicking the main half produces its message like a plain
+//! button, while the arrow half opens a [`menu`](crate::menu) of alternative actions under
+//! the button, such as "Save as…" next to "Save". The menu is the one of
+//! [`context_menu`](crate::context_menu), usable from the keyboard once open.
+//!
+//! Both halves are hovered and pressed on their own, and the style sees the status of each.
+//! Right to left, the arrow is on the left and the menu opens from the right edge.
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        mouse, overlay, renderer, text,
+        widget::{self, tree::Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment, border, touch, Background, Border, Color, Element, Event, Length, Padding, Pixels, Point, Rectangle,
+    Shadow, Size, Vector,
+};
+
+use crate::direction::{self, Direction};
+use crate::menu::{self, Item, Origin, Outcome, Panels, Session};
+use crate::pixel;
+
+const ARROW_WIDTH: f32 = 28.0;
+
+/// Creates a new [`SplitButton`] showing `content`, with `items` in its menu.
+pub fn split_button<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    items: impl IntoIterator<Item = Item<Message>>,
+) -> SplitButton<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog + menu::Catalog,
+    Renderer: text::Renderer<Font = iced::Font>,
+{
+    SplitButton::new(content, items)
+}
+
+/// A button with a main action and an arrow opening a menu of alternatives.
+#[allow(missing_debug_implementations)]
+pub struct SplitButton<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Theme: Catalog + menu::Catalog,
+    Renderer: text::Renderer<Font = iced::Font>,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    items: Vec<Item<Message>>,
+    on_press: Option<Message>,
+    width: Length,
+    height: Length,
+    padding: Padding,
+    text_size: Pixels,
+    font: iced::Font,
+    direction: Option<Direction>,
+    class: <Theme as Catalog>::Class<'a>,
+    menu_class: <Theme as menu::Catalog>::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> SplitButton<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog + menu::Catalog,
+    Renderer: text::Renderer<Font = iced::Font>,
+{
+    /// Creates a new [`SplitButton`] showing `content`, with `items` in its menu.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        items: impl IntoIterator<Item = Item<Message>>,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            items: items.into_iter().collect(),
+            on_press: None,
+            width: Length::Shrink,
+            height: Length::Shrink,
+            padding: Padding::from([5, 10]),
+            text_size: Pixels(14.0),
+            font: iced::Font::default(),
+            direction: None,
+            class: <Theme as Catalog>::default(),
+            menu_class: <Theme as menu::Catalog>::default(),
+        }
+    }
+
+    /// Sets the message of the main half, which is disabled without one.
+    pub fn on_press(mut self, message: Message) -> Self {
+        self.on_press = Some(message);
+        self
+    }
+
+    /// Sets the message of the main half if `Some`, disabling it otherwise.
+    pub fn on_press_maybe(mut self, message: Option<Message>) -> Self {
+        self.on_press = message;
+        self
+    }
+
+    /// Sets the width of the button.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the button.
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the padding around the content of the main half.
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the menu.
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = size.into();
+        self
+    }
+
+    /// Sets the font of the menu.
+    pub fn font(mut self, font: iced::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the [`Direction`] of the button, instead of the one set with [`direction::set`].
+    ///
+    /// Right to left puts the arrow on the left and opens the menu from the right edge.
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    /// Sets the style of the button.
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status, Status) -> Style + 'a) -> Self
+    where
+        <Theme as Catalog>::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the button.
+    #[must_use]
+    pub fn class(mut self, class: impl Into<<Theme as Catalog>::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Sets the style of the menu.
+    #[must_use]
+    pub fn menu_style(mut self, style: impl Fn(&Theme) -> menu::Style + 'a) -> Self
+    where
+        <Theme as menu::Catalog>::Class<'a>: From<menu::StyleFn<'a, Theme>>,
+    {
+        self.menu_class = (Box::new(style) as menu::StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the menu.
+    #[must_use]
+    pub fn menu_class(mut self, class: impl Into<<Theme as menu::Catalog>::Class<'a>>) -> Self {
+        self.menu_class = class.into();
+        self
+    }
+
+    /// Shows the shortcuts `keymap` binds to the entries of the menu.
+    #[cfg(feature = "hotkeys")]
+    #[must_use]
+    pub fn hotkeys(mut self, keymap: &crate::hotkeys::Keymap<Message>) -> Self
+    where
+        Message: PartialEq,
+    {
+        self.items = self.items.into_iter().map(|item| item.hotkeys(keymap)).collect();
+        self
+    }
+}
+
+/// A half of a [`SplitButton`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Part {
+    Main,
+    Arrow,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    hovered: Option<Part>,
+    is_pressed: bool,
+    is_open: bool,
+    session: Session,
+}
+
+impl State {
+    fn close(&mut self) {
+        self.is_open = false;
+        self.session = Session::default();
+    }
+}
+
+/// The main half and the arrow half, on the left right to left.
+fn halves(bounds: Rectangle, direction: Direction) -> (Rectangle, Rectangle) {
+    let arrow_width = ARROW_WIDTH.min(bounds.width);
+
+    (
+        direction.mirror(
+            Rectangle {
+                width: bounds.width - arrow_width,
+                ..bounds
+            },
+            bounds,
+        ),
+        direction.mirror(
+            Rectangle {
+                x: bounds.x + bounds.width - arrow_width,
+                width: arrow_width,
+                ..bounds
+            },
+            bounds,
+        ),
+    )
+}
+
+impl<Message, Theme, Renderer> SplitButton<'_, Message, Theme, Renderer>
+where
+    Theme: Catalog + menu::Catalog,
+    Renderer: text::Renderer<Font = iced::Font>,
+{
+    fn layout_direction(&self) -> Direction {
+        self.direction.unwrap_or_else(direction::get)
+    }
+
+    fn half_at(&self, bounds: Rectangle, point: Point) -> Option<Part> {
+        let (main, arrow) = halves(bounds, self.layout_direction());
+
+        if main.contains(point) {
+            Some(Part::Main)
+        } else if arrow.contains(point) {
+            Some(Part::Arrow)
+        } else {
+            None
+        }
+    }
+
+    fn statuses(&self, state: &State) -> (Status, Status) {
+        let main = if self.on_press.is_none() {
+            Status::Disabled
+        } else if state.is_pressed {
+            Status::Pressed
+        } else if state.hovered == Some(Part::Main) {
+            Status::Hovered
+        } else {
+            Status::Active
+        };
+
+        let arrow = if self.items.is_empty() {
+            Status::Disabled
+        } else if state.is_open {
+            Status::Pressed
+        } else if state.hovered == Some(Part::Arrow) {
+            Status::Hovered
+        } else {
+            Status::Active
+        };
+
+        (main, arrow)
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for SplitButton<'_, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog + menu::Catalog,
+    Renderer: text::Renderer<Font = iced::Font>,
+{
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+
+        if self.items.is_empty() {
+            tree.state.downcast_mut::<State>().close();
+        }
+    }
+
+    fn layout(&mut self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let limits = limits.width(self.width).height(self.height);
+        let content = self.content.as_widget_mut().layout(
+            &mut tree.children[0],
+            renderer,
+            &limits.shrink(self.padding).shrink(Size::new(ARROW_WIDTH, 0.0)),
+        );
+        let intrinsic = content.size().expand(self.padding) + Size::new(ARROW_WIDTH, 0.0);
+        let size = limits.resolve(self.width, self.height, intrinsic);
+
+        // Content centered vertically in the main half, after the left padding
+        let main = Size::new(size.width - ARROW_WIDTH - self.padding.x(), size.height - self.padding.y());
+        let content = content
+            .move_to(Point::new(self.padding.left, self.padding.top))
+            .align(iced::Alignment::Start, iced::Alignment::Center, main);
+        let mirrored = self.layout_direction().mirror(content.bounds(), Rectangle::with_size(size));
+        let content = content.move_to(mirrored.position());
+
+        Node::with_children(size, vec![content])
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        if let Some(content) = layout.children().next() {
+            self.content.as_widget_mut().update(
+                &mut tree.children[0],
+                event,
+                content,
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            );
+        }
+
+        if shell.is_event_captured() {
+            return;
+        }
+
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. }) | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                let hovered = cursor.position().and_then(|position| self.half_at(bounds, position));
+
+                if hovered != state.hovered {
+                    state.hovered = hovered;
+                    shell.request_redraw();
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    return;
+                };
+
+                match self.half_at(bounds, position) {
+                    Some(Part::Main) if self.on_press.is_some() => state.is_pressed = true,
+                    Some(Part::Arrow) if !self.items.is_empty() => {
+                        if state.is_open {
+                            state.close();
+                        } else {
+                            state.is_open = true;
+                        }
+                        shell.invalidate_layout();
+                    }
+                    _ => return,
+                }
+
+                shell.capture_event();
+                shell.request_redraw();
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+                if state.is_pressed =>
+            {
+                state.is_pressed = false;
+
+                if cursor
+                    .position()
+                    .and_then(|position| self.half_at(bounds, position))
+                    == Some(Part::Main)
+                    && let Some(message) = &self.on_press
+                {
+                    shell.publish(message.clone());
+                }
+
+                shell.capture_event();
+                shell.request_redraw();
+            }
+            Event::Touch(touch::Event::FingerLost { .. }) if state.is_pressed => {
+                state.is_pressed = false;
+                shell.request_redraw();
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        defaults: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let (main_status, arrow_status) = self.statuses(state);
+        let style = <Theme as Catalog>::style(theme, &self.class, main_status, arrow_status);
+        let bounds = layout.bounds();
+        let dir = self.layout_direction();
+        let (main, arrow) = halves(bounds, dir);
+        let (main_radius, arrow_radius) = match dir {
+            Direction::LeftToRight => (border::left(style.radius), border::right(style.radius)),
+            Direction::RightToLeft => (border::right(style.radius), border::left(style.radius)),
+        };
+
+        for (half, appearance, radius) in [
+            (main, &style.main, main_radius),
+            (arrow, &style.arrow, arrow_radius),
+        ] {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: pixel::snap(half),
+                    border: pixel::border(Border { radius, ..appearance.border }),
+                    shadow: appearance.shadow,
+                    ..renderer::Quad::default()
+                },
+                appearance.background.unwrap_or(Background::Color(Color::TRANSPARENT)),
+            );
+        }
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: pixel::snap(Rectangle {
+                    x: if dir.is_rtl() { arrow.x + arrow.width } else { arrow.x } - 0.5,
+                    y: bounds.y + 4.0,
+                    width: 1.0,
+                    height: (bounds.height - 8.0).max(0.0),
+                }),
+                ..renderer::Quad::default()
+            },
+            style.divider,
+        );
+
+        if let Some(content) = layout.children().next() {
+            self.content.as_widget().draw(
+                &tree.children[0],
+                renderer,
+                theme,
+                &renderer::Style {
+                    text_color: style.main.text_color.unwrap_or(defaults.text_color),
+                },
+                content,
+                cursor,
+                &main.intersection(viewport).unwrap_or(main),
+            );
+        }
+
+        renderer.fill_text(
+            iced::advanced::Text {
+                content: String::from("▾"),
+                bounds: arrow.size(),
+                size: self.text_size,
+                font: iced::Font::default(),
+                align_x: text::Alignment::Center,
+                align_y: alignment::Vertical::Center,
+                line_height: text::LineHeight::default(),
+                shaping: text::Shaping::Advanced,
+                wrapping: text::Wrapping::None,
+            },
+            arrow.center(),
+            style.arrow.text_color.unwrap_or(defaults.text_color),
+            *viewport,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let (main, arrow) = self.statuses(state);
+
+        match cursor.position().and_then(|position| self.half_at(layout.bounds(), position)) {
+            Some(Part::Main) if main != Status::Disabled => mouse::Interaction::Pointer,
+            Some(Part::Arrow) if arrow != Status::Disabled => mouse::Interaction::Pointer,
+            Some(_) => mouse::Interaction::NotAllowed,
+            None => mouse::Interaction::None,
+        }
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn widget::Operation,
+    ) {
+        if let Some(content) = layout.children().next() {
+            self.content
+                .as_widget_mut()
+                .operate(&mut tree.children[0], content, renderer, operation);
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        _renderer: &Renderer,
+        _viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_mut::<State>();
+
+        if !state.is_open {
+            return None;
+        }
+
+        Some(overlay::Element::new(Box::new(Overlay {
+            panels: Panels::new(&self.items, self.text_size, self.font, self.layout_direction()),
+            state,
+            anchor: layout.bounds() + translation,
+            class: &self.menu_class,
+        })))
+    }
+}
+
+/// The open menu of a [`SplitButton`].
+struct Overlay<'a, 'b, Message, Theme>
+where
+    Theme: menu::Catalog,
+{
+    panels: Panels<'a, Message>,
+    state: &'a mut State,
+    anchor: Rectangle,
+    class: &'a <Theme as menu::Catalog>::Class<'b>,
+}
+
+impl<Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer> for Overlay<'_, '_, Message, Theme>
+where
+    Message: Clone,
+    Theme: menu::Catalog,
+    Renderer: text::Renderer<Font = iced::Font>,
+{
+    fn layout(&mut self, _renderer: &Renderer, bounds: Size) -> Node {
+        self.panels
+            .layout::<Renderer::Paragraph>(&self.state.session, Origin::Below(self.anchor), bounds);
+
+        Node::new(bounds)
+    }
+
+    fn update(
+        &mut self,
+        event: &Event,
+        _layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        match self.panels.update(&mut self.state.session, event, cursor, &|message| message, shell) {
+            Outcome::None => return,
+            Outcome::Close => self.state.close(),
+            Outcome::Outside(_) => {
+                // A press on the arrow is left to the button, which closes the menu itself
+                if cursor.is_over(self.anchor) {
+                    return;
+                }
+                self.state.close();
+            }
+            Outcome::Handled | Outcome::Previous | Outcome::Next => {}
+        }
+
+        shell.capture_event();
+        shell.invalidate_layout();
+        shell.request_redraw();
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _defaults: &renderer::Style,
+        _layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+    ) {
+        self.panels.draw(renderer, &theme.style(self.class), &self.state.session);
+    }
+
+    fn mouse_interaction(
+        &self,
+        _layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.panels.mouse_interaction(&self.state.session, cursor)
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<SplitButton<'a, Message, Theme, Renderer>> for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + menu::Catalog + 'a,
+    Renderer: text::Renderer<Font = iced::Font> + 'a,
+{
+    fn from(button: SplitButton<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(button)
+    }
+}
+
+/// The state of a half of a [`SplitButton`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// Can be pressed
+    Active,
+    /// Under the cursor
+    Hovered,
+    /// Held down, or for the arrow, with its menu open
+    Pressed,
+    /// Without a message, or for the arrow, without items
+    Disabled,
+}
+
+/// The theme catalog of a [`SplitButton`].
+pub trait Catalog {
+    /// The style class
+    type Class<'a>;
+
+    /// Default style
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// Get the style for a class and the status of the main and arrow halves
+    fn style(&self, class: &Self::Class<'_>, main: Status, arrow: Status) -> Style;
+}
+
+/// The appearance of one half of a [`SplitButton`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Half {
+    /// Background of the half
+    pub background: Option<Background>,
+    /// Color of the content, the inherited one otherwise
+    pub text_color: Option<Color>,
+    /// Border of the half, its radius is set by the button
+    pub border: Border,
+    /// Shadow of the half
+    pub shadow: Shadow,
+}
+
+/// The appearance of a [`SplitButton`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The main half
+    pub main: Half,
+    /// The arrow half
+    pub arrow: Half,
+    /// Line between the halves
+    pub divider: Color,
+    /// Corner radius of the outer corners
+    pub radius: f32,
+}
+
+/// Styling function
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status, Status) -> Style + 'a>;
+
+impl Catalog for iced::Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(primary)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, main: Status, arrow: Status) -> Style {
+        class(self, main, arrow)
+    }
+}
+
+fn half(pair: iced::theme::palette::Pair, hovered: iced::theme::palette::Pair, status: Status) -> Half {
+    let (background, text_color) = match status {
+        Status::Active => (pair.color, pair.text),
+        Status::Hovered | Status::Pressed => (hovered.color, hovered.text),
+        Status::Disabled => (pair.color.scale_alpha(0.5), pair.text.scale_alpha(0.5)),
+    };
+
+    Half {
+        background: Some(background.into()),
+        text_color: Some(text_color),
+        ..Half::default()
+    }
+}
+
+/// A split button in the primary color, like iced's primary button.
+pub fn primary(theme: &iced::Theme, main: Status, arrow: Status) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        main: half(palette.primary.base, palette.primary.strong, main),
+        arrow: half(palette.primary.base, palette.primary.strong, arrow),
+        divider: palette.primary.strong.color,
+        radius: 2.0,
+    }
+}
+
+/// A split button in the background colors, like iced's secondary button.
+pub fn secondary(theme: &iced::Theme, main: Status, arrow: Status) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        main: half(palette.secondary.base, palette.secondary.strong, main),
+        arrow: half(palette.secondary.base, palette.secondary.strong, arrow),
+        divider: palette.secondary.strong.color,
+        radius: 2.0,
+    }
+}
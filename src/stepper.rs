@@ -0,0 +1,839 @@
+//! A wizard that walks through numbered steps, one content page at a time.
+//!
+//! The header shows every step with its number (a check mark once complete) and title; the
+//! footer has Back and Next buttons, Next turning into Finish on the last step. Next is only
+//! enabled when [`Stepper::can_advance`] allows leaving the current step, so each page can
+//! gate on its own validation. Disabled steps are shown but skipped.
+//!
+//! The app owns the current step: every move is reported through `on_change`.
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        mouse, overlay, renderer, text,
+        widget::{self, tree::Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment, Background, Border, Color, Element, Event, Length, Pixels, Point, Rectangle,
+    Size, Vector,
+};
+
+use crate::pixel;
+
+const MARKER_SIZE: f32 = 28.0;
+const TITLE_SPACING: f32 = 6.0;
+const CONNECTOR_WIDTH: f32 = 2.0;
+const CONNECTOR_GAP: f32 = 6.0;
+const BUTTON_HEIGHT: f32 = 32.0;
+const BUTTON_PADDING: f32 = 16.0;
+const SECTION_SPACING: f32 = 16.0;
+
+/// Creates a new [`Stepper`] on the given step.
+pub fn stepper<'a, Message, Theme, Renderer>(
+    current: usize,
+    on_change: impl Fn(usize) -> Message + 'a,
+) -> Stepper<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer<Font = iced::Font>,
+{
+    Stepper::new(current, on_change)
+}
+
+/// One page of a [`Stepper`].
+#[allow(missing_debug_implementations)]
+pub struct Step<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer> {
+    title: String,
+    description: Option<String>,
+    disabled: bool,
+    content: Element<'a, Message, Theme, Renderer>,
+}
+
+impl<'a, Message, Theme, Renderer> Step<'a, Message, Theme, Renderer> {
+    /// Creates a step with a title and its page.
+    pub fn new(title: impl Into<String>, content: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        Self {
+            title: title.into(),
+            description: None,
+            disabled: false,
+            content: content.into(),
+        }
+    }
+
+    /// Sets a short line shown under the title.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Disables the step: it is drawn greyed out and Next and Back jump over it.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+/// The state of a step in the header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StepStatus {
+    Complete,
+    Current,
+    Upcoming,
+    Disabled,
+}
+
+/// A wizard with numbered steps and Back / Next navigation.
+#[allow(missing_debug_implementations)]
+pub struct Stepper<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer<Font = iced::Font>,
+{
+    steps: Vec<Step<'a, Message, Theme, Renderer>>,
+    current: usize,
+    on_change: Box<dyn Fn(usize) -> Message + 'a>,
+    can_advance: Option<Box<dyn Fn(usize) -> bool + 'a>>,
+    on_finish: Option<Box<dyn Fn() -> Message + 'a>>,
+    free_navigation: bool,
+    back_label: String,
+    next_label: String,
+    finish_label: String,
+    width: Length,
+    height: Length,
+    text_size: Pixels,
+    font: iced::Font,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> Stepper<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer<Font = iced::Font>,
+{
+    /// Creates a new [`Stepper`] on the given step.
+    pub fn new(current: usize, on_change: impl Fn(usize) -> Message + 'a) -> Self {
+        Self {
+            steps: Vec::new(),
+            current,
+            on_change: Box::new(on_change),
+            can_advance: None,
+            on_finish: None,
+            free_navigation: false,
+            back_label: String::from("Back"),
+            next_label: String::from("Next"),
+            finish_label: String::from("Finish"),
+            width: Length::Fill,
+            height: Length::Shrink,
+            text_size: Pixels(14.0),
+            font: iced::Font::default(),
+            class: Theme::default(),
+        }
+    }
+
+    /// Adds a step.
+    pub fn push(mut self, step: Step<'a, Message, Theme, Renderer>) -> Self {
+        self.steps.push(step);
+        self
+    }
+
+    /// Adds several steps.
+    pub fn extend(mut self, steps: impl IntoIterator<Item = Step<'a, Message, Theme, Renderer>>) -> Self {
+        self.steps.extend(steps);
+        self
+    }
+
+    /// Decides whether the given step may be left forwards, disabling Next otherwise.
+    pub fn can_advance(mut self, can_advance: impl Fn(usize) -> bool + 'a) -> Self {
+        self.can_advance = Some(Box::new(can_advance));
+        self
+    }
+
+    /// Sets the message produced by the Finish button on the last step.
+    ///
+    /// Without it the last step shows no forward button.
+    pub fn on_finish(self, message: Message) -> Self
+    where
+        Message: Clone + 'a,
+    {
+        self.on_finish_with(move || message.clone())
+    }
+
+    /// Sets the function making the message produced by the Finish button on the last step,
+    /// for messages that can not be cloned.
+    pub fn on_finish_with(mut self, on_finish: impl Fn() -> Message + 'a) -> Self {
+        self.on_finish = Some(Box::new(on_finish));
+        self
+    }
+
+    /// Lets any step be picked from the header, as long as every step before it can be
+    /// advanced past. By default only earlier steps can be picked.
+    pub fn free_navigation(mut self, free: bool) -> Self {
+        self.free_navigation = free;
+        self
+    }
+
+    /// Sets the labels of the Back, Next and Finish buttons.
+    pub fn labels(mut self, back: impl Into<String>, next: impl Into<String>, finish: impl Into<String>) -> Self {
+        self.back_label = back.into();
+        self.next_label = next.into();
+        self.finish_label = finish.into();
+        self
+    }
+
+    /// Sets the width of the stepper.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the stepper; the page takes what the header and footer leave.
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the text size of the header and buttons.
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = size.into();
+        self
+    }
+
+    /// Sets the font of the header and buttons.
+    pub fn font(mut self, font: iced::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style.
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, StepStatus) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class.
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    fn status(&self, index: usize) -> StepStatus {
+        if self.steps[index].disabled {
+            StepStatus::Disabled
+        } else if index == self.current {
+            StepStatus::Current
+        } else if index < self.current {
+            StepStatus::Complete
+        } else {
+            StepStatus::Upcoming
+        }
+    }
+
+    fn allows_leaving(&self, index: usize) -> bool {
+        self.can_advance.as_ref().is_none_or(|can_advance| can_advance(index))
+    }
+
+    fn next(&self) -> Option<usize> {
+        (self.current + 1..self.steps.len()).find(|&index| !self.steps[index].disabled)
+    }
+
+    fn previous(&self) -> Option<usize> {
+        (0..self.current).rev().find(|&index| !self.steps[index].disabled)
+    }
+
+    /// Whether clicking the step in the header moves to it.
+    fn can_jump_to(&self, index: usize) -> bool {
+        if self.steps[index].disabled || index == self.current {
+            return false;
+        }
+
+        index < self.current
+            || self.free_navigation
+                && (self.current..index).all(|step| self.steps[step].disabled || self.allows_leaving(step))
+    }
+
+    fn is_last(&self) -> bool {
+        self.next().is_none()
+    }
+
+    fn forward_label(&self) -> Option<&str> {
+        if !self.is_last() {
+            Some(&self.next_label)
+        } else if self.on_finish.is_some() {
+            Some(&self.finish_label)
+        } else {
+            None
+        }
+    }
+
+    fn line_height(&self) -> f32 {
+        text::LineHeight::default().to_absolute(self.text_size).0
+    }
+
+    fn header_height(&self) -> f32 {
+        let descriptions = self.steps.iter().any(|step| step.description.is_some());
+        let lines = if descriptions { 2.0 } else { 1.0 };
+
+        MARKER_SIZE + TITLE_SPACING + self.line_height() * lines
+    }
+
+    fn slot(&self, bounds: Rectangle, index: usize) -> Rectangle {
+        let width = bounds.width / self.steps.len().max(1) as f32;
+
+        Rectangle {
+            x: bounds.x + index as f32 * width,
+            y: bounds.y,
+            width,
+            height: self.header_height(),
+        }
+    }
+
+    fn marker(&self, bounds: Rectangle, index: usize) -> Rectangle {
+        let slot = self.slot(bounds, index);
+
+        Rectangle {
+            x: slot.center_x() - MARKER_SIZE / 2.0,
+            y: slot.y,
+            width: MARKER_SIZE,
+            height: MARKER_SIZE,
+        }
+    }
+
+    fn label_width(&self, label: &str) -> f32 {
+        use text::Paragraph as _;
+
+        Renderer::Paragraph::with_text(iced::advanced::Text {
+            content: label,
+            bounds: Size::INFINITE,
+            size: self.text_size,
+            font: self.font,
+            align_x: text::Alignment::Left,
+            align_y: alignment::Vertical::Top,
+            line_height: text::LineHeight::default(),
+            shaping: text::Shaping::Advanced,
+            wrapping: text::Wrapping::None,
+        })
+        .min_bounds()
+        .width
+    }
+
+    /// The Back and forward buttons in the footer, when shown.
+    fn buttons(&self, bounds: Rectangle) -> (Option<Rectangle>, Option<Rectangle>) {
+        let y = bounds.y + bounds.height - BUTTON_HEIGHT;
+
+        let back = self.previous().map(|_| Rectangle {
+            x: bounds.x,
+            y,
+            width: self.label_width(&self.back_label) + BUTTON_PADDING * 2.0,
+            height: BUTTON_HEIGHT,
+        });
+
+        let forward = self.forward_label().map(|label| {
+            let width = self.label_width(label) + BUTTON_PADDING * 2.0;
+
+            Rectangle {
+                x: bounds.x + bounds.width - width,
+                y,
+                width,
+                height: BUTTON_HEIGHT,
+            }
+        });
+
+        (back, forward)
+    }
+
+    fn text(&self, content: impl Into<String>, bounds: Size, size: Pixels) -> iced::advanced::Text<String, iced::Font> {
+        iced::advanced::Text {
+            content: content.into(),
+            bounds,
+            size,
+            font: self.font,
+            align_x: text::Alignment::Center,
+            align_y: alignment::Vertical::Center,
+            line_height: text::LineHeight::default(),
+            shaping: text::Shaping::Advanced,
+            wrapping: text::Wrapping::None,
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Stepper<'_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer<Font = iced::Font>,
+{
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.steps.iter().map(|step| Tree::new(&step.content)).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.steps.iter().map(|step| &step.content).collect::<Vec<_>>());
+    }
+
+    fn layout(&mut self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let limits = limits.width(self.width).height(self.height);
+        let header_height = self.header_height();
+        let chrome = header_height + BUTTON_HEIGHT + SECTION_SPACING * 2.0;
+        let content_limits = limits.shrink(Size::new(0.0, chrome));
+        let current = self.current;
+
+        // Only the current page is laid out, the others keep an empty node so children line up
+        let children: Vec<Node> = self
+            .steps
+            .iter_mut()
+            .zip(&mut tree.children)
+            .enumerate()
+            .map(|(index, (step, child))| {
+                if index == current {
+                    step.content
+                        .as_widget_mut()
+                        .layout(child, renderer, &content_limits)
+                        .move_to(Point::new(0.0, header_height + SECTION_SPACING))
+                } else {
+                    Node::default()
+                }
+            })
+            .collect();
+
+        let content = children.get(current).map_or(Size::ZERO, Node::size);
+        let size = limits.resolve(
+            self.width,
+            self.height,
+            Size::new(content.width, content.height + chrome),
+        );
+
+        Node::with_children(size, children)
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let current = self.current;
+
+        if let Some(((step, child), child_layout)) = self
+            .steps
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .nth(current)
+        {
+            step.content
+                .as_widget_mut()
+                .update(child, event, child_layout, cursor, renderer, clipboard, shell, viewport);
+        }
+
+        if shell.is_event_captured() {
+            return;
+        }
+
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    return;
+                };
+                let (back, forward) = self.buttons(bounds);
+
+                let target = if back.is_some_and(|back| back.contains(position)) {
+                    self.previous()
+                } else if forward.is_some_and(|forward| forward.contains(position)) {
+                    if !self.allows_leaving(current) {
+                        None
+                    } else if let Some(next) = self.next() {
+                        Some(next)
+                    } else {
+                        if let Some(on_finish) = &self.on_finish {
+                            shell.publish(on_finish());
+                        }
+                        shell.capture_event();
+                        None
+                    }
+                } else {
+                    (0..self.steps.len())
+                        .find(|&index| self.slot(bounds, index).contains(position))
+                        .filter(|&index| self.can_jump_to(index))
+                };
+
+                if let Some(target) = target {
+                    shell.publish((self.on_change)(target));
+                    shell.capture_event();
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                shell.request_redraw();
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        defaults: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let position = cursor.position_over(bounds);
+        let clip = bounds.intersection(viewport).unwrap_or(bounds);
+
+        for index in 0..self.steps.len() {
+            let step = &self.steps[index];
+            let status = self.status(index);
+            let style = theme.style(&self.class, status);
+            let marker = self.marker(bounds, index);
+            let slot = self.slot(bounds, index);
+            let hovered = position.is_some_and(|position| slot.contains(position)) && self.can_jump_to(index);
+
+            // Connector to the next marker, colored once this step is behind
+            if index + 1 < self.steps.len() {
+                let next = self.marker(bounds, index + 1);
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: pixel::snap(Rectangle {
+                            x: marker.x + marker.width + CONNECTOR_GAP,
+                            y: marker.center_y() - CONNECTOR_WIDTH / 2.0,
+                            width: (next.x - marker.x - marker.width - CONNECTOR_GAP * 2.0).max(0.0),
+                            height: CONNECTOR_WIDTH,
+                        }),
+                        ..renderer::Quad::default()
+                    },
+                    style.connector,
+                );
+            }
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: pixel::snap(marker),
+                    border: pixel::border(Border {
+                        color: style.marker_border,
+                        width: if hovered { 2.0 } else { 1.0 },
+                        radius: (MARKER_SIZE / 2.0).into(),
+                    }),
+                    ..renderer::Quad::default()
+                },
+                style.marker_background,
+            );
+
+            let symbol = if status == StepStatus::Complete {
+                String::from("✓")
+            } else {
+                (index + 1).to_string()
+            };
+            renderer.fill_text(
+                self.text(symbol, marker.size(), self.text_size),
+                marker.center(),
+                style.marker_text,
+                clip,
+            );
+
+            let line_height = self.line_height();
+            let title_y = marker.y + marker.height + TITLE_SPACING;
+            let title_bounds = Rectangle {
+                x: slot.x,
+                y: title_y,
+                width: slot.width,
+                height: line_height,
+            };
+            renderer.fill_text(
+                self.text(step.title.clone(), title_bounds.size(), self.text_size),
+                title_bounds.center(),
+                style.title,
+                title_bounds.intersection(&clip).unwrap_or(title_bounds),
+            );
+
+            if let Some(description) = &step.description {
+                let description_bounds = Rectangle {
+                    y: title_y + line_height,
+                    ..title_bounds
+                };
+                renderer.fill_text(
+                    self.text(description.clone(), description_bounds.size(), Pixels(self.text_size.0 * 0.85)),
+                    description_bounds.center(),
+                    style.description,
+                    description_bounds.intersection(&clip).unwrap_or(description_bounds),
+                );
+            }
+        }
+
+        if let Some(((step, child), child_layout)) = self
+            .steps
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .nth(self.current)
+        {
+            step.content
+                .as_widget()
+                .draw(child, renderer, theme, defaults, child_layout, cursor, viewport);
+        }
+
+        let style = theme.style(&self.class, StepStatus::Current);
+        let (back, forward) = self.buttons(bounds);
+        let enabled = self.allows_leaving(self.current);
+
+        for (button, label, primary) in [
+            (back, self.back_label.as_str(), false),
+            (forward, self.forward_label().unwrap_or_default(), true),
+        ] {
+            let Some(button) = button else {
+                continue;
+            };
+
+            let usable = !primary || enabled;
+            let hovered = usable && position.is_some_and(|position| button.contains(position));
+            let (background, text_color) = match (primary, usable) {
+                (_, false) => (style.button_disabled_background, style.button_disabled_text),
+                (true, true) => (style.primary_button_background, style.primary_button_text),
+                (false, true) => (style.button_background, style.button_text),
+            };
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: pixel::snap(button),
+                    border: pixel::border(Border {
+                        width: if hovered { 2.0 } else { style.button_border.width },
+                        ..style.button_border
+                    }),
+                    ..renderer::Quad::default()
+                },
+                background,
+            );
+            renderer.fill_text(
+                self.text(label, button.size(), self.text_size),
+                button.center(),
+                text_color,
+                clip,
+            );
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let bounds = layout.bounds();
+
+        if let Some(position) = cursor.position_over(bounds) {
+            let (back, forward) = self.buttons(bounds);
+            let on_back = back.is_some_and(|back| back.contains(position));
+            let on_forward = forward.is_some_and(|forward| forward.contains(position)) && self.allows_leaving(self.current);
+            let on_step = (0..self.steps.len())
+                .any(|index| self.slot(bounds, index).contains(position) && self.can_jump_to(index));
+
+            if on_back || on_forward || on_step {
+                return mouse::Interaction::Pointer;
+            }
+        }
+
+        self.steps
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .nth(self.current)
+            .map(|((step, child), child_layout)| {
+                step.content
+                    .as_widget()
+                    .mouse_interaction(child, child_layout, cursor, viewport, renderer)
+            })
+            .unwrap_or_default()
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn widget::Operation,
+    ) {
+        let current = self.current;
+
+        operation.container(None, layout.bounds());
+        operation.traverse(&mut |operation| {
+            if let Some(((step, child), child_layout)) = self
+                .steps
+                .iter_mut()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .nth(current)
+            {
+                step.content.as_widget_mut().operate(child, child_layout, renderer, operation);
+            }
+        });
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let current = self.current;
+
+        self.steps
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .nth(current)
+            .and_then(|((step, child), child_layout)| {
+                step.content
+                    .as_widget_mut()
+                    .overlay(child, child_layout, renderer, viewport, translation)
+            })
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Stepper<'a, Message, Theme, Renderer>> for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer<Font = iced::Font> + 'a,
+{
+    fn from(stepper: Stepper<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(stepper)
+    }
+}
+
+/// The theme catalog of a [`Stepper`].
+pub trait Catalog {
+    /// The style class
+    type Class<'a>;
+
+    /// Default style
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// Get the style for a class and the state of a step
+    fn style(&self, class: &Self::Class<'_>, status: StepStatus) -> Style;
+}
+
+/// The appearance of a [`Stepper`], for one step state.
+///
+/// The button colors are read from the [`StepStatus::Current`] style.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// Background of the numbered circle
+    pub marker_background: Background,
+    /// Border of the numbered circle
+    pub marker_border: Color,
+    /// Number or check mark in the circle
+    pub marker_text: Color,
+    /// Step title
+    pub title: Color,
+    /// Line under the title
+    pub description: Color,
+    /// Line from this step to the next one
+    pub connector: Color,
+    /// Background of the Back button
+    pub button_background: Background,
+    /// Label of the Back button
+    pub button_text: Color,
+    /// Background of the Next and Finish button
+    pub primary_button_background: Background,
+    /// Label of the Next and Finish button
+    pub primary_button_text: Color,
+    /// Background of a button that cannot be used
+    pub button_disabled_background: Background,
+    /// Label of a button that cannot be used
+    pub button_disabled_text: Color,
+    /// Border of the buttons
+    pub button_border: Border,
+}
+
+/// Styling function
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, StepStatus) -> Style + 'a>;
+
+impl Catalog for iced::Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: StepStatus) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default stepper style.
+pub fn default(theme: &iced::Theme, status: StepStatus) -> Style {
+    let palette = theme.extended_palette();
+
+    let (marker_background, marker_border, marker_text) = match status {
+        StepStatus::Complete => (palette.primary.base.color, palette.primary.base.color, palette.primary.base.text),
+        StepStatus::Current => (palette.background.base.color, palette.primary.strong.color, palette.primary.strong.color),
+        StepStatus::Upcoming => (palette.background.base.color, palette.background.strong.color, palette.background.base.text),
+        StepStatus::Disabled => (palette.background.weak.color, palette.background.weak.color, palette.background.strong.color),
+    };
+
+    Style {
+        marker_background: marker_background.into(),
+        marker_border,
+        marker_text,
+        title: match status {
+            StepStatus::Disabled => palette.background.strong.color,
+            _ => palette.background.base.text,
+        },
+        description: palette.background.strong.color,
+        connector: match status {
+            StepStatus::Complete => palette.primary.base.color,
+            _ => palette.background.strong.color,
+        },
+        button_background: palette.background.weak.color.into(),
+        button_text: palette.background.weak.text,
+        primary_button_background: palette.primary.base.color.into(),
+        primary_button_text: palette.primary.base.text,
+        button_disabled_background: palette.background.weak.color.scale_alpha(0.5).into(),
+        button_disabled_text: palette.background.strong.color,
+        button_border: Border {
+            color: palette.primary.strong.color,
+            width: 0.0,
+            radius: 4.0.into(),
+        },
+    }
+}
+
+/// Completed steps in the success color.
+pub fn success(theme: &iced::Theme, status: StepStatus) -> Style {
+    let palette = theme.extended_palette();
+    let base = default(theme, status);
+
+    match status {
+        StepStatus::Complete => Style {
+            marker_background: palette.success.base.color.into(),
+            marker_border: palette.success.base.color,
+            marker_text: palette.success.base.text,
+            connector: palette.success.base.color,
+            ..base
+        },
+        _ => base,
+    }
+}
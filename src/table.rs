@@ -0,0 +1,1448 @@
+//! A data table for flat data, the sibling of the tree widget.
+//!
+//! Columns can be resized by dragging the edge of their header and sorted by clicking it,
+//! rows can be selected (single or multiple) and the header stays fixed while the body
+//! scrolls. Every cell is an arbitrary [`Element`].
+//!
+//! The table does not sort data itself: [`Table::on_sort`] reports the requested order and
+//! [`Table::sort`] tells the table which indicator to show.
+//!
+//! Right to left, the first column is on the right and the scrollbar on the left.
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        mouse, overlay, renderer,
+        text,
+        widget::{self, tree::Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment, keyboard, Alignment, Background, Border, Color, Element, Event, Length, Padding,
+    Pixels, Point, Rectangle, Shadow, Size, Vector,
+};
+use std::collections::HashSet;
+
+use crate::direction::{self, Direction};
+use crate::focus::{self, Ring};
+use crate::pixel;
+
+const DEFAULT_HEADER_HEIGHT: f32 = 32.0;
+const DEFAULT_COLUMN_WIDTH: f32 = 120.0;
+const DEFAULT_MIN_COLUMN_WIDTH: f32 = 40.0;
+const MIN_ROW_HEIGHT: f32 = 28.0;
+const RESIZE_HANDLE_WIDTH: f32 = 8.0;
+const SORT_INDICATOR_WIDTH: f32 = 16.0;
+const SCROLLBAR_WIDTH: f32 = 6.0;
+const SCROLLBAR_MARGIN: f32 = 2.0;
+const MIN_THUMB_HEIGHT: f32 = 20.0;
+const LINE_SCROLL: f32 = 60.0; // Pixels scrolled per wheel line
+
+/// Creates a new [`Table`] with the given columns and rows.
+pub fn table<'a, Message, Theme, Renderer>(
+    columns: impl IntoIterator<Item = Column>,
+    rows: impl IntoIterator<Item = Row<'a, Message, Theme, Renderer>>,
+) -> Table<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer<Font = iced::Font>,
+{
+    Table::new(columns, rows)
+}
+
+/// The order a column is sorted in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SortOrder {
+    Ascending,
+    Descending,
+}
+
+impl SortOrder {
+    /// Returns the opposite order.
+    pub fn reversed(self) -> Self {
+        match self {
+            SortOrder::Ascending => SortOrder::Descending,
+            SortOrder::Descending => SortOrder::Ascending,
+        }
+    }
+}
+
+/// How rows of a [`Table`] can be selected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SelectionMode {
+    /// Rows cannot be selected.
+    None,
+    /// A click selects a single row.
+    #[default]
+    Single,
+    /// Ctrl/Cmd-click toggles rows and Shift-click selects a range.
+    Multiple,
+}
+
+/// A column of a [`Table`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Column {
+    title: String,
+    width: f32,
+    min_width: f32,
+    sortable: bool,
+    resizable: bool,
+    align_x: Alignment,
+}
+
+impl Column {
+    /// Creates a new [`Column`] with the given header title.
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            width: DEFAULT_COLUMN_WIDTH,
+            min_width: DEFAULT_MIN_COLUMN_WIDTH,
+            sortable: true,
+            resizable: true,
+            align_x: Alignment::Start,
+        }
+    }
+
+    /// Sets the initial width of the column.
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the width the column cannot be resized below.
+    pub fn min_width(mut self, min_width: f32) -> Self {
+        self.min_width = min_width;
+        self
+    }
+
+    /// Sets whether clicking the header requests a sort.
+    pub fn sortable(mut self, sortable: bool) -> Self {
+        self.sortable = sortable;
+        self
+    }
+
+    /// Sets whether the column can be resized by dragging its header edge.
+    pub fn resizable(mut self, resizable: bool) -> Self {
+        self.resizable = resizable;
+        self
+    }
+
+    /// Sets the horizontal alignment of the cells in the column.
+    pub fn align_x(mut self, alignment: impl Into<Alignment>) -> Self {
+        self.align_x = alignment.into();
+        self
+    }
+}
+
+/// A row of a [`Table`], holding one [`Element`] per column.
+#[allow(missing_debug_implementations)]
+pub struct Row<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer> {
+    cells: Vec<Element<'a, Message, Theme, Renderer>>,
+    id: Option<usize>,
+}
+
+impl<'a, Message, Theme, Renderer> Row<'a, Message, Theme, Renderer> {
+    /// Creates a new [`Row`] with the given cells.
+    pub fn new(
+        cells: impl IntoIterator<Item = Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            cells: cells.into_iter().collect(),
+            id: None,
+        }
+    }
+
+    /// Sets the id reported in selections, defaulting to the row index.
+    ///
+    /// Give rows stable ids when the host re-sorts them so the selection follows the data.
+    pub fn with_id(mut self, id: usize) -> Self {
+        self.id = Some(id);
+        self
+    }
+}
+
+/// A table with a fixed header, resizable and sortable columns and selectable rows.
+#[allow(missing_debug_implementations)]
+pub struct Table<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    columns: Vec<Column>,
+    rows: Vec<Row<'a, Message, Theme, Renderer>>,
+    width: Length,
+    height: Length,
+    header_height: f32,
+    row_height: Option<f32>,
+    cell_padding: Padding,
+    text_size: Pixels,
+    font: iced::Font,
+    sort: Option<(usize, SortOrder)>,
+    selection_mode: SelectionMode,
+    selected: Option<HashSet<usize>>,
+    on_sort: Option<Box<dyn Fn(usize, SortOrder) -> Message + 'a>>,
+    on_resize: Option<Box<dyn Fn(usize, f32) -> Message + 'a>>,
+    on_select: Option<Box<dyn Fn(HashSet<usize>) -> Message + 'a>>,
+    direction: Option<Direction>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> Table<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer<Font = iced::Font>,
+{
+    /// Creates a new [`Table`] with the given columns and rows.
+    pub fn new(
+        columns: impl IntoIterator<Item = Column>,
+        rows: impl IntoIterator<Item = Row<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            columns: columns.into_iter().collect(),
+            rows: rows.into_iter().collect(),
+            width: Length::Fill,
+            height: Length::Shrink,
+            header_height: DEFAULT_HEADER_HEIGHT,
+            row_height: None,
+            cell_padding: Padding::from([4, 8]),
+            text_size: Pixels(14.0),
+            font: iced::Font::default(),
+            sort: None,
+            selection_mode: SelectionMode::default(),
+            selected: None,
+            on_sort: None,
+            on_resize: None,
+            on_select: None,
+            direction: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Appends a row to the table.
+    pub fn push(mut self, row: Row<'a, Message, Theme, Renderer>) -> Self {
+        self.rows.push(row);
+        self
+    }
+
+    /// Sets the width of the table.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the table. The body scrolls when the rows do not fit.
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the height of the header.
+    pub fn header_height(mut self, height: f32) -> Self {
+        self.header_height = height;
+        self
+    }
+
+    /// Gives every row the same height instead of fitting the tallest cell.
+    pub fn row_height(mut self, height: f32) -> Self {
+        self.row_height = Some(height);
+        self
+    }
+
+    /// Sets the padding inside every cell.
+    pub fn cell_padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.cell_padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the header titles.
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = size.into();
+        self
+    }
+
+    /// Sets the font of the header titles.
+    pub fn font(mut self, font: iced::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Shows the sort indicator on the given column.
+    pub fn sort(mut self, column: usize, order: SortOrder) -> Self {
+        self.sort = Some((column, order));
+        self
+    }
+
+    /// Sets how rows can be selected.
+    pub fn selection_mode(mut self, mode: SelectionMode) -> Self {
+        self.selection_mode = mode;
+        self
+    }
+
+    /// Sets the selected row ids, overriding the selection kept by the table.
+    pub fn selected(mut self, ids: impl IntoIterator<Item = usize>) -> Self {
+        self.selected = Some(ids.into_iter().collect());
+        self
+    }
+
+    /// Sets the message produced when a sortable header is clicked.
+    ///
+    /// Clicking the sorted column again requests the reversed order.
+    pub fn on_sort(mut self, callback: impl Fn(usize, SortOrder) -> Message + 'a) -> Self {
+        self.on_sort = Some(Box::new(callback));
+        self
+    }
+
+    /// Sets the message produced with the new width when a column resize ends.
+    pub fn on_resize(mut self, callback: impl Fn(usize, f32) -> Message + 'a) -> Self {
+        self.on_resize = Some(Box::new(callback));
+        self
+    }
+
+    /// Sets the message produced with the selected row ids when the selection changes.
+    pub fn on_select(mut self, callback: impl Fn(HashSet<usize>) -> Message + 'a) -> Self {
+        self.on_select = Some(Box::new(callback));
+        self
+    }
+
+    /// Sets the style.
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class.
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Sets the [`Direction`] of the table, instead of the one set with [`direction::set`].
+    ///
+    /// Right to left puts the first column on the right, cells keep their own layout.
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    // Columns are laid out left to right, and mirrored on the way in and out
+    fn layout_direction(&self) -> Direction {
+        self.direction.unwrap_or_else(direction::get)
+    }
+
+    fn row_id(&self, index: usize) -> usize {
+        self.rows
+            .get(index)
+            .and_then(|row| row.id)
+            .unwrap_or(index)
+    }
+
+    /// Updates the selection for a click (or arrow key) on the given row.
+    fn select_row(
+        &self,
+        state: &mut State,
+        index: usize,
+        modifiers: keyboard::Modifiers,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let id = self.row_id(index);
+
+        let selected = match self.selection_mode {
+            SelectionMode::None => return,
+            SelectionMode::Single => HashSet::from([id]),
+            SelectionMode::Multiple if modifiers.shift() => {
+                let anchor = state.anchor.unwrap_or(index);
+                let (start, end) = (anchor.min(index), anchor.max(index));
+                (start..=end).map(|i| self.row_id(i)).collect()
+            }
+            SelectionMode::Multiple if modifiers.control() || modifiers.command() => {
+                let mut selected = state.selected.clone();
+                if !selected.remove(&id) {
+                    selected.insert(id);
+                }
+                selected
+            }
+            SelectionMode::Multiple => HashSet::from([id]),
+        };
+
+        if !modifiers.shift() {
+            state.anchor = Some(index);
+        }
+        state.cursor_row = Some(index);
+
+        if selected != state.selected {
+            state.selected = selected;
+
+            if let Some(on_select) = &self.on_select {
+                shell.publish(on_select(state.selected.clone()));
+            }
+        }
+    }
+}
+
+struct State {
+    widths: Vec<f32>,
+    configured: Vec<f32>,
+    scroll: f32,
+    content_height: f32,
+    rows: Vec<(f32, f32)>, // (top, height) of every row in body coordinates
+    selected: HashSet<usize>,
+    anchor: Option<usize>,
+    cursor_row: Option<usize>,
+    hovered_row: Option<usize>,
+    hovered_header: Option<usize>,
+    pressed_header: Option<usize>,
+    resizing: Option<Resize>,
+    scrollbar_grab: Option<f32>,
+    is_focused: bool,
+    modifiers: keyboard::Modifiers,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Resize {
+    column: usize,
+    origin_x: f32,
+    origin_width: f32,
+}
+
+impl State {
+    fn new(columns: &[Column]) -> Self {
+        let widths: Vec<f32> = columns.iter().map(|column| column.width).collect();
+
+        Self {
+            configured: widths.clone(),
+            widths,
+            scroll: 0.0,
+            content_height: 0.0,
+            rows: Vec::new(),
+            selected: HashSet::new(),
+            anchor: None,
+            cursor_row: None,
+            hovered_row: None,
+            hovered_header: None,
+            pressed_header: None,
+            resizing: None,
+            scrollbar_grab: None,
+            is_focused: false,
+            modifiers: keyboard::Modifiers::empty(),
+        }
+    }
+
+    /// Keeps user-resized widths unless the host changed the configured width of a column.
+    fn sync_columns(&mut self, columns: &[Column]) {
+        self.widths.resize(columns.len(), DEFAULT_COLUMN_WIDTH);
+        self.configured.resize(columns.len(), f32::NAN);
+
+        for (i, column) in columns.iter().enumerate() {
+            if self.configured[i] != column.width {
+                self.configured[i] = column.width;
+                self.widths[i] = column.width;
+            }
+            self.widths[i] = self.widths[i].max(column.min_width);
+        }
+
+        if self.resizing.is_some_and(|resize| resize.column >= columns.len()) {
+            self.resizing = None;
+        }
+    }
+
+    fn max_scroll(&self, body: Rectangle) -> f32 {
+        (self.content_height - body.height).max(0.0)
+    }
+
+    /// The x offsets of the left edge of every column, relative to the table.
+    fn column_starts(&self) -> impl Iterator<Item = f32> + '_ {
+        self.widths.iter().scan(0.0, |x, width| {
+            let start = *x;
+            *x += width;
+            Some(start)
+        })
+    }
+
+    fn column_at(&self, bounds: Rectangle, x: f32) -> Option<usize> {
+        self.column_starts()
+            .zip(&self.widths)
+            .position(|(start, width)| {
+                let start = bounds.x + start;
+                x >= start && x < start + width
+            })
+    }
+
+    fn resize_handle_at(&self, columns: &[Column], bounds: Rectangle, header: f32, point: Point) -> Option<usize> {
+        if point.y < bounds.y || point.y > bounds.y + header {
+            return None;
+        }
+
+        self.column_starts()
+            .zip(&self.widths)
+            .enumerate()
+            .filter(|(i, (start, width))| {
+                let edge = bounds.x + start + *width;
+                columns[*i].resizable && (point.x - edge).abs() <= RESIZE_HANDLE_WIDTH / 2.0
+            })
+            .map(|(i, _)| i)
+            .last()
+    }
+
+    fn row_at(&self, body: Rectangle, point: Point) -> Option<usize> {
+        if !body.contains(point) {
+            return None;
+        }
+
+        let y = point.y - body.y + self.scroll;
+
+        self.rows
+            .iter()
+            .position(|&(top, height)| y >= top && y < top + height)
+    }
+
+    /// The scrollbar track and thumb, if the rows overflow the body.
+    fn scrollbar(&self, body: Rectangle) -> Option<(Rectangle, Rectangle)> {
+        if self.content_height <= body.height || body.height <= 0.0 {
+            return None;
+        }
+
+        let track = Rectangle {
+            x: body.x + body.width - SCROLLBAR_WIDTH - SCROLLBAR_MARGIN,
+            y: body.y + SCROLLBAR_MARGIN,
+            width: SCROLLBAR_WIDTH,
+            height: (body.height - SCROLLBAR_MARGIN * 2.0).max(0.0),
+        };
+
+        let thumb_height = (track.height * body.height / self.content_height)
+            .max(MIN_THUMB_HEIGHT)
+            .min(track.height);
+        let ratio = self.scroll / self.max_scroll(body).max(1.0);
+
+        let thumb = Rectangle {
+            y: track.y + (track.height - thumb_height) * ratio,
+            height: thumb_height,
+            ..track
+        };
+
+        Some((track, thumb))
+    }
+
+    /// Scrolls just enough to show the given row.
+    fn scroll_to_row(&mut self, body: Rectangle, index: usize) {
+        if let Some(&(top, height)) = self.rows.get(index) {
+            if top < self.scroll {
+                self.scroll = top;
+            } else if top + height > self.scroll + body.height {
+                self.scroll = top + height - body.height;
+            }
+            self.scroll = self.scroll.clamp(0.0, self.max_scroll(body));
+        }
+    }
+}
+
+impl widget::operation::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
+fn body_bounds(bounds: Rectangle, header_height: f32) -> Rectangle {
+    let header_height = header_height.min(bounds.height);
+
+    Rectangle {
+        y: bounds.y + header_height,
+        height: bounds.height - header_height,
+        ..bounds
+    }
+}
+
+/// Only hands the cursor to cells while it is over the visible body.
+fn body_cursor(cursor: mouse::Cursor, body: Rectangle) -> mouse::Cursor {
+    if cursor.is_over(body) {
+        cursor
+    } else {
+        mouse::Cursor::Unavailable
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Table<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer<Font = iced::Font>,
+{
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        let mut state = State::new(&self.columns);
+
+        if let Some(selected) = &self.selected {
+            state.selected = selected.clone();
+        }
+
+        widget::tree::State::new(state)
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.rows
+            .iter()
+            .flat_map(|row| row.cells.iter().map(Tree::new))
+            .collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let cells: Vec<_> = self.rows.iter().flat_map(|row| row.cells.iter()).collect();
+        tree.diff_children(&cells);
+
+        let state = tree.state.downcast_mut::<State>();
+        state.sync_columns(&self.columns);
+
+        if let Some(selected) = &self.selected {
+            state.selected.clone_from(selected);
+        }
+    }
+
+    fn layout(&mut self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let state = tree.state.downcast_mut::<State>();
+        state.sync_columns(&self.columns);
+
+        let limits = limits.width(self.width).height(self.height);
+        let padding = self.cell_padding;
+        let mut trees = tree.children.iter_mut();
+        let mut rows = Vec::with_capacity(self.rows.len());
+        let mut top = 0.0;
+
+        state.rows.clear();
+
+        for row in &mut self.rows {
+            let mut cells = Vec::with_capacity(row.cells.len());
+            let mut height = self.row_height.unwrap_or(0.0);
+
+            for (i, (cell, cell_tree)) in row.cells.iter_mut().zip(trees.by_ref()).enumerate() {
+                let Some(&width) = state.widths.get(i) else {
+                    cells.push(Node::new(Size::ZERO));
+                    continue;
+                };
+
+                let max_height = self
+                    .row_height
+                    .map_or(f32::INFINITY, |height| (height - padding.y()).max(0.0));
+                let cell_limits = Limits::new(
+                    Size::ZERO,
+                    Size::new((width - padding.x()).max(0.0), max_height),
+                );
+                let node = cell.as_widget_mut().layout(cell_tree, renderer, &cell_limits);
+
+                if self.row_height.is_none() {
+                    height = height.max(node.size().height + padding.y());
+                }
+                cells.push(node);
+            }
+
+            if self.row_height.is_none() {
+                height = height.max(MIN_ROW_HEIGHT);
+            }
+
+            state.rows.push((top, height));
+            rows.push(cells);
+            top += height;
+        }
+
+        state.content_height = top;
+
+        let columns_width: f32 = state.widths.iter().sum();
+        let size = limits.resolve(
+            self.width,
+            self.height,
+            Size::new(columns_width, self.header_height + state.content_height),
+        );
+
+        let body = body_bounds(Rectangle::with_size(size), self.header_height);
+        state.scroll = state.scroll.clamp(0.0, state.max_scroll(body));
+
+        let starts: Vec<f32> = state.column_starts().collect();
+        let dir = self.layout_direction();
+        let mut children = Vec::new();
+
+        for (cells, &(top, height)) in rows.into_iter().zip(&state.rows) {
+            for (i, node) in cells.into_iter().enumerate() {
+                let (Some(&start), Some(&width), Some(column)) =
+                    (starts.get(i), state.widths.get(i), self.columns.get(i))
+                else {
+                    children.push(node);
+                    continue;
+                };
+
+                let node_size = node.size();
+                let x = match column.align_x {
+                    Alignment::Start => start + padding.left,
+                    Alignment::Center => start + (width - node_size.width) / 2.0,
+                    Alignment::End => start + width - padding.right - node_size.width,
+                };
+                let y = top
+                    + padding.top
+                    + ((height - padding.y() - node_size.height) / 2.0).max(0.0);
+
+                let node = node.move_to(Point::new(x, self.header_height + y - state.scroll));
+                let position = dir.mirror(node.bounds(), Rectangle::with_size(size)).position();
+
+                children.push(node.move_to(position));
+            }
+        }
+
+        Node::with_children(size, children)
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let body = body_bounds(bounds, self.header_height);
+
+        if let Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) = event {
+            tree.state.downcast_mut::<State>().modifiers = *modifiers;
+        }
+
+        // Active drags own the pointer, so cells don't react while a column or the scrollbar moves
+        let dragging = {
+            let state = tree.state.downcast_ref::<State>();
+            state.resizing.is_some() || state.scrollbar_grab.is_some()
+        };
+
+        if !dragging {
+            let cell_cursor = body_cursor(cursor, body);
+            let cell_viewport = body.intersection(viewport).unwrap_or(body);
+
+            for ((cell, cell_tree), cell_layout) in self
+                .rows
+                .iter_mut()
+                .flat_map(|row| row.cells.iter_mut())
+                .zip(&mut tree.children)
+                .zip(layout.children())
+            {
+                cell.as_widget_mut().update(
+                    cell_tree,
+                    event,
+                    cell_layout,
+                    cell_cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    &cell_viewport,
+                );
+            }
+        }
+
+        if shell.is_event_captured() {
+            return;
+        }
+
+        let state = tree.state.downcast_mut::<State>();
+        let dir = self.layout_direction();
+        let cursor = dir.mirror_cursor(cursor, bounds);
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                let position = dir.mirror_point(*position, bounds);
+
+                if let Some(resize) = state.resizing {
+                    let min_width = self.columns[resize.column].min_width;
+                    let width = (resize.origin_width + position.x - resize.origin_x).max(min_width);
+
+                    if width != state.widths[resize.column] {
+                        state.widths[resize.column] = width;
+                        shell.invalidate_layout();
+                        shell.request_redraw();
+                    }
+                    return;
+                }
+
+                if let Some(grab) = state.scrollbar_grab {
+                    if let Some((track, thumb)) = state.scrollbar(body) {
+                        let travel = (track.height - thumb.height).max(1.0);
+                        let ratio = ((position.y - grab - track.y) / travel).clamp(0.0, 1.0);
+                        state.scroll = ratio * state.max_scroll(body);
+                        shell.invalidate_layout();
+                        shell.request_redraw();
+                    }
+                    return;
+                }
+
+                let hovered_row = cursor.position().and_then(|p| state.row_at(body, p));
+                let hovered_header = cursor
+                    .position_over(Rectangle { height: self.header_height, ..bounds })
+                    .and_then(|p| state.column_at(bounds, p.x));
+
+                if hovered_row != state.hovered_row || hovered_header != state.hovered_header {
+                    state.hovered_row = hovered_row;
+                    state.hovered_header = hovered_header;
+                    shell.request_redraw();
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    state.is_focused = false;
+                    return;
+                };
+
+                state.is_focused = true;
+
+                if let Some(column) =
+                    state.resize_handle_at(&self.columns, bounds, self.header_height, position)
+                {
+                    state.resizing = Some(Resize {
+                        column,
+                        origin_x: position.x,
+                        origin_width: state.widths[column],
+                    });
+                    shell.capture_event();
+                    return;
+                }
+
+                if position.y < body.y {
+                    if let Some(column) = state.column_at(bounds, position.x)
+                        && self.columns[column].sortable
+                        && self.on_sort.is_some()
+                    {
+                        state.pressed_header = Some(column);
+                        shell.capture_event();
+                    }
+                    return;
+                }
+
+                if let Some((track, thumb)) = state.scrollbar(body)
+                    && track.contains(position)
+                {
+                    if !thumb.contains(position) {
+                        // Jump so the thumb is centered under the cursor, then drag from there
+                        let travel = (track.height - thumb.height).max(1.0);
+                        let ratio = ((position.y - thumb.height / 2.0 - track.y) / travel).clamp(0.0, 1.0);
+                        state.scroll = ratio * state.max_scroll(body);
+                        shell.invalidate_layout();
+                    }
+                    let thumb = state.scrollbar(body).map_or(thumb, |(_, thumb)| thumb);
+                    state.scrollbar_grab = Some(position.y - thumb.y);
+                    shell.capture_event();
+                    shell.request_redraw();
+                    return;
+                }
+
+                if self.selection_mode != SelectionMode::None
+                    && let Some(index) = state.row_at(body, position)
+                {
+                    self.select_row(state, index, state.modifiers, shell);
+                    shell.capture_event();
+                    shell.request_redraw();
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                if let Some(resize) = state.resizing.take() {
+                    if let Some(on_resize) = &self.on_resize {
+                        shell.publish(on_resize(resize.column, state.widths[resize.column]));
+                    }
+                    shell.capture_event();
+                    shell.request_redraw();
+                    return;
+                }
+
+                if state.scrollbar_grab.take().is_some() {
+                    shell.capture_event();
+                    shell.request_redraw();
+                    return;
+                }
+
+                if let Some(column) = state.pressed_header.take()
+                    && let Some(position) = cursor.position_over(bounds)
+                    && position.y < body.y
+                    && state.column_at(bounds, position.x) == Some(column)
+                    && let Some(on_sort) = &self.on_sort
+                {
+                    let order = match self.sort {
+                        Some((sorted, order)) if sorted == column => order.reversed(),
+                        _ => SortOrder::Ascending,
+                    };
+                    shell.publish(on_sort(column, order));
+                    shell.capture_event();
+                }
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+                if !cursor.is_over(body) || state.max_scroll(body) <= 0.0 {
+                    return;
+                }
+
+                let dy = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => y * LINE_SCROLL,
+                    mouse::ScrollDelta::Pixels { y, .. } => *y,
+                };
+                let scroll = (state.scroll - dy).clamp(0.0, state.max_scroll(body));
+
+                if scroll != state.scroll {
+                    state.scroll = scroll;
+                    state.hovered_row = None;
+                    shell.invalidate_layout();
+                    shell.request_redraw();
+                }
+                shell.capture_event();
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed { key, .. }) if state.is_focused => {
+                if self.selection_mode == SelectionMode::None || self.rows.is_empty() {
+                    return;
+                }
+
+                let last = self.rows.len() - 1;
+                let next = match key {
+                    keyboard::Key::Named(keyboard::key::Named::ArrowUp) => {
+                        state.cursor_row.map_or(0, |row| row.saturating_sub(1))
+                    }
+                    keyboard::Key::Named(keyboard::key::Named::ArrowDown) => {
+                        state.cursor_row.map_or(0, |row| (row + 1).min(last))
+                    }
+                    keyboard::Key::Named(keyboard::key::Named::Home) => 0,
+                    keyboard::Key::Named(keyboard::key::Named::End) => last,
+                    _ => return,
+                };
+
+                // Arrow keys never toggle, so only Shift is kept to extend the selection
+                let modifiers = state.modifiers & keyboard::Modifiers::SHIFT;
+                self.select_row(state, next, modifiers, shell);
+
+                state.scroll_to_row(body, next);
+                shell.invalidate_layout();
+                shell.capture_event();
+                shell.request_redraw();
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        defaults: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let style = theme.style(&self.class);
+        let bounds = layout.bounds();
+        let body = body_bounds(bounds, self.header_height);
+        let header = Rectangle {
+            height: self.header_height.min(bounds.height),
+            ..bounds
+        };
+        let starts: Vec<f32> = state.column_starts().collect();
+        let dir = self.layout_direction();
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: pixel::snap(bounds),
+                border: pixel::border(style.border),
+                shadow: Shadow::default(),
+                snap: true,
+            },
+            style.background.unwrap_or(Background::Color(Color::TRANSPARENT)),
+        );
+
+        // Body: row backgrounds, cells and dividers, clipped below the fixed header
+        if let Some(clip) = body.intersection(viewport) {
+            renderer.with_layer(clip, |renderer| {
+                for (index, &(top, height)) in state.rows.iter().enumerate() {
+                    let row_bounds = Rectangle {
+                        x: bounds.x,
+                        y: body.y + top - state.scroll,
+                        width: bounds.width,
+                        height,
+                    };
+
+                    if row_bounds.intersection(&clip).is_none() {
+                        continue;
+                    }
+
+                    let background = if state.selected.contains(&self.row_id(index)) {
+                        Some(style.selected_row_background)
+                    } else if state.hovered_row == Some(index) && self.selection_mode != SelectionMode::None {
+                        style.hovered_row_background
+                    } else if index % 2 == 1 {
+                        style.striped_row_background
+                    } else {
+                        None
+                    };
+
+                    if let Some(background) = background {
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: pixel::snap(row_bounds),
+                                ..renderer::Quad::default()
+                            },
+                            background,
+                        );
+                    }
+
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: pixel::snap(Rectangle {
+                                y: row_bounds.y + row_bounds.height - 1.0,
+                                height: 1.0,
+                                ..row_bounds
+                            }),
+                            ..renderer::Quad::default()
+                        },
+                        style.divider,
+                    );
+                }
+
+                let cell_cursor = body_cursor(cursor, body);
+
+                for ((cell, cell_tree), cell_layout) in self
+                    .rows
+                    .iter()
+                    .flat_map(|row| row.cells.iter().enumerate())
+                    .zip(&tree.children)
+                    .zip(layout.children())
+                {
+                    let (column, cell) = cell;
+
+                    if column >= state.widths.len()
+                        || cell_layout.bounds().intersection(&clip).is_none()
+                    {
+                        continue;
+                    }
+
+                    cell.as_widget().draw(
+                        cell_tree,
+                        renderer,
+                        theme,
+                        &renderer::Style {
+                            text_color: style.text.unwrap_or(defaults.text_color),
+                        },
+                        cell_layout,
+                        cell_cursor,
+                        &clip,
+                    );
+                }
+
+                for start in starts.iter().skip(1) {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: pixel::snap(dir.mirror(
+                                Rectangle {
+                                    x: bounds.x + start,
+                                    y: body.y,
+                                    width: 1.0,
+                                    height: body.height,
+                                },
+                                bounds,
+                            )),
+                            ..renderer::Quad::default()
+                        },
+                        style.divider,
+                    );
+                }
+            });
+        }
+
+        // Header: background, titles, sort indicator and resize handles
+        if let Some(clip) = header.intersection(viewport) {
+            renderer.with_layer(clip, |renderer| {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: pixel::snap(header),
+                        border: pixel::border(Border {
+                            radius: style.border.radius.bottom(0.0),
+                            ..Border::default()
+                        }),
+                        ..renderer::Quad::default()
+                    },
+                    style.header_background,
+                );
+
+                let handle = state
+                    .resizing
+                    .map(|resize| resize.column)
+                    .or_else(|| {
+                        dir.mirror_cursor(cursor, bounds)
+                            .position()
+                            .and_then(|p| state.resize_handle_at(&self.columns, bounds, self.header_height, p))
+                    });
+
+                for (i, column) in self.columns.iter().enumerate() {
+                    let (Some(&start), Some(&width)) = (starts.get(i), state.widths.get(i)) else {
+                        continue;
+                    };
+                    let unmirrored = Rectangle {
+                        x: bounds.x + start,
+                        width,
+                        ..header
+                    };
+                    let cell = dir.mirror(unmirrored, bounds);
+
+                    if column.sortable
+                        && self.on_sort.is_some()
+                        && handle.is_none()
+                        && state.hovered_header == Some(i)
+                        && let Some(background) = style.header_hovered_background
+                    {
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: pixel::snap(cell),
+                                ..renderer::Quad::default()
+                            },
+                            background,
+                        );
+                    }
+
+                    let sorted = self.sort.filter(|(sorted, _)| *sorted == i).map(|(_, order)| order);
+                    let title_clip = dir.mirror(
+                        Rectangle {
+                            width: (width - self.cell_padding.right - sorted.map_or(0.0, |_| SORT_INDICATOR_WIDTH)).max(0.0),
+                            ..unmirrored
+                        },
+                        bounds,
+                    );
+
+                    if let Some(title_clip) = title_clip.intersection(&clip) {
+                        renderer.fill_text(
+                            iced::advanced::Text {
+                                content: column.title.clone(),
+                                bounds: Size::new(f32::INFINITY, self.header_height),
+                                size: self.text_size,
+                                font: self.font,
+                                align_x: dir.start().into(),
+                                align_y: alignment::Vertical::Center,
+                                line_height: text::LineHeight::default(),
+                                shaping: text::Shaping::Advanced,
+                                wrapping: text::Wrapping::None,
+                            },
+                            Point::new(dir.mirror_x(unmirrored.x + self.cell_padding.left, bounds), cell.center_y()),
+                            style.header_text,
+                            title_clip,
+                        );
+                    }
+
+                    if let Some(order) = sorted {
+                        let indicator = match order {
+                            SortOrder::Ascending => "▲",
+                            SortOrder::Descending => "▼",
+                        };
+
+                        renderer.fill_text(
+                            iced::advanced::Text {
+                                content: indicator.into(),
+                                bounds: Size::new(SORT_INDICATOR_WIDTH, self.header_height),
+                                size: Pixels(self.text_size.0 * 0.7),
+                                font: iced::Font::default(),
+                                align_x: text::Alignment::Center,
+                                align_y: alignment::Vertical::Center,
+                                line_height: text::LineHeight::default(),
+                                shaping: text::Shaping::Advanced,
+                                wrapping: text::Wrapping::None,
+                            },
+                            Point::new(
+                                dir.mirror_x(
+                                    unmirrored.x + width - self.cell_padding.right / 2.0 - SORT_INDICATOR_WIDTH / 2.0,
+                                    bounds,
+                                ),
+                                cell.center_y(),
+                            ),
+                            style.sort_indicator,
+                            clip,
+                        );
+                    }
+
+                    let active = handle == Some(i);
+                    let edge_width = if active { 2.0 } else { 1.0 };
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: pixel::snap(dir.mirror(
+                                Rectangle {
+                                    x: unmirrored.x + width - edge_width,
+                                    y: if active { header.y } else { header.y + header.height * 0.25 },
+                                    width: edge_width,
+                                    height: if active { header.height } else { header.height * 0.5 },
+                                },
+                                bounds,
+                            )),
+                            ..renderer::Quad::default()
+                        },
+                        if active { style.resize_handle } else { style.divider },
+                    );
+                }
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: pixel::snap(Rectangle {
+                            y: header.y + header.height - 1.0,
+                            height: 1.0,
+                            ..header
+                        }),
+                        ..renderer::Quad::default()
+                    },
+                    style.divider,
+                );
+            });
+        }
+
+        if let Some((_, thumb)) = state.scrollbar(body) {
+            let thumb = dir.mirror(thumb, bounds);
+            let active = state.scrollbar_grab.is_some() || cursor.is_over(thumb);
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: pixel::snap(thumb),
+                    border: pixel::border(Border {
+                        radius: (SCROLLBAR_WIDTH / 2.0).into(),
+                        ..Border::default()
+                    }),
+                    ..renderer::Quad::default()
+                },
+                if active { style.scrollbar_hovered } else { style.scrollbar },
+            );
+        }
+
+        if state.is_focused && let Some(focus) = style.focus_outline {
+            focus::draw_ring(renderer, bounds, Ring::new(focus).width(1.0).radius(style.border.radius));
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let body = body_bounds(bounds, self.header_height);
+
+        if state.resizing.is_some() {
+            return mouse::Interaction::ResizingHorizontally;
+        }
+        if state.scrollbar_grab.is_some() {
+            return mouse::Interaction::Grabbing;
+        }
+
+        let Some(position) = self.layout_direction().mirror_cursor(cursor, bounds).position_over(bounds) else {
+            return mouse::Interaction::None;
+        };
+
+        if state
+            .resize_handle_at(&self.columns, bounds, self.header_height, position)
+            .is_some()
+        {
+            return mouse::Interaction::ResizingHorizontally;
+        }
+
+        if position.y < body.y {
+            return match state.column_at(bounds, position.x) {
+                Some(column) if self.columns[column].sortable && self.on_sort.is_some() => {
+                    mouse::Interaction::Pointer
+                }
+                _ => mouse::Interaction::None,
+            };
+        }
+
+        let cell_cursor = body_cursor(cursor, body);
+        let cell_viewport = body.intersection(viewport).unwrap_or(body);
+
+        self.rows
+            .iter()
+            .flat_map(|row| row.cells.iter())
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((cell, cell_tree), cell_layout)| {
+                cell.as_widget()
+                    .mouse_interaction(cell_tree, cell_layout, cell_cursor, &cell_viewport, renderer)
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn widget::Operation,
+    ) {
+        let bounds = layout.bounds();
+        operation.focusable(None, bounds, tree.state.downcast_mut::<State>());
+
+        operation.container(None, bounds);
+        operation.traverse(&mut |operation| {
+            for ((cell, cell_tree), cell_layout) in self
+                .rows
+                .iter_mut()
+                .flat_map(|row| row.cells.iter_mut())
+                .zip(&mut tree.children)
+                .zip(layout.children())
+            {
+                cell.as_widget_mut().operate(cell_tree, cell_layout, renderer, operation);
+            }
+        });
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let children = self
+            .rows
+            .iter_mut()
+            .flat_map(|row| row.cells.iter_mut())
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .filter_map(|((cell, cell_tree), cell_layout)| {
+                cell.as_widget_mut()
+                    .overlay(cell_tree, cell_layout, renderer, viewport, translation)
+            })
+            .collect::<Vec<_>>();
+
+        (!children.is_empty()).then(|| overlay::Group::with_children(children).overlay())
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Table<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer<Font = iced::Font> + 'a,
+{
+    fn from(table: Table<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(table)
+    }
+}
+
+/// The theme catalog of a [`Table`].
+pub trait Catalog {
+    /// The style class
+    type Class<'a>;
+
+    /// Default style
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// Get the style for a class
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// The appearance of a [`Table`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// Background of the whole table
+    pub background: Option<Background>,
+    /// Border around the table
+    pub border: Border,
+    /// Default text color handed to the cells
+    pub text: Option<Color>,
+    /// Header background
+    pub header_background: Background,
+    /// Header background of a hovered sortable column
+    pub header_hovered_background: Option<Background>,
+    /// Header title color
+    pub header_text: Color,
+    /// Background of every other row
+    pub striped_row_background: Option<Background>,
+    /// Background of the hovered row
+    pub hovered_row_background: Option<Background>,
+    /// Background of selected rows
+    pub selected_row_background: Background,
+    /// Row, column and header separator color
+    pub divider: Color,
+    /// Color of the column edge being hovered or dragged
+    pub resize_handle: Color,
+    /// Sort arrow color
+    pub sort_indicator: Color,
+    /// Scrollbar thumb color
+    pub scrollbar: Color,
+    /// Scrollbar thumb color while hovered or dragged
+    pub scrollbar_hovered: Color,
+    /// Outline drawn while the table has keyboard focus
+    pub focus_outline: Option<Color>,
+}
+
+/// Styling function
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for iced::Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default table style.
+pub fn default(theme: &iced::Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: Some(palette.background.base.color.into()),
+        border: Border {
+            color: palette.background.strong.color,
+            width: 1.0,
+            radius: 4.0.into(),
+        },
+        text: Some(palette.background.base.text),
+        header_background: palette.background.weak.color.into(),
+        header_hovered_background: Some(palette.background.strong.color.into()),
+        header_text: palette.background.weak.text,
+        striped_row_background: None,
+        hovered_row_background: Some(palette.background.weakest.color.into()),
+        selected_row_background: palette.primary.weak.color.scale_alpha(0.5).into(),
+        divider: palette.background.strong.color,
+        resize_handle: palette.primary.strong.color,
+        sort_indicator: palette.primary.strong.color,
+        scrollbar: palette.background.strong.color,
+        scrollbar_hovered: palette.primary.strong.color,
+        focus_outline: Some(palette.primary.strong.color),
+    }
+}
+
+/// The default style with alternating row backgrounds.
+pub fn striped(theme: &iced::Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        striped_row_background: Some(palette.background.weakest.color.into()),
+        hovered_row_background: Some(palette.background.weak.color.into()),
+        ..default(theme)
+    }
+}
@@ -0,0 +1,1144 @@
+//! A tab bar with close buttons, drag-to-reorder and an overflow menu.
+//!
+//! The strip scrolls horizontally when the tabs do not fit, and a dropdown at its end lists
+//! every tab so hidden ones stay reachable. The bar only draws the strip: the app keeps the
+//! list of tabs and shows the content of the active one.
+//!
+//! Right to left, the tabs line up from the right with the overflow dropdown on the left.
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        mouse, overlay, renderer,
+        text,
+        widget::{self, tree::Tree},
+        Clipboard, Layout, Overlay, Shell, Widget,
+    },
+    alignment, keyboard, touch, Background, Border, Color, Element, Event, Length, Padding,
+    Pixels, Point, Rectangle, Shadow, Size, Vector,
+};
+
+use crate::direction::{self, Direction};
+use crate::dnd;
+use crate::reorder::{self, Axis, Reorder};
+use crate::pixel;
+
+const DEFAULT_TAB_HEIGHT: f32 = 32.0;
+const DEFAULT_MIN_TAB_WIDTH: f32 = 60.0;
+const DEFAULT_MAX_TAB_WIDTH: f32 = 220.0;
+const CLOSE_SIZE: f32 = 16.0;
+const CLOSE_SPACING: f32 = 6.0;
+const OVERFLOW_BUTTON_WIDTH: f32 = 28.0;
+const MENU_ITEM_HEIGHT: f32 = 28.0;
+const MENU_PADDING: f32 = 4.0;
+const MENU_MAX_WIDTH: f32 = 280.0;
+const LINE_SCROLL: f32 = 40.0; // Pixels scrolled per wheel line
+
+/// Creates a new [`TabBar`] with the given tabs.
+pub fn tab_bar<'a, Message, Theme>(tabs: impl IntoIterator<Item = Tab>) -> TabBar<'a, Message, Theme>
+where
+    Theme: Catalog,
+{
+    TabBar::new(tabs)
+}
+
+/// A tab of a [`TabBar`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tab {
+    label: String,
+    closable: bool,
+}
+
+impl Tab {
+    /// Creates a new closable [`Tab`] with the given label.
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            closable: true,
+        }
+    }
+
+    /// Sets whether the tab shows a close button.
+    pub fn closable(mut self, closable: bool) -> Self {
+        self.closable = closable;
+        self
+    }
+}
+
+impl<T: Into<String>> From<T> for Tab {
+    fn from(label: T) -> Self {
+        Tab::new(label)
+    }
+}
+
+/// A horizontal strip of tabs.
+#[allow(missing_debug_implementations)]
+pub struct TabBar<'a, Message, Theme = iced::Theme>
+where
+    Theme: Catalog,
+{
+    tabs: Vec<Tab>,
+    active: Option<usize>,
+    width: Length,
+    height: f32,
+    min_tab_width: f32,
+    max_tab_width: f32,
+    spacing: f32,
+    padding: Padding,
+    text_size: Pixels,
+    font: iced::Font,
+    on_select: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    on_close: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    on_reorder: Option<Box<dyn Fn(Vec<usize>) -> Message + 'a>>,
+    direction: Option<Direction>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme> TabBar<'a, Message, Theme>
+where
+    Theme: Catalog,
+{
+    /// Creates a new [`TabBar`] with the given tabs.
+    pub fn new(tabs: impl IntoIterator<Item = Tab>) -> Self {
+        Self {
+            tabs: tabs.into_iter().collect(),
+            active: None,
+            width: Length::Fill,
+            height: DEFAULT_TAB_HEIGHT,
+            min_tab_width: DEFAULT_MIN_TAB_WIDTH,
+            max_tab_width: DEFAULT_MAX_TAB_WIDTH,
+            spacing: 2.0,
+            padding: Padding::from([0, 12]),
+            text_size: Pixels(14.0),
+            font: iced::Font::default(),
+            on_select: None,
+            on_close: None,
+            on_reorder: None,
+            direction: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Appends a tab.
+    pub fn push(mut self, tab: impl Into<Tab>) -> Self {
+        self.tabs.push(tab.into());
+        self
+    }
+
+    /// Sets the active tab, which is highlighted and scrolled into view.
+    pub fn active(mut self, index: usize) -> Self {
+        self.active = Some(index);
+        self
+    }
+
+    /// Sets the width of the bar.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the bar.
+    pub fn height(mut self, height: f32) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the narrowest a tab gets.
+    pub fn min_tab_width(mut self, width: f32) -> Self {
+        self.min_tab_width = width;
+        self
+    }
+
+    /// Sets the widest a tab gets; longer labels are clipped.
+    pub fn max_tab_width(mut self, width: f32) -> Self {
+        self.max_tab_width = width;
+        self
+    }
+
+    /// Sets the gap between tabs.
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the padding inside every tab.
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the labels.
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = size.into();
+        self
+    }
+
+    /// Sets the font of the labels.
+    pub fn font(mut self, font: iced::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the message produced when a tab is clicked or picked from the overflow menu.
+    pub fn on_select(mut self, callback: impl Fn(usize) -> Message + 'a) -> Self {
+        self.on_select = Some(Box::new(callback));
+        self
+    }
+
+    /// Sets the message produced when a close button is clicked or a tab is middle-clicked.
+    pub fn on_close(mut self, callback: impl Fn(usize) -> Message + 'a) -> Self {
+        self.on_close = Some(Box::new(callback));
+        self
+    }
+
+    /// Enables dragging tabs, producing the new order once one is dropped.
+    ///
+    /// `order[i]` is the previous index of the tab now at `i`.
+    pub fn on_reorder(mut self, callback: impl Fn(Vec<usize>) -> Message + 'a) -> Self {
+        self.on_reorder = Some(Box::new(callback));
+        self
+    }
+
+    /// Sets the style.
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class.
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Sets the [`Direction`] of the bar, instead of the one set with [`direction::set`].
+    ///
+    /// Right to left lines the tabs up from the right, with the close buttons on their left.
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    // The bar is laid out left to right, and mirrored on the way in and out
+    fn layout_direction(&self) -> Direction {
+        self.direction.unwrap_or_else(direction::get)
+    }
+
+    fn label_text(&self, label: &str, width: f32) -> iced::advanced::Text<String, iced::Font> {
+        iced::advanced::Text {
+            content: label.to_owned(),
+            bounds: Size::new(width, self.height),
+            size: self.text_size,
+            font: self.font,
+            align_x: text::Alignment::Left,
+            align_y: alignment::Vertical::Center,
+            line_height: text::LineHeight::default(),
+            shaping: text::Shaping::Advanced,
+            wrapping: text::Wrapping::None,
+        }
+    }
+
+    fn label_width<P: text::Paragraph<Font = iced::Font>>(&self, label: &str) -> f32 {
+        P::with_text(iced::advanced::Text {
+            content: label,
+            bounds: Size::new(f32::INFINITY, self.height),
+            size: self.text_size,
+            font: self.font,
+            align_x: text::Alignment::Left,
+            align_y: alignment::Vertical::Center,
+            line_height: text::LineHeight::default(),
+            shaping: text::Shaping::Advanced,
+            wrapping: text::Wrapping::None,
+        })
+        .min_bounds()
+        .width
+    }
+
+    fn close_visible(&self, index: usize) -> bool {
+        self.on_close.is_some() && self.tabs.get(index).is_some_and(|tab| tab.closable)
+    }
+}
+
+#[derive(Default)]
+struct State {
+    widths: Vec<f32>,
+    scroll: f32,
+    overflowing: bool,
+    hovered: Option<Target>,
+    drag: Option<Reorder>,
+    pressed_close: Option<usize>,
+    menu_open: bool,
+    reveal: Option<usize>,
+    last_active: Option<usize>,
+}
+
+/// The part of the bar under the cursor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Target {
+    Tab(usize),
+    Close(usize),
+    Overflow,
+}
+
+impl State {
+    /// The part of the bar holding the tabs, excluding the overflow button.
+    fn strip(&self, bounds: Rectangle) -> Rectangle {
+        if self.overflowing {
+            Rectangle {
+                width: (bounds.width - OVERFLOW_BUTTON_WIDTH).max(0.0),
+                ..bounds
+            }
+        } else {
+            bounds
+        }
+    }
+
+    fn overflow_button(&self, bounds: Rectangle) -> Option<Rectangle> {
+        self.overflowing.then_some(Rectangle {
+            x: bounds.x + bounds.width - OVERFLOW_BUTTON_WIDTH,
+            width: OVERFLOW_BUTTON_WIDTH,
+            ..bounds
+        })
+    }
+
+    fn content_width(&self, spacing: f32) -> f32 {
+        let gaps = spacing * self.widths.len().saturating_sub(1) as f32;
+        self.widths.iter().sum::<f32>() + gaps
+    }
+
+    /// The on-screen rectangle of every tab, shifted by the scroll offset.
+    fn tab_bounds(&self, bounds: Rectangle, spacing: f32) -> Vec<Rectangle> {
+        let mut x = bounds.x - self.scroll;
+
+        self.widths
+            .iter()
+            .map(|&width| {
+                let tab = Rectangle { x, width, ..bounds };
+                x += width + spacing;
+                tab
+            })
+            .collect()
+    }
+
+    fn target_at<Message, Theme: Catalog>(
+        &self,
+        bar: &TabBar<'_, Message, Theme>,
+        bounds: Rectangle,
+        point: Point,
+    ) -> Option<Target> {
+        if !bounds.contains(point) {
+            return None;
+        }
+
+        if self.overflow_button(bounds).is_some_and(|button| button.contains(point)) {
+            return Some(Target::Overflow);
+        }
+
+        if !self.strip(bounds).contains(point) {
+            return None;
+        }
+
+        let index = self
+            .tab_bounds(bounds, bar.spacing)
+            .iter()
+            .position(|tab| tab.contains(point))?;
+
+        if bar.close_visible(index)
+            && close_bounds(self.tab_bounds(bounds, bar.spacing)[index], bar.padding).contains(point)
+        {
+            Some(Target::Close(index))
+        } else {
+            Some(Target::Tab(index))
+        }
+    }
+
+    /// Scrolls just enough to show the given tab.
+    fn scroll_into_view(&mut self, index: usize, visible: f32, spacing: f32) {
+        let Some(&width) = self.widths.get(index) else {
+            return;
+        };
+        let start: f32 = self.widths[..index].iter().map(|width| width + spacing).sum();
+
+        if start < self.scroll {
+            self.scroll = start;
+        } else if start + width > self.scroll + visible {
+            self.scroll = start + width - visible;
+        }
+    }
+}
+
+fn close_bounds(tab: Rectangle, padding: Padding) -> Rectangle {
+    Rectangle {
+        x: tab.x + tab.width - padding.right - CLOSE_SIZE,
+        y: tab.center_y() - CLOSE_SIZE / 2.0,
+        width: CLOSE_SIZE,
+        height: CLOSE_SIZE,
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer> for TabBar<'a, Message, Theme>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer<Font = iced::Font>,
+{
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Fixed(self.height),
+        }
+    }
+
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(State {
+            reveal: self.active,
+            last_active: self.active,
+            ..State::default()
+        })
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+
+        if self.active != state.last_active {
+            state.last_active = self.active;
+            state.reveal = self.active;
+        }
+
+        if state.drag.is_some_and(|drag| drag.index >= self.tabs.len()) {
+            state.drag = None;
+        }
+    }
+
+    fn layout(&mut self, tree: &mut Tree, _renderer: &Renderer, limits: &Limits) -> Node {
+        let state = tree.state.downcast_mut::<State>();
+
+        state.widths = self
+            .tabs
+            .iter()
+            .enumerate()
+            .map(|(index, tab)| {
+                let label = self.label_width::<Renderer::Paragraph>(&tab.label);
+                let close = if self.close_visible(index) { CLOSE_SPACING + CLOSE_SIZE } else { 0.0 };
+
+                (self.padding.x() + label + close)
+                    .clamp(self.min_tab_width, self.max_tab_width.max(self.min_tab_width))
+            })
+            .collect();
+
+        let content_width = state.content_width(self.spacing);
+        let size = limits
+            .height(self.height)
+            .resolve(self.width, self.height, Size::new(content_width, self.height));
+
+        state.overflowing = content_width > size.width;
+
+        let visible = state.strip(Rectangle::with_size(size)).width;
+        if let Some(index) = state.reveal.take() {
+            state.scroll_into_view(index, visible, self.spacing);
+        }
+        state.scroll = state.scroll.clamp(0.0, (content_width - visible).max(0.0));
+
+        if !state.overflowing {
+            state.menu_open = false;
+        }
+
+        Node::new(size)
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+        let dir = self.layout_direction();
+        let cursor = dir.mirror_cursor(cursor, bounds);
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. }) | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                let position = cursor.position();
+
+                if let Some(mut drag) = state.drag
+                    && let Some(position) = position
+                {
+                    let tabs = state.tab_bounds(bounds, self.spacing);
+                    drag.drag_to(position, dir.mirror_point(position, bounds), tabs, Axis::Horizontal);
+                    state.drag = Some(drag);
+
+                    if drag.is_active() {
+                        shell.capture_event();
+                        shell.request_redraw();
+                        return;
+                    }
+                }
+
+                let hovered = position.and_then(|position| state.target_at(self, bounds, position));
+                if hovered != state.hovered {
+                    state.hovered = hovered;
+                    shell.request_redraw();
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    return;
+                };
+
+                match state.target_at(self, bounds, position) {
+                    Some(Target::Overflow) => {
+                        state.menu_open = !state.menu_open;
+                    }
+                    Some(Target::Close(index)) => {
+                        state.pressed_close = Some(index);
+                    }
+                    Some(Target::Tab(index)) => {
+                        if self.active != Some(index)
+                            && let Some(on_select) = &self.on_select
+                        {
+                            shell.publish(on_select(index));
+                        }
+                        if self.on_reorder.is_some() {
+                            state.drag = Some(Reorder::new(dnd::TAB, index, dir.mirror_point(position, bounds)));
+                        }
+                    }
+                    None => return,
+                }
+
+                shell.capture_event();
+                shell.request_redraw();
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. }) => {
+                if let Some(drag) = state.drag.take()
+                    && drag.is_active()
+                {
+                    if let Some(order) = drag.release(self.tabs.len())
+                        && let Some(on_reorder) = &self.on_reorder
+                    {
+                        // Keep the measured widths in step until the next layout
+                        reorder::apply(&mut state.widths, &order);
+                        shell.publish(on_reorder(order));
+                        shell.invalidate_layout();
+                    }
+                    shell.capture_event();
+                    shell.request_redraw();
+                    return;
+                }
+
+                if let Some(index) = state.pressed_close.take() {
+                    if cursor
+                        .position()
+                        .and_then(|position| state.target_at(self, bounds, position))
+                        == Some(Target::Close(index))
+                        && let Some(on_close) = &self.on_close
+                    {
+                        shell.publish(on_close(index));
+                    }
+                    shell.capture_event();
+                    shell.request_redraw();
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Middle)) => {
+                if let Some(position) = cursor.position_over(bounds)
+                    && let Some(Target::Tab(index) | Target::Close(index)) =
+                        state.target_at(self, bounds, position)
+                    && self.close_visible(index)
+                    && let Some(on_close) = &self.on_close
+                {
+                    shell.publish(on_close(index));
+                    shell.capture_event();
+                }
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+                if !state.overflowing || !cursor.is_over(bounds) {
+                    return;
+                }
+
+                // Vertical wheels scroll the strip too, most mice have no horizontal wheel
+                let (x, y, unit) = match delta {
+                    mouse::ScrollDelta::Lines { x, y } => (*x, *y, LINE_SCROLL),
+                    mouse::ScrollDelta::Pixels { x, y } => (*x, *y, 1.0),
+                };
+                // A horizontal wheel moves the mirrored strip the other way
+                let delta = unit
+                    * match (x != 0.0, dir) {
+                        (true, Direction::LeftToRight) => x,
+                        (true, Direction::RightToLeft) => -x,
+                        (false, _) => y,
+                    };
+                let max = (state.content_width(self.spacing) - state.strip(bounds).width).max(0.0);
+                let scroll = (state.scroll - delta).clamp(0.0, max);
+
+                if scroll != state.scroll {
+                    state.scroll = scroll;
+                    state.hovered = None;
+                    shell.request_redraw();
+                }
+                shell.capture_event();
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(keyboard::key::Named::Escape),
+                ..
+            }) if state.drag.is_some_and(|drag| drag.is_active()) => {
+                if let Some(drag) = state.drag.take() {
+                    drag.cancel();
+                }
+                shell.capture_event();
+                shell.request_redraw();
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _defaults: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let style = theme.style(&self.class);
+        let bounds = layout.bounds();
+        let dir = self.layout_direction();
+        let strip = dir.mirror(state.strip(bounds), bounds);
+        let tabs = state.tab_bounds(bounds, self.spacing);
+        let dragging = state.drag.filter(|drag| drag.is_active());
+
+        if let Some(background) = style.background {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: pixel::snap(bounds),
+                    ..renderer::Quad::default()
+                },
+                background,
+            );
+        }
+
+        let Some(clip) = strip.intersection(viewport) else {
+            return;
+        };
+
+        renderer.with_layer(clip, |renderer| {
+            for (index, (tab, &unmirrored)) in self.tabs.iter().zip(&tabs).enumerate() {
+                let tab_bounds = dir.mirror(unmirrored, bounds);
+                if tab_bounds.intersection(&clip).is_none() {
+                    continue;
+                }
+
+                let is_active = self.active == Some(index);
+                let is_hovered = dragging.is_none()
+                    && matches!(state.hovered, Some(Target::Tab(i) | Target::Close(i)) if i == index);
+                let is_dragged = dragging.is_some_and(|drag| drag.index == index);
+
+                let background = if is_active {
+                    Some(style.active_tab_background)
+                } else if is_hovered || is_dragged {
+                    style.tab_hovered_background
+                } else {
+                    style.tab_background
+                };
+
+                if let Some(background) = background {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: pixel::snap(tab_bounds),
+                            border: pixel::border(style.tab_border),
+                            ..renderer::Quad::default()
+                        },
+                        background,
+                    );
+                }
+
+                if is_active && let Some(indicator) = style.active_indicator {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: pixel::snap(Rectangle {
+                                y: tab_bounds.y + tab_bounds.height - 2.0,
+                                height: 2.0,
+                                ..tab_bounds
+                            }),
+                            ..renderer::Quad::default()
+                        },
+                        indicator,
+                    );
+                }
+
+                let close = self.close_visible(index);
+                let label_width = tab_bounds.width
+                    - self.padding.x()
+                    - if close { CLOSE_SPACING + CLOSE_SIZE } else { 0.0 };
+                let label_clip = dir.mirror(
+                    Rectangle {
+                        x: unmirrored.x + self.padding.left,
+                        width: label_width.max(0.0),
+                        ..unmirrored
+                    },
+                    bounds,
+                );
+                let text_color = if is_active { style.active_text_color } else { style.text_color };
+
+                if let Some(label_clip) = label_clip.intersection(&clip) {
+                    renderer.fill_text(
+                        iced::advanced::Text {
+                            align_x: dir.start().into(),
+                            ..self.label_text(&tab.label, f32::INFINITY)
+                        },
+                        Point::new(dir.mirror_x(unmirrored.x + self.padding.left, bounds), tab_bounds.center_y()),
+                        if is_dragged { text_color.scale_alpha(0.6) } else { text_color },
+                        label_clip,
+                    );
+                }
+
+                if close {
+                    let close_bounds = dir.mirror(close_bounds(unmirrored, self.padding), bounds);
+
+                    if state.hovered == Some(Target::Close(index)) || state.pressed_close == Some(index) {
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: pixel::snap(close_bounds),
+                                border: pixel::border(Border {
+                                    radius: (CLOSE_SIZE / 2.0).into(),
+                                    ..Border::default()
+                                }),
+                                ..renderer::Quad::default()
+                            },
+                            style.close_hovered_background,
+                        );
+                    }
+
+                    renderer.fill_text(
+                        iced::advanced::Text {
+                            content: "×".into(),
+                            bounds: Size::new(CLOSE_SIZE, CLOSE_SIZE),
+                            size: self.text_size,
+                            font: iced::Font::default(),
+                            align_x: text::Alignment::Center,
+                            align_y: alignment::Vertical::Center,
+                            line_height: text::LineHeight::default(),
+                            shaping: text::Shaping::Advanced,
+                            wrapping: text::Wrapping::None,
+                        },
+                        close_bounds.center(),
+                        style.close_color,
+                        clip,
+                    );
+                }
+            }
+
+            // Drop indicator in the gap the dragged tab would move to
+            if let Some(drag) = dragging
+                && let Some(x) = reorder::gap_position(&tabs, drag.target, Axis::Horizontal)
+            {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: pixel::snap(Rectangle {
+                            x: dir.mirror_x(x, bounds) - 1.0,
+                            width: 2.0,
+                            ..bounds
+                        }),
+                        ..renderer::Quad::default()
+                    },
+                    style.drop_indicator,
+                );
+            }
+        });
+
+        if let Some(button) = state.overflow_button(bounds).map(|button| dir.mirror(button, bounds)) {
+            if state.menu_open || state.hovered == Some(Target::Overflow) {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: pixel::snap(button),
+                        border: pixel::border(style.tab_border),
+                        ..renderer::Quad::default()
+                    },
+                    style.tab_hovered_background.unwrap_or(style.active_tab_background),
+                );
+            }
+
+            renderer.fill_text(
+                iced::advanced::Text {
+                    content: "⌄".into(),
+                    bounds: button.size(),
+                    size: self.text_size,
+                    font: iced::Font::default(),
+                    align_x: text::Alignment::Center,
+                    align_y: alignment::Vertical::Center,
+                    line_height: text::LineHeight::default(),
+                    shaping: text::Shaping::Advanced,
+                    wrapping: text::Wrapping::None,
+                },
+                button.center(),
+                style.text_color,
+                *viewport,
+            );
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        if state.drag.is_some_and(|drag| drag.is_active()) {
+            return mouse::Interaction::Grabbing;
+        }
+
+        let cursor = self.layout_direction().mirror_cursor(cursor, layout.bounds());
+
+        match cursor
+            .position()
+            .and_then(|position| state.target_at(self, layout.bounds(), position))
+        {
+            Some(_) => mouse::Interaction::Pointer,
+            None => mouse::Interaction::None,
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        _renderer: &Renderer,
+        _viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_mut::<State>();
+        let button = self.layout_direction().mirror(state.overflow_button(layout.bounds())?, layout.bounds());
+
+        if !state.menu_open {
+            return None;
+        }
+
+        Some(overlay::Element::new(Box::new(OverflowMenu {
+            bar: self,
+            state,
+            anchor: button + translation,
+        })))
+    }
+}
+
+/// The dropdown listing every tab, opened from the overflow button.
+struct OverflowMenu<'a, 'b, Message, Theme>
+where
+    Theme: Catalog,
+{
+    bar: &'a TabBar<'b, Message, Theme>,
+    state: &'a mut State,
+    anchor: Rectangle,
+}
+
+impl<Message, Theme> OverflowMenu<'_, '_, Message, Theme>
+where
+    Theme: Catalog,
+{
+    fn item_at(&self, bounds: Rectangle, point: Point) -> Option<usize> {
+        if !bounds.contains(point) {
+            return None;
+        }
+
+        let index = ((point.y - bounds.y - MENU_PADDING) / MENU_ITEM_HEIGHT).floor();
+        (index >= 0.0 && (index as usize) < self.bar.tabs.len()).then_some(index as usize)
+    }
+}
+
+impl<Message, Theme, Renderer> Overlay<Message, Theme, Renderer> for OverflowMenu<'_, '_, Message, Theme>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer<Font = iced::Font>,
+{
+    fn layout(&mut self, _renderer: &Renderer, bounds: Size) -> Node {
+        let width = self
+            .bar
+            .tabs
+            .iter()
+            .map(|tab| self.bar.label_width::<Renderer::Paragraph>(&tab.label))
+            .fold(0.0, f32::max)
+            + self.bar.padding.x()
+            + MENU_PADDING * 2.0;
+        let width = width.min(MENU_MAX_WIDTH).min(bounds.width);
+        let height = (self.bar.tabs.len() as f32 * MENU_ITEM_HEIGHT + MENU_PADDING * 2.0).min(bounds.height);
+
+        // Align to the bar's end under the button, flipping above it when there is no room below
+        let x = match self.bar.layout_direction() {
+            Direction::LeftToRight => self.anchor.x + self.anchor.width - width,
+            Direction::RightToLeft => self.anchor.x,
+        }
+        .clamp(0.0, (bounds.width - width).max(0.0));
+        let below = self.anchor.y + self.anchor.height;
+        let y = if below + height > bounds.height {
+            (self.anchor.y - height).max(0.0)
+        } else {
+            below
+        };
+
+        Node::new(Size::new(width, height)).move_to(Point::new(x, y))
+    }
+
+    fn update(
+        &mut self,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. }) if cursor.is_over(bounds) => {
+                shell.request_redraw();
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                // The button toggles the menu itself
+                if cursor.is_over(self.anchor) {
+                    return;
+                }
+
+                if let Some(index) = cursor.position().and_then(|position| self.item_at(bounds, position)) {
+                    if self.bar.active != Some(index)
+                        && let Some(on_select) = &self.bar.on_select
+                    {
+                        shell.publish(on_select(index));
+                    }
+                    self.state.reveal = Some(index);
+                    shell.invalidate_layout();
+                }
+
+                self.state.menu_open = false;
+                shell.capture_event();
+                shell.request_redraw();
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(keyboard::key::Named::Escape),
+                ..
+            }) => {
+                self.state.menu_open = false;
+                shell.capture_event();
+                shell.request_redraw();
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _defaults: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        let style = theme.style(&self.bar.class);
+        let bounds = layout.bounds();
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: pixel::snap(bounds),
+                border: pixel::border(style.menu_border),
+                shadow: style.menu_shadow,
+                snap: true,
+            },
+            style.menu_background,
+        );
+
+        let hovered = cursor.position().and_then(|position| self.item_at(bounds, position));
+        let dir = self.bar.layout_direction();
+
+        renderer.with_layer(bounds, |renderer| {
+            for (index, tab) in self.bar.tabs.iter().enumerate() {
+                let item = Rectangle {
+                    x: bounds.x + MENU_PADDING,
+                    y: bounds.y + MENU_PADDING + index as f32 * MENU_ITEM_HEIGHT,
+                    width: bounds.width - MENU_PADDING * 2.0,
+                    height: MENU_ITEM_HEIGHT,
+                };
+                let is_active = self.bar.active == Some(index);
+
+                if hovered == Some(index) || is_active {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: pixel::snap(item),
+                            border: pixel::border(Border {
+                                radius: style.menu_border.radius,
+                                ..Border::default()
+                            }),
+                            ..renderer::Quad::default()
+                        },
+                        if hovered == Some(index) { style.menu_hovered_background } else { style.active_tab_background },
+                    );
+                }
+
+                renderer.fill_text(
+                    iced::advanced::Text {
+                        bounds: item.size(),
+                        align_x: dir.start().into(),
+                        ..self.bar.label_text(&tab.label, item.width)
+                    },
+                    Point::new(dir.mirror_x(item.x + self.bar.padding.left / 2.0, item), item.center_y()),
+                    if is_active { style.active_text_color } else { style.text_color },
+                    item,
+                );
+            }
+        });
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        match cursor.position().and_then(|position| self.item_at(layout.bounds(), position)) {
+            Some(_) => mouse::Interaction::Pointer,
+            None => mouse::Interaction::None,
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<TabBar<'a, Message, Theme>> for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer<Font = iced::Font> + 'a,
+{
+    fn from(tab_bar: TabBar<'a, Message, Theme>) -> Self {
+        Element::new(tab_bar)
+    }
+}
+
+/// The theme catalog of a [`TabBar`].
+pub trait Catalog {
+    /// The style class
+    type Class<'a>;
+
+    /// Default style
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// Get the style for a class
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// The appearance of a [`TabBar`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// Background behind the whole strip
+    pub background: Option<Background>,
+    /// Background of inactive tabs
+    pub tab_background: Option<Background>,
+    /// Background of a hovered or dragged tab
+    pub tab_hovered_background: Option<Background>,
+    /// Background of the active tab
+    pub active_tab_background: Background,
+    /// Border of every tab
+    pub tab_border: Border,
+    /// Line drawn under the active tab
+    pub active_indicator: Option<Color>,
+    /// Label color of inactive tabs
+    pub text_color: Color,
+    /// Label color of the active tab
+    pub active_text_color: Color,
+    /// Close button glyph color
+    pub close_color: Color,
+    /// Background of a hovered close button
+    pub close_hovered_background: Color,
+    /// Color of the gap a dragged tab would be dropped into
+    pub drop_indicator: Color,
+    /// Background of the overflow menu
+    pub menu_background: Background,
+    /// Border of the overflow menu
+    pub menu_border: Border,
+    /// Shadow of the overflow menu
+    pub menu_shadow: Shadow,
+    /// Background of the hovered overflow menu item
+    pub menu_hovered_background: Background,
+}
+
+/// Styling function
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for iced::Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default tab bar style.
+pub fn default(theme: &iced::Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: Some(palette.background.weak.color.into()),
+        tab_background: None,
+        tab_hovered_background: Some(palette.background.strong.color.into()),
+        active_tab_background: palette.background.base.color.into(),
+        tab_border: Border {
+            radius: iced::border::top(4.0),
+            ..Border::default()
+        },
+        active_indicator: Some(palette.primary.strong.color),
+        text_color: palette.background.weak.text,
+        active_text_color: palette.background.base.text,
+        close_color: palette.background.weak.text,
+        close_hovered_background: palette.background.strongest.color,
+        drop_indicator: palette.primary.strong.color,
+        menu_background: palette.background.base.color.into(),
+        menu_border: Border {
+            color: palette.background.strong.color,
+            width: 1.0,
+            radius: 4.0.into(),
+        },
+        menu_shadow: Shadow {
+            color: Color::BLACK.scale_alpha(0.3),
+            offset: Vector::new(0.0, 2.0),
+            blur_radius: 8.0,
+        },
+        menu_hovered_background: palette.primary.weak.color.into(),
+    }
+}
+
+/// Pill shaped tabs without the underline, for toolbars.
+pub fn pills(theme: &iced::Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: None,
+        active_tab_background: palette.primary.base.color.into(),
+        active_text_color: palette.primary.base.text,
+        tab_border: iced::border::rounded(16),
+        active_indicator: None,
+        ..default(theme)
+    }
+}
@@ -0,0 +1,983 @@
+//! A text field that turns what is typed into removable chips.
+//!
+//! Enter or a comma adds the typed text as a chip, Backspace on an empty draft removes the
+//! last chip and every chip has its own × button. Typing filters an optional list of
+//! suggestions shown in a dropdown below the field, picked with the mouse or the arrow keys
+//! and Enter. Pasting text with commas or new lines adds every piece as a chip.
+//!
+//! The field only edits at the end of the draft; the tags themselves are owned by the app
+//! and every change is reported through `on_change` with the complete list.
+
+use iced::{
+    advanced::{
+        clipboard,
+        layout::{Limits, Node},
+        mouse, overlay, renderer, text,
+        widget::{self, tree::Tree},
+        Clipboard, Layout, Overlay, Shell, Widget,
+    },
+    alignment, keyboard, Background, Border, Color, Element, Event, Length, Padding, Pixels,
+    Point, Rectangle, Shadow, Size, Vector,
+};
+
+use crate::pixel;
+
+const CHIP_PADDING: Padding = Padding {
+    top: 2.0,
+    right: 4.0,
+    bottom: 2.0,
+    left: 8.0,
+};
+const CLOSE_SIZE: f32 = 16.0;
+const MIN_DRAFT_WIDTH: f32 = 60.0;
+const CARET_WIDTH: f32 = 1.0;
+const MENU_ITEM_HEIGHT: f32 = 28.0;
+const MENU_PADDING: f32 = 4.0;
+const MENU_OFFSET: f32 = 2.0;
+const DEFAULT_MAX_SUGGESTIONS: usize = 8;
+
+/// Creates a new [`TagInput`] showing the given tags.
+pub fn tag_input<'a, Message, Theme>(
+    tags: &'a [String],
+    on_change: impl Fn(Vec<String>) -> Message + 'a,
+) -> TagInput<'a, Message, Theme>
+where
+    Theme: Catalog,
+{
+    TagInput::new(tags, on_change)
+}
+
+/// A field of chips, added by typing and removed with Backspace or their × button.
+#[allow(missing_debug_implementations)]
+pub struct TagInput<'a, Message, Theme = iced::Theme>
+where
+    Theme: Catalog,
+{
+    tags: &'a [String],
+    on_change: Box<dyn Fn(Vec<String>) -> Message + 'a>,
+    on_input: Option<Box<dyn Fn(String) -> Message + 'a>>,
+    suggestions: Vec<String>,
+    max_suggestions: usize,
+    allow_duplicates: bool,
+    max_tags: Option<usize>,
+    placeholder: String,
+    width: Length,
+    padding: Padding,
+    spacing: f32,
+    text_size: Pixels,
+    font: iced::Font,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme> TagInput<'a, Message, Theme>
+where
+    Theme: Catalog,
+{
+    /// Creates a new [`TagInput`] showing the given tags.
+    pub fn new(tags: &'a [String], on_change: impl Fn(Vec<String>) -> Message + 'a) -> Self {
+        Self {
+            tags,
+            on_change: Box::new(on_change),
+            on_input: None,
+            suggestions: Vec::new(),
+            max_suggestions: DEFAULT_MAX_SUGGESTIONS,
+            allow_duplicates: false,
+            max_tags: None,
+            placeholder: String::new(),
+            width: Length::Fill,
+            padding: Padding::from(4),
+            spacing: 4.0,
+            text_size: Pixels(14.0),
+            font: iced::Font::default(),
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the message produced whenever the draft text changes, e.g. to fetch suggestions.
+    pub fn on_input(mut self, on_input: impl Fn(String) -> Message + 'a) -> Self {
+        self.on_input = Some(Box::new(on_input));
+        self
+    }
+
+    /// Sets the suggestions offered while typing, filtered by the draft.
+    pub fn suggestions(mut self, suggestions: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.suggestions = suggestions.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets how many suggestions the dropdown shows at most.
+    pub fn max_suggestions(mut self, max: usize) -> Self {
+        self.max_suggestions = max;
+        self
+    }
+
+    /// Allows the same tag to be added more than once.
+    pub fn allow_duplicates(mut self, allow: bool) -> Self {
+        self.allow_duplicates = allow;
+        self
+    }
+
+    /// Limits the number of tags; once reached, typing is ignored.
+    pub fn max_tags(mut self, max: usize) -> Self {
+        self.max_tags = Some(max);
+        self
+    }
+
+    /// Sets the text shown while there are no tags and no draft.
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Sets the width of the field.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the padding of the field.
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the space between chips.
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets the text size.
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = size.into();
+        self
+    }
+
+    /// Sets the font.
+    pub fn font(mut self, font: iced::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style.
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class.
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    fn is_full(&self) -> bool {
+        self.max_tags.is_some_and(|max| self.tags.len() >= max)
+    }
+
+    fn contains(&self, tag: &str) -> bool {
+        self.tags.iter().any(|existing| existing.eq_ignore_ascii_case(tag))
+    }
+
+    /// The suggestions containing the draft, leaving out tags already added.
+    fn matching(&self, draft: &str) -> Vec<&str> {
+        let needle = draft.trim().to_lowercase();
+
+        self.suggestions
+            .iter()
+            .filter(|suggestion| self.allow_duplicates || !self.contains(suggestion))
+            .filter(|suggestion| suggestion.to_lowercase().contains(&needle))
+            .take(self.max_suggestions)
+            .map(String::as_str)
+            .collect()
+    }
+
+    /// Adds the pieces as tags, returning whether any was added.
+    fn add<'s>(&self, pieces: impl IntoIterator<Item = &'s str>, shell: &mut Shell<'_, Message>) -> bool {
+        let mut tags = self.tags.to_vec();
+
+        for piece in pieces {
+            let piece = piece.trim();
+            let duplicate = !self.allow_duplicates && tags.iter().any(|tag| tag.eq_ignore_ascii_case(piece));
+
+            if !piece.is_empty() && !duplicate && self.max_tags.is_none_or(|max| tags.len() < max) {
+                tags.push(piece.to_string());
+            }
+        }
+
+        let added = tags.len() > self.tags.len();
+        if added {
+            shell.publish((self.on_change)(tags));
+        }
+        added
+    }
+
+    fn remove(&self, index: usize, shell: &mut Shell<'_, Message>) {
+        if index < self.tags.len() {
+            let mut tags = self.tags.to_vec();
+            tags.remove(index);
+            shell.publish((self.on_change)(tags));
+        }
+    }
+
+    fn set_draft(&self, state: &mut State, draft: String, shell: &mut Shell<'_, Message>) {
+        if draft != state.draft {
+            state.draft = draft;
+            state.highlighted = None;
+
+            if let Some(on_input) = &self.on_input {
+                shell.publish(on_input(state.draft.clone()));
+            }
+        }
+    }
+
+    fn text(&self, content: impl Into<String>, bounds: Size) -> iced::advanced::Text<String, iced::Font> {
+        iced::advanced::Text {
+            content: content.into(),
+            bounds,
+            size: self.text_size,
+            font: self.font,
+            align_x: text::Alignment::Left,
+            align_y: alignment::Vertical::Center,
+            line_height: text::LineHeight::default(),
+            shaping: text::Shaping::Advanced,
+            wrapping: text::Wrapping::None,
+        }
+    }
+
+    fn measure<P: text::Paragraph<Font = iced::Font>>(&self, content: &str) -> Size {
+        P::with_text(iced::advanced::Text {
+            content,
+            bounds: Size::INFINITE,
+            size: self.text_size,
+            font: self.font,
+            align_x: text::Alignment::Left,
+            align_y: alignment::Vertical::Top,
+            line_height: text::LineHeight::default(),
+            shaping: text::Shaping::Advanced,
+            wrapping: text::Wrapping::None,
+        })
+        .min_bounds()
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    draft: String,
+    is_focused: bool,
+    /// Whether the suggestions were dismissed with Escape since the last edit
+    dismissed: bool,
+    highlighted: Option<usize>,
+    /// Chip bounds relative to the field, from the last layout
+    chips: Vec<Rectangle>,
+    /// Where the draft goes, relative to the field
+    draft_bounds: Rectangle,
+}
+
+impl State {
+    fn chip_at(&self, origin: Point, point: Point) -> Option<usize> {
+        let relative = Point::new(point.x - origin.x, point.y - origin.y);
+        self.chips.iter().position(|chip| chip.contains(relative))
+    }
+
+    fn close_at(&self, origin: Point, point: Point) -> Option<usize> {
+        let relative = Point::new(point.x - origin.x, point.y - origin.y);
+        self.chips.iter().position(|chip| close_bounds(*chip).contains(relative))
+    }
+}
+
+impl widget::operation::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
+/// The × button at the end of a chip.
+fn close_bounds(chip: Rectangle) -> Rectangle {
+    Rectangle {
+        x: chip.x + chip.width - CHIP_PADDING.right - CLOSE_SIZE,
+        y: chip.center_y() - CLOSE_SIZE / 2.0,
+        width: CLOSE_SIZE,
+        height: CLOSE_SIZE,
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for TagInput<'_, Message, Theme>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer<Font = iced::Font>,
+{
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(State::default())
+    }
+
+    fn layout(&mut self, tree: &mut Tree, _renderer: &Renderer, limits: &Limits) -> Node {
+        let state = tree.state.downcast_mut::<State>();
+        let line_height = self.measure::<Renderer::Paragraph>("Ag").height;
+        let chip_height = line_height + CHIP_PADDING.y();
+        let max_width = limits.max().width;
+        let inner_width = if max_width.is_finite() {
+            (max_width - self.padding.x()).max(MIN_DRAFT_WIDTH)
+        } else {
+            f32::INFINITY
+        };
+
+        // Chips flow left to right, wrapping to a new line when they do not fit
+        let (mut x, mut y) = (0.0_f32, 0.0_f32);
+        let mut widest = 0.0_f32;
+        state.chips.clear();
+
+        for tag in self.tags {
+            let width = self.measure::<Renderer::Paragraph>(tag).width + CHIP_PADDING.x() + CLOSE_SIZE;
+            let width = width.min(inner_width);
+
+            if x > 0.0 && x + width > inner_width {
+                x = 0.0;
+                y += chip_height + self.spacing;
+            }
+
+            state.chips.push(Rectangle {
+                x: self.padding.left + x,
+                y: self.padding.top + y,
+                width,
+                height: chip_height,
+            });
+            x += width + self.spacing;
+            widest = widest.max(x - self.spacing);
+        }
+
+        let draft_width = if self.tags.is_empty() {
+            self.measure::<Renderer::Paragraph>(&self.placeholder).width.max(MIN_DRAFT_WIDTH)
+        } else {
+            MIN_DRAFT_WIDTH
+        };
+
+        if x > 0.0 && x + draft_width > inner_width {
+            x = 0.0;
+            y += chip_height + self.spacing;
+        }
+
+        state.draft_bounds = Rectangle {
+            x: self.padding.left + x,
+            y: self.padding.top + y,
+            width: if inner_width.is_finite() { (inner_width - x).max(draft_width) } else { draft_width },
+            height: chip_height,
+        };
+        widest = widest.max(x + draft_width);
+
+        let intrinsic = Size::new(widest + self.padding.x(), y + chip_height + self.padding.y());
+
+        Node::new(limits.resolve(self.width, Length::Shrink, intrinsic))
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+        let origin = bounds.position();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    if state.is_focused {
+                        state.is_focused = false;
+                        shell.request_redraw();
+                    }
+                    return;
+                };
+
+                if let Some(index) = state.close_at(origin, position) {
+                    self.remove(index, shell);
+                }
+
+                state.is_focused = true;
+                state.dismissed = false;
+                shell.capture_event();
+                shell.request_redraw();
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                shell.request_redraw();
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key, modifiers, text, ..
+            }) if state.is_focused => {
+                let matching = self.matching(&state.draft);
+                let menu_open = !state.dismissed && !state.draft.is_empty() && !matching.is_empty();
+
+                match key {
+                    keyboard::Key::Named(keyboard::key::Named::Enter) => {
+                        let picked = state.highlighted.filter(|_| menu_open).and_then(|index| matching.get(index));
+                        let added = match picked {
+                            Some(suggestion) => self.add([*suggestion], shell),
+                            None => self.add([state.draft.as_str()], shell),
+                        };
+
+                        if added || !state.draft.trim().is_empty() {
+                            self.set_draft(state, String::new(), shell);
+                        }
+                    }
+                    keyboard::Key::Named(keyboard::key::Named::Backspace) => {
+                        if state.draft.is_empty() {
+                            if let Some(last) = self.tags.len().checked_sub(1) {
+                                self.remove(last, shell);
+                            }
+                        } else {
+                            let mut draft = state.draft.clone();
+                            draft.pop();
+                            self.set_draft(state, draft, shell);
+                        }
+                    }
+                    keyboard::Key::Named(keyboard::key::Named::ArrowDown) if menu_open => {
+                        state.highlighted = Some(state.highlighted.map_or(0, |index| (index + 1) % matching.len()));
+                    }
+                    keyboard::Key::Named(keyboard::key::Named::ArrowUp) if menu_open => {
+                        state.highlighted = Some(
+                            state
+                                .highlighted
+                                .map_or(matching.len() - 1, |index| (index + matching.len() - 1) % matching.len()),
+                        );
+                    }
+                    keyboard::Key::Named(keyboard::key::Named::Escape) => {
+                        if menu_open {
+                            state.dismissed = true;
+                        } else {
+                            state.is_focused = false;
+                        }
+                    }
+                    keyboard::Key::Character(c) if modifiers.command() && c.as_str() == "v" => {
+                        let Some(pasted) = clipboard.read(clipboard::Kind::Standard) else {
+                            return;
+                        };
+
+                        if pasted.contains([',', '\n']) {
+                            let draft = format!("{}{pasted}", state.draft);
+                            self.add(draft.split([',', '\n']), shell);
+                            self.set_draft(state, String::new(), shell);
+                        } else if !self.is_full() {
+                            self.set_draft(state, format!("{}{}", state.draft, pasted.trim_end()), shell);
+                        }
+                    }
+                    _ => {
+                        let Some(text) = text.as_ref().filter(|_| !modifiers.command()) else {
+                            return;
+                        };
+
+                        let mut draft = state.draft.clone();
+                        for c in text.chars().filter(|c| !c.is_control()) {
+                            if c == ',' {
+                                self.add([draft.as_str()], shell);
+                                draft.clear();
+                            } else if !(self.is_full() || draft.is_empty() && c.is_whitespace()) {
+                                draft.push(c);
+                            }
+                        }
+                        state.dismissed = false;
+                        self.set_draft(state, draft, shell);
+                    }
+                }
+
+                shell.capture_event();
+                shell.request_redraw();
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _defaults: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let origin = Vector::new(bounds.x, bounds.y);
+        let status = if state.is_focused {
+            Status::Focused
+        } else if cursor.is_over(bounds) {
+            Status::Hovered
+        } else {
+            Status::Active
+        };
+        let style = theme.style(&self.class, status);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: pixel::snap(bounds),
+                border: pixel::border(style.border),
+                ..renderer::Quad::default()
+            },
+            style.background,
+        );
+
+        let hovered_close = cursor.position().and_then(|position| state.close_at(bounds.position(), position));
+        let hovered_chip = cursor.position().and_then(|position| state.chip_at(bounds.position(), position));
+        let clip = bounds.intersection(viewport).unwrap_or(bounds);
+
+        for (index, (tag, chip)) in self.tags.iter().zip(&state.chips).enumerate() {
+            let chip = *chip + origin;
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: pixel::snap(chip),
+                    border: pixel::border(style.chip_border),
+                    ..renderer::Quad::default()
+                },
+                if hovered_chip == Some(index) { style.chip_hovered_background } else { style.chip_background },
+            );
+
+            let label = Rectangle {
+                width: (chip.width - CHIP_PADDING.x() - CLOSE_SIZE).max(0.0),
+                ..chip
+            };
+            renderer.fill_text(
+                self.text(tag.clone(), label.size()),
+                Point::new(chip.x + CHIP_PADDING.left, chip.center_y()),
+                style.chip_text,
+                Rectangle {
+                    x: chip.x + CHIP_PADDING.left,
+                    ..label
+                }
+                .intersection(&clip)
+                .unwrap_or(label),
+            );
+
+            let close = close_bounds(chip);
+            if hovered_close == Some(index) {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: pixel::snap(close),
+                        border: pixel::border(Border {
+                            radius: (CLOSE_SIZE / 2.0).into(),
+                            ..Border::default()
+                        }),
+                        ..renderer::Quad::default()
+                    },
+                    style.close_hovered_background,
+                );
+            }
+            renderer.fill_text(
+                iced::advanced::Text {
+                    align_x: text::Alignment::Center,
+                    ..self.text("×", close.size())
+                },
+                close.center(),
+                style.chip_text,
+                clip,
+            );
+        }
+
+        let draft = state.draft_bounds + origin;
+        if state.draft.is_empty() && self.tags.is_empty() {
+            renderer.fill_text(
+                self.text(self.placeholder.clone(), draft.size()),
+                Point::new(draft.x, draft.center_y()),
+                style.placeholder,
+                draft.intersection(&clip).unwrap_or(draft),
+            );
+        } else {
+            renderer.fill_text(
+                self.text(state.draft.clone(), draft.size()),
+                Point::new(draft.x, draft.center_y()),
+                style.text,
+                draft.intersection(&clip).unwrap_or(draft),
+            );
+        }
+
+        if state.is_focused {
+            let width = self.measure::<Renderer::Paragraph>(&state.draft).width.min(draft.width);
+            let line_height = draft.height - CHIP_PADDING.y();
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: pixel::snap(Rectangle {
+                        x: draft.x + width,
+                        y: draft.center_y() - line_height / 2.0,
+                        width: CARET_WIDTH,
+                        height: line_height,
+                    }),
+                    ..renderer::Quad::default()
+                },
+                style.caret,
+            );
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+
+        match cursor.position_over(bounds) {
+            Some(position) if state.close_at(bounds.position(), position).is_some() => mouse::Interaction::Pointer,
+            Some(_) => mouse::Interaction::Text,
+            None => mouse::Interaction::None,
+        }
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn widget::Operation,
+    ) {
+        operation.focusable(None, layout.bounds(), tree.state.downcast_mut::<State>());
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        _renderer: &Renderer,
+        _viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_mut::<State>();
+
+        if !state.is_focused || state.dismissed || state.draft.is_empty() || self.is_full() {
+            return None;
+        }
+
+        let matching: Vec<String> = self.matching(&state.draft).into_iter().map(String::from).collect();
+        if matching.is_empty() {
+            return None;
+        }
+
+        Some(overlay::Element::new(Box::new(Suggestions {
+            input: self,
+            state,
+            matching,
+            anchor: layout.bounds() + translation,
+        })))
+    }
+}
+
+/// The dropdown of suggestions matching the draft.
+struct Suggestions<'a, 'b, Message, Theme>
+where
+    Theme: Catalog,
+{
+    input: &'a TagInput<'b, Message, Theme>,
+    state: &'a mut State,
+    matching: Vec<String>,
+    anchor: Rectangle,
+}
+
+impl<Message, Theme> Suggestions<'_, '_, Message, Theme>
+where
+    Theme: Catalog,
+{
+    fn item_at(&self, bounds: Rectangle, point: Point) -> Option<usize> {
+        if !bounds.contains(point) {
+            return None;
+        }
+
+        let index = ((point.y - bounds.y - MENU_PADDING) / MENU_ITEM_HEIGHT).floor();
+        (index >= 0.0 && (index as usize) < self.matching.len()).then_some(index as usize)
+    }
+}
+
+impl<Message, Theme, Renderer> Overlay<Message, Theme, Renderer> for Suggestions<'_, '_, Message, Theme>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer<Font = iced::Font>,
+{
+    fn layout(&mut self, _renderer: &Renderer, bounds: Size) -> Node {
+        let width = self.anchor.width.min(bounds.width);
+        let height = (self.matching.len() as f32 * MENU_ITEM_HEIGHT + MENU_PADDING * 2.0).min(bounds.height);
+
+        // Below the field, flipping above it when there is no room
+        let below = self.anchor.y + self.anchor.height + MENU_OFFSET;
+        let y = if below + height > bounds.height {
+            (self.anchor.y - MENU_OFFSET - height).max(0.0)
+        } else {
+            below
+        };
+        let x = self.anchor.x.clamp(0.0, (bounds.width - width).max(0.0));
+
+        Node::new(Size::new(width, height)).move_to(Point::new(x, y))
+    }
+
+    fn update(
+        &mut self,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                if let Some(index) = self.item_at(bounds, *position)
+                    && self.state.highlighted != Some(index)
+                {
+                    self.state.highlighted = Some(index);
+                    shell.request_redraw();
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let Some(index) = cursor.position().and_then(|position| self.item_at(bounds, position)) else {
+                    return;
+                };
+
+                // Keep focus in the field so typing can go on
+                self.input.add([self.matching[index].as_str()], shell);
+                self.input.set_draft(self.state, String::new(), shell);
+                shell.capture_event();
+                shell.request_redraw();
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _defaults: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+    ) {
+        let style = theme.style(&self.input.class, Status::Focused);
+        let bounds = layout.bounds();
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: pixel::snap(bounds),
+                border: pixel::border(style.menu_border),
+                shadow: style.menu_shadow,
+                snap: true,
+            },
+            style.menu_background,
+        );
+
+        renderer.with_layer(bounds, |renderer| {
+            for (index, suggestion) in self.matching.iter().enumerate() {
+                let item = Rectangle {
+                    x: bounds.x + MENU_PADDING,
+                    y: bounds.y + MENU_PADDING + index as f32 * MENU_ITEM_HEIGHT,
+                    width: bounds.width - MENU_PADDING * 2.0,
+                    height: MENU_ITEM_HEIGHT,
+                };
+
+                if self.state.highlighted == Some(index) {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: pixel::snap(item),
+                            border: pixel::border(Border {
+                                radius: style.menu_border.radius,
+                                ..Border::default()
+                            }),
+                            ..renderer::Quad::default()
+                        },
+                        style.menu_highlighted_background,
+                    );
+                }
+
+                renderer.fill_text(
+                    self.input.text(suggestion.clone(), item.size()),
+                    Point::new(item.x + 8.0, item.center_y()),
+                    style.text,
+                    item,
+                );
+            }
+        });
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        match cursor.position().and_then(|position| self.item_at(layout.bounds(), position)) {
+            Some(_) => mouse::Interaction::Pointer,
+            None => mouse::Interaction::None,
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<TagInput<'a, Message, Theme>> for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer<Font = iced::Font> + 'a,
+{
+    fn from(input: TagInput<'a, Message, Theme>) -> Self {
+        Element::new(input)
+    }
+}
+
+/// The state of a [`TagInput`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Active,
+    Hovered,
+    Focused,
+}
+
+/// The theme catalog of a [`TagInput`].
+pub trait Catalog {
+    /// The style class
+    type Class<'a>;
+
+    /// Default style
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// Get the style for a class and status
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+/// The appearance of a [`TagInput`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// Background of the field
+    pub background: Background,
+    /// Border of the field
+    pub border: Border,
+    /// Color of the draft text and suggestions
+    pub text: Color,
+    /// Color of the placeholder
+    pub placeholder: Color,
+    /// Color of the caret
+    pub caret: Color,
+    /// Background of a chip
+    pub chip_background: Background,
+    /// Background of the hovered chip
+    pub chip_hovered_background: Background,
+    /// Border of a chip
+    pub chip_border: Border,
+    /// Label and × color of a chip
+    pub chip_text: Color,
+    /// Background behind the hovered × button
+    pub close_hovered_background: Background,
+    /// Background of the suggestion dropdown
+    pub menu_background: Background,
+    /// Border of the suggestion dropdown
+    pub menu_border: Border,
+    /// Shadow of the suggestion dropdown
+    pub menu_shadow: Shadow,
+    /// Background of the highlighted suggestion
+    pub menu_highlighted_background: Background,
+}
+
+/// Styling function
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for iced::Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default tag input style.
+pub fn default(theme: &iced::Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: palette.background.base.color.into(),
+        border: Border {
+            color: match status {
+                Status::Active => palette.background.strong.color,
+                Status::Hovered => palette.background.base.text,
+                Status::Focused => palette.primary.strong.color,
+            },
+            width: 1.0,
+            radius: 4.0.into(),
+        },
+        text: palette.background.base.text,
+        placeholder: palette.background.strong.color,
+        caret: palette.background.base.text,
+        chip_background: palette.background.weak.color.into(),
+        chip_hovered_background: palette.background.strong.color.into(),
+        chip_border: Border {
+            radius: 4.0.into(),
+            ..Border::default()
+        },
+        chip_text: palette.background.weak.text,
+        close_hovered_background: palette.danger.weak.color.into(),
+        menu_background: palette.background.base.color.into(),
+        menu_border: Border {
+            color: palette.background.strong.color,
+            width: 1.0,
+            radius: 4.0.into(),
+        },
+        menu_shadow: Shadow {
+            color: Color::BLACK.scale_alpha(0.3),
+            offset: Vector::new(0.0, 2.0),
+            blur_radius: 8.0,
+        },
+        menu_highlighted_background: palette.primary.weak.color.into(),
+    }
+}
+
+/// Rounded, primary colored chips.
+pub fn pills(theme: &iced::Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+    let chip_radius = 12.0;
+
+    Style {
+        chip_background: palette.primary.base.color.into(),
+        chip_hovered_background: palette.primary.strong.color.into(),
+        chip_border: Border {
+            radius: chip_radius.into(),
+            ..Border::default()
+        },
+        chip_text: palette.primary.base.text,
+        close_hovered_background: palette.primary.weak.color.into(),
+        ..default(theme, status)
+    }
+}
@@ -0,0 +1,384 @@
+//! Off-screen harness for driving widgets in tests.
+//!
+//! A [`Simulator`] mounts an element without a window, feeds it synthetic mouse, keyboard and
+//! touch events and collects the messages it produces. Nothing is drawn: it runs on the null
+//! renderer `()` by default, which measures text as empty, so assertions should be about
+//! messages and widget state rather than geometry that depends on text. Widgets that only work
+//! with the iced renderer can be mounted with [`Simulator::with_renderer`] and [`renderer`].
+//!
+//! Time only moves when told to: [`Simulator::advance`] sends a redraw at the simulated
+//! instant, which is what animated widgets step their animations on.
+//!
+//! ```ignore
+//! let mut ui = Simulator::new(rating(2.0).on_change(Message::Rated), Size::new(200.0, 40.0));
+//!
+//! ui.click(Point::new(100.0, 12.0));
+//! assert_eq!(ui.into_messages(), vec![Message::Rated(4.0)]);
+//! ```
+//!
+//! The element borrows the app state, so after handling the messages build a new simulator
+//! from a fresh view, passing the [`Simulator::into_cache`] of the old one along to keep the
+//! widget states.
+
+use std::task::{Context, Poll, Waker};
+
+use iced::{
+    advanced::{
+        clipboard, mouse, renderer,
+        widget::{self, Operation},
+    },
+    keyboard,
+    time::{Duration, Instant},
+    touch, window, Element, Event, Font, Pixels, Point, Size, Vector,
+};
+use iced_runtime::user_interface::{self, UserInterface};
+
+pub use iced_runtime::user_interface::Cache;
+
+/// Time between the frames sent by [`Simulator::advance`].
+const FRAME: Duration = Duration::from_millis(16);
+
+/// Creates a headless iced renderer, drawing with the CPU.
+///
+/// Returns `None` if the renderer cannot be created.
+pub fn renderer() -> Option<iced::Renderer> {
+    use iced::advanced::renderer::Headless;
+
+    let mut future = std::pin::pin!(iced::Renderer::new(Font::default(), Pixels(16.0), Some("tiny-skia")));
+    let mut context = Context::from_waker(Waker::noop());
+
+    // The software renderer is created right away, without waiting on a device
+    match future.as_mut().poll(&mut context) {
+        Poll::Ready(renderer) => renderer,
+        Poll::Pending => None,
+    }
+}
+
+/// A clipboard kept in memory.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Clipboard {
+    pub standard: Option<String>,
+    pub primary: Option<String>,
+}
+
+impl clipboard::Clipboard for Clipboard {
+    fn read(&self, kind: clipboard::Kind) -> Option<String> {
+        match kind {
+            clipboard::Kind::Standard => self.standard.clone(),
+            clipboard::Kind::Primary => self.primary.clone(),
+        }
+    }
+
+    fn write(&mut self, kind: clipboard::Kind, contents: String) {
+        match kind {
+            clipboard::Kind::Standard => self.standard = Some(contents),
+            clipboard::Kind::Primary => self.primary = Some(contents),
+        }
+    }
+}
+
+/// A widget mounted off-screen.
+#[allow(missing_debug_implementations)]
+pub struct Simulator<'a, Message, Theme = iced::Theme, Renderer = ()> {
+    ui: Option<UserInterface<'a, Message, Theme, Renderer>>,
+    renderer: Renderer,
+    size: Size,
+    cursor: mouse::Cursor,
+    modifiers: keyboard::Modifiers,
+    clipboard: Clipboard,
+    messages: Vec<Message>,
+    now: Instant,
+    interaction: mouse::Interaction,
+    redraw: window::RedrawRequest,
+}
+
+impl<'a, Message> Simulator<'a, Message, iced::Theme, ()> {
+    /// Mounts `element` in a window of `size`, with the built-in theme and the null renderer.
+    pub fn new(element: impl Into<Element<'a, Message, iced::Theme, ()>>, size: Size) -> Self {
+        Self::with_renderer(element, size, ())
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Simulator<'a, Message, Theme, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    /// Mounts `element` in a window of `size`, on the given renderer.
+    pub fn with_renderer(
+        element: impl Into<Element<'a, Message, Theme, Renderer>>,
+        size: Size,
+        renderer: Renderer,
+    ) -> Self {
+        Self::with_cache(element, size, renderer, Cache::new())
+    }
+
+    /// Mounts `element` with the widget states of an earlier simulator, see
+    /// [`Simulator::into_cache`].
+    pub fn with_cache(
+        element: impl Into<Element<'a, Message, Theme, Renderer>>,
+        size: Size,
+        mut renderer: Renderer,
+        cache: Cache,
+    ) -> Self {
+        let ui = UserInterface::build(element, size, cache, &mut renderer);
+
+        Self {
+            ui: Some(ui),
+            renderer,
+            size,
+            cursor: mouse::Cursor::Unavailable,
+            modifiers: keyboard::Modifiers::default(),
+            clipboard: Clipboard::default(),
+            messages: Vec::new(),
+            now: Instant::now(),
+            interaction: mouse::Interaction::None,
+            redraw: window::RedrawRequest::Wait,
+        }
+    }
+
+    /// Sends `event` to the widget.
+    pub fn event(&mut self, event: Event) {
+        self.events([event]);
+    }
+
+    /// Sends several events to the widget, in a single update like a window would batch them.
+    pub fn events(&mut self, events: impl IntoIterator<Item = Event>) {
+        let events: Vec<Event> = events.into_iter().collect();
+        let Some(ui) = self.ui.as_mut() else {
+            return;
+        };
+
+        let (state, _) = ui.update(&events, self.cursor, &mut self.renderer, &mut self.clipboard, &mut self.messages);
+
+        match state {
+            user_interface::State::Updated {
+                mouse_interaction,
+                redraw_request,
+                ..
+            } => {
+                self.interaction = mouse_interaction;
+                self.redraw = redraw_request;
+            }
+            user_interface::State::Outdated => {
+                // Rebuild so the widget states follow the new widget tree
+                if let Some(ui) = self.ui.take() {
+                    self.ui = Some(ui.relayout(self.size, &mut self.renderer));
+                }
+            }
+        }
+    }
+
+    /// Moves the cursor to `position`.
+    pub fn move_to(&mut self, position: Point) {
+        self.cursor = mouse::Cursor::Available(position);
+        self.event(Event::Mouse(mouse::Event::CursorMoved { position }));
+    }
+
+    /// Moves the cursor by `offset` in `steps` equal moves, as a drag would.
+    pub fn move_by(&mut self, offset: Vector, steps: usize) {
+        let start = self.cursor.position().unwrap_or_default();
+        let steps = steps.max(1);
+
+        for step in 1..=steps {
+            self.move_to(start + offset * (step as f32 / steps as f32));
+        }
+    }
+
+    /// Moves the cursor out of the window.
+    pub fn leave(&mut self) {
+        self.cursor = mouse::Cursor::Unavailable;
+        self.event(Event::Mouse(mouse::Event::CursorLeft));
+    }
+
+    /// Presses `button` where the cursor is.
+    pub fn press(&mut self, button: mouse::Button) {
+        self.event(Event::Mouse(mouse::Event::ButtonPressed(button)));
+    }
+
+    /// Releases `button` where the cursor is.
+    pub fn release(&mut self, button: mouse::Button) {
+        self.event(Event::Mouse(mouse::Event::ButtonReleased(button)));
+    }
+
+    /// Moves to `position` and clicks the left button there.
+    pub fn click(&mut self, position: Point) {
+        self.move_to(position);
+        self.press(mouse::Button::Left);
+        self.release(mouse::Button::Left);
+    }
+
+    /// Presses the left button at `from`, moves to `to` in a few steps and releases it there.
+    pub fn drag(&mut self, from: Point, to: Point) {
+        self.move_to(from);
+        self.press(mouse::Button::Left);
+        self.move_by(to - from, 8);
+        self.release(mouse::Button::Left);
+    }
+
+    /// Scrolls the wheel by `delta` lines where the cursor is.
+    pub fn scroll(&mut self, delta: Vector) {
+        self.event(Event::Mouse(mouse::Event::WheelScrolled {
+            delta: mouse::ScrollDelta::Lines { x: delta.x, y: delta.y },
+        }));
+    }
+
+    /// Touches the screen at `position` with `finger`.
+    pub fn finger_press(&mut self, finger: u64, position: Point) {
+        self.event(Event::Touch(touch::Event::FingerPressed {
+            id: touch::Finger(finger),
+            position,
+        }));
+    }
+
+    /// Moves `finger` to `position`.
+    pub fn finger_move(&mut self, finger: u64, position: Point) {
+        self.event(Event::Touch(touch::Event::FingerMoved {
+            id: touch::Finger(finger),
+            position,
+        }));
+    }
+
+    /// Lifts `finger` at `position`.
+    pub fn finger_lift(&mut self, finger: u64, position: Point) {
+        self.event(Event::Touch(touch::Event::FingerLifted {
+            id: touch::Finger(finger),
+            position,
+        }));
+    }
+
+    /// Sets the modifiers held for the keys that follow.
+    pub fn modifiers(&mut self, modifiers: keyboard::Modifiers) {
+        self.modifiers = modifiers;
+        self.event(Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)));
+    }
+
+    /// Presses and releases `key` with the current modifiers.
+    pub fn tap(&mut self, key: impl Into<keyboard::Key>) {
+        let key = key.into();
+        let text = match &key {
+            keyboard::Key::Character(c) if !self.modifiers.command() => Some(c.clone()),
+            keyboard::Key::Named(keyboard::key::Named::Space) => Some(" ".into()),
+            keyboard::Key::Named(keyboard::key::Named::Enter) => Some("\r".into()),
+            _ => None,
+        };
+        let physical_key = keyboard::key::Physical::Unidentified(keyboard::key::NativeCode::Unidentified);
+
+        self.events([
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: key.clone(),
+                modified_key: key.clone(),
+                physical_key,
+                location: keyboard::Location::Standard,
+                modifiers: self.modifiers,
+                text,
+                repeat: false,
+            }),
+            Event::Keyboard(keyboard::Event::KeyReleased {
+                key: key.clone(),
+                modified_key: key,
+                physical_key,
+                location: keyboard::Location::Standard,
+                modifiers: self.modifiers,
+            }),
+        ]);
+    }
+
+    /// Types `text`, one character key at a time.
+    pub fn type_text(&mut self, text: &str) {
+        for c in text.chars() {
+            self.tap(keyboard::Key::Character(c.to_string().into()));
+        }
+    }
+
+    /// Moves the simulated clock forward by `duration`, sending a redraw every frame on the way.
+    pub fn advance(&mut self, duration: Duration) {
+        let end = self.now + duration;
+
+        while self.now < end {
+            self.now = (self.now + FRAME).min(end);
+            self.event(Event::Window(window::Event::RedrawRequested(self.now)));
+        }
+    }
+
+    /// Sends a single redraw at the current simulated instant.
+    pub fn redraw(&mut self) {
+        self.event(Event::Window(window::Event::RedrawRequested(self.now)));
+    }
+
+    /// The simulated instant.
+    pub fn now(&self) -> Instant {
+        self.now
+    }
+
+    /// Where the cursor is.
+    pub fn cursor(&self) -> mouse::Cursor {
+        self.cursor
+    }
+
+    /// The mouse interaction the widget asked for after the last event.
+    pub fn mouse_interaction(&self) -> mouse::Interaction {
+        self.interaction
+    }
+
+    /// Whether the widget asked to be redrawn, e.g. to keep an animation going.
+    pub fn redraw_requested(&self) -> bool {
+        !matches!(self.redraw, window::RedrawRequest::Wait)
+    }
+
+    /// The clipboard the widget reads from and writes to.
+    pub fn clipboard(&mut self) -> &mut Clipboard {
+        &mut self.clipboard
+    }
+
+    /// The messages produced so far.
+    pub fn messages(&self) -> &[Message] {
+        &self.messages
+    }
+
+    /// Takes the messages produced so far.
+    pub fn take_messages(&mut self) -> Vec<Message> {
+        std::mem::take(&mut self.messages)
+    }
+
+    /// Consumes the simulator, returning the messages produced.
+    pub fn into_messages(self) -> Vec<Message> {
+        self.messages
+    }
+
+    /// Runs `operation` over the widget tree, e.g. to find the focused widget.
+    pub fn operate(&mut self, operation: &mut dyn Operation) {
+        if let Some(ui) = self.ui.as_mut() {
+            ui.operate(&self.renderer, operation);
+        }
+    }
+
+    /// Returns whether the widget with `id` has the focus.
+    pub fn is_focused(&mut self, id: widget::Id) -> bool {
+        let mut operation = widget::operation::focusable::find_focused();
+        self.operate(&mut widget::operation::black_box(&mut operation));
+
+        matches!(operation.finish(), widget::operation::Outcome::Some(focused) if focused == id)
+    }
+
+    /// Draws the widget, which some widgets need before they measure themselves.
+    pub fn draw(&mut self, theme: &Theme) {
+        let cursor = self.cursor;
+
+        if let Some(ui) = self.ui.as_mut() {
+            ui.draw(
+                &mut self.renderer,
+                theme,
+                &renderer::Style {
+                    text_color: iced::Color::BLACK,
+                },
+                cursor,
+            );
+        }
+    }
+
+    /// Consumes the simulator, returning the widget states so they can be carried over to a
+    /// simulator of the next view with [`Simulator::with_cache`].
+    pub fn into_cache(mut self) -> Cache {
+        self.ui.take().map(UserInterface::into_cache).unwrap_or_default()
+    }
+}
@@ -0,0 +1,169 @@
+//! Theming shared by the widgets.
+//!
+//! Every widget keeps its own `Catalog` and `Style`, but the styles of the tree, overlays,
+//! collapsibles and the date range picker are all built from one [`Palette`] derived from the
+//! iced theme, so their colors, corners and shadows match when used side by side.
+//!
+//! A [`Preset`] changes that palette the same way for every widget. Each of these modules has
+//! a style function per preset, `default`, `compact`, `flat` and `high_contrast`, to pass to the
+//! widget's `style` method:
+//!
+//! ```ignore
+//! tree_handle(branches).style(tree::flat)
+//! collapsible("Details", content).style(collapsible::flat)
+//! ```
+
+use iced::{Color, Shadow, Vector};
+
+/// A ready-made look shared by the widgets.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Preset {
+    /// The colors of the iced theme with rounded corners and soft shadows
+    #[default]
+    Standard,
+    /// Tighter corners and smaller shadows, for dense layouts
+    Compact,
+    /// A single surface color, square corners and no shadows
+    Flat,
+    /// Black and white surfaces with strong borders
+    HighContrast,
+}
+
+impl Preset {
+    /// Every preset, in the order they are listed.
+    pub const ALL: [Self; 4] = [Self::Standard, Self::Compact, Self::Flat, Self::HighContrast];
+}
+
+impl std::fmt::Display for Preset {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Standard => "Standard",
+            Self::Compact => "Compact",
+            Self::Flat => "Flat",
+            Self::HighContrast => "High contrast",
+        })
+    }
+}
+
+/// The colors and metrics the widget styles are built from.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Palette {
+    /// Background of the widgets
+    pub background: Color,
+    /// Text on the background
+    pub text: Color,
+    /// Slightly raised surfaces, such as overlay headers
+    pub raised: Color,
+    /// Recessed surfaces, such as the content of a collapsible
+    pub inset: Color,
+    /// Text on recessed surfaces
+    pub inset_text: Color,
+    /// Prominent surfaces, such as collapsible headers
+    pub strong: Color,
+    /// Text on prominent surfaces
+    pub strong_text: Color,
+    /// Borders, separators and secondary text like placeholders
+    pub border: Color,
+    /// Focus rings, drop indicators and chosen values
+    pub accent: Color,
+    /// Text on the accent
+    pub accent_text: Color,
+    /// Faint accent, such as guide lines and selected ranges
+    pub accent_weak: Color,
+    /// Outline of selected entries
+    pub selection: Color,
+    /// Overlay drawn over hovered and selected entries
+    pub highlight: Color,
+    /// Drop refusals and errors
+    pub danger: Color,
+    /// Corner radius of fields and containers
+    pub radius: f32,
+    /// Width of the borders of fields and popups
+    pub border_width: f32,
+    /// Width of the outline of containers told apart by their background otherwise
+    pub outline_width: f32,
+    /// Shadow of popups and overlays
+    pub shadow: Shadow,
+}
+
+impl Palette {
+    /// Derives the palette of `preset` from `theme`.
+    pub fn new(theme: &iced::Theme, preset: Preset) -> Self {
+        let palette = theme.extended_palette();
+
+        let standard = Self {
+            background: palette.background.base.color,
+            text: palette.background.base.text,
+            raised: palette.background.weak.color,
+            inset: palette.background.weakest.color,
+            inset_text: palette.background.weakest.text,
+            strong: palette.background.strong.color,
+            strong_text: palette.background.strong.text,
+            border: palette.background.strong.color,
+            accent: palette.primary.strong.color,
+            accent_text: palette.primary.strong.text,
+            accent_weak: palette.primary.weak.color,
+            selection: palette.secondary.base.color,
+            highlight: Color::from_rgba(0.0, 0.0, 0.0, 0.1),
+            danger: palette.danger.strong.color,
+            radius: 4.0,
+            border_width: 1.0,
+            outline_width: 0.0,
+            shadow: Shadow {
+                color: Color::from_rgba(0.0, 0.0, 0.0, 0.3),
+                offset: Vector::new(0.0, 4.0),
+                blur_radius: 16.0,
+            },
+        };
+
+        match preset {
+            Preset::Standard => standard,
+            Preset::Compact => Self {
+                radius: 2.0,
+                shadow: Shadow {
+                    offset: Vector::new(0.0, 2.0),
+                    blur_radius: 6.0,
+                    ..standard.shadow
+                },
+                ..standard
+            },
+            Preset::Flat => Self {
+                raised: standard.background,
+                inset: standard.background,
+                inset_text: standard.text,
+                strong: standard.background,
+                strong_text: standard.text,
+                radius: 0.0,
+                outline_width: 1.0,
+                shadow: Shadow::default(),
+                ..standard
+            },
+            Preset::HighContrast => {
+                let (background, text) = if palette.is_dark {
+                    (Color::BLACK, Color::WHITE)
+                } else {
+                    (Color::WHITE, Color::BLACK)
+                };
+
+                Self {
+                    background,
+                    text,
+                    raised: background,
+                    inset: background,
+                    inset_text: text,
+                    strong: background,
+                    strong_text: text,
+                    border: text,
+                    accent_weak: standard.accent.scale_alpha(0.5),
+                    selection: text,
+                    highlight: text.scale_alpha(0.2),
+                    border_width: 2.0,
+                    outline_width: 2.0,
+                    shadow: Shadow::default(),
+                    ..standard
+                }
+            }
+        }
+    }
+}
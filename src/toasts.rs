@@ -0,0 +1,1018 @@
+//! Toast notifications stacked in a corner over the app.
+//!
+//! The app stores a [`Toasts`] manager in its state, pushes notifications into it and wraps
+//! its view with [`toast_layer`]. The layer draws the toasts in an overlay, counts down their
+//! timers (paused while hovered) and reports dismissals and action clicks as messages, so the
+//! app removes them from the manager in `update`.
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        mouse, overlay, renderer, text,
+        widget::{self, tree::Tree},
+        Clipboard, Layout, Overlay, Shell, Widget,
+    },
+    alignment, window, Background, Border, Color, Element, Event, Length, Pixels, Point,
+    Rectangle, Shadow, Size, Vector,
+};
+use iced::time::{Duration, Instant};
+use std::collections::HashMap;
+
+use crate::pixel;
+
+const DEFAULT_WIDTH: f32 = 320.0;
+const DEFAULT_DURATION: Duration = Duration::from_secs(5);
+const TOAST_PADDING: f32 = 12.0;
+const ACCENT_WIDTH: f32 = 4.0;
+const CLOSE_SIZE: f32 = 16.0;
+const ACTION_HEIGHT: f32 = 26.0;
+const ACTION_PADDING: f32 = 10.0;
+const ACTION_SPACING: f32 = 6.0;
+const LINE_SPACING: f32 = 4.0;
+
+/// Identifies a toast inside a [`Toasts`] manager.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Id(u64);
+
+/// How important a toast is, which picks its colors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Severity {
+    #[default]
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+/// The corner the toasts are stacked in. The newest toast sits closest to the corner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Corner {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    #[default]
+    BottomRight,
+}
+
+/// A notification shown by a [`ToastLayer`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Toast {
+    id: Id,
+    title: String,
+    body: Option<String>,
+    severity: Severity,
+    duration: Option<Duration>,
+    actions: Vec<String>,
+}
+
+impl Toast {
+    /// Creates a new informational [`Toast`] that dismisses itself after five seconds.
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            id: Id(0),
+            title: title.into(),
+            body: None,
+            severity: Severity::default(),
+            duration: Some(DEFAULT_DURATION),
+            actions: Vec::new(),
+        }
+    }
+
+    /// Sets the text shown below the title.
+    pub fn body(mut self, body: impl Into<String>) -> Self {
+        self.body = Some(body.into());
+        self
+    }
+
+    /// Sets the severity.
+    pub fn severity(mut self, severity: Severity) -> Self {
+        self.severity = severity;
+        self
+    }
+
+    /// Sets how long the toast stays up before it is dismissed.
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = Some(duration);
+        self
+    }
+
+    /// Keeps the toast up until it is closed or dismissed by the app.
+    pub fn persistent(mut self) -> Self {
+        self.duration = None;
+        self
+    }
+
+    /// Adds an action button, reported by index through [`ToastLayer::on_action`].
+    pub fn action(mut self, label: impl Into<String>) -> Self {
+        self.actions.push(label.into());
+        self
+    }
+
+    /// The id assigned when the toast was pushed.
+    pub fn id(&self) -> Id {
+        self.id
+    }
+
+    /// The title of the toast.
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+}
+
+/// Creates a new informational [`Toast`].
+pub fn info(title: impl Into<String>) -> Toast {
+    Toast::new(title)
+}
+
+/// Creates a new success [`Toast`].
+pub fn success(title: impl Into<String>) -> Toast {
+    Toast::new(title).severity(Severity::Success)
+}
+
+/// Creates a new warning [`Toast`].
+pub fn warning(title: impl Into<String>) -> Toast {
+    Toast::new(title).severity(Severity::Warning)
+}
+
+/// Creates a new error [`Toast`], which stays up until closed.
+pub fn error(title: impl Into<String>) -> Toast {
+    Toast::new(title).severity(Severity::Error).persistent()
+}
+
+/// The notifications currently shown, kept in the app state.
+#[derive(Debug, Clone, Default)]
+pub struct Toasts {
+    toasts: Vec<Toast>,
+    next_id: u64,
+    max_visible: Option<usize>,
+}
+
+impl Toasts {
+    /// Creates an empty manager.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Limits how many toasts are shown at once; older ones wait until newer ones go away.
+    pub fn max_visible(mut self, count: usize) -> Self {
+        self.max_visible = Some(count);
+        self
+    }
+
+    /// Shows a toast, returning its id.
+    pub fn push(&mut self, mut toast: Toast) -> Id {
+        let id = Id(self.next_id);
+        self.next_id += 1;
+        toast.id = id;
+        self.toasts.push(toast);
+        id
+    }
+
+    /// Removes a toast, returning it if it was still shown.
+    pub fn dismiss(&mut self, id: Id) -> Option<Toast> {
+        let index = self.toasts.iter().position(|toast| toast.id == id)?;
+        Some(self.toasts.remove(index))
+    }
+
+    /// Removes every toast.
+    pub fn clear(&mut self) {
+        self.toasts.clear();
+    }
+
+    /// Returns the toast with the given id.
+    pub fn get(&self, id: Id) -> Option<&Toast> {
+        self.toasts.iter().find(|toast| toast.id == id)
+    }
+
+    /// Iterates over the toasts, oldest first.
+    pub fn iter(&self) -> impl Iterator<Item = &Toast> {
+        self.toasts.iter()
+    }
+
+    /// The number of toasts.
+    pub fn len(&self) -> usize {
+        self.toasts.len()
+    }
+
+    /// Whether there are no toasts.
+    pub fn is_empty(&self) -> bool {
+        self.toasts.is_empty()
+    }
+
+    /// The toasts on screen, newest first.
+    fn visible(&self) -> impl Iterator<Item = &Toast> {
+        let count = self.max_visible.unwrap_or(usize::MAX);
+        self.toasts.iter().rev().take(count)
+    }
+}
+
+/// Wraps the content of an app and shows the toasts of a [`Toasts`] manager over it.
+pub fn toast_layer<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    toasts: &'a Toasts,
+) -> ToastLayer<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer<Font = iced::Font>,
+{
+    ToastLayer::new(content, toasts)
+}
+
+/// A container drawing toast notifications over its content.
+#[allow(missing_debug_implementations)]
+pub struct ToastLayer<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Theme: Catalog,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    toasts: &'a Toasts,
+    corner: Corner,
+    width: f32,
+    spacing: f32,
+    margin: f32,
+    text_size: Pixels,
+    font: iced::Font,
+    pause_on_hover: bool,
+    on_dismiss: Option<Box<dyn Fn(Id) -> Message + 'a>>,
+    on_action: Option<Box<dyn Fn(Id, usize) -> Message + 'a>>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> ToastLayer<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer<Font = iced::Font>,
+{
+    /// Creates a new [`ToastLayer`] over the given content.
+    pub fn new(content: impl Into<Element<'a, Message, Theme, Renderer>>, toasts: &'a Toasts) -> Self {
+        Self {
+            content: content.into(),
+            toasts,
+            corner: Corner::default(),
+            width: DEFAULT_WIDTH,
+            spacing: 8.0,
+            margin: 16.0,
+            text_size: Pixels(14.0),
+            font: iced::Font::default(),
+            pause_on_hover: true,
+            on_dismiss: None,
+            on_action: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the corner the toasts are stacked in.
+    pub fn corner(mut self, corner: Corner) -> Self {
+        self.corner = corner;
+        self
+    }
+
+    /// Sets the width of every toast.
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the gap between toasts.
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the distance between the stack and the edges of the content.
+    pub fn margin(mut self, margin: f32) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    /// Sets the text size of the toasts; titles use the same size in bold.
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = size.into();
+        self
+    }
+
+    /// Sets the font of the toasts.
+    pub fn font(mut self, font: iced::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets whether hovering a toast pauses its timer. Enabled by default.
+    pub fn pause_on_hover(mut self, pause: bool) -> Self {
+        self.pause_on_hover = pause;
+        self
+    }
+
+    /// Sets the message produced when a toast is closed or its timer runs out.
+    ///
+    /// Without it toasts show no close button and never time out.
+    pub fn on_dismiss(mut self, callback: impl Fn(Id) -> Message + 'a) -> Self {
+        self.on_dismiss = Some(Box::new(callback));
+        self
+    }
+
+    /// Sets the message produced with the toast id and action index when an action is clicked.
+    pub fn on_action(mut self, callback: impl Fn(Id, usize) -> Message + 'a) -> Self {
+        self.on_action = Some(Box::new(callback));
+        self
+    }
+
+    /// Sets the style.
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Severity) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class.
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+/// The layout settings of a [`ToastLayer`], copied into its overlay.
+#[derive(Debug, Clone, Copy)]
+struct Placement {
+    corner: Corner,
+    width: f32,
+    spacing: f32,
+    margin: f32,
+    text_size: Pixels,
+    font: iced::Font,
+}
+
+impl Placement {
+    fn text(&self, content: &str, width: f32, bold: bool) -> iced::advanced::Text<String, iced::Font> {
+        iced::advanced::Text {
+            content: content.to_owned(),
+            bounds: Size::new(width, f32::INFINITY),
+            size: self.text_size,
+            font: if bold {
+                iced::Font {
+                    weight: iced::font::Weight::Bold,
+                    ..self.font
+                }
+            } else {
+                self.font
+            },
+            align_x: text::Alignment::Left,
+            align_y: alignment::Vertical::Top,
+            line_height: text::LineHeight::default(),
+            shaping: text::Shaping::Advanced,
+            wrapping: text::Wrapping::Word,
+        }
+    }
+}
+
+/// Time left on the timer of a toast.
+#[derive(Debug, Clone, Copy)]
+struct Timer {
+    remaining: Duration,
+    last_tick: Option<Instant>,
+}
+
+#[derive(Default)]
+struct State {
+    timers: HashMap<Id, Timer>,
+    hovered: Option<Id>,
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer> for ToastLayer<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer<Font = iced::Font>,
+{
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+
+        // Forget the timers of toasts the app has removed
+        let state = tree.state.downcast_mut::<State>();
+        state.timers.retain(|id, _| self.toasts.get(*id).is_some());
+        if state.hovered.is_some_and(|id| self.toasts.get(id).is_none()) {
+            state.hovered = None;
+        }
+    }
+
+    fn layout(&mut self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        self.content
+            .as_widget_mut()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        if let Event::Window(window::Event::RedrawRequested(now)) = event
+            && let Some(on_dismiss) = &self.on_dismiss
+        {
+            let state = tree.state.downcast_mut::<State>();
+
+            for toast in self.toasts.visible() {
+                let Some(duration) = toast.duration else {
+                    continue;
+                };
+                let timer = state.timers.entry(toast.id).or_insert(Timer {
+                    remaining: duration,
+                    last_tick: None,
+                });
+
+                if self.pause_on_hover && state.hovered == Some(toast.id) {
+                    timer.last_tick = None;
+                    continue;
+                }
+
+                if let Some(last_tick) = timer.last_tick {
+                    timer.remaining = timer.remaining.saturating_sub(now.saturating_duration_since(last_tick));
+                }
+                timer.last_tick = Some(*now);
+
+                if timer.remaining.is_zero() {
+                    shell.publish(on_dismiss(toast.id));
+                } else {
+                    shell.request_redraw_at(*now + timer.remaining);
+                }
+            }
+        }
+
+        self.content.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content
+            .as_widget()
+            .draw(&tree.children[0], renderer, theme, style, layout, cursor, viewport);
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content
+            .as_widget()
+            .mouse_interaction(&tree.children[0], layout, cursor, viewport, renderer)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn widget::Operation,
+    ) {
+        self.content
+            .as_widget_mut()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let bounds = layout.bounds() + translation;
+        let placement = Placement {
+            corner: self.corner,
+            width: self.width,
+            spacing: self.spacing,
+            margin: self.margin,
+            text_size: self.text_size,
+            font: self.font,
+        };
+        let (state, children) = (&mut tree.state, &mut tree.children);
+
+        let content = self
+            .content
+            .as_widget_mut()
+            .overlay(&mut children[0], layout, renderer, viewport, translation);
+
+        let toasts = (!self.toasts.is_empty()).then(|| {
+            overlay::Element::new(Box::new(ToastOverlay {
+                toasts: self.toasts,
+                placement,
+                on_dismiss: self.on_dismiss.as_deref(),
+                on_action: self.on_action.as_deref(),
+                class: &self.class,
+                state: state.downcast_mut::<State>(),
+                bounds,
+                placed: Vec::new(),
+            }))
+        });
+
+        let children: Vec<_> = content.into_iter().chain(toasts).collect();
+        (!children.is_empty()).then(|| overlay::Group::with_children(children).overlay())
+    }
+}
+
+/// A toast placed on screen, with the hit areas of its buttons.
+struct Placed {
+    id: Id,
+    severity: Severity,
+    bounds: Rectangle,
+    title_height: f32,
+    close: Option<Rectangle>,
+    actions: Vec<Rectangle>,
+}
+
+struct ToastOverlay<'a, 'b, Message, Theme>
+where
+    Theme: Catalog,
+{
+    toasts: &'a Toasts,
+    placement: Placement,
+    on_dismiss: Option<&'a dyn Fn(Id) -> Message>,
+    on_action: Option<&'a dyn Fn(Id, usize) -> Message>,
+    class: &'a Theme::Class<'b>,
+    state: &'a mut State,
+    bounds: Rectangle,
+    placed: Vec<Placed>,
+}
+
+impl<Message, Theme> ToastOverlay<'_, '_, Message, Theme>
+where
+    Theme: Catalog,
+{
+    fn inner_width(&self) -> f32 {
+        self.placement.width - TOAST_PADDING * 2.0 - ACCENT_WIDTH
+    }
+
+    fn measure<P: text::Paragraph<Font = iced::Font>>(&self, content: &str, width: f32, bold: bool) -> Size {
+        let text = self.placement.text(content, width, bold);
+        P::with_text(iced::advanced::Text {
+            content: text.content.as_str(),
+            bounds: text.bounds,
+            size: text.size,
+            font: text.font,
+            align_x: text.align_x,
+            align_y: text.align_y,
+            line_height: text.line_height,
+            shaping: text.shaping,
+            wrapping: text.wrapping,
+        })
+        .min_bounds()
+    }
+
+    fn hovered(&self, cursor: mouse::Cursor) -> Option<&Placed> {
+        let position = cursor.position()?;
+        self.placed.iter().find(|placed| placed.bounds.contains(position))
+    }
+}
+
+impl<Message, Theme, Renderer> Overlay<Message, Theme, Renderer> for ToastOverlay<'_, '_, Message, Theme>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer<Font = iced::Font>,
+{
+    fn layout(&mut self, _renderer: &Renderer, bounds: Size) -> Node {
+        let area = self
+            .bounds
+            .intersection(&Rectangle::with_size(bounds))
+            .unwrap_or(self.bounds)
+            .shrink(self.placement.margin);
+        let width = self.placement.width.min(area.width.max(0.0));
+        let inner = self.inner_width();
+        let closable = self.on_dismiss.is_some();
+        let title_width = inner - if closable { CLOSE_SIZE + ACTION_SPACING } else { 0.0 };
+
+        let x = match self.placement.corner {
+            Corner::TopLeft | Corner::BottomLeft => area.x,
+            Corner::TopRight | Corner::BottomRight => area.x + area.width - width,
+        };
+        let from_top = matches!(self.placement.corner, Corner::TopLeft | Corner::TopRight);
+        let mut y = if from_top { area.y } else { area.y + area.height };
+
+        let mut placed = Vec::new();
+
+        for toast in self.toasts.visible() {
+            let title_height = self.measure::<Renderer::Paragraph>(&toast.title, title_width, true).height;
+            let body_height = toast
+                .body
+                .as_deref()
+                .map_or(0.0, |body| LINE_SPACING + self.measure::<Renderer::Paragraph>(body, inner, false).height);
+            let actions_height = if toast.actions.is_empty() { 0.0 } else { ACTION_SPACING * 2.0 + ACTION_HEIGHT };
+            let height = TOAST_PADDING * 2.0 + title_height + body_height + actions_height;
+
+            // Stop once the stack leaves the content area
+            let top = if from_top { y } else { y - height };
+            if top < area.y - 0.5 || top + height > area.y + area.height + 0.5 {
+                break;
+            }
+
+            let toast_bounds = Rectangle { x, y: top, width, height };
+            let content_x = toast_bounds.x + ACCENT_WIDTH + TOAST_PADDING;
+
+            let close = closable.then_some(Rectangle {
+                x: toast_bounds.x + toast_bounds.width - TOAST_PADDING - CLOSE_SIZE,
+                y: toast_bounds.y + TOAST_PADDING,
+                width: CLOSE_SIZE,
+                height: CLOSE_SIZE,
+            });
+
+            let mut action_x = content_x;
+            let actions = toast
+                .actions
+                .iter()
+                .map(|label| {
+                    let width = self.measure::<Renderer::Paragraph>(label, f32::INFINITY, true).width + ACTION_PADDING * 2.0;
+                    let action = Rectangle {
+                        x: action_x,
+                        y: toast_bounds.y + toast_bounds.height - TOAST_PADDING - ACTION_HEIGHT,
+                        width,
+                        height: ACTION_HEIGHT,
+                    };
+                    action_x += width + ACTION_SPACING;
+                    action
+                })
+                .collect();
+
+            placed.push(Placed {
+                id: toast.id,
+                severity: toast.severity,
+                bounds: toast_bounds,
+                title_height,
+                close,
+                actions,
+            });
+
+            y = if from_top { y + height + self.placement.spacing } else { y - height - self.placement.spacing };
+        }
+
+        self.placed = placed;
+
+        Node::new(bounds)
+    }
+
+    fn update(
+        &mut self,
+        event: &Event,
+        _layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                let hovered = self.hovered(cursor).map(|placed| placed.id);
+
+                if hovered != self.state.hovered {
+                    // Resume from now, not from the frame before the pause
+                    if let Some(previous) = self.state.hovered
+                        && let Some(timer) = self.state.timers.get_mut(&previous)
+                    {
+                        timer.last_tick = None;
+                    }
+                    self.state.hovered = hovered;
+                    shell.request_redraw();
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let Some(position) = cursor.position() else {
+                    return;
+                };
+                let Some(placed) = self.hovered(cursor) else {
+                    return;
+                };
+
+                if let Some(close) = placed.close
+                    && close.contains(position)
+                    && let Some(on_dismiss) = &self.on_dismiss
+                {
+                    shell.publish(on_dismiss(placed.id));
+                } else if let Some(index) = placed.actions.iter().position(|action| action.contains(position))
+                    && let Some(on_action) = &self.on_action
+                {
+                    shell.publish(on_action(placed.id, index));
+                }
+
+                // Clicks on a toast never reach the content below
+                shell.capture_event();
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _defaults: &renderer::Style,
+        _layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        let inner = self.inner_width();
+
+        for placed in &self.placed {
+            let Some(toast) = self.toasts.get(placed.id) else {
+                continue;
+            };
+            let style = theme.style(self.class, placed.severity);
+            let bounds = placed.bounds;
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: pixel::snap(bounds),
+                    border: pixel::border(style.border),
+                    shadow: style.shadow,
+                    snap: true,
+                },
+                style.background,
+            );
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: pixel::snap(Rectangle {
+                        width: ACCENT_WIDTH,
+                        ..bounds
+                    }),
+                    border: pixel::border(Border {
+                        radius: style.border.radius.right(0.0),
+                        ..Border::default()
+                    }),
+                    ..renderer::Quad::default()
+                },
+                style.accent,
+            );
+
+            let content_x = bounds.x + ACCENT_WIDTH + TOAST_PADDING;
+            let title_y = bounds.y + TOAST_PADDING;
+            let title_width = inner - if placed.close.is_some() { CLOSE_SIZE + ACTION_SPACING } else { 0.0 };
+
+            renderer.fill_text(
+                self.placement.text(&toast.title, title_width, true),
+                Point::new(content_x, title_y),
+                style.title_color,
+                bounds,
+            );
+
+            if let Some(body) = &toast.body {
+                renderer.fill_text(
+                    self.placement.text(body, inner, false),
+                    Point::new(content_x, title_y + placed.title_height + LINE_SPACING),
+                    style.text_color,
+                    bounds,
+                );
+            }
+
+            if let Some(close) = placed.close {
+                if cursor.is_over(close) {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: pixel::snap(close),
+                            border: pixel::border(Border {
+                                radius: (CLOSE_SIZE / 2.0).into(),
+                                ..Border::default()
+                            }),
+                            ..renderer::Quad::default()
+                        },
+                        style.action_hovered_background,
+                    );
+                }
+
+                renderer.fill_text(
+                    iced::advanced::Text {
+                        content: "×".into(),
+                        bounds: close.size(),
+                        size: self.placement.text_size,
+                        font: iced::Font::default(),
+                        align_x: text::Alignment::Center,
+                        align_y: alignment::Vertical::Center,
+                        line_height: text::LineHeight::default(),
+                        shaping: text::Shaping::Advanced,
+                        wrapping: text::Wrapping::None,
+                    },
+                    close.center(),
+                    style.text_color,
+                    bounds,
+                );
+            }
+
+            for (label, action) in toast.actions.iter().zip(&placed.actions) {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: pixel::snap(*action),
+                        border: pixel::border(Border {
+                            color: style.action_color,
+                            width: 1.0,
+                            radius: 4.0.into(),
+                        }),
+                        ..renderer::Quad::default()
+                    },
+                    if cursor.is_over(*action) {
+                        style.action_hovered_background
+                    } else {
+                        Background::Color(Color::TRANSPARENT)
+                    },
+                );
+
+                renderer.fill_text(
+                    iced::advanced::Text {
+                        bounds: action.size(),
+                        align_x: text::Alignment::Center,
+                        align_y: alignment::Vertical::Center,
+                        wrapping: text::Wrapping::None,
+                        ..self.placement.text(label, action.width, true)
+                    },
+                    action.center(),
+                    style.action_color,
+                    bounds,
+                );
+            }
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let Some(placed) = self.hovered(cursor) else {
+            // Let the content below handle the cursor
+            return mouse::Interaction::None;
+        };
+        let over_button = placed
+            .close
+            .iter()
+            .chain(&placed.actions)
+            .any(|button| cursor.is_over(*button));
+
+        if over_button {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::Idle
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<ToastLayer<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer<Font = iced::Font> + 'a,
+{
+    fn from(layer: ToastLayer<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(layer)
+    }
+}
+
+/// The theme catalog of a [`ToastLayer`].
+pub trait Catalog {
+    /// The style class
+    type Class<'a>;
+
+    /// Default style
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// Get the style of a toast with the given severity
+    fn style(&self, class: &Self::Class<'_>, severity: Severity) -> Style;
+}
+
+/// The appearance of a toast.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// Background of the toast
+    pub background: Background,
+    /// Border of the toast
+    pub border: Border,
+    /// Shadow of the toast
+    pub shadow: Shadow,
+    /// Stripe along the leading edge showing the severity
+    pub accent: Background,
+    /// Title color
+    pub title_color: Color,
+    /// Body and close button color
+    pub text_color: Color,
+    /// Action button label and outline color
+    pub action_color: Color,
+    /// Background of hovered action and close buttons
+    pub action_hovered_background: Background,
+}
+
+/// Styling function
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Severity) -> Style + 'a>;
+
+impl Catalog for iced::Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, severity: Severity) -> Style {
+        class(self, severity)
+    }
+}
+
+/// The default toast style: a neutral card with a stripe in the severity color.
+pub fn default(theme: &iced::Theme, severity: Severity) -> Style {
+    let palette = theme.extended_palette();
+    let accent = match severity {
+        Severity::Info => palette.primary.base.color,
+        Severity::Success => palette.success.base.color,
+        Severity::Warning => palette.warning.base.color,
+        Severity::Error => palette.danger.base.color,
+    };
+
+    Style {
+        background: palette.background.weak.color.into(),
+        border: Border {
+            color: palette.background.strong.color,
+            width: 1.0,
+            radius: 6.0.into(),
+        },
+        shadow: Shadow {
+            color: Color::BLACK.scale_alpha(0.3),
+            offset: Vector::new(0.0, 3.0),
+            blur_radius: 10.0,
+        },
+        accent: accent.into(),
+        title_color: palette.background.weak.text,
+        text_color: palette.background.weak.text.scale_alpha(0.8),
+        action_color: accent,
+        action_hovered_background: accent.scale_alpha(0.2).into(),
+    }
+}
+
+/// Toasts filled with the severity color.
+pub fn filled(theme: &iced::Theme, severity: Severity) -> Style {
+    let palette = theme.extended_palette();
+    let pair = match severity {
+        Severity::Info => palette.primary.base,
+        Severity::Success => palette.success.base,
+        Severity::Warning => palette.warning.base,
+        Severity::Error => palette.danger.base,
+    };
+
+    Style {
+        background: pair.color.into(),
+        border: Border {
+            radius: 6.0.into(),
+            ..Border::default()
+        },
+        accent: Background::Color(Color::TRANSPARENT),
+        title_color: pair.text,
+        text_color: pair.text.scale_alpha(0.85),
+        action_color: pair.text,
+        action_hovered_background: pair.text.scale_alpha(0.15).into(),
+        ..default(theme, severity)
+    }
+}
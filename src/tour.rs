@@ -0,0 +1,972 @@
+//! A guided tour pointing out parts of an app one at a time.
+//!
+//! A [`Tour`] wraps the app's content and, while a step is active, dims everything except
+//! that step's target and shows a card next to it explaining what it is. Targets are either
+//! widgets found by their [`Id`](widget::Id), looked up again every frame so the spotlight
+//! follows them through resizes and scrolling, or fixed areas of the window.
+//!
+//! The app owns the current step: the card's Next, Back and Skip buttons (or Enter, the arrow
+//! keys and Escape) produce the messages set with [`Tour::on_next`], [`Tour::on_back`] and
+//! [`Tour::on_skip`]. The tour is hidden when the current step is `None` or past the last.
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        mouse, overlay, renderer, text,
+        widget::{self, tree::Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment, keyboard, Background, Border, Color, Element, Event, Length, Pixels, Point, Rectangle, Shadow,
+    Size, Vector,
+};
+
+use crate::pixel;
+
+const CARD_PADDING: f32 = 16.0;
+const CARD_GAP: f32 = 12.0;
+const SPACING: f32 = 8.0;
+const BUTTON_HEIGHT: f32 = 28.0;
+const BUTTON_PADDING: f32 = 12.0;
+const MARGIN: f32 = 8.0;
+
+/// Creates a new [`Tour`] over `content`, showing `steps[current]`.
+pub fn tour<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    steps: impl IntoIterator<Item = Step>,
+    current: Option<usize>,
+) -> Tour<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer<Font = iced::Font>,
+{
+    Tour::new(content, steps, current)
+}
+
+/// What a [`Step`] points at.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Target {
+    /// The widget with this id, wherever it is laid out
+    Widget(widget::Id),
+    /// A fixed area of the window
+    Area(Rectangle),
+}
+
+impl From<widget::Id> for Target {
+    fn from(id: widget::Id) -> Self {
+        Self::Widget(id)
+    }
+}
+
+impl From<Rectangle> for Target {
+    fn from(area: Rectangle) -> Self {
+        Self::Area(area)
+    }
+}
+
+/// One stop of a [`Tour`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Step {
+    pub target: Target,
+    pub title: String,
+    pub description: String,
+}
+
+impl Step {
+    /// Creates a new [`Step`] explaining `target`.
+    pub fn new(target: impl Into<Target>, title: impl Into<String>, description: impl Into<String>) -> Self {
+        Self {
+            target: target.into(),
+            title: title.into(),
+            description: description.into(),
+        }
+    }
+}
+
+/// Content with a guided tour over it.
+#[allow(missing_debug_implementations)]
+pub struct Tour<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer<Font = iced::Font>,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    steps: Vec<Step>,
+    current: Option<usize>,
+    on_next: Option<Box<dyn Fn() -> Message + 'a>>,
+    on_back: Option<Box<dyn Fn() -> Message + 'a>>,
+    on_skip: Option<Box<dyn Fn() -> Message + 'a>>,
+    card: Card,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> Tour<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer<Font = iced::Font>,
+{
+    /// Creates a new [`Tour`] over `content`, showing `steps[current]`.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        steps: impl IntoIterator<Item = Step>,
+        current: Option<usize>,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            steps: steps.into_iter().collect(),
+            current,
+            on_next: None,
+            on_back: None,
+            on_skip: None,
+            card: Card {
+                width: 300.0,
+                spotlight_padding: 6.0,
+                text_size: Pixels(14.0),
+                font: iced::Font::default(),
+            },
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the message of the Next button, which reads Done on the last step.
+    pub fn on_next(self, message: Message) -> Self
+    where
+        Message: Clone + 'a,
+    {
+        self.on_next_with(move || message.clone())
+    }
+
+    /// Sets the function making the message of the Next button, for messages that
+    /// can not be cloned.
+    pub fn on_next_with(mut self, on_next: impl Fn() -> Message + 'a) -> Self {
+        self.on_next = Some(Box::new(on_next));
+        self
+    }
+
+    /// Sets the message of the Back button, which is hidden without one.
+    pub fn on_back(self, message: Message) -> Self
+    where
+        Message: Clone + 'a,
+    {
+        self.on_back_with(move || message.clone())
+    }
+
+    /// Sets the function making the message of the Back button, for messages that
+    /// can not be cloned.
+    pub fn on_back_with(mut self, on_back: impl Fn() -> Message + 'a) -> Self {
+        self.on_back = Some(Box::new(on_back));
+        self
+    }
+
+    /// Sets the message of the Skip button, which is hidden without one.
+    pub fn on_skip(self, message: Message) -> Self
+    where
+        Message: Clone + 'a,
+    {
+        self.on_skip_with(move || message.clone())
+    }
+
+    /// Sets the function making the message of the Skip button, for messages that
+    /// can not be cloned.
+    pub fn on_skip_with(mut self, on_skip: impl Fn() -> Message + 'a) -> Self {
+        self.on_skip = Some(Box::new(on_skip));
+        self
+    }
+
+    /// Sets the width of the card.
+    pub fn card_width(mut self, width: f32) -> Self {
+        self.card.width = width;
+        self
+    }
+
+    /// Sets how far the spotlight reaches past the target.
+    pub fn spotlight_padding(mut self, padding: f32) -> Self {
+        self.card.spotlight_padding = padding;
+        self
+    }
+
+    /// Sets the text size of the card.
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.card.text_size = size.into();
+        self
+    }
+
+    /// Sets the font of the card.
+    pub fn font(mut self, font: iced::Font) -> Self {
+        self.card.font = font;
+        self
+    }
+
+    /// Sets the style.
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class.
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+/// The card settings of a [`Tour`], copied into its overlay.
+#[derive(Debug, Clone, Copy)]
+struct Card {
+    width: f32,
+    spotlight_padding: f32,
+    text_size: Pixels,
+    font: iced::Font,
+}
+
+impl Card {
+    fn text(&self, content: &str, width: f32, size: f32, bold: bool) -> iced::advanced::Text<String, iced::Font> {
+        iced::advanced::Text {
+            content: content.to_owned(),
+            bounds: Size::new(width, f32::INFINITY),
+            size: Pixels(size),
+            font: if bold {
+                iced::Font {
+                    weight: iced::font::Weight::Bold,
+                    ..self.font
+                }
+            } else {
+                self.font
+            },
+            align_x: text::Alignment::Left,
+            align_y: alignment::Vertical::Top,
+            line_height: text::LineHeight::default(),
+            shaping: text::Shaping::Advanced,
+            wrapping: text::Wrapping::Word,
+        }
+    }
+
+    fn measure<P: text::Paragraph<Font = iced::Font>>(&self, content: &str, width: f32, size: f32, bold: bool) -> Size {
+        let text = self.text(content, width, size, bold);
+
+        P::with_text(iced::advanced::Text {
+            content: text.content.as_str(),
+            bounds: text.bounds,
+            size: text.size,
+            font: text.font,
+            align_x: text.align_x,
+            align_y: text.align_y,
+            line_height: text.line_height,
+            shaping: text.shaping,
+            wrapping: text.wrapping,
+        })
+        .min_bounds()
+    }
+}
+
+/// Finds the bounds of the widget with an id, accounting for scrolling.
+struct FindBounds<'a> {
+    id: &'a widget::Id,
+    bounds: Option<Rectangle>,
+    /// Scroll offset of the scrollable being traversed
+    translation: Vector,
+    /// Scroll offset reported by a scrollable, applied to its contents
+    pending: Vector,
+}
+
+impl FindBounds<'_> {
+    fn visit(&mut self, id: Option<&widget::Id>, bounds: Rectangle) {
+        if self.bounds.is_none() && id == Some(self.id) {
+            self.bounds = Some(bounds - self.translation);
+        }
+    }
+}
+
+impl widget::Operation for FindBounds<'_> {
+    fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn widget::Operation)) {
+        if self.bounds.is_some() {
+            return;
+        }
+
+        let outer = self.translation;
+        self.translation = outer + std::mem::replace(&mut self.pending, Vector::ZERO);
+        operate(self);
+        self.translation = outer;
+    }
+
+    fn container(&mut self, id: Option<&widget::Id>, bounds: Rectangle) {
+        self.visit(id, bounds);
+    }
+
+    fn scrollable(
+        &mut self,
+        id: Option<&widget::Id>,
+        bounds: Rectangle,
+        _content_bounds: Rectangle,
+        translation: Vector,
+        _state: &mut dyn widget::operation::Scrollable,
+    ) {
+        self.visit(id, bounds);
+        self.pending = translation;
+    }
+
+    fn focusable(&mut self, id: Option<&widget::Id>, bounds: Rectangle, _state: &mut dyn widget::operation::Focusable) {
+        self.visit(id, bounds);
+    }
+
+    fn text_input(&mut self, id: Option<&widget::Id>, bounds: Rectangle, _state: &mut dyn widget::operation::TextInput) {
+        self.visit(id, bounds);
+    }
+
+    fn text(&mut self, id: Option<&widget::Id>, bounds: Rectangle, _text: &str) {
+        self.visit(id, bounds);
+    }
+
+    fn custom(&mut self, id: Option<&widget::Id>, bounds: Rectangle, _state: &mut dyn std::any::Any) {
+        self.visit(id, bounds);
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Tour<'_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer<Font = iced::Font>,
+{
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn layout(&mut self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let content = self
+            .content
+            .as_widget_mut()
+            .layout(&mut tree.children[0], renderer, limits);
+
+        Node::with_children(content.size(), vec![content])
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout.children().next().unwrap_or(layout),
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        defaults: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            defaults,
+            layout.children().next().unwrap_or(layout),
+            cursor,
+            viewport,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout.children().next().unwrap_or(layout),
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn widget::Operation,
+    ) {
+        self.content.as_widget_mut().operate(
+            &mut tree.children[0],
+            layout.children().next().unwrap_or(layout),
+            renderer,
+            operation,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let content_layout = layout.children().next().unwrap_or(layout);
+
+        let spotlight = self
+            .current
+            .and_then(|index| self.steps.get(index))
+            .map(|step| match &step.target {
+                Target::Area(area) => Some(*area),
+                Target::Widget(id) => {
+                    let mut find = FindBounds {
+                        id,
+                        bounds: None,
+                        translation: Vector::ZERO,
+                        pending: Vector::ZERO,
+                    };
+                    self.content
+                        .as_widget_mut()
+                        .operate(&mut tree.children[0], content_layout, renderer, &mut find);
+
+                    find.bounds.map(|bounds| bounds + translation)
+                }
+            });
+
+        let content = self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            content_layout,
+            renderer,
+            viewport,
+            translation,
+        );
+
+        let tour = spotlight.zip(self.current).map(|(target, index)| {
+            overlay::Element::new(Box::new(Overlay {
+                step: &self.steps[index],
+                index,
+                count: self.steps.len(),
+                target,
+                on_next: self.on_next.as_deref(),
+                on_back: self.on_back.as_deref().filter(|_| index > 0),
+                on_skip: self.on_skip.as_deref(),
+                card: self.card,
+                class: &self.class,
+            }))
+        });
+
+        match (content, tour) {
+            (Some(content), Some(tour)) => Some(overlay::Group::with_children(vec![content, tour]).overlay()),
+            (content, tour) => content.or(tour),
+        }
+    }
+}
+
+/// Where the parts of the card go for the current step.
+struct Geometry {
+    /// The lit area, `None` when the target was not found
+    spotlight: Option<Rectangle>,
+    card: Rectangle,
+    title: Point,
+    description: Point,
+    counter: Point,
+    skip: Option<Rectangle>,
+    back: Option<Rectangle>,
+    next: Rectangle,
+}
+
+/// The backdrop and card of a [`Tour`].
+struct Overlay<'a, 'b, Message, Theme>
+where
+    Theme: Catalog,
+{
+    step: &'a Step,
+    index: usize,
+    count: usize,
+    target: Option<Rectangle>,
+    on_next: Option<&'a (dyn Fn() -> Message + 'b)>,
+    on_back: Option<&'a (dyn Fn() -> Message + 'b)>,
+    on_skip: Option<&'a (dyn Fn() -> Message + 'b)>,
+    card: Card,
+    class: &'a Theme::Class<'b>,
+}
+
+impl<Message, Theme> Overlay<'_, '_, Message, Theme>
+where
+    Theme: Catalog,
+{
+    fn next_label(&self) -> &'static str {
+        if self.index + 1 >= self.count {
+            "Done"
+        } else {
+            "Next"
+        }
+    }
+
+    fn counter(&self) -> String {
+        format!("{} of {}", self.index + 1, self.count)
+    }
+
+    fn small_size(&self) -> f32 {
+        self.card.text_size.0 * 0.85
+    }
+
+    fn geometry<P: text::Paragraph<Font = iced::Font>>(&self, window: Size) -> Geometry {
+        let size = self.card.text_size.0;
+        let width = self.card.width.min(window.width - MARGIN * 2.0).max(0.0);
+        let inner = (width - CARD_PADDING * 2.0).max(0.0);
+
+        let title = self.card.measure::<P>(&self.step.title, inner, size * 1.15, true);
+        let description = self.card.measure::<P>(&self.step.description, inner, size, false);
+        let height = CARD_PADDING * 2.0 + title.height + SPACING + description.height + SPACING * 2.0 + BUTTON_HEIGHT;
+
+        let spotlight = self.target.map(|target| target.expand(self.card.spotlight_padding));
+
+        // Below the target, else above, else beside it; centered without a target
+        let position = match spotlight {
+            Some(spot) => {
+                let x = spot.x.clamp(MARGIN, (window.width - width - MARGIN).max(MARGIN));
+                let below = spot.y + spot.height + CARD_GAP;
+                let above = spot.y - CARD_GAP - height;
+                let right = spot.x + spot.width + CARD_GAP;
+                let y = spot.y.clamp(MARGIN, (window.height - height - MARGIN).max(MARGIN));
+
+                if below + height <= window.height - MARGIN {
+                    Point::new(x, below)
+                } else if above >= MARGIN {
+                    Point::new(x, above)
+                } else if right + width <= window.width - MARGIN {
+                    Point::new(right, y)
+                } else {
+                    Point::new((spot.x - CARD_GAP - width).max(MARGIN), y)
+                }
+            }
+            None => Point::new((window.width - width) / 2.0, (window.height - height) / 2.0),
+        };
+        let card = Rectangle::new(position, Size::new(width, height));
+
+        let button = |label: &str| {
+            self.card.measure::<P>(label, f32::INFINITY, size, false).width + BUTTON_PADDING * 2.0
+        };
+        let buttons_y = card.y + card.height - CARD_PADDING - BUTTON_HEIGHT;
+        let next_width = button(self.next_label());
+        let next = Rectangle {
+            x: card.x + card.width - CARD_PADDING - next_width,
+            y: buttons_y,
+            width: next_width,
+            height: BUTTON_HEIGHT,
+        };
+        let back = self.on_back.map(|_| {
+            let width = button("Back");
+            Rectangle {
+                x: next.x - SPACING - width,
+                y: buttons_y,
+                width,
+                height: BUTTON_HEIGHT,
+            }
+        });
+        let skip = self.on_skip.map(|_| Rectangle {
+            x: card.x + CARD_PADDING - BUTTON_PADDING,
+            y: buttons_y,
+            width: button("Skip"),
+            height: BUTTON_HEIGHT,
+        });
+
+        Geometry {
+            spotlight,
+            card,
+            title: Point::new(card.x + CARD_PADDING, card.y + CARD_PADDING),
+            description: Point::new(card.x + CARD_PADDING, card.y + CARD_PADDING + title.height + SPACING),
+            counter: Point::new(
+                next.x - back.map_or(0.0, |back| back.width + SPACING) - SPACING,
+                buttons_y + BUTTON_HEIGHT / 2.0,
+            ),
+            skip,
+            back,
+            next,
+        }
+    }
+
+    fn draw_button<Renderer>(
+        &self,
+        renderer: &mut Renderer,
+        bounds: Rectangle,
+        label: &str,
+        style: &Style,
+        primary: bool,
+        hovered: bool,
+    ) where
+        Renderer: text::Renderer<Font = iced::Font>,
+    {
+        let background = match (primary, hovered) {
+            (true, false) => Some(style.button_background),
+            (true, true) => Some(style.button_hovered_background),
+            (false, true) => Some(style.secondary_hovered_background),
+            (false, false) => None,
+        };
+
+        if let Some(background) = background {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: pixel::snap(bounds),
+                    border: pixel::border(Border {
+                        radius: style.card_border.radius,
+                        ..Border::default()
+                    }),
+                    ..renderer::Quad::default()
+                },
+                background,
+            );
+        }
+
+        renderer.fill_text(
+            iced::advanced::Text {
+                align_x: text::Alignment::Center,
+                align_y: alignment::Vertical::Center,
+                ..self.card.text(label, bounds.width, self.card.text_size.0, false)
+            },
+            bounds.center(),
+            if primary { style.button_text } else { style.secondary_text },
+            bounds,
+        );
+    }
+}
+
+impl<Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer> for Overlay<'_, '_, Message, Theme>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer<Font = iced::Font>,
+{
+    fn layout(&mut self, _renderer: &Renderer, bounds: Size) -> Node {
+        Node::new(bounds)
+    }
+
+    fn update(
+        &mut self,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let message = match event {
+            Event::Mouse(mouse::Event::ButtonPressed(_)) => {
+                let geometry = self.geometry::<Renderer::Paragraph>(layout.bounds().size());
+                let clicked = |bounds: Option<Rectangle>| bounds.is_some_and(|bounds| cursor.is_over(bounds));
+
+                if clicked(Some(geometry.next)) {
+                    self.on_next
+                } else if clicked(geometry.back) {
+                    self.on_back
+                } else if clicked(geometry.skip) {
+                    self.on_skip
+                } else {
+                    None
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                shell.request_redraw();
+                return;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed { key, .. }) => match key {
+                keyboard::Key::Named(keyboard::key::Named::Enter | keyboard::key::Named::ArrowRight) => self.on_next,
+                keyboard::Key::Named(keyboard::key::Named::ArrowLeft) => self.on_back,
+                keyboard::Key::Named(keyboard::key::Named::Escape) => self.on_skip,
+                _ => None,
+            },
+            // Scrolling stays with the app, the spotlight follows its target
+            _ => return,
+        };
+
+        // Everything behind the backdrop is out of reach while the tour runs
+        if let Some(message) = message {
+            shell.publish(message());
+        }
+        shell.capture_event();
+        shell.request_redraw();
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _defaults: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        let style = theme.style(self.class);
+        let window = layout.bounds();
+        let geometry = self.geometry::<Renderer::Paragraph>(window.size());
+
+        renderer.with_layer(window, |renderer| {
+            let dim = |renderer: &mut Renderer, bounds: Rectangle| {
+                if bounds.width > 0.0 && bounds.height > 0.0 {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: pixel::snap(bounds),
+                            ..renderer::Quad::default()
+                        },
+                        style.backdrop,
+                    );
+                }
+            };
+
+            match geometry.spotlight {
+                // Four bands around the spotlight leave it undimmed
+                Some(spot) => {
+                    let top = spot.y.max(window.y);
+                    let bottom = (spot.y + spot.height).min(window.y + window.height);
+                    let middle = (bottom - top).max(0.0);
+
+                    dim(renderer, Rectangle { height: (top - window.y).max(0.0), ..window });
+                    dim(
+                        renderer,
+                        Rectangle {
+                            y: bottom,
+                            height: (window.y + window.height - bottom).max(0.0),
+                            ..window
+                        },
+                    );
+                    dim(
+                        renderer,
+                        Rectangle {
+                            y: top,
+                            width: (spot.x - window.x).max(0.0),
+                            height: middle,
+                            ..window
+                        },
+                    );
+                    dim(
+                        renderer,
+                        Rectangle {
+                            x: spot.x + spot.width,
+                            y: top,
+                            width: (window.x + window.width - spot.x - spot.width).max(0.0),
+                            height: middle,
+                        },
+                    );
+
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: pixel::snap(spot),
+                            border: pixel::border(style.spotlight_border),
+                            ..renderer::Quad::default()
+                        },
+                        Color::TRANSPARENT,
+                    );
+                }
+                None => dim(renderer, window),
+            }
+        });
+
+        renderer.with_layer(window, |renderer| {
+            let card = geometry.card;
+            let inner = (card.width - CARD_PADDING * 2.0).max(0.0);
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: pixel::snap(card),
+                    border: pixel::border(style.card_border),
+                    shadow: style.card_shadow,
+                    snap: true,
+                },
+                style.card_background,
+            );
+
+            renderer.fill_text(
+                self.card.text(&self.step.title, inner, self.card.text_size.0 * 1.15, true),
+                geometry.title,
+                style.title,
+                card,
+            );
+            renderer.fill_text(
+                self.card.text(&self.step.description, inner, self.card.text_size.0, false),
+                geometry.description,
+                style.text,
+                card,
+            );
+            renderer.fill_text(
+                iced::advanced::Text {
+                    align_x: text::Alignment::Right,
+                    align_y: alignment::Vertical::Center,
+                    ..self.card.text(&self.counter(), inner, self.small_size(), false)
+                },
+                geometry.counter,
+                style.secondary_text,
+                card,
+            );
+
+            if let Some(skip) = geometry.skip {
+                self.draw_button(renderer, skip, "Skip", &style, false, cursor.is_over(skip));
+            }
+            if let Some(back) = geometry.back {
+                self.draw_button(renderer, back, "Back", &style, false, cursor.is_over(back));
+            }
+            self.draw_button(
+                renderer,
+                geometry.next,
+                self.next_label(),
+                &style,
+                true,
+                cursor.is_over(geometry.next),
+            );
+        });
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let geometry = self.geometry::<Renderer::Paragraph>(layout.bounds().size());
+        let on_button = [Some(geometry.next), geometry.back, geometry.skip]
+            .into_iter()
+            .flatten()
+            .any(|bounds| cursor.is_over(bounds));
+
+        if on_button {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::Idle
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Tour<'a, Message, Theme, Renderer>> for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer<Font = iced::Font> + 'a,
+{
+    fn from(tour: Tour<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(tour)
+    }
+}
+
+/// The theme catalog of a [`Tour`].
+pub trait Catalog {
+    /// The style class
+    type Class<'a>;
+
+    /// Default style
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// Get the style for a class
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// The appearance of a [`Tour`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// Color laid over everything but the target
+    pub backdrop: Color,
+    /// Border drawn around the target
+    pub spotlight_border: Border,
+    /// Background of the card
+    pub card_background: Background,
+    /// Border of the card
+    pub card_border: Border,
+    /// Shadow of the card
+    pub card_shadow: Shadow,
+    /// Color of the step title
+    pub title: Color,
+    /// Color of the step description
+    pub text: Color,
+    /// Color of the step counter and the Back and Skip buttons
+    pub secondary_text: Color,
+    /// Background of the Next button
+    pub button_background: Background,
+    /// Label of the Next button
+    pub button_text: Color,
+    /// Background of the Next button when hovered
+    pub button_hovered_background: Background,
+    /// Background of the Back and Skip buttons when hovered
+    pub secondary_hovered_background: Background,
+}
+
+/// Styling function
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for iced::Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default tour style.
+pub fn default(theme: &iced::Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        backdrop: Color::BLACK.scale_alpha(0.6),
+        spotlight_border: Border {
+            color: palette.primary.strong.color,
+            width: 2.0,
+            radius: 6.0.into(),
+        },
+        card_background: palette.background.base.color.into(),
+        card_border: Border {
+            color: palette.background.strong.color,
+            width: 1.0,
+            radius: 6.0.into(),
+        },
+        card_shadow: Shadow {
+            color: Color::BLACK.scale_alpha(0.4),
+            offset: Vector::new(0.0, 4.0),
+            blur_radius: 16.0,
+        },
+        title: palette.background.base.text,
+        text: palette.background.base.text,
+        secondary_text: palette.background.strong.text,
+        button_background: palette.primary.strong.color.into(),
+        button_text: palette.primary.strong.text,
+        button_hovered_background: palette.primary.base.color.into(),
+        secondary_hovered_background: palette.background.weak.color.into(),
+    }
+}
+
+/// A lighter backdrop without a border around the target.
+pub fn subtle(theme: &iced::Theme) -> Style {
+    Style {
+        backdrop: Color::BLACK.scale_alpha(0.3),
+        spotlight_border: Border::default(),
+        ..default(theme)
+    }
+}
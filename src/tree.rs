@@ -1,29 +1,52 @@
 use iced::{
     advanced::{
+        clipboard,
         layout,
         renderer,
-        text::Renderer as _,
+        text::{Paragraph as _, Renderer as _},
         widget::{self, tree::Tree},
         Clipboard, Layout, Shell, Widget,
-    }, border::Radius, keyboard, mouse, widget::text::Alignment, Border, Color, Element, Event, Length, Pixels, Point, Rectangle, Size, Vector
+    }, border::Radius, keyboard, mouse, touch, widget::text::Alignment, Border, Color, Element, Event, Length, Pixels, Point, Rectangle, Size, Vector
 };
+use iced::animation::{Animation, Easing};
+use iced::time::{Duration, Instant};
 use std::collections::{HashSet, HashMap};
 
+use crate::direction::{self, Direction};
+use crate::dnd;
+use crate::gestures::{self, Gesture};
+use crate::operation;
+use crate::theme::{Palette, Preset};
+use crate::pixel;
+use crate::profile::{self, Phase};
+#[cfg(feature = "debug")]
+use crate::debug;
+
 // Constants for layout
 const LINE_HEIGHT: f32 = 32.0;       
 const ARROW_X_PAD: f32 = 4.0;       
 const ARROW_W: f32 = 16.0;          
 const HANDLE_HOVER_W: f32 = 24.0;   
-const HANDLE_STRIPE_W: f32 = 2.0;   
+const HANDLE_STRIPE_W: f32 = crate::depth::GUIDE_WIDTH;
 const CONTENT_GAP: f32 = 14.0;       
-const DRAG_THRESHOLD: f32 = 5.0;     // Minimum distance to start drag
+const VIRTUAL_HEIGHT: f32 = 1000.0; // Height laid out by a virtualized tree before it sees its viewport
+const EDIT_PAD: f32 = 4.0;           // Space between the rename field's border and its text
+const CARET_WIDTH: f32 = 1.0;
+const CHECKBOX_SIZE: f32 = 16.0;
+const CHECKBOX_GAP: f32 = 8.0;       // Space between a checkbox and the content
+const AUTO_SCROLL_EDGE: f32 = 32.0;  // How close to the viewport's edge a drag scrolls
+const AUTO_SCROLL_SPEED: f32 = 600.0; // Pixels per second at the very edge
+const ANIMATION_DURATION: Duration = Duration::from_millis(200);
+const DEFAULT_COLUMN_WIDTH: f32 = 120.0;
+const DEFAULT_MIN_COLUMN_WIDTH: f32 = 40.0;
+const RESIZE_HANDLE_WIDTH: f32 = 8.0;
+const CELL_PADDING: f32 = 8.0;      // Space between a cell and the edges of its column
 
 /// Creates a new [`TreeHandle`] with the given root branches.
 pub fn tree_handle<'a, Message, Theme, Renderer>(
     roots: impl IntoIterator<Item = Branch<'a, Message, Theme, Renderer>>,
 ) -> TreeHandle<'a, Message, Theme, Renderer>
 where
-    Message: Clone,
     Theme: Catalog,
     Renderer: iced::advanced::Renderer + iced::advanced::text::Renderer,
 {
@@ -43,9 +66,141 @@ pub fn branch<'a, Message, Theme, Renderer>(
         align_y: iced::Alignment::Center,
         accepts_drops: false,
         draggable: true,
+        lazy: false,
+        name: None,
+        label: None,
+        disabled: false,
+        cells: Vec::new(),
+    }
+}
+
+/// Creates a new [`Branch`] for a tree with [`TreeColumns`], the first cell in the tree's
+/// column and the others in the columns after it.
+pub fn branch_with_cells<'a, Message, Theme, Renderer>(
+    cells: impl IntoIterator<Item = Element<'a, Message, Theme, Renderer>>,
+) -> Branch<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    let mut cells = cells.into_iter();
+    let content = cells.next().unwrap_or_else(|| iced::widget::Space::new().into());
+
+    Branch {
+        cells: cells.collect(),
+        ..branch(content)
+    }
+}
+
+/// A column of a tree with [`TreeColumns`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Column {
+    title: String,
+    width: f32,
+    min_width: f32,
+    resizable: bool,
+    align_x: iced::Alignment,
+}
+
+impl Column {
+    /// Creates a new [`Column`] with the given header title.
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            width: DEFAULT_COLUMN_WIDTH,
+            min_width: DEFAULT_MIN_COLUMN_WIDTH,
+            resizable: true,
+            align_x: iced::Alignment::Start,
+        }
+    }
+
+    /// Sets the initial width of the column.
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the width the column cannot be resized below.
+    pub fn min_width(mut self, min_width: f32) -> Self {
+        self.min_width = min_width;
+        self
+    }
+
+    /// Sets whether the column can be resized by dragging its header edge.
+    pub fn resizable(mut self, resizable: bool) -> Self {
+        self.resizable = resizable;
+        self
+    }
+
+    /// Sets the horizontal alignment of the cells and title of the column.
+    pub fn align_x(mut self, alignment: impl Into<iced::Alignment>) -> Self {
+        self.align_x = alignment.into();
+        self
     }
 }
 
+/// The columns of a tree shown as a table, set with [`TreeHandle::columns`].
+///
+/// The first column holds the tree itself: arrows, handles and each branch's content, indented.
+/// The cells given with [`branch_with_cells`] after the first go in the columns after it, and
+/// a branch without them leaves those columns empty.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TreeColumns {
+    columns: Vec<Column>,
+    header: bool,
+}
+
+impl TreeColumns {
+    /// Creates the columns, the first of them holding the tree, with a header row.
+    pub fn new(columns: impl IntoIterator<Item = Column>) -> Self {
+        Self {
+            columns: columns.into_iter().collect(),
+            header: true,
+        }
+    }
+
+    /// Sets whether a header row above the branches shows the titles, and the edges to drag
+    /// to resize the columns.
+    pub fn header(mut self, header: bool) -> Self {
+        self.header = header;
+        self
+    }
+}
+
+/// Finds `query` in `text` ignoring case, the way [`TreeHandle::filter`] matches branches,
+/// returning the byte range of the first match so the content can highlight it.
+pub fn find_match(text: &str, query: &str) -> Option<std::ops::Range<usize>> {
+    crate::search::find_ignore_case(text, query)
+}
+
+/// Whether a branch is checked, with [`TreeHandle::with_checkboxes`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CheckState {
+    /// The branch, or every branch under it, is checked
+    Checked,
+    /// The branch, or every branch under it, is unchecked
+    Unchecked,
+    /// Some of the branches under it are checked
+    Indeterminate,
+}
+
+/// The expansion, selection and order of a tree by external id, to store with the app's
+/// settings and pass back with [`TreeHandle::snapshot`] in a later session.
+///
+/// Taken with the [`snapshot`] operation. Branches without an external id are left out.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Snapshot {
+    /// The expanded branches
+    pub expanded: HashSet<usize>,
+    /// The selected branches
+    pub selected: HashSet<usize>,
+    /// Every branch with its parent, top to bottom, when they were reordered by dragging or
+    /// the keyboard; empty when the tree showed them as given
+    pub order: Vec<(usize, Option<usize>)>,
+}
+
 #[derive(Debug, Clone)]
 pub struct DropInfo{
     pub dragged_ids: Vec<usize>,
@@ -60,15 +215,28 @@ pub enum DropPosition {
     Into,
 }
 
+/// Makes the message of branches dragged out of a tree.
+type DragOut<'a, Message> = Box<dyn Fn(Vec<usize>, Point) -> Message + 'a>;
+
+/// Makes the message of a payload from another widget dropped on a tree.
+type ExternalDrop<'a, Message> = Box<dyn Fn(DropInfo, dnd::Payload) -> Message + 'a>;
+
+/// Makes the message of a toggled checkbox from the check state of every branch.
+type OnCheck<'a, Message> = Box<dyn Fn(HashMap<usize, CheckState>) -> Message + 'a>;
+
+/// Decides whether a drop is allowed.
+type DropValidate<'a> = Box<dyn Fn(&DropInfo) -> bool + 'a>;
+
 #[allow(missing_debug_implementations)]
 pub struct TreeHandle<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer> 
 where 
-    Message: Clone,
     Theme: Catalog,
     Renderer: iced::advanced::text::Renderer,
 {
     branches: Vec<Branch_>,
     branch_content: Vec<Element<'a, Message, Theme, Renderer>>, 
+    branch_cells: Vec<Vec<Element<'a, Message, Theme, Renderer>>>,
+    cell_start: Vec<usize>, // Index of each branch's first cell among every branch's cells
     width: Length, 
     height: Length,
     spacing: f32, 
@@ -77,11 +245,36 @@ where
     padding_y: f32,
     on_drop: Option<Box<dyn Fn(DropInfo) -> Message + 'a>>,
     on_select: Option<Box< dyn Fn(HashSet<usize>) -> Message + 'a>>,
+    on_toggle: Option<Box<dyn Fn(usize, bool) -> Message + 'a>>,
+    on_expand: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    on_rename: Option<Box<dyn Fn(usize, String) -> Message + 'a>>,
+    on_context_menu: Option<Box<dyn Fn(usize, Point) -> Message + 'a>>,
+    on_drag_out: Option<DragOut<'a, Message>>,
+    on_external_drop: Option<ExternalDrop<'a, Message>>,
+    accepts_external_drops: bool,
+    on_drop_validate: Option<DropValidate<'a>>,
+    on_check: Option<OnCheck<'a, Message>>,
+    on_auto_scroll: Option<Box<dyn Fn(f32) -> Message + 'a>>,
+    auto_scroll: (f32, f32), // Edge and speed
+    checkboxes: bool,
+    checked: Option<HashSet<usize>>,
     force_reset_order: bool,
     ext_to_int: HashMap<usize, usize>,
     int_to_ext: Vec<usize>, // index is internal id; value is external id or 0
     expand_icon: Option<Element<'a, Message, Theme, Renderer>>,
     collapse_icon: Option<Element<'a, Message, Theme, Renderer>>,
+    direction: Option<Direction>,
+    id: Option<widget::Id>,
+    virtualized: bool,
+    show_guides: bool,
+    sticky_headers: u16,
+    animations: bool,
+    animation_duration: Duration,
+    columns: Option<TreeColumns>,
+    on_column_resize: Option<Box<dyn Fn(usize, f32) -> Message + 'a>>,
+    matches: Option<HashSet<usize>>, // Internal ids of the branches the filter matched
+    expanded: Option<HashSet<usize>>,
+    snapshot: Option<Snapshot>,
     class: Theme::Class<'a>,
 }
 
@@ -94,10 +287,21 @@ struct Branch_ {
     has_children: bool,
     accepts_drops: bool,
     draggable: bool,
+    lazy: bool,
+    name: Option<String>,
+    label: Option<String>,
+    disabled: bool, // Set on the branch or one of its ancestors
     align_x: iced::Alignment,
     align_y: iced::Alignment,
 }
 
+impl Branch_ {
+    /// Whether the branch shows an expand arrow, either for its children or lazy content.
+    fn is_expandable(&self) -> bool {
+        self.has_children || self.lazy
+    }
+}
+
 #[derive(Clone, Debug)]
 struct BranchState {
     id: usize,
@@ -117,6 +321,8 @@ struct TreeState {
     // Interaction state
     selected: HashSet<usize>,
     focused: Option<usize>,
+    // Where Shift extends the selection from, the branch last focused without Shift
+    anchor: Option<usize>,
     hovered: Option<usize>,
     hovered_handle: Option<usize>,
     
@@ -127,11 +333,319 @@ struct TreeState {
     // Selection rectangle state
     selection_rect: Option<SelectionRect>,
     
-    // Tree structure state (for reordering)
+    // Tree structure state (for reordering), and whether it differs from the given one
     branch_order: Option<Vec<BranchState>>,
+    reordered: bool,
 
     // Track keyboard modifiers
     current_modifiers: keyboard::Modifiers,
+
+    // External ids of the branches of the last layout, indexed by internal id
+    known_ids: Vec<usize>,
+
+    // Touch gestures, and whether a long press picked branches up
+    gestures: gestures::Recognizer,
+    touch_drag: bool,
+    // Mouse events made from a gesture are going through the tree, not its branches
+    replaying: bool,
+
+    // Made by operations, applied on the next `operate` and laid out on the next update
+    requests: Vec<Request>,
+    relayout: bool,
+    // The branch `reveal` asked for and where its row will be, reported before `operate` ends
+    revealed: Option<(usize, Rectangle)>,
+
+    // Virtualized trees: the last viewport seen and the rows laid out around it, relative to the tree
+    viewport: Option<Rectangle>,
+    realized: Vec<bool>,
+    realized_area: Option<(f32, f32)>,
+
+    // External ids of the expanded branches last passed with `TreeHandle::expanded`
+    synced_expanded: Option<HashSet<usize>>,
+    // The snapshot last applied from `TreeHandle::snapshot`
+    synced_snapshot: Option<Snapshot>,
+
+    // Branches checked with `with_checkboxes`, and the external ids last passed with `checked`
+    checked: HashSet<usize>,
+    synced_checked: Option<HashSet<usize>>,
+
+    // The ancestors of the filter's matches last opened, and those among them that were closed
+    filter_ancestors: HashSet<usize>,
+    filter_opened: HashSet<usize>,
+
+    // Inline renaming, and the last press on a row to tell double-clicks
+    editing: Option<Editing>,
+    last_click: Option<iced::advanced::mouse::Click>,
+
+    // Drops from other widgets: the tree's token as a drop target, and where one would land
+    drop_token: u64,
+    external_drop: Option<(usize, DropPosition)>,
+    // Whether `on_drop_validate` refused the drop target, drawn in the deny color
+    drop_denied: bool,
+
+    // Rows sliding to where they were last laid out
+    slide: Slide,
+
+    // The widths of `TreeColumns`, the widths the app last configured, and a resize under way
+    column_widths: Vec<f32>,
+    configured_widths: Vec<f32>,
+    resizing: Option<ColumnResize>,
+}
+
+impl TreeState {
+    /// Where dragged branches would land, from the tree's own drag or one from another widget.
+    fn drop_target(&self) -> Option<(usize, &DropPosition)> {
+        match &self.drag_active {
+            Some(drag) => drag.drop_target.map(|target| (target, &drag.drop_position)),
+            None => self.external_drop.as_ref().map(|(target, position)| (*target, position)),
+        }
+    }
+
+    /// The external id of a branch as of the last layout, as `on_select` reports it.
+    fn external_id(&self, id: usize) -> usize {
+        self.known_ids.get(id).copied().unwrap_or(id)
+    }
+
+    /// The expansion, selection and custom order by external id, leaving out branches without one.
+    fn snapshot(&self) -> Snapshot {
+        let external = |id: usize| Some(self.external_id(id)).filter(|&external| external != 0);
+
+        let order = match &self.branch_order {
+            Some(order) if self.reordered => order
+                .iter()
+                .filter_map(|bs| Some((external(bs.id)?, bs.parent_id.and_then(external))))
+                .collect(),
+            _ => Vec::new(),
+        };
+
+        Snapshot {
+            expanded: self.expanded.iter().filter_map(|&id| external(id)).collect(),
+            selected: self.selected.iter().filter_map(|&id| external(id)).collect(),
+            order,
+        }
+    }
+}
+
+/// A parent pinned to the top of the viewport by `sticky_headers`.
+#[derive(Debug, Clone, Copy)]
+struct StickyRow {
+    index: usize,
+    // How far the row is drawn below where it was laid out
+    offset: f32,
+    // Where it is drawn
+    bounds: Rectangle,
+}
+
+/// Rows sliding from where they were drawn to where they were laid out, by internal id.
+#[derive(Debug, Clone, Default)]
+struct Slide {
+    // Where each row was last laid out, relative to the tree
+    to: HashMap<usize, f32>,
+    // Where the rows moving started from
+    from: HashMap<usize, f32>,
+    // Rows just shown, revealed from under the ancestor they were hidden in
+    under: HashMap<usize, usize>,
+    animation: Option<Animation<bool>>,
+    progress: f32,
+}
+
+impl Slide {
+    /// Where a row is drawn, relative to the tree.
+    fn y(&self, id: usize) -> Option<f32> {
+        let to = *self.to.get(&id)?;
+        Some(match self.from.get(&id) {
+            Some(from) => from + (to - from) * self.progress,
+            None => to,
+        })
+    }
+
+    /// Moves the rows to the positions of a new layout, from where they are drawn now when
+    /// `animate` is set. Rows not drawn before come out from under their closest ancestor that was.
+    fn retarget(
+        &mut self,
+        to: HashMap<usize, f32>,
+        parent: impl Fn(usize) -> Option<usize>,
+        animate: Option<Duration>,
+        now: Instant,
+    ) {
+        if to == self.to {
+            return;
+        }
+
+        let Some(duration) = animate.filter(|_| !self.to.is_empty()) else {
+            *self = Self { to, ..Self::default() };
+            return;
+        };
+
+        let drawn: HashMap<usize, f32> = self.to.keys().filter_map(|&id| Some((id, self.y(id)?))).collect();
+        let mut from = HashMap::new();
+        let mut under = HashMap::new();
+
+        for &id in to.keys() {
+            if let Some(&y) = drawn.get(&id) {
+                from.insert(id, y);
+                if let Some(&ancestor) = self.under.get(&id).filter(|ancestor| to.contains_key(ancestor)) {
+                    under.insert(id, ancestor);
+                }
+                continue;
+            }
+
+            let mut ancestor = parent(id);
+            while let Some(candidate) = ancestor {
+                if let Some(&y) = drawn.get(&candidate) {
+                    from.insert(id, y);
+                    under.insert(id, candidate);
+                    break;
+                }
+                ancestor = parent(candidate);
+            }
+        }
+
+        *self = Self {
+            to,
+            from,
+            under,
+            animation: Some(Animation::new(false).easing(Easing::EaseOut).duration(duration).go(true, now)),
+            progress: 0.0,
+        };
+    }
+
+    /// Moves on to the frame at `now`, returning whether the rows are still sliding.
+    fn advance(&mut self, now: Instant) -> bool {
+        let Some(animation) = &self.animation else {
+            return false;
+        };
+
+        if animation.is_animating(now) {
+            self.progress = animation.interpolate(0.0, 1.0, now);
+            true
+        } else {
+            *self = Self { to: std::mem::take(&mut self.to), ..Self::default() };
+            false
+        }
+    }
+
+    /// Keeps the rows under the internal ids they have after the branches changed.
+    fn remap(&mut self, remap: impl Fn(usize) -> Option<usize>) {
+        let rows = |rows: &HashMap<usize, f32>| rows.iter().filter_map(|(&id, &y)| Some((remap(id)?, y))).collect();
+        self.to = rows(&self.to);
+        self.from = rows(&self.from);
+        self.under = self.under.iter().filter_map(|(&id, &ancestor)| Some((remap(id)?, remap(ancestor)?))).collect();
+    }
+}
+
+/// A column being resized by dragging the edge of its title.
+#[derive(Debug, Clone, Copy)]
+struct ColumnResize {
+    column: usize,
+    origin_x: f32,
+    origin_width: f32,
+}
+
+/// A branch being renamed and the name typed so far.
+#[derive(Debug, Clone)]
+struct Editing {
+    id: usize,
+    draft: String,
+    /// Byte offset of the caret in `draft`
+    caret: usize,
+    /// Other end of the selection, the caret being one end
+    anchor: Option<usize>,
+}
+
+impl Editing {
+    /// Starts editing `draft` with all of it selected, ready to be typed over.
+    fn new(id: usize, draft: String) -> Self {
+        Self {
+            id,
+            caret: draft.len(),
+            anchor: (!draft.is_empty()).then_some(0),
+            draft,
+        }
+    }
+
+    /// The selected byte range, if any text is selected.
+    fn selection(&self) -> Option<std::ops::Range<usize>> {
+        let anchor = self.anchor.filter(|&anchor| anchor != self.caret)?;
+        Some(anchor.min(self.caret)..anchor.max(self.caret))
+    }
+
+    fn previous(&self, offset: usize) -> usize {
+        self.draft[..offset].char_indices().next_back().map_or(0, |(i, _)| i)
+    }
+
+    fn next(&self, offset: usize) -> usize {
+        self.draft[offset..].chars().next().map_or(offset, |c| offset + c.len_utf8())
+    }
+
+    /// Moves the caret to `offset`, extending the selection from where it was when `extend`.
+    fn move_to(&mut self, offset: usize, extend: bool) {
+        if extend {
+            self.anchor.get_or_insert(self.caret);
+        } else {
+            self.anchor = None;
+        }
+        self.caret = offset;
+    }
+
+    /// Moves the caret a character back or forward, or to the matching end of the selection.
+    fn step(&mut self, forward: bool, extend: bool) {
+        let offset = match self.selection() {
+            Some(selection) if !extend => if forward { selection.end } else { selection.start },
+            _ if forward => self.next(self.caret),
+            _ => self.previous(self.caret),
+        };
+        self.move_to(offset, extend);
+    }
+
+    /// Replaces the selection, or inserts at the caret, with `text`.
+    fn insert(&mut self, text: &str) {
+        let range = self.selection().unwrap_or(self.caret..self.caret);
+        self.draft.replace_range(range.clone(), text);
+        self.caret = range.start + text.len();
+        self.anchor = None;
+    }
+
+    /// Erases the selection, or the character before or after the caret.
+    fn erase(&mut self, forward: bool) {
+        let range = match self.selection() {
+            Some(selection) => selection,
+            None if forward => self.caret..self.next(self.caret),
+            None => self.previous(self.caret)..self.caret,
+        };
+        self.draft.replace_range(range.clone(), "");
+        self.caret = range.start;
+        self.anchor = None;
+    }
+}
+
+/// A change asked for by an operation, by external branch id.
+#[derive(Debug, Clone)]
+enum Request {
+    Expand(usize, bool),
+    Select(HashSet<usize>),
+    Focus(usize),
+    Reveal(usize),
+}
+
+/// Passed to operations with the bounds of the row of the branch with this external id, once
+/// [`reveal`] opened its ancestors.
+struct Revealed(usize);
+
+impl widget::operation::Focusable for TreeState {
+    fn is_focused(&self) -> bool {
+        self.focused.is_some()
+    }
+
+    fn focus(&mut self) {
+        // The first root branch is always shown
+        self.focused.get_or_insert(0);
+    }
+
+    fn unfocus(&mut self) {
+        self.focused = None;
+        self.editing = None;
+    }
 }
 
 /// Combined state that includes both animation state and text state
@@ -145,7 +659,7 @@ where
 
 #[derive(Debug, Clone)]
 struct DragPending {
-    start_position: Point,
+    press: dnd::Press,
     branch_ids: Vec<usize>,
     primary_branch_id: usize, // Actual dragged branch, for overlay rendering
     branch_bounds: Rectangle,
@@ -154,6 +668,7 @@ struct DragPending {
 
 #[derive(Debug, Clone)]
 struct DragActive {
+    press: dnd::Press,
     dragged_nodes: Vec<usize>,
     primary_node: usize, // Actual dragged branch, for overlay rendering
     drag_start_bounds: Rectangle,
@@ -161,6 +676,8 @@ struct DragActive {
     current_position: Point,
     drop_target: Option<usize>,
     drop_position: DropPosition,
+    outside: bool, // Whether the cursor left the tree, reported once through `on_drag_out`
+    scrolled_at: Option<Instant>, // The last frame `on_auto_scroll` was asked to scroll at
 }
 
 #[derive(Debug, Clone)]
@@ -173,7 +690,6 @@ struct SelectionRect {
 impl<'a, Message, Theme, Renderer> 
     TreeHandle<'a, Message, Theme, Renderer>
 where 
-    Message: Clone,
     Theme: Catalog,
     Renderer: iced::advanced::Renderer  + iced::advanced::text::Renderer,
 {
@@ -188,6 +704,7 @@ where
 
         let mut branches = Vec::new();
         let mut branch_content = Vec::new();
+        let mut branch_cells = Vec::new();
         let mut next_id = 0usize;
 
         // Flatten the tree structure into arrays
@@ -198,6 +715,7 @@ where
             next_id: &mut usize,
             branches: &mut Vec<Branch_>,
             branch_content: &mut Vec<Element<'a, Message, Theme, Renderer>>,
+            branch_cells: &mut Vec<Vec<Element<'a, Message, Theme, Renderer>>>,
             width: &mut Length,
             height: &mut Length,
         ) where
@@ -207,6 +725,7 @@ where
             *next_id += 1;
             
             let has_children = !branch.children.is_empty();
+            let disabled = branch.disabled || parent_id.is_some_and(|parent| branches[parent].disabled);
             
             branches.push(Branch_ {
                 id: current_id,
@@ -216,6 +735,10 @@ where
                 has_children,
                 accepts_drops: branch.accepts_drops,
                 draggable: branch.draggable,
+                lazy: branch.lazy,
+                name: branch.name,
+                label: branch.label,
+                disabled,
                 align_x: branch.align_x,
                 align_y: branch.align_y,
             });
@@ -224,6 +747,7 @@ where
             *width = width.enclose(size_hint.width);
             *height = height.enclose(size_hint.height);
             branch_content.push(branch.content);
+            branch_cells.push(branch.cells);
             
             for child in branch.children {
                 flatten_branch(
@@ -233,6 +757,7 @@ where
                     next_id,
                     branches,
                     branch_content,
+                    branch_cells,
                     width,
                     height,
                 );
@@ -247,6 +772,7 @@ where
                 &mut next_id,
                 &mut branches,
                 &mut branch_content,
+                &mut branch_cells,
                 &mut width,
                 &mut height,
             );
@@ -266,27 +792,84 @@ where
             }
         }
 
+        let cell_start = branch_cells
+            .iter()
+            .scan(0, |start, cells| {
+                let first = *start;
+                *start += cells.len();
+                Some(first)
+            })
+            .collect();
+
         Self {
             branches,
             branch_content,
+            branch_cells,
+            cell_start,
             width,
             height,
             spacing: 4.0,
-            indent: 20.0,
+            indent: crate::depth::DEFAULT_INDENT,
             padding_x: 10.0,
             padding_y: 5.0,
             on_drop: None,
             on_select: None,
+            on_toggle: None,
+            on_expand: None,
+            on_rename: None,
+            on_context_menu: None,
+            on_drag_out: None,
+            on_external_drop: None,
+            accepts_external_drops: false,
+            on_drop_validate: None,
+            on_check: None,
+            on_auto_scroll: None,
+            auto_scroll: (AUTO_SCROLL_EDGE, AUTO_SCROLL_SPEED),
+            checkboxes: false,
+            checked: None,
             force_reset_order: false,
             ext_to_int,
             int_to_ext,
             expand_icon: None,
             collapse_icon: None,
+            direction: None,
+            id: None,
+            virtualized: false,
+            show_guides: false,
+            sticky_headers: 0,
+            animations: true,
+            animation_duration: ANIMATION_DURATION,
+            columns: None,
+            on_column_resize: None,
+            matches: None,
+            expanded: None,
+            snapshot: None,
             class: Theme::default(),
         }
     }
 
-    /// Sets the message to emit when a drop occurs
+    /// Sets the message to emit every frame a drag is held near the top or bottom of the
+    /// viewport, with how far to scroll down, negative to scroll up, for
+    /// [`scrollable::scroll_by`](iced::widget::scrollable::scroll_by) on the scrollable
+    /// around the tree. The closer to the edge, the faster it scrolls.
+    pub fn on_auto_scroll<F>(mut self, f: F) -> Self
+    where
+        F: Fn(f32) -> Message + 'a,
+    {
+        self.on_auto_scroll = Some(Box::new(f));
+        self
+    }
+
+    /// Sets how close to the edge of the viewport a drag starts scrolling, and how many
+    /// pixels per second it scrolls at the very edge, 32 and 600 by default.
+    pub fn auto_scroll(mut self, edge: f32, speed: f32) -> Self {
+        self.auto_scroll = (edge, speed);
+        self
+    }
+
+    /// Sets the message to emit when a drop occurs, or when Alt and the arrow keys move the
+    /// focused branch: up or down past a sibling, left out of its parent, right into the
+    /// sibling above.
     pub fn on_drop<F>(mut self, f: F) -> Self 
     where
         F: Fn(DropInfo) -> Message + 'a,
@@ -304,6 +887,27 @@ where
         self
     }
 
+    /// Sets the message to emit when a branch is expanded or collapsed, with its id and
+    /// whether it is now expanded
+    pub fn on_toggle<F>(mut self, f: F) -> Self
+    where
+        F: Fn(usize, bool) -> Message + 'a,
+    {
+        self.on_toggle = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the message to emit when a [lazy](Branch::lazy) branch whose children are not
+    /// loaded yet is expanded, with its id, so the app can load them and rebuild the tree.
+    /// The branch stays expanded and shows the children once they are given.
+    pub fn on_expand<F>(mut self, f: F) -> Self
+    where
+        F: Fn(usize) -> Message + 'a,
+    {
+        self.on_expand = Some(Box::new(f));
+        self
+    }
+
     /// Sets the collapse icon (shown when branch is expanded)
     pub fn collapse_icon(
         mut self,
@@ -322,6 +926,125 @@ where
         self
     }
 
+    /// Sets the message to emit when a [renamable](Branch::renamable) branch is renamed, with
+    /// its id and the new name. Double-clicking the branch or pressing F2 while it is focused
+    /// edits its name in place, starting with all of it selected. The arrow keys, Home and End
+    /// move the caret, with Shift to select, and Ctrl/Cmd+A, C, X and V select all, copy, cut
+    /// and paste. Enter commits the name and Escape cancels.
+    pub fn on_rename<F>(mut self, f: F) -> Self
+    where
+        F: Fn(usize, String) -> Message + 'a,
+    {
+        self.on_rename = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the message to emit when a branch is right-clicked, with its id and the position
+    /// of the cursor. The branch is selected first unless it already is, so the menu acts on
+    /// the selection.
+    ///
+    /// The right click is not captured, so wrapping the tree in a
+    /// [`ContextMenu`](crate::context_menu::ContextMenu) opens it where the branch was clicked,
+    /// with the entries the app builds for the branch it was told about.
+    pub fn on_context_menu<F>(mut self, f: F) -> Self
+    where
+        F: Fn(usize, Point) -> Message + 'a,
+    {
+        self.on_context_menu = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the message to emit when branches dragged out of the tree leave its bounds, with
+    /// their ids and the cursor position, so the app can hand them to a drop zone elsewhere.
+    /// It is emitted again each time the cursor leaves after coming back.
+    ///
+    /// The drag is also published as a [`dnd::TREE`] payload, which
+    /// [`DropTarget`](crate::dnd::DropTarget)s take without any help from the app.
+    pub fn on_drag_out<F>(mut self, f: F) -> Self
+    where
+        F: Fn(Vec<usize>, Point) -> Message + 'a,
+    {
+        self.on_drag_out = Some(Box::new(f));
+        self
+    }
+
+    /// Checks every drop target while branches are dragged over it, beyond
+    /// [`Branch::accepts_drops`]. A refused target is drawn in the deny color, and releasing
+    /// there drops nothing. Drops from other widgets are checked as well.
+    pub fn on_drop_validate<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&DropInfo) -> bool + 'a,
+    {
+        self.on_drop_validate = Some(Box::new(f));
+        self
+    }
+
+    /// Lets payloads dragged from other widgets, such as another tree, be dropped on the
+    /// branches, shown with the same indicators as the tree's own drags. The drop is reported
+    /// through [`on_external_drop`](Self::on_external_drop).
+    pub fn accepts_external_drops(mut self, accepts: bool) -> Self {
+        self.accepts_external_drops = accepts;
+        self
+    }
+
+    /// Sets the message to emit when a payload from another widget is dropped on the tree,
+    /// with where it landed and the payload. The `dragged_ids` of the [`DropInfo`] are the
+    /// payload's items, ids in the widget they came from.
+    pub fn on_external_drop<F>(mut self, f: F) -> Self
+    where
+        F: Fn(DropInfo, dnd::Payload) -> Message + 'a,
+    {
+        self.on_external_drop = Some(Box::new(f));
+        self
+    }
+
+    /// Expands the branches with these external ids and collapses every other, applied
+    /// whenever they differ from the last ones passed or the branches change. With
+    /// [`on_toggle`](Self::on_toggle) the app can own the expansion state, passing back the
+    /// branches it keeps expanded; a toggle only takes effect once it is passed back. Branches
+    /// are no longer expanded on their own when they first show up or gain children.
+    pub fn expanded(mut self, ids: impl IntoIterator<Item = usize>) -> Self {
+        self.expanded = Some(ids.into_iter().collect());
+        self
+    }
+
+    /// Shows a checkbox before each branch. Checking a branch checks everything under it,
+    /// and a parent is checked, unchecked or [`CheckState::Indeterminate`] by its children.
+    /// Clicking a checkbox or pressing Space on the focused branch toggles it, Ctrl/Cmd+Space
+    /// still selects.
+    pub fn with_checkboxes(mut self) -> Self {
+        self.checkboxes = true;
+        self
+    }
+
+    /// Sets the message to emit when a checkbox is toggled, with the [`CheckState`] of every
+    /// branch by external id.
+    pub fn on_check<F>(mut self, f: F) -> Self
+    where
+        F: Fn(HashMap<usize, CheckState>) -> Message + 'a,
+    {
+        self.on_check = Some(Box::new(f));
+        self
+    }
+
+    /// Checks the branches with these external ids, and everything under them, and unchecks
+    /// every other, applied whenever they differ from the last ones passed or the branches
+    /// change. With [`on_check`](Self::on_check) the app can own the checked branches, like
+    /// [`expanded`](Self::expanded) does for the expansion.
+    pub fn checked(mut self, ids: impl IntoIterator<Item = usize>) -> Self {
+        self.checked = Some(ids.into_iter().collect());
+        self
+    }
+
+    /// Restores the expansion, selection and order of a [`Snapshot`] taken with the
+    /// [`snapshot`] operation, applied when the tree is first shown and whenever it differs
+    /// from the last one passed. Branches are matched by external id: those the snapshot does
+    /// not know keep their given place under their parent, and those gone are skipped.
+    pub fn snapshot(mut self, snapshot: Snapshot) -> Self {
+        self.snapshot = Some(snapshot);
+        self
+    }
+
     /// Forces the tree to reset its internal ordering state.
     /// This is useful when the external structure has changed and
     /// the tree needs to reflect the new hierarchy based on external IDs.
@@ -354,56 +1077,174 @@ where
         self 
     }
 
-    /// Sets the class of the [`Tree`].
-    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self { 
-        self.class = class.into(); 
-        self 
+    /// Sets the [`Direction`] of the [`Tree`], instead of the one set with [`direction::set`].
+    ///
+    /// Right to left indents branches from the right, with their arrows pointing left.
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.direction = Some(direction);
+        self
     }
 
-    /// Sets the padding of the cells of the [`Tree`].
-    pub fn padding(self, padding: impl Into<Pixels>) -> Self {
-        let padding = padding.into();
-        self.padding_x(padding).padding_y(padding)
+    /// Sets the [`widget::Id`] used to [`expand`], [`collapse`], [`select`] or [`focus`]
+    /// branches from `update`.
+    pub fn id(mut self, id: impl Into<widget::Id>) -> Self {
+        self.id = Some(id.into());
+        self
     }
 
-    /// Sets the horizontal padding of the cells of the [`Tree`].
-    pub fn padding_x(mut self, padding: impl Into<Pixels>) -> Self {
-        self.padding_x = padding.into().0;
+    /// Only lays out and draws the branches in view, plus half a view above and below, for
+    /// trees of thousands of branches inside a `scrollable`.
+    ///
+    /// Branches out of view keep the height they were last laid out with, or the height of
+    /// one line until they are first seen, so the scroll extent stays put and drops onto them
+    /// land where expected.
+    pub fn virtualized(mut self) -> Self {
+        self.virtualized = true;
         self
     }
 
-    /// Sets the vertical padding of the cells of the [`Tree`].
-    pub fn padding_y(mut self, padding: impl Into<Pixels>) -> Self {
-        self.padding_y = padding.into().0;
+    /// Draws guide lines in the style's `line_color` from each parent's arrow down to its
+    /// children, ending in an elbow at the last child.
+    pub fn show_guides(mut self, show: bool) -> Self {
+        self.show_guides = show;
         self
     }
 
-    // Helper to get ordered indices from saved state
-    fn get_ordered_indices(&self, state: &TreeState) -> Vec<usize> {
-        if let Some(ref branch_order) = state.branch_order {
-            let mut indices = Vec::new();
-            
-            for bs in branch_order {
-                if let Some(idx) = self.branches.iter().position(|b| b.id == bs.id) {
-                    indices.push(idx);
-                }
-            }
-            
-            // Add any new branches not in saved state
-            for (i, branch) in self.branches.iter().enumerate() {
-                if !branch_order.iter().any(|bs| bs.id == branch.id) {
-                    indices.push(i);
-                }
-            }
-            
-            indices
-        } else {
-            (0..self.branches.len()).collect()
+    /// Shows only the branches whose label, or name if they have none, contains `query`
+    /// ignoring case, along with their ancestors, which open while they have matches under
+    /// them. An empty `query` shows every branch.
+    ///
+    /// The expansion is kept: branches opened by the filter close again when it no longer
+    /// needs them, and the rest stay as they were. [`find_match`] finds the match in a label
+    /// to highlight in the content.
+    pub fn filter(self, query: &str) -> Self {
+        if query.trim().is_empty() {
+            return self;
         }
+
+        self.filter_internal(|branch| {
+            branch.label.as_deref().or(branch.name.as_deref()).is_some_and(|text| find_match(text, query).is_some())
+        })
     }
-    
-    // Helper to get effective branch info
-    fn get_branch_info(&self, index: usize, state: &TreeState) -> (usize, Option<usize>, u16) {
+
+    /// Shows only the branches for which `matches` is true by external id, along with their
+    /// ancestors, like [`TreeHandle::filter`].
+    pub fn filter_with(self, matches: impl Fn(usize) -> bool) -> Self {
+        let ids: Vec<usize> = (0..self.branches.len()).map(|id| self.preferred_id(id)).collect();
+        self.filter_internal(|branch| matches(ids[branch.id]))
+    }
+
+    fn filter_internal(mut self, matches: impl Fn(&Branch_) -> bool) -> Self {
+        self.matches = Some(self.branches.iter().filter(|branch| matches(branch)).map(|branch| branch.id).collect());
+        self
+    }
+
+    /// Pins the parents of the branches scrolled past to the top of the viewport, up to
+    /// `max_depth` levels deep, each pushed up and away as the last of its children leaves.
+    ///
+    /// A pinned parent stands in for its row: clicking it selects it, and its arrow collapses it.
+    pub fn sticky_headers(mut self, max_depth: u16) -> Self {
+        self.sticky_headers = max_depth;
+        self
+    }
+
+    /// Whether rows slide to where they move, on by default: the children of a branch being
+    /// expanded slide out from under it, the rows below make room for them or close up after
+    /// a collapse, and rows part for a drop indicator and settle after a drop.
+    pub fn animations(mut self, animations: bool) -> Self {
+        self.animations = animations;
+        self
+    }
+
+    /// Sets how long rows take to slide with [`TreeHandle::animations`], 200ms by default.
+    pub fn animation_duration(mut self, duration: Duration) -> Self {
+        self.animation_duration = duration;
+        self
+    }
+
+    /// Shows the tree as a table, each branch's cells from [`branch_with_cells`] aligned in
+    /// columns after the tree's own, under an optional header.
+    ///
+    /// Dragging the right edge of a column's title resizes it. The widths are kept in the
+    /// tree's state until the app configures a column with a different width.
+    pub fn columns(mut self, columns: TreeColumns) -> Self {
+        self.columns = Some(columns);
+        self
+    }
+
+    /// Sets the message produced with the new width when a column resize ends.
+    pub fn on_column_resize<F>(mut self, f: F) -> Self
+    where
+        F: Fn(usize, f32) -> Message + 'a,
+    {
+        self.on_column_resize = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the style of the [`Tree`], such as [`flat`] or one of the other presets.
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the class of the [`Tree`].
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self { 
+        self.class = class.into(); 
+        self 
+    }
+
+    /// Sets the padding of the cells of the [`Tree`].
+    pub fn padding(self, padding: impl Into<Pixels>) -> Self {
+        let padding = padding.into();
+        self.padding_x(padding).padding_y(padding)
+    }
+
+    /// Sets the horizontal padding of the cells of the [`Tree`].
+    pub fn padding_x(mut self, padding: impl Into<Pixels>) -> Self {
+        self.padding_x = padding.into().0;
+        self
+    }
+
+    /// Sets the vertical padding of the cells of the [`Tree`].
+    pub fn padding_y(mut self, padding: impl Into<Pixels>) -> Self {
+        self.padding_y = padding.into().0;
+        self
+    }
+
+    // The direction branches are laid out in
+    fn layout_direction(&self) -> Direction {
+        self.direction.unwrap_or_else(direction::get)
+    }
+
+    // Helper to get ordered indices from saved state
+    fn get_ordered_indices(&self, state: &TreeState) -> Vec<usize> {
+        if let Some(ref branch_order) = state.branch_order {
+            let mut indices = Vec::new();
+            
+            for bs in branch_order {
+                if let Some(idx) = self.branches.iter().position(|b| b.id == bs.id) {
+                    indices.push(idx);
+                }
+            }
+            
+            // Add any new branches not in saved state
+            for (i, branch) in self.branches.iter().enumerate() {
+                if !branch_order.iter().any(|bs| bs.id == branch.id) {
+                    indices.push(i);
+                }
+            }
+            
+            indices
+        } else {
+            (0..self.branches.len()).collect()
+        }
+    }
+    
+    // Helper to get effective branch info
+    fn get_branch_info(&self, index: usize, state: &TreeState) -> (usize, Option<usize>, u16) {
         let branch = &self.branches[index];
         
         if let Some(ref branch_order) = state.branch_order
@@ -413,6 +1254,104 @@ where
         
         (branch.id, branch.parent_id, branch.depth)
     }
+
+    // The parents of the branches drawn, and those followed by a sibling further down, whose
+    // guide line carries on past them
+    fn guides(&self, state: &TreeState, ordered: &[usize]) -> (HashMap<usize, Option<usize>>, HashSet<usize>) {
+        let mut parents = HashMap::new();
+        let mut last_children = HashMap::new();
+        let mut continued = HashSet::new();
+
+        for &i in ordered {
+            if !state.visible_branches.get(i).copied().unwrap_or(false) {
+                continue;
+            }
+            let (id, parent_id, _) = self.get_branch_info(i, state);
+            if state.drag_active.as_ref().is_some_and(|drag| drag.dragged_nodes.contains(&id)) {
+                continue;
+            }
+
+            parents.insert(id, parent_id);
+            if let Some(previous) = last_children.insert(parent_id, id) {
+                continued.insert(previous);
+            }
+        }
+
+        (parents, continued)
+    }
+
+    /// The parents pinned to the top of the viewport, outermost first.
+    fn sticky_rows(&self, state: &TreeState, ordered: &[usize], bounds: Rectangle, viewport: &Rectangle) -> Vec<StickyRow> {
+        if self.sticky_headers == 0 || viewport.y <= bounds.y {
+            return Vec::new();
+        }
+
+        // Every row drawn, as (index, id, parent, depth, y, height)
+        let mut rows = Vec::new();
+        let mut y = bounds.y + self.top();
+        for &i in ordered {
+            if !state.visible_branches.get(i).copied().unwrap_or(false) {
+                continue;
+            }
+            let (id, parent_id, depth) = self.get_branch_info(i, state);
+            if state.drag_active.as_ref().is_some_and(|drag| drag.dragged_nodes.contains(&id)) {
+                continue;
+            }
+
+            let height = state.branch_heights.get(i).copied().unwrap_or(0.0);
+            rows.push((i, id, parent_id, depth, y, height));
+            y += height + self.spacing;
+        }
+        let positions: HashMap<usize, usize> = rows.iter().enumerate().map(|(row, &(_, id, ..))| (id, row)).collect();
+
+        let mut pinned = Vec::new();
+        let mut top = viewport.y;
+        for depth in 0..self.sticky_headers {
+            // The parent at this depth of the row showing under the headers pinned so far
+            let Some(mut row) = rows.iter().position(|&(.., y, height)| y + height > top) else {
+                break;
+            };
+            while rows[row].3 > depth {
+                match rows[row].2.and_then(|parent| positions.get(&parent)) {
+                    Some(&parent) => row = parent,
+                    None => break,
+                }
+            }
+
+            let (index, _, _, row_depth, row_y, height) = rows[row];
+            if row_depth != depth || row_y >= top {
+                break;
+            }
+
+            // Pushed up by the end of its children
+            let Some(&(.., last_y, last_height)) = rows[row + 1..].iter().take_while(|&&(.., d, _, _)| d > depth).last() else {
+                break;
+            };
+            let y = top.min(last_y + last_height - height);
+
+            pinned.push(StickyRow {
+                index,
+                offset: y - row_y,
+                bounds: Rectangle { x: bounds.x, y, width: bounds.width, height },
+            });
+            top = y + height;
+        }
+
+        pinned
+    }
+
+    /// Moves a cursor over a pinned parent back to the row it stands in for.
+    fn unpin_cursor(&self, state: &TreeState, ordered: &[usize], bounds: Rectangle, viewport: &Rectangle, cursor: mouse::Cursor) -> mouse::Cursor {
+        let Some(position) = cursor.position_over(*viewport) else {
+            return cursor;
+        };
+
+        self.sticky_rows(state, ordered, bounds, viewport)
+            .iter()
+            .rev()
+            .find(|sticky| sticky.bounds.contains(position))
+            .map_or(cursor, |sticky| mouse::Cursor::Available(Point::new(position.x, position.y - sticky.offset)))
+    }
     
     // Determines if a branch is visible
     fn is_branch_visible(&self, index: usize, state: &TreeState) -> bool {
@@ -465,6 +1404,29 @@ where
         false
     }
 
+    /// The branches a virtualized tree lays out, those around the last viewport seen, and the
+    /// area they cover.
+    fn realized_branches(&self, state: &TreeState, ordered_indices: &[usize], heights: &[f32]) -> (Vec<bool>, (f32, f32)) {
+        let viewport = state.viewport.unwrap_or(Rectangle::new(Point::ORIGIN, Size::new(0.0, VIRTUAL_HEIGHT)));
+        let top = viewport.y - viewport.height / 2.0;
+        let bottom = viewport.y + viewport.height * 1.5;
+
+        let mut realized = vec![false; self.branches.len()];
+        let mut y = self.top();
+
+        for &i in ordered_indices {
+            if !state.visible_branches.get(i).copied().unwrap_or(false) {
+                continue;
+            }
+
+            let height = heights.get(i).copied().unwrap_or(LINE_HEIGHT);
+            realized[i] = y + height >= top && y <= bottom;
+            y += height + self.spacing;
+        }
+
+        (realized, (top, bottom))
+    }
+
     /// Calculate drop position based on mouse position
     fn calculate_drop_position(
         &self, 
@@ -546,8 +1508,1060 @@ where
                 }
             }
         }
-        
-        newly_has_children
+        
+        newly_has_children
+    }
+
+    /// Expands or collapses a branch and reports it through `on_toggle`, and through
+    /// `on_expand` when its children still have to be loaded.
+    ///
+    /// When the app owns the expansion through [`TreeHandle::expanded`], the branch only
+    /// changes once the app passes it back, so a toggle it ignores leaves it as it was.
+    fn set_expanded(&self, state: &mut TreeState, id: usize, expanded: bool, shell: &mut Shell<'_, Message>) {
+        if self.is_disabled(id) {
+            return;
+        }
+
+        if self.expanded.is_none() {
+            if expanded {
+                state.expanded.insert(id);
+            } else {
+                state.expanded.remove(&id);
+            }
+        }
+        // Toggled by hand, it stays as it is once the filter is gone
+        state.filter_opened.remove(&id);
+
+        if let Some(on_toggle) = &self.on_toggle {
+            shell.publish(on_toggle(self.preferred_id(id), expanded));
+        }
+
+        if expanded
+            && let Some(on_expand) = &self.on_expand
+            && self.branches.get(id).is_some_and(|b| b.lazy && !b.has_children)
+        {
+            shell.publish(on_expand(self.preferred_id(id)));
+        }
+    }
+
+    /// Carries expansion, selection and focus over by external id when the app rebuilt the
+    /// tree with different branches, such as children loaded on expansion.
+    fn remap_state(&self, state: &mut TreeState) {
+        if state.known_ids == self.int_to_ext {
+            return;
+        }
+
+        let mut slide = std::mem::take(&mut state.slide);
+        let remap = |id: usize| match state.known_ids.get(id) {
+            Some(&external) if external != 0 => self.ext_to_int.get(&external).copied(),
+            _ => (id < self.branches.len()).then_some(id),
+        };
+
+        let mut expanded: HashSet<usize> = state.expanded.iter().filter_map(|&id| remap(id)).collect();
+        let selected = state.selected.iter().filter_map(|&id| remap(id)).collect();
+        let focused = state.focused.and_then(remap);
+        let anchor = state.anchor.and_then(remap);
+
+        // Branches the tree has not seen before start expanded, as on creation
+        if self.expanded.is_none() {
+            let known: HashSet<usize> = state.known_ids.iter().copied().filter(|&id| id != 0).collect();
+            for branch in &self.branches {
+                if branch.has_children && branch.external_id != 0 && !known.contains(&branch.external_id) {
+                    expanded.insert(branch.id);
+                }
+            }
+        }
+
+        state.expanded = expanded;
+        state.selected = selected;
+        state.focused = focused;
+        state.anchor = anchor;
+        state.checked = state.checked.iter().filter_map(|&id| remap(id)).collect();
+        state.filter_ancestors = state.filter_ancestors.iter().filter_map(|&id| remap(id)).collect();
+        state.filter_opened = state.filter_opened.iter().filter_map(|&id| remap(id)).collect();
+        state.editing = state.editing.take().and_then(|editing| remap(editing.id).map(|id| Editing { id, ..editing }));
+        slide.remap(remap);
+        state.hovered = None;
+        state.hovered_handle = None;
+        state.drag_pending = None;
+        if let Some(drag) = state.drag_active.take() {
+            drag.press.cancel();
+        }
+        state.external_drop = None;
+        state.branch_order = None;
+        state.reordered = false;
+        state.slide = slide;
+        state.known_ids = self.int_to_ext.clone();
+    }
+
+    /// Expands the branches passed with [`TreeHandle::expanded`] when they differ from the last
+    /// ones, or always when `force` is set because the branches changed.
+    fn sync_expanded(&self, state: &mut TreeState, force: bool) {
+        let Some(expanded) = &self.expanded else {
+            return;
+        };
+
+        if !force && state.synced_expanded.as_ref() == Some(expanded) {
+            return;
+        }
+
+        state.expanded = expanded.iter().filter_map(|external| self.ext_to_int.get(external).copied()).collect();
+        state.synced_expanded = Some(expanded.clone());
+    }
+
+    /// Applies the snapshot passed with [`TreeHandle::snapshot`] when it differs from the last one.
+    fn sync_snapshot(&mut self, state: &mut TreeState) {
+        let Some(snapshot) = self.snapshot.take() else {
+            return;
+        };
+
+        if state.synced_snapshot.as_ref() != Some(&snapshot) {
+            let internal = |external: &usize| self.ext_to_int.get(external).copied();
+            state.expanded = snapshot.expanded.iter().filter_map(internal).collect();
+            state.selected = snapshot.selected.iter().filter_map(internal).collect();
+            state.anchor = None;
+
+            if !snapshot.order.is_empty() {
+                state.branch_order = Some(self.restored_order(&snapshot.order));
+                state.reordered = true;
+                self.update_has_children(state);
+            }
+
+            state.synced_snapshot = Some(snapshot.clone());
+        }
+
+        self.snapshot = Some(snapshot);
+    }
+
+    /// The order of a [`Snapshot`] by internal id. Branches it does not know go after the last
+    /// branch under their parent, and those whose parent is gone move to the top level.
+    fn restored_order(&self, order: &[(usize, Option<usize>)]) -> Vec<BranchState> {
+        let mut restored: Vec<BranchState> = Vec::new();
+
+        for &(external, parent) in order {
+            let Some(&id) = self.ext_to_int.get(&external) else {
+                continue;
+            };
+            if restored.iter().any(|bs| bs.id == id) {
+                continue;
+            }
+
+            // Parents come before their children, so a parent not restored yet is gone
+            let parent = parent
+                .and_then(|parent| self.ext_to_int.get(&parent))
+                .and_then(|&parent| restored.iter().find(|bs| bs.id == parent));
+            restored.push(BranchState {
+                id,
+                parent_id: parent.map(|bs| bs.id),
+                depth: parent.map_or(0, |bs| bs.depth + 1),
+            });
+        }
+
+        for branch in &self.branches {
+            if restored.iter().any(|bs| bs.id == branch.id) {
+                continue;
+            }
+
+            let parent = branch.parent_id.and_then(|parent| restored.iter().position(|bs| bs.id == parent));
+            let (index, depth) = match parent {
+                Some(position) => {
+                    let depth = restored[position].depth;
+                    let end = restored[position + 1..]
+                        .iter()
+                        .position(|bs| bs.depth <= depth)
+                        .map_or(restored.len(), |offset| position + 1 + offset);
+                    (end, depth + 1)
+                }
+                None => (restored.len(), 0),
+            };
+
+            restored.insert(index, BranchState {
+                id: branch.id,
+                parent_id: parent.map(|position| restored[position].id),
+                depth,
+            });
+        }
+
+        restored
+    }
+
+    /// Checks the branches passed with [`TreeHandle::checked`] when they differ from the last
+    /// ones, or always when `force` is set because the branches changed.
+    fn sync_checked(&self, state: &mut TreeState, force: bool) {
+        let Some(checked) = &self.checked else {
+            return;
+        };
+
+        if !force && state.synced_checked.as_ref() == Some(checked) {
+            return;
+        }
+
+        let roots: Vec<usize> = checked.iter().filter_map(|external| self.ext_to_int.get(external).copied()).collect();
+        let children = self.effective_children(state);
+        state.checked.clear();
+        for id in roots {
+            self.set_checked(state, &children, id, true);
+        }
+        state.synced_checked = Some(checked.clone());
+    }
+
+    /// The children of every branch by internal id, in the order they are shown.
+    fn effective_children(&self, state: &TreeState) -> Vec<Vec<usize>> {
+        let mut children = vec![Vec::new(); self.branches.len()];
+        for i in self.get_ordered_indices(state) {
+            if let Some(parent) = self.get_branch_info(i, state).1
+                && let Some(siblings) = children.get_mut(parent)
+            {
+                siblings.push(i);
+            }
+        }
+        children
+    }
+
+    /// Whether each branch is checked by internal id: a parent by its children, the others
+    /// by themselves.
+    fn check_states(&self, state: &TreeState) -> Vec<CheckState> {
+        let children = self.effective_children(state);
+        let mut states = vec![CheckState::Unchecked; self.branches.len()];
+
+        // Children come after their parent, so going backwards they are known first
+        for i in self.get_ordered_indices(state).into_iter().rev() {
+            states[i] = if children[i].is_empty() {
+                if state.checked.contains(&i) { CheckState::Checked } else { CheckState::Unchecked }
+            } else if children[i].iter().all(|&child| states[child] == CheckState::Checked) {
+                CheckState::Checked
+            } else if children[i].iter().all(|&child| states[child] == CheckState::Unchecked) {
+                CheckState::Unchecked
+            } else {
+                CheckState::Indeterminate
+            };
+        }
+
+        states
+    }
+
+    /// Checks or unchecks the branch `id` and everything under it, leaving disabled branches.
+    fn set_checked(&self, state: &mut TreeState, children: &[Vec<usize>], id: usize, checked: bool) {
+        if !self.is_disabled(id) {
+            if checked {
+                state.checked.insert(id);
+            } else {
+                state.checked.remove(&id);
+            }
+        }
+
+        for &child in children.get(id).into_iter().flatten() {
+            self.set_checked(state, children, child, checked);
+        }
+    }
+
+    /// Toggles the checkbox of the branch `id`, publishing `on_check`.
+    fn toggle_checked(&self, state: &mut TreeState, id: usize, shell: &mut Shell<'_, Message>) {
+        if self.is_disabled(id) {
+            return;
+        }
+
+        let checked = self.check_states(state)[id] != CheckState::Checked;
+        let children = self.effective_children(state);
+        self.set_checked(state, &children, id, checked);
+
+        if let Some(on_check) = &self.on_check {
+            let states = self
+                .check_states(state)
+                .into_iter()
+                .enumerate()
+                .map(|(id, check)| (self.preferred_id(id), check))
+                .collect();
+            shell.publish(on_check(states));
+        }
+    }
+
+    /// Where the rows start, below the header of the columns if there is one.
+    fn top(&self) -> f32 {
+        match &self.columns {
+            Some(columns) if columns.header => self.padding_y + LINE_HEIGHT + self.spacing,
+            _ => self.padding_y,
+        }
+    }
+
+    /// The header row of the columns, left to right.
+    fn header_bounds(&self, bounds: Rectangle) -> Option<Rectangle> {
+        self.columns.as_ref().filter(|columns| columns.header).map(|_| Rectangle {
+            y: bounds.y + self.padding_y,
+            height: LINE_HEIGHT,
+            ..bounds
+        })
+    }
+
+    /// Keeps the widths columns were resized to unless the app changed the configured width.
+    fn sync_columns(&self, state: &mut TreeState) {
+        let Some(columns) = &self.columns else {
+            state.column_widths.clear();
+            state.configured_widths.clear();
+            state.resizing = None;
+            return;
+        };
+
+        state.column_widths.resize(columns.columns.len(), DEFAULT_COLUMN_WIDTH);
+        state.configured_widths.resize(columns.columns.len(), f32::NAN);
+
+        for (i, column) in columns.columns.iter().enumerate() {
+            if state.configured_widths[i] != column.width {
+                state.configured_widths[i] = column.width;
+                state.column_widths[i] = column.width;
+            }
+            state.column_widths[i] = state.column_widths[i].max(column.min_width);
+        }
+
+        if state.resizing.is_some_and(|resize| resize.column >= columns.columns.len()) {
+            state.resizing = None;
+        }
+    }
+
+    /// Where each column starts and how wide it is, relative to the tree, left to right.
+    fn column_spans(&self, state: &TreeState) -> Vec<(f32, f32)> {
+        state
+            .column_widths
+            .iter()
+            .scan(self.padding_x, |x, &width| {
+                let start = *x;
+                *x += width;
+                Some((start, width))
+            })
+            .collect()
+    }
+
+    /// The column whose right edge in the header is at `point`, left to right.
+    fn resize_handle_at(&self, state: &TreeState, bounds: Rectangle, point: Point) -> Option<usize> {
+        let columns = self.columns.as_ref()?;
+        if !self.header_bounds(bounds)?.contains(point) {
+            return None;
+        }
+
+        self.column_spans(state)
+            .into_iter()
+            .enumerate()
+            .rev()
+            .find(|(i, (start, width))| {
+                let edge = bounds.x + start + width;
+                columns.columns[*i].resizable && (point.x - edge).abs() <= RESIZE_HANDLE_WIDTH / 2.0
+            })
+            .map(|(i, _)| i)
+    }
+
+    /// Where the content of the branches ends, at the right edge of the tree's column if there
+    /// are columns.
+    fn content_right(&self, state: &TreeState, bounds: Rectangle) -> f32 {
+        match state.column_widths.first() {
+            Some(width) => bounds.x + self.padding_x + width,
+            None => bounds.x + bounds.width - self.padding_x,
+        }
+    }
+
+    /// The cells of a branch shown in the columns, with their index among every branch's cells.
+    fn cells(&self, i: usize) -> impl Iterator<Item = (usize, &Element<'a, Message, Theme, Renderer>)> {
+        let shown = self.columns.as_ref().map_or(0, |columns| columns.columns.len().saturating_sub(1));
+        self.branch_cells[i].iter().take(shown).enumerate().map(move |(k, cell)| (self.cell_start[i] + k, cell))
+    }
+
+    /// How far the content of a branch starts from its indent, past the arrow and checkbox.
+    fn content_offset(&self) -> f32 {
+        if self.checkboxes {
+            ARROW_W + CONTENT_GAP + CHECKBOX_SIZE + CHECKBOX_GAP
+        } else {
+            ARROW_W + CONTENT_GAP
+        }
+    }
+
+    /// The checkbox of a row, left to right, given its indent.
+    fn checkbox_bounds(&self, indent_x: f32, row: Rectangle) -> Rectangle {
+        Rectangle {
+            x: indent_x + ARROW_W + CONTENT_GAP,
+            y: row.center_y() - CHECKBOX_SIZE / 2.0,
+            width: CHECKBOX_SIZE,
+            height: CHECKBOX_SIZE,
+        }
+    }
+
+    /// Which branches are shown, under expanded parents and among those the filter shows.
+    fn visibility(&self, state: &TreeState, shown: Option<&HashSet<usize>>) -> Vec<bool> {
+        (0..self.branches.len())
+            .map(|i| self.is_branch_visible(i, state) && shown.is_none_or(|shown| shown.contains(&i)))
+            .collect()
+    }
+
+    /// The branches the filter shows, its matches and their ancestors, or every branch
+    /// without a filter.
+    fn filter_shown(&self, state: &TreeState) -> Option<(HashSet<usize>, HashSet<usize>)> {
+        let matches = self.matches.as_ref()?;
+        let mut ancestors = HashSet::new();
+
+        for &id in matches {
+            let mut current = id;
+            while let Some(parent) = self.get_branch_info(current, state).1 {
+                if !ancestors.insert(parent) {
+                    break;
+                }
+                current = parent;
+            }
+        }
+
+        let shown = matches.union(&ancestors).copied().collect();
+        Some((shown, ancestors))
+    }
+
+    /// Opens the ancestors of new matches of the filter, and closes those it opened once they
+    /// have none.
+    fn sync_filter(&self, state: &mut TreeState, ancestors: HashSet<usize>) {
+        for &id in ancestors.difference(&state.filter_ancestors) {
+            if state.expanded.insert(id) {
+                state.filter_opened.insert(id);
+            }
+        }
+
+        let closed: Vec<usize> = state.filter_opened.difference(&ancestors).copied().collect();
+        for id in closed {
+            state.expanded.remove(&id);
+            state.filter_opened.remove(&id);
+        }
+
+        state.filter_ancestors = ancestors;
+    }
+
+    /// Applies the requests operations made, without publishing `on_toggle` or `on_select`.
+    fn apply_requests(&self, state: &mut TreeState, bounds: Rectangle) {
+        for request in std::mem::take(&mut state.requests) {
+            match request {
+                Request::Expand(external, expanded) => {
+                    if let Some(&id) = self.ext_to_int.get(&external) {
+                        if expanded {
+                            state.expanded.insert(id);
+                        } else {
+                            state.expanded.remove(&id);
+                        }
+                    }
+                }
+                Request::Select(externals) => {
+                    state.selected = externals
+                        .iter()
+                        .filter_map(|external| self.ext_to_int.get(external).copied())
+                        .filter(|&id| !self.is_disabled(id))
+                        .collect();
+                }
+                Request::Focus(external) => {
+                    if let Some(&id) = self.ext_to_int.get(&external) {
+                        state.focused = Some(id);
+                        state.anchor = Some(id);
+                    }
+                }
+                Request::Reveal(external) => {
+                    if let Some(&id) = self.ext_to_int.get(&external) {
+                        let mut ancestor = self.get_branch_info(id, state).1;
+                        while let Some(parent) = ancestor {
+                            state.expanded.insert(parent);
+                            ancestor = self.get_branch_info(parent, state).1;
+                        }
+
+                        // Not laid out yet, the rows just shown count as they were last seen
+                        let filter = self.filter_shown(state);
+                        let visible = self.visibility(state, filter.as_ref().map(|(shown, _)| shown));
+                        let ordered_indices = self.get_ordered_indices(state);
+                        state.revealed = self.row_bounds(state, &visible, &ordered_indices, bounds, id).map(|row| (external, row));
+                    }
+                }
+            }
+            state.relayout = true;
+        }
+    }
+
+    /// The ids of the branches shown and enabled, top to bottom.
+    fn visible_ids(&self, state: &TreeState, ordered_indices: &[usize]) -> Vec<usize> {
+        ordered_indices
+            .iter()
+            .filter(|&&i| i < state.visible_branches.len() && state.visible_branches[i] && !self.branches[i].disabled)
+            .map(|&i| self.branches[i].id)
+            .collect()
+    }
+
+    /// The branch a drag at `position` lands on and where, with the `dragged` branches taken
+    /// out of the rows. `None` keeps the last target, as between rows.
+    fn drop_at(
+        &self,
+        state: &TreeState,
+        ordered_indices: &[usize],
+        bounds: Rectangle,
+        position: Point,
+        dragged: &[usize],
+    ) -> Option<(usize, DropPosition)> {
+        let mut y = bounds.y + self.top();
+        let mut last = None;
+
+        for &i in ordered_indices {
+            if i >= self.branches.len() || !state.visible_branches.get(i).copied().unwrap_or(false) {
+                continue;
+            }
+
+            let branch = &self.branches[i];
+            let (id, parent_id, _) = self.get_branch_info(i, state);
+            if dragged.contains(&id) {
+                continue;
+            }
+
+            let height = state.branch_heights.get(i).copied().unwrap_or(LINE_HEIGHT);
+            let row_bounds = Rectangle { x: bounds.x, y, width: bounds.width, height };
+
+            // Rows overlap a little, so the spacing between them still targets one
+            let expanded_bounds = Rectangle {
+                y: row_bounds.y - 2.0,
+                height: row_bounds.height + 4.0,
+                ..row_bounds
+            };
+
+            // Nothing lands in a disabled branch, beside one only when its parent is enabled
+            let parent_disabled = parent_id.is_some_and(|parent| self.is_disabled(parent));
+
+            if expanded_bounds.contains(position) && !parent_disabled {
+                let drop_position = self.calculate_drop_position(
+                    position.y,
+                    row_bounds,
+                    branch.has_children && !branch.disabled,
+                    state.expanded.contains(&id),
+                    branch.accepts_drops && !branch.disabled,
+                );
+                return Some((id, drop_position));
+            }
+
+            last = Some((id, y + height));
+            y += height + self.spacing;
+        }
+
+        // Below the last row, after it
+        match last {
+            Some((id, bottom)) if position.y > bounds.y && position.y > bottom => Some((id, DropPosition::After)),
+            _ => None,
+        }
+    }
+
+    /// Whether `on_drop_validate` lets the branches with external `dragged_ids` land on `target`.
+    fn allows_drop(&self, dragged_ids: Vec<usize>, target: usize, position: &DropPosition) -> bool {
+        self.on_drop_validate.as_ref().is_none_or(|validate| {
+            validate(&DropInfo {
+                dragged_ids,
+                target_id: Some(self.preferred_id(target)),
+                position: position.clone(),
+            })
+        })
+    }
+
+    /// Follows a payload from another widget over the tree, given where it is and its items,
+    /// returning whether where it would land changed.
+    fn track_external_drop(&self, state: &mut TreeState, ordered_indices: &[usize], bounds: Rectangle, drag: Option<(Point, Vec<usize>)>) -> bool {
+        let hovered = drag.filter(|(position, _)| bounds.contains(*position));
+        dnd::hover(state.drop_token, hovered.is_some());
+
+        let target = match &hovered {
+            Some((position, _)) => self.drop_at(state, ordered_indices, bounds, *position, &[]).or(state.external_drop.clone()),
+            None => None,
+        };
+
+        let changed = target != state.external_drop;
+        if changed {
+            state.drop_denied = match (&target, hovered) {
+                (Some((target, position)), Some((_, items))) => !self.allows_drop(items, *target, position),
+                _ => false,
+            };
+        }
+        state.external_drop = target;
+        changed
+    }
+
+    /// The shown branch whose row contains `position`, while nothing is dragged.
+    fn branch_at(&self, state: &TreeState, ordered_indices: &[usize], bounds: Rectangle, position: Point) -> Option<usize> {
+        let mut y = bounds.y + self.top();
+
+        for &i in ordered_indices {
+            if !state.visible_branches.get(i).copied().unwrap_or(false) {
+                continue;
+            }
+
+            let height = state.branch_heights.get(i).copied().unwrap_or(0.0);
+            if (Rectangle { x: bounds.x, y, width: bounds.width, height }).contains(position) {
+                return Some(self.branches[i].id);
+            }
+            y += height + self.spacing;
+        }
+
+        None
+    }
+
+    /// The row of a shown branch, while nothing is dragged.
+    fn row_bounds(&self, state: &TreeState, visible: &[bool], ordered_indices: &[usize], bounds: Rectangle, id: usize) -> Option<Rectangle> {
+        let mut y = bounds.y + self.top();
+
+        for &i in ordered_indices {
+            if !visible.get(i).copied().unwrap_or(false) {
+                continue;
+            }
+
+            let height = state.branch_heights.get(i).copied().unwrap_or(0.0);
+            if self.branches[i].id == id {
+                return Some(Rectangle { x: bounds.x, y, width: bounds.width, height });
+            }
+            y += height + self.spacing;
+        }
+
+        None
+    }
+
+    /// Selects the branches shown from the anchor to `to`, adding them to the selection when
+    /// `extend` is set, and focuses `to`.
+    fn select_range(&self, state: &mut TreeState, visible: &[usize], to: usize, extend: bool) {
+        let anchor = state.anchor.or(state.focused).unwrap_or(to);
+        let from = visible.iter().position(|&id| id == anchor);
+        let until = visible.iter().position(|&id| id == to);
+
+        if !extend {
+            state.selected.clear();
+        }
+
+        match (from, until) {
+            (Some(from), Some(until)) => {
+                state.selected.extend(&visible[from.min(until)..=from.max(until)]);
+            }
+            _ => {
+                state.selected.insert(to);
+            }
+        }
+
+        state.anchor = Some(anchor);
+        state.focused = Some(to);
+    }
+
+    /// Starts renaming a branch, if it has a name and the tree reports renames.
+    fn start_editing(&self, state: &mut TreeState, id: usize) -> bool {
+        let Some(name) = self.branches.get(id).filter(|branch| !branch.disabled).and_then(|branch| branch.name.as_ref()) else {
+            return false;
+        };
+        if self.on_rename.is_none() {
+            return false;
+        }
+
+        state.editing = Some(Editing::new(id, name.clone()));
+        state.drag_pending = None;
+        true
+    }
+
+    /// Stops renaming, reporting the new name through `on_rename` when `commit` is set and
+    /// it changed.
+    fn finish_editing(&self, state: &mut TreeState, commit: bool, shell: &mut Shell<'_, Message>) {
+        let Some(editing) = state.editing.take() else {
+            return;
+        };
+
+        let name = self.branches.get(editing.id).and_then(|branch| branch.name.as_deref());
+        if commit
+            && name != Some(editing.draft.as_str())
+            && let Some(on_rename) = &self.on_rename
+        {
+            shell.publish(on_rename(self.preferred_id(editing.id), editing.draft));
+        }
+        shell.request_redraw();
+    }
+
+    /// Where Alt and `arrow` move the branch `id`: up or down past a sibling, left out of its
+    /// parent, or right into the sibling above as its last child.
+    fn keyboard_move(&self, state: &TreeState, ordered_indices: &[usize], id: usize, arrow: keyboard::key::Named) -> Option<(usize, DropPosition)> {
+        let branch = self.branches.get(id).filter(|branch| branch.draggable && !branch.disabled)?;
+        let (_, parent_id, _) = self.get_branch_info(branch.id, state);
+
+        let children = |parent: Option<usize>| -> Vec<usize> {
+            ordered_indices
+                .iter()
+                .copied()
+                .filter(|&i| self.get_branch_info(i, state).1 == parent)
+                .collect()
+        };
+        let siblings: Vec<usize> = children(parent_id)
+            .into_iter()
+            .filter(|&i| state.visible_branches.get(i).copied().unwrap_or(false))
+            .collect();
+        let position = siblings.iter().position(|&sibling| sibling == id)?;
+        let above = position.checked_sub(1).map(|above| siblings[above]);
+
+        let (target, drop_position) = match arrow {
+            keyboard::key::Named::ArrowUp => (above?, DropPosition::Before),
+            keyboard::key::Named::ArrowDown => (*siblings.get(position + 1)?, DropPosition::After),
+            keyboard::key::Named::ArrowLeft => (parent_id?, DropPosition::After),
+            keyboard::key::Named::ArrowRight => {
+                let above = above?;
+                match children(Some(above)).last() {
+                    Some(&last) => (last, DropPosition::After),
+                    None if self.branches[above].accepts_drops => (above, DropPosition::Into),
+                    None => return None,
+                }
+            }
+            _ => return None,
+        };
+
+        // The same rules as a drop: nothing lands in or beside the branches of a disabled parent
+        let new_parent = match drop_position {
+            DropPosition::Into => Some(target),
+            _ => self.get_branch_info(target, state).1,
+        };
+        if new_parent.is_some_and(|parent| self.is_disabled(parent)) {
+            return None;
+        }
+
+        self.allows_drop(vec![self.preferred_id(id)], target, &drop_position)
+            .then_some((target, drop_position))
+    }
+
+    /// Moves the branches with internal `dragged_ids` and their descendants to `target_id`,
+    /// the way a drop there would. With `end_to_root`, landing after the last branch of the
+    /// tree moves them to the root, as the drop preview shows.
+    fn reorder_branches(
+        &mut self,
+        state: &mut TreeState,
+        dragged_ids: &[usize],
+        target_id: usize,
+        drop_position: &DropPosition,
+        end_to_root: bool,
+    ) {
+        let current_order = state.branch_order.clone().unwrap_or_else(|| {
+            self.branches.iter().map(|b| BranchState {
+                id: b.id,
+                parent_id: b.parent_id,
+                depth: b.depth,
+            }).collect()
+        });
+        
+        let state_map: HashMap<usize, BranchState> = current_order.iter()
+            .map(|bs| (bs.id, bs.clone()))
+            .collect();
+        
+        // Use standalone function to avoid borrow issues
+        let mut items_to_move = HashSet::new();
+        for &id in dragged_ids {
+            collect_branch_and_descendants(id, &mut items_to_move, &current_order);
+        }
+        
+        let target_state = state_map.get(&target_id)
+            .cloned()
+            .unwrap_or(BranchState {
+                id: target_id,
+                parent_id: None,
+                depth: 0,
+            });
+        
+        let mut new_order: Vec<BranchState> = Vec::new();
+        let mut removed_items: Vec<BranchState> = Vec::new();
+        
+        for bs in current_order {
+            if items_to_move.contains(&bs.id) {
+                removed_items.push(bs);
+            } else {
+                new_order.push(bs);
+            }
+        }
+        
+        let (new_parent_id, new_base_depth) = match drop_position {
+            DropPosition::Before => (target_state.parent_id, target_state.depth),
+            DropPosition::After => {
+                let is_last_item = new_order.iter()
+                    .rposition(|bs| bs.id == target_id)
+                    .map(|idx| idx == new_order.len() - 1 || 
+                        !new_order[idx + 1..].iter().any(|bs| bs.parent_id == target_state.parent_id))
+                    .unwrap_or(false);
+                
+                if end_to_root && is_last_item && target_state.parent_id.is_some() {
+                    let has_root_siblings_after = new_order.iter()
+                        .skip_while(|bs| bs.id != target_id)
+                        .skip(1)
+                        .any(|bs| bs.parent_id.is_none());
+                    
+                    if !has_root_siblings_after {
+                        (None, 0)
+                    } else {
+                        (target_state.parent_id, target_state.depth)
+                    }
+                } else {
+                    (target_state.parent_id, target_state.depth)
+                }
+            }
+            DropPosition::Into => (Some(target_id), target_state.depth + 1),
+        };
+        
+        let insertion_index = match drop_position {
+            DropPosition::Before => {
+                new_order.iter().position(|bs| bs.id == target_id)
+                    .unwrap_or(new_order.len())
+            }
+            DropPosition::Into => {
+                let parent_pos = new_order.iter().position(|bs| bs.id == target_id)
+                    .unwrap_or(new_order.len());
+                parent_pos + 1
+            }
+            DropPosition::After => {
+                let mut idx = new_order.iter().position(|bs| bs.id == target_id)
+                    .map(|i| i + 1)
+                    .unwrap_or(new_order.len());
+                
+                while idx < new_order.len() {
+                    let current = &new_order[idx];
+                    if is_descendant_of(current.id, target_id, &new_order) {
+                        idx += 1;
+                    } else {
+                        break;
+                    }
+                }
+                idx
+            }
+        };
+        
+        let old_depth = removed_items.iter()
+            .find(|bs| dragged_ids.contains(&bs.id))
+            .map(|bs| bs.depth)
+            .unwrap_or(0);
+        let depth_change = new_base_depth as i32 - old_depth as i32;
+        
+        let mut insert_offset = 0;
+        for mut bs in removed_items {
+            if dragged_ids.contains(&bs.id) {
+                bs.parent_id = new_parent_id;
+                bs.depth = new_base_depth;
+            } else {
+                bs.depth = (bs.depth as i32 + depth_change).max(0) as u16;
+            }
+            new_order.insert(insertion_index + insert_offset, bs);
+            insert_offset += 1;
+        }
+        
+        state.branch_order = Some(new_order);
+        state.reordered = true;
+        self.update_has_children(state);
+    }
+
+    /// Draws a checkbox in `bounds`, greyed out when `disabled`.
+    fn draw_checkbox(&self, renderer: &mut Renderer, style: &Style, check: CheckState, disabled: bool, bounds: Rectangle, viewport: &Rectangle)
+    where
+        Renderer: iced::advanced::text::Renderer<Font = iced::Font>,
+    {
+        let color = if disabled { style.disabled_text } else { style.checkbox };
+        let filled = check != CheckState::Unchecked;
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: pixel::snap(bounds),
+                border: pixel::border(Border {
+                    color,
+                    width: 1.0,
+                    radius: Radius::from(3.0),
+                }),
+                ..Default::default()
+            },
+            if filled { color } else { Color::TRANSPARENT },
+        );
+
+        match check {
+            CheckState::Checked => {
+                renderer.fill_text(
+                    iced::advanced::Text {
+                        content: "✓".into(),
+                        bounds: bounds.size(),
+                        size: Pixels(CHECKBOX_SIZE - 4.0),
+                        font: iced::Font::default(),
+                        align_x: Alignment::Center,
+                        align_y: iced::alignment::Vertical::Center,
+                        line_height: iced::advanced::text::LineHeight::default(),
+                        shaping: iced::advanced::text::Shaping::Advanced,
+                        wrapping: iced::advanced::text::Wrapping::default(),
+                    },
+                    bounds.center(),
+                    style.checkmark,
+                    *viewport,
+                );
+            }
+            CheckState::Indeterminate => {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: pixel::snap(Rectangle {
+                            x: bounds.x + 4.0,
+                            y: bounds.center_y() - 1.0,
+                            width: bounds.width - 8.0,
+                            height: 2.0,
+                        }),
+                        ..Default::default()
+                    },
+                    style.checkmark,
+                );
+            }
+            CheckState::Unchecked => {}
+        }
+    }
+
+    /// Draws the expand or collapse arrow of the branch at `index`, left to right in `arrow`,
+    /// mirrored within `bounds` right to left.
+    #[allow(clippy::too_many_arguments)]
+    fn draw_arrow(
+        &self,
+        tree: &widget::Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        tree_style: &Style,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        bounds: Rectangle,
+        index: usize,
+        arrow: Rectangle,
+    ) where
+        Renderer: iced::advanced::text::Renderer<Font = iced::Font>,
+    {
+        let branch = &self.branches[index];
+        if !branch.is_expandable() {
+            return;
+        }
+
+        let state = &tree.state.downcast_ref::<CombinedState<Renderer::Paragraph>>().tree_state;
+        let dir = self.layout_direction();
+        let expanded = state.expanded.contains(&branch.id);
+
+        if self.expand_icon.is_none() && self.collapse_icon.is_none() {
+            // Use default text icons
+            let glyph = match (expanded, dir) {
+                (true, _) => "🠻",
+                (false, Direction::LeftToRight) => "🠺",
+                (false, Direction::RightToLeft) => "🠸",
+            };
+
+            renderer.fill_text(
+                iced::advanced::Text {
+                    content: glyph.into(),
+                    bounds: Size::new(ARROW_W, arrow.height),
+                    size: Pixels(16.0),
+                    font: iced::Font::default(),
+                    align_x: Alignment::Center,
+                    align_y: iced::alignment::Vertical::Center,
+                    line_height: iced::advanced::text::LineHeight::default(),
+                    shaping: iced::advanced::text::Shaping::Advanced,
+                    wrapping: iced::advanced::text::Wrapping::default(),
+                },
+                dir.mirror_point(Point::new(arrow.x + ARROW_X_PAD, arrow.center_y()), bounds),
+                if branch.disabled { tree_style.disabled_text } else { tree_style.arrow_color },
+                *viewport,
+            );
+        } else {
+            let (icon_element, icon_tree_index) = if expanded {
+                // collapse_icon is at index 1 (if both icons exist) or 0 (if only collapse exists)
+                let idx = if self.expand_icon.is_some() { 1 } else { 0 };
+                (self.collapse_icon.as_ref().unwrap(), idx)
+            } else {
+                // expand_icon is always at index 0
+                (self.expand_icon.as_ref().unwrap(), 0)
+            };
+
+            // Create a simple layout for the icon
+            let icon_bounds = dir.mirror(Rectangle { x: arrow.x + ARROW_X_PAD, ..arrow }, bounds);
+            let icon_layout = layout::Node::new(Size::new(ARROW_W, arrow.height))
+                .move_to(Point::new(icon_bounds.x, icon_bounds.y));
+
+            icon_element.as_widget().draw(
+                &tree.children[icon_tree_index],
+                renderer,
+                theme,
+                style,
+                Layout::new(&icon_layout),
+                cursor,
+                viewport,
+            );
+        }
+    }
+
+    /// Draws the field a branch is renamed in, with the name typed so far, the selection and
+    /// the caret, scrolled to keep the caret in view.
+    fn draw_editor(&self, renderer: &mut Renderer, style: &Style, editing: &Editing, field: Rectangle, viewport: &Rectangle) {
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: pixel::snap(field),
+                border: pixel::border(Border {
+                    color: style.focus_border,
+                    width: 1.0,
+                    radius: Radius::from(2.0),
+                }),
+                ..Default::default()
+            },
+            style.selection_background,
+        );
+
+        let size = renderer.default_size();
+        let font = renderer.default_font();
+        let area = Rectangle {
+            x: field.x + EDIT_PAD,
+            width: (field.width - EDIT_PAD * 2.0).max(0.0),
+            ..field
+        };
+        let text = |content: &str| iced::advanced::Text {
+            content: content.to_string(),
+            bounds: Size::new(f32::INFINITY, area.height),
+            size,
+            font,
+            align_x: Alignment::Left,
+            align_y: iced::alignment::Vertical::Center,
+            line_height: iced::advanced::text::LineHeight::default(),
+            shaping: iced::advanced::text::Shaping::Advanced,
+            wrapping: iced::advanced::text::Wrapping::None,
+        };
+        // Offsets are measured by laying out the text up to them
+        let x_of = |offset: usize| Renderer::Paragraph::with_text(text(&editing.draft[..offset]).as_ref()).min_bounds().width;
+        let caret_x = x_of(editing.caret);
+        let scroll = (caret_x + CARET_WIDTH - area.width).max(0.0);
+        let line_height = iced::advanced::text::LineHeight::default().to_absolute(size).0;
+        let clip = area.intersection(viewport).unwrap_or(area);
+
+        if let Some(selection) = editing.selection() {
+            let (start, end) = (x_of(selection.start), x_of(selection.end));
+            let highlight = Rectangle {
+                x: area.x + start - scroll,
+                y: area.center_y() - line_height / 2.0,
+                width: end - start,
+                height: line_height,
+            };
+            if let Some(highlight) = highlight.intersection(&clip) {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: pixel::snap(highlight),
+                        ..Default::default()
+                    },
+                    style.focus_border.scale_alpha(0.4),
+                );
+            }
+        }
+
+        renderer.fill_text(text(&editing.draft), Point::new(area.x - scroll, area.center_y()), style.text, clip);
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: pixel::snap(Rectangle {
+                    x: area.x + caret_x - scroll,
+                    y: area.center_y() - line_height / 2.0,
+                    width: CARET_WIDTH,
+                    height: line_height,
+                }),
+                ..Default::default()
+            },
+            style.text,
+        );
+    }
+
+    /// Reports the selection through `on_select`.
+    fn publish_selection(&self, state: &TreeState, shell: &mut Shell<'_, Message>) {
+        if let Some(ref on_select) = self.on_select {
+            shell.publish(on_select(state.selected.iter().map(|&internal| self.preferred_id(internal)).collect()));
+        }
+    }
+
+    #[inline]
+    fn is_disabled(&self, id: usize) -> bool {
+        self.branches.get(id).is_some_and(|branch| branch.disabled)
     }
 
     #[inline]
@@ -565,12 +2579,73 @@ where
         }
     }
 
+    /// Outlines the row of the branch at `index` for the inspector.
+    #[cfg(feature = "debug")]
+    fn outline_branch(&self, renderer: &mut Renderer, state: &TreeState, index: usize, row: Rectangle)
+    where
+        Renderer: iced::advanced::text::Renderer<Font = iced::Font>,
+    {
+        let (id, _, depth) = self.get_branch_info(index, state);
+        let branch = &self.branches[index];
+
+        let drop = state.drop_target().filter(|&(target, _)| target == id);
+        let kind = match drop {
+            Some(_) => debug::Kind::Drop,
+            None => debug::Kind::Row,
+        };
+
+        let mut label = format!("#{id} ext {} depth {depth}", branch.external_id);
+        if branch.is_expandable() {
+            label.push_str(if state.expanded.contains(&id) { " expanded" } else { " collapsed" });
+        }
+        if let Some((_, position)) = drop {
+            label.push_str(&format!(" drop {position:?}"));
+        }
+
+        debug::outline(renderer, row, kind, &label);
+    }
+
+    /// Outlines the whole tree and sums it up in the inspector panel.
+    #[cfg(feature = "debug")]
+    fn report(&self, renderer: &mut Renderer, state: &TreeState, bounds: Rectangle)
+    where
+        Renderer: iced::advanced::text::Renderer<Font = iced::Font>,
+    {
+        let name = match &self.id {
+            Some(id) => format!("tree {id:?}"),
+            None => "tree".to_owned(),
+        };
+        debug::outline(renderer, bounds, debug::Kind::Widget, "");
+
+        let shown = state.visible_branches.iter().filter(|&&visible| visible).count();
+        debug::report(format!(
+            "{name}: {} branches, {shown} shown, {} hidden, {} expanded, {} selected",
+            self.branches.len(),
+            self.branches.len() - shown,
+            state.expanded.len(),
+            state.selected.len(),
+        ));
+
+        if self.virtualized {
+            let realized = state.realized.iter().filter(|&&realized| realized).count();
+            debug::report(format!("{name}: virtualized, {realized} laid out around the viewport"));
+        }
+
+        if let Some(drag) = &state.drag_active {
+            let dragged: Vec<usize> = drag.dragged_nodes.iter().map(|&id| self.preferred_id(id)).collect();
+            let target = match drag.drop_target {
+                Some(target) => format!("{:?} {}", drag.drop_position, self.preferred_id(target)),
+                None => "no target".to_owned(),
+            };
+            debug::report(format!("{name} drag: {dragged:?} {target}"));
+        }
+    }
+
 }
 
 impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
     for TreeHandle<'a, Message, Theme, Renderer>
 where
-    Message: Clone,
     Theme: Catalog,
     Renderer: iced::advanced::Renderer + iced::advanced::text::Renderer<Font = iced::Font>,
 {
@@ -586,13 +2661,10 @@ where
     }
 
     fn state(&self) -> widget::tree::State {
-        let mut expanded = HashSet::new();
-        
-        for branch in &self.branches {
-            if branch.has_children {
-                expanded.insert(branch.id);
-            }
-        }
+        let expanded = match &self.expanded {
+            Some(expanded) => expanded.iter().filter_map(|external| self.ext_to_int.get(external).copied()).collect(),
+            None => self.branches.iter().filter(|branch| branch.has_children).map(|branch| branch.id).collect(),
+        };
         
         widget::tree::State::new(
             CombinedState{
@@ -603,13 +2675,40 @@ where
                     visible_branches: Vec::new(),
                     selected: HashSet::new(),
                     focused: None,
+                    anchor: None,
                     hovered: None,
                     hovered_handle: None,
                     drag_pending: None,
                     drag_active: None,
                     selection_rect: None,
                     branch_order: None,
+                    reordered: false,
                     current_modifiers: keyboard::Modifiers::empty(),
+                    known_ids: self.int_to_ext.clone(),
+                    gestures: gestures::Recognizer::new(),
+                    touch_drag: false,
+                    replaying: false,
+                    requests: Vec::new(),
+                    relayout: false,
+                    revealed: None,
+                    viewport: None,
+                    realized: Vec::new(),
+                    realized_area: None,
+                    synced_expanded: self.expanded.clone(),
+                    synced_snapshot: None,
+                    checked: HashSet::new(),
+                    synced_checked: None,
+                    filter_ancestors: HashSet::new(),
+                    filter_opened: HashSet::new(),
+                    editing: None,
+                    last_click: None,
+                    drop_token: dnd::target_token(),
+                    external_drop: None,
+                    drop_denied: false,
+                    slide: Slide::default(),
+                    column_widths: Vec::new(),
+                    configured_widths: Vec::new(),
+                    resizing: None,
                 },
                 icon_text: widget::text::State::<Renderer::Paragraph>::default(),
             }
@@ -630,6 +2729,10 @@ where
         for content in &self.branch_content {
             children.push(Tree::new(content));
         }
+
+        for cell in self.branch_cells.iter().flatten() {
+            children.push(Tree::new(cell));
+        }
         
         children
     }
@@ -649,6 +2752,8 @@ where
             children.push(child);
         }
 
+        children.extend(self.branch_cells.iter().flatten());
+
         state.diff_children(&children);
     }
 
@@ -658,14 +2763,19 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
+        let span = profile::span("tree", Phase::Layout);
         let combined_state = tree.state.downcast_mut::<CombinedState<Renderer::Paragraph>>();
 
         // Check if we need to force reset the order
         if self.force_reset_order {
             combined_state.tree_state.branch_order = None;
+            combined_state.tree_state.reordered = false;
             self.force_reset_order = false;
         }
 
+        let remapped = combined_state.tree_state.known_ids != self.int_to_ext;
+        self.remap_state(&mut combined_state.tree_state);
+
         // Initialize branch order if not present
         if combined_state.tree_state.branch_order.is_none() {
             combined_state.tree_state.branch_order = Some(
@@ -683,11 +2793,25 @@ where
         // Update has_children flags based on current state and get newly parented branches
         let newly_has_children = self.update_has_children(&combined_state.tree_state);
 
-        // Auto-expand branches that just gained children
-        for branch_id in newly_has_children {
-            combined_state.tree_state.expanded.insert(branch_id);
+        // Auto-expand branches that just gained children, unless the app owns the expansion
+        if self.expanded.is_none() {
+            for branch_id in newly_has_children {
+                combined_state.tree_state.expanded.insert(branch_id);
+            }
         }
 
+        self.sync_expanded(&mut combined_state.tree_state, remapped);
+        self.sync_checked(&mut combined_state.tree_state, remapped);
+        self.sync_snapshot(&mut combined_state.tree_state);
+        self.sync_columns(&mut combined_state.tree_state);
+        let content_offset = self.content_offset();
+        let spans = self.column_spans(&combined_state.tree_state);
+        // With columns, the content ends at the right edge of the tree's column
+        let content_right = spans.first().map(|(start, width)| start + width);
+
+        let filter = self.filter_shown(&combined_state.tree_state);
+        self.sync_filter(&mut combined_state.tree_state, filter.as_ref().map(|(_, ancestors)| ancestors.clone()).unwrap_or_default());
+
         let ordered_indices = self.get_ordered_indices(&combined_state.tree_state);
         let branch_count = self.branches.len();
 
@@ -696,19 +2820,27 @@ where
         let tree_fluid = self.width.fluid();
 
         // Update visibility
-        combined_state.tree_state.visible_branches = vec![false; branch_count];
-        for i in 0..branch_count {
-            combined_state.tree_state.visible_branches[i] = self.is_branch_visible(i, &combined_state.tree_state);
-        }
+        combined_state.tree_state.visible_branches = self.visibility(&combined_state.tree_state, filter.as_ref().map(|(shown, _)| shown));
 
         let mut cells = Vec::with_capacity(branch_count);
         cells.resize(branch_count, layout::Node::default());
 
+        // Virtualized, rows out of view keep their last height instead of being laid out
+        let previous_heights = std::mem::take(&mut combined_state.tree_state.branch_heights);
+        if self.virtualized {
+            let (realized, area) = self.realized_branches(&combined_state.tree_state, &ordered_indices, &previous_heights);
+            combined_state.tree_state.realized = realized;
+            combined_state.tree_state.realized_area = Some(area);
+        } else {
+            combined_state.tree_state.realized = vec![true; branch_count];
+            combined_state.tree_state.realized_area = None;
+        }
+
         combined_state.tree_state.branch_heights = vec![0.0; branch_count];
         combined_state.tree_state.branch_widths = vec![0.0; branch_count];
 
         // Layout passes
-        let mut y = self.padding_y;
+        let mut y = self.top();
 
         let mut width_fill_factors = vec![0u16; branch_count];
         let mut row_fill_factors = vec![0u16; branch_count];
@@ -730,6 +2862,13 @@ where
                 combined_state.tree_state.branch_widths[i] = 0.0;
             }
 
+            if !combined_state.tree_state.realized[i] {
+                cells[i] = layout::Node::new(Size::ZERO);
+                combined_state.tree_state.branch_heights[i] = previous_heights.get(i).copied().unwrap_or(LINE_HEIGHT);
+                combined_state.tree_state.branch_widths[i] = 0.0;
+                continue;
+            }
+
             let (_, _, effective_depth) = self.get_branch_info(i, &combined_state.tree_state);
             let child_state = &mut tree.children[i];
             let content = &mut self.branch_content[i];
@@ -749,8 +2888,8 @@ where
 
             // Non-fluid: lay out immediately with the full remaining content width
             let indent_x = self.padding_x + (effective_depth as f32 * self.indent);
-            let content_x = indent_x + ARROW_W + CONTENT_GAP;
-            let avail_w = (available.width - content_x - self.padding_x).max(0.0);
+            let content_x = indent_x + content_offset;
+            let avail_w = (content_right.unwrap_or(available.width - self.padding_x) - content_x).max(0.0);
 
             let content_limits = layout::Limits::new(
                 Size::ZERO,
@@ -759,6 +2898,7 @@ where
             .max_width(avail_w);
 
             let content_layout = content.as_widget_mut().layout(child_state, renderer, &content_limits);
+            span.row();
             let content_size =
                 content_limits.resolve(Length::Shrink, Length::Shrink, content_layout.size());
 
@@ -771,6 +2911,26 @@ where
             cells[i] = content_layout;
         }
 
+        // Cells in the columns after the tree's make their rows as tall as the tallest of them
+        let shown_cells = spans.len().saturating_sub(1);
+        let cells_start = self.get_child_content_index() + branch_count;
+        let mut cell_nodes = vec![layout::Node::default(); self.branch_cells.iter().map(Vec::len).sum()];
+        for &i in &ordered_indices {
+            if i >= branch_count || !combined_state.tree_state.visible_branches[i] || !combined_state.tree_state.realized[i] {
+                continue;
+            }
+
+            for (k, cell) in self.branch_cells[i].iter_mut().take(shown_cells).enumerate() {
+                let index = self.cell_start[i] + k;
+                let width = (spans[k + 1].1 - CELL_PADDING * 2.0).max(0.0);
+                let cell_limits = layout::Limits::new(Size::ZERO, Size::new(width, available.height));
+                let node = cell.as_widget_mut().layout(&mut tree.children[cells_start + index], renderer, &cell_limits);
+
+                combined_state.tree_state.branch_heights[i] = combined_state.tree_state.branch_heights[i].max(node.size().height);
+                cell_nodes[index] = node;
+            }
+        }
+
         // Sum up non-fluid heights
         for (i, &h) in combined_state.tree_state.branch_heights.iter().enumerate() {
             if combined_state.tree_state.visible_branches[i] && row_fill_factors[i] == 0 {
@@ -795,6 +2955,7 @@ where
         let available_fluid_height = (available.height
             - total_nonfluid_height
             - self.padding_y * 2.0
+            - (self.top() - self.padding_y)
             - self.spacing
                 * combined_state.tree_state
                     .visible_branches
@@ -831,8 +2992,8 @@ where
             let is_width_fluid = w_factor != 0 || size_hint.width.is_fill();
 
             let indent_x = self.padding_x + (effective_depth as f32 * self.indent);
-            let content_x = indent_x + ARROW_W + CONTENT_GAP;
-            let avail_w = (available.width - content_x - self.padding_x).max(0.0);
+            let content_x = indent_x + content_offset;
+            let avail_w = (content_right.unwrap_or(available.width - self.padding_x) - content_x).max(0.0);
 
             let max_h = if row_fill_factors[i] == 0 {
                 if size_hint.height.is_fill() {
@@ -848,6 +3009,7 @@ where
                 layout::Limits::new(Size::ZERO, Size::new(avail_w, max_h)).max_width(avail_w);
 
             let content_layout = content.as_widget_mut().layout(child_state, renderer, &content_limits);
+            span.row();
 
             let content_size = content_limits.resolve(
                 if is_width_fluid { tree_fluid } else { Length::Shrink },
@@ -864,13 +3026,14 @@ where
         }
 
         // THIRD PASS — position each visible branch
-        y = self.padding_y;
+        y = self.top();
 
-        let drop_indicator_space = if combined_state.tree_state.drag_active.is_some() {
+        let drop_indicator_space = if combined_state.tree_state.drop_target().is_some() {
             LINE_HEIGHT + self.spacing
         } else {
             0.0
         };
+        let mut positions = HashMap::new();
 
         for &i in &ordered_indices {
             if i >= self.branches.len() || !combined_state.tree_state.visible_branches[i] {
@@ -879,24 +3042,24 @@ where
 
             let branch = &self.branches[i];
 
-            if let Some(ref drag) = combined_state.tree_state.drag_active {
-                if drag.dragged_nodes.contains(&branch.id) {
-                    continue;
-                }
+            if let Some(ref drag) = combined_state.tree_state.drag_active
+                && drag.dragged_nodes.contains(&branch.id)
+            {
+                continue;
+            }
 
-                if drag.drop_target == Some(branch.id)
-                    && drag.drop_position == DropPosition::Before
-                {
-                    y += drop_indicator_space;
-                }
+            let drop = combined_state.tree_state.drop_target();
+            if drop == Some((branch.id, &DropPosition::Before)) {
+                y += drop_indicator_space;
             }
 
             let (_, _, effective_depth) = self.get_branch_info(i, &combined_state.tree_state);
 
             let indent_x = self.padding_x + (effective_depth as f32 * self.indent);
-            let content_x = indent_x + ARROW_W + CONTENT_GAP;
+            let content_x = indent_x + content_offset;
 
             cells[i].move_to_mut((content_x, y));
+            positions.insert(i, y);
 
             let Branch_ { align_x, align_y, .. } = branch;
             cells[i].align_mut(
@@ -907,27 +3070,61 @@ where
 
             y += combined_state.tree_state.branch_heights[i] + self.spacing;
 
-            if let Some(ref drag) = combined_state.tree_state.drag_active {
-                if drag.drop_target == Some(branch.id) && drag.drop_position == DropPosition::Into
-                    && combined_state.tree_state.expanded.contains(&branch.id) {
-                        y += drop_indicator_space;
-                    }
-
-                if drag.drop_target == Some(branch.id) && drag.drop_position == DropPosition::After {
+            if drop == Some((branch.id, &DropPosition::Into))
+                && combined_state.tree_state.expanded.contains(&branch.id) {
                     y += drop_indicator_space;
                 }
+
+            if drop == Some((branch.id, &DropPosition::After)) {
+                y += drop_indicator_space;
+            }
+        }
+
+        if let Some(columns) = &self.columns {
+            for &i in &ordered_indices {
+                let Some(&y) = positions.get(&i) else {
+                    continue;
+                };
+
+                for (k, column) in columns.columns.iter().skip(1).enumerate().take(self.branch_cells[i].len()) {
+                    let index = self.cell_start[i] + k;
+                    let (start, width) = spans[k + 1];
+                    cell_nodes[index].move_to_mut((start + CELL_PADDING, y));
+                    cell_nodes[index].align_mut(
+                        column.align_x,
+                        self.branches[i].align_y,
+                        Size::new((width - CELL_PADDING * 2.0).max(0.0), combined_state.tree_state.branch_heights[i]),
+                    );
+                }
             }
         }
+        cells.extend(cell_nodes);
+
+        let state = &mut combined_state.tree_state;
+        let mut slide = std::mem::take(&mut state.slide);
+        let animate = self.animations.then_some(self.animation_duration);
+        slide.retarget(positions, |id| self.get_branch_info(id, state).1, animate, Instant::now());
+        state.slide = slide;
 
         let intrinsic = limits.resolve(
             self.width,
             self.height,
             Size::new(
-                max_content_width + self.padding_x,
+                spans.last().map_or(0.0, |(start, width)| start + width).max(max_content_width) + self.padding_x,
                 y - self.spacing + self.padding_y,
             ),
         );
 
+        // Right to left, each branch moves to the other side of the tree
+        let dir = self.layout_direction();
+        if dir.is_rtl() {
+            let tree_bounds = Rectangle::new(Point::ORIGIN, intrinsic);
+            for cell in &mut cells {
+                let bounds = dir.mirror(cell.bounds(), tree_bounds);
+                cell.move_to_mut((bounds.x, bounds.y));
+            }
+        }
+
         layout::Node::with_children(intrinsic, cells)
     }
 
@@ -943,12 +3140,88 @@ where
         shell: &mut Shell<'_, Message>,
         viewport: &Rectangle,
     ) {
+        let _span = profile::span("tree", Phase::Update);
+
+        // Operations can not relayout, so branches they expanded are laid out here
+        let state = &mut tree.state.downcast_mut::<CombinedState<Renderer::Paragraph>>().tree_state;
+        if std::mem::take(&mut state.relayout) {
+            shell.invalidate_layout();
+            shell.request_redraw();
+        }
+
+        // Virtualized, the rows around the viewport are laid out again once it leaves them
+        if self.virtualized {
+            let bounds = layout.bounds();
+            let visible = Rectangle {
+                x: viewport.x - bounds.x,
+                y: viewport.y - bounds.y,
+                ..*viewport
+            };
+            state.viewport = Some(visible);
+
+            if state.realized_area.is_none_or(|(top, bottom)| visible.y < top || visible.y + visible.height > bottom) {
+                shell.invalidate_layout();
+                shell.request_redraw();
+            }
+        }
+
+        // Rows slide on each frame until they reach where they were laid out
+        if let Event::Window(iced::window::Event::RedrawRequested(now)) = event
+            && state.slide.advance(*now)
+        {
+            shell.request_redraw();
+        }
+
+        // A tap clicks a branch, a long press picks it up and the finger then drags it
+        if matches!(event, Event::Touch(_) | Event::Window(iced::window::Event::RedrawRequested(_))) {
+            let state = &mut tree.state.downcast_mut::<CombinedState<Renderer::Paragraph>>().tree_state;
+            let gesture = state.gestures.update(event);
+
+            if let Some(deadline) = state.gestures.deadline() {
+                shell.request_redraw_at(deadline);
+            }
+
+            let press = Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left));
+            let release = Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left));
+
+            let replay = match (gesture, event) {
+                (Some(Gesture::Tap(position)), _) => vec![(press, position), (release, position)],
+                (Some(Gesture::LongPress(position)), _) => {
+                    state.touch_drag = true;
+                    vec![(press, position)]
+                }
+                (_, Event::Touch(touch::Event::FingerMoved { position, .. })) if state.touch_drag => {
+                    vec![(Event::Mouse(mouse::Event::CursorMoved { position: *position }), *position)]
+                }
+                (_, Event::Touch(touch::Event::FingerLifted { position, .. } | touch::Event::FingerLost { position, .. }))
+                    if state.touch_drag =>
+                {
+                    state.touch_drag = false;
+                    vec![(release, *position)]
+                }
+                _ => Vec::new(),
+            };
+
+            state.replaying = true;
+            for (event, position) in &replay {
+                self.update(tree, event, layout, mouse::Cursor::Available(*position), renderer, clipboard, shell, viewport);
+            }
+            tree.state.downcast_mut::<CombinedState<Renderer::Paragraph>>().tree_state.replaying = false;
+        }
+
         let combined_state = tree.state.downcast_mut::<CombinedState<Renderer::Paragraph>>();
         let ordered_indices = self.get_ordered_indices(&combined_state.tree_state);
         let child_layout_index = self.get_child_content_index();
+
+        // A parent pinned by `sticky_headers` stands in for its row
+        let cursor = self.unpin_cursor(&combined_state.tree_state, &ordered_indices, layout.bounds(), viewport, cursor);
         
         // Update all visible children
         for &i in &ordered_indices {
+            if combined_state.tree_state.replaying {
+                break;
+            }
+
             if i >= self.branches.len() || 
                i >= combined_state.tree_state.visible_branches.len() || 
                !combined_state.tree_state.visible_branches[i] {
@@ -959,6 +3232,19 @@ where
                 && drag.dragged_nodes.contains(&self.branches[i].id) {
                     continue;
                 }
+
+            if !combined_state.tree_state.realized.get(i).copied().unwrap_or(true) {
+                continue;
+            }
+
+            // Renamed in place, the field stands in for the content
+            if combined_state.tree_state.editing.as_ref().is_some_and(|editing| editing.id == self.branches[i].id) {
+                continue;
+            }
+
+            if self.branches[i].disabled {
+                continue;
+            }
             
             let branch = &mut self.branch_content[i];
             let child_state = &mut tree.children[i + child_layout_index];
@@ -967,8 +3253,170 @@ where
             branch.as_widget_mut().update(
                 child_state, event, child_layout, cursor, renderer, clipboard, shell, viewport,
             );
+
+            let shown_cells = self.columns.as_ref().map_or(0, |columns| columns.columns.len().saturating_sub(1));
+            for (k, cell) in self.branch_cells[i].iter_mut().take(shown_cells).enumerate() {
+                let index = self.cell_start[i] + k;
+                if let Some(cell_layout) = layout.children().nth(self.branches.len() + index) {
+                    cell.as_widget_mut().update(
+                        &mut tree.children[child_layout_index + self.branches.len() + index],
+                        event, cell_layout, cursor, renderer, clipboard, shell, viewport,
+                    );
+                }
+            }
         }
         
+        // The rows below are hit tested left to right, so right to left mirrors the cursor first
+        let dir = self.layout_direction();
+        let tree_cursor = dir.mirror_cursor(cursor, layout.bounds());
+
+        // Dragging the edge of a column's title resizes it, and the header takes every other press
+        if let Some(columns) = &self.columns
+            && let Some(header) = self.header_bounds(layout.bounds())
+        {
+            let state = &mut combined_state.tree_state;
+            match event {
+                Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                    if let Some(resize) = state.resizing
+                        && let Some(position) = tree_cursor.position()
+                    {
+                        let min_width = columns.columns[resize.column].min_width;
+                        let width = (resize.origin_width + position.x - resize.origin_x).max(min_width);
+
+                        if width != state.column_widths[resize.column] {
+                            state.column_widths[resize.column] = width;
+                            shell.invalidate_layout();
+                            shell.request_redraw();
+                        }
+                        shell.capture_event();
+                        return;
+                    }
+
+                    if tree_cursor.is_over(header) {
+                        shell.request_redraw();
+                    }
+                }
+                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                    if let Some(position) = tree_cursor.position_over(header) {
+                        if let Some(column) = self.resize_handle_at(state, layout.bounds(), position) {
+                            state.resizing = Some(ColumnResize {
+                                column,
+                                origin_x: position.x,
+                                origin_width: state.column_widths[column],
+                            });
+                            shell.request_redraw();
+                        }
+                        shell.capture_event();
+                        return;
+                    }
+                }
+                Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                    if let Some(resize) = state.resizing.take() {
+                        if let Some(on_column_resize) = &self.on_column_resize {
+                            shell.publish(on_column_resize(resize.column, state.column_widths[resize.column]));
+                        }
+                        shell.capture_event();
+                        shell.request_redraw();
+                        return;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        // Payloads from other widgets are followed over the branches and taken when dropped
+        if self.accepts_external_drops && combined_state.tree_state.drag_active.is_none() {
+            match event {
+                Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                    if let Some((target, position)) = combined_state.tree_state.external_drop.take() {
+                        if combined_state.tree_state.drop_denied {
+                            // Refused, and not left to the widget it came from either
+                            dnd::cancel();
+                        } else if let Some(on_external_drop) = &self.on_external_drop
+                            && let Some(payload) = dnd::take(combined_state.tree_state.drop_token)
+                        {
+                            let drop_info = DropInfo {
+                                dragged_ids: payload.items.clone(),
+                                target_id: Some(self.preferred_id(target)),
+                                position,
+                            };
+                            shell.publish(on_external_drop(drop_info, payload));
+                            shell.capture_event();
+                        }
+                        dnd::hover(combined_state.tree_state.drop_token, false);
+                        shell.invalidate_layout();
+                        shell.request_redraw();
+                        return;
+                    }
+                }
+                Event::Mouse(_) | Event::Keyboard(_) => {
+                    // An overlay drawing the drag hides the cursor from widgets
+                    let drag = dnd::active().map(|drag| {
+                        let position = cursor.position().unwrap_or(drag.position);
+                        (dir.mirror_point(position, layout.bounds()), drag.payload.items)
+                    });
+
+                    if self.track_external_drop(&mut combined_state.tree_state, &ordered_indices, layout.bounds(), drag) {
+                        shell.invalidate_layout();
+                        shell.request_redraw();
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        // While a branch is renamed, the keys edit its name
+        if let Event::Keyboard(keyboard::Event::KeyPressed { key, modifiers, text, .. }) = event
+            && let Some(editing) = &mut combined_state.tree_state.editing
+        {
+            match key.as_ref() {
+                keyboard::Key::Named(keyboard::key::Named::Enter) => {
+                    self.finish_editing(&mut combined_state.tree_state, true, shell);
+                }
+                keyboard::Key::Named(keyboard::key::Named::Escape) => {
+                    self.finish_editing(&mut combined_state.tree_state, false, shell);
+                }
+                keyboard::Key::Named(keyboard::key::Named::Backspace) => editing.erase(false),
+                keyboard::Key::Named(keyboard::key::Named::Delete) => editing.erase(true),
+                keyboard::Key::Named(keyboard::key::Named::ArrowLeft) => editing.step(false, modifiers.shift()),
+                keyboard::Key::Named(keyboard::key::Named::ArrowRight) => editing.step(true, modifiers.shift()),
+                keyboard::Key::Named(keyboard::key::Named::Home) => editing.move_to(0, modifiers.shift()),
+                keyboard::Key::Named(keyboard::key::Named::End) => {
+                    editing.move_to(editing.draft.len(), modifiers.shift());
+                }
+                keyboard::Key::Character("a") if modifiers.command() => {
+                    editing.anchor = Some(0);
+                    editing.caret = editing.draft.len();
+                }
+                keyboard::Key::Character(c @ ("c" | "x")) if modifiers.command() => {
+                    if let Some(selection) = editing.selection() {
+                        clipboard.write(clipboard::Kind::Standard, editing.draft[selection].to_string());
+                        if c == "x" {
+                            editing.erase(false);
+                        }
+                    }
+                }
+                keyboard::Key::Character("v") if modifiers.command() => {
+                    if let Some(pasted) = clipboard.read(clipboard::Kind::Standard) {
+                        // Names are a single line
+                        let pasted: String = pasted.chars().filter(|c| !c.is_control()).collect();
+                        editing.insert(&pasted);
+                    }
+                }
+                _ => {
+                    let typed = text.as_deref().filter(|_| !modifiers.command()).unwrap_or_default();
+                    let typed: String = typed.chars().filter(|c| !c.is_control()).collect();
+                    if !typed.is_empty() {
+                        editing.insert(&typed);
+                    }
+                }
+            }
+
+            shell.capture_event();
+            shell.request_redraw();
+            return;
+        }
+
         // Handle tree-specific events
         match event {
             Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
@@ -976,9 +3424,19 @@ where
             }
 
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
-                if let Some(position) = cursor.position() {
+                if let Some(position) = tree_cursor.position() {
                     let bounds = layout.bounds();
 
+                    // Pressing anywhere but the branch being renamed commits the new name
+                    if let Some(editing) = &combined_state.tree_state.editing {
+                        let row = self.row_bounds(&combined_state.tree_state, &combined_state.tree_state.visible_branches, &ordered_indices, bounds, editing.id);
+                        if row.is_some_and(|row| row.contains(position)) {
+                            shell.capture_event();
+                            return;
+                        }
+                        self.finish_editing(&mut combined_state.tree_state, true, shell);
+                    }
+
                     // Check if Ctrl is held for selection rectangle
                     if combined_state.tree_state.current_modifiers.control() || combined_state.tree_state.current_modifiers.command() {
                         combined_state.tree_state.selection_rect = Some(SelectionRect {
@@ -989,7 +3447,7 @@ where
                         shell.request_redraw();
                     }
 
-                    let mut y = bounds.y + self.padding_y;
+                    let mut y = bounds.y + self.top();
                     
                     for &i in &ordered_indices {
                         if i >= self.branches.len() || 
@@ -1016,7 +3474,7 @@ where
                         };
                         
                         // Check if clicking on arrow
-                        if branch.has_children {
+                        if branch.is_expandable() {
                             let arrow_bounds = Rectangle {
                                 x: indent_x,
                                 y,
@@ -1025,19 +3483,50 @@ where
                             };
                             
                             if arrow_bounds.contains(position) {
-                                if combined_state.tree_state.expanded.contains(&branch.id) {
-                                    combined_state.tree_state.expanded.remove(&branch.id);
-                                } else {
-                                    combined_state.tree_state.expanded.insert(branch.id);
-                                }
+                                let expanded = !combined_state.tree_state.expanded.contains(&branch.id);
+                                self.set_expanded(&mut combined_state.tree_state, branch.id, expanded, shell);
                                 shell.invalidate_layout();
                                 shell.request_redraw();
                                 return;
                             }
                         }
 
+                        if self.checkboxes && self.checkbox_bounds(indent_x, branch_bounds).contains(position) {
+                            self.toggle_checked(&mut combined_state.tree_state, branch.id, shell);
+                            shell.capture_event();
+                            shell.request_redraw();
+                            return;
+                        }
+
                         if branch_bounds.contains(position) {
 
+                            if branch.disabled {
+                                return;
+                            }
+
+                            let click = iced::advanced::mouse::Click::new(position, mouse::Button::Left, combined_state.tree_state.last_click);
+                            combined_state.tree_state.last_click = Some(click);
+
+                            if click.kind() == iced::advanced::mouse::click::Kind::Double
+                                && self.start_editing(&mut combined_state.tree_state, branch.id)
+                            {
+                                shell.capture_event();
+                                shell.request_redraw();
+                                return;
+                            }
+
+                            // Shift selects the range from the anchor, Ctrl/Cmd adds it to the selection
+                            if combined_state.tree_state.current_modifiers.shift() {
+                                let visible = self.visible_ids(&combined_state.tree_state, &ordered_indices);
+                                let extend = combined_state.tree_state.current_modifiers.control()
+                                    || combined_state.tree_state.current_modifiers.command();
+                                self.select_range(&mut combined_state.tree_state, &visible, branch.id, extend);
+                                self.publish_selection(&combined_state.tree_state, shell);
+                                shell.invalidate_widgets();
+                                shell.request_redraw();
+                                return;
+                            }
+
                             if !branch.draggable {
                                 // Branch is not draggable - only allow selection
                                 if combined_state.tree_state.current_modifiers.control() || combined_state.tree_state.current_modifiers.command() {
@@ -1051,6 +3540,7 @@ where
                                     combined_state.tree_state.selected.insert(branch.id);
                                 }
                                 combined_state.tree_state.focused = Some(branch.id);
+                                combined_state.tree_state.anchor = Some(branch.id);
 
                                 if let Some(ref on_select) = self.on_select {
                                     let external_ids: HashSet<usize> = combined_state.tree_state
@@ -1080,7 +3570,7 @@ where
                             );
                             
                             combined_state.tree_state.drag_pending = Some(DragPending {
-                                start_position: position,
+                                press: dnd::Press::new(dir.mirror_point(position, layout.bounds())),
                                 branch_ids: filtered_ids,
                                 primary_branch_id: branch.id,
                                 branch_bounds,
@@ -1098,6 +3588,7 @@ where
                                 combined_state.tree_state.selected.insert(branch.id);
                             }
                             combined_state.tree_state.focused = Some(branch.id);
+                            combined_state.tree_state.anchor = Some(branch.id);
 
                             if let Some(ref on_select) = self.on_select {
                                 let external_ids: HashSet<usize> = combined_state.tree_state
@@ -1114,6 +3605,31 @@ where
                 }
             }
 
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Right)) => {
+                if let Some(on_context_menu) = &self.on_context_menu
+                    && combined_state.tree_state.drag_active.is_none()
+                    && let Some(position) = tree_cursor.position_over(layout.bounds())
+                    && let Some(id) = self.branch_at(&combined_state.tree_state, &ordered_indices, layout.bounds(), position)
+                    && !self.is_disabled(id)
+                {
+                    self.finish_editing(&mut combined_state.tree_state, true, shell);
+
+                    if !combined_state.tree_state.selected.contains(&id) {
+                        combined_state.tree_state.selected = HashSet::from([id]);
+                        self.publish_selection(&combined_state.tree_state, shell);
+                    }
+                    combined_state.tree_state.focused = Some(id);
+                    combined_state.tree_state.anchor = Some(id);
+
+                    // Reported where the cursor is, not mirrored, to open a menu at
+                    if let Some(position) = cursor.position() {
+                        shell.publish(on_context_menu(self.preferred_id(id), position));
+                    }
+                    shell.invalidate_widgets();
+                    shell.request_redraw();
+                }
+            }
+
             Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
                 // End selection rectangle if active
                 if combined_state.tree_state.selection_rect.is_some() {
@@ -1136,7 +3652,7 @@ where
             }
 
             Event::Mouse(mouse::Event::CursorMoved { .. }) => {
-                if let Some(position) = cursor.position() {
+                if let Some(position) = tree_cursor.position() {
 
                     // Handle selection rectangle
                     if let Some(ref mut selection_rect) = combined_state.tree_state.selection_rect {
@@ -1155,7 +3671,7 @@ where
                         combined_state.tree_state.selected = selection_rect.initial_selection.clone();
                         
                         // Add branches that intersect with selection rectangle
-                        let mut y = bounds.y + self.padding_y;
+                        let mut y = bounds.y + self.top();
                         for &i in &ordered_indices {
                             if i >= self.branches.len() || 
                             i >= combined_state.tree_state.visible_branches.len() || 
@@ -1173,7 +3689,7 @@ where
                             };
                             
                             // Check if branch intersects with selection rectangle
-                            if rectangles_intersect(&branch_bounds, &rect_bounds) {
+                            if rectangles_intersect(&branch_bounds, &rect_bounds) && !branch.disabled {
                                 if combined_state.tree_state.current_modifiers.shift() {
                                     // Shift+Ctrl removes from selection
                                     combined_state.tree_state.selected.remove(&branch.id);
@@ -1190,13 +3706,13 @@ where
                     }
 
                     // Check if we should start dragging
-                    if let Some(ref pending) = combined_state.tree_state.drag_pending {
-                        let distance = ((position.x - pending.start_position.x).powi(2) + 
-                                       (position.y - pending.start_position.y).powi(2)).sqrt();
-                        
-                        if distance >= DRAG_THRESHOLD {
-                            // Start actual drag
+                    if let Some(ref mut pending) = combined_state.tree_state.drag_pending {
+                        // Start actual drag, published so it can be dropped on other widgets too
+                        if pending.press.drag_to(dir.mirror_point(position, layout.bounds()), || {
+                            dnd::Payload::new(dnd::TREE, pending.branch_ids.iter().map(|&id| self.preferred_id(id)))
+                        }) {
                             combined_state.tree_state.drag_active = Some(DragActive {
+                                press: pending.press,
                                 dragged_nodes: pending.branch_ids.clone(),
                                 primary_node: pending.primary_branch_id,
                                 drag_start_bounds: pending.branch_bounds,
@@ -1204,7 +3720,10 @@ where
                                 current_position: position,
                                 drop_target: None,
                                 drop_position: DropPosition::Before,
+                                outside: false,
+                                scrolled_at: None,
                             });
+                            combined_state.tree_state.drop_denied = false;
                             combined_state.tree_state.drag_pending = None;
                             shell.invalidate_layout();
                             shell.request_redraw();
@@ -1212,7 +3731,7 @@ where
                     } else if combined_state.tree_state.drag_active.is_none() {
                         // Handle hover states
                         let bounds = layout.bounds();
-                        let mut y = bounds.y + self.padding_y;
+                        let mut y = bounds.y + self.top();
                         let mut new_hovered = None;
                         let mut new_hovered_handle = None;
                         
@@ -1233,6 +3752,9 @@ where
                             };
                             
                             if branch_bounds.contains(position) {
+                                if branch.disabled {
+                                    break;
+                                }
                                 new_hovered = Some(branch.id);
                                 
                                 let (_, _, effective_depth) = self.get_branch_info(i, &combined_state.tree_state);
@@ -1265,45 +3787,106 @@ where
 
             Event::Keyboard(keyboard::Event::KeyPressed { key, modifiers, .. }) => {
                 if let Some(focused) = combined_state.tree_state.focused {
-                    let visible_ordered: Vec<usize> = ordered_indices.iter()
-                        .filter(|&&i| i < combined_state.tree_state.visible_branches.len() && combined_state.tree_state.visible_branches[i])
-                        .map(|&i| self.branches[i].id)
-                        .collect();
+                    let visible_ordered = self.visible_ids(&combined_state.tree_state, &ordered_indices);
+
+                    // Right to left, the arrows swap so left expands towards the children
+                    let key = &match key {
+                        keyboard::Key::Named(keyboard::key::Named::ArrowLeft) if dir.is_rtl() => {
+                            keyboard::Key::Named(keyboard::key::Named::ArrowRight)
+                        }
+                        keyboard::Key::Named(keyboard::key::Named::ArrowRight) if dir.is_rtl() => {
+                            keyboard::Key::Named(keyboard::key::Named::ArrowLeft)
+                        }
+                        key => key.clone(),
+                    };
 
                     match key {
-                        keyboard::Key::Named(keyboard::key::Named::ArrowUp) => {
-                            if let Some(current_pos) = visible_ordered.iter().position(|&id| id == focused)
-                                && current_pos > 0 {
-                                    combined_state.tree_state.focused = Some(visible_ordered[current_pos - 1]);
-                                    shell.invalidate_widgets();
-                                    shell.request_redraw();
+                        // Alt moves the focused branch instead, reported like a drop
+                        keyboard::Key::Named(
+                            arrow @ (keyboard::key::Named::ArrowUp
+                            | keyboard::key::Named::ArrowDown
+                            | keyboard::key::Named::ArrowLeft
+                            | keyboard::key::Named::ArrowRight),
+                        ) if modifiers.alt() => {
+                            if let Some((target, position)) = self.keyboard_move(&combined_state.tree_state, &ordered_indices, focused, *arrow) {
+                                self.reorder_branches(&mut combined_state.tree_state, &[focused], target, &position, false);
+
+                                if let Some(on_drop) = &self.on_drop {
+                                    shell.publish(on_drop(DropInfo {
+                                        dragged_ids: vec![self.preferred_id(focused)],
+                                        target_id: Some(self.preferred_id(target)),
+                                        position,
+                                    }));
+                                }
+
+                                // Moved under the branch above, which opens to show it
+                                let parent = self.get_branch_info(focused, &combined_state.tree_state).1;
+                                if let Some(parent) = parent
+                                    && !combined_state.tree_state.expanded.contains(&parent)
+                                {
+                                    self.set_expanded(&mut combined_state.tree_state, parent, true, shell);
                                 }
+                                shell.capture_event();
+                                shell.invalidate_layout();
+                                shell.request_redraw();
+                            }
                         }
-                        keyboard::Key::Named(keyboard::key::Named::ArrowDown) => {
-                            if let Some(current_pos) = visible_ordered.iter().position(|&id| id == focused)
-                                && current_pos < visible_ordered.len() - 1 {
-                                    combined_state.tree_state.focused = Some(visible_ordered[current_pos + 1]);
-                                    shell.invalidate_widgets();
-                                    shell.request_redraw();
+                        keyboard::Key::Named(keyboard::key::Named::ArrowUp | keyboard::key::Named::ArrowDown) => {
+                            let up = matches!(key, keyboard::Key::Named(keyboard::key::Named::ArrowUp));
+                            let next = visible_ordered.iter().position(|&id| id == focused).and_then(|current_pos| {
+                                if up {
+                                    current_pos.checked_sub(1)
+                                } else {
+                                    Some(current_pos + 1).filter(|&pos| pos < visible_ordered.len())
+                                }
+                            });
+
+                            if let Some(next) = next.map(|pos| visible_ordered[pos]) {
+                                if modifiers.shift() {
+                                    // Shift extends the selection from the anchor to the new focus
+                                    let extend = modifiers.control() || modifiers.command();
+                                    self.select_range(&mut combined_state.tree_state, &visible_ordered, next, extend);
+                                    self.publish_selection(&combined_state.tree_state, shell);
+                                } else {
+                                    combined_state.tree_state.focused = Some(next);
+                                    combined_state.tree_state.anchor = Some(next);
                                 }
+                                shell.invalidate_widgets();
+                                shell.request_redraw();
+                            }
                         }
                         keyboard::Key::Named(keyboard::key::Named::ArrowLeft) => {
                             if let Some(branch) = self.branches.iter().find(|b| b.id == focused)
-                                && branch.has_children && combined_state.tree_state.expanded.contains(&focused) {
-                                    combined_state.tree_state.expanded.remove(&focused);
+                                && branch.is_expandable() && combined_state.tree_state.expanded.contains(&focused) {
+                                    self.set_expanded(&mut combined_state.tree_state, focused, false, shell);
                                     shell.invalidate_layout();
                                     shell.request_redraw();
                                 }
                         }
                         keyboard::Key::Named(keyboard::key::Named::ArrowRight) => {
                             if let Some(branch) = self.branches.iter().find(|b| b.id == focused)
-                                && branch.has_children && !combined_state.tree_state.expanded.contains(&focused) {
-                                    combined_state.tree_state.expanded.insert(focused);
+                                && branch.is_expandable() && !combined_state.tree_state.expanded.contains(&focused) {
+                                    self.set_expanded(&mut combined_state.tree_state, focused, true, shell);
                                     shell.invalidate_layout();
                                     shell.request_redraw();
                                 }
                         }
-                        keyboard::Key::Named(keyboard::key::Named::Space) => {
+                        keyboard::Key::Named(keyboard::key::Named::F2) => {
+                            let started = self.start_editing(&mut combined_state.tree_state, focused);
+                            if started {
+                                shell.capture_event();
+                                shell.request_redraw();
+                            }
+                        }
+                        keyboard::Key::Named(keyboard::key::Named::Space)
+                            if self.checkboxes && !modifiers.control() && !modifiers.command() =>
+                        {
+                            self.toggle_checked(&mut combined_state.tree_state, focused, shell);
+                            shell.capture_event();
+                            shell.request_redraw();
+                        }
+                        keyboard::Key::Named(keyboard::key::Named::Space) if !self.is_disabled(focused) => {
+                            combined_state.tree_state.anchor = Some(focused);
                             if modifiers.control() || modifiers.command() {
                                 if combined_state.tree_state.selected.contains(&focused) {
                                     combined_state.tree_state.selected.remove(&focused);
@@ -1335,12 +3918,20 @@ where
         }
 
         let content_children_start = self.get_child_content_index();
-        for ((child, state), layout) in self
+        let tree_state = &tree.state.downcast_ref::<CombinedState<Renderer::Paragraph>>().tree_state;
+        let realized = &tree_state.realized;
+        let editing = tree_state.editing.as_ref().map(|editing| editing.id);
+        for (i, ((child, state), layout)) in self
             .branch_content
             .iter_mut()
             .zip(&mut tree.children[content_children_start..])
             .zip(layout.children().skip(content_children_start))
+            .enumerate()
         {
+            if !realized.get(i).copied().unwrap_or(true) || self.branches[i].disabled || editing == Some(i) {
+                continue;
+            }
+
             child.as_widget_mut().update(
                 state, event, layout, cursor, renderer, clipboard, shell,
                 viewport,
@@ -1358,6 +3949,7 @@ where
         cursor: mouse::Cursor,
         viewport: &Rectangle,
     ) {
+        let span = profile::span("tree", Phase::Draw);
         let bounds = layout.bounds();
         let combined_state = tree.state.downcast_ref::<CombinedState<Renderer::Paragraph>>();
 
@@ -1365,9 +3957,112 @@ where
         let child_layout_index = self.get_child_content_index();
         let ordered_indices = self.get_ordered_indices(state);
         let tree_style = theme.style(&self.class);
+        let drop_color = if state.drop_denied {
+            tree_style.deny_drop_indicator_color
+        } else {
+            tree_style.accept_drop_indicator_color
+        };
+
+        // Everything below is placed left to right, then mirrored right to left
+        let dir = self.layout_direction();
+        let mirror = |rectangle: Rectangle| dir.mirror(rectangle, bounds);
+
+        // Over a parent pinned by `sticky_headers`, the cursor is over its row
+        let sticky = self.sticky_rows(state, &ordered_indices, bounds, viewport);
+        let cursor = self.unpin_cursor(state, &ordered_indices, bounds, viewport, cursor);
+
+        let check_states = self.checkboxes.then(|| self.check_states(state));
+
+        // Guides run down the middle of the parents' arrows
+        let guides = self.show_guides.then(|| self.guides(state, &ordered_indices));
+        let arrow_center = if self.expand_icon.is_none() && self.collapse_icon.is_none() {
+            ARROW_X_PAD
+        } else {
+            ARROW_X_PAD + ARROW_W / 2.0
+        };
+        let guide_x = |depth: u16| {
+            bounds.x + self.padding_x + (depth as f32 * self.indent) + arrow_center - crate::depth::GUIDE_WIDTH / 2.0
+        };
         
+        let cells_start = child_layout_index + self.branches.len();
+
         renderer.with_layer(*viewport, |renderer| {
-            let mut y = bounds.y + self.padding_y;
+            // The columns' titles, and the edge of the column resized or about to be
+            if let Some(columns) = &self.columns
+                && let Some(header) = self.header_bounds(bounds)
+            {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: pixel::snap(header),
+                        border: Border::default(),
+                        ..Default::default()
+                    },
+                    tree_style.header_background,
+                );
+                span.quad();
+
+                let handle = state.resizing.map(|resize| resize.column).or_else(|| {
+                    dir.mirror_cursor(cursor, bounds).position().and_then(|position| self.resize_handle_at(state, bounds, position))
+                });
+                let text_size = renderer.default_size();
+
+                for ((i, column), (start, width)) in columns.columns.iter().enumerate().zip(self.column_spans(state)) {
+                    let cell = Rectangle { x: bounds.x + start, width, ..header };
+                    let title = Rectangle {
+                        x: cell.x + CELL_PADDING,
+                        width: (width - CELL_PADDING * 2.0).max(0.0),
+                        ..cell
+                    };
+                    let (x, align_x) = match column.align_x {
+                        iced::Alignment::Start => (title.x, Alignment::Left),
+                        iced::Alignment::Center => (title.center_x(), Alignment::Center),
+                        iced::Alignment::End => (title.x + title.width, Alignment::Right),
+                    };
+                    let align_x = match align_x {
+                        Alignment::Left if dir.is_rtl() => Alignment::Right,
+                        Alignment::Right if dir.is_rtl() => Alignment::Left,
+                        align_x => align_x,
+                    };
+
+                    if let Some(clip) = mirror(title).intersection(viewport) {
+                        renderer.fill_text(
+                            iced::advanced::Text {
+                                content: column.title.clone(),
+                                bounds: Size::new(f32::INFINITY, header.height),
+                                size: text_size,
+                                font: iced::Font::default(),
+                                align_x,
+                                align_y: iced::alignment::Vertical::Center,
+                                line_height: iced::advanced::text::LineHeight::default(),
+                                shaping: iced::advanced::text::Shaping::Advanced,
+                                wrapping: iced::advanced::text::Wrapping::None,
+                            },
+                            dir.mirror_point(Point::new(x, header.center_y()), bounds),
+                            tree_style.header_text,
+                            clip,
+                        );
+                    }
+
+                    let active = handle == Some(i);
+                    let edge_width = if active { 2.0 } else { 1.0 };
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: pixel::snap(mirror(Rectangle {
+                                x: cell.x + width - edge_width,
+                                y: if active { header.y } else { header.y + header.height * 0.25 },
+                                width: edge_width,
+                                height: if active { header.height } else { header.height * 0.5 },
+                            })),
+                            border: Border::default(),
+                            ..Default::default()
+                        },
+                        if active { tree_style.selection_border } else { tree_style.line_color.scale_alpha(0.3) },
+                    );
+                    span.quad();
+                }
+            }
+
+            let mut y = bounds.y + self.top();
 
             // Helper to draw drop preview
             let draw_drop_preview = |renderer: &mut Renderer, y: f32, depth: u16, width: f32| {
@@ -1376,36 +4071,38 @@ where
                 
                 renderer.fill_quad(
                     renderer::Quad {
-                        bounds: Rectangle {
+                        bounds: pixel::snap(mirror(Rectangle {
                             x: preview_indent,
                             y,
                             width: width - preview_indent + bounds.x,
                             height: preview_height,
-                        },
-                        border: Border {
-                            color: tree_style.accept_drop_indicator_color,
+                        })),
+                        border: pixel::border(Border {
+                            color: drop_color,
                             width: 2.0,
                             radius: Radius::from(4.0),
-                        },
+                        }),
                         ..Default::default()
                     },
-                    tree_style.accept_drop_indicator_color.scale_alpha(0.1),
+                    drop_color.scale_alpha(0.1),
                 );
+                span.quad();
                 
                 let handle_x = preview_indent + ARROW_W;
                 renderer.fill_quad(
                     renderer::Quad {
-                        bounds: Rectangle {
+                        bounds: pixel::snap(mirror(Rectangle {
                             x: handle_x,
                             y: y + 2.0,
                             width: HANDLE_STRIPE_W,
                             height: preview_height - 4.0,
-                        },
+                        })),
                         border: Border::default(),
                         ..Default::default()
                     },
                     tree_style.line_color.scale_alpha(0.3),
                 );
+                span.quad();
             };
 
             let mut pending_into_adjustment = false;
@@ -1421,16 +4118,18 @@ where
                 let branch = &self.branches[i];
                 let (id, parent_id, effective_depth) = self.get_branch_info(i, state);
 
-                if let Some(ref drag) = state.drag_active {
-                    if drag.dragged_nodes.contains(&id) {
-                        continue;
-                    }
-                    
-                    if drag.drop_target == Some(id) && drag.drop_position == DropPosition::Before {
-                        let preview_depth = effective_depth;
-                        draw_drop_preview(renderer, y, preview_depth, bounds.width);
-                        y += LINE_HEIGHT + self.spacing;
-                    }
+                if let Some(ref drag) = state.drag_active
+                    && drag.dragged_nodes.contains(&id)
+                {
+                    continue;
+                }
+
+                let drop = state.drop_target().filter(|&(target, _)| target == id).map(|(_, position)| position);
+
+                if drop == Some(&DropPosition::Before) {
+                    let preview_depth = effective_depth;
+                    draw_drop_preview(renderer, y, preview_depth, bounds.width);
+                    y += LINE_HEIGHT + self.spacing;
                 }
 
                 if pending_into_adjustment {
@@ -1442,217 +4141,288 @@ where
                 let branch_height = state.branch_heights[i];
                 let branch_y = y;
 
-                if let Some(ref drag) = state.drag_active
-                    && drag.drop_target == Some(id) && drag.drop_position == DropPosition::Into {
-                        if state.expanded.contains(&id) {
-                            pending_into_adjustment = true;
-                        } else {
-                            let indicator_width = 30.0;
-                            let indicator_x = bounds.x + bounds.width - indicator_width - 10.0;
+                // Virtualized, rows out of view only move the rows below them
+                if self.virtualized && (branch_y + branch_height < viewport.y || branch_y > viewport.y + viewport.height) {
+                    match drop {
+                        Some(DropPosition::Into) => pending_into_adjustment = state.expanded.contains(&id),
+                        Some(DropPosition::After) => y += LINE_HEIGHT + self.spacing,
+                        Some(DropPosition::Before) | None => {}
+                    }
+                    y += branch_height + self.spacing;
+                    continue;
+                }
+                span.row();
+
+                // Sliding, the row is drawn where it has got to, and one coming out from under a
+                // branch only below that branch
+                let offset = state.slide.y(i).map_or(0.0, |slid| bounds.y + slid - branch_y);
+                let reveal = state.slide.under.get(&i).and_then(|&ancestor| {
+                    let top = (bounds.y + state.slide.y(ancestor)? + state.branch_heights.get(ancestor)?).max(viewport.y);
+                    Some(Rectangle { y: top, height: (viewport.y + viewport.height - top).max(0.0), ..*viewport })
+                });
+
+                let mut draw_row = |renderer: &mut Renderer| {
+                    let viewport = &Rectangle { y: viewport.y - offset, ..*viewport };
+
+                    if drop == Some(&DropPosition::Into) {
+                            if state.expanded.contains(&id) {
+                                pending_into_adjustment = true;
+                            } else {
+                                let indicator_width = 30.0;
+                                let indicator_x = bounds.x + bounds.width - indicator_width - 10.0;
                             
-                            renderer.fill_quad(
-                                renderer::Quad {
-                                    bounds: Rectangle {
-                                        x: indicator_x,
-                                        y: y + branch_height / 2.0 - 1.5,
-                                        width: indicator_width,
-                                        height: 3.0,
+                                renderer.fill_quad(
+                                    renderer::Quad {
+                                        bounds: pixel::snap(mirror(Rectangle {
+                                            x: indicator_x,
+                                            y: y + branch_height / 2.0 - 1.5,
+                                            width: indicator_width,
+                                            height: 3.0,
+                                        })),
+                                        border: Border::default(),
+                                        ..Default::default()
                                     },
-                                    border: Border::default(),
-                                    ..Default::default()
-                                },
-                                tree_style.accept_drop_indicator_color,
-                            );
+                                    drop_color,
+                                );
+                                span.quad();
                             
-                            renderer.fill_text(
-                                iced::advanced::Text {
-                                    content: "→".into(),
-                                    bounds: Size::new(20.0, branch_height),
-                                    size: Pixels(16.0),
-                                    font: iced::Font::default(),
-                                    align_x: Alignment::Center,
-                                    align_y: iced::alignment::Vertical::Center,
-                                    line_height: iced::advanced::text::LineHeight::default(),
-                                    shaping: iced::advanced::text::Shaping::Advanced,
-                                    wrapping: iced::advanced::text::Wrapping::default(),
-                                },
-                                Point::new(indicator_x - 20.0, y + (branch_height / 2.0)),
-                                tree_style.accept_drop_indicator_color,
-                                *viewport,
-                            );
+                                renderer.fill_text(
+                                    iced::advanced::Text {
+                                        content: if dir.is_rtl() { "←" } else { "→" }.into(),
+                                        bounds: Size::new(20.0, branch_height),
+                                        size: Pixels(16.0),
+                                        font: iced::Font::default(),
+                                        align_x: Alignment::Center,
+                                        align_y: iced::alignment::Vertical::Center,
+                                        line_height: iced::advanced::text::LineHeight::default(),
+                                        shaping: iced::advanced::text::Shaping::Advanced,
+                                        wrapping: iced::advanced::text::Wrapping::default(),
+                                    },
+                                    dir.mirror_point(Point::new(indicator_x - 20.0, y + (branch_height / 2.0)), bounds),
+                                    drop_color,
+                                    *viewport,
+                                );
+                            }
                         }
-                    }
-
-                // Draw selection background
-                if state.selected.contains(&id) {
-                    renderer.fill_quad(
-                        renderer::Quad {
-                            bounds: Rectangle {
-                                x: bounds.x,
-                                y,
-                                width: bounds.width,
-                                height: branch_height,
-                            },
-                            border: Border::default(),
-                            ..Default::default()
-                        },
-                        tree_style.selection_background,
-                    );
-                }
 
-                // Draw drop-into indicator border
-                if let Some(ref drag) = state.drag_active
-                    && drag.drop_target == Some(id) && drag.drop_position == DropPosition::Into {
+                    // Draw selection background
+                    if state.selected.contains(&id) {
                         renderer.fill_quad(
                             renderer::Quad {
-                                bounds: Rectangle {
+                                bounds: pixel::snap(Rectangle {
                                     x: bounds.x,
                                     y,
                                     width: bounds.width,
                                     height: branch_height,
-                                },
-                                border: Border {
-                                    color: tree_style.accept_drop_indicator_color,
-                                    width: 2.0,
-                                    radius: Radius::from(4.0),
-                                },
+                                }),
+                                border: Border::default(),
                                 ..Default::default()
                             },
-                            tree_style.accept_drop_indicator_color.scale_alpha(0.1),
+                            tree_style.selection_background,
                         );
+                        span.quad();
                     }
+
+                    // Draw drop-into indicator border
+                    if drop == Some(&DropPosition::Into) {
+                            renderer.fill_quad(
+                                renderer::Quad {
+                                    bounds: pixel::snap(Rectangle {
+                                        x: bounds.x,
+                                        y,
+                                        width: bounds.width,
+                                        height: branch_height,
+                                    }),
+                                    border: pixel::border(Border {
+                                        color: drop_color,
+                                        width: 2.0,
+                                        radius: Radius::from(4.0),
+                                    }),
+                                    ..Default::default()
+                                },
+                                drop_color.scale_alpha(0.1),
+                            );
+                            span.quad();
+                        }
                 
-                // Draw hover/focus border
-                if state.focused == Some(id) || state.hovered == Some(id) {
-                    renderer.fill_quad(
-                        renderer::Quad {
-                            bounds: Rectangle {
-                                x: bounds.x,
-                                y,
-                                width: bounds.width,
-                                height: branch_height,
-                            },
-                            border: Border {
-                                color: tree_style.focus_border,
-                                width: 1.0,
-                                radius: Radius::from(2.0),
-                            },
-                            ..Default::default()
-                        },
-                        iced::Background::Color(Color::TRANSPARENT),
-                    );
-                }
-                
-                // Draw expand/collapse arrow
-                if branch.has_children {
-                    if self.expand_icon.is_none() && self.collapse_icon.is_none() {
-                        // Use default text icons
-                        let arrow = if state.expanded.contains(&id) { "🠻" } else { "🠺" };
-                        
-                        renderer.fill_text(
-                            iced::advanced::Text {
-                                content: arrow.into(),
-                                bounds: Size::new(ARROW_W, branch_height),
-                                size: Pixels(16.0),
-                                font: iced::Font::default(),
-                                align_x: Alignment::Center,
-                                align_y: iced::alignment::Vertical::Center,
-                                line_height: iced::advanced::text::LineHeight::default(),
-                                shaping: iced::advanced::text::Shaping::Advanced,
-                                wrapping: iced::advanced::text::Wrapping::default(),
+                    // Draw hover/focus border
+                    if state.focused == Some(id) || state.hovered == Some(id) {
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: pixel::snap(Rectangle {
+                                    x: bounds.x,
+                                    y,
+                                    width: bounds.width,
+                                    height: branch_height,
+                                }),
+                                border: pixel::border(Border {
+                                    color: tree_style.focus_border,
+                                    width: 1.0,
+                                    radius: Radius::from(2.0),
+                                }),
+                                ..Default::default()
                             },
-                            Point::new(indent_x + ARROW_X_PAD, y + (branch_height / 2.0)),
-                            tree_style.arrow_color,
-                            *viewport,
+                            iced::Background::Color(Color::TRANSPARENT),
                         );
-                    } else {
-                        // Draw custom icon Element
-                        // Calculate which icon tree to use based on branch index among expandable branches
-                        let expandable_branch_index = self.branches[..i]
-                            .iter()
-                            .filter(|b| b.has_children)
-                            .count();
-                        
-                        let (icon_element, icon_tree_index) = if state.expanded.contains(&id) {
-                            // collapse_icon is at index 1 (if both icons exist) or 0 (if only collapse exists)
-                            let idx = if self.expand_icon.is_some() { 1 } else { 0 };
-                            (self.collapse_icon.as_ref().unwrap(), idx)
+                        span.quad();
+                    }
+                
+                    // Guide lines, from the row above down to this one and on to the next sibling
+                    if let Some((parents, continued)) = &guides
+                        && let Some(parent) = parent_id
+                        && effective_depth > 0
+                    {
+                        let top = branch_y - self.spacing;
+                        let middle = branch_y + branch_height / 2.0;
+                        let mut guide = |x: f32, y: f32, width: f32, height: f32| {
+                            renderer.fill_quad(
+                                renderer::Quad {
+                                    bounds: pixel::snap(mirror(Rectangle { x, y, width, height })),
+                                    border: Border::default(),
+                                    ..Default::default()
+                                },
+                                tree_style.line_color,
+                            );
+                            span.quad();
+                        };
+
+                        // The elbow to this branch, whose line ends here at the last child
+                        let elbow_x = guide_x(effective_depth - 1);
+                        let elbow_bottom = if continued.contains(&id) {
+                            branch_y + branch_height
                         } else {
-                            // expand_icon is always at index 0
-                            (self.expand_icon.as_ref().unwrap(), 0)
+                            middle + crate::depth::GUIDE_WIDTH / 2.0
                         };
-                        
-                        // Create a simple layout for the icon
-                        let icon_bounds = Rectangle {
-                            x: indent_x + ARROW_X_PAD,
-                            y: branch_y,
-                            width: ARROW_W,
-                            height: branch_height,
+                        let elbow_end = if branch.is_expandable() {
+                            indent_x + arrow_center - ARROW_W / 2.0
+                        } else {
+                            indent_x + ARROW_W
                         };
-                        
-                        let icon_layout = layout::Node::new(Size::new(ARROW_W, branch_height))
-                            .move_to(Point::new(icon_bounds.x, icon_bounds.y));
-                        
-                        icon_element.as_widget().draw(
-                            &tree.children[icon_tree_index],
-                            renderer,
-                            theme,
-                            style,
-                            Layout::new(&icon_layout),
-                            cursor,
-                            viewport,
-                        );
+                        guide(elbow_x, top, crate::depth::GUIDE_WIDTH, elbow_bottom - top);
+                        guide(elbow_x, middle - crate::depth::GUIDE_WIDTH / 2.0, (elbow_end - elbow_x).max(0.0), crate::depth::GUIDE_WIDTH);
+
+                        // The lines of ancestors with siblings still to come pass by
+                        let mut ancestor = parent;
+                        let mut depth = effective_depth - 1;
+                        while depth > 0 {
+                            if continued.contains(&ancestor) {
+                                guide(guide_x(depth - 1), top, crate::depth::GUIDE_WIDTH, branch_y + branch_height - top);
+                            }
+                            match parents.get(&ancestor) {
+                                Some(Some(parent)) => ancestor = *parent,
+                                _ => break,
+                            }
+                            depth -= 1;
+                        }
                     }
-                }
+
+                    // Disabled branches draw their text and arrow greyed out
+                    let content_style = if branch.disabled {
+                        renderer::Style { text_color: tree_style.disabled_text }
+                    } else {
+                        *style
+                    };
+
+                    // Draw expand/collapse arrow
+                    self.draw_arrow(tree, renderer, theme, &content_style, &tree_style, cursor, viewport, bounds, i, Rectangle {
+                        x: indent_x,
+                        y: branch_y,
+                        width: ARROW_W,
+                        height: branch_height,
+                    });
                 
-                // Draw handle/drag area
-                let handle_x = indent_x + ARROW_W;
-                let handle_width = HANDLE_STRIPE_W;
+                    // Draw handle/drag area
+                    let handle_x = indent_x + ARROW_W;
+                    let handle_width = HANDLE_STRIPE_W;
                 
-                renderer.fill_quad(
-                    renderer::Quad {
-                        bounds: Rectangle {
-                            x: handle_x,
-                            y: branch_y + 2.0,
-                            width: handle_width,
-                            height: branch_height - 4.0,
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: pixel::snap(mirror(Rectangle {
+                                x: handle_x,
+                                y: branch_y + 2.0,
+                                width: handle_width,
+                                height: branch_height - 4.0,
+                            })),
+                            border: Border::default(),
+                            ..Default::default()
                         },
-                        border: Border::default(),
-                        ..Default::default()
-                    },
-                    tree_style.line_color,
-                );
+                        tree_style.line_color,
+                    );
+                    span.quad();
+
+                    if let Some(check_states) = &check_states {
+                        let row = Rectangle { x: bounds.x, y: branch_y, width: bounds.width, height: branch_height };
+                        let checkbox = self.checkbox_bounds(indent_x, row);
+                        self.draw_checkbox(renderer, &tree_style, check_states[i], branch.disabled, mirror(checkbox), viewport);
+                        span.quad();
+                    }
                 
-                // Draw the branch content HERE for this specific branch
-                if let Some(ref drag) = state.drag_active {
-                    if !drag.dragged_nodes.contains(&id) {
+                    // Draw the branch content HERE for this specific branch
+                    if let Some(editing) = state.editing.as_ref().filter(|editing| editing.id == id) {
+                        let field_x = indent_x + self.content_offset() - EDIT_PAD;
+                        let field = Rectangle {
+                            x: field_x,
+                            y: branch_y + 2.0,
+                            width: (self.content_right(state, bounds) - field_x).max(0.0),
+                            height: (branch_height - 4.0).max(0.0),
+                        };
+                        self.draw_editor(renderer, &tree_style, editing, mirror(field), viewport);
+                        span.quads(2 + usize::from(editing.selection().is_some()));
+                    } else if !state.realized.get(i).copied().unwrap_or(true) {
+                        // Scrolled into view since the last layout, drawn once laid out
+                    } else if let Some(ref drag) = state.drag_active {
+                        if !drag.dragged_nodes.contains(&id) {
+                            let child_state = &tree.children[i + child_layout_index];
+                            let child_layout = layout.children().nth(i + self.get_child_content_index()).unwrap();
+                            self.branch_content[i].as_widget().draw(
+                                child_state, renderer, theme, &content_style, child_layout, cursor, viewport,
+                            );
+                        }
+                    } else {
                         let child_state = &tree.children[i + child_layout_index];
                         let child_layout = layout.children().nth(i + self.get_child_content_index()).unwrap();
                         self.branch_content[i].as_widget().draw(
-                            child_state, renderer, theme, style, child_layout, cursor, viewport,
+                            child_state, renderer, theme, &content_style, child_layout, cursor, viewport,
                         );
                     }
-                } else {
-                    let child_state = &tree.children[i + child_layout_index];
-                    let child_layout = layout.children().nth(i + self.get_child_content_index()).unwrap();
-                    self.branch_content[i].as_widget().draw(
-                        child_state, renderer, theme, style, child_layout, cursor, viewport,
-                    );
-                }
+
+                    // Cells in the columns after the tree's
+                    if state.realized.get(i).copied().unwrap_or(true) {
+                        for (index, cell) in self.cells(i) {
+                            if let Some(cell_layout) = layout.children().nth(self.branches.len() + index) {
+                                cell.as_widget().draw(
+                                    &tree.children[cells_start + index], renderer, theme, &content_style, cell_layout, cursor, viewport,
+                                );
+                            }
+                        }
+                    }
                 
+                    #[cfg(feature = "debug")]
+                    if debug::is_enabled() {
+                        self.outline_branch(renderer, state, i, Rectangle { x: bounds.x, y: branch_y, width: bounds.width, height: branch_height });
+                    }
+                };
+                let slide = |renderer: &mut Renderer| {
+                    if offset == 0.0 {
+                        draw_row(renderer);
+                    } else {
+                        renderer.with_translation(Vector::new(0.0, offset), draw_row);
+                    }
+                };
+                match reveal {
+                    Some(clip) => renderer.with_layer(clip, slide),
+                    None => slide(renderer),
+                }
+
                 y += branch_height + self.spacing;
 
-                if let Some(ref drag) = state.drag_active
-                    && drag.drop_target == Some(id) && 
-                    drag.drop_position == DropPosition::Into && 
-                    state.expanded.contains(&id) {
+                if drop == Some(&DropPosition::Into) && state.expanded.contains(&id) {
                         let child_preview_y = branch_y + branch_height + self.spacing;
                         let child_depth = effective_depth + 1;
                         draw_drop_preview(renderer, child_preview_y, child_depth, bounds.width);
                     }
 
-                if let Some(ref drag) = state.drag_active
-                    && drag.drop_target == Some(id) && drag.drop_position == DropPosition::After {
+                if drop == Some(&DropPosition::After) {
                         let is_last_visible_item = !ordered_indices.iter()
                             .skip_while(|&&j| j != i)
                             .skip(1)
@@ -1682,18 +4452,147 @@ where
                     // Draw selection rectangle outline
                     renderer.fill_quad(
                         renderer::Quad {
-                            bounds: rect_bounds,
-                            border: Border {
+                            bounds: pixel::snap(mirror(rect_bounds)),
+                            border: pixel::border(Border {
                                 color: tree_style.selection_border,
                                 width: 1.0,
                                 radius: Radius::from(2.0),
-                            },
+                            }),
                             ..Default::default()
                         },
                         tree_style.selection_border.scale_alpha(0.1),
                     );
+                    span.quad();
                 }
             }
+
+            // Pinned parents, drawn where they were laid out and moved over the rows underneath
+            if !sticky.is_empty() {
+                renderer.with_layer(*viewport, |renderer| {
+                    for pinned in &sticky {
+                        let i = pinned.index;
+                        let branch = &self.branches[i];
+                        let (id, _, effective_depth) = self.get_branch_info(i, state);
+                        let row = Rectangle { y: pinned.bounds.y - pinned.offset, ..pinned.bounds };
+                        let indent_x = bounds.x + self.padding_x + (effective_depth as f32 * self.indent);
+                        let row_viewport = Rectangle { y: viewport.y - pinned.offset, ..*viewport };
+                        let content_style = if branch.disabled {
+                            renderer::Style { text_color: tree_style.disabled_text }
+                        } else {
+                            *style
+                        };
+                        span.row();
+
+                        renderer.with_translation(Vector::new(0.0, pinned.offset), |renderer| {
+                            let background = if state.selected.contains(&id) {
+                                tree_style.selection_background
+                            } else {
+                                Color::TRANSPARENT
+                            };
+                            for color in [tree_style.sticky_background, background] {
+                                renderer.fill_quad(
+                                    renderer::Quad {
+                                        bounds: pixel::snap(row),
+                                        border: Border::default(),
+                                        ..Default::default()
+                                    },
+                                    color,
+                                );
+                                span.quad();
+                            }
+
+                            if state.focused == Some(id) || state.hovered == Some(id) {
+                                renderer.fill_quad(
+                                    renderer::Quad {
+                                        bounds: pixel::snap(row),
+                                        border: pixel::border(Border {
+                                            color: tree_style.focus_border,
+                                            width: 1.0,
+                                            radius: Radius::from(2.0),
+                                        }),
+                                        ..Default::default()
+                                    },
+                                    iced::Background::Color(Color::TRANSPARENT),
+                                );
+                                span.quad();
+                            }
+
+                            self.draw_arrow(tree, renderer, theme, &content_style, &tree_style, cursor, &row_viewport, bounds, i, Rectangle {
+                                x: indent_x,
+                                y: row.y,
+                                width: ARROW_W,
+                                height: row.height,
+                            });
+
+                            renderer.fill_quad(
+                                renderer::Quad {
+                                    bounds: pixel::snap(mirror(Rectangle {
+                                        x: indent_x + ARROW_W,
+                                        y: row.y + 2.0,
+                                        width: HANDLE_STRIPE_W,
+                                        height: row.height - 4.0,
+                                    })),
+                                    border: Border::default(),
+                                    ..Default::default()
+                                },
+                                tree_style.line_color,
+                            );
+                            span.quad();
+
+                            if let Some(check_states) = &check_states {
+                                let checkbox = self.checkbox_bounds(indent_x, row);
+                                self.draw_checkbox(renderer, &tree_style, check_states[i], branch.disabled, mirror(checkbox), &row_viewport);
+                                span.quad();
+                            }
+
+                            if let Some(editing) = state.editing.as_ref().filter(|editing| editing.id == id) {
+                                let field_x = indent_x + self.content_offset() - EDIT_PAD;
+                                let field = Rectangle {
+                                    x: field_x,
+                                    y: row.y + 2.0,
+                                    width: (self.content_right(state, bounds) - field_x).max(0.0),
+                                    height: (row.height - 4.0).max(0.0),
+                                };
+                                self.draw_editor(renderer, &tree_style, editing, mirror(field), &row_viewport);
+                                span.quads(2 + usize::from(editing.selection().is_some()));
+                            } else if state.realized.get(i).copied().unwrap_or(true) {
+                                let child_layout = layout.children().nth(i + child_layout_index).unwrap();
+                                self.branch_content[i].as_widget().draw(
+                                    &tree.children[i + child_layout_index],
+                                    renderer,
+                                    theme,
+                                    &content_style,
+                                    child_layout,
+                                    cursor,
+                                    &row_viewport,
+                                );
+                            }
+                        });
+                    }
+
+                    // A line under the last, where the rows slide underneath
+                    if let Some(last) = sticky.last() {
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: pixel::snap(Rectangle {
+                                    y: last.bounds.y + last.bounds.height,
+                                    height: 1.0,
+                                    ..last.bounds
+                                }),
+                                border: Border::default(),
+                                ..Default::default()
+                            },
+                            tree_style.line_color.scale_alpha(0.3),
+                        );
+                        span.quad();
+                    }
+                });
+            }
+
+            #[cfg(feature = "debug")]
+            if debug::is_enabled() {
+                self.report(renderer, state, bounds);
+            }
         });
     }
 
@@ -1707,6 +4606,11 @@ where
     ) -> mouse::Interaction {
         let combined_state = tree.state.downcast_ref::<CombinedState<Renderer::Paragraph>>();
         let state = &combined_state.tree_state;
+        let cursor = if self.sticky_headers > 0 {
+            self.unpin_cursor(state, &self.get_ordered_indices(state), layout.bounds(), viewport, cursor)
+        } else {
+            cursor
+        };
         
         let child_layout_index = self.get_child_content_index();
         let child_interaction = self.branch_content
@@ -1715,14 +4619,35 @@ where
             .zip(layout.children().skip(child_layout_index))
             .enumerate()
             .filter(|(i, _)| {
-                state.visible_branches.get(*i).copied().unwrap_or(false)
+                state.visible_branches.get(*i).copied().unwrap_or(false) && !self.branches[*i].disabled
             })
             .map(|(_, ((branch, child_state), child_layout))| {
                 branch.as_widget().mouse_interaction(
                     child_state, child_layout, cursor, viewport, renderer,
                 )
             })
-            .find(|&interaction| interaction != mouse::Interaction::None);
+            .find(|&interaction| interaction != mouse::Interaction::None)
+            .or_else(|| {
+                (0..self.branches.len())
+                    .filter(|&i| state.visible_branches.get(i).copied().unwrap_or(false) && !self.branches[i].disabled)
+                    .flat_map(|i| self.cells(i))
+                    .filter_map(|(index, cell)| {
+                        let cell_layout = layout.children().nth(self.branches.len() + index)?;
+                        let cell_state = &tree.children[child_layout_index + self.branches.len() + index];
+                        Some(cell.as_widget().mouse_interaction(cell_state, cell_layout, cursor, viewport, renderer))
+                    })
+                    .find(|&interaction| interaction != mouse::Interaction::None)
+            });
+
+        // Over the edge of a column's title, or while dragging it, the column resizes
+        let bounds = layout.bounds();
+        if state.resizing.is_some()
+            || self.layout_direction().mirror_cursor(cursor, bounds).position().is_some_and(|position| {
+                self.resize_handle_at(state, bounds, position).is_some()
+            })
+        {
+            return mouse::Interaction::ResizingHorizontally;
+        }
         
         // If a child has a specific interaction, use it
         if let Some(interaction) = child_interaction {
@@ -1745,6 +4670,14 @@ where
         renderer: &Renderer,
         operation: &mut dyn widget::Operation,
     ) {
+        let state = &mut tree.state.downcast_mut::<CombinedState<Renderer::Paragraph>>().tree_state;
+        operation.custom(self.id.as_ref(), layout.bounds(), state);
+        self.apply_requests(state, layout.bounds());
+        if let Some((branch, row)) = state.revealed.take() {
+            operation.custom(self.id.as_ref(), row, &mut Revealed(branch));
+        }
+        operation.focusable(self.id.as_ref(), layout.bounds(), state);
+
         let child_layout_index = self.get_child_content_index();
         
         for i in 0..self.branch_content.len() {
@@ -1757,6 +4690,17 @@ where
                 );
             }
         }
+
+        let cells_start = child_layout_index + self.branch_content.len();
+        for (index, (cell, cell_layout)) in self
+            .branch_cells
+            .iter_mut()
+            .flatten()
+            .zip(layout.children().skip(self.branch_content.len()))
+            .enumerate()
+        {
+            cell.as_widget_mut().operate(&mut tree.children[cells_start + index], cell_layout, renderer, operation);
+        }
     }
 
     fn overlay<'b>(
@@ -1829,7 +4773,6 @@ where
 // Custom overlay for rendering dragged items
 struct DragOverlay<'a, 'b, Message, Theme, Renderer>
 where 
-    Message: Clone,
     Theme: Catalog,
     Renderer: iced::advanced::text::Renderer,
 {
@@ -1842,10 +4785,54 @@ where
     translation: Vector,
 }
 
+impl<Message, Theme, Renderer> DragOverlay<'_, '_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: iced::advanced::text::Renderer,
+{
+    /// Asks the app to scroll while the drag is near the top or bottom of the viewport,
+    /// returning whether it is.
+    fn auto_scroll(&mut self, position: Point, now: Instant, shell: &mut Shell<'_, Message>) -> bool {
+        let Some(on_auto_scroll) = &self.tree_handle.on_auto_scroll else {
+            return false;
+        };
+        let combined_state = self.state.state.downcast_mut::<CombinedState<Renderer::Paragraph>>();
+        let Some(drag) = &mut combined_state.tree_state.drag_active else {
+            return false;
+        };
+
+        let (edge, speed) = self.tree_handle.auto_scroll;
+        let above = self.viewport.y + edge - position.y;
+        let below = position.y - (self.viewport.y + self.viewport.height - edge);
+        let direction = if edge <= 0.0 {
+            0.0
+        } else if above > 0.0 {
+            -(above / edge).min(1.0)
+        } else if below > 0.0 {
+            (below / edge).min(1.0)
+        } else {
+            0.0
+        };
+
+        if direction == 0.0 {
+            drag.scrolled_at = None;
+            return false;
+        }
+
+        // Paced by the time between frames, skipping the first and long pauses
+        let elapsed = drag.scrolled_at.map_or(0.0, |then| now.duration_since(then).as_secs_f32().min(0.1));
+        drag.scrolled_at = Some(now);
+        if elapsed > 0.0 {
+            shell.publish(on_auto_scroll(direction * speed * elapsed));
+        }
+        shell.request_redraw();
+        true
+    }
+}
+
 impl<'a, Message, Theme, Renderer> iced::advanced::overlay::Overlay<Message, Theme, Renderer> 
     for DragOverlay<'_, '_, Message, Theme, Renderer>
 where
-    Message: Clone,
     Theme: Catalog,
     Renderer: iced::advanced::Renderer + iced::advanced::text::Renderer<Font = iced::Font>,
 {
@@ -1877,7 +4864,7 @@ where
                     };
                     
                     let indent_x = effective_depth as f32 * self.tree_handle.indent;
-                    let content_width = indent_x + ARROW_W + CONTENT_GAP + combined_state.tree_state.branch_widths[i] + self.tree_handle.padding_x;
+                    let content_width = indent_x + self.tree_handle.content_offset() + combined_state.tree_state.branch_widths[i] + self.tree_handle.padding_x;
                     max_width = max_width.max(content_width);
                     
                     total_height += combined_state.tree_state.branch_heights[i].max(LINE_HEIGHT);
@@ -1892,6 +4879,15 @@ where
             (310.0, LINE_HEIGHT)
         };      
 
+        // Right to left, the ghost follows the cursor on the other side of the tree
+        let dir = self.tree_handle.layout_direction();
+        let position = Point::new(
+            dir.mirror_x(position.x - self.translation.x, self.tree_layout.bounds())
+                - if dir.is_rtl() { width } else { 0.0 }
+                + self.translation.x,
+            position.y,
+        );
+
         layout::Node::new(Size::new(width, height))
             .move_to(position)
     }
@@ -1905,120 +4901,86 @@ where
         _clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
     ) {
+        // A finger dragging after a long press moves and drops like the mouse
+        let touch_drag = self.state.state.downcast_ref::<CombinedState<Renderer::Paragraph>>().tree_state.touch_drag;
+        let touch_event = match event {
+            Event::Touch(touch::Event::FingerMoved { position, .. }) if touch_drag => Some((
+                Event::Mouse(mouse::Event::CursorMoved { position: *position }),
+                *position,
+            )),
+            Event::Touch(touch::Event::FingerLifted { position, .. } | touch::Event::FingerLost { position, .. })
+                if touch_drag =>
+            {
+                Some((Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)), *position))
+            }
+            _ => None,
+        };
+        let (event, cursor) = match &touch_event {
+            Some((mouse_event, position)) => (mouse_event, mouse::Cursor::Available(*position)),
+            None => (event, cursor),
+        };
+
+        // Scrolled, the branches move under the cursor as if it had moved over them
+        let scrolled_event;
+        let event = match (event, cursor.position()) {
+            (Event::Window(iced::window::Event::RedrawRequested(now)), Some(position)) if self.auto_scroll(position, *now, shell) => {
+                scrolled_event = Event::Mouse(mouse::Event::CursorMoved { position });
+                &scrolled_event
+            }
+            _ => event,
+        };
+
         match event {
             Event::Mouse(mouse::Event::CursorMoved { .. }) => {
                 if let Some(position) = cursor.position() {
                     let combined_state = self.state.state.downcast_mut::<CombinedState<Renderer::Paragraph>>();
                     let ordered_indices = self.tree_handle.get_ordered_indices(&combined_state.tree_state);
         
-                    let position = Point::new(
-                        position.x - self.translation.x,
-                        position.y - self.translation.y,
+                    dnd::update(position);
+                    let window_position = position;
+
+                    let position = self.tree_handle.layout_direction().mirror_point(
+                        Point::new(position.x - self.translation.x, position.y - self.translation.y),
+                        self.tree_layout.bounds(),
                     );
                     
-                    let branch_infos: Vec<_> = ordered_indices.iter()
-                        .filter_map(|&i| {
-                            if i >= self.tree_handle.branches.len() || 
-                               i >= combined_state.tree_state.visible_branches.len() || 
-                               !combined_state.tree_state.visible_branches[i] {
-                                return None;
-                            }
-                            
-                            let branch = &self.tree_handle.branches[i];
-                            let (id, parent_id, depth) = self.tree_handle.get_branch_info(i, &combined_state.tree_state);
-                            
-                            let branch_height = if i < combined_state.tree_state.branch_heights.len() {
-                                combined_state.tree_state.branch_heights[i]
-                            } else {
-                                LINE_HEIGHT
-                            };
-                            
-                            Some((
-                                id,
-                                parent_id,
-                                depth,
-                                branch_height,
-                                branch.has_children,
-                                combined_state.tree_state.expanded.contains(&id),
-                                branch.accepts_drops
-                            ))
-                        })
-                        .collect();
-                    
+                    let drop = combined_state.tree_state.drag_active.as_ref().and_then(|drag| {
+                        self.tree_handle.drop_at(
+                            &combined_state.tree_state,
+                            &ordered_indices,
+                            self.tree_layout.bounds(),
+                            position,
+                            &drag.dragged_nodes,
+                        )
+                    });
+
                     if let Some(ref mut drag) = combined_state.tree_state.drag_active {
                         drag.current_position = position;
                         
                         let tree_bounds = self.tree_layout.bounds();
-                        let mut new_drop_target = drag.drop_target;
-                        let mut new_drop_position = drag.drop_position.clone();
-                        
-                        let mut branch_positions = Vec::new();
-                        let mut y = tree_bounds.y + self.tree_handle.padding_y;
-                        
-                        for (id, parent_id, depth, branch_height, has_children, is_expanded, accepts_drops) in &branch_infos {
-                            if drag.dragged_nodes.contains(id) {
-                                continue;
-                            }
-                            
-                            branch_positions.push((
-                                *id,
-                                *parent_id,
-                                *depth,
-                                y,
-                                *branch_height,
-                                *has_children,
-                                *is_expanded,
-                                *accepts_drops
-                            ));
-                            
-                            y += branch_height + self.tree_handle.spacing;
-                        }
-                        
-                        let mut found_target = false;
-                        for (id, _parent_id, _depth, branch_y, height, has_children, is_expanded, accepts_drops) in &branch_positions {
-                            let row_bounds = Rectangle {
-                                x: tree_bounds.x,
-                                y: *branch_y,
-                                width: tree_bounds.width,
-                                height: *height,
-                            };
-                            
-                            let expanded_bounds = Rectangle {
-                                x: row_bounds.x,
-                                y: row_bounds.y - 2.0,
-                                width: row_bounds.width,
-                                height: row_bounds.height + 4.0,
-                            };
-                            
-                            if expanded_bounds.contains(position) {
-                                found_target = true;
-                                new_drop_target = Some(*id);
-                                
-                                new_drop_position = self.tree_handle.calculate_drop_position(
-                                    position.y,
-                                    row_bounds,
-                                    *has_children,
-                                    *is_expanded,
-                                    *accepts_drops  // Pass accepts_drops
-                                );
-                                break;
-                            }
-                        }
 
-                        if !found_target && position.y > tree_bounds.y && !branch_positions.is_empty() {
-                            let (last_id, _, _, last_y, last_height, _, _, _) = 
-                                branch_positions.last().unwrap();
-                            
-                            if position.y > last_y + last_height {
-                                new_drop_target = Some(*last_id);
-                                new_drop_position = DropPosition::After;
-                            }
+                        let outside = !tree_bounds.contains(position);
+                        if outside && !drag.outside
+                            && let Some(ref on_drag_out) = self.tree_handle.on_drag_out
+                        {
+                            let dragged = drag.dragged_nodes.iter().map(|&id| self.tree_handle.preferred_id(id)).collect();
+                            shell.publish(on_drag_out(dragged, window_position));
                         }
-                        
+                        drag.outside = outside;
+
+                        let (new_drop_target, new_drop_position) = match drop {
+                            Some((target, position)) => (Some(target), position),
+                            None => (drag.drop_target, drag.drop_position.clone()),
+                        };
+
                         let changed = new_drop_target != drag.drop_target || 
                                       new_drop_position != drag.drop_position;
                         
                         if changed {
+                            combined_state.tree_state.drop_denied = new_drop_target.is_some_and(|target| {
+                                let dragged = drag.dragged_nodes.iter().map(|&id| self.tree_handle.preferred_id(id)).collect();
+                                !self.tree_handle.allows_drop(dragged, target, &new_drop_position)
+                            });
                             drag.drop_target = new_drop_target;
                             drag.drop_position = new_drop_position;
                             shell.invalidate_layout();
@@ -2030,10 +4992,14 @@ where
             }
             
             Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
-                let (drop_target, drop_position, dragged_nodes, dragged_external, target_external) = {
+                let (drop_target, drop_position, dragged_nodes, dragged_external, target_external, own_drop) = {
                     let combined_state = self.state.state.downcast_mut::<CombinedState<Renderer::Paragraph>>();
+                    // A drop target outside the tree takes the branches instead
+                    let own_drop = combined_state.tree_state.drag_active.as_ref().is_some_and(|drag| drag.press.release());
 
-                    if let Some(ref drag) = combined_state.tree_state.drag_active {
+                    if let Some(ref drag) = combined_state.tree_state.drag_active
+                        && !combined_state.tree_state.drop_denied
+                    {
                         // Convert internal IDs to external IDs while we have access to everything
                         let dragged_ext: Vec<usize> = drag
                             .dragged_nodes
@@ -2048,16 +5014,20 @@ where
                             drag.drop_position.clone(), 
                             drag.dragged_nodes.clone(),
                             dragged_ext,
-                            target_ext
+                            target_ext,
+                            own_drop,
                         )
                     } else {
-                        (None, DropPosition::Before, vec![], vec![], None)
+                        (None, DropPosition::Before, vec![], vec![], None, own_drop)
                     }
                 };
-                
-                if let Some(target_id) = drop_target {
+
+                if let Some(target_id) = drop_target
+                    && own_drop
+                {
                     // Use internal IDs for reordering
-                    self.reorder_branches(&dragged_nodes, target_id, &drop_position);
+                    let combined_state = self.state.state.downcast_mut::<CombinedState<Renderer::Paragraph>>();
+                    self.tree_handle.reorder_branches(&mut combined_state.tree_state, &dragged_nodes, target_id, &drop_position, true);
                     
                     // Use external IDs for the callback
                     if let Some(ref on_drop) = self.tree_handle.on_drop
@@ -2079,7 +5049,22 @@ where
             
             Event::Mouse(mouse::Event::CursorLeft) => {
                 let combined_state = self.state.state.downcast_mut::<CombinedState<Renderer::Paragraph>>();
-                combined_state.tree_state.drag_active = None;
+                if let Some(drag) = combined_state.tree_state.drag_active.take() {
+                    drag.press.cancel();
+                }
+                shell.invalidate_layout();
+                shell.request_redraw();
+            }
+
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(keyboard::key::Named::Escape),
+                ..
+            }) => {
+                let combined_state = self.state.state.downcast_mut::<CombinedState<Renderer::Paragraph>>();
+                if let Some(drag) = combined_state.tree_state.drag_active.take() {
+                    drag.press.cancel();
+                }
+                shell.capture_event();
                 shell.invalidate_layout();
                 shell.request_redraw();
             }
@@ -2099,6 +5084,10 @@ where
         let child_layout_index = self.tree_handle.get_child_content_index();
         let drag_bounds = layout.bounds();
         let tree_style = theme.style(&self.tree_handle.class);
+
+        // The ghost mirrors itself right to left, its content having been mirrored in the layout
+        let dir = self.tree_handle.layout_direction();
+        let mirror = |rectangle: Rectangle| dir.mirror(rectangle, drag_bounds);
         
         renderer.with_layer(self.viewport, |renderer| {
             let primary_index = self.tree_handle.branches
@@ -2141,12 +5130,12 @@ where
             // Draw the branch background with decorations
             renderer.fill_quad(
                 renderer::Quad {
-                    bounds: branch_bounds,
-                    border: Border {
+                    bounds: pixel::snap(mirror(branch_bounds)),
+                    border: pixel::border(Border {
                         color: tree_style.selection_border.scale_alpha(0.9),
                         width: 2.0,
                         radius: Radius::from(2.0),
-                    },
+                    }),
                     ..Default::default()
                 },
                 tree_style.selection_background.scale_alpha(0.9),
@@ -2158,12 +5147,12 @@ where
             
             renderer.fill_quad(
                 renderer::Quad {
-                    bounds: Rectangle {
+                    bounds: pixel::snap(mirror(Rectangle {
                         x: handle_x,
                         y: drag_bounds.y + 2.0,
                         width: HANDLE_STRIPE_W,
                         height: branch_height - 4.0,
-                    },
+                    })),
                     border: Border::default(),
                     ..Default::default()
                 },
@@ -2171,9 +5160,13 @@ where
             );
             
             // Draw the content
-            let content_x = indent_x + ARROW_W + CONTENT_GAP;
+            let content_x = indent_x + self.tree_handle.content_offset();
+            let content_bounds = mirror(Rectangle {
+                x: drag_bounds.x + content_x,
+                ..self.layout.bounds()
+            });
             let translation = Vector::new(
-                (drag_bounds.x + content_x) - self.layout.bounds().x,
+                content_bounds.x - self.layout.bounds().x,
                 drag_bounds.y - self.layout.bounds().y,
             );
             
@@ -2216,143 +5209,131 @@ where
     }
 }
 
-impl<'a, 'b, Message, Theme, Renderer> DragOverlay<'a, 'b, Message, Theme, Renderer>
-where 
-    Message: Clone,
-    Theme: Catalog,
-    Renderer: iced::advanced::text::Renderer<Font = iced::Font>,
-{
-    fn reorder_branches(
-        &mut self,
-        dragged_ids: &[usize],
-        target_id: usize,
-        drop_position: &DropPosition,
-    ) {
-        // Get the current order before we borrow state mutably
-        let (current_order, branches_copy) = {
-            let combined_state = self.state.state.downcast_mut::<CombinedState<Renderer::Paragraph>>();
-            let order = if let Some(ref branch_order) = combined_state.tree_state.branch_order {
-                branch_order.clone()
-            } else {
-                self.tree_handle.branches.iter().map(|b| BranchState {
-                    id: b.id,
-                    parent_id: b.parent_id,
-                    depth: b.depth,
-                }).collect()
-            };
-            (order, self.tree_handle.branches.clone())
-        };
-        
-        let state_map: HashMap<usize, BranchState> = current_order.iter()
-            .map(|bs| (bs.id, bs.clone()))
-            .collect();
-        
-        // Use standalone function to avoid borrow issues
-        let mut items_to_move = HashSet::new();
-        for &id in dragged_ids {
-            collect_branch_and_descendants(id, &mut items_to_move, &current_order);
-        }
-        
-        let target_state = state_map.get(&target_id)
-            .cloned()
-            .unwrap_or(BranchState {
-                id: target_id,
-                parent_id: None,
-                depth: 0,
-            });
-        
-        let mut new_order: Vec<BranchState> = Vec::new();
-        let mut removed_items: Vec<BranchState> = Vec::new();
-        
-        for bs in current_order {
-            if items_to_move.contains(&bs.id) {
-                removed_items.push(bs);
-            } else {
-                new_order.push(bs);
+// Standalone helper functions to avoid borrow issues
+/// Expands the branch with external id `branch` in the tree with the given Id.
+///
+/// Unlike a click, this does not publish `on_toggle`.
+pub fn expand<T>(id: widget::Id, branch: usize) -> impl widget::Operation<T> {
+    request(id, Request::Expand(branch, true))
+}
+
+/// Collapses the branch with external id `branch` in the tree with the given Id.
+///
+/// Unlike a click, this does not publish `on_toggle`.
+pub fn collapse<T>(id: widget::Id, branch: usize) -> impl widget::Operation<T> {
+    request(id, Request::Expand(branch, false))
+}
+
+/// Selects exactly the branches with the given external ids in the tree with the given Id.
+///
+/// Unlike a click, this does not publish `on_select`.
+pub fn select<T>(id: widget::Id, branches: impl IntoIterator<Item = usize>) -> impl widget::Operation<T> {
+    request(id, Request::Select(branches.into_iter().collect()))
+}
+
+/// Moves keyboard focus to the branch with external id `branch` in the tree with the given Id.
+pub fn focus<T>(id: widget::Id, branch: usize) -> impl widget::Operation<T> {
+    request(id, Request::Focus(branch))
+}
+
+/// Reports the external ids of the branches selected in the tree with the given Id.
+pub fn selected(id: widget::Id) -> impl widget::Operation<HashSet<usize>> {
+    operation::query(id, |state: &TreeState| state.selected.iter().map(|&id| state.external_id(id)).collect())
+}
+
+/// Reports the external ids of the branches expanded in the tree with the given Id.
+pub fn expanded(id: widget::Id) -> impl widget::Operation<HashSet<usize>> {
+    operation::query(id, |state: &TreeState| state.expanded.iter().map(|&id| state.external_id(id)).collect())
+}
+
+/// Reports the external id of the branch focused in the tree with the given Id, if it has the focus.
+pub fn focused(id: widget::Id) -> impl widget::Operation<Option<usize>> {
+    operation::query(id, |state: &TreeState| state.focused.map(|id| state.external_id(id)))
+}
+
+/// Reports the expansion, selection and custom order of the tree with the given Id as a
+/// [`Snapshot`], to restore later with [`TreeHandle::snapshot`].
+pub fn snapshot(id: widget::Id) -> impl widget::Operation<Snapshot> {
+    operation::query(id, TreeState::snapshot)
+}
+
+/// Expands the ancestors of the branch with external id `branch` in the tree with the given Id
+/// and reports the bounds of its row, to scroll it into view.
+///
+/// The bounds are relative to the content of the closest scrollable around the tree, ready for
+/// [`scroll_to`](iced::widget::operation::scroll_to), or to the window without one. Rows the
+/// tree is yet to lay out count with the height they were last seen with, one line at first.
+/// Nothing is reported when the tree has no such branch or its filter hides it.
+///
+/// Unlike a click, this does not publish `on_toggle`.
+pub fn reveal(id: widget::Id, branch: usize) -> impl widget::Operation<Rectangle> {
+    struct Reveal {
+        id: widget::Id,
+        branch: usize,
+        // Where the content of the scrollable being traversed starts, and of the next one
+        origin: Vector,
+        content: Option<Vector>,
+        bounds: Option<Rectangle>,
+    }
+
+    impl widget::Operation<Rectangle> for Reveal {
+        fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn widget::Operation<Rectangle>)) {
+            let origin = self.origin;
+            if let Some(content) = self.content.take() {
+                self.origin = content;
             }
+            operate(self);
+            self.origin = origin;
         }
-        
-        let (new_parent_id, new_base_depth) = match drop_position {
-            DropPosition::Before => (target_state.parent_id, target_state.depth),
-            DropPosition::After => {
-                let is_last_item = new_order.iter()
-                    .rposition(|bs| bs.id == target_id)
-                    .map(|idx| idx == new_order.len() - 1 || 
-                        !new_order[idx + 1..].iter().any(|bs| bs.parent_id == target_state.parent_id))
-                    .unwrap_or(false);
-                
-                if is_last_item && target_state.parent_id.is_some() {
-                    let has_root_siblings_after = new_order.iter()
-                        .skip_while(|bs| bs.id != target_id)
-                        .skip(1)
-                        .any(|bs| bs.parent_id.is_none());
-                    
-                    if !has_root_siblings_after {
-                        (None, 0)
-                    } else {
-                        (target_state.parent_id, target_state.depth)
-                    }
-                } else {
-                    (target_state.parent_id, target_state.depth)
-                }
-            }
-            DropPosition::Into => (Some(target_id), target_state.depth + 1),
-        };
-        
-        let insertion_index = match drop_position {
-            DropPosition::Before => {
-                new_order.iter().position(|bs| bs.id == target_id)
-                    .unwrap_or(new_order.len())
-            }
-            DropPosition::Into => {
-                let parent_pos = new_order.iter().position(|bs| bs.id == target_id)
-                    .unwrap_or(new_order.len());
-                parent_pos + 1
+
+        fn scrollable(
+            &mut self,
+            _id: Option<&widget::Id>,
+            _bounds: Rectangle,
+            content_bounds: Rectangle,
+            _translation: Vector,
+            _state: &mut dyn widget::operation::Scrollable,
+        ) {
+            self.content = Some(Vector::new(content_bounds.x, content_bounds.y));
+        }
+
+        fn custom(&mut self, id: Option<&widget::Id>, bounds: Rectangle, state: &mut dyn std::any::Any) {
+            if id != Some(&self.id) {
+                return;
             }
-            DropPosition::After => {
-                let mut idx = new_order.iter().position(|bs| bs.id == target_id)
-                    .map(|i| i + 1)
-                    .unwrap_or(new_order.len());
-                
-                while idx < new_order.len() {
-                    let current = &new_order[idx];
-                    if is_descendant_of(current.id, target_id, &new_order) {
-                        idx += 1;
-                    } else {
-                        break;
-                    }
-                }
-                idx
+
+            if let Some(state) = state.downcast_mut::<TreeState>() {
+                state.requests.push(Request::Reveal(self.branch));
+            } else if state.downcast_ref::<Revealed>().is_some_and(|revealed| revealed.0 == self.branch) {
+                self.bounds = Some(bounds - self.origin);
             }
-        };
-        
-        let old_depth = removed_items.iter()
-            .find(|bs| dragged_ids.contains(&bs.id))
-            .map(|bs| bs.depth)
-            .unwrap_or(0);
-        let depth_change = new_base_depth as i32 - old_depth as i32;
-        
-        let mut insert_offset = 0;
-        for mut bs in removed_items {
-            if dragged_ids.contains(&bs.id) {
-                bs.parent_id = new_parent_id;
-                bs.depth = new_base_depth;
-            } else {
-                bs.depth = (bs.depth as i32 + depth_change).max(0) as u16;
+        }
+
+        fn finish(&self) -> widget::operation::Outcome<Rectangle> {
+            match self.bounds {
+                Some(bounds) => widget::operation::Outcome::Some(bounds),
+                None => widget::operation::Outcome::None,
             }
-            new_order.insert(insertion_index + insert_offset, bs);
-            insert_offset += 1;
         }
-        
-        // Now update the state
-        let combined_state = self.state.state.downcast_mut::<CombinedState<Renderer::Paragraph>>();
-        combined_state.tree_state.branch_order = Some(new_order);
-        
-        self.tree_handle.update_has_children(&combined_state.tree_state);
+    }
+
+    Reveal {
+        id,
+        branch,
+        origin: Vector::ZERO,
+        content: None,
+        bounds: None,
     }
 }
 
-// Standalone helper functions to avoid borrow issues
+fn request<T>(id: widget::Id, request: Request) -> impl widget::Operation<T> {
+    let mut request = Some(request);
+
+    operation::on_state(id, move |state: &mut TreeState| {
+        state.requests.extend(request.take());
+    })
+}
+
 fn is_descendant_of(potential_child: usize, potential_ancestor: usize, states: &[BranchState]) -> bool {
     let mut current_id = Some(potential_child);
     
@@ -2429,7 +5410,7 @@ fn rectangles_intersect(a: &Rectangle, b: &Rectangle) -> bool {
 impl<'a, Message, Theme, Renderer> From<TreeHandle<'a, Message, Theme, Renderer>>
     for Element<'a, Message, Theme, Renderer>
 where
-    Message: Clone + 'a,
+    Message: 'a,
     Theme: Catalog + 'a,
     Renderer: iced::advanced::Renderer + iced::advanced::text::Renderer<Font = iced::Font> + 'a,
 {
@@ -2448,6 +5429,11 @@ pub struct Branch<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer> {
     pub align_y: iced::Alignment,
     pub accepts_drops: bool,
     pub draggable: bool, 
+    pub lazy: bool,
+    pub name: Option<String>,
+    pub label: Option<String>,
+    pub disabled: bool,
+    pub cells: Vec<Element<'a, Message, Theme, Renderer>>,
 }
 
 impl<'a, Message, Theme, Renderer> 
@@ -2479,10 +5465,40 @@ impl<'a, Message, Theme, Renderer>
         self
     }
 
+    /// Marks the branch as having unloaded children: the expand arrow shows while
+    /// `children` is empty, and expanding it publishes [`TreeHandle::on_expand`] so they
+    /// can be loaded. [`TreeHandle::on_toggle`] reports the expansion as well.
+    pub fn lazy(mut self) -> Self {
+        self.lazy = true;
+        self
+    }
+
     pub fn with_id(mut self, id: usize) -> Self {
         self.external_id = id;
         self
     }
+
+    /// Lets the branch be renamed in place, starting from `name`, when the tree has
+    /// [`TreeHandle::on_rename`].
+    pub fn renamable(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Sets the text [`TreeHandle::filter`] matches, instead of the name given with
+    /// [`Branch::renamable`].
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Greys out the branch and its descendants, drawn with [`Style::disabled_text`]. They
+    /// can't be selected, expanded, collapsed, dragged or dropped into, and their content
+    /// gets no events.
+    pub fn disabled(mut self) -> Self {
+        self.disabled = true;
+        self
+    }
 }
 
 /// The theme catalog for the tree widget
@@ -2502,6 +5518,8 @@ pub trait Catalog {
 pub struct Style {
     /// Text color
     pub text: Color,
+    /// Text and arrow color of disabled branches
+    pub disabled_text: Color,
     /// Selection background color
     pub selection_background: Color,
     /// Selection text color
@@ -2518,12 +5536,23 @@ pub struct Style {
     pub accept_drop_indicator_color: Color,
     /// Drop indicator color - Deny
     pub deny_drop_indicator_color: Color,
+    /// Background of the parents pinned by `sticky_headers`
+    pub sticky_background: Color,
+    /// Border of the checkboxes, and fill of the checked ones
+    pub checkbox: Color,
+    /// Check mark and dash of the checked and indeterminate checkboxes
+    pub checkmark: Color,
+    /// Background of the header row of `TreeColumns`
+    pub header_background: Color,
+    /// Titles in the header row of `TreeColumns`
+    pub header_text: Color,
 }
 
 impl Default for Style {
     fn default() -> Self {
         Self {
             text: Color::BLACK,
+            disabled_text: Color::from_rgb(0.6, 0.6, 0.6),
             selection_background: Color::from_rgba(0.0, 0.0, 0.0, 0.05),
             selection_text: Color::BLACK,
             selection_border: Color::from_rgb(0.0, 0.5, 1.0),
@@ -2532,6 +5561,11 @@ impl Default for Style {
             line_color: Color::from_rgb(0.3, 0.3, 0.3),
             accept_drop_indicator_color: Color::from_rgb(0.0, 0.8, 0.0),
             deny_drop_indicator_color: Color::from_rgb(1.0, 0.0, 0.0),
+            sticky_background: Color::WHITE,
+            checkbox: Color::from_rgb(0.0, 0.5, 1.0),
+            checkmark: Color::WHITE,
+            header_background: Color::from_rgb(0.95, 0.95, 0.95),
+            header_text: Color::BLACK,
         }
     }
 }
@@ -2543,24 +5577,50 @@ impl Catalog for iced::Theme {
     type Class<'a> = StyleFn<'a, Self>;
     
     fn default<'a>() -> Self::Class<'a> {
-        Box::new(|theme| {
-            let palette = theme.extended_palette();
-            
-            Style {
-                text: palette.background.base.text,
-                selection_background: Color::from_rgba(0.0, 0.0, 0.0, 0.1),
-                selection_text: palette.background.base.text,
-                selection_border: palette.secondary.base.color,
-                focus_border: palette.secondary.base.color.scale_alpha(0.5),
-                arrow_color: palette.background.base.text,
-                line_color: palette.primary.weak.color,
-                accept_drop_indicator_color: palette.primary.strong.color,
-                deny_drop_indicator_color: palette.danger.strong.color,
-            }
-        })
+        Box::new(default)
     }
     
     fn style(&self, class: &Self::Class<'_>) -> Style {
         class(self)
     }
+}
+
+/// The default tree style.
+pub fn default(theme: &iced::Theme) -> Style {
+    from_palette(Palette::new(theme, Preset::Standard))
+}
+
+/// The [`Preset::Compact`] tree style.
+pub fn compact(theme: &iced::Theme) -> Style {
+    from_palette(Palette::new(theme, Preset::Compact))
+}
+
+/// The [`Preset::Flat`] tree style.
+pub fn flat(theme: &iced::Theme) -> Style {
+    from_palette(Palette::new(theme, Preset::Flat))
+}
+
+/// The [`Preset::HighContrast`] tree style.
+pub fn high_contrast(theme: &iced::Theme) -> Style {
+    from_palette(Palette::new(theme, Preset::HighContrast))
+}
+
+fn from_palette(palette: Palette) -> Style {
+    Style {
+        text: palette.text,
+        disabled_text: palette.border,
+        selection_background: palette.highlight,
+        selection_text: palette.text,
+        selection_border: palette.selection,
+        focus_border: palette.selection.scale_alpha(0.5),
+        arrow_color: palette.text,
+        line_color: palette.accent_weak,
+        accept_drop_indicator_color: palette.accent,
+        deny_drop_indicator_color: palette.danger,
+        sticky_background: palette.background,
+        checkbox: palette.accent,
+        checkmark: palette.accent_text,
+        header_background: palette.raised,
+        header_text: palette.text,
+    }
 }
\ No newline at end of file
@@ -0,0 +1,264 @@
+//! A dropdown picking items from a tree.
+//!
+//! [`TreeSelect`] shows the current selection on a button that opens a [`TreeHandle`] of the
+//! items in a dropdown. With one item at a time, clicking an item picks it and closes the
+//! dropdown; with [`TreeSelect::multiple`], every item has a checkbox and checking a parent
+//! checks everything under it.
+//!
+//! The dropdown can have a search field on top, driven by the app through
+//! [`TreeSelect::search`]; items are shown while they or anything under them match.
+//!
+//! [`TreeHandle`]: crate::tree::TreeHandle
+
+use std::collections::HashSet;
+use std::rc::Rc;
+
+use iced::advanced::text;
+use iced::widget::{button, checkbox, column, container, mouse_area, row, scrollable, text as label, text_input};
+use iced::{mouse, Alignment, Element, Length};
+
+use crate::generic_overlay::{self, dropdown_root};
+use crate::tree::{self, branch, tree_handle, Branch};
+
+/// Creates a new [`TreeSelect`] of `items`, producing `on_change` with the new selection.
+pub fn tree_select<'a, Message>(
+    items: &'a [Item],
+    selected: &'a HashSet<usize>,
+    on_change: impl Fn(HashSet<usize>) -> Message + 'a,
+) -> TreeSelect<'a, Message> {
+    TreeSelect::new(items, selected, on_change)
+}
+
+/// Creates a new [`Item`] with an id and a label.
+pub fn item(id: usize, label: impl Into<String>) -> Item {
+    Item {
+        id,
+        label: label.into(),
+        children: Vec::new(),
+    }
+}
+
+/// An entry of a [`TreeSelect`], with the entries nested under it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Item {
+    /// The id reported in the selection, unique in the tree and not 0
+    pub id: usize,
+    /// The text shown for the item, and matched by the search
+    pub label: String,
+    /// The items nested under this one
+    pub children: Vec<Item>,
+}
+
+impl Item {
+    /// Nests `children` under the item.
+    pub fn with_children(mut self, children: impl IntoIterator<Item = Item>) -> Self {
+        self.children = children.into_iter().collect();
+        self
+    }
+
+    /// The ids of the item and everything under it.
+    fn ids(&self) -> Vec<usize> {
+        std::iter::once(self.id)
+            .chain(self.children.iter().flat_map(Item::ids))
+            .collect()
+    }
+
+    fn matches(&self, query: &str) -> bool {
+        self.label.to_lowercase().contains(query) || self.children.iter().any(|child| child.matches(query))
+    }
+}
+
+/// Finds the item with `id` among `items` and everything under them.
+fn find(items: &[Item], id: usize) -> Option<&Item> {
+    items
+        .iter()
+        .find_map(|item| if item.id == id { Some(item) } else { find(&item.children, id) })
+}
+
+/// A button showing the selection that opens a tree of items to pick from.
+#[allow(missing_debug_implementations)]
+pub struct TreeSelect<'a, Message> {
+    items: &'a [Item],
+    selected: &'a HashSet<usize>,
+    on_change: Rc<dyn Fn(HashSet<usize>) -> Message + 'a>,
+    query: &'a str,
+    on_search: Option<Box<dyn Fn(String) -> Message + 'a>>,
+    multiple: bool,
+    placeholder: String,
+    width: Length,
+    overlay_width: f32,
+    max_height: f32,
+}
+
+impl<'a, Message> TreeSelect<'a, Message> {
+    /// Creates a new [`TreeSelect`] of `items`, producing `on_change` with the new selection.
+    pub fn new(
+        items: &'a [Item],
+        selected: &'a HashSet<usize>,
+        on_change: impl Fn(HashSet<usize>) -> Message + 'a,
+    ) -> Self {
+        Self {
+            items,
+            selected,
+            on_change: Rc::new(on_change),
+            query: "",
+            on_search: None,
+            multiple: false,
+            placeholder: String::from("Select…"),
+            width: Length::Fixed(240.0),
+            overlay_width: 280.0,
+            max_height: 320.0,
+        }
+    }
+
+    /// Lets more than one item be selected, each with a checkbox.
+    pub fn multiple(mut self) -> Self {
+        self.multiple = true;
+        self
+    }
+
+    /// Shows a search field with `query`, producing `on_search` as it is edited.
+    pub fn search(mut self, query: &'a str, on_search: impl Fn(String) -> Message + 'a) -> Self {
+        self.query = query;
+        self.on_search = Some(Box::new(on_search));
+        self
+    }
+
+    /// Sets the text shown while nothing is selected.
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Sets the width of the button.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the width of the dropdown.
+    pub fn overlay_width(mut self, width: f32) -> Self {
+        self.overlay_width = width;
+        self
+    }
+
+    /// Sets the tallest the dropdown grows before it scrolls.
+    pub fn max_height(mut self, height: f32) -> Self {
+        self.max_height = height;
+        self
+    }
+
+    /// The text of the button for the current selection.
+    fn summary(&self) -> String {
+        let mut labels: Vec<&str> = self
+            .selected
+            .iter()
+            .filter_map(|id| find(self.items, *id))
+            .map(|item| item.label.as_str())
+            .collect();
+        labels.sort_unstable();
+
+        match labels.len() {
+            0 => self.placeholder.clone(),
+            1 | 2 => labels.join(", "),
+            count => format!("{count} selected"),
+        }
+    }
+
+    fn branches<Theme, Renderer>(&self, items: &'a [Item], query: &str) -> Vec<Branch<'a, Message, Theme, Renderer>>
+    where
+        Message: Clone + 'a,
+        Theme: checkbox::Catalog + iced::widget::text::Catalog + 'a,
+        Renderer: iced::advanced::Renderer + text::Renderer<Font = iced::Font> + 'a,
+    {
+        items
+            .iter()
+            .filter(|item| query.is_empty() || item.matches(query))
+            .map(|item| {
+                let is_selected = self.selected.contains(&item.id);
+                let on_change = self.on_change.clone();
+
+                let content: Element<'a, Message, Theme, Renderer> = if self.multiple {
+                    let selected = self.selected.clone();
+                    let ids = item.ids();
+
+                    checkbox(is_selected)
+                        .label(item.label.as_str())
+                        .on_toggle(move |checked| {
+                            let mut selected = selected.clone();
+                            for id in &ids {
+                                if checked {
+                                    selected.insert(*id);
+                                } else {
+                                    selected.remove(id);
+                                }
+                            }
+                            on_change(selected)
+                        })
+                        .into()
+                } else {
+                    let content = row![label(if is_selected { "✓" } else { "" }).width(14), label(item.label.as_str())]
+                        .spacing(4)
+                        .width(Length::Fill);
+
+                    mouse_area(content)
+                        .on_press(on_change(HashSet::from([item.id])))
+                        .interaction(mouse::Interaction::Pointer)
+                        .into()
+                };
+
+                // Once an item matches, everything under it is shown
+                let query = if item.label.to_lowercase().contains(query) { "" } else { query };
+
+                branch(content)
+                    .with_id(item.id)
+                    .block_dragging()
+                    .with_children(self.branches(&item.children, query))
+            })
+            .collect()
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<TreeSelect<'a, Message>> for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: tree::Catalog
+        + generic_overlay::Catalog
+        + button::Catalog
+        + checkbox::Catalog
+        + container::Catalog
+        + scrollable::Catalog
+        + text_input::Catalog
+        + iced::widget::text::Catalog
+        + 'a,
+    Renderer: iced::advanced::Renderer + text::Renderer<Font = iced::Font> + 'a,
+{
+    fn from(select: TreeSelect<'a, Message>) -> Self {
+        let summary = select.summary();
+        let query = select.query.trim().to_lowercase();
+        let tree: Element<'a, Message, Theme, Renderer> = tree_handle(select.branches(select.items, &query))
+            .width(Length::Fill)
+            .into();
+
+        let mut content = column![].spacing(4).padding(4);
+        if let Some(on_search) = select.on_search {
+            content = content.push(text_input("Search…", select.query).on_input(on_search).padding(6));
+        }
+        let content = content.push(scrollable(tree).height(Length::Shrink));
+
+        let button_label = row![label(summary).width(Length::Fill), label("▾")]
+            .spacing(6)
+            .align_y(Alignment::Center);
+
+        let dropdown = dropdown_root(button_label, container(content).max_height(select.max_height))
+            .overlay_width(select.overlay_width)
+            .overlay_max_height(select.max_height)
+            .width(select.width);
+
+        if select.multiple {
+            dropdown.into()
+        } else {
+            dropdown.close_on_select().into()
+        }
+    }
+}
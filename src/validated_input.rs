@@ -0,0 +1,523 @@
+//! A wrapper showing the validation state of an input.
+//!
+//! [`ValidatedInput`] draws a colored border over its content, usually a text input, with an
+//! icon at its end and a message line under it, all from a [`Validation`] the app gives it.
+//! Checks that take a while, like asking a server whether a name is taken, show as
+//! [`Validation::Pending`] with animated dots until the app has the answer.
+//!
+//! The wrapper does no validating itself, so any rule and any timing is up to the app.
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        mouse, overlay, renderer, text,
+        widget::{self, tree::Tree, Operation},
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment,
+    time::{Duration, Instant},
+    window, Border, Color, Element, Event, Length, Pixels, Point, Rectangle, Size, Vector,
+};
+
+use crate::pixel;
+
+const ICON_WIDTH: f32 = 20.0;
+const DOT_SIZE: f32 = 4.0;
+const DOT_SPACING: f32 = 3.0;
+const PENDING_PERIOD: Duration = Duration::from_millis(900);
+
+/// Wraps `content` to show `validation` around it.
+pub fn validated_input<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    validation: &Validation,
+) -> ValidatedInput<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer<Font = iced::Font>,
+{
+    ValidatedInput::new(content, validation)
+}
+
+/// The outcome of validating an input, as decided by the app.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum Validation {
+    /// Not validated yet, shown as the plain input
+    #[default]
+    Unchecked,
+    /// A check is running
+    Pending,
+    /// The value is valid, with an optional message
+    Success(Option<String>),
+    /// The value is accepted with a warning
+    Warning(String),
+    /// The value is invalid
+    Error(String),
+}
+
+impl Validation {
+    /// The message shown under the input, if any.
+    pub fn message(&self) -> Option<&str> {
+        match self {
+            Validation::Unchecked | Validation::Pending => None,
+            Validation::Success(message) => message.as_deref(),
+            Validation::Warning(message) | Validation::Error(message) => Some(message),
+        }
+    }
+
+    /// Whether the value may be submitted, which it may unless it is an error or pending.
+    pub fn is_acceptable(&self) -> bool {
+        !matches!(self, Validation::Pending | Validation::Error(_))
+    }
+
+    fn status(&self) -> Status {
+        match self {
+            Validation::Unchecked => Status::Unchecked,
+            Validation::Pending => Status::Pending,
+            Validation::Success(_) => Status::Success,
+            Validation::Warning(_) => Status::Warning,
+            Validation::Error(_) => Status::Error,
+        }
+    }
+}
+
+/// An input with a validation border, icon and message.
+#[allow(missing_debug_implementations)]
+pub struct ValidatedInput<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    validation: Validation,
+    show_icon: bool,
+    reserve_message: bool,
+    spacing: f32,
+    text_size: Pixels,
+    font: Option<Renderer::Font>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> ValidatedInput<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer<Font = iced::Font>,
+{
+    /// Wraps `content` to show `validation` around it.
+    pub fn new(content: impl Into<Element<'a, Message, Theme, Renderer>>, validation: &Validation) -> Self {
+        Self {
+            content: content.into(),
+            validation: validation.clone(),
+            show_icon: true,
+            reserve_message: false,
+            spacing: 4.0,
+            text_size: Pixels(12.0),
+            font: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets whether the icon is drawn at the end of the input, which it is by default.
+    pub fn icon(mut self, show: bool) -> Self {
+        self.show_icon = show;
+        self
+    }
+
+    /// Keeps room for the message line while there is no message, so the layout does not move.
+    pub fn reserve_message(mut self, reserve: bool) -> Self {
+        self.reserve_message = reserve;
+        self
+    }
+
+    /// Sets the space between the input and the message.
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets the text size of the message.
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = size.into();
+        self
+    }
+
+    /// Sets the font of the message, the default font otherwise.
+    pub fn font(mut self, font: iced::Font) -> Self {
+        self.font = Some(font);
+        self
+    }
+
+    /// Sets the style.
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class.
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    fn message_height(&self) -> f32 {
+        if self.validation.message().is_some() || self.reserve_message {
+            self.spacing + text::LineHeight::default().to_absolute(self.text_size).0
+        } else {
+            0.0
+        }
+    }
+
+    fn text(&self, content: String, bounds: Size, font: iced::Font) -> iced::advanced::Text<String, iced::Font> {
+        iced::advanced::Text {
+            content,
+            bounds,
+            size: self.text_size,
+            font,
+            align_x: text::Alignment::Left,
+            align_y: alignment::Vertical::Center,
+            line_height: text::LineHeight::default(),
+            shaping: text::Shaping::Advanced,
+            wrapping: text::Wrapping::None,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct State {
+    now: Instant,
+    /// When the input last became pending, to start the dots from the first one
+    started: Option<Instant>,
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for ValidatedInput<'_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer<Font = iced::Font>,
+{
+    fn size(&self) -> Size<Length> {
+        let content = self.content.as_widget().size();
+
+        Size {
+            width: content.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(State {
+            now: Instant::now(),
+            started: None,
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn layout(&mut self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let message = self.message_height();
+        let content = self.content.as_widget_mut().layout(
+            &mut tree.children[0],
+            renderer,
+            &limits.shrink(Size::new(0.0, message)),
+        );
+        let size = content.size();
+
+        Node::with_children(
+            limits.resolve(self.size().width, Length::Shrink, Size::new(size.width, size.height + message)),
+            vec![content],
+        )
+    }
+
+    fn operate(&mut self, tree: &mut Tree, layout: Layout<'_>, renderer: &Renderer, operation: &mut dyn Operation) {
+        if let Some(content) = layout.children().next() {
+            self.content
+                .as_widget_mut()
+                .operate(&mut tree.children[0], content, renderer, operation);
+        }
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            let state = tree.state.downcast_mut::<State>();
+
+            if self.validation == Validation::Pending {
+                state.now = *now;
+                state.started.get_or_insert(*now);
+                shell.request_redraw();
+            } else {
+                state.started = None;
+            }
+        }
+
+        if let Some(content) = layout.children().next() {
+            self.content.as_widget_mut().update(
+                &mut tree.children[0],
+                event,
+                content,
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            );
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        defaults: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let Some(content_layout) = layout.children().next() else {
+            return;
+        };
+        let bounds = layout.bounds();
+        let content = content_layout.bounds();
+        let style = theme.style(&self.class, self.validation.status());
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            defaults,
+            content_layout,
+            cursor,
+            viewport,
+        );
+
+        // Drawn over the content's own border
+        if style.border.width > 0.0 {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: pixel::snap(content),
+                    border: pixel::border(style.border),
+                    ..renderer::Quad::default()
+                },
+                Color::TRANSPARENT,
+            );
+        }
+
+        if self.show_icon {
+            let icon = Rectangle::new(
+                Point::new(content.x + content.width - ICON_WIDTH - 4.0, content.y),
+                Size::new(ICON_WIDTH, content.height),
+            );
+
+            if self.validation == Validation::Pending {
+                let state = tree.state.downcast_ref::<State>();
+                let elapsed = state.started.map_or(0.0, |started| (state.now - started).as_secs_f32());
+                let phase = (elapsed / PENDING_PERIOD.as_secs_f32()).fract();
+                let width = DOT_SIZE * 3.0 + DOT_SPACING * 2.0;
+
+                for dot in 0..3 {
+                    // Each dot brightens in turn
+                    let distance = (phase * 3.0 - dot as f32).rem_euclid(3.0);
+                    let alpha = 0.3 + 0.7 * (1.0 - distance.min(1.0));
+                    let x = icon.center_x() - width / 2.0 + dot as f32 * (DOT_SIZE + DOT_SPACING);
+
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: pixel::snap(Rectangle::new(
+                                Point::new(x, icon.center_y() - DOT_SIZE / 2.0),
+                                Size::new(DOT_SIZE, DOT_SIZE),
+                            )),
+                            border: pixel::border(Border {
+                                radius: (DOT_SIZE / 2.0).into(),
+                                ..Border::default()
+                            }),
+                            ..renderer::Quad::default()
+                        },
+                        style.icon.scale_alpha(alpha),
+                    );
+                }
+            } else {
+                let glyph = match self.validation {
+                    Validation::Success(_) => Some("✓"),
+                    Validation::Warning(_) => Some("!"),
+                    Validation::Error(_) => Some("✕"),
+                    Validation::Unchecked | Validation::Pending => None,
+                };
+
+                if let Some(glyph) = glyph {
+                    renderer.fill_text(
+                        iced::advanced::Text {
+                            size: Pixels(14.0),
+                            align_x: text::Alignment::Center,
+                            ..self.text(glyph.to_string(), icon.size(), font)
+                        },
+                        icon.center(),
+                        style.icon,
+                        content,
+                    );
+                }
+            }
+        }
+
+        if let Some(message) = self.validation.message() {
+            let line = Rectangle::new(
+                Point::new(bounds.x, content.y + content.height + self.spacing),
+                Size::new(bounds.width, bounds.y + bounds.height - content.y - content.height - self.spacing),
+            );
+
+            renderer.fill_text(
+                self.text(message.to_string(), line.size(), font),
+                Point::new(line.x, line.center_y()),
+                style.message,
+                line.intersection(viewport).unwrap_or(line),
+            );
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        layout.children().next().map_or(mouse::Interaction::None, |content| {
+            self.content
+                .as_widget()
+                .mouse_interaction(&tree.children[0], content, cursor, viewport, renderer)
+        })
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let content = layout.children().next()?;
+
+        self.content
+            .as_widget_mut()
+            .overlay(&mut tree.children[0], content, renderer, viewport, translation)
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<ValidatedInput<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer<Font = iced::Font> + 'a,
+{
+    fn from(input: ValidatedInput<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(input)
+    }
+}
+
+/// The state shown by a [`ValidatedInput`], following its [`Validation`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Unchecked,
+    Pending,
+    Success,
+    Warning,
+    Error,
+}
+
+/// The theme catalog of a [`ValidatedInput`].
+pub trait Catalog {
+    /// The style class
+    type Class<'a>;
+
+    /// Default style
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// Get the style for a class and status
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+/// The appearance of a [`ValidatedInput`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// Border drawn over the input, none when its width is zero
+    pub border: Border,
+    /// Color of the icon and the pending dots
+    pub icon: Color,
+    /// Color of the message
+    pub message: Color,
+}
+
+/// Styling function
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for iced::Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default validated input style, with borders matching iced's text input.
+pub fn default(theme: &iced::Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+    let color = match status {
+        Status::Unchecked => Color::TRANSPARENT,
+        Status::Pending => palette.background.strong.color,
+        Status::Success => palette.success.base.color,
+        Status::Warning => palette.warning.base.color,
+        Status::Error => palette.danger.base.color,
+    };
+
+    Style {
+        border: Border {
+            color,
+            width: if status == Status::Unchecked { 0.0 } else { 1.0 },
+            radius: 2.0.into(),
+        },
+        icon: color,
+        message: if status == Status::Pending {
+            palette.background.strong.color
+        } else {
+            color
+        },
+    }
+}
+
+/// Only the icon and message are colored, the input keeps its own border.
+pub fn subtle(theme: &iced::Theme, status: Status) -> Style {
+    Style {
+        border: Border::default(),
+        ..default(theme, status)
+    }
+}
@@ -0,0 +1,437 @@
+//! A container that zooms and pans its content.
+//!
+//! The mouse wheel zooms around the cursor, keeping the point under it in place, and dragging
+//! with the middle button pans. Zooming is kept between [`Viewport::min_zoom`] and
+//! [`Viewport::max_zoom`]. The content sees the cursor in its own coordinates, so it stays
+//! interactive at any zoom.
+//!
+//! The viewport keeps its [`Transform`] itself and reports every change through
+//! [`Viewport::on_transform`], so the host can map between screen and content coordinates
+//! with [`Transform::to_content`] and [`Transform::to_viewport`]. Passing a new transform with
+//! [`Viewport::transform`] moves the view, for example to reset or fit it.
+//!
+//! Overlays opened by the content, such as dropdown menus, are not shown.
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        mouse, renderer,
+        widget::{self, tree::Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    Element, Event, Length, Point, Rectangle, Size, Transformation, Vector,
+};
+
+/// Creates a new [`Viewport`] around `content`.
+pub fn viewport<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Viewport<'a, Message, Theme, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    Viewport::new(content)
+}
+
+/// How the content of a [`Viewport`] is placed: scaled by `scale`, then moved by `offset`.
+///
+/// Points are relative to the top left corner of the viewport and of the content.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Transform {
+    #[cfg_attr(feature = "serde", serde(with = "crate::persist::Vector"))]
+    pub offset: Vector,
+    pub scale: f32,
+}
+
+impl Transform {
+    /// The content at its own size, in the top left corner.
+    pub const IDENTITY: Self = Self {
+        offset: Vector::ZERO,
+        scale: 1.0,
+    };
+
+    /// Maps a point in the viewport to the content.
+    pub fn to_content(&self, point: Point) -> Point {
+        Point::new(
+            (point.x - self.offset.x) / self.scale,
+            (point.y - self.offset.y) / self.scale,
+        )
+    }
+
+    /// Maps a point in the content to the viewport.
+    pub fn to_viewport(&self, point: Point) -> Point {
+        Point::new(
+            point.x * self.scale + self.offset.x,
+            point.y * self.scale + self.offset.y,
+        )
+    }
+
+    /// Zooms to `scale` keeping `anchor`, a point in the viewport, over the same content.
+    pub fn zoom_at(&self, anchor: Point, scale: f32) -> Self {
+        let content = self.to_content(anchor);
+
+        Self {
+            offset: Vector::new(anchor.x - content.x * scale, anchor.y - content.y * scale),
+            scale,
+        }
+    }
+
+    /// The part of the content shown in a viewport of `size`.
+    pub fn visible(&self, size: Size) -> Rectangle {
+        Rectangle::new(
+            self.to_content(Point::ORIGIN),
+            Size::new(size.width / self.scale, size.height / self.scale),
+        )
+    }
+
+    /// Pans at the same zoom so `corner`, a point in the content, is in the top left corner.
+    pub fn looking_at(&self, corner: Point) -> Self {
+        Self {
+            offset: Vector::new(-corner.x * self.scale, -corner.y * self.scale),
+            scale: self.scale,
+        }
+    }
+
+    pub(crate) fn transformation(&self, origin: Point) -> Transformation {
+        Transformation::translate(origin.x + self.offset.x, origin.y + self.offset.y)
+            * Transformation::scale(self.scale)
+            * Transformation::translate(-origin.x, -origin.y)
+    }
+}
+
+impl Default for Transform {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+/// A container zooming and panning its content.
+#[allow(missing_debug_implementations)]
+pub struct Viewport<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+    transform: Option<Transform>,
+    on_transform: Option<Box<dyn Fn(Transform) -> Message + 'a>>,
+    width: Length,
+    height: Length,
+    min_zoom: f32,
+    max_zoom: f32,
+    zoom_step: f32,
+}
+
+impl<'a, Message, Theme, Renderer> Viewport<'a, Message, Theme, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    /// Creates a new [`Viewport`] around `content`.
+    pub fn new(content: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        Self {
+            content: content.into(),
+            transform: None,
+            on_transform: None,
+            width: Length::Fill,
+            height: Length::Fill,
+            min_zoom: 0.1,
+            max_zoom: 10.0,
+            zoom_step: 1.1,
+        }
+    }
+
+    /// Moves the view to `transform` whenever it differs from the one last passed.
+    pub fn transform(mut self, transform: Transform) -> Self {
+        self.transform = Some(transform);
+        self
+    }
+
+    /// Sets the message produced when the view is zoomed or panned.
+    pub fn on_transform(mut self, on_transform: impl Fn(Transform) -> Message + 'a) -> Self {
+        self.on_transform = Some(Box::new(on_transform));
+        self
+    }
+
+    /// Sets the width of the viewport.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the viewport.
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the smallest scale, raising the largest one to it if it is below.
+    pub fn min_zoom(mut self, zoom: f32) -> Self {
+        self.min_zoom = zoom.max(f32::EPSILON);
+        self.max_zoom = self.max_zoom.max(self.min_zoom);
+        self
+    }
+
+    /// Sets the largest scale, lowering the smallest one to it if it is above.
+    pub fn max_zoom(mut self, zoom: f32) -> Self {
+        self.max_zoom = zoom.max(f32::EPSILON);
+        self.min_zoom = self.min_zoom.min(self.max_zoom);
+        self
+    }
+
+    /// Sets how much one notch of the wheel zooms by, as a factor.
+    pub fn zoom_step(mut self, step: f32) -> Self {
+        self.zoom_step = step.max(1.0);
+        self
+    }
+
+    fn set(&self, state: &mut State, transform: Transform, shell: &mut Shell<'_, Message>) {
+        if transform != state.transform {
+            state.transform = transform;
+
+            if let Some(on_transform) = &self.on_transform {
+                shell.publish(on_transform(transform));
+            }
+        }
+        shell.request_redraw();
+    }
+
+    /// The visible part of the content, in its coordinates.
+    fn content_viewport(&self, state: &State, bounds: Rectangle, viewport: &Rectangle) -> Rectangle {
+        let visible = bounds.intersection(viewport).unwrap_or(bounds);
+        visible * state.transform.transformation(bounds.position()).inverse()
+    }
+
+    fn map_cursor(&self, state: &State, bounds: Rectangle, cursor: mouse::Cursor) -> mouse::Cursor {
+        match cursor.position_over(bounds) {
+            Some(position) => {
+                mouse::Cursor::Available(position * state.transform.transformation(bounds.position()).inverse())
+            }
+            None => mouse::Cursor::Unavailable,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    transform: Transform,
+    /// The transform last passed by the host
+    synced: Option<Transform>,
+    /// Where the middle button was last seen while panning
+    panning: Option<Point>,
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Viewport<'_, Message, Theme, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(State {
+            transform: self.transform.unwrap_or_default(),
+            synced: self.transform,
+            panning: None,
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+
+        let state = tree.state.downcast_mut::<State>();
+        if let Some(transform) = self.transform
+            && state.synced != Some(transform)
+        {
+            state.transform = transform;
+            state.synced = Some(transform);
+        }
+    }
+
+    fn layout(&mut self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let size = limits.resolve(self.width, self.height, Size::ZERO);
+
+        // Content filling an axis fills the viewport, anything else gets its own size
+        let content_size = self.content.as_widget().size();
+        let max = Size::new(
+            if content_size.width.is_fill() { size.width } else { f32::INFINITY },
+            if content_size.height.is_fill() { size.height } else { f32::INFINITY },
+        );
+        let content = self
+            .content
+            .as_widget_mut()
+            .layout(&mut tree.children[0], renderer, &Limits::new(Size::ZERO, max));
+
+        Node::with_children(size, vec![content])
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_mut::<State>();
+
+        match event {
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) if cursor.is_over(bounds) => {
+                let Some(position) = cursor.position_in(bounds) else {
+                    return;
+                };
+                let notches = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => *y,
+                    mouse::ScrollDelta::Pixels { y, .. } => y / 50.0,
+                };
+                let scale = (state.transform.scale * self.zoom_step.powf(notches)).clamp(self.min_zoom, self.max_zoom);
+
+                let transform = state.transform.zoom_at(position, scale);
+                self.set(state, transform, shell);
+                shell.capture_event();
+                return;
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Middle)) => {
+                if let Some(position) = cursor.position_over(bounds) {
+                    state.panning = Some(position);
+                    shell.capture_event();
+                    return;
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position }) if state.panning.is_some() => {
+                let last = state.panning.replace(*position).unwrap_or(*position);
+                let transform = Transform {
+                    offset: state.transform.offset + (*position - last),
+                    ..state.transform
+                };
+
+                self.set(state, transform, shell);
+                shell.capture_event();
+                return;
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Middle)) if state.panning.is_some() => {
+                state.panning = None;
+                shell.capture_event();
+                return;
+            }
+            _ => {}
+        }
+
+        // The content gets the cursor and every position in its own coordinates
+        let transformation = state.transform.transformation(bounds.position()).inverse();
+        let event = match event {
+            Event::Mouse(mouse::Event::CursorMoved { position }) => Event::Mouse(mouse::Event::CursorMoved {
+                position: *position * transformation,
+            }),
+            event => event.clone(),
+        };
+        let content_viewport = self.content_viewport(state, bounds, viewport);
+        let cursor = self.map_cursor(state, bounds, cursor);
+
+        self.content.as_widget_mut().update(
+            &mut tree.children[0],
+            &event,
+            layout.children().next().unwrap_or(layout),
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            &content_viewport,
+        );
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        defaults: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let Some(clip) = bounds.intersection(viewport) else {
+            return;
+        };
+
+        let content_viewport = self.content_viewport(state, bounds, viewport);
+        let cursor = self.map_cursor(state, bounds, cursor);
+
+        renderer.with_layer(clip, |renderer| {
+            renderer.with_transformation(state.transform.transformation(bounds.position()), |renderer| {
+                self.content.as_widget().draw(
+                    &tree.children[0],
+                    renderer,
+                    theme,
+                    defaults,
+                    layout.children().next().unwrap_or(layout),
+                    cursor,
+                    &content_viewport,
+                );
+            });
+        });
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+
+        if state.panning.is_some() {
+            return mouse::Interaction::Grabbing;
+        }
+
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout.children().next().unwrap_or(layout),
+            self.map_cursor(state, bounds, cursor),
+            &self.content_viewport(state, bounds, viewport),
+            renderer,
+        )
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn widget::Operation,
+    ) {
+        self.content.as_widget_mut().operate(
+            &mut tree.children[0],
+            layout.children().next().unwrap_or(layout),
+            renderer,
+            operation,
+        );
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Viewport<'a, Message, Theme, Renderer>> for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(viewport: Viewport<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(viewport)
+    }
+}
@@ -0,0 +1,159 @@
+//! Drives widgets off-screen with the [`Simulator`] and checks the messages they produce.
+
+use std::collections::HashSet;
+
+use iced::keyboard::{self, key::Named};
+use iced::widget::text;
+use iced::{Point, Size};
+use widgets::generic_overlay::overlay_button;
+use widgets::rating::rating;
+use widgets::testing::{Cache, Simulator};
+use widgets::tree::{branch, tree_handle};
+
+#[derive(Debug, Clone, PartialEq)]
+enum Message {
+    Rated(f32),
+    Selected(HashSet<usize>),
+    Toggled(usize, bool),
+    Opened(bool),
+    Renamed(usize, String),
+}
+
+/// Mounts a rating of `value`, runs `interact` on it and applies the messages it produced.
+fn rate(value: &mut f32, cache: Cache, interact: impl FnOnce(&mut Simulator<'_, Message>)) -> Cache {
+    let mut ui = Simulator::with_cache(
+        rating(*value).on_change(Message::Rated),
+        Size::new(200.0, 40.0),
+        (),
+        cache,
+    );
+
+    interact(&mut ui);
+
+    for message in ui.take_messages() {
+        if let Message::Rated(rated) = message {
+            *value = rated;
+        }
+    }
+
+    ui.into_cache()
+}
+
+#[test]
+fn rating_follows_clicks_and_arrows() {
+    let mut value = 2.0;
+    let mut cache = Cache::default();
+
+    // Stars are 24 wide with 4 between them, so the fourth one spans 84 to 108
+    cache = rate(&mut value, cache, |ui| ui.click(Point::new(100.0, 12.0)));
+    assert_eq!(value, 4.0);
+
+    // The click focused the rating, so the arrow keys now change it
+    cache = rate(&mut value, cache, |ui| ui.tap(Named::ArrowRight));
+    assert_eq!(value, 5.0);
+
+    rate(&mut value, cache, |ui| ui.tap(Named::ArrowLeft));
+    assert_eq!(value, 4.0);
+}
+
+#[test]
+fn tree_selects_and_toggles_branches() {
+    let tree = tree_handle(vec![
+        branch(text("a")).with_id(1).with_children(vec![branch(text("b")).with_id(2)]),
+        branch(text("c")).with_id(3),
+    ])
+    .on_select(Message::Selected)
+    .on_toggle(Message::Toggled);
+    let mut ui = Simulator::new(tree, Size::new(200.0, 200.0));
+
+    // Clicking the first row past its arrow selects it and focuses the tree
+    ui.click(Point::new(40.0, 20.0));
+    // Left collapses the focused branch, and its arrow expands it again
+    ui.tap(Named::ArrowLeft);
+    ui.click(Point::new(10.0, 20.0));
+
+    assert_eq!(
+        ui.into_messages(),
+        vec![Message::Selected(HashSet::from([1])), Message::Toggled(1, false), Message::Toggled(1, true)],
+    );
+}
+
+#[test]
+fn tree_keeps_expansion_the_app_rejects() {
+    let tree = || {
+        tree_handle(vec![
+            branch(text("a")).with_id(1).with_children(vec![branch(text("b")).with_id(2)]),
+            branch(text("c")).with_id(3),
+        ])
+        .expanded([1])
+        .on_select(Message::Selected)
+        .on_toggle(Message::Toggled)
+    };
+
+    let mut ui = Simulator::new(tree(), Size::new(200.0, 200.0));
+    ui.click(Point::new(40.0, 20.0));
+    ui.tap(Named::ArrowLeft);
+    assert_eq!(
+        ui.take_messages(),
+        vec![Message::Selected(HashSet::from([1])), Message::Toggled(1, false)],
+    );
+
+    // The app ignores the toggle and rebuilds with the same expansion, so the child stays shown
+    let mut ui = Simulator::with_cache(tree(), Size::new(200.0, 200.0), (), ui.into_cache());
+    ui.tap(Named::ArrowDown);
+    ui.tap(Named::Space);
+
+    assert_eq!(ui.into_messages(), vec![Message::Selected(HashSet::from([2]))]);
+}
+
+#[test]
+fn tree_renames_with_caret_movement_and_paste() {
+    let tree = tree_handle(vec![branch(text("alpha")).with_id(1).renamable("alpha")])
+        .on_select(Message::Selected)
+        .on_rename(Message::Renamed);
+    let mut ui = Simulator::new(tree, Size::new(200.0, 200.0));
+    ui.clipboard().standard = Some(" copy".into());
+
+    ui.click(Point::new(40.0, 20.0));
+    ui.tap(Named::F2);
+    // The whole name starts selected, Home drops the selection
+    ui.tap(Named::Home);
+    ui.tap(Named::ArrowRight);
+    ui.tap(Named::Delete);
+    ui.type_text("L");
+    ui.tap(Named::End);
+    ui.modifiers(keyboard::Modifiers::COMMAND);
+    ui.tap(keyboard::Key::Character("v".into()));
+    ui.modifiers(keyboard::Modifiers::empty());
+    ui.tap(Named::Enter);
+
+    assert_eq!(
+        ui.into_messages(),
+        vec![Message::Selected(HashSet::from([1])), Message::Renamed(1, "aLpha copy".into())],
+    );
+}
+
+#[test]
+fn overlay_opens_on_click_and_closes_on_escape() {
+    let button = overlay_button(text("open"), "Title", text("content")).on_toggle(Message::Opened);
+    let mut ui = Simulator::new(button, Size::new(400.0, 400.0));
+
+    ui.click(Point::new(5.0, 5.0));
+    assert_eq!(ui.take_messages(), vec![Message::Opened(true)]);
+
+    ui.tap(Named::Escape);
+    assert_eq!(ui.take_messages(), vec![Message::Opened(false)]);
+}
+
+#[test]
+fn overlay_closes_on_click_outside() {
+    let button = overlay_button(text("open"), "Title", text("content"))
+        .close_on_click_outside()
+        .on_toggle(Message::Opened);
+    let mut ui = Simulator::new(button, Size::new(400.0, 400.0));
+
+    ui.click(Point::new(5.0, 5.0));
+    ui.click(Point::new(390.0, 390.0));
+
+    assert_eq!(ui.into_messages(), vec![Message::Opened(true), Message::Opened(false)]);
+}